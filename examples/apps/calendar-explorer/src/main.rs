@@ -15,10 +15,10 @@ use crossterm::event::{self, KeyCode};
 use ratatui::layout::{Constraint, Layout, Margin, Rect};
 use ratatui::style::{Color, Modifier, Style, Stylize};
 use ratatui::text::{Line, Text};
-use ratatui::widgets::calendar::{CalendarEventStore, Monthly};
+use ratatui::widgets::calendar::{CalendarEventStore, Monthly, Recurrence};
 use ratatui::{DefaultTerminal, Frame};
 use time::ext::NumericalDuration;
-use time::{Date, Month, OffsetDateTime};
+use time::{Date, Month, OffsetDateTime, Weekday};
 
 fn main() -> Result<()> {
     color_eyre::install()?;
@@ -86,9 +86,7 @@ fn render(frame: &mut Frame, calendar_style: StyledCalendar, selected_date: Date
         Constraint::Fill(1),
     ]));
     frame.render_widget(header.centered(), text_area);
-    calendar_style
-        .render_year(frame, area, selected_date)
-        .unwrap();
+    calendar_style.render_year(frame, area, selected_date);
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -129,8 +127,8 @@ impl fmt::Display for StyledCalendar {
 }
 
 impl StyledCalendar {
-    fn render_year(self, frame: &mut Frame, area: Rect, date: Date) -> Result<()> {
-        let events = events(date)?;
+    fn render_year(self, frame: &mut Frame, area: Rect, date: Date) {
+        let events = events(date);
 
         let vertical = Layout::vertical([Constraint::Ratio(1, 3); 3]);
         let horizontal = &Layout::horizontal([Constraint::Ratio(1, 4); 4]);
@@ -147,7 +145,6 @@ impl StyledCalendar {
                 .unwrap();
             self.render_month(frame, area, month, &events);
         }
-        Ok(())
     }
 
     fn render_month(self, frame: &mut Frame, area: Rect, date: Date, events: &CalendarEventStore) {
@@ -181,8 +178,8 @@ impl StyledCalendar {
     }
 }
 
-/// Makes a list of dates for the current year.
-fn events(selected_date: Date) -> Result<CalendarEventStore> {
+/// Makes a list of dates, with holidays and seasons recurring every year.
+fn events(selected_date: Date) -> CalendarEventStore {
     const SELECTED: Style = Style::new()
         .fg(Color::White)
         .bg(Color::Red)
@@ -200,37 +197,37 @@ fn events(selected_date: Date) -> Result<CalendarEventStore> {
             .add_modifier(Modifier::BOLD)
             .bg(Color::Blue),
     );
-    let y = selected_date.year();
-
-    // new year's
-    list.add(Date::from_calendar_date(y, Month::January, 1)?, HOLIDAY);
-    // next new_year's for December "show surrounding"
-    list.add(Date::from_calendar_date(y + 1, Month::January, 1)?, HOLIDAY);
-    // groundhog day
-    list.add(Date::from_calendar_date(y, Month::February, 2)?, HOLIDAY);
-    // april fool's
-    list.add(Date::from_calendar_date(y, Month::April, 1)?, HOLIDAY);
-    // earth day
-    list.add(Date::from_calendar_date(y, Month::April, 22)?, HOLIDAY);
-    // star wars day
-    list.add(Date::from_calendar_date(y, Month::May, 4)?, HOLIDAY);
-    // festivus
-    list.add(Date::from_calendar_date(y, Month::December, 23)?, HOLIDAY);
-    // new year's eve
-    list.add(Date::from_calendar_date(y, Month::December, 31)?, HOLIDAY);
-
-    // seasons
-    // spring equinox
-    list.add(Date::from_calendar_date(y, Month::March, 22)?, SEASON);
-    // summer solstice
-    list.add(Date::from_calendar_date(y, Month::June, 21)?, SEASON);
-    // fall equinox
-    list.add(Date::from_calendar_date(y, Month::September, 22)?, SEASON);
-    // winter solstice
-    list.add(Date::from_calendar_date(y, Month::December, 21)?, SEASON);
+
+    let holidays = [
+        (Month::January, 1),   // new year's
+        (Month::February, 2),  // groundhog day
+        (Month::April, 1),     // april fool's
+        (Month::April, 22),    // earth day
+        (Month::May, 4),       // star wars day
+        (Month::December, 23), // festivus
+        (Month::December, 31), // new year's eve
+    ];
+    for (month, day) in holidays {
+        list.add_recurring(Recurrence::Yearly(month, day), HOLIDAY);
+    }
+    // thanksgiving
+    list.add_recurring(
+        Recurrence::NthWeekday(Month::November, 4, Weekday::Thursday),
+        HOLIDAY,
+    );
+
+    let seasons = [
+        (Month::March, 22),     // spring equinox
+        (Month::June, 21),      // summer solstice
+        (Month::September, 22), // fall equinox
+        (Month::December, 21),  // winter solstice
+    ];
+    for (month, day) in seasons {
+        list.add_recurring(Recurrence::Yearly(month, day), SEASON);
+    }
 
     // selected date
     list.add(selected_date, SELECTED);
 
-    Ok(list)
+    list
 }
@@ -137,6 +137,7 @@ impl App {
             Constraint::Length(v)
             | Constraint::Min(v)
             | Constraint::Max(v)
+            | Constraint::Content(v)
             | Constraint::Fill(v)
             | Constraint::Percentage(v) => *v = v.saturating_add(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_add(1),
@@ -151,6 +152,7 @@ impl App {
             Constraint::Length(v)
             | Constraint::Min(v)
             | Constraint::Max(v)
+            | Constraint::Content(v)
             | Constraint::Fill(v)
             | Constraint::Percentage(v) => *v = v.saturating_sub(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_sub(1),
@@ -230,7 +232,7 @@ impl From<Constraint> for ConstraintName {
             Percentage(_) => Self::Percentage,
             Ratio(_, _) => Self::Ratio,
             Min(_) => Self::Min,
-            Max(_) => Self::Max,
+            Max(_) | Constraint::Content(_) => Self::Max,
             Fill(_) => Self::Fill,
         }
     }
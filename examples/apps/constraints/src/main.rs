@@ -373,7 +373,7 @@ impl Example {
             Constraint::Ratio(_, _) => RATIO_COLOR,
             Constraint::Fill(_) => FILL_COLOR,
             Constraint::Min(_) => MIN_COLOR,
-            Constraint::Max(_) => MAX_COLOR,
+            Constraint::Max(_) | Constraint::Content(_) => MAX_COLOR,
         };
         let fg = Color::White;
         let title = format!("{constraint}");
@@ -170,12 +170,10 @@ impl App {
         }
     }
     fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
-        // increase the layout cache to account for the number of layout events. This ensures that
-        // layout is not generally reprocessed on every frame (which would lead to possible janky
-        // results when there are more than one possible solution to the requested layout). This
-        // assumes the user changes spacing about a 100 times or so.
-        let cache_size = EXAMPLE_DATA.len() * SelectedTab::iter().len() * 100;
-        Layout::init_cache(NonZeroUsize::new(cache_size).unwrap());
+        // let the layout cache grow with the number of layout events. This ensures that layout is
+        // not generally reprocessed on every frame (which would lead to possible janky results when
+        // there are more than one possible solution to the requested layout).
+        Layout::init_adaptive_cache(NonZeroUsize::new(100_000).unwrap());
 
         while self.is_running() {
             terminal.draw(|frame| frame.render_widget(self, frame.area()))?;
@@ -322,7 +320,12 @@ impl App {
         let demo_area = Rect::new(0, 0, area.width, height);
         let mut demo_buf = Buffer::empty(demo_area);
 
-        let scrollbar_needed = self.scroll_offset != 0 || height > area.height;
+        let mut scrollbar_state = ScrollbarState::from_lengths(
+            height as usize,
+            area.height as usize,
+            self.scroll_offset as usize,
+        );
+        let scrollbar_needed = scrollbar_state.is_scrollable();
         let content_area = if scrollbar_needed {
             Rect {
                 width: demo_area.width - 1,
@@ -347,12 +350,9 @@ impl App {
             buf[(area.x + x, area.y + y)] = cell;
         }
 
-        if scrollbar_needed {
-            let area = area.intersection(buf.area);
-            let mut state = ScrollbarState::new(max_scroll_offset() as usize)
-                .position(self.scroll_offset as usize);
-            Scrollbar::new(ScrollbarOrientation::VerticalRight).render(area, buf, &mut state);
-        }
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .auto_hide(true)
+            .render(area.intersection(buf.area), buf, &mut scrollbar_state);
         scrollbar_needed
     }
 }
@@ -527,7 +527,7 @@ impl Example {
 const fn color_for_constraint(constraint: Constraint, theme: Theme) -> Color {
     match constraint {
         Constraint::Min(_) => theme.min_bg,
-        Constraint::Max(_) => theme.max_bg,
+        Constraint::Max(_) | Constraint::Content(_) => theme.max_bg,
         Constraint::Length(_) => theme.length_bg,
         Constraint::Percentage(_) => theme.percentage_bg,
         Constraint::Ratio(_, _) => theme.ratio_bg,
@@ -26,9 +26,8 @@ use ratatui::{Frame, Terminal, TerminalOptions, Viewport, symbols};
 
 fn main() -> Result<()> {
     color_eyre::install()?;
-    let mut terminal = ratatui::init_with_options(TerminalOptions {
-        viewport: Viewport::Inline(8),
-    });
+    let mut terminal =
+        ratatui::init_with_options(TerminalOptions::new().viewport(Viewport::Inline(8)));
 
     let (tx, rx) = mpsc::channel();
     input_handling(tx.clone());
@@ -1,7 +1,8 @@
 //! A Ratatui example that demonstrates how to handle input form focus
 //!
 //! This example demonstrates how to handle cursor and input focus between multiple fields in a
-//! form. You can navigate between fields using the Tab key.
+//! form with a [`FocusManager`]. You can navigate between fields using the Tab and Shift-Tab keys,
+//! or by clicking on a field.
 //!
 //! This does not handle cursor movement etc. This is just a simple example. In a real application,
 //! consider using [`tui-input`], or [`tui-prompts`], or [`tui-textarea`].
@@ -10,24 +11,32 @@
 //! See the [`latest`] branch for the code which works with the most recent Ratatui release.
 //!
 //! [`latest`]: https://github.com/ratatui/ratatui/tree/latest
+//! [`FocusManager`]: ratatui::widgets::FocusManager
 //! [`tui-input`]: https://crates.io/crates/tui-input
 //! [`tui-prompts`]: https://crates.io/crates/tui-prompts
 //! [`tui-textarea`]: https://crates.io/crates/tui-textarea
 
 use color_eyre::Result;
-use crossterm::event::{self, KeyCode, KeyEvent};
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, MouseButton,
+    MouseEventKind,
+};
+use crossterm::execute;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Layout, Offset, Rect};
-use ratatui::style::Stylize;
+use ratatui::style::Style;
 use ratatui::text::Line;
-use ratatui::widgets::Widget;
+use ratatui::widgets::{FocusManager, Widget};
 use ratatui::{DefaultTerminal, Frame};
 use serde::Serialize;
 
 fn main() -> Result<()> {
     color_eyre::install()?;
     // serialize the form to JSON if the user submitted it, otherwise print "Canceled"
-    match ratatui::run(|terminal| App::default().run(terminal)) {
+    execute!(std::io::stdout(), EnableMouseCapture)?;
+    let result = ratatui::run(|terminal| App::default().run(terminal));
+    execute!(std::io::stdout(), DisableMouseCapture)?;
+    match result {
         Ok(Some(form)) => println!("{}", serde_json::to_string_pretty(&form)?),
         Ok(None) => println!("Canceled"),
         Err(err) => eprintln!("{err}"),
@@ -62,17 +71,21 @@ impl App {
         }
     }
 
-    fn render(&self, frame: &mut Frame) {
+    fn render(&mut self, frame: &mut Frame) {
         self.form.render(frame);
     }
 
     fn handle_events(&mut self) -> Result<()> {
-        if let Some(key) = event::read()?.as_key_press_event() {
-            match key.code {
+        match event::read()? {
+            Event::Key(key) if key.is_press() => match key.code {
                 KeyCode::Esc => self.state = AppState::Cancelled,
                 KeyCode::Enter => self.state = AppState::Submitted,
                 _ => self.form.on_key_press(key),
+            },
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                self.form.focus.focus_at((mouse.column, mouse.row));
             }
+            _ => {}
         }
         Ok(())
     }
@@ -81,7 +94,7 @@ impl App {
 #[derive(Serialize)]
 struct InputForm {
     #[serde(skip)]
-    focus: Focus,
+    focus: FocusManager<Field>,
     first_name: StringField,
     last_name: StringField,
     age: AgeField,
@@ -90,7 +103,8 @@ struct InputForm {
 impl Default for InputForm {
     fn default() -> Self {
         Self {
-            focus: Focus::FirstName,
+            focus: FocusManager::from_iter([Field::FirstName, Field::LastName, Field::Age])
+                .focused_style(Style::new().reversed()),
             first_name: StringField::new("First Name"),
             last_name: StringField::new("Last Name"),
             age: AgeField::new("Age"),
@@ -102,54 +116,63 @@ impl InputForm {
     // Handle focus navigation or pass the event to the focused field.
     fn on_key_press(&mut self, event: KeyEvent) {
         match event.code {
-            KeyCode::Tab => self.focus = self.focus.next(),
-            _ => match self.focus {
-                Focus::FirstName => self.first_name.on_key_press(event),
-                Focus::LastName => self.last_name.on_key_press(event),
-                Focus::Age => self.age.on_key_press(event),
+            KeyCode::Tab => {
+                self.focus.focus_next();
+            }
+            KeyCode::BackTab => {
+                self.focus.focus_prev();
+            }
+            _ => match self.focus.focused() {
+                Some(Field::FirstName) => self.first_name.on_key_press(event),
+                Some(Field::LastName) => self.last_name.on_key_press(event),
+                Some(Field::Age) => self.age.on_key_press(event),
+                None => {}
             },
         }
     }
 
     /// Render the form with the current focus.
     ///
-    /// The cursor is placed at the end of the focused field.
-    fn render(&self, frame: &mut Frame) {
+    /// The label of the focused field is highlighted and the cursor is placed at the end of the
+    /// focused field. The areas of the fields are stored in the focus manager so that fields can
+    /// be focused with the mouse.
+    fn render(&mut self, frame: &mut Frame) {
         let layout = Layout::vertical(Constraint::from_lengths([1, 1, 1]));
         let [first_name_area, last_name_area, age_area] = frame.area().layout(&layout);
 
-        frame.render_widget(&self.first_name, first_name_area);
-        frame.render_widget(&self.last_name, last_name_area);
-        frame.render_widget(&self.age, age_area);
+        self.focus.set_area(&Field::FirstName, first_name_area);
+        self.focus.set_area(&Field::LastName, last_name_area);
+        self.focus.set_area(&Field::Age, age_area);
 
-        let cursor_position = match self.focus {
-            Focus::FirstName => first_name_area + self.first_name.cursor_offset(),
-            Focus::LastName => last_name_area + self.last_name.cursor_offset(),
-            Focus::Age => age_area + self.age.cursor_offset(),
+        let label_style = |field| self.focus.style_for(&field, Style::new().bold());
+        frame.render_widget(
+            self.first_name.widget(label_style(Field::FirstName)),
+            first_name_area,
+        );
+        frame.render_widget(
+            self.last_name.widget(label_style(Field::LastName)),
+            last_name_area,
+        );
+        frame.render_widget(self.age.widget(label_style(Field::Age)), age_area);
+
+        let cursor_position = match self.focus.focused() {
+            Some(Field::FirstName) => first_name_area + self.first_name.cursor_offset(),
+            Some(Field::LastName) => last_name_area + self.last_name.cursor_offset(),
+            Some(Field::Age) => age_area + self.age.cursor_offset(),
+            None => return,
         };
         frame.set_cursor_position(cursor_position);
     }
 }
 
-#[derive(Default, PartialEq, Eq)]
-enum Focus {
-    #[default]
+/// The fields of the form, in focus order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
     FirstName,
     LastName,
     Age,
 }
 
-impl Focus {
-    // Round-robin focus order.
-    const fn next(&self) -> Self {
-        match self {
-            Self::FirstName => Self::LastName,
-            Self::LastName => Self::Age,
-            Self::Age => Self::FirstName,
-        }
-    }
-}
-
 /// A new-type representing a string field with a label.
 #[derive(Debug, Serialize)]
 struct StringField {
@@ -181,18 +204,13 @@ impl StringField {
         let x = (self.label.len() + self.value.len() + 2) as i32;
         Offset::new(x, 0)
     }
-}
 
-impl Widget for &StringField {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let layout = Layout::horizontal([
-            Constraint::Length(self.label.len() as u16 + 2),
-            Constraint::Fill(1),
-        ]);
-        let [label_area, value_area] = area.layout(&layout);
-        let label = Line::from_iter([self.label, ": "]).bold();
-        label.render(label_area, buf);
-        self.value.clone().render(value_area, buf);
+    fn widget(&self, label_style: Style) -> FieldWidget<'_> {
+        FieldWidget {
+            label: self.label,
+            value: self.value.clone(),
+            label_style,
+        }
     }
 }
 
@@ -246,18 +264,32 @@ impl AgeField {
         let x = (self.label.len() + self.value.to_string().len() + 2) as i32;
         Offset::new(x, 0)
     }
+
+    fn widget(&self, label_style: Style) -> FieldWidget<'_> {
+        FieldWidget {
+            label: self.label,
+            value: self.value.to_string(),
+            label_style,
+        }
+    }
+}
+
+/// Renders a field as its label followed by its value.
+struct FieldWidget<'a> {
+    label: &'a str,
+    value: String,
+    label_style: Style,
 }
 
-impl Widget for &AgeField {
+impl Widget for FieldWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let layout = Layout::horizontal([
             Constraint::Length(self.label.len() as u16 + 2),
             Constraint::Fill(1),
         ]);
         let [label_area, value_area] = area.layout(&layout);
-        let label = Line::from_iter([self.label, ": "]).bold();
-        let value = self.value.to_string();
+        let label = Line::from_iter([self.label, ": "]).style(self.label_style);
         label.render(label_area, buf);
-        value.render(value_area, buf);
+        self.value.render(value_area, buf);
     }
 }
@@ -62,7 +62,6 @@ fn vertical_bar(hour: usize, temperature: &u8) -> Bar<'_> {
 
 /// create a yellow to red value based on the value (50-90)
 fn temperature_style(value: u8) -> Style {
-    let green = (255.0 * (1.0 - f64::from(value - 50) / 40.0)) as u8;
-    let color = Color::Rgb(255, green, 0);
-    Style::new().fg(color)
+    let t = f32::from(value.saturating_sub(50)) / 40.0;
+    Style::new().fg(Color::Rgb(255, 255, 0).lerp(Color::Rgb(255, 0, 0), t))
 }
@@ -1,23 +1,23 @@
 //! An example of how to use [`WidgetRef`] to store heterogeneous widgets in a container.
 //!
-//! This example creates a `StackContainer` widget that can hold any number of widgets of
-//! different types. It creates two widgets, `Greeting` and `Farewell`, and stores them in a
-//! `StackContainer` with a vertical layout. The `StackContainer` widget renders each of its
-//! child widgets in the order they were added.
+//! This example uses the [`Stack`] widget, which can hold any number of widgets of different types.
+//! It creates two widgets, `Greeting` and `Farewell`, and stores them in a [`Stack`] with a
+//! vertical layout. The [`Stack`] widget renders each of its child widgets in the order they were
+//! added.
 //!
 //! This example runs with the Ratatui library code in the branch that you are currently
 //! reading. See the [`latest`] branch for the code which works with the most recent Ratatui
 //! release.
 //!
 //! [`latest`]: https://github.com/ratatui/ratatui/tree/latest
-use std::iter::zip;
-
+//! [`WidgetRef`]: ratatui::widgets::WidgetRef
+//! [`Stack`]: ratatui::widgets::Stack
 use color_eyre::Result;
 use crossterm::event;
 use ratatui::Frame;
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::widgets::{Block, Paragraph, Widget, WidgetRef};
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Block, Paragraph, Stack, Widget};
 
 fn main() -> Result<()> {
     color_eyre::install()?;
@@ -32,14 +32,10 @@ fn main() -> Result<()> {
 }
 
 fn render(frame: &mut Frame) {
-    let container = StackContainer {
-        direction: Direction::Vertical,
-        widgets: vec![
-            (Box::new(&Greeting), Constraint::Percentage(50)),
-            (Box::new(&Farewell), Constraint::Percentage(50)),
-        ],
-    };
-    frame.render_widget(&container, frame.area());
+    let stack = Stack::vertical()
+        .child(&Greeting, Constraint::Percentage(50))
+        .child(&Farewell, Constraint::Percentage(50));
+    frame.render_widget(stack, frame.area());
 }
 
 struct Greeting;
@@ -61,21 +57,3 @@ impl Widget for &Farewell {
             .render(area, buf);
     }
 }
-
-struct StackContainer {
-    direction: Direction,
-    widgets: Vec<(Box<dyn WidgetRef>, Constraint)>,
-}
-
-impl Widget for &StackContainer {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let layout = Layout::default()
-            .direction(self.direction)
-            .constraints(self.widgets.iter().map(|(_, constraint)| *constraint))
-            .split(area);
-        let widgets = self.widgets.iter().map(|(widget, _)| widget);
-        for (widget, area) in zip(widgets, layout.iter()) {
-            widget.render_ref(*area, buf);
-        }
-    }
-}
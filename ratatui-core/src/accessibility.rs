@@ -0,0 +1,399 @@
+//! Semantic descriptions of the user interface for screen readers and braille displays.
+//!
+//! A terminal user interface is drawn as a grid of characters, which assistive technologies can
+//! only read back as rows of text without knowing that e.g. a row is the selected item of a list
+//! or that a bar of block characters is a progress bar at 42%. This module lets the application
+//! and widgets describe what they draw:
+//!
+//! - a [`SemanticNode`] describes one element with a [`Role`], a label, an optional value and
+//!   whether it is selected
+//! - widgets implement [`Accessible`] or [`StatefulAccessible`] to create the nodes that describe
+//!   them
+//! - the nodes of a frame are registered with [`Frame::describe`], [`Frame::describe_widget`] and
+//!   [`Frame::describe_stateful_widget`] and collected in [`Semantics`]
+//! - after each frame whose description changed, the [`Terminal`] passes the new and previous
+//!   descriptions to [`Backend::update_semantics`]
+//!
+//! A backend (or a wrapper around one) exports the description in whatever form the assistive
+//! technology needs, e.g. the linearized text of [`Semantics`]'s `Display` implementation, or only
+//! the nodes returned by [`Semantics::changes`] to announce what changed.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui_core::accessibility::{Role, SemanticNode};
+//! use ratatui_core::backend::TestBackend;
+//! use ratatui_core::layout::Rect;
+//! use ratatui_core::terminal::Terminal;
+//!
+//! let mut terminal = Terminal::new(TestBackend::new(20, 2))?;
+//! terminal.draw(|frame| {
+//!     let area = Rect::new(0, 0, 20, 1);
+//!     frame.render_widget("Downloads", area);
+//!     frame.describe(SemanticNode::new(Role::Heading, area).label("Downloads"));
+//!     frame.describe(
+//!         SemanticNode::new(Role::ProgressBar, Rect::new(0, 1, 20, 1))
+//!             .label("file.zip")
+//!             .value("42%"),
+//!     );
+//! })?;
+//! assert_eq!(
+//!     terminal.backend().semantics().to_string(),
+//!     "heading: Downloads\nprogress bar: file.zip, 42%\n"
+//! );
+//! # Ok::<(), core::convert::Infallible>(())
+//! ```
+//!
+//! [`Backend::update_semantics`]: crate::backend::Backend::update_semantics
+//! [`Frame::describe`]: crate::terminal::Frame::describe
+//! [`Frame::describe_widget`]: crate::terminal::Frame::describe_widget
+//! [`Frame::describe_stateful_widget`]: crate::terminal::Frame::describe_stateful_widget
+//! [`Terminal`]: crate::terminal::Terminal
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use strum::Display;
+
+use crate::layout::Rect;
+
+/// The kind of element that a [`SemanticNode`] describes.
+///
+/// The roles are named after the matching ARIA roles, and are formatted as lower case words (e.g.
+/// `list item`) in the linearized text.
+#[derive(Debug, Default, Display, Clone, Copy, Eq, PartialEq, Hash)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum Role {
+    /// A generic section of the user interface, such as a bordered block.
+    #[default]
+    Region,
+    /// A heading or title.
+    Heading,
+    /// Static text.
+    Text,
+    /// A button that can be activated.
+    Button,
+    /// A checkbox that is checked (selected) or not.
+    #[strum(to_string = "check box")]
+    CheckBox,
+    /// A list of items.
+    List,
+    /// An item of a list.
+    #[strum(to_string = "list item")]
+    ListItem,
+    /// A table of rows.
+    Table,
+    /// A row of a table.
+    Row,
+    /// A set of tabs.
+    #[strum(to_string = "tab list")]
+    TabList,
+    /// A tab, which is selected if it is the current tab.
+    Tab,
+    /// A progress bar or gauge, whose value is the progress.
+    #[strum(to_string = "progress bar")]
+    ProgressBar,
+    /// An editable text field, whose value is the text.
+    #[strum(to_string = "text input")]
+    TextInput,
+    /// A message that should be announced, such as a notification.
+    Status,
+}
+
+/// The description of a single element of the user interface.
+///
+/// A node has a [`Role`], the area it is drawn in, and optionally a label, a value, a selection
+/// state and a nesting level. The level is used to indent nodes that belong to the previous node of
+/// a lower level, such as the items of a list.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::accessibility::{Role, SemanticNode};
+/// use ratatui_core::layout::Rect;
+///
+/// let node = SemanticNode::new(Role::ListItem, Rect::new(0, 1, 10, 1))
+///     .label("Inbox")
+///     .value("3 unread")
+///     .selected(true)
+///     .level(1);
+/// assert_eq!(node.to_string(), "  list item: Inbox, 3 unread, selected");
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct SemanticNode {
+    /// The kind of element.
+    pub role: Role,
+    /// The area the element is drawn in.
+    pub area: Rect,
+    /// The name of the element, such as the title of a block or the text of an item.
+    pub label: String,
+    /// The current state of the element, such as `42%` for a progress bar.
+    pub value: Option<String>,
+    /// Whether the element is selected, or `None` if it can't be selected.
+    pub selected: Option<bool>,
+    /// The nesting level, which is 0 for top level elements and 1 for their children.
+    pub level: u16,
+}
+
+impl SemanticNode {
+    /// Creates a node with the given role that is drawn in `area`.
+    pub const fn new(role: Role, area: Rect) -> Self {
+        Self {
+            role,
+            area,
+            label: String::new(),
+            value: None,
+            selected: None,
+            level: 0,
+        }
+    }
+
+    /// Sets the label, which names the element (e.g. the title of a block or the text of an item).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T: Into<String>>(mut self, label: T) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Sets the value, which is the current state of the element (e.g. `42%` for a progress bar).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value<T: Into<String>>(mut self, value: T) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets whether the element is selected (e.g. the selected item of a list or the current tab).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn selected(mut self, selected: bool) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    /// Sets the nesting level, which is 0 for top level elements and 1 for their children.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn level(mut self, level: u16) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Returns whether the nodes describe the same content, ignoring where they are drawn.
+    fn same_content(&self, other: &Self) -> bool {
+        self.role == other.role
+            && self.label == other.label
+            && self.value == other.value
+            && self.selected == other.selected
+            && self.level == other.level
+    }
+}
+
+/// Formats the node as a line of linearized text, e.g. `  list item: Inbox, 3 unread, selected`.
+///
+/// The line is indented by two spaces per nesting level and lists the role, the label, the value,
+/// and `selected` if the element is selected.
+impl fmt::Display for SemanticNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for _ in 0..self.level {
+            f.write_str("  ")?;
+        }
+        write!(f, "{}", self.role)?;
+        let mut separator = ": ";
+        for part in [Some(self.label.as_str()), self.value.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.is_empty())
+        {
+            write!(f, "{separator}{part}")?;
+            separator = ", ";
+        }
+        if self.selected == Some(true) {
+            write!(f, "{separator}selected")?;
+        }
+        Ok(())
+    }
+}
+
+/// The semantic description of a frame: the [`SemanticNode`]s in the order they were registered.
+///
+/// The `Display` implementation linearizes the description into one line of text per node, which
+/// is suitable for screen readers and braille displays.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Semantics {
+    nodes: Vec<SemanticNode>,
+}
+
+impl Semantics {
+    /// Creates an empty description.
+    pub const fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    /// Adds a node to the end of the description.
+    pub fn push(&mut self, node: SemanticNode) {
+        self.nodes.push(node);
+    }
+
+    /// Returns the nodes of the description.
+    pub fn nodes(&self) -> &[SemanticNode] {
+        &self.nodes
+    }
+
+    /// Returns whether the description has no nodes.
+    pub const fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Removes all nodes.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    /// Returns the nodes whose content is not part of the `previous` description.
+    ///
+    /// Nodes that only moved to a different area are not returned. This is useful to announce only
+    /// what changed since the previous frame, such as a newly selected item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::accessibility::{Role, SemanticNode, Semantics};
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let item = |label, selected| {
+    ///     SemanticNode::new(Role::ListItem, Rect::default())
+    ///         .label(label)
+    ///         .selected(selected)
+    /// };
+    /// let previous = Semantics::from_iter([item("a", true), item("b", false)]);
+    /// let current = Semantics::from_iter([item("a", false), item("b", true)]);
+    /// let changes: Vec<_> = current
+    ///     .changes(&previous)
+    ///     .map(ToString::to_string)
+    ///     .collect();
+    /// assert_eq!(changes, ["list item: a", "list item: b, selected"]);
+    /// ```
+    pub fn changes<'a>(&'a self, previous: &'a Self) -> impl Iterator<Item = &'a SemanticNode> {
+        self.nodes.iter().filter(|node| {
+            !previous
+                .nodes
+                .iter()
+                .any(|previous| previous.same_content(node))
+        })
+    }
+}
+
+impl FromIterator<SemanticNode> for Semantics {
+    fn from_iter<T: IntoIterator<Item = SemanticNode>>(iter: T) -> Self {
+        Self {
+            nodes: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<SemanticNode> for Semantics {
+    fn extend<T: IntoIterator<Item = SemanticNode>>(&mut self, iter: T) {
+        self.nodes.extend(iter);
+    }
+}
+
+/// Formats the description as linearized text, with one line per node.
+impl fmt::Display for Semantics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in &self.nodes {
+            writeln!(f, "{node}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A widget that can describe itself for assistive technologies.
+///
+/// The description is added to `semantics` as one or more [`SemanticNode`]s, e.g. a node with the
+/// [`Role::List`] role followed by a node of level 1 for each item. It is registered with
+/// [`Frame::describe_widget`], usually right before or after rendering the widget to the same area.
+///
+/// [`Frame::describe_widget`]: crate::terminal::Frame::describe_widget
+pub trait Accessible {
+    /// Adds the description of the widget drawn in `area` to `semantics`.
+    fn describe(&self, area: Rect, semantics: &mut Semantics);
+}
+
+/// A stateful widget that can describe itself for assistive technologies.
+///
+/// This is the equivalent of [`Accessible`] for widgets whose description depends on their state,
+/// such as the selected item of a list. It is registered with
+/// [`Frame::describe_stateful_widget`].
+///
+/// [`Frame::describe_stateful_widget`]: crate::terminal::Frame::describe_stateful_widget
+pub trait StatefulAccessible {
+    /// The state of the widget.
+    type State: ?Sized;
+
+    /// Adds the description of the widget drawn in `area` with `state` to `semantics`.
+    fn describe(&self, area: Rect, state: &Self::State, semantics: &mut Semantics);
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn node_display() {
+        let area = Rect::new(0, 0, 5, 1);
+        assert_eq!(SemanticNode::new(Role::List, area).to_string(), "list");
+        assert_eq!(
+            SemanticNode::new(Role::CheckBox, area)
+                .label("Wrap")
+                .selected(false)
+                .to_string(),
+            "check box: Wrap"
+        );
+        assert_eq!(
+            SemanticNode::new(Role::TextInput, area)
+                .value("hello")
+                .level(2)
+                .to_string(),
+            "    text input: hello"
+        );
+    }
+
+    #[test]
+    fn linearized_text() {
+        let area = Rect::new(0, 0, 5, 1);
+        let semantics = Semantics::from_iter([
+            SemanticNode::new(Role::TabList, area),
+            SemanticNode::new(Role::Tab, area)
+                .label("One")
+                .selected(true)
+                .level(1),
+            SemanticNode::new(Role::Tab, area)
+                .label("Two")
+                .selected(false)
+                .level(1),
+        ]);
+        assert_eq!(
+            semantics.to_string(),
+            "tab list\n  tab: One, selected\n  tab: Two\n"
+        );
+    }
+
+    #[test]
+    fn changes_ignore_moved_nodes() {
+        let text = |label, y| SemanticNode::new(Role::Text, Rect::new(0, y, 5, 1)).label(label);
+        let previous = Semantics::from_iter([text("a", 0), text("b", 1)]);
+        let current = Semantics::from_iter([text("b", 0), text("c", 1)]);
+        let changes: Vec<_> = current.changes(&previous).collect();
+        assert_eq!(changes, [&text("c", 1)]);
+    }
+}
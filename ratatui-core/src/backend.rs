@@ -10,7 +10,15 @@
 //! - [Termina]: enable the `termina` feature and use [`TerminaBackend`]
 //! - [Termwiz]: enable the `termwiz` feature and use [`TermwizBackend`]
 //!
-//! Additionally, a [`TestBackend`] is provided for testing purposes.
+//! To render to a terminal emulator that isn't driven by a terminal library, such as [xterm.js] in
+//! a web page, implement [`AnsiTarget`] for it and use [`AnsiBackend`]. To render to a character
+//! framebuffer in memory, such as the text mode of an embedded device without an OS, use
+//! [`FramebufferBackend`]. To render to an asynchronous writer, such as the channel of an SSH
+//! server, enable the `async` feature and use `AsyncWriteBackend`.
+//!
+//! Additionally, a [`TestBackend`] is provided for testing purposes, and an `AsciicastBackend`
+//! (enable the `asciicast` feature) records the frames drawn to another backend as an [asciinema]
+//! recording.
 //!
 //! See the [Backend Comparison] section of the [Ratatui Website] for more details on the different
 //! backends.
@@ -100,18 +108,39 @@
 //! [Termion]: https://crates.io/crates/termion
 //! [Termina]: https://crates.io/crates/termina
 //! [Termwiz]: https://crates.io/crates/termwiz
+//! [asciinema]: https://asciinema.org
+//! [xterm.js]: https://xtermjs.org
 //! [Examples]: https://github.com/ratatui/ratatui/tree/main/ratatui/examples/README.md
 //! [Backend Comparison]: https://ratatui.rs/concepts/backends/comparison/
 //! [Ratatui Website]: https://ratatui.rs
 
 use strum::{Display, EnumString};
 
-use crate::buffer::Cell;
+use crate::accessibility::Semantics;
+use crate::buffer::{Cell, LineAttribute};
 use crate::layout::{Position, Size};
+use crate::style::ColorSupport;
 
 mod test;
 pub use self::test::TestBackend;
 
+mod ansi;
+pub(crate) use self::ansi::AnsiWriter;
+pub use self::ansi::{AnsiBackend, AnsiTarget};
+
+mod framebuffer;
+pub use self::framebuffer::{FramebufferBackend, FramebufferCell};
+
+#[cfg(feature = "async")]
+mod async_write;
+#[cfg(feature = "async")]
+pub use self::async_write::AsyncWriteBackend;
+
+#[cfg(feature = "asciicast")]
+mod asciicast;
+#[cfg(feature = "asciicast")]
+pub use self::asciicast::{AsciicastBackend, AsciicastError};
+
 /// Defines which region of the terminal's visible display area is cleared.
 ///
 /// Clearing operates on character cells in the active display surface. It does not move, hide, or
@@ -138,7 +167,11 @@ pub enum ClearType {
 }
 
 /// The window size in characters (columns / rows) as well as pixels.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+///
+/// This struct is marked `#[non_exhaustive]` so that fields can be added without breaking
+/// changes. Backends create it with [`WindowSize::new`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub struct WindowSize {
     /// Size of the window in characters (columns / rows).
     pub columns_rows: Size,
@@ -150,6 +183,18 @@ pub struct WindowSize {
     pub pixels: Size,
 }
 
+impl WindowSize {
+    /// Creates a new `WindowSize` from the size in characters and in pixels.
+    ///
+    /// Use [`Size::ZERO`] for `pixels` if the backend can't determine the size in pixels.
+    pub const fn new(columns_rows: Size, pixels: Size) -> Self {
+        Self {
+            columns_rows,
+            pixels,
+        }
+    }
+}
+
 /// The `Backend` trait provides an abstraction over different terminal libraries. It defines the
 /// methods required to draw content, manipulate the cursor, and clear the terminal screen.
 ///
@@ -177,6 +222,100 @@ pub trait Backend {
         Ok(())
     }
 
+    /// Set the [`LineAttribute`] (e.g. double-width or double-height) of the row at `y`.
+    ///
+    /// This is called by the [`Terminal`] when the line attribute of a row changes between frames.
+    /// Backends may move the cursor to the start of the row, which the [`Terminal`] records as the
+    /// last known cursor position.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation does nothing, which renders all rows at single width.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    fn set_line_attribute(
+        &mut self,
+        _y: u16,
+        _attribute: LineAttribute,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Starts a synchronized update, during which the terminal doesn't display the changes.
+    ///
+    /// The [`Terminal`] calls this before drawing a frame when synchronized output is enabled (see
+    /// [`Terminal::set_synchronized_output`]), and calls
+    /// [`end_synchronized_update`](Self::end_synchronized_update) after the frame is drawn, so
+    /// that the terminal displays the whole frame at once instead of showing partially drawn
+    /// frames. Terminals implement this with the `CSI ? 2026 h` and `CSI ? 2026 l` sequences
+    /// (synchronized output mode), which terminals that don't support it ignore.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation does nothing.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    /// [`Terminal::set_synchronized_output`]: crate::terminal::Terminal::set_synchronized_output
+    fn begin_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Ends a synchronized update started with
+    /// [`begin_synchronized_update`](Self::begin_synchronized_update), which displays the
+    /// changes made during the update.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation does nothing.
+    fn end_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Exports the semantic description of a frame to assistive technologies.
+    ///
+    /// The [`Terminal`] calls this after a frame is rendered, with the description the frame
+    /// registered (see [`Frame::describe`]) and the description of the previous frame, when the
+    /// two differ. Backends for screen readers or braille displays can output the linearized text
+    /// of `semantics`, or only announce the nodes returned by [`Semantics::changes`].
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation does nothing.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    /// [`Frame::describe`]: crate::terminal::Frame::describe
+    fn update_semantics(
+        &mut self,
+        _semantics: &Semantics,
+        _previous: &Semantics,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Returns the colors supported by the terminal.
+    ///
+    /// Pass this to [`Terminal::set_color_support`] to convert colors that the terminal can't
+    /// display to the nearest supported colors.
+    ///
+    /// This method is optional. The default implementation returns [`ColorSupport::TrueColor`].
+    /// Backends for real terminals usually detect the color support from the environment using
+    /// [`ColorSupport::detect`].
+    ///
+    /// [`Terminal::set_color_support`]: crate::terminal::Terminal::set_color_support
+    fn color_support(&self) -> ColorSupport {
+        ColorSupport::TrueColor
+    }
+
+    /// Returns the number of bytes written to the terminal since the backend was created, or
+    /// `None` if the backend doesn't count them.
+    ///
+    /// This is used to report the bytes written for each frame in the [`FrameStats`] passed to
+    /// [`Terminal::on_frame`].
+    ///
+    /// This method is optional. The default implementation returns `None`.
+    ///
+    /// [`FrameStats`]: crate::terminal::FrameStats
+    /// [`Terminal::on_frame`]: crate::terminal::Terminal::on_frame
+    fn bytes_written(&self) -> Option<u64> {
+        None
+    }
+
     /// Hide the cursor on the terminal screen.
     ///
     ///
@@ -0,0 +1,571 @@
+use alloc::string::String;
+use core::fmt::Write as _;
+use core::time::Duration;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::backend::{Backend, ClearType, WindowSize};
+use crate::buffer::{Cell, LineAttribute};
+use crate::layout::{Position, Size};
+use crate::style::{Color, ColorSupport, Modifier};
+
+/// A target that displays ANSI escape sequences, such as a terminal emulator running in a web
+/// page.
+///
+/// This is the hook to connect an [`AnsiBackend`] to a terminal emulator that is not driven by a
+/// terminal library, e.g. [xterm.js] when running under `wasm32-unknown-unknown`. It only needs
+/// alloc, so it can be implemented in `no_std` environments.
+///
+/// [xterm.js]: https://xtermjs.org
+pub trait AnsiTarget {
+    /// Error type associated with this target.
+    type Error: core::error::Error;
+
+    /// Writes the ANSI escape sequences and text of a frame to the target.
+    ///
+    /// This is called once per [`Backend::flush`] with all output since the previous call, so the
+    /// target can pass it to the terminal emulator in a single call (e.g. `Terminal.write` in
+    /// xterm.js).
+    fn write(&mut self, ansi: &str) -> Result<(), Self::Error>;
+
+    /// Returns the size of the terminal emulator in columns and rows.
+    fn size(&self) -> Result<Size, Self::Error>;
+
+    /// Returns the size of the terminal emulator in columns/rows and pixels.
+    ///
+    /// The default implementation returns a pixel size of zero.
+    fn window_size(&mut self) -> Result<WindowSize, Self::Error> {
+        Ok(WindowSize {
+            columns_rows: self.size()?,
+            pixels: Size::ZERO,
+        })
+    }
+
+    /// Returns the time elapsed since a fixed point in the past, or `None` if the target has no
+    /// clock.
+    ///
+    /// This gives applications a monotonic clock without `std::time`, e.g. from
+    /// `performance.now()` in a web page. The default implementation returns `None`.
+    fn now(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// A [`Backend`] that renders to an [`AnsiTarget`] using ANSI escape sequences.
+///
+/// This makes it possible to run applications in terminal emulators that are not driven by a
+/// terminal library, such as [xterm.js] in a web page, by implementing [`AnsiTarget`] for the
+/// emulator. The output of each frame is collected and passed to [`AnsiTarget::write`] when the
+/// backend is flushed. Unlike the other backends, this backend only needs alloc and works in
+/// `no_std` environments.
+///
+/// As the cursor position can't be queried from the target, the backend keeps track of the
+/// position that was last set.
+///
+/// Underline styles are drawn as plain underlines.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::{AnsiBackend, AnsiTarget};
+/// use ratatui_core::layout::Size;
+/// use ratatui_core::terminal::Terminal;
+///
+/// /// A stand-in for a handle to an xterm.js terminal.
+/// struct XtermJs {
+///     written: String,
+/// }
+///
+/// impl AnsiTarget for XtermJs {
+///     type Error = core::convert::Infallible;
+///
+///     fn write(&mut self, ansi: &str) -> Result<(), Self::Error> {
+///         self.written.push_str(ansi);
+///         Ok(())
+///     }
+///
+///     fn size(&self) -> Result<Size, Self::Error> {
+///         Ok(Size::new(20, 2))
+///     }
+/// }
+///
+/// let backend = AnsiBackend::new(XtermJs {
+///     written: String::new(),
+/// });
+/// let mut terminal = Terminal::new(backend)?;
+/// terminal.draw(|frame| frame.render_widget("Hello", frame.area()))?;
+/// assert!(terminal.backend().target().written.contains("Hello"));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [xterm.js]: https://xtermjs.org
+#[derive(Debug)]
+pub struct AnsiBackend<T> {
+    target: T,
+    ansi: AnsiWriter,
+    color_support: ColorSupport,
+    cursor_position: Position,
+}
+
+impl<T: AnsiTarget> AnsiBackend<T> {
+    /// Creates a backend that renders to `target`.
+    pub fn new(target: T) -> Self {
+        Self {
+            target,
+            ansi: AnsiWriter::default(),
+            color_support: ColorSupport::TrueColor,
+            cursor_position: Position::ORIGIN,
+        }
+    }
+
+    /// Sets the colors the target can display.
+    ///
+    /// Defaults to [`ColorSupport::TrueColor`], which xterm.js supports.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = color_support;
+        self
+    }
+
+    /// Returns a reference to the target.
+    pub const fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Returns a mutable reference to the target.
+    pub const fn target_mut(&mut self) -> &mut T {
+        &mut self.target
+    }
+
+    /// Returns the time elapsed since a fixed point in the past, if the target has a clock.
+    ///
+    /// See [`AnsiTarget::now`].
+    pub fn now(&self) -> Option<Duration> {
+        self.target.now()
+    }
+
+    /// Consumes the backend, returning the target.
+    pub fn into_target(self) -> T {
+        self.target
+    }
+}
+
+impl<T: AnsiTarget> Backend for AnsiBackend<T> {
+    type Error = T::Error;
+
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), Self::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        for (x, y, cell) in content {
+            self.ansi.draw_cell(x, y, cell);
+        }
+        self.ansi.reset_style();
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+
+    fn append_lines(&mut self, n: u16) -> Result<(), Self::Error> {
+        self.ansi.append_lines(n);
+        Ok(())
+    }
+
+    fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) -> Result<(), Self::Error> {
+        self.ansi.set_line_attribute(y, attribute);
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+
+    fn begin_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        self.ansi.begin_synchronized_update();
+        Ok(())
+    }
+
+    fn end_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        self.ansi.end_synchronized_update();
+        Ok(())
+    }
+
+    fn color_support(&self) -> ColorSupport {
+        self.color_support
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.ansi.hide_cursor();
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.ansi.show_cursor();
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> Result<Position, Self::Error> {
+        Ok(self.cursor_position)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> Result<(), Self::Error> {
+        self.cursor_position = position.into();
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.ansi.clear_region(ClearType::All);
+        Ok(())
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> Result<(), Self::Error> {
+        self.ansi.clear_region(clear_type);
+        Ok(())
+    }
+
+    fn size(&self) -> Result<Size, Self::Error> {
+        self.target.size()
+    }
+
+    fn window_size(&mut self) -> Result<WindowSize, Self::Error> {
+        self.target.window_size()
+    }
+
+    fn bytes_written(&self) -> Option<u64> {
+        Some(self.ansi.bytes_written())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        if !self.ansi.output.is_empty() {
+            self.target.write(&self.ansi.output)?;
+            self.ansi.clear_output();
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_up(
+        &mut self,
+        region: core::ops::Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        self.ansi.scroll_region(&region, line_count, 'S');
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_down(
+        &mut self,
+        region: core::ops::Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        self.ansi.scroll_region(&region, line_count, 'T');
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+}
+
+/// The style of a cell that is set with SGR escape sequences.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct SgrStyle {
+    fg: Color,
+    bg: Color,
+    #[cfg(feature = "underline-color")]
+    underline_color: Color,
+    modifier: Modifier,
+}
+
+impl SgrStyle {
+    const fn of(cell: &Cell) -> Self {
+        Self {
+            fg: cell.fg,
+            bg: cell.bg,
+            #[cfg(feature = "underline-color")]
+            underline_color: cell.underline_color,
+            modifier: cell.modifier,
+        }
+    }
+}
+
+/// Builds the ANSI escape sequences that draw to a terminal, for backends that don't write to a
+/// terminal library.
+#[derive(Debug, Default)]
+pub(crate) struct AnsiWriter {
+    pub(crate) output: String,
+    /// The number of bytes of output that were cleared after being written.
+    written: u64,
+    /// The position of the cursor, or `None` if it is unknown.
+    cursor: Option<Position>,
+    style: SgrStyle,
+}
+
+impl AnsiWriter {
+    /// Clears the output after it was written to the target.
+    pub(crate) fn clear_output(&mut self) {
+        self.written += self.output.len() as u64;
+        self.output.clear();
+    }
+
+    /// Returns the number of bytes of output produced so far, including the pending output.
+    pub(crate) const fn bytes_written(&self) -> u64 {
+        self.written + self.output.len() as u64
+    }
+
+    pub(crate) fn move_to(&mut self, position: Position) {
+        if self.cursor != Some(position) {
+            let _ = write!(self.output, "\x1b[{};{}H", position.y + 1, position.x + 1);
+            self.cursor = Some(position);
+        }
+    }
+
+    pub(crate) fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) {
+        self.move_to(Position::new(x, y));
+        self.write_cell(cell);
+        let width = u16::try_from(cell.symbol().width()).unwrap_or(u16::MAX);
+        self.cursor = Some(Position::new(x.saturating_add(width), y));
+    }
+
+    /// Writes the symbol of the cell with its style, at the current position of the cursor.
+    pub(crate) fn write_cell(&mut self, cell: &Cell) {
+        let style = SgrStyle::of(cell);
+        if style != self.style {
+            self.set_style(style);
+        }
+        self.output.push_str(cell.symbol());
+    }
+
+    pub(crate) fn reset_style(&mut self) {
+        if self.style != SgrStyle::default() {
+            self.output.push_str("\x1b[0m");
+            self.style = SgrStyle::default();
+        }
+    }
+
+    /// Resets the style and sets all parts of `style` that differ from the default.
+    fn set_style(&mut self, style: SgrStyle) {
+        self.output.push_str("\x1b[0");
+        for (modifier, code) in [
+            (Modifier::BOLD, "1"),
+            (Modifier::DIM, "2"),
+            (Modifier::ITALIC, "3"),
+            (Modifier::UNDERLINED, "4"),
+            (Modifier::SLOW_BLINK, "5"),
+            (Modifier::RAPID_BLINK, "6"),
+            (Modifier::REVERSED, "7"),
+            (Modifier::HIDDEN, "8"),
+            (Modifier::CROSSED_OUT, "9"),
+            (Modifier::OVERLINED, "53"),
+        ] {
+            if style.modifier.contains(modifier) {
+                self.output.push(';');
+                self.output.push_str(code);
+            }
+        }
+        self.push_color(style.fg, 30);
+        self.push_color(style.bg, 40);
+        #[cfg(feature = "underline-color")]
+        self.push_color(style.underline_color, 50);
+        self.output.push('m');
+        self.style = style;
+    }
+
+    /// Pushes the SGR parameters of a color, where `base` is 30 for the foreground, 40 for the
+    /// background and 50 for the underline color.
+    fn push_color(&mut self, color: Color, base: u8) {
+        let (index, named) = match color {
+            Color::Reset => return,
+            Color::Black => (0, true),
+            Color::Red => (1, true),
+            Color::Green => (2, true),
+            Color::Yellow => (3, true),
+            Color::Blue => (4, true),
+            Color::Magenta => (5, true),
+            Color::Cyan => (6, true),
+            Color::Gray => (7, true),
+            Color::DarkGray => (8, true),
+            Color::LightRed => (9, true),
+            Color::LightGreen => (10, true),
+            Color::LightYellow => (11, true),
+            Color::LightBlue => (12, true),
+            Color::LightMagenta => (13, true),
+            Color::LightCyan => (14, true),
+            Color::White => (15, true),
+            Color::Indexed(index) => (index, false),
+            Color::Rgb(r, g, b) => {
+                let _ = write!(self.output, ";{};2;{r};{g};{b}", base + 8);
+                return;
+            }
+        };
+        // there are no 16-color codes for the underline color
+        let _ = match index {
+            _ if !named || base == 50 => write!(self.output, ";{};5;{index}", base + 8),
+            0..=7 => write!(self.output, ";{}", base + index),
+            _ => write!(self.output, ";{}", base + 52 + index),
+        };
+    }
+
+    pub(crate) fn hide_cursor(&mut self) {
+        self.output.push_str("\x1b[?25l");
+    }
+
+    pub(crate) fn show_cursor(&mut self) {
+        self.output.push_str("\x1b[?25h");
+    }
+
+    pub(crate) fn begin_synchronized_update(&mut self) {
+        self.output.push_str("\x1b[?2026h");
+    }
+
+    pub(crate) fn end_synchronized_update(&mut self) {
+        self.output.push_str("\x1b[?2026l");
+    }
+
+    pub(crate) fn clear_region(&mut self, clear_type: ClearType) {
+        self.output.push_str(match clear_type {
+            ClearType::All => "\x1b[2J",
+            ClearType::AfterCursor => "\x1b[J",
+            ClearType::BeforeCursor => "\x1b[1J",
+            ClearType::CurrentLine => "\x1b[2K",
+            ClearType::UntilNewLine => "\x1b[K",
+        });
+    }
+
+    pub(crate) fn append_lines(&mut self, n: u16) {
+        for _ in 0..n {
+            self.output.push('\n');
+        }
+        self.cursor = None;
+    }
+
+    pub(crate) fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) {
+        self.move_to(Position::new(0, y));
+        self.output.push_str(attribute.escape_sequence());
+    }
+
+    /// Scrolls a region of the screen, where `direction` is `'S'` for up and `'T'` for down.
+    #[cfg(feature = "scrolling-regions")]
+    pub(crate) fn scroll_region(
+        &mut self,
+        region: &core::ops::Range<u16>,
+        line_count: u16,
+        direction: char,
+    ) {
+        let _ = write!(
+            self.output,
+            "\x1b[{};{}r\x1b[{line_count}{direction}\x1b[r",
+            region.start + 1,
+            region.end
+        );
+        self.cursor = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use super::*;
+    use crate::style::{Style, Stylize};
+    use crate::terminal::{Terminal, TerminalOptions};
+
+    #[derive(Debug, Default)]
+    struct Target {
+        writes: alloc::vec::Vec<String>,
+    }
+
+    impl AnsiTarget for Target {
+        type Error = Infallible;
+
+        fn write(&mut self, ansi: &str) -> Result<(), Self::Error> {
+            self.writes.push(ansi.into());
+            Ok(())
+        }
+
+        fn size(&self) -> Result<Size, Self::Error> {
+            Ok(Size::new(4, 2))
+        }
+    }
+
+    #[test]
+    fn counts_bytes_written() {
+        let mut backend = AnsiBackend::new(Target::default());
+        assert_eq!(backend.bytes_written(), Some(0));
+        backend.hide_cursor().unwrap();
+        assert_eq!(backend.bytes_written(), Some(6));
+        backend.flush().unwrap();
+        backend.show_cursor().unwrap();
+        assert_eq!(backend.bytes_written(), Some(12));
+    }
+
+    #[test]
+    fn writes_each_frame_once() {
+        let mut terminal = Terminal::new(AnsiBackend::new(Target::default())).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget("ab".bold(), frame.area()))
+            .unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget("ab".bold(), frame.area());
+                frame.set_cursor_position((1, 1));
+            })
+            .unwrap();
+        assert_eq!(
+            terminal.backend().target().writes,
+            [
+                "\x1b[1;1H\x1b[0;1mab\x1b[0m\x1b[1;1H\x1b[?25l",
+                "\x1b[?25h\x1b[2;2H",
+            ]
+        );
+        assert_eq!(
+            terminal.backend_mut().get_cursor_position(),
+            Ok(Position::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn synchronized_output() {
+        let options = TerminalOptions::new().synchronized_output(true);
+        let backend = AnsiBackend::new(Target::default());
+        let mut terminal = Terminal::with_options(backend, options).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget("ab", frame.area()))
+            .unwrap();
+        assert_eq!(
+            terminal.backend().target().writes,
+            ["\x1b[?2026h\x1b[1;1Hab\x1b[1;1H\x1b[?25l\x1b[?2026l"]
+        );
+    }
+
+    #[test]
+    fn window_size_defaults_to_zero_pixels() {
+        let mut backend = AnsiBackend::new(Target::default());
+        assert_eq!(
+            backend.window_size(),
+            Ok(WindowSize {
+                columns_rows: Size::new(4, 2),
+                pixels: Size::ZERO,
+            })
+        );
+        assert_eq!(backend.now(), None);
+    }
+
+    #[test]
+    fn sgr_colors_and_modifiers() {
+        let mut ansi = AnsiWriter::default();
+        let mut cell = Cell::new("x");
+        cell.set_style(
+            Style::new()
+                .bold()
+                .overlined()
+                .fg(Color::LightBlue)
+                .bg(Color::Rgb(1, 2, 3)),
+        );
+        ansi.draw_cell(0, 0, &cell);
+        cell.set_style(Style::reset().fg(Color::Indexed(42)));
+        ansi.draw_cell(1, 0, &cell);
+        assert_eq!(
+            ansi.output,
+            "\x1b[1;1H\x1b[0;1;53;94;48;2;1;2;3mx\x1b[0;38;5;42mx"
+        );
+    }
+}
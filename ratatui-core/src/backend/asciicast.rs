@@ -0,0 +1,424 @@
+use alloc::string::String;
+use core::fmt::Write as _;
+use core::time::Duration;
+use std::io::{self, Write};
+use std::time::Instant;
+
+use crate::accessibility::Semantics;
+use crate::backend::ansi::AnsiWriter;
+use crate::backend::{Backend, ClearType, WindowSize};
+use crate::buffer::{Cell, LineAttribute};
+use crate::layout::{Position, Size};
+use crate::style::ColorSupport;
+
+/// A [`Backend`] that records the frames drawn to another backend as an [asciicast v2] recording.
+///
+/// Every call to [`Backend::flush`] (which [`Terminal::draw`] makes once per frame) writes the ANSI
+/// escape sequences needed to draw the changes to the screen as an output event, together with the
+/// time at which it happened. The recording can be played back with [asciinema] or embedded in a
+/// web page with the asciinema player.
+///
+/// All calls are forwarded to the wrapped backend, which provides the size of the terminal and the
+/// cursor position. Wrap a [`TestBackend`] to produce recordings headlessly (e.g. in CI), or wrap
+/// the backend of a real terminal to record a live session. When the wrapped backend changes size,
+/// a resize event is added to the recording.
+///
+/// By default, events are timed with the real time since the backend was created. Use
+/// [`AsciicastBackend::frame_interval`] to space out the frames evenly instead, which makes
+/// recordings reproducible.
+///
+/// Underline styles are recorded as plain underlines.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui_core::backend::{AsciicastBackend, TestBackend};
+/// use ratatui_core::terminal::Terminal;
+///
+/// let backend = AsciicastBackend::new(TestBackend::new(20, 2), Vec::new())
+///     .title("demo")
+///     .frame_interval(Duration::from_millis(100));
+/// let mut terminal = Terminal::new(backend)?;
+/// terminal.draw(|frame| frame.render_widget("Hello", frame.area()))?;
+/// terminal.draw(|frame| frame.render_widget("World", frame.area()))?;
+///
+/// let recording = String::from_utf8(terminal.backend().writer().clone())?;
+/// assert!(recording.starts_with(r#"{"version": 2, "width": 20, "height": 2, "title": "demo"}"#));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+/// [asciinema]: https://asciinema.org
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+/// [`TestBackend`]: crate::backend::TestBackend
+#[derive(Debug)]
+pub struct AsciicastBackend<B, W> {
+    inner: B,
+    writer: W,
+    title: Option<String>,
+    clock: Clock,
+    /// The size written to the header or the last resize event, or `None` before the header.
+    size: Option<Size>,
+    ansi: AnsiWriter,
+}
+
+/// How the events of an [`AsciicastBackend`] are timed.
+#[derive(Debug)]
+enum Clock {
+    RealTime(Instant),
+    Fixed {
+        interval: Duration,
+        elapsed: Duration,
+    },
+}
+
+/// An error of an [`AsciicastBackend`].
+#[derive(Debug, thiserror::Error)]
+pub enum AsciicastError<E> {
+    /// The wrapped backend returned an error.
+    #[error(transparent)]
+    Backend(E),
+    /// The recording could not be written.
+    #[error("failed to write the asciicast recording")]
+    Io(#[from] io::Error),
+}
+
+impl<B: Backend, W: Write> AsciicastBackend<B, W> {
+    /// Creates a backend that forwards all calls to `inner` and writes the recording to `writer`.
+    pub fn new(inner: B, writer: W) -> Self {
+        Self {
+            inner,
+            writer,
+            title: None,
+            clock: Clock::RealTime(Instant::now()),
+            size: None,
+            ansi: AnsiWriter::default(),
+        }
+    }
+
+    /// Sets the title that is stored in the header of the recording.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Times each frame `interval` after the previous one, instead of using the real time.
+    ///
+    /// The first frame is recorded at time zero.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frame_interval(mut self, interval: Duration) -> Self {
+        self.clock = Clock::Fixed {
+            interval,
+            elapsed: Duration::ZERO,
+        };
+        self
+    }
+
+    /// Returns a reference to the wrapped backend.
+    pub const fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped backend.
+    pub const fn inner_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Returns a reference to the writer of the recording.
+    pub const fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the backend, returning the wrapped backend and the writer.
+    pub fn into_parts(self) -> (B, W) {
+        (self.inner, self.writer)
+    }
+
+    /// Writes the pending output as an event, preceded by the header or a resize event if needed.
+    fn write_events(&mut self) -> Result<(), AsciicastError<B::Error>> {
+        let size = self.inner.size().map_err(AsciicastError::Backend)?;
+        match self.size {
+            None => {
+                write!(
+                    self.writer,
+                    r#"{{"version": 2, "width": {}, "height": {}"#,
+                    size.width, size.height
+                )?;
+                if let Some(title) = &self.title {
+                    write!(self.writer, r#", "title": {}"#, json_string(title))?;
+                }
+                writeln!(self.writer, "}}")?;
+            }
+            Some(previous) if previous != size => {
+                let time = self.clock.now();
+                writeln!(
+                    self.writer,
+                    r#"[{time:.6}, "r", "{}x{}"]"#,
+                    size.width, size.height
+                )?;
+            }
+            Some(_) => {}
+        }
+        self.size = Some(size);
+
+        if !self.ansi.output.is_empty() {
+            let time = self.clock.now();
+            writeln!(
+                self.writer,
+                r#"[{time:.6}, "o", {}]"#,
+                json_string(&self.ansi.output)
+            )?;
+            self.ansi.output.clear();
+            self.clock.advance();
+        }
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<B: Backend, W: Write> Backend for AsciicastBackend<B, W> {
+    type Error = AsciicastError<B::Error>;
+
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), Self::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let ansi = &mut self.ansi;
+        self.inner
+            .draw(content.inspect(|&(x, y, cell)| ansi.draw_cell(x, y, cell)))
+            .map_err(AsciicastError::Backend)?;
+        ansi.reset_style();
+        Ok(())
+    }
+
+    fn append_lines(&mut self, n: u16) -> Result<(), Self::Error> {
+        self.ansi.append_lines(n);
+        self.inner.append_lines(n).map_err(AsciicastError::Backend)
+    }
+
+    fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) -> Result<(), Self::Error> {
+        self.ansi.set_line_attribute(y, attribute);
+        self.inner
+            .set_line_attribute(y, attribute)
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn begin_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        self.ansi.begin_synchronized_update();
+        self.inner
+            .begin_synchronized_update()
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn end_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        self.ansi.end_synchronized_update();
+        self.inner
+            .end_synchronized_update()
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn update_semantics(
+        &mut self,
+        semantics: &Semantics,
+        previous: &Semantics,
+    ) -> Result<(), Self::Error> {
+        self.inner
+            .update_semantics(semantics, previous)
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn color_support(&self) -> ColorSupport {
+        self.inner.color_support()
+    }
+
+    fn bytes_written(&self) -> Option<u64> {
+        self.inner.bytes_written()
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.ansi.hide_cursor();
+        self.inner.hide_cursor().map_err(AsciicastError::Backend)
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.ansi.show_cursor();
+        self.inner.show_cursor().map_err(AsciicastError::Backend)
+    }
+
+    fn get_cursor_position(&mut self) -> Result<Position, Self::Error> {
+        self.inner
+            .get_cursor_position()
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> Result<(), Self::Error> {
+        let position = position.into();
+        self.ansi.move_to(position);
+        self.inner
+            .set_cursor_position(position)
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.ansi.clear_region(ClearType::All);
+        self.inner.clear().map_err(AsciicastError::Backend)
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> Result<(), Self::Error> {
+        self.ansi.clear_region(clear_type);
+        self.inner
+            .clear_region(clear_type)
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn size(&self) -> Result<Size, Self::Error> {
+        self.inner.size().map_err(AsciicastError::Backend)
+    }
+
+    fn window_size(&mut self) -> Result<WindowSize, Self::Error> {
+        self.inner.window_size().map_err(AsciicastError::Backend)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.inner.flush().map_err(AsciicastError::Backend)?;
+        self.write_events()
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_up(
+        &mut self,
+        region: core::ops::Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        self.ansi.scroll_region(&region, line_count, 'S');
+        self.inner
+            .scroll_region_up(region, line_count)
+            .map_err(AsciicastError::Backend)
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_down(
+        &mut self,
+        region: core::ops::Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        self.ansi.scroll_region(&region, line_count, 'T');
+        self.inner
+            .scroll_region_down(region, line_count)
+            .map_err(AsciicastError::Backend)
+    }
+}
+
+impl Clock {
+    fn now(&self) -> f64 {
+        match self {
+            Self::RealTime(start) => start.elapsed().as_secs_f64(),
+            Self::Fixed { elapsed, .. } => elapsed.as_secs_f64(),
+        }
+    }
+
+    fn advance(&mut self) {
+        if let Self::Fixed { interval, elapsed } = self {
+            *elapsed += *interval;
+        }
+    }
+}
+
+/// Formats a string as a JSON string literal.
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", u32::from(c));
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::backend::TestBackend;
+    use crate::style::Stylize;
+    use crate::terminal::Terminal;
+
+    fn recording(backend: &AsciicastBackend<TestBackend, Vec<u8>>) -> String {
+        String::from_utf8(backend.writer.clone()).unwrap()
+    }
+
+    #[test]
+    fn records_header_and_frames() {
+        let backend = AsciicastBackend::new(TestBackend::new(6, 2), Vec::new())
+            .title("a \"demo\"")
+            .frame_interval(Duration::from_millis(500));
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget("ab", frame.area()))
+            .unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget("ac".red(), frame.area());
+                frame.set_cursor_position((1, 1));
+            })
+            .unwrap();
+
+        assert_eq!(
+            recording(terminal.backend()),
+            concat!(
+                r#"{"version": 2, "width": 6, "height": 2, "title": "a \"demo\""}"#,
+                "\n",
+                r#"[0.000000, "o", "\u001b[1;1Hab\u001b[?25l"]"#,
+                "\n",
+                r#"[0.500000, "o", "\u001b[1;1H\u001b[0;31mac\u001b[0m\u001b[?25h\u001b[2;2H"]"#,
+                "\n",
+            )
+        );
+        assert_eq!(terminal.backend().inner().buffer()[(1, 0)].symbol(), "c");
+    }
+
+    #[test]
+    fn records_resize() {
+        let mut backend = AsciicastBackend::new(TestBackend::new(4, 1), Vec::new())
+            .frame_interval(Duration::from_secs(1));
+        backend.flush().unwrap();
+        backend.inner_mut().resize(8, 2);
+        backend.clear().unwrap();
+        backend.flush().unwrap();
+
+        assert_eq!(
+            recording(&backend),
+            concat!(
+                r#"{"version": 2, "width": 4, "height": 1}"#,
+                "\n",
+                r#"[0.000000, "r", "8x2"]"#,
+                "\n",
+                r#"[0.000000, "o", "\u001b[2J"]"#,
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn json_string_escapes() {
+        assert_eq!(json_string("a\"b\\c\n\x1b"), r#""a\"b\\c\n\u001b""#);
+    }
+}
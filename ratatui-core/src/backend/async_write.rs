@@ -0,0 +1,391 @@
+use std::io;
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::backend::ansi::AnsiWriter;
+use crate::backend::{Backend, ClearType, WindowSize};
+use crate::buffer::{Cell, LineAttribute};
+use crate::layout::{Position, Size};
+use crate::style::ColorSupport;
+
+/// A [`Backend`] that renders to an [`AsyncWrite`] using ANSI escape sequences.
+///
+/// This is intended for applications that serve a user interface to a remote terminal, such as
+/// SSH servers built with [russh], where there is no local tty to write to or to query the size
+/// of. The backend writes to any [`AsyncWrite`] (e.g. the writer of an SSH channel) and the size of
+/// the remote terminal is set by the application with [`resize`] or [`set_window_size`] (e.g. from
+/// the `pty-req` and `window-change` requests of an SSH session).
+///
+/// As [`Backend`] is synchronous, the output of each frame is collected in memory and written to
+/// the writer by [`flush_async`], which must be awaited after each call to [`Terminal::draw`]:
+///
+/// ```rust
+/// use ratatui_core::backend::AsyncWriteBackend;
+/// use ratatui_core::layout::Size;
+/// use ratatui_core::terminal::Terminal;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // a stand-in for the writer of an SSH channel
+/// let writer = Vec::new();
+/// let backend = AsyncWriteBackend::new(writer, Size::new(20, 2));
+/// let mut terminal = Terminal::new(backend)?;
+/// terminal.draw(|frame| frame.render_widget("Hello", frame.area()))?;
+/// terminal.backend_mut().flush_async().await?;
+///
+/// // when the remote terminal is resized, the next frame is drawn at the new size
+/// terminal.backend_mut().resize(Size::new(40, 10));
+/// terminal.draw(|frame| frame.render_widget("Hello", frame.area()))?;
+/// terminal.backend_mut().flush_async().await?;
+///
+/// let output = String::from_utf8(terminal.backend().writer().clone())?;
+/// assert!(output.contains("Hello"));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The cursor position can't be queried from the remote terminal, so the backend keeps track of
+/// the position that was last set. Underline styles are drawn as plain underlines.
+///
+/// [russh]: https://crates.io/crates/russh
+/// [`resize`]: Self::resize
+/// [`set_window_size`]: Self::set_window_size
+/// [`flush_async`]: Self::flush_async
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+#[derive(Debug)]
+pub struct AsyncWriteBackend<W> {
+    writer: W,
+    ansi: AnsiWriter,
+    window_size: WindowSize,
+    color_support: ColorSupport,
+    cursor_position: Position,
+    /// How many bytes of the pending output were written by a [`flush_async`] call that failed.
+    ///
+    /// [`flush_async`]: Self::flush_async
+    written: usize,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWriteBackend<W> {
+    /// Creates a backend that renders to `writer`, for a terminal of the given size in columns and
+    /// rows.
+    pub fn new(writer: W, size: Size) -> Self {
+        Self {
+            writer,
+            ansi: AnsiWriter::default(),
+            window_size: WindowSize {
+                columns_rows: size,
+                pixels: Size::ZERO,
+            },
+            color_support: ColorSupport::TrueColor,
+            cursor_position: Position::ORIGIN,
+            written: 0,
+        }
+    }
+
+    /// Sets the colors the remote terminal can display.
+    ///
+    /// Defaults to [`ColorSupport::TrueColor`]. The `TERM` environment variable sent by the client
+    /// (e.g. in the `pty-req` request of an SSH session) can be used to choose a lower level.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = color_support;
+        self
+    }
+
+    /// Sets the size of the remote terminal in columns and rows.
+    ///
+    /// The pixel size is left unchanged. The [`Terminal`] resizes its viewport to the new size
+    /// before drawing the next frame.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    pub const fn resize(&mut self, size: Size) {
+        self.window_size.columns_rows = size;
+    }
+
+    /// Sets the size of the remote terminal in columns/rows and pixels.
+    pub const fn set_window_size(&mut self, window_size: WindowSize) {
+        self.window_size = window_size;
+    }
+
+    /// Returns the output that has not been written to the writer yet.
+    ///
+    /// This is returned as bytes, as a failed [`flush_async`](Self::flush_async) call may have
+    /// written only part of a character.
+    pub fn pending_output(&self) -> &[u8] {
+        &self.ansi.output.as_bytes()[self.written..]
+    }
+
+    /// Writes the pending output to the writer and flushes it.
+    ///
+    /// The backend keeps track of how much of the output has been written, so a failed call can be
+    /// retried without writing any of it twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to or flushing the writer fails.
+    pub async fn flush_async(&mut self) -> io::Result<()> {
+        while self.written < self.ansi.output.len() {
+            let n = self
+                .writer
+                .write(&self.ansi.output.as_bytes()[self.written..])
+                .await?;
+            if n == 0 {
+                return Err(io::ErrorKind::WriteZero.into());
+            }
+            self.written += n;
+        }
+        self.ansi.clear_output();
+        self.written = 0;
+        self.writer.flush().await
+    }
+
+    /// Returns a reference to the writer.
+    pub const fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the writer.
+    ///
+    /// Output written directly to the writer is not synchronized with the pending output, so call
+    /// [`flush_async`](Self::flush_async) first.
+    pub const fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the backend, returning the writer.
+    ///
+    /// Any pending output is discarded.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: AsyncWrite + Unpin> Backend for AsyncWriteBackend<W> {
+    type Error = core::convert::Infallible;
+
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), Self::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        for (x, y, cell) in content {
+            self.ansi.draw_cell(x, y, cell);
+        }
+        self.ansi.reset_style();
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+
+    fn append_lines(&mut self, n: u16) -> Result<(), Self::Error> {
+        self.ansi.append_lines(n);
+        Ok(())
+    }
+
+    fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) -> Result<(), Self::Error> {
+        self.ansi.set_line_attribute(y, attribute);
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+
+    fn begin_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        self.ansi.begin_synchronized_update();
+        Ok(())
+    }
+
+    fn end_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        self.ansi.end_synchronized_update();
+        Ok(())
+    }
+
+    fn color_support(&self) -> ColorSupport {
+        self.color_support
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.ansi.hide_cursor();
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.ansi.show_cursor();
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> Result<Position, Self::Error> {
+        Ok(self.cursor_position)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> Result<(), Self::Error> {
+        self.cursor_position = position.into();
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.ansi.clear_region(ClearType::All);
+        Ok(())
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> Result<(), Self::Error> {
+        self.ansi.clear_region(clear_type);
+        Ok(())
+    }
+
+    fn size(&self) -> Result<Size, Self::Error> {
+        Ok(self.window_size.columns_rows)
+    }
+
+    fn window_size(&mut self) -> Result<WindowSize, Self::Error> {
+        Ok(self.window_size)
+    }
+
+    /// Counts the output that is pending as well, as it is written by
+    /// [`AsyncWriteBackend::flush_async`] after the frame is drawn.
+    fn bytes_written(&self) -> Option<u64> {
+        Some(self.ansi.bytes_written())
+    }
+
+    /// Does nothing, as the output can only be written asynchronously.
+    ///
+    /// Call [`AsyncWriteBackend::flush_async`] to write the output to the writer.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_up(
+        &mut self,
+        region: core::ops::Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        self.ansi.scroll_region(&region, line_count, 'S');
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_down(
+        &mut self,
+        region: core::ops::Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        self.ansi.scroll_region(&region, line_count, 'T');
+        self.ansi.move_to(self.cursor_position);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    use super::*;
+    use crate::terminal::Terminal;
+
+    #[tokio::test]
+    async fn flush_async_writes_pending_output() {
+        let mut backend = AsyncWriteBackend::new(Vec::new(), Size::new(10, 2));
+        let cell = Cell::new("a");
+        backend.draw([(1, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(backend.pending_output(), b"\x1b[1;2Ha\x1b[1;1H");
+        assert!(backend.writer().is_empty());
+
+        backend.flush_async().await.unwrap();
+        assert_eq!(backend.pending_output(), b"");
+        assert_eq!(backend.writer(), b"\x1b[1;2Ha\x1b[1;1H");
+    }
+
+    /// A writer that accepts a limited number of bytes, then fails.
+    struct FailingWriter {
+        written: Vec<u8>,
+        capacity: usize,
+    }
+
+    impl AsyncWrite for FailingWriter {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            if self.capacity == 0 {
+                return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+            }
+            let n = buf.len().min(self.capacity);
+            self.capacity -= n;
+            self.written.extend_from_slice(&buf[..n]);
+            Poll::Ready(Ok(n))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn flush_async_resumes_after_partial_write() {
+        let writer = FailingWriter {
+            written: Vec::new(),
+            capacity: 4,
+        };
+        let mut backend = AsyncWriteBackend::new(writer, Size::new(10, 2));
+        let cell = Cell::new("a");
+        backend.draw([(1, 0, &cell)].into_iter()).unwrap();
+
+        assert!(backend.flush_async().await.is_err());
+        assert_eq!(backend.writer().written, b"\x1b[1;");
+        assert_eq!(backend.pending_output(), b"2Ha\x1b[1;1H");
+
+        backend.writer_mut().capacity = usize::MAX;
+        backend.flush_async().await.unwrap();
+        assert_eq!(backend.writer().written, b"\x1b[1;2Ha\x1b[1;1H");
+        assert_eq!(backend.pending_output(), b"");
+    }
+
+    #[test]
+    fn resize() {
+        let mut backend = AsyncWriteBackend::new(Vec::new(), Size::new(10, 2));
+        assert_eq!(backend.size().unwrap(), Size::new(10, 2));
+
+        backend.set_window_size(WindowSize {
+            columns_rows: Size::new(20, 4),
+            pixels: Size::new(200, 80),
+        });
+        backend.resize(Size::new(30, 5));
+        assert_eq!(
+            backend.window_size().unwrap(),
+            WindowSize {
+                columns_rows: Size::new(30, 5),
+                pixels: Size::new(200, 80),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn terminal_resizes_to_injected_size() {
+        let backend = AsyncWriteBackend::new(Vec::new(), Size::new(10, 2));
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget("Hi", frame.area()))
+            .unwrap();
+        terminal.backend_mut().flush_async().await.unwrap();
+
+        terminal.backend_mut().resize(Size::new(4, 1));
+        let frame = terminal
+            .draw(|frame| frame.render_widget("Yo", frame.area()))
+            .unwrap();
+        assert_eq!(frame.area.as_size(), Size::new(4, 1));
+        terminal.backend_mut().flush_async().await.unwrap();
+        let output = String::from_utf8(terminal.backend().writer().clone()).unwrap();
+        assert!(output.contains("Hi"));
+        assert!(output.contains("Yo"));
+    }
+}
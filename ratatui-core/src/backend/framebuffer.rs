@@ -0,0 +1,437 @@
+use core::ops::Range;
+
+use crate::backend::{Backend, ClearType, WindowSize};
+use crate::buffer::Cell;
+use crate::layout::{Position, Size};
+use crate::style::ColorSupport;
+
+/// A cell of a character framebuffer that a [`FramebufferBackend`] draws to.
+///
+/// This is implemented for [`Cell`], so a slice of cells can be used as the framebuffer directly.
+/// Implement it for the cell type of a hardware text mode (e.g. the character and attribute bytes
+/// of a VGA text buffer) to draw to it without an intermediate buffer.
+pub trait FramebufferCell {
+    /// Sets the cell to the content of a cell drawn by Ratatui.
+    fn set(&mut self, cell: &Cell);
+
+    /// Clears the cell.
+    ///
+    /// The default implementation sets the cell to [`Cell::EMPTY`].
+    fn reset(&mut self) {
+        self.set(&Cell::EMPTY);
+    }
+}
+
+impl FramebufferCell for Cell {
+    fn set(&mut self, cell: &Cell) {
+        self.clone_from(cell);
+    }
+
+    fn reset(&mut self) {
+        Self::reset(self);
+    }
+}
+
+/// A [`Backend`] that draws to a character framebuffer in memory.
+///
+/// The framebuffer is a row-major slice of cells that implement [`FramebufferCell`], such as a
+/// `[Cell; WIDTH * HEIGHT]` array or the memory-mapped buffer of a hardware text mode. The backend
+/// has no OS dependencies and doesn't allocate, so it can be used to build the user interfaces of
+/// firmware and embedded devices (e.g. on a serial console or LCD character display) with the
+/// widgets of Ratatui in `no_std` environments.
+///
+/// Cells are written to the framebuffer as soon as they are drawn. The backend keeps track of the
+/// cursor position and visibility, which can be read with [`cursor_position`] and
+/// [`is_cursor_visible`] to display the cursor.
+///
+/// As the framebuffer has no scrollback, the backend is intended to be used with a fullscreen or
+/// fixed viewport.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::FramebufferBackend;
+/// use ratatui_core::buffer::Cell;
+/// use ratatui_core::terminal::Terminal;
+///
+/// const WIDTH: u16 = 20;
+/// const HEIGHT: u16 = 2;
+///
+/// let mut framebuffer = [const { Cell::EMPTY }; (WIDTH * HEIGHT) as usize];
+/// let backend = FramebufferBackend::new(&mut framebuffer, WIDTH);
+/// let mut terminal = Terminal::new(backend)?;
+/// terminal.draw(|frame| frame.render_widget("Hello", frame.area()))?;
+///
+/// drop(terminal);
+/// assert_eq!(framebuffer[0].symbol(), "H");
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// A custom [`FramebufferCell`] draws to the memory layout of a text mode:
+///
+/// ```rust
+/// use ratatui_core::backend::{FramebufferBackend, FramebufferCell};
+/// use ratatui_core::buffer::Cell;
+/// use ratatui_core::style::{Color, ColorSupport};
+/// use ratatui_core::terminal::Terminal;
+///
+/// /// A character and its attribute (foreground and background colors) in a VGA text buffer.
+/// #[derive(Clone, Copy, Default)]
+/// #[repr(C)]
+/// struct VgaChar {
+///     character: u8,
+///     attribute: u8,
+/// }
+///
+/// impl FramebufferCell for VgaChar {
+///     fn set(&mut self, cell: &Cell) {
+///         self.character = cell
+///             .symbol()
+///             .bytes()
+///             .next()
+///             .filter(u8::is_ascii)
+///             .unwrap_or(b'?');
+///         let fg = match cell.fg {
+///             Color::Red => 0x4,
+///             _ => 0x7,
+///         };
+///         self.attribute = fg;
+///     }
+/// }
+///
+/// // on real hardware, this would be the memory at 0xb8000
+/// let mut vga_buffer = [VgaChar::default(); 80 * 25];
+/// let backend = FramebufferBackend::new(&mut vga_buffer, 80).color_support(ColorSupport::Ansi16);
+/// let mut terminal = Terminal::new(backend)?;
+/// terminal.draw(|frame| frame.render_widget("Hi", frame.area()))?;
+///
+/// drop(terminal);
+/// assert_eq!(vga_buffer[0].character, b'H');
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`cursor_position`]: Self::cursor_position
+/// [`is_cursor_visible`]: Self::is_cursor_visible
+#[derive(Debug)]
+pub struct FramebufferBackend<'a, C = Cell> {
+    cells: &'a mut [C],
+    size: Size,
+    color_support: ColorSupport,
+    cursor_position: Position,
+    cursor_visible: bool,
+}
+
+impl<'a, C: FramebufferCell> FramebufferBackend<'a, C> {
+    /// Creates a backend that draws to a row-major framebuffer that is `width` cells wide.
+    ///
+    /// The height of the framebuffer is the number of complete rows in `cells`. Cells after the
+    /// last complete row are not drawn to.
+    pub fn new(cells: &'a mut [C], width: u16) -> Self {
+        let height = cells
+            .len()
+            .checked_div(usize::from(width))
+            .unwrap_or_default();
+        let height = u16::try_from(height).unwrap_or(u16::MAX);
+        Self {
+            cells,
+            size: Size::new(width, height),
+            color_support: ColorSupport::TrueColor,
+            cursor_position: Position::ORIGIN,
+            cursor_visible: true,
+        }
+    }
+
+    /// Sets the colors the framebuffer can display.
+    ///
+    /// Defaults to [`ColorSupport::TrueColor`], as the colors are passed to the framebuffer
+    /// unchanged. Set this to the colors of the display so that widgets that adapt to the color
+    /// support of the terminal choose colors that it can show.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = color_support;
+        self
+    }
+
+    /// Returns the cells of the framebuffer.
+    pub const fn cells(&self) -> &[C] {
+        self.cells
+    }
+
+    /// Returns the cells of the framebuffer mutably.
+    pub const fn cells_mut(&mut self) -> &mut [C] {
+        self.cells
+    }
+
+    /// Returns the position of the cursor.
+    pub const fn cursor_position(&self) -> Position {
+        self.cursor_position
+    }
+
+    /// Returns whether the cursor is visible.
+    pub const fn is_cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Returns the index of the cell at the position, if it is in the framebuffer.
+    fn index_of(&self, x: u16, y: u16) -> Option<usize> {
+        (x < self.size.width && y < self.size.height)
+            .then(|| usize::from(y) * usize::from(self.size.width) + usize::from(x))
+    }
+
+    /// Returns the range of the cells in the rows of the framebuffer.
+    fn rows(&self, rows: Range<u16>) -> Range<usize> {
+        let width = usize::from(self.size.width);
+        let start = usize::from(rows.start.min(self.size.height)) * width;
+        let end = usize::from(rows.end.min(self.size.height)) * width;
+        start..end.max(start)
+    }
+
+    /// Clears the cells in the range.
+    fn reset(&mut self, range: Range<usize>) {
+        for cell in &mut self.cells[range] {
+            cell.reset();
+        }
+    }
+}
+
+impl<C: FramebufferCell> Backend for FramebufferBackend<'_, C> {
+    type Error = core::convert::Infallible;
+
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), Self::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        for (x, y, cell) in content {
+            if let Some(index) = self.index_of(x, y) {
+                self.cells[index].set(cell);
+            }
+        }
+        Ok(())
+    }
+
+    fn color_support(&self) -> ColorSupport {
+        self.color_support
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.cursor_visible = false;
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.cursor_visible = true;
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> Result<Position, Self::Error> {
+        Ok(self.cursor_position)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> Result<(), Self::Error> {
+        self.cursor_position = position.into();
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.reset(self.rows(0..self.size.height));
+        Ok(())
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> Result<(), Self::Error> {
+        let Position { x, y } = self.cursor_position;
+        let Some(cursor) = self.index_of(x, y) else {
+            return match clear_type {
+                ClearType::All => self.clear(),
+                _ => Ok(()),
+            };
+        };
+        let line = self.rows(y..y + 1);
+        let range = match clear_type {
+            ClearType::All => return self.clear(),
+            ClearType::AfterCursor => cursor..self.rows(0..self.size.height).end,
+            ClearType::BeforeCursor => 0..cursor + 1,
+            ClearType::CurrentLine => line,
+            ClearType::UntilNewLine => cursor..line.end,
+        };
+        self.reset(range);
+        Ok(())
+    }
+
+    fn size(&self) -> Result<Size, Self::Error> {
+        Ok(self.size)
+    }
+
+    fn window_size(&mut self) -> Result<WindowSize, Self::Error> {
+        Ok(WindowSize {
+            columns_rows: self.size,
+            pixels: Size::ZERO,
+        })
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_up(&mut self, region: Range<u16>, line_count: u16) -> Result<(), Self::Error> {
+        let region = self.rows(region);
+        let shift = usize::from(line_count)
+            .saturating_mul(usize::from(self.size.width))
+            .min(region.len());
+        self.cells[region.clone()].rotate_left(shift);
+        self.reset(region.end - shift..region.end);
+        Ok(())
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_down(
+        &mut self,
+        region: Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        let region = self.rows(region);
+        let shift = usize::from(line_count)
+            .saturating_mul(usize::from(self.size.width))
+            .min(region.len());
+        self.cells[region.clone()].rotate_right(shift);
+        self.reset(region.start..region.start + shift);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::buffer::Buffer;
+    use crate::layout::Rect;
+
+    /// Returns the framebuffer as a buffer, to compare it with the expected lines.
+    fn as_buffer(cells: &[Cell], width: u16) -> Buffer {
+        let height = u16::try_from(cells.len() / usize::from(width)).unwrap();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+        buffer.content = cells.to_vec();
+        buffer
+    }
+
+    fn framebuffer(lines: &[&str]) -> Vec<Cell> {
+        Buffer::with_lines(lines.iter().copied()).content
+    }
+
+    #[test]
+    fn new() {
+        let mut cells = vec![Cell::EMPTY; 25];
+        let mut backend = FramebufferBackend::new(&mut cells, 10);
+        // the incomplete last row is not used
+        assert_eq!(backend.size().unwrap(), Size::new(10, 2));
+        assert_eq!(backend.cursor_position(), Position::ORIGIN);
+        assert!(backend.is_cursor_visible());
+        assert_eq!(
+            backend.window_size().unwrap().columns_rows,
+            Size::new(10, 2)
+        );
+    }
+
+    #[test]
+    fn zero_width() {
+        let mut cells = vec![Cell::EMPTY; 4];
+        let backend = FramebufferBackend::new(&mut cells, 0);
+        assert_eq!(backend.size().unwrap(), Size::ZERO);
+    }
+
+    #[test]
+    fn draw() {
+        let mut cells = vec![Cell::EMPTY; 6];
+        let mut backend = FramebufferBackend::new(&mut cells, 3);
+        let a = Cell::new("a");
+        let b = Cell::new("b");
+        backend
+            .draw([(0, 0, &a), (2, 1, &b), (3, 0, &a), (0, 2, &b)].into_iter())
+            .unwrap();
+        assert_eq!(as_buffer(&cells, 3), Buffer::with_lines(["a  ", "  b"]));
+    }
+
+    #[test]
+    fn cursor() {
+        let mut cells = vec![Cell::EMPTY; 6];
+        let mut backend = FramebufferBackend::new(&mut cells, 3);
+        backend.hide_cursor().unwrap();
+        backend.set_cursor_position((2, 1)).unwrap();
+        assert!(!backend.is_cursor_visible());
+        assert_eq!(backend.get_cursor_position().unwrap(), Position::new(2, 1));
+        backend.show_cursor().unwrap();
+        assert!(backend.is_cursor_visible());
+    }
+
+    #[test]
+    fn clear_region() {
+        let lines = ["aaa", "aaa", "aaa"];
+        let cases = [
+            (ClearType::All, ["   ", "   ", "   "]),
+            (ClearType::AfterCursor, ["aaa", "a  ", "   "]),
+            (ClearType::BeforeCursor, ["   ", "  a", "aaa"]),
+            (ClearType::CurrentLine, ["aaa", "   ", "aaa"]),
+            (ClearType::UntilNewLine, ["aaa", "a  ", "aaa"]),
+        ];
+        for (clear_type, expected) in cases {
+            let mut cells = framebuffer(&lines);
+            let mut backend = FramebufferBackend::new(&mut cells, 3);
+            backend.set_cursor_position((1, 1)).unwrap();
+            backend.clear_region(clear_type).unwrap();
+            assert_eq!(
+                as_buffer(&cells, 3),
+                Buffer::with_lines(expected),
+                "{clear_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn clear_region_with_cursor_outside() {
+        let mut cells = framebuffer(&["aaa"]);
+        let mut backend = FramebufferBackend::new(&mut cells, 3);
+        backend.set_cursor_position((5, 5)).unwrap();
+        backend.clear_region(ClearType::AfterCursor).unwrap();
+        assert_eq!(as_buffer(&cells, 3), Buffer::with_lines(["aaa"]));
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    #[test]
+    fn scroll_region_up() {
+        let mut cells = framebuffer(&["aaa", "bbb", "ccc", "ddd"]);
+        let mut backend = FramebufferBackend::new(&mut cells, 3);
+        backend.scroll_region_up(1..3, 1).unwrap();
+        assert_eq!(
+            as_buffer(&cells, 3),
+            Buffer::with_lines(["aaa", "ccc", "   ", "ddd"])
+        );
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    #[test]
+    fn scroll_region_past_end() {
+        let mut cells = framebuffer(&["aaa", "bbb", "ccc"]);
+        let mut backend = FramebufferBackend::new(&mut cells, 3);
+        backend.scroll_region_up(1..5, 10).unwrap();
+        assert_eq!(
+            as_buffer(&cells, 3),
+            Buffer::with_lines(["aaa", "   ", "   "])
+        );
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    #[test]
+    fn scroll_region_down() {
+        let mut cells = framebuffer(&["aaa", "bbb", "ccc", "ddd"]);
+        let mut backend = FramebufferBackend::new(&mut cells, 3);
+        backend.scroll_region_down(1..4, 1).unwrap();
+        assert_eq!(
+            as_buffer(&cells, 3),
+            Buffer::with_lines(["aaa", "   ", "bbb", "ccc"])
+        );
+    }
+}
@@ -6,8 +6,9 @@ use alloc::vec;
 use core::fmt::{self, Write};
 use core::iter;
 
+use crate::accessibility::Semantics;
 use crate::backend::{Backend, ClearType, WindowSize};
-use crate::buffer::{Buffer, Cell, CellWidth};
+use crate::buffer::{Buffer, Cell, CellWidth, LineAttribute};
 use crate::layout::{Position, Rect, Size};
 
 /// A [`Backend`] implementation used for integration testing that renders to an memory buffer.
@@ -34,6 +35,8 @@ pub struct TestBackend {
     scrollback: Buffer,
     cursor: bool,
     pos: (u16, u16),
+    #[cfg_attr(feature = "serde", serde(skip))]
+    semantics: Semantics,
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -74,6 +77,7 @@ impl TestBackend {
             scrollback: Buffer::empty(Rect::new(0, 0, width, 0)),
             cursor: false,
             pos: (0, 0),
+            semantics: Semantics::new(),
         }
     }
 
@@ -96,6 +100,7 @@ impl TestBackend {
             scrollback,
             cursor: false,
             pos: (0, 0),
+            semantics: Semantics::new(),
         }
     }
 
@@ -117,6 +122,15 @@ impl TestBackend {
         }
     }
 
+    /// Returns the semantic description of the last frame that was passed to
+    /// [`Backend::update_semantics`].
+    ///
+    /// This is used to test the descriptions that widgets and applications register with
+    /// [`Frame::describe`](crate::terminal::Frame::describe).
+    pub const fn semantics(&self) -> &Semantics {
+        &self.semantics
+    }
+
     /// Returns a reference to the internal scrollback buffer of the `TestBackend`.
     ///
     /// The scrollback buffer represents the part of the screen that is currently hidden from view,
@@ -144,30 +158,32 @@ impl TestBackend {
 
     /// Asserts that the `TestBackend`'s buffer is equal to the expected buffer.
     ///
-    /// This is a shortcut for `assert_eq!(self.buffer(), &expected)`.
+    /// This is a shortcut for `assert_buffer_eq!(self.buffer(), expected)`.
     ///
     /// # Panics
     ///
-    /// When they are not equal, a panic occurs with a detailed error message showing the
-    /// differences between the expected and actual buffers.
-    #[expect(deprecated)]
+    /// When they are not equal, a panic occurs with a [`BufferDiffReport`] showing the differences
+    /// between the expected and actual buffers.
+    ///
+    /// [`BufferDiffReport`]: crate::buffer::BufferDiffReport
     #[track_caller]
     pub fn assert_buffer(&self, expected: &Buffer) {
-        // TODO: use assert_eq!()
-        crate::assert_buffer_eq!(&self.buffer, expected);
+        crate::assert_buffer_eq!(self.buffer, *expected);
     }
 
     /// Asserts that the `TestBackend`'s scrollback buffer is equal to the expected buffer.
     ///
-    /// This is a shortcut for `assert_eq!(self.scrollback(), &expected)`.
+    /// This is a shortcut for `assert_buffer_eq!(self.scrollback(), expected)`.
     ///
     /// # Panics
     ///
-    /// When they are not equal, a panic occurs with a detailed error message showing the
-    /// differences between the expected and actual buffers.
+    /// When they are not equal, a panic occurs with a [`BufferDiffReport`] showing the differences
+    /// between the expected and actual buffers.
+    ///
+    /// [`BufferDiffReport`]: crate::buffer::BufferDiffReport
     #[track_caller]
     pub fn assert_scrollback(&self, expected: &Buffer) {
-        assert_eq!(&self.scrollback, expected);
+        crate::assert_buffer_eq!(self.scrollback, *expected);
     }
 
     /// Asserts that the `TestBackend`'s scrollback buffer is empty.
@@ -183,6 +199,7 @@ impl TestBackend {
                 ..Rect::ZERO
             },
             content: vec![],
+            ..Buffer::default()
         };
         self.assert_scrollback(&expected);
     }
@@ -234,6 +251,17 @@ impl TestBackend {
         let actual = self.get_cursor_position().unwrap();
         assert_eq!(actual, position.into());
     }
+
+    /// Returns the end of the cells of the row at `y` that are shown, which is the middle of the
+    /// row if it has a double width or double height [`LineAttribute`].
+    fn visible_width(&self, y: u16) -> u16 {
+        let area = self.buffer.area;
+        if self.buffer.line_attribute(y) == LineAttribute::Single {
+            area.right()
+        } else {
+            area.x + area.width.div_ceil(2)
+        }
+    }
 }
 
 impl fmt::Display for TestBackend {
@@ -254,11 +282,27 @@ impl Backend for TestBackend {
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
         for (x, y, c) in content {
-            self.buffer[(x, y)] = c.clone();
+            if x < self.visible_width(y) {
+                self.buffer[(x, y)] = c.clone();
+            }
+        }
+        Ok(())
+    }
+
+    fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) -> Result<()> {
+        self.buffer.set_line_attribute(y, attribute);
+        // like a terminal, discard the cells that no longer fit in a row made double width
+        for x in self.visible_width(y)..self.buffer.area.right() {
+            self.buffer[(x, y)].reset();
         }
         Ok(())
     }
 
+    fn update_semantics(&mut self, semantics: &Semantics, _previous: &Semantics) -> Result<()> {
+        self.semantics.clone_from(semantics);
+        Ok(())
+    }
+
     fn hide_cursor(&mut self) -> Result<()> {
         self.cursor = false;
         Ok(())
@@ -370,10 +414,10 @@ impl Backend for TestBackend {
             width: 640,
             height: 480,
         };
-        Ok(WindowSize {
-            columns_rows: self.buffer.area.as_size(),
-            pixels: WINDOW_PIXEL_SIZE,
-        })
+        Ok(WindowSize::new(
+            self.buffer.area.as_size(),
+            WINDOW_PIXEL_SIZE,
+        ))
     }
 
     fn flush(&mut self) -> Result<()> {
@@ -480,6 +524,7 @@ mod tests {
                 scrollback: Buffer::empty(Rect::new(0, 0, 10, 0)),
                 cursor: false,
                 pos: (0, 0),
+                semantics: Semantics::new(),
             }
         );
     }
@@ -529,7 +574,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic = "assertion `left == right` failed"]
+    #[should_panic = "buffer areas not equal"]
     fn assert_scrollback_panics() {
         let backend = TestBackend::new(10, 2);
         backend.assert_scrollback_lines(["aaaaaaaaaa"; 2]);
@@ -963,6 +1008,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[0..10 * 5].to_vec(),
+                ..Buffer::default()
             },
             Buffer::with_lines([
                 "         6",
@@ -978,6 +1024,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[10 * 65530..10 * 65535].to_vec(),
+                ..Buffer::default()
             },
             Buffer::with_lines([
                 "     65536",
@@ -5,9 +5,23 @@ mod assert;
 mod buffer;
 mod cell;
 mod cell_width;
+mod compact;
 mod diff;
+mod line_attribute;
+mod report;
+mod selection;
+mod symbol;
+mod width_policy;
 
+#[doc(hidden)]
+pub use assert::assert_buffers_equal;
 pub use buffer::Buffer;
 pub use cell::{Cell, CellDiffOption};
 pub use cell_width::CellWidth;
+pub use compact::{CellRun, CompactBuffer};
 pub use diff::BufferDiff;
+pub(crate) use diff::RowUpdates;
+pub use line_attribute::LineAttribute;
+pub use report::BufferDiffReport;
+pub use selection::{Selection, SelectionMode};
+pub use width_policy::{AmbiguousWidth, EmojiPresentation, WidthPolicy};
@@ -1,44 +1,96 @@
+use crate::buffer::{Buffer, BufferDiffReport};
+
+/// The environment variable that names the directory where the buffers of failed assertions are
+/// written.
+#[cfg(feature = "std")]
+const FAILED_BUFFERS_DIR_VAR: &str = "RATATUI_FAILED_BUFFERS_DIR";
+
 /// Assert that two buffers are equal by comparing their areas and content.
 ///
 /// # Panics
-/// When the buffers differ this method panics and displays the differences similar to
-/// `assert_eq!()`.
-#[deprecated = "use `assert_eq!(&actual, &expected)`"]
+///
+/// When the buffers differ this method panics with a [`BufferDiffReport`] that shows the rows of
+/// both buffers side by side, marks the cells that differ (including cells that only differ in
+/// style) and lists their expected and actual values.
+///
+/// The changed cells are highlighted with ANSI colors, unless the `NO_COLOR` environment variable
+/// is set or the `std` feature is disabled.
+///
+/// When the `std` feature is enabled and the `RATATUI_FAILED_BUFFERS_DIR` environment variable is
+/// set, the expected and actual buffers and the report are also written to files in that
+/// directory, named after the current thread (which is the name of the test when run by `cargo
+/// test`), for inspection after the tests have run.
+///
+/// [`BufferDiffReport`]: crate::buffer::BufferDiffReport
 #[macro_export]
 macro_rules! assert_buffer_eq {
     ($actual_expr:expr, $expected_expr:expr) => {
-        match (&$actual_expr, &$expected_expr) {
-            (actual, expected) => {
-                assert!(
-                    actual.area == expected.area,
-                    "buffer areas not equal\nexpected: {expected:?}\nactual:   {actual:?}",
-                );
-                let nice_diff = expected
-                    .diff(actual)
-                    .into_iter()
-                    .enumerate()
-                    .map(|(i, (x, y, cell))| {
-                        let expected_cell = &expected[(x, y)];
-                        ::alloc::format!("{i}: at ({x}, {y})\n  expected: {expected_cell:?}\n  actual:   {cell:?}")
-                    })
-                    .collect::<::alloc::vec::Vec<::alloc::string::String>>()
-                    .join("\n");
-                assert!(
-                    nice_diff.is_empty(),
-                    "buffer contents not equal\nexpected: {expected:?}\nactual:   {actual:?}\ndiff:\n{nice_diff}",
-                );
-                // shouldn't get here, but this guards against future behavior
-                // that changes equality but not area or content
-                assert_eq!(
-                    actual, expected,
-                    "buffers are not equal in an unexpected way. Please open an issue about this."
-                );
-            }
-        }
+        $crate::buffer::assert_buffers_equal(&$actual_expr, &$expected_expr)
     };
 }
 
-#[expect(deprecated)]
+/// The implementation of [`assert_buffer_eq!`](crate::assert_buffer_eq).
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_buffers_equal(actual: &Buffer, expected: &Buffer) {
+    if actual == expected {
+        return;
+    }
+    let report = BufferDiffReport::new(actual, expected);
+    #[cfg(feature = "std")]
+    write_failed_buffers(actual, expected, &report);
+    panic!("{}", report.colored(use_colors()));
+}
+
+/// Returns whether the report of a failed assertion is colored.
+#[cfg(feature = "std")]
+fn use_colors() -> bool {
+    std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+/// Returns whether the report of a failed assertion is colored.
+#[cfg(not(feature = "std"))]
+const fn use_colors() -> bool {
+    false
+}
+
+/// Writes the buffers and the report of a failed assertion to the directory named by
+/// [`FAILED_BUFFERS_DIR_VAR`], if it is set.
+///
+/// Errors are printed rather than returned, so that they don't hide the failed assertion.
+#[cfg(feature = "std")]
+fn write_failed_buffers(actual: &Buffer, expected: &Buffer, report: &BufferDiffReport) {
+    use alloc::format;
+    use std::path::PathBuf;
+    use std::{eprintln, fs, thread};
+
+    let Some(dir) = std::env::var_os(FAILED_BUFFERS_DIR_VAR).map(PathBuf::from) else {
+        return;
+    };
+    let name = thread::current()
+        .name()
+        .unwrap_or("buffer")
+        .replace("::", ".")
+        .replace(|c: char| !c.is_alphanumeric() && c != '.' && c != '_', "-");
+    let result = fs::create_dir_all(&dir)
+        .and_then(|()| {
+            fs::write(
+                dir.join(format!("{name}.expected.txt")),
+                format!("{expected:?}"),
+            )
+        })
+        .and_then(|()| {
+            fs::write(
+                dir.join(format!("{name}.actual.txt")),
+                format!("{actual:?}"),
+            )
+        })
+        .and_then(|()| fs::write(dir.join(format!("{name}.diff.txt")), format!("{report}")));
+    if let Err(err) = result {
+        eprintln!("failed to write buffers to {}: {err}", dir.display());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::buffer::Buffer;
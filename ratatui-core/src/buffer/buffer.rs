@@ -1,13 +1,18 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
-use core::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut, Range};
 use core::{cmp, fmt};
 
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::buffer::{BufferDiff, Cell, CellWidth};
+use crate::backend::AnsiWriter;
+use crate::buffer::{BufferDiff, Cell, CellWidth, LineAttribute};
 use crate::layout::{Position, Rect};
 use crate::style::Style;
+#[cfg(feature = "underline-style")]
+use crate::style::UnderlineStyle;
 use crate::text::{Line, Span};
 
 /// A buffer that maps to the desired content of the terminal after the draw call
@@ -70,6 +75,12 @@ pub struct Buffer {
     /// The content of the buffer. The length of this Vec should always be equal to area.width *
     /// area.height
     pub content: Vec<Cell>,
+    /// The [`LineAttribute`] of each row that is not [`LineAttribute::Single`], keyed by the row
+    /// offset from the top of the buffer (not the absolute `y` coordinate).
+    ///
+    /// Use [`Buffer::line_attribute`] and [`Buffer::set_line_attribute`] to access it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) line_attributes: BTreeMap<u16, LineAttribute>,
 }
 
 impl Buffer {
@@ -84,7 +95,11 @@ impl Buffer {
     pub fn filled(area: Rect, cell: Cell) -> Self {
         let size = area.area() as usize;
         let content = vec![cell; size];
-        Self { area, content }
+        Self {
+            area,
+            content,
+            line_attributes: BTreeMap::new(),
+        }
     }
 
     /// Returns a Buffer containing the given lines
@@ -104,6 +119,48 @@ impl Buffer {
         buffer
     }
 
+    /// Returns a Buffer containing the given lines, styled by the ANSI escape sequences they
+    /// contain
+    ///
+    /// This makes the expected buffers of tests that check styles shorter and closer to what is
+    /// shown in a terminal: the SGR sequences (`ESC [ ... m`) of each line are parsed with
+    /// [`Text::from_ansi`] into the styles of the cells. Each line starts with the default style,
+    /// and lines that contain `\n` are split into several rows.
+    ///
+    /// This is only available when the `ansi-parser` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let buffer = Buffer::with_ansi_lines(["\x1b[1;31m>>\x1b[0m Item 1", "   Item 2"]);
+    /// assert_eq!(
+    ///     buffer,
+    ///     Buffer::with_lines([
+    ///         Line::from(vec![">>".red().bold(), " Item 1".into()]),
+    ///         Line::from("   Item 2"),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// [`Text::from_ansi`]: crate::text::Text::from_ansi
+    #[cfg(feature = "ansi-parser")]
+    #[must_use]
+    pub fn with_ansi_lines<Iter>(lines: Iter) -> Self
+    where
+        Iter: IntoIterator,
+        Iter::Item: AsRef<str>,
+    {
+        Self::with_lines(
+            lines
+                .into_iter()
+                .flat_map(|line| crate::text::Text::from_ansi(line.as_ref()).lines),
+        )
+    }
+
     /// Returns the content of the buffer as a slice
     pub fn content(&self) -> &[Cell] {
         &self.content
@@ -213,6 +270,84 @@ impl Buffer {
         self.content.get_mut(index)
     }
 
+    /// Returns the cells of the row at the given `y` coordinate, from left to right, or [`None`]
+    /// if the row is outside the `Buffer`'s area.
+    ///
+    /// The first cell of the row is at the `x` coordinate of the buffer's area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let buffer = Buffer::with_lines(["abc", "def"]);
+    /// let row = buffer.row(1).unwrap();
+    /// assert_eq!(row[0].symbol(), "d");
+    /// assert_eq!(buffer.row(2), None);
+    /// ```
+    #[must_use]
+    pub fn row(&self, y: u16) -> Option<&[Cell]> {
+        let index = self.index_of_opt(Position::new(self.area.x, y))?;
+        self.content
+            .get(index..index + usize::from(self.area.width))
+    }
+
+    /// Returns the cells of the row at the given `y` coordinate, from left to right, or [`None`]
+    /// if the row is outside the `Buffer`'s area.
+    ///
+    /// Changing many cells of a row through the slice is faster than indexing the buffer for each
+    /// cell, as the bounds are only checked once. To change only some columns of the row, use
+    /// [`Buffer::row_cells_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+    /// if let Some(row) = buffer.row_mut(1) {
+    ///     for cell in row {
+    ///         cell.set_symbol("x");
+    ///     }
+    /// }
+    /// assert_eq!(buffer, Buffer::with_lines(["   ", "xxx"]));
+    /// ```
+    #[must_use]
+    pub fn row_mut(&mut self, y: u16) -> Option<&mut [Cell]> {
+        let index = self.index_of_opt(Position::new(self.area.x, y))?;
+        self.content
+            .get_mut(index..index + usize::from(self.area.width))
+    }
+
+    /// Returns the cells of the row at the given `y` coordinate between the given `x`
+    /// coordinates, clipped to the `Buffer`'s area.
+    ///
+    /// The slice is empty when the row or the columns are outside the buffer's area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+    /// for cell in buffer.row_cells_mut(0, 3..10) {
+    ///     cell.set_symbol("x");
+    /// }
+    /// assert_eq!(buffer, Buffer::with_lines(["   xx"]));
+    /// ```
+    pub fn row_cells_mut(&mut self, y: u16, columns: Range<u16>) -> &mut [Cell] {
+        let left = self.area.left();
+        let start = usize::from(columns.start.clamp(left, self.area.right()) - left);
+        let end = usize::from(columns.end.clamp(left, self.area.right()) - left);
+        match self.row_mut(y) {
+            Some(row) if start < end => &mut row[start..end],
+            _ => &mut [],
+        }
+    }
+
     /// Returns the index in the `Vec<Cell>` for the given global (x, y) coordinates.
     ///
     /// Global coordinates are offset by the Buffer's area offset (`x`/`y`).
@@ -406,12 +541,39 @@ impl Buffer {
         let style = style.into();
         let area = self.area.intersection(area);
         for y in area.top()..area.bottom() {
-            for x in area.left()..area.right() {
-                self[(x, y)].set_style(style);
+            for cell in self.row_cells_mut(y, area.left()..area.right()) {
+                cell.set_style(style);
             }
         }
     }
 
+    /// Returns the [`LineAttribute`] of the row at the given `y` coordinate.
+    ///
+    /// Rows outside the buffer and rows without an explicit attribute are
+    /// [`LineAttribute::Single`].
+    pub fn line_attribute(&self, y: u16) -> LineAttribute {
+        y.checked_sub(self.area.y)
+            .and_then(|row| self.line_attributes.get(&row))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sets the [`LineAttribute`] of the row at the given `y` coordinate.
+    ///
+    /// This is used to render a row at double width or double height on terminals that support
+    /// it. See [`LineAttribute`] for more details. Rows outside the buffer are ignored.
+    pub fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) {
+        if y < self.area.top() || y >= self.area.bottom() {
+            return;
+        }
+        let row = y - self.area.y;
+        if attribute == LineAttribute::Single {
+            self.line_attributes.remove(&row);
+        } else {
+            self.line_attributes.insert(row, attribute);
+        }
+    }
+
     /// Resize the buffer so that the mapped area matches the given area and that the buffer
     /// length is equal to area.width * area.height
     pub fn resize(&mut self, area: Rect) {
@@ -421,14 +583,16 @@ impl Buffer {
         } else {
             self.content.resize(length, Cell::EMPTY);
         }
+        self.line_attributes.retain(|&row, _| row < area.height);
         self.area = area;
     }
 
-    /// Reset all cells in the buffer
+    /// Reset all cells and line attributes in the buffer
     pub fn reset(&mut self) {
         for cell in &mut self.content {
             cell.reset();
         }
+        self.line_attributes.clear();
     }
 
     /// Merge an other buffer into this one
@@ -457,6 +621,17 @@ impl Buffer {
             let k = ((y - area.y) * area.width + x - area.x) as usize;
             self.content[k] = other.content[i].clone();
         }
+
+        // Line attributes are keyed by row offset, so shift them to the new origin
+        let shift = self.area.y - area.y;
+        self.line_attributes = core::mem::take(&mut self.line_attributes)
+            .into_iter()
+            .map(|(row, attribute)| (row + shift, attribute))
+            .collect();
+        let shift = other.area.y - area.y;
+        for (&row, &attribute) in &other.line_attributes {
+            self.line_attributes.insert(row + shift, attribute);
+        }
         self.area = area;
     }
 
@@ -506,6 +681,74 @@ impl Buffer {
     pub fn diff_iter<'prev, 'next>(&'prev self, other: &'next Self) -> BufferDiff<'prev, 'next> {
         BufferDiff::new(self, other)
     }
+
+    /// Returns the symbols of the buffer as text, one line per row.
+    ///
+    /// Each row is followed by a newline and keeps its trailing spaces, so the text has the shape
+    /// of the buffer. The cells hidden by multi-width symbols are skipped. This is useful to copy
+    /// the screen to the clipboard, or to compare it with a golden file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    ///
+    /// let buffer = Buffer::with_lines(["ab", "コ"]);
+    /// assert_eq!(buffer.to_plain_text(), "ab\nコ\n");
+    /// ```
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::with_capacity(self.content.len() + self.area.height as usize);
+        for row in self.rows_visible_cells() {
+            for cell in row {
+                text.push_str(cell.symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Returns the symbols of the buffer as text styled with ANSI escape sequences, one line per
+    /// row.
+    ///
+    /// This is the same as [`Buffer::to_plain_text`], with SGR escape sequences that set the colors
+    /// and modifiers of the cells. The style is reset at the end of each row, so the lines can be
+    /// printed one by one, or parsed back with `Buffer::with_ansi_lines` (requires the
+    /// `ansi-parser` feature).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let buffer = Buffer::with_lines([Line::from(vec!["a".red(), "b".into()])]);
+    /// assert_eq!(buffer.to_ansi_text(), "\x1b[0;31ma\x1b[0mb\n");
+    /// ```
+    pub fn to_ansi_text(&self) -> String {
+        let mut ansi = AnsiWriter::default();
+        for row in self.rows_visible_cells() {
+            for cell in row {
+                ansi.write_cell(cell);
+            }
+            ansi.reset_style();
+            ansi.output.push('\n');
+        }
+        ansi.output
+    }
+
+    /// Returns the cells of each row, without the cells hidden by multi-width symbols.
+    fn rows_visible_cells(&self) -> impl Iterator<Item = impl Iterator<Item = &Cell>> {
+        let width = usize::from(self.area.width).max(1);
+        self.content.chunks(width).map(|row| {
+            let mut skip: u16 = 0;
+            row.iter().filter(move |cell| {
+                let visible = skip == 0;
+                skip = cmp::max(skip, cell.cell_width()).saturating_sub(1);
+                visible
+            })
+        })
+    }
 }
 
 impl<P: Into<Position>> Index<P> for Buffer {
@@ -584,6 +827,10 @@ impl fmt::Debug for Buffer {
         f.write_str(",\n    content: [\n")?;
         let mut last_style = None;
         let mut styles = vec![];
+        #[cfg(feature = "underline-style")]
+        let mut last_underline_style = UnderlineStyle::Single;
+        #[cfg(feature = "underline-style")]
+        let mut underline_styles = vec![];
         for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
             let mut overwritten = vec![];
             let mut skip: u16 = 0;
@@ -612,6 +859,12 @@ impl fmt::Debug for Buffer {
                         styles.push((x, y, c.fg, c.bg, c.modifier));
                     }
                 }
+                // underline styles are listed separately, as they are rarely set
+                #[cfg(feature = "underline-style")]
+                if c.underline_style != last_underline_style {
+                    last_underline_style = c.underline_style;
+                    underline_styles.push((x, y, c.underline_style));
+                }
             }
             f.write_str("\",")?;
             if !overwritten.is_empty() {
@@ -621,7 +874,14 @@ impl fmt::Debug for Buffer {
             }
             f.write_str("\n")?;
         }
-        f.write_str("    ],\n    styles: [\n")?;
+        f.write_str("    ],\n")?;
+        if !self.line_attributes.is_empty() {
+            f.write_fmt(format_args!(
+                "    line_attributes: {:?},\n",
+                self.line_attributes
+            ))?;
+        }
+        f.write_str("    styles: [\n")?;
         for s in styles {
             #[cfg(feature = "underline-color")]
             f.write_fmt(format_args!(
@@ -634,7 +894,18 @@ impl fmt::Debug for Buffer {
                 s.0, s.1, s.2, s.3, s.4
             ))?;
         }
-        f.write_str("    ]\n}")?;
+        f.write_str("    ]")?;
+        #[cfg(feature = "underline-style")]
+        if !underline_styles.is_empty() {
+            f.write_str(",\n    underline_styles: [\n")?;
+            for (x, y, underline_style) in underline_styles {
+                f.write_fmt(format_args!(
+                    "        x: {x}, y: {y}, underline_style: {underline_style:?},\n"
+                ))?;
+            }
+            f.write_str("    ]")?;
+        }
+        f.write_str("\n}")?;
         Ok(())
     }
 }
@@ -798,6 +1069,40 @@ mod tests {
         assert_eq!(buf.cell_mut(Position::new(10, 10)), None);
     }
 
+    #[test]
+    fn row() {
+        let mut buf = Buffer::with_lines(["Hello", "World"]);
+        buf.area = Rect::new(2, 3, 5, 2);
+
+        let row = buf.row(4).unwrap();
+        assert_eq!(row.len(), 5);
+        assert_eq!(row[0].symbol(), "W");
+        assert_eq!(buf.row(2), None);
+        assert_eq!(buf.row(5), None);
+
+        buf.row_mut(3).unwrap()[4].set_symbol("!");
+        assert_eq!(buf[(6, 3)].symbol(), "!");
+        assert_eq!(buf.row_mut(5), None);
+    }
+
+    #[rstest]
+    #[case::inside(3..5, "H12lo")]
+    #[case::clipped_left(0..4, "12llo")]
+    #[case::clipped_right(5..10, "Hel12")]
+    #[case::outside(8..10, "Hello")]
+    #[case::empty(4..4, "Hello")]
+    fn row_cells_mut(#[case] columns: Range<u16>, #[case] expected: &str) {
+        let mut buf = Buffer::with_lines(["Hello"]);
+        buf.area.x = 2;
+        for (cell, symbol) in buf.row_cells_mut(0, columns).iter_mut().zip(["1", "2"]) {
+            cell.set_symbol(symbol);
+        }
+        let mut expected = Buffer::with_lines([expected]);
+        expected.area.x = 2;
+        assert_eq!(buf, expected);
+        assert!(buf.row_cells_mut(1, 0..10).is_empty());
+    }
+
     #[test]
     fn index() {
         let buf = Buffer::with_lines(["Hello", "World"]);
@@ -1348,6 +1653,51 @@ mod tests {
         assert_eq!(one, expected);
     }
 
+    #[test]
+    fn line_attribute() {
+        let mut buffer = Buffer::empty(Rect::new(0, 2, 3, 3));
+        buffer.set_line_attribute(3, LineAttribute::DoubleWidth);
+        // rows outside the buffer are ignored
+        buffer.set_line_attribute(1, LineAttribute::DoubleWidth);
+        buffer.set_line_attribute(5, LineAttribute::DoubleWidth);
+
+        assert_eq!(buffer.line_attribute(2), LineAttribute::Single);
+        assert_eq!(buffer.line_attribute(3), LineAttribute::DoubleWidth);
+        assert_eq!(buffer.line_attribute(1), LineAttribute::Single);
+        assert_eq!(buffer.line_attributes.len(), 1);
+
+        buffer.set_line_attribute(3, LineAttribute::Single);
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 2, 3, 3)));
+    }
+
+    #[test]
+    fn line_attributes_reset_and_resize() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        buffer.set_line_attribute(0, LineAttribute::DoubleHeightTop);
+        buffer.set_line_attribute(2, LineAttribute::DoubleWidth);
+
+        buffer.resize(Rect::new(0, 0, 3, 2));
+        assert_eq!(buffer.line_attribute(0), LineAttribute::DoubleHeightTop);
+        assert_eq!(buffer.line_attribute(2), LineAttribute::Single);
+
+        buffer.reset();
+        assert_eq!(buffer.line_attribute(0), LineAttribute::Single);
+    }
+
+    #[test]
+    fn merge_line_attributes() {
+        let mut one = Buffer::empty(Rect::new(0, 2, 2, 2));
+        one.set_line_attribute(2, LineAttribute::DoubleWidth);
+        let mut two = Buffer::empty(Rect::new(0, 0, 2, 2));
+        two.set_line_attribute(1, LineAttribute::DoubleHeightBottom);
+
+        one.merge(&two);
+
+        assert_eq!(one.line_attribute(0), LineAttribute::Single);
+        assert_eq!(one.line_attribute(1), LineAttribute::DoubleHeightBottom);
+        assert_eq!(one.line_attribute(2), LineAttribute::DoubleWidth);
+    }
+
     #[rstest]
     #[case(CellDiffOption::None, CellDiffOption::Skip, [CellDiffOption::None, CellDiffOption::None, CellDiffOption::Skip, CellDiffOption::Skip, CellDiffOption::Skip, CellDiffOption::Skip])]
     #[case(CellDiffOption::Skip, CellDiffOption::None, [CellDiffOption::Skip, CellDiffOption::Skip, CellDiffOption::None, CellDiffOption::None, CellDiffOption::None, CellDiffOption::None])]
@@ -1396,6 +1746,55 @@ mod tests {
         assert_eq!(buf, Buffer::with_lines(["foo".red(), "bar".blue()]));
     }
 
+    #[test]
+    fn to_plain_text_skips_hidden_cells() {
+        let buffer = Buffer::with_lines(["aコb", "    "]);
+        assert_eq!(buffer.to_plain_text(), "aコb\n    \n");
+        assert_eq!(Buffer::empty(Rect::ZERO).to_plain_text(), "");
+    }
+
+    #[test]
+    fn to_ansi_text() {
+        let mut buffer = Buffer::with_lines(["ab", "cd"]);
+        buffer.set_style(Rect::new(1, 0, 1, 2), Style::new().bold().on_blue());
+        assert_eq!(
+            buffer.to_ansi_text(),
+            "a\x1b[0;1;44mb\x1b[0m\nc\x1b[0;1;44md\x1b[0m\n"
+        );
+    }
+
+    #[cfg(feature = "ansi-parser")]
+    #[test]
+    fn to_ansi_text_round_trips() {
+        let mut buffer = Buffer::with_lines(["ab", "cd"]);
+        buffer.set_style(Rect::new(0, 0, 1, 2), Style::new().red().italic());
+        let text = buffer.to_ansi_text();
+        assert_eq!(Buffer::with_ansi_lines(text.lines()), buffer);
+    }
+
+    #[cfg(feature = "ansi-parser")]
+    #[test]
+    fn with_ansi_lines() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        buf.set_string(0, 0, "foo", Style::new().red().bold());
+        buf.set_string(3, 0, "bar", Style::new());
+        buf.set_string(0, 1, "baz", Style::new().on_blue());
+        buf.set_string(0, 2, "qux", Style::new());
+        assert_eq!(
+            buf,
+            Buffer::with_ansi_lines([
+                "\x1b[1;31mfoo\x1b[0mbar",
+                // each line starts with the default style
+                "\x1b[44mbaz",
+                "qux",
+            ])
+        );
+        assert_eq!(
+            buf,
+            Buffer::with_ansi_lines(["\x1b[1;31mfoo\x1b[0mbar\n\x1b[44mbaz\x1b[0m\nqux"])
+        );
+    }
+
     #[test]
     fn control_sequence_rendered_full() {
         let text = "I \x1b[0;36mwas\x1b[0m here!";
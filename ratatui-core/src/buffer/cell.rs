@@ -1,8 +1,9 @@
 use core::num::NonZeroU16;
 
-use compact_str::CompactString;
-
 use crate::buffer::cell_width::CellWidth;
+use crate::buffer::symbol::Symbol;
+#[cfg(feature = "underline-style")]
+use crate::style::UnderlineStyle;
 use crate::style::{Color, Modifier, Style};
 use crate::symbols::merge::MergeStrategy;
 
@@ -39,11 +40,12 @@ pub struct Cell {
     ///
     /// This accepts unicode grapheme clusters which might take up more than one cell.
     ///
-    /// This is a [`CompactString`] which is a wrapper around [`String`] that uses a small inline
-    /// buffer for short strings.
+    /// Symbols of up to 16 bytes, which covers every character and almost every grapheme
+    /// cluster, are stored inline so that cells are cheap to clone and compare. Longer symbols
+    /// are stored on the heap.
     ///
     /// See <https://github.com/ratatui/ratatui/pull/601> for more information.
-    symbol: Option<CompactString>,
+    symbol: Symbol,
 
     /// The foreground color of the cell.
     pub fg: Color,
@@ -55,6 +57,10 @@ pub struct Cell {
     #[cfg(feature = "underline-color")]
     pub underline_color: Color,
 
+    /// The underline style of the cell.
+    #[cfg(feature = "underline-style")]
+    pub underline_style: UnderlineStyle,
+
     /// The modifier of the cell.
     pub modifier: Modifier,
 
@@ -75,11 +81,13 @@ impl Cell {
     /// An empty `Cell`
     #[allow(deprecated)]
     pub const EMPTY: Self = Self {
-        symbol: None,
+        symbol: Symbol::Empty,
         fg: Color::Reset,
         bg: Color::Reset,
         #[cfg(feature = "underline-color")]
         underline_color: Color::Reset,
+        #[cfg(feature = "underline-style")]
+        underline_style: UnderlineStyle::Single,
         modifier: Modifier::empty(),
         diff_option: CellDiffOption::None,
         skip: false,
@@ -87,13 +95,10 @@ impl Cell {
 
     /// Creates a new `Cell` with the given symbol.
     ///
-    /// This works at compile time and puts the symbol onto the stack. Fails to build when the
-    /// symbol doesn't fit onto the stack and requires to be placed on the heap. Use
-    /// `Self::default().set_symbol()` in that case. See [`CompactString::const_new`] for more
-    /// details on this.
+    /// This works at compile time and borrows the symbol, which doesn't need to be copied.
     pub const fn new(symbol: &'static str) -> Self {
         Self {
-            symbol: Some(CompactString::const_new(symbol)),
+            symbol: Symbol::const_new(symbol),
             ..Self::EMPTY
         }
     }
@@ -103,7 +108,7 @@ impl Cell {
     /// If the cell has no symbol, returns a single space character.
     #[must_use]
     pub fn symbol(&self) -> &str {
-        self.symbol.as_ref().map_or(" ", |s| s.as_str())
+        self.symbol.as_str()
     }
 
     /// Merges the symbol of the cell with the one already on the cell, using the provided
@@ -144,17 +149,18 @@ impl Cell {
     /// [border collapsing]: https://ratatui.rs/recipes/layout/collapse-borders/
     /// [Box Drawing Unicode block]: https://en.wikipedia.org/wiki/Box_Drawing
     pub fn merge_symbol(&mut self, symbol: &str, strategy: MergeStrategy) -> &mut Self {
-        let merged_symbol = self
-            .symbol
-            .as_ref()
-            .map_or(symbol, |s| strategy.merge(s, symbol));
-        self.symbol = Some(CompactString::new(merged_symbol));
+        let merged_symbol = if self.symbol.is_empty() {
+            symbol
+        } else {
+            strategy.merge(self.symbol.as_str(), symbol)
+        };
+        self.symbol = Symbol::new(merged_symbol);
         self
     }
 
     /// Sets the symbol of the cell.
     pub fn set_symbol(&mut self, symbol: &str) -> &mut Self {
-        self.symbol = Some(CompactString::new(symbol));
+        self.symbol = Symbol::new(symbol);
         self
     }
 
@@ -162,14 +168,14 @@ impl Cell {
     ///
     /// This is particularly useful for adding zero-width characters to the cell.
     pub(crate) fn append_symbol(&mut self, symbol: &str) -> &mut Self {
-        self.symbol.get_or_insert_default().push_str(symbol);
+        self.symbol.push_str(symbol);
         self
     }
 
     /// Sets the symbol of the cell to a single character.
     pub fn set_char(&mut self, ch: char) -> &mut Self {
         let mut buf = [0; 4];
-        self.symbol = Some(CompactString::new(ch.encode_utf8(&mut buf)));
+        self.symbol = Symbol::new(ch.encode_utf8(&mut buf));
         self
     }
 
@@ -201,6 +207,10 @@ impl Cell {
         if let Some(c) = style.underline_color {
             self.underline_color = c;
         }
+        #[cfg(feature = "underline-style")]
+        if let Some(underline_style) = style.underline_style {
+            self.underline_style = underline_style;
+        }
         self.modifier.insert(style.add_modifier);
         self.modifier.remove(style.sub_modifier);
         self
@@ -214,6 +224,8 @@ impl Cell {
             bg: Some(self.bg),
             #[cfg(feature = "underline-color")]
             underline_color: Some(self.underline_color),
+            #[cfg(feature = "underline-style")]
+            underline_style: Some(self.underline_style),
             add_modifier: self.modifier,
             sub_modifier: Modifier::empty(),
         }
@@ -257,19 +269,25 @@ impl PartialEq for Cell {
     /// single space symbol. This is to ensure that empty cells are treated uniformly,
     /// regardless of how they were created
     fn eq(&self, other: &Self) -> bool {
-        // Treat None and Some(" ") as equal
-        let symbols_eq = self.symbol() == other.symbol();
+        // Treat no symbol and a space as equal
+        let symbols_eq = self.symbol == other.symbol;
 
         #[cfg(feature = "underline-color")]
         let underline_color_eq = self.underline_color == other.underline_color;
         #[cfg(not(feature = "underline-color"))]
         let underline_color_eq = true;
 
+        #[cfg(feature = "underline-style")]
+        let underline_style_eq = self.underline_style == other.underline_style;
+        #[cfg(not(feature = "underline-style"))]
+        let underline_style_eq = true;
+
         #[allow(deprecated)]
         let skip_eq = self.skip == other.skip;
 
         symbols_eq
             && underline_color_eq
+            && underline_style_eq
             && skip_eq
             && self.fg == other.fg
             && self.bg == other.bg
@@ -283,14 +301,16 @@ impl Eq for Cell {}
 impl core::hash::Hash for Cell {
     /// Hashes the cell.
     ///
-    /// This treats symbols with Some(" ") as equal to None, so that empty cells are
+    /// This treats a space symbol as equal to no symbol, so that empty cells are
     /// treated uniformly, regardless of how they were created.
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-        self.symbol().hash(state);
+        self.symbol.hash(state);
         self.fg.hash(state);
         self.bg.hash(state);
         #[cfg(feature = "underline-color")]
         self.underline_color.hash(state);
+        #[cfg(feature = "underline-style")]
+        self.underline_style.hash(state);
         self.modifier.hash(state);
         self.diff_option.hash(state);
         #[allow(deprecated)]
@@ -312,6 +332,8 @@ impl CellWidth for Cell {
     fn cell_width(&self) -> u16 {
         match self.diff_option {
             CellDiffOption::ForcedWidth(w) => w.get(),
+            // skips the width lookup for the most common symbols
+            _ if self.symbol.is_printable_ascii() => 1,
             _ => self.symbol().cell_width(),
         }
     }
@@ -328,11 +350,13 @@ mod tests {
         assert_eq!(
             cell,
             Cell {
-                symbol: Some(CompactString::const_new("あ")),
+                symbol: Symbol::const_new("あ"),
                 fg: Color::Reset,
                 bg: Color::Reset,
                 #[cfg(feature = "underline-color")]
                 underline_color: Color::Reset,
+                #[cfg(feature = "underline-style")]
+                underline_style: UnderlineStyle::Single,
                 modifier: Modifier::empty(),
                 diff_option: CellDiffOption::None,
                 skip: false,
@@ -392,6 +416,20 @@ mod tests {
         assert_eq!(cell.bg, Color::Blue);
     }
 
+    #[cfg(feature = "underline-style")]
+    #[test]
+    fn set_underline_style() {
+        let mut cell = Cell::EMPTY;
+        cell.set_style(Style::new().underline_style(UnderlineStyle::Dotted));
+        assert_eq!(cell.underline_style, UnderlineStyle::Dotted);
+        assert_eq!(cell.style().underline_style, Some(UnderlineStyle::Dotted));
+        assert_ne!(cell, Cell::EMPTY);
+
+        // styles without an underline style keep the current one
+        cell.set_style(Style::new().red());
+        assert_eq!(cell.underline_style, UnderlineStyle::Dotted);
+    }
+
     #[test]
     fn set_skip() {
         let mut cell = Cell::EMPTY;
@@ -430,6 +468,8 @@ mod tests {
                 bg: Some(Color::Reset),
                 #[cfg(feature = "underline-color")]
                 underline_color: Some(Color::Reset),
+                #[cfg(feature = "underline-style")]
+                underline_style: Some(UnderlineStyle::Single),
                 add_modifier: Modifier::empty(),
                 sub_modifier: Modifier::empty(),
             }
@@ -1,4 +1,4 @@
-use unicode_width::UnicodeWidthStr;
+use crate::buffer::WidthPolicy;
 
 /// Halfwidth Katakana Voiced Sound Mark (dakuten).
 const HALFWIDTH_KATAKANA_VOICED_SOUND_MARK: char = '\u{FF9E}';
@@ -10,9 +10,9 @@ const HALFWIDTH_KATAKANA_SEMI_VOICED_SOUND_MARK: char = '\u{FF9F}';
 /// This trait provides a unified way to compute cell widths for both string content
 /// and [`Cell`](super::Cell)s:
 ///
-/// - **`str`**: width is derived from [`UnicodeWidthStr`], with a fast path for single-byte ASCII
-///   characters and a terminal-compatibility adjustment for halfwidth katakana dakuten/handakuten
-///   (`U+FF9E`/`U+FF9F`).
+/// - **`str`**: width is derived from the current [`WidthPolicy`], with a fast path for single-byte
+///   ASCII characters and a terminal-compatibility adjustment for halfwidth katakana
+///   dakuten/handakuten (`U+FF9E`/`U+FF9F`).
 /// - **[`Cell`](super::Cell)**: returns the
 ///   [`CellDiffOption::ForcedWidth`](super::CellDiffOption::ForcedWidth) when set, otherwise falls
 ///   back to the width of the cell's symbol.
@@ -39,7 +39,7 @@ impl CellWidth for str {
             );
             1
         } else {
-            let width = self.width() as u16;
+            let width = WidthPolicy::current().str_width(self) as u16;
             width.saturating_add(count_halfwidth_sound_marks(self))
         }
     }
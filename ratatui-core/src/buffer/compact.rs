@@ -0,0 +1,164 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::buffer::{Buffer, Cell, LineAttribute};
+use crate::layout::Rect;
+
+/// A run-length encoded representation of a [`Buffer`].
+///
+/// Consecutive cells that are equal are stored once, together with the number of times they are
+/// repeated. As most of a rendered frame consists of runs of blank or identically styled cells,
+/// this is much smaller than the [`Buffer`] itself, which makes it suited for storing frame
+/// snapshots (e.g. with the `serde` feature), diffing them and replaying them in external test
+/// tooling.
+///
+/// Convert a [`Buffer`] with [`CompactBuffer::from`] and back with [`Buffer::from`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::buffer::{Buffer, CompactBuffer};
+/// use ratatui_core::layout::Rect;
+/// use ratatui_core::style::Style;
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+/// buffer.set_string(0, 0, "ab", Style::new());
+///
+/// let compact = CompactBuffer::from(&buffer);
+/// assert_eq!(compact.runs.len(), 3);
+/// assert_eq!(compact.runs[2].count, 18);
+/// assert_eq!(Buffer::from(compact), buffer);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompactBuffer {
+    /// The area represented by the buffer.
+    pub area: Rect,
+    /// The cells of the buffer, from left to right and top to bottom.
+    pub runs: Vec<CellRun>,
+    /// The [`LineAttribute`] of each row that is not [`LineAttribute::Single`], keyed by the row
+    /// offset from the top of the buffer.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub line_attributes: BTreeMap<u16, LineAttribute>,
+}
+
+/// A cell that is repeated `count` times in a [`CompactBuffer`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellRun {
+    /// The number of consecutive cells in the run.
+    pub count: usize,
+    /// The cell that is repeated.
+    pub cell: Cell,
+}
+
+impl From<&Buffer> for CompactBuffer {
+    fn from(buffer: &Buffer) -> Self {
+        let mut runs: Vec<CellRun> = Vec::new();
+        for cell in &buffer.content {
+            match runs.last_mut() {
+                Some(run) if run.cell == *cell => run.count += 1,
+                _ => runs.push(CellRun {
+                    count: 1,
+                    cell: cell.clone(),
+                }),
+            }
+        }
+        Self {
+            area: buffer.area,
+            runs,
+            line_attributes: buffer.line_attributes.clone(),
+        }
+    }
+}
+
+impl From<Buffer> for CompactBuffer {
+    fn from(buffer: Buffer) -> Self {
+        Self::from(&buffer)
+    }
+}
+
+impl From<CompactBuffer> for Buffer {
+    /// Expands the runs into a [`Buffer`].
+    ///
+    /// If the runs don't cover the whole area, the remaining cells are empty. Cells beyond the
+    /// area are ignored.
+    fn from(compact: CompactBuffer) -> Self {
+        let size = compact.area.area() as usize;
+        let mut content = Vec::with_capacity(size);
+        for run in compact.runs {
+            let count = run.count.min(size - content.len());
+            content.extend(core::iter::repeat_n(run.cell, count));
+        }
+        content.resize(size, Cell::EMPTY);
+        Self {
+            area: compact.area,
+            content,
+            line_attributes: compact.line_attributes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::style::{Color, Style};
+
+    #[test]
+    fn round_trip() {
+        let mut buffer = Buffer::empty(Rect::new(2, 3, 4, 3));
+        buffer.set_string(2, 3, "aab", Style::new().fg(Color::Red));
+        buffer.set_string(2, 4, "cc", Style::new());
+        buffer.set_line_attribute(5, LineAttribute::DoubleWidth);
+
+        let compact = CompactBuffer::from(&buffer);
+        let counts: Vec<usize> = compact.runs.iter().map(|run| run.count).collect();
+        assert_eq!(counts, [2, 1, 1, 2, 6]);
+        assert_eq!(Buffer::from(compact), buffer);
+    }
+
+    #[test]
+    fn mismatched_runs() {
+        let area = Rect::new(0, 0, 2, 2);
+        let short = CompactBuffer {
+            area,
+            runs: vec![CellRun {
+                count: 1,
+                cell: Cell::new("x"),
+            }],
+            ..CompactBuffer::default()
+        };
+        assert_eq!(Buffer::from(short), Buffer::with_lines(["x ", "  "]));
+
+        let long = CompactBuffer {
+            area,
+            runs: vec![CellRun {
+                count: 10,
+                cell: Cell::new("x"),
+            }],
+            ..CompactBuffer::default()
+        };
+        assert_eq!(Buffer::from(long), Buffer::with_lines(["xx", "xx"]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut buffer = Buffer::with_lines(["ab", "  "]);
+        buffer[(0, 0)].set_fg(Color::Blue);
+
+        let compact = CompactBuffer::from(&buffer);
+        let json = serde_json::to_string(&compact).unwrap();
+        let deserialized: CompactBuffer = serde_json::from_str(&json).unwrap();
+        assert_eq!(Buffer::from(deserialized), buffer);
+
+        let json = serde_json::to_string(&buffer).unwrap();
+        let deserialized: Buffer = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, buffer);
+    }
+}
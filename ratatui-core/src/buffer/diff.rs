@@ -43,7 +43,8 @@ const VISIBLE_ON_BLANK: Modifier = Modifier::REVERSED
     .union(Modifier::UNDERLINED)
     .union(Modifier::SLOW_BLINK)
     .union(Modifier::RAPID_BLINK)
-    .union(Modifier::CROSSED_OUT);
+    .union(Modifier::CROSSED_OUT)
+    .union(Modifier::OVERLINED);
 
 impl<'prev, 'next> BufferDiff<'prev, 'next> {
     /// Creates a new iterator over the differences between `prev` and `next` terminal cells.
@@ -198,6 +199,57 @@ impl<'next> Iterator for BufferDiff<'_, 'next> {
     }
 }
 
+/// An iterator over all the cells of some rows of a buffer, used to redraw whole rows.
+///
+/// Yields `(x, y, &Cell)` tuples for each cell of the rows, in order. The trailing columns of
+/// multi-width characters and skipped cells are not yielded.
+#[derive(Debug)]
+pub(crate) struct RowUpdates<'a> {
+    /// The buffer whose cells are yielded.
+    buffer: &'a Buffer,
+    /// The rows that are left to yield after the current row.
+    rows: alloc::vec::IntoIter<u16>,
+    /// The current row.
+    y: Option<u16>,
+    /// The column of the next cell in the current row.
+    x: u16,
+}
+
+impl<'a> RowUpdates<'a> {
+    /// Creates an iterator over the cells of the given rows, which must be inside the buffer.
+    pub(crate) fn new(buffer: &'a Buffer, rows: alloc::vec::Vec<u16>) -> Self {
+        let mut rows = rows.into_iter();
+        Self {
+            buffer,
+            y: rows.next(),
+            rows,
+            x: buffer.area.left(),
+        }
+    }
+}
+
+impl<'a> Iterator for RowUpdates<'a> {
+    type Item = (u16, u16, &'a Cell);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let area = self.buffer.area;
+        loop {
+            let y = self.y?;
+            if self.x >= area.right() {
+                self.y = self.rows.next();
+                self.x = area.left();
+                continue;
+            }
+            let x = self.x;
+            let cell = &self.buffer[(x, y)];
+            self.x = x.saturating_add(cell.cell_width().max(1));
+            if !is_skip(cell) {
+                return Some((x, y, cell));
+            }
+        }
+    }
+}
+
 /// Returns `true` if this cell should be skipped during diffing.
 #[allow(deprecated)]
 const fn is_skip(cell: &Cell) -> bool {
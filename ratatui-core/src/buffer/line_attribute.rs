@@ -0,0 +1,101 @@
+use strum::{Display, EnumString};
+
+/// The rendering mode of an entire row of the terminal.
+///
+/// Terminals that implement the DEC line attribute sequences can render a row at double width
+/// (DECDWL) or at double width and double height (DECDHL). Double-height text is drawn using two
+/// consecutive rows containing the same text: the first row is marked with
+/// [`DoubleHeightTop`](Self::DoubleHeightTop) and the second with
+/// [`DoubleHeightBottom`](Self::DoubleHeightBottom).
+///
+/// Every cell in a double-width or double-height row takes up two columns on screen, so only the
+/// first half of the row's cells is visible. Widgets that render into such rows should limit
+/// themselves to `area.width / 2` columns.
+///
+/// Line attributes are set per row on a [`Buffer`] using [`Buffer::set_line_attribute`] and are
+/// sent to the terminal by the [`Terminal`] when they change between frames. Backends that don't
+/// support line attributes ignore them, in which case the row is rendered normally.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::buffer::{Buffer, LineAttribute};
+/// use ratatui_core::layout::Rect;
+/// use ratatui_core::style::Style;
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+/// buffer.set_string(0, 0, "Banner", Style::new());
+/// buffer.set_string(0, 1, "Banner", Style::new());
+/// buffer.set_line_attribute(0, LineAttribute::DoubleHeightTop);
+/// buffer.set_line_attribute(1, LineAttribute::DoubleHeightBottom);
+///
+/// assert_eq!(buffer.line_attribute(1), LineAttribute::DoubleHeightBottom);
+/// ```
+///
+/// [`Buffer`]: crate::buffer::Buffer
+/// [`Buffer::set_line_attribute`]: crate::buffer::Buffer::set_line_attribute
+/// [`Terminal`]: crate::terminal::Terminal
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineAttribute {
+    /// A normal single-width, single-height row (DECSWL).
+    #[default]
+    Single,
+    /// A double-width, single-height row (DECDWL).
+    DoubleWidth,
+    /// The top half of a double-width, double-height row (DECDHL).
+    DoubleHeightTop,
+    /// The bottom half of a double-width, double-height row (DECDHL).
+    DoubleHeightBottom,
+}
+
+impl LineAttribute {
+    /// Returns the escape sequence that applies this attribute to the row containing the cursor.
+    ///
+    /// This is useful for backends that write escape sequences directly.
+    pub const fn escape_sequence(self) -> &'static str {
+        match self {
+            Self::Single => "\x1b#5",
+            Self::DoubleWidth => "\x1b#6",
+            Self::DoubleHeightTop => "\x1b#3",
+            Self::DoubleHeightBottom => "\x1b#4",
+        }
+    }
+
+    /// Returns true if the row is rendered at double width.
+    pub const fn is_double_width(self) -> bool {
+        !matches!(self, Self::Single)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(LineAttribute::Single, "\x1b#5")]
+    #[case(LineAttribute::DoubleWidth, "\x1b#6")]
+    #[case(LineAttribute::DoubleHeightTop, "\x1b#3")]
+    #[case(LineAttribute::DoubleHeightBottom, "\x1b#4")]
+    fn escape_sequence(#[case] attribute: LineAttribute, #[case] expected: &str) {
+        assert_eq!(attribute.escape_sequence(), expected);
+    }
+
+    #[test]
+    fn is_double_width() {
+        assert!(!LineAttribute::Single.is_double_width());
+        assert!(LineAttribute::DoubleWidth.is_double_width());
+        assert!(LineAttribute::DoubleHeightTop.is_double_width());
+        assert!(LineAttribute::DoubleHeightBottom.is_double_width());
+    }
+
+    #[test]
+    fn to_string_round_trips() {
+        let attribute = LineAttribute::DoubleHeightTop;
+        assert_eq!(attribute.to_string().parse(), Ok(attribute));
+    }
+}
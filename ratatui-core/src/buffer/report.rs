@@ -0,0 +1,372 @@
+use alloc::string::String;
+use core::{cmp, fmt};
+
+use crate::buffer::{Buffer, Cell, CellWidth};
+
+/// The escape sequence that highlights the changed cells of the expected buffer.
+const EXPECTED_HIGHLIGHT: &str = "\x1b[30;42m";
+/// The escape sequence that highlights the changed cells of the actual buffer.
+const ACTUAL_HIGHLIGHT: &str = "\x1b[30;41m";
+/// The escape sequence that resets the highlighting.
+const RESET: &str = "\x1b[0m";
+
+/// A human readable report of the differences between two buffers.
+///
+/// The report shows the rows of the expected and actual buffers side by side, followed by a column
+/// that marks the changed cells of each row:
+///
+/// - `^` marks a cell whose symbol differs
+/// - `~` marks a cell whose symbol is the same but whose style differs
+/// - `*` marks a cell that differs in any other way (e.g. its [`CellDiffOption`]), or that only
+///   exists in one of the buffers
+///
+/// The changed cells are then listed with their positions and their expected and actual values.
+/// When [`colored`](Self::colored) is enabled, the changed cells are also highlighted in the rows
+/// with ANSI escape sequences.
+///
+/// This is the message of the panic of [`assert_buffer_eq!`](crate::assert_buffer_eq) and
+/// [`TestBackend::assert_buffer`](crate::backend::TestBackend::assert_buffer).
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::buffer::{Buffer, BufferDiffReport};
+///
+/// let expected = Buffer::with_lines(["foo", "bar"]);
+/// let actual = Buffer::with_lines(["foo", "baz"]);
+/// let report = BufferDiffReport::new(&actual, &expected).to_string();
+/// assert!(report.starts_with("buffer contents not equal: 1 cell differs"));
+/// assert!(report.contains(r#"   1 "bar"     "baz"     "  ^""#));
+/// ```
+///
+/// [`CellDiffOption`]: crate::buffer::CellDiffOption
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BufferDiffReport<'a> {
+    actual: &'a Buffer,
+    expected: &'a Buffer,
+    colored: bool,
+}
+
+/// How a cell differs between two buffers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum CellChange {
+    Symbol,
+    Style,
+    Other,
+}
+
+impl CellChange {
+    /// Returns how the actual cell differs from the expected cell, if it does.
+    fn between(expected: Option<&Cell>, actual: Option<&Cell>) -> Option<Self> {
+        match (expected, actual) {
+            (Some(expected), Some(actual)) if expected == actual => None,
+            (Some(expected), Some(actual)) if expected.symbol() != actual.symbol() => {
+                Some(Self::Symbol)
+            }
+            (Some(expected), Some(actual)) if expected.style() != actual.style() => {
+                Some(Self::Style)
+            }
+            (None, None) => None,
+            _ => Some(Self::Other),
+        }
+    }
+
+    const fn marker(self) -> char {
+        match self {
+            Self::Symbol => '^',
+            Self::Style => '~',
+            Self::Other => '*',
+        }
+    }
+}
+
+impl<'a> BufferDiffReport<'a> {
+    /// Creates a report of the differences between the actual and the expected buffer.
+    pub const fn new(actual: &'a Buffer, expected: &'a Buffer) -> Self {
+        Self {
+            actual,
+            expected,
+            colored: false,
+        }
+    }
+
+    /// Sets whether the changed cells are highlighted with ANSI escape sequences.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+
+    /// Returns the number of cells that differ between the buffers.
+    ///
+    /// Cells that only exist in one of the buffers are counted as well.
+    pub fn changed_cells(&self) -> usize {
+        let (width, height) = self.max_size();
+        (0..height)
+            .flat_map(|row| (0..width).map(move |col| (col, row)))
+            .filter(|&(col, row)| self.change(col, row).is_some())
+            .count()
+    }
+
+    /// Returns the largest width and height of the two buffers.
+    fn max_size(&self) -> (u16, u16) {
+        let width = cmp::max(self.expected.area.width, self.actual.area.width);
+        let height = cmp::max(self.expected.area.height, self.actual.area.height);
+        (width, height)
+    }
+
+    /// Returns how the cell at the offset from the top left of the buffers differs.
+    fn change(&self, col: u16, row: u16) -> Option<CellChange> {
+        CellChange::between(
+            cell_at(self.expected, col, row),
+            cell_at(self.actual, col, row),
+        )
+    }
+
+    /// Writes a row of the buffer as a quoted string, highlighting the changed cells, and returns
+    /// the displayed width of the row.
+    fn write_row(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        buffer: &Buffer,
+        row: u16,
+        highlight: &str,
+    ) -> Result<usize, fmt::Error> {
+        if row >= buffer.area.height {
+            return Ok(0);
+        }
+        f.write_str("\"")?;
+        let mut width = 2;
+        let mut hidden: u16 = 0;
+        for col in 0..buffer.area.width {
+            let Some(cell) = cell_at(buffer, col, row) else {
+                break;
+            };
+            // cells hidden by multi-width symbols are not displayed, like in `Buffer`'s Debug
+            if hidden == 0 {
+                let symbol = cell.symbol();
+                if self.colored && self.change(col, row).is_some() {
+                    write!(f, "{highlight}{symbol}{RESET}")?;
+                } else {
+                    f.write_str(symbol)?;
+                }
+                width += usize::from(symbol.cell_width());
+            }
+            hidden = cmp::max(hidden, cell.cell_width()).saturating_sub(1);
+        }
+        f.write_str("\"")?;
+        Ok(width)
+    }
+
+    /// Returns the markers of the changed cells of a row, or an empty string if no cell changed.
+    fn markers(&self, row: u16) -> String {
+        let (width, _) = self.max_size();
+        let markers: String = (0..width)
+            .map(|col| self.change(col, row).map_or(' ', CellChange::marker))
+            .collect();
+        String::from(markers.trim_end())
+    }
+}
+
+impl fmt::Display for BufferDiffReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let changed_cells = self.changed_cells();
+        if self.expected.area == self.actual.area {
+            let cells = if changed_cells == 1 {
+                "cell differs"
+            } else {
+                "cells differ"
+            };
+            writeln!(f, "buffer contents not equal: {changed_cells} {cells}")?;
+        } else {
+            writeln!(
+                f,
+                "buffer areas not equal\nexpected: {:?}\nactual:   {:?}",
+                self.expected.area, self.actual.area
+            )?;
+        }
+        if self.expected.line_attributes != self.actual.line_attributes {
+            writeln!(
+                f,
+                "line attributes not equal\nexpected: {:?}\nactual:   {:?}",
+                self.expected.line_attributes, self.actual.line_attributes
+            )?;
+        }
+
+        // the quoted rows are padded to the width of the widest buffer
+        let (width, height) = self.max_size();
+        let column_width = cmp::max(usize::from(width) + 2, "expected".len());
+        writeln!(
+            f,
+            "     {:column_width$}  {:column_width$}  diff",
+            "expected", "actual"
+        )?;
+        for row in 0..height {
+            write!(f, "{row:>4} ")?;
+            let expected_width = self.write_row(f, self.expected, row, EXPECTED_HIGHLIGHT)?;
+            write!(
+                f,
+                "{:1$}",
+                "",
+                column_width.saturating_sub(expected_width) + 2
+            )?;
+            let actual_width = self.write_row(f, self.actual, row, ACTUAL_HIGHLIGHT)?;
+            let markers = self.markers(row);
+            if !markers.is_empty() {
+                write!(
+                    f,
+                    "{:1$}",
+                    "",
+                    column_width.saturating_sub(actual_width) + 2
+                )?;
+                write!(f, "\"{markers}\"")?;
+            }
+            writeln!(f)?;
+        }
+
+        // only the cells that exist in both buffers are listed
+        let mut listed_heading = false;
+        for row in 0..height {
+            for col in 0..width {
+                let (Some(expected), Some(actual)) = (
+                    cell_at(self.expected, col, row),
+                    cell_at(self.actual, col, row),
+                ) else {
+                    continue;
+                };
+                let change = CellChange::between(Some(expected), Some(actual));
+                if change.is_some() && !listed_heading {
+                    writeln!(f, "changed cells (expected -> actual):")?;
+                    listed_heading = true;
+                }
+                match change {
+                    Some(CellChange::Symbol) => writeln!(
+                        f,
+                        "  ({col}, {row}): {:?} -> {:?}",
+                        expected.symbol(),
+                        actual.symbol()
+                    )?,
+                    Some(CellChange::Style) => writeln!(
+                        f,
+                        "  ({col}, {row}): {:?} -> {:?}",
+                        expected.style(),
+                        actual.style()
+                    )?,
+                    Some(CellChange::Other) => {
+                        writeln!(f, "  ({col}, {row}): {expected:?} -> {actual:?}")?;
+                    }
+                    None => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the cell at the offset from the top left of the buffer, if it is in the buffer.
+fn cell_at(buffer: &Buffer, col: u16, row: u16) -> Option<&Cell> {
+    if col >= buffer.area.width || row >= buffer.area.height {
+        return None;
+    }
+    let index = usize::from(row) * usize::from(buffer.area.width) + usize::from(col);
+    buffer.content.get(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use indoc::indoc;
+
+    use super::*;
+    use crate::layout::Rect;
+    use crate::style::{Color, Stylize};
+
+    #[test]
+    fn symbol_differences() {
+        let expected = Buffer::with_lines(["foo", "bar"]);
+        let actual = Buffer::with_lines(["fox", "bar"]);
+        let report = BufferDiffReport::new(&actual, &expected).to_string();
+        assert_eq!(
+            report,
+            indoc! {r#"
+                buffer contents not equal: 1 cell differs
+                     expected  actual    diff
+                   0 "foo"     "fox"     "  ^"
+                   1 "bar"     "bar"
+                changed cells (expected -> actual):
+                  (2, 0): "o" -> "x"
+            "#}
+        );
+    }
+
+    #[test]
+    fn style_differences() {
+        let expected = Buffer::with_lines(["foo".red()]);
+        let actual = Buffer::with_lines(["f".red() + "oo".blue()]);
+        let report = BufferDiffReport::new(&actual, &expected);
+        assert_eq!(report.changed_cells(), 2);
+        let report = report.to_string();
+        assert!(
+            report.contains(r#"   0 "foo"     "foo"     " ~~""#),
+            "{report}"
+        );
+        assert!(report.contains("(1, 0): Style::new().red()"), "{report}");
+        assert!(report.contains("-> Style::new().blue()"), "{report}");
+    }
+
+    #[test]
+    fn area_differences() {
+        let expected = Buffer::with_lines(["foo"]);
+        let actual = Buffer::with_lines(["fo", "ba"]);
+        let report = BufferDiffReport::new(&actual, &expected).to_string();
+        assert_eq!(
+            report,
+            indoc! {r#"
+                buffer areas not equal
+                expected: Rect { x: 0, y: 0, width: 3, height: 1 }
+                actual:   Rect { x: 0, y: 0, width: 2, height: 2 }
+                     expected  actual    diff
+                   0 "foo"     "fo"      "  *"
+                   1           "ba"      "**"
+            "#}
+        );
+    }
+
+    #[test]
+    fn colored() {
+        let expected = Buffer::with_lines(["ab"]);
+        let mut actual = Buffer::with_lines(["ab"]);
+        actual[(1, 0)].set_fg(Color::Red);
+        let report = BufferDiffReport::new(&actual, &expected)
+            .colored(true)
+            .to_string();
+        assert!(report.contains("\"a\x1b[30;42mb\x1b[0m\""), "{report}");
+        assert!(report.contains("\"a\x1b[30;41mb\x1b[0m\""), "{report}");
+    }
+
+    #[test]
+    fn wide_symbols() {
+        let expected = Buffer::with_lines(["コンa"]);
+        let actual = Buffer::with_lines(["コンb"]);
+        let report = BufferDiffReport::new(&actual, &expected).to_string();
+        assert!(
+            report.contains(r#"   0 "コンa"   "コンb"   "    ^""#),
+            "{report}"
+        );
+    }
+
+    #[test]
+    fn equal_buffers() {
+        let buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        let report = BufferDiffReport::new(&buffer, &buffer);
+        assert_eq!(report.changed_cells(), 0);
+        assert!(
+            report
+                .to_string()
+                .starts_with("buffer contents not equal: 0 cells differ")
+        );
+    }
+}
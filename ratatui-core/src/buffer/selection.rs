@@ -0,0 +1,232 @@
+use alloc::string::String;
+
+use strum::{Display, EnumString};
+
+use crate::buffer::{Buffer, CellDiffOption, CellWidth};
+use crate::layout::{Position, Rect};
+use crate::style::Style;
+
+/// A selection of the cells of a [`Buffer`], e.g. made by dragging the mouse over the screen.
+///
+/// A selection goes from an `anchor`, where the selection started (e.g. where the mouse button was
+/// pressed), to a `cursor`, where it currently ends (e.g. where the mouse was dragged to). Both
+/// positions are included in the selection, and either can come first. How the cells between them
+/// are selected depends on the [`SelectionMode`].
+///
+/// Use [`Selection::text`] to get the selected text, e.g. to copy it to the clipboard, and
+/// [`Selection::apply_style`] to highlight the selected cells.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::buffer::{Buffer, Selection};
+/// use ratatui_core::layout::{Position, Rect};
+///
+/// let buffer = Buffer::with_lines(["Hello world", "from the", "terminal"]);
+///
+/// // the mouse was pressed on the `w` and dragged to the `t` of `the`
+/// let mut selection = Selection::new(Position::new(6, 0), Position::new(6, 0));
+/// selection.extend_to(Position::new(5, 1));
+/// assert_eq!(selection.text(&buffer), "world\nfrom t");
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Selection {
+    /// The position where the selection started.
+    pub anchor: Position,
+    /// The position where the selection currently ends.
+    pub cursor: Position,
+    /// How the cells between the anchor and the cursor are selected.
+    pub mode: SelectionMode,
+}
+
+/// How the cells between the anchor and the cursor of a [`Selection`] are selected.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectionMode {
+    /// Selects the cells in reading order, from the first position to the end of its row, every
+    /// row in between, and the start of the last row up to the last position, like selecting text
+    /// in a terminal.
+    #[default]
+    Linear,
+    /// Selects the rectangle of cells that has the anchor and the cursor as opposite corners.
+    Block,
+}
+
+impl Selection {
+    /// Creates a new linear selection from `anchor` to `cursor`.
+    pub const fn new(anchor: Position, cursor: Position) -> Self {
+        Self {
+            anchor,
+            cursor,
+            mode: SelectionMode::Linear,
+        }
+    }
+
+    /// Sets the selection mode.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mode(mut self, mode: SelectionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Moves the end of the selection to `cursor`, keeping the anchor, e.g. when the mouse is
+    /// dragged.
+    pub const fn extend_to(&mut self, cursor: Position) {
+        self.cursor = cursor;
+    }
+
+    /// Returns the first and last selected positions, in reading order.
+    ///
+    /// For a [block](SelectionMode::Block) selection, these are the top left and bottom right
+    /// corners of the selected rectangle.
+    pub fn bounds(&self) -> (Position, Position) {
+        let (anchor, cursor) = (self.anchor, self.cursor);
+        match self.mode {
+            SelectionMode::Linear if (anchor.y, anchor.x) <= (cursor.y, cursor.x) => {
+                (anchor, cursor)
+            }
+            SelectionMode::Linear => (cursor, anchor),
+            SelectionMode::Block => (
+                Position::new(anchor.x.min(cursor.x), anchor.y.min(cursor.y)),
+                Position::new(anchor.x.max(cursor.x), anchor.y.max(cursor.y)),
+            ),
+        }
+    }
+
+    /// Returns true if the position is selected.
+    pub fn contains(&self, position: Position) -> bool {
+        let (start, end) = self.bounds();
+        if position.y < start.y || position.y > end.y {
+            return false;
+        }
+        match self.mode {
+            SelectionMode::Linear => {
+                (position.y > start.y || position.x >= start.x)
+                    && (position.y < end.y || position.x <= end.x)
+            }
+            SelectionMode::Block => position.x >= start.x && position.x <= end.x,
+        }
+    }
+
+    /// Returns the selected text of the buffer.
+    ///
+    /// Each selected row is a line of the text. A wide character (e.g. `😀` or `你`) is included
+    /// once if any of the cells it covers is selected. Trailing whitespace is removed from each
+    /// line, as the rows of a buffer are padded with spaces. Cells that are marked to be skipped
+    /// are ignored.
+    pub fn text(&self, buf: &Buffer) -> String {
+        let mut text = String::new();
+        let mut line = String::new();
+        for (index, row) in self.rows(buf.area).enumerate() {
+            if index > 0 {
+                text.push('\n');
+            }
+            line.clear();
+            // the column of the next cell that isn't hidden by a wide character
+            let mut next_x = buf.area.left();
+            for x in buf.area.left()..buf.area.right() {
+                let cell = &buf[(x, row.y)];
+                if x < next_x || cell.diff_option == CellDiffOption::Skip {
+                    continue;
+                }
+                let width = cell.symbol().cell_width().max(1);
+                next_x = x.saturating_add(width);
+                if x < row.right() && next_x > row.left() {
+                    line.push_str(cell.symbol());
+                }
+            }
+            text.push_str(line.trim_end());
+        }
+        text
+    }
+
+    /// Applies a style to the selected cells of the buffer, e.g. to highlight the selection.
+    pub fn apply_style<S: Into<Style>>(&self, buf: &mut Buffer, style: S) {
+        let style = style.into();
+        for row in self.rows(buf.area) {
+            buf.set_style(row, style);
+        }
+    }
+
+    /// Returns the selected part of each row of the area, from top to bottom.
+    fn rows(&self, area: Rect) -> impl Iterator<Item = Rect> {
+        let (start, end) = self.bounds();
+        let mode = self.mode;
+        let top = start.y.max(area.top());
+        let bottom = end.y.saturating_add(1).min(area.bottom());
+        (top..bottom).filter_map(move |y| {
+            let (left, right) = match mode {
+                SelectionMode::Linear => (
+                    if y == start.y { start.x } else { area.left() },
+                    if y == end.y { end.x } else { area.right() },
+                ),
+                SelectionMode::Block => (start.x, end.x),
+            };
+            let left = left.max(area.left());
+            let right = right.saturating_add(1).min(area.right());
+            (left < right).then(|| Rect::new(left, y, right - left, 1))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::single_cell((1, 0), (1, 0), "b")]
+    #[case::forward((1, 0), (2, 1), "bcd\nefg")]
+    #[case::backward((2, 1), (1, 0), "bcd\nefg")]
+    #[case::whole_rows((0, 0), (9, 2), "abcd\nefgh\nijkl")]
+    #[case::outside_buffer((5, 0), (8, 0), "")]
+    fn linear_text(#[case] anchor: (u16, u16), #[case] cursor: (u16, u16), #[case] text: &str) {
+        let buf = Buffer::with_lines(["abcd  ", "efgh", "ijkl"]);
+        let selection = Selection::new(anchor.into(), cursor.into());
+        assert_eq!(selection.text(&buf), text);
+    }
+
+    #[test]
+    fn block_text() {
+        let buf = Buffer::with_lines(["abcd", "efgh", "ijkl"]);
+        let selection =
+            Selection::new(Position::new(2, 2), Position::new(1, 0)).mode(SelectionMode::Block);
+        assert_eq!(selection.text(&buf), "bc\nfg\njk");
+        assert!(selection.contains(Position::new(1, 1)));
+        assert!(!selection.contains(Position::new(0, 1)));
+    }
+
+    #[rstest]
+    #[case::first_half(0, "😀")]
+    #[case::second_half(1, "😀")]
+    #[case::after(2, "a")]
+    fn wide_characters(#[case] x: u16, #[case] text: &str) {
+        let buf = Buffer::with_lines(["😀a"]);
+        let selection = Selection::new(Position::new(x, 0), Position::new(x, 0));
+        assert_eq!(selection.text(&buf), text);
+    }
+
+    #[test]
+    fn offset_buffer() {
+        let mut buf = Buffer::empty(Rect::new(10, 5, 4, 2));
+        buf.set_string(10, 5, "abcd", Style::new());
+        buf.set_string(10, 6, "efgh", Style::new());
+        let selection = Selection::new(Position::new(12, 5), Position::new(11, 6));
+        assert_eq!(selection.text(&buf), "cd\nef");
+    }
+
+    #[test]
+    fn apply_style() {
+        let mut buf = Buffer::with_lines(["abcd", "efgh"]);
+        Selection::new(Position::new(2, 0), Position::new(0, 1))
+            .apply_style(&mut buf, Style::new().reversed());
+        let mut expected = Buffer::with_lines(["abcd", "efgh"]);
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().reversed());
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+}
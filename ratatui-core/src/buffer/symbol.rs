@@ -0,0 +1,188 @@
+use alloc::boxed::Box;
+use core::hash::{Hash, Hasher};
+
+use arrayvec::ArrayString;
+use compact_str::CompactString;
+
+/// The number of bytes of a symbol that are stored inline in a [`Cell`].
+///
+/// This covers every single character and almost every grapheme cluster, so only unusually long
+/// clusters (e.g. family emoji joined with zero width joiners) are stored on the heap.
+///
+/// [`Cell`]: crate::buffer::Cell
+pub(crate) const INLINE_CAPACITY: usize = 16;
+
+/// The symbol of a [`Cell`].
+///
+/// Symbols of up to [`INLINE_CAPACITY`] bytes are stored inline and symbols that are known at
+/// compile time are borrowed, so cloning most cells is a copy of plain bytes. Longer symbols are
+/// stored on the heap behind a thin pointer. The symbol is 24 bytes, the same size as the
+/// `Option<CompactString>` that cells used to store, so the gain is in cloning rather than in
+/// memory. Every variant stores a `str`, so reading the symbol never needs to check that it is
+/// valid UTF-8.
+///
+/// [`Cell`]: crate::buffer::Cell
+#[derive(Debug, Default, Clone)]
+pub(crate) enum Symbol {
+    /// No symbol, which is drawn as a space
+    #[default]
+    Empty,
+    /// A symbol that was created at compile time
+    Static(&'static str),
+    /// A symbol that fits inline
+    Inline(ArrayString<INLINE_CAPACITY>),
+    /// A symbol that is longer than [`INLINE_CAPACITY`] bytes
+    Heap(Box<CompactString>),
+}
+
+impl Symbol {
+    /// Creates a symbol at compile time.
+    pub(crate) const fn const_new(symbol: &'static str) -> Self {
+        Self::Static(symbol)
+    }
+
+    /// Creates a symbol, storing it inline when it fits.
+    pub(crate) fn new(symbol: &str) -> Self {
+        ArrayString::from(symbol).map_or_else(
+            |_| Self::Heap(Box::new(CompactString::new(symbol))),
+            Self::Inline,
+        )
+    }
+
+    /// Returns the symbol, which is a space when there is no symbol.
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            Self::Empty => " ",
+            Self::Static(symbol) => symbol,
+            Self::Inline(symbol) => symbol.as_str(),
+            Self::Heap(symbol) => symbol.as_str(),
+        }
+    }
+
+    /// Returns whether there is no symbol.
+    pub(crate) const fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    /// Returns whether the symbol is a single printable ASCII character (or no symbol), which is
+    /// one column wide regardless of the width policy.
+    pub(crate) fn is_printable_ascii(&self) -> bool {
+        matches!(self.as_str().as_bytes(), [b' '..=b'~'])
+    }
+
+    /// Appends a string to the symbol, moving it to the heap when it no longer fits inline.
+    pub(crate) fn push_str(&mut self, string: &str) {
+        match self {
+            Self::Heap(symbol) => symbol.push_str(string),
+            Self::Inline(symbol) if string.len() <= symbol.remaining_capacity() => {
+                symbol.push_str(string);
+            }
+            _ => {
+                let prefix = if self.is_empty() { "" } else { self.as_str() };
+                let mut symbol = CompactString::new(prefix);
+                symbol.push_str(string);
+                *self = Self::new(&symbol);
+            }
+        }
+    }
+}
+
+impl PartialEq for Symbol {
+    /// Compares two symbols, treating no symbol as equal to a space, as both are drawn the same.
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    /// Hashes the symbol as a string, treating no symbol as a space to match [`PartialEq`].
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Symbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (!self.is_empty())
+            .then(|| self.as_str())
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Symbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Option::<CompactString>::deserialize(deserializer)?;
+        Ok(symbol.map_or(Self::Empty, |symbol| Self::new(&symbol)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size() {
+        assert_eq!(size_of::<Symbol>(), 24);
+        assert_eq!(size_of::<Symbol>(), size_of::<Option<CompactString>>());
+    }
+
+    #[test]
+    fn inline_and_heap() {
+        let inline = "x".repeat(INLINE_CAPACITY);
+        assert!(matches!(Symbol::new(&inline), Symbol::Inline(_)));
+        assert_eq!(Symbol::new(&inline).as_str(), inline);
+
+        let family = "👨\u{200D}👩\u{200D}👧\u{200D}👦";
+        assert!(matches!(Symbol::new(family), Symbol::Heap(_)));
+        assert_eq!(Symbol::new(family).as_str(), family);
+        assert_eq!(Symbol::Empty.as_str(), " ");
+    }
+
+    #[test]
+    fn const_new() {
+        const SYMBOL: Symbol = Symbol::const_new("あ");
+        assert_eq!(SYMBOL, Symbol::new("あ"));
+        assert_eq!(SYMBOL.as_str(), "あ");
+    }
+
+    #[test]
+    fn push_str() {
+        let mut symbol = Symbol::Empty;
+        symbol.push_str("a");
+        assert_eq!(symbol, Symbol::new("a"));
+        assert!(matches!(symbol, Symbol::Inline(_)));
+        symbol.push_str(&"b".repeat(INLINE_CAPACITY));
+        assert!(matches!(symbol, Symbol::Heap(_)));
+        symbol.push_str("c");
+        assert_eq!(symbol.as_str(), "abbbbbbbbbbbbbbbbc");
+
+        let mut symbol = Symbol::const_new("a");
+        symbol.push_str("b");
+        assert!(matches!(symbol, Symbol::Inline(_)));
+        assert_eq!(symbol.as_str(), "ab");
+    }
+
+    #[test]
+    fn is_printable_ascii() {
+        assert!(Symbol::Empty.is_printable_ascii());
+        assert!(Symbol::new(" ").is_printable_ascii());
+        assert!(Symbol::new("~").is_printable_ascii());
+        assert!(!Symbol::new("\t").is_printable_ascii());
+        assert!(!Symbol::new("ab").is_printable_ascii());
+        assert!(!Symbol::new("あ").is_printable_ascii());
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(Symbol::Empty, Symbol::new(" "));
+        assert_eq!(Symbol::new(" "), Symbol::Empty);
+        assert_eq!(Symbol::const_new("a"), Symbol::new("a"));
+        assert_ne!(Symbol::Empty, Symbol::new(""));
+        assert_ne!(Symbol::new("a"), Symbol::new("b"));
+        assert_ne!(Symbol::new("a"), Symbol::new("a\0"));
+    }
+}
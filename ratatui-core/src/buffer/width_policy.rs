@@ -0,0 +1,265 @@
+use strum::{Display, EnumString};
+use unicode_width::UnicodeWidthStr;
+
+/// The variation selector that requests the emoji presentation of a character (VS16).
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+
+/// How the width of characters is computed, to match how the terminal renders them.
+///
+/// Terminals disagree on the width of some characters:
+///
+/// - Characters with an [East Asian Width] of "ambiguous", such as `±`, `①` or box drawing
+///   characters, are one column wide in most terminals, but two columns wide in terminals
+///   configured for CJK text. See [`AmbiguousWidth`].
+/// - Characters that are text by default but can be shown as emoji, such as `❤` or `✔`, are two
+///   columns wide when followed by the emoji presentation selector (VS16, `U+FE0F`), but some
+///   terminals ignore the selector. See [`EmojiPresentation`].
+///
+/// When the policy does not match the terminal, the cursor position of the terminal drifts from the
+/// position ratatui expects, which leaves artifacts on the screen. The policy is used by
+/// [`Span::width`], [`Line::width`], the [`CellWidth`] of strings and cells (which places the cells
+/// in a [`Buffer`] and wraps text in paragraphs), and the truncation of lines.
+///
+/// The current policy is usually set with [`Terminal::set_width_policy`] or
+/// [`TerminalOptions::width_policy`], or directly with [`WidthPolicy::set_current`]. When the `std`
+/// feature is enabled, the policy is stored per thread, and a terminal makes its policy current on
+/// the thread that draws it at the start of each frame. Without `std`, the policy is shared by the
+/// whole program, so programs that draw several terminals with different policies must do so from
+/// a single thread.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::buffer::{AmbiguousWidth, WidthPolicy};
+///
+/// let policy = WidthPolicy::new().ambiguous(AmbiguousWidth::Wide);
+/// assert_eq!(policy.str_width("±1"), 3);
+/// assert_eq!(WidthPolicy::new().str_width("±1"), 2);
+/// ```
+///
+/// [East Asian Width]: https://www.unicode.org/reports/tr11/
+/// [`Span::width`]: crate::text::Span::width
+/// [`Line::width`]: crate::text::Line::width
+/// [`CellWidth`]: crate::buffer::CellWidth
+/// [`Buffer`]: crate::buffer::Buffer
+/// [`Terminal::set_width_policy`]: crate::terminal::Terminal::set_width_policy
+/// [`TerminalOptions::width_policy`]: crate::terminal::TerminalOptions::width_policy
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WidthPolicy {
+    /// The width of characters with an ambiguous East Asian Width
+    pub ambiguous: AmbiguousWidth,
+    /// The width of characters followed by the emoji presentation selector
+    pub emoji_presentation: EmojiPresentation,
+}
+
+/// The width of characters with an [East Asian Width] of "ambiguous".
+///
+/// [East Asian Width]: https://www.unicode.org/reports/tr11/
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AmbiguousWidth {
+    /// Ambiguous characters are one column wide, as in most terminals.
+    #[default]
+    Narrow,
+    /// Ambiguous characters are two columns wide, as in terminals configured for CJK text.
+    Wide,
+}
+
+/// The width of characters followed by the emoji presentation selector (VS16, `U+FE0F`).
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmojiPresentation {
+    /// The selector makes the character two columns wide, as specified by Unicode.
+    #[default]
+    Wide,
+    /// The selector is ignored, so the character keeps its text width, as in terminals that do not
+    /// support the selector.
+    Narrow,
+}
+
+impl WidthPolicy {
+    /// Creates the default policy, which follows the Unicode widths with narrow ambiguous
+    /// characters.
+    pub const fn new() -> Self {
+        Self {
+            ambiguous: AmbiguousWidth::Narrow,
+            emoji_presentation: EmojiPresentation::Wide,
+        }
+    }
+
+    /// Sets the width of characters with an ambiguous East Asian Width.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn ambiguous(mut self, ambiguous: AmbiguousWidth) -> Self {
+        self.ambiguous = ambiguous;
+        self
+    }
+
+    /// Sets the width of characters followed by the emoji presentation selector.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn emoji_presentation(mut self, emoji_presentation: EmojiPresentation) -> Self {
+        self.emoji_presentation = emoji_presentation;
+        self
+    }
+
+    /// Returns the policy used to compute widths.
+    pub fn current() -> Self {
+        current::get()
+    }
+
+    /// Sets the policy used to compute widths.
+    ///
+    /// [`Terminal::set_width_policy`] calls this, and also redraws the terminal, which is
+    /// necessary when the policy changes after the first frame.
+    ///
+    /// [`Terminal::set_width_policy`]: crate::terminal::Terminal::set_width_policy
+    pub fn set_current(self) {
+        current::set(self);
+    }
+
+    /// Returns the width of a string in columns with this policy.
+    pub fn str_width(self, s: &str) -> usize {
+        if self.emoji_presentation == EmojiPresentation::Narrow
+            && s.contains(EMOJI_PRESENTATION_SELECTOR)
+        {
+            return s
+                .split(EMOJI_PRESENTATION_SELECTOR)
+                .map(|part| self.unicode_width(part))
+                .sum();
+        }
+        self.unicode_width(s)
+    }
+
+    fn unicode_width(self, s: &str) -> usize {
+        match self.ambiguous {
+            AmbiguousWidth::Narrow => s.width(),
+            AmbiguousWidth::Wide => s.width_cjk(),
+        }
+    }
+
+    const fn to_bits(self) -> u8 {
+        let ambiguous = match self.ambiguous {
+            AmbiguousWidth::Narrow => 0,
+            AmbiguousWidth::Wide => 1,
+        };
+        let emoji_presentation = match self.emoji_presentation {
+            EmojiPresentation::Wide => 0,
+            EmojiPresentation::Narrow => 2,
+        };
+        ambiguous | emoji_presentation
+    }
+
+    const fn from_bits(bits: u8) -> Self {
+        Self {
+            ambiguous: if bits & 1 == 0 {
+                AmbiguousWidth::Narrow
+            } else {
+                AmbiguousWidth::Wide
+            },
+            emoji_presentation: if bits & 2 == 0 {
+                EmojiPresentation::Wide
+            } else {
+                EmojiPresentation::Narrow
+            },
+        }
+    }
+}
+
+// With std: each thread has its own policy, so that threads (and tests) don't affect each other.
+#[cfg(feature = "std")]
+mod current {
+    use core::cell::Cell;
+
+    use super::WidthPolicy;
+
+    std::thread_local! {
+        static CURRENT: Cell<u8> = const { Cell::new(WidthPolicy::new().to_bits()) };
+    }
+
+    pub fn get() -> WidthPolicy {
+        WidthPolicy::from_bits(CURRENT.with(Cell::get))
+    }
+
+    pub fn set(policy: WidthPolicy) {
+        CURRENT.with(|current| current.set(policy.to_bits()));
+    }
+}
+
+// Without std: the policy is shared by the whole program.
+#[cfg(not(feature = "std"))]
+mod current {
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    use super::WidthPolicy;
+
+    static CURRENT: AtomicU8 = AtomicU8::new(WidthPolicy::new().to_bits());
+
+    pub fn get() -> WidthPolicy {
+        WidthPolicy::from_bits(CURRENT.load(Ordering::Relaxed))
+    }
+
+    pub fn set(policy: WidthPolicy) {
+        CURRENT.store(policy.to_bits(), Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::ascii("abc", 3, 3)]
+    #[case::cjk("日本", 4, 4)]
+    #[case::ambiguous("±①", 2, 4)]
+    #[case::box_drawing("─│", 2, 4)]
+    fn ambiguous_width(#[case] s: &str, #[case] narrow: usize, #[case] wide: usize) {
+        let policy = WidthPolicy::new();
+        assert_eq!(
+            policy.ambiguous(AmbiguousWidth::Narrow).str_width(s),
+            narrow
+        );
+        assert_eq!(policy.ambiguous(AmbiguousWidth::Wide).str_width(s), wide);
+    }
+
+    #[rstest]
+    #[case::text_presentation("❤", 1, 1)]
+    #[case::emoji_presentation("❤\u{FE0F}", 2, 1)]
+    #[case::default_emoji("😀", 2, 2)]
+    #[case::mixed("a✔\u{FE0F}b", 4, 3)]
+    fn emoji_presentation(#[case] s: &str, #[case] wide: usize, #[case] narrow: usize) {
+        let policy = WidthPolicy::new();
+        let wide_policy = policy.emoji_presentation(EmojiPresentation::Wide);
+        let narrow_policy = policy.emoji_presentation(EmojiPresentation::Narrow);
+        assert_eq!(wide_policy.str_width(s), wide);
+        assert_eq!(narrow_policy.str_width(s), narrow);
+    }
+
+    #[test]
+    fn bits_round_trip() {
+        for ambiguous in [AmbiguousWidth::Narrow, AmbiguousWidth::Wide] {
+            for emoji_presentation in [EmojiPresentation::Wide, EmojiPresentation::Narrow] {
+                let policy = WidthPolicy {
+                    ambiguous,
+                    emoji_presentation,
+                };
+                assert_eq!(WidthPolicy::from_bits(policy.to_bits()), policy);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn current() {
+        assert_eq!(WidthPolicy::current(), WidthPolicy::default());
+        let policy = WidthPolicy::new().ambiguous(AmbiguousWidth::Wide);
+        policy.set_current();
+        assert_eq!(WidthPolicy::current(), policy);
+        WidthPolicy::new().set_current();
+    }
+}
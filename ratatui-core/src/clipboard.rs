@@ -0,0 +1,278 @@
+//! Copying text to the system clipboard with OSC 52 escape sequences.
+//!
+//! Most terminal emulators (e.g. xterm, kitty, `WezTerm`, iTerm2, Windows Terminal and Alacritty)
+//! set the system clipboard when they receive an OSC 52 escape sequence containing the base64
+//! encoded text. As the sequence is written to the terminal like any other output, this works over
+//! SSH and doesn't need external programs such as `xclip` or `pbcopy`.
+//!
+//! [`Osc52`] formats the escape sequence, and [`copy`] writes it to the writer of a backend (e.g.
+//! [`CrosstermBackend::writer_mut`]). Terminal multiplexers don't forward OSC 52 sequences by
+//! default, so the sequence can be wrapped for them with [`Osc52::passthrough`].
+//!
+//! Some terminals ask the user for permission or limit the length of the copied text, and others
+//! don't support OSC 52 at all, so copying may silently fail.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use ratatui_core::clipboard::{self, Osc52, Passthrough};
+//!
+//! // with `ratatui::crossterm`, the writer is `terminal.backend_mut().writer_mut()`
+//! let mut writer = Vec::new();
+//! clipboard::copy(&mut writer, "Hello")?;
+//! assert_eq!(writer, b"\x1b]52;c;SGVsbG8=\x07");
+//!
+//! let sequence = Osc52::new("Hello")
+//!     .passthrough(Passthrough::Tmux)
+//!     .to_string();
+//! assert_eq!(sequence, "\x1bPtmux;\x1b\x1b]52;c;SGVsbG8=\x07\x1b\\");
+//! # }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! [`CrosstermBackend::writer_mut`]: https://docs.rs/ratatui/latest/ratatui/backend/struct.CrosstermBackend.html#method.writer_mut
+use alloc::string::String;
+use core::fmt;
+
+use strum::{Display, EnumString};
+
+/// The maximum length of each part of a sequence that is split for GNU Screen.
+const SCREEN_CHUNK_LENGTH: usize = 76;
+
+/// The characters used to encode base64.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The clipboard that is set by an [`Osc52`] sequence.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClipboardSelection {
+    /// The system clipboard, which is pasted with e.g. `Ctrl+V`.
+    #[default]
+    Clipboard,
+    /// The primary selection of X11 and Wayland, which is pasted with the middle mouse button.
+    Primary,
+}
+
+impl ClipboardSelection {
+    /// Returns the parameter of the OSC 52 sequence for the selection.
+    const fn parameter(self) -> char {
+        match self {
+            Self::Clipboard => 'c',
+            Self::Primary => 'p',
+        }
+    }
+}
+
+/// How an [`Osc52`] sequence is wrapped to pass through a terminal multiplexer.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Passthrough {
+    /// The sequence is not wrapped.
+    #[default]
+    None,
+    /// The sequence is wrapped in a tmux passthrough sequence.
+    ///
+    /// This requires the `allow-passthrough` option of tmux to be enabled. Alternatively, tmux
+    /// sets the clipboard itself when its `set-clipboard` option is enabled, in which case the
+    /// sequence doesn't need to be wrapped.
+    Tmux,
+    /// The sequence is split into parts that are each wrapped in a device control string, which
+    /// GNU Screen passes to the terminal.
+    Screen,
+}
+
+/// An OSC 52 escape sequence that sets the clipboard to a text.
+///
+/// The sequence is written by formatting it, e.g. with [`write!`] or
+/// [`ToString::to_string`](alloc::string::ToString::to_string).
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::clipboard::{ClipboardSelection, Osc52};
+///
+/// let sequence = Osc52::new("Hi").selection(ClipboardSelection::Primary);
+/// assert_eq!(sequence.to_string(), "\x1b]52;p;SGk=\x07");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Osc52<'a> {
+    text: &'a str,
+    selection: ClipboardSelection,
+    passthrough: Passthrough,
+}
+
+impl<'a> Osc52<'a> {
+    /// Creates a sequence that sets the system clipboard to the text.
+    pub const fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            selection: ClipboardSelection::Clipboard,
+            passthrough: Passthrough::None,
+        }
+    }
+
+    /// Sets the clipboard to set.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn selection(mut self, selection: ClipboardSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets how the sequence is wrapped to pass through a terminal multiplexer.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn passthrough(mut self, passthrough: Passthrough) -> Self {
+        self.passthrough = passthrough;
+        self
+    }
+
+    /// Returns the OSC 52 sequence, without any passthrough wrapping.
+    fn sequence(&self) -> String {
+        let mut sequence = String::with_capacity(self.text.len().div_ceil(3) * 4 + 8);
+        sequence.push_str("\x1b]52;");
+        sequence.push(self.selection.parameter());
+        sequence.push(';');
+        encode_base64(self.text.as_bytes(), &mut sequence);
+        sequence.push('\x07');
+        sequence
+    }
+}
+
+impl fmt::Display for Osc52<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sequence = self.sequence();
+        match self.passthrough {
+            Passthrough::None => f.write_str(&sequence),
+            Passthrough::Tmux => {
+                // escape characters in the wrapped sequence are doubled
+                f.write_str("\x1bPtmux;")?;
+                for part in sequence.split_inclusive('\x1b') {
+                    f.write_str(part)?;
+                    if part.ends_with('\x1b') {
+                        f.write_str("\x1b")?;
+                    }
+                }
+                f.write_str("\x1b\\")
+            }
+            Passthrough::Screen => {
+                // the sequence is ASCII, so it can be split at any byte
+                let mut rest = sequence.as_str();
+                while !rest.is_empty() {
+                    let (part, remainder) = rest
+                        .split_at_checked(SCREEN_CHUNK_LENGTH)
+                        .unwrap_or((rest, ""));
+                    write!(f, "\x1bP{part}\x1b\\")?;
+                    rest = remainder;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Writes an OSC 52 sequence that sets the system clipboard to the text, and flushes the writer.
+///
+/// Use [`Osc52`] to set the primary selection or to pass the sequence through a terminal
+/// multiplexer.
+///
+/// # Errors
+///
+/// Returns an error if writing to or flushing the writer fails.
+#[cfg(feature = "std")]
+pub fn copy<W: std::io::Write>(writer: &mut W, text: &str) -> std::io::Result<()> {
+    write!(writer, "{}", Osc52::new(text))?;
+    writer.flush()
+}
+
+/// Appends the base64 encoding of the bytes, with padding, to the string.
+fn encode_base64(bytes: &[u8], output: &mut String) {
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |group, (index, &byte)| {
+                group | (u32::from(byte) << (16 - 8 * index))
+            });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (group >> (18 - 6 * index)) & 0x3f;
+                output.push(char::from(BASE64_ALPHABET[sextet as usize]));
+            } else {
+                output.push('=');
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::empty("", "")]
+    #[case::one_byte("f", "Zg==")]
+    #[case::two_bytes("fo", "Zm8=")]
+    #[case::three_bytes("foo", "Zm9v")]
+    #[case::longer("foobar", "Zm9vYmFy")]
+    #[case::unicode("ü", "w7w=")]
+    fn base64(#[case] text: &str, #[case] expected: &str) {
+        let mut output = String::new();
+        encode_base64(text.as_bytes(), &mut output);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn sequence() {
+        assert_eq!(Osc52::new("foo").to_string(), "\x1b]52;c;Zm9v\x07");
+        assert_eq!(
+            Osc52::new("foo")
+                .selection(ClipboardSelection::Primary)
+                .to_string(),
+            "\x1b]52;p;Zm9v\x07"
+        );
+    }
+
+    #[test]
+    fn tmux_passthrough() {
+        assert_eq!(
+            Osc52::new("foo").passthrough(Passthrough::Tmux).to_string(),
+            "\x1bPtmux;\x1b\x1b]52;c;Zm9v\x07\x1b\\"
+        );
+    }
+
+    #[test]
+    fn screen_passthrough() {
+        let text = "a".repeat(60);
+        let sequence = Osc52::new(&text)
+            .passthrough(Passthrough::Screen)
+            .to_string();
+        let parts: Vec<_> = sequence.split_terminator("\x1b\\").collect();
+        // 8 bytes of prefix, 80 bytes of base64 and the terminator make 2 parts
+        assert_eq!(parts.len(), 2);
+        assert!(parts.iter().all(|part| part.starts_with("\x1bP")));
+        assert_eq!(parts[0].len(), 2 + SCREEN_CHUNK_LENGTH);
+        let unwrapped: String = parts
+            .iter()
+            .filter_map(|part| part.strip_prefix("\x1bP"))
+            .collect();
+        assert_eq!(unwrapped, Osc52::new(&text).to_string());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn copy_writes_and_flushes() {
+        let mut writer = Vec::new();
+        copy(&mut writer, "foo").unwrap();
+        assert_eq!(writer, b"\x1b]52;c;Zm9v\x07");
+    }
+}
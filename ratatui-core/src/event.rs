@@ -0,0 +1,126 @@
+#![warn(missing_docs)]
+//! Backend independent input events.
+//!
+//! Each backend reports input events with its own types. The backend crates convert them to the
+//! types of this module (e.g. with `FromCrossterm` or `FromTermion`), so that applications can
+//! handle input the same way whatever backend they use.
+//!
+//! Termwiz mouse events are not converted, as they report which buttons are held rather than which
+//! button was pressed or released.
+use bitflags::bitflags;
+
+use crate::layout::{Position, Rect};
+
+/// A mouse event, such as a click, a drag or a scroll of the mouse wheel.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+/// use ratatui_core::layout::{Position, Rect};
+///
+/// let event = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), Position::new(3, 1))
+///     .modifiers(KeyModifiers::CTRL);
+/// let button = Rect::new(2, 1, 8, 1);
+/// assert!(event.is_click_in(button));
+/// assert!(event.modifiers.contains(KeyModifiers::CTRL));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct MouseEvent {
+    /// What the mouse did.
+    pub kind: MouseEventKind,
+    /// The zero-based position of the mouse in the terminal, which can be compared with the areas
+    /// that widgets were rendered to.
+    pub position: Position,
+    /// The modifier keys held during the event, which are empty if the backend doesn't report
+    /// them (termion).
+    pub modifiers: KeyModifiers,
+}
+
+/// The kind of a [`MouseEvent`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum MouseEventKind {
+    /// A button was pressed.
+    Down(MouseButton),
+    /// A button was released, or `None` if the backend doesn't report which one (termion).
+    Up(Option<MouseButton>),
+    /// The mouse moved while a button was held down, or `None` if the backend doesn't report which
+    /// one (termion).
+    Drag(Option<MouseButton>),
+    /// The mouse moved without any button held down.
+    Moved,
+    /// The mouse wheel was scrolled down.
+    ScrollDown,
+    /// The mouse wheel was scrolled up.
+    ScrollUp,
+    /// The mouse wheel was scrolled left.
+    ScrollLeft,
+    /// The mouse wheel was scrolled right.
+    ScrollRight,
+}
+
+/// A mouse button.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum MouseButton {
+    /// The left mouse button.
+    Left,
+    /// The right mouse button.
+    Right,
+    /// The middle mouse button.
+    Middle,
+}
+
+bitflags! {
+    /// The modifier keys held during a key press or a [`MouseEvent`].
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct KeyModifiers: u8 {
+        /// The Control key.
+        const CTRL = 0b0001;
+        /// The Alt (or Option) key.
+        const ALT = 0b0010;
+        /// The Shift key.
+        const SHIFT = 0b0100;
+        /// The Super (Windows or Command) key.
+        const SUPER = 0b1000;
+    }
+}
+
+impl MouseEvent {
+    /// Creates a new `MouseEvent` without any modifier keys.
+    pub const fn new(kind: MouseEventKind, position: Position) -> Self {
+        Self {
+            kind,
+            position,
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+
+    /// Sets the modifier keys held during the event.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn modifiers(mut self, modifiers: KeyModifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Returns true if the left button was pressed inside `area`.
+    pub fn is_click_in(self, area: Rect) -> bool {
+        self.kind == MouseEventKind::Down(MouseButton::Left) && area.contains(self.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_click_in() {
+        let area = Rect::new(2, 2, 3, 1);
+        let click = |kind, x| MouseEvent::new(kind, Position::new(x, 2)).is_click_in(area);
+        assert!(click(MouseEventKind::Down(MouseButton::Left), 2));
+        assert!(!click(MouseEventKind::Down(MouseButton::Left), 5));
+        assert!(!click(MouseEventKind::Down(MouseButton::Right), 3));
+        assert!(!click(MouseEventKind::Up(None), 3));
+    }
+}
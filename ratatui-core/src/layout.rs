@@ -311,6 +311,8 @@
 //!   examples
 
 mod alignment;
+#[cfg(feature = "layout-cache")]
+mod cache;
 mod constraint;
 mod direction;
 mod flex;
@@ -322,7 +324,9 @@ mod rect;
 mod size;
 
 pub use alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
-pub use constraint::Constraint;
+#[cfg(feature = "layout-cache")]
+pub use cache::LayoutCacheStats;
+pub use constraint::{Constraint, ParseConstraintError, PrioritizedConstraint, Priority};
 pub use direction::Direction;
 pub use flex::Flex;
 pub use layout::{Layout, Spacing};
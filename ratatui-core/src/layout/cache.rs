@@ -0,0 +1,204 @@
+use core::hash::Hash;
+use core::num::NonZeroUsize;
+
+use lru::LruCache;
+
+const TWO: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+
+/// Statistics about the layout cache.
+///
+/// The statistics are returned by [`Layout::cache_stats`] and can be used to tune the size of the
+/// cache with [`Layout::init_cache`], or to check whether [`Layout::init_adaptive_cache`] is a
+/// better fit for an application.
+///
+/// With the `std` feature, the layout cache is thread-local, so the statistics only cover layouts
+/// computed on the current thread.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint, Layout, Rect};
+///
+/// Layout::reset_cache_stats();
+/// let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]);
+/// let area = Rect::new(0, 0, 10, 10);
+/// layout.split(area);
+/// layout.split(area);
+///
+/// let stats = Layout::cache_stats();
+/// assert_eq!((stats.hits, stats.misses), (1, 1));
+/// ```
+///
+/// [`Layout::cache_stats`]: crate::layout::Layout::cache_stats
+/// [`Layout::init_cache`]: crate::layout::Layout::init_cache
+/// [`Layout::init_adaptive_cache`]: crate::layout::Layout::init_adaptive_cache
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct LayoutCacheStats {
+    /// The number of layouts that were found in the cache.
+    pub hits: u64,
+    /// The number of layouts that were not found in the cache and had to be computed.
+    pub misses: u64,
+    /// The number of layouts that were removed from the cache to make room for new ones.
+    pub evictions: u64,
+    /// The number of layouts currently stored in the cache.
+    pub len: usize,
+    /// The maximum number of layouts the cache can currently store.
+    pub capacity: usize,
+}
+
+impl LayoutCacheStats {
+    /// Returns the ratio of hits to lookups, or `0.0` if the cache wasn't used yet.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / lookups as f64
+    }
+}
+
+/// A LRU cache that keeps statistics about its usage and can grow when it is full.
+pub(crate) struct StatsCache<K: Hash + Eq, V> {
+    entries: LruCache<K, V>,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    /// The size up to which the cache grows instead of evicting entries, if adaptive.
+    max_size: Option<NonZeroUsize>,
+}
+
+impl<K: Hash + Eq, V> StatsCache<K, V> {
+    /// Creates a cache with a fixed size.
+    pub(crate) fn new(size: NonZeroUsize) -> Self {
+        Self {
+            entries: LruCache::new(size),
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            max_size: None,
+        }
+    }
+
+    /// Sets a fixed size, evicting the least recently used entries if needed.
+    pub(crate) fn resize(&mut self, size: NonZeroUsize) {
+        self.max_size = None;
+        self.resize_entries(size);
+    }
+
+    /// Makes the cache grow when it is full, doubling its size up to `max_size`.
+    ///
+    /// The cache is shrunk to `max_size` if it is currently larger.
+    pub(crate) fn set_adaptive(&mut self, max_size: NonZeroUsize) {
+        self.max_size = Some(max_size);
+        if self.entries.cap() > max_size {
+            self.resize_entries(max_size);
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn cap(&self) -> NonZeroUsize {
+        self.entries.cap()
+    }
+
+    /// Returns the cached value for the key, recording a hit or a miss.
+    pub(crate) fn get(&mut self, key: &K) -> Option<&V> {
+        let value = self.entries.get(key);
+        if value.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        value
+    }
+
+    /// Inserts a value, growing the cache or evicting the least recently used entry if it is full.
+    pub(crate) fn put(&mut self, key: K, value: V) {
+        let cap = self.entries.cap();
+        if self.entries.len() == cap.get() && !self.entries.contains(&key) {
+            match self.max_size {
+                Some(max_size) if cap < max_size => {
+                    self.entries.resize(cap.saturating_mul(TWO).min(max_size));
+                }
+                _ => self.evictions += 1,
+            }
+        }
+        self.entries.put(key, value);
+    }
+
+    pub(crate) fn stats(&self) -> LayoutCacheStats {
+        LayoutCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            len: self.entries.len(),
+            capacity: self.entries.cap().get(),
+        }
+    }
+
+    pub(crate) const fn reset_stats(&mut self) {
+        self.hits = 0;
+        self.misses = 0;
+        self.evictions = 0;
+    }
+
+    fn resize_entries(&mut self, size: NonZeroUsize) {
+        let evicted = self.entries.len().saturating_sub(size.get());
+        self.evictions += evicted as u64;
+        self.entries.resize(size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn size(size: usize) -> NonZeroUsize {
+        NonZeroUsize::new(size).unwrap()
+    }
+
+    #[test]
+    fn records_hits_misses_and_evictions() {
+        let mut cache = StatsCache::new(size(1));
+        assert_eq!(cache.get(&1), None);
+        cache.put(1, "one");
+        assert_eq!(cache.get(&1), Some(&"one"));
+        cache.put(2, "two");
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (1, 1, 1));
+        assert_eq!((stats.len, stats.capacity), (1, 1));
+        assert!((stats.hit_rate() - 0.5).abs() < f64::EPSILON);
+
+        cache.reset_stats();
+        assert_eq!(cache.stats().hits, 0);
+        assert_eq!(cache.stats().len, 1);
+    }
+
+    #[test]
+    fn adaptive_grows_up_to_max_size() {
+        let mut cache = StatsCache::new(size(2));
+        cache.set_adaptive(size(5));
+        for i in 0..6 {
+            cache.put(i, i);
+        }
+        let stats = cache.stats();
+        assert_eq!((stats.len, stats.capacity, stats.evictions), (5, 5, 1));
+
+        // switching back to a fixed size evicts the oldest entries
+        cache.resize(size(3));
+        assert_eq!(cache.stats().evictions, 3);
+        assert_eq!(cache.get(&0), None);
+        assert_eq!(cache.get(&5), Some(&5));
+    }
+
+    #[test]
+    fn adaptive_shrinks_to_max_size() {
+        let mut cache = StatsCache::<u8, u8>::new(size(10));
+        cache.set_adaptive(size(4));
+        assert_eq!(cache.cap(), size(4));
+    }
+
+    #[test]
+    fn hit_rate_without_lookups() {
+        assert!(LayoutCacheStats::default().hit_rate().abs() < f64::EPSILON);
+    }
+}
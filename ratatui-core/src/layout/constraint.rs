@@ -1,8 +1,11 @@
 use alloc::vec::Vec;
 use core::fmt;
+use core::str::FromStr;
 
 use strum::EnumIs;
 
+use crate::widgets::IntrinsicSize;
+
 /// A constraint that defines the size of a layout element.
 ///
 /// Constraints are the core mechanism for defining how space should be allocated within a
@@ -15,12 +18,16 @@ use strum::EnumIs;
 /// Constraints are prioritized in the following order:
 ///
 /// 1. [`Constraint::Min`]
-/// 2. [`Constraint::Max`]
+/// 2. [`Constraint::Max`] and [`Constraint::Content`]
 /// 3. [`Constraint::Length`]
 /// 4. [`Constraint::Percentage`]
 /// 5. [`Constraint::Ratio`]
 /// 6. [`Constraint::Fill`]
 ///
+/// This order can be changed for individual constraints with
+/// [`with_priority`](Self::with_priority), to choose which constraint breaks first when the layout
+/// is overconstrained.
+///
 /// # Size Calculation
 ///
 /// - [`apply`](Self::apply) - Apply the constraint to a length and return the resulting size
@@ -34,10 +41,20 @@ use strum::EnumIs;
 /// - [`from_mins`](Self::from_mins) - Create a collection of minimum constraints
 /// - [`from_fills`](Self::from_fills) - Create a collection of fill constraints
 ///
+/// # Content Sizing
+///
+/// - [`content_width`](Self::content_width) - Size an element to the width of a widget
+/// - [`content_height`](Self::content_height) - Size an element to the height of a widget
+///
+/// # Priority
+///
+/// - [`with_priority`](Self::with_priority) - Raise or lower the priority of the constraint
+///
 /// # Conversion and Construction
 ///
 /// - [`from(u16)`](Self::from) - Create a [`Length`](Self::Length) constraint from `u16`
 /// - [`from(&Constraint)`](Self::from) - Create from `&Constraint` (copy)
+/// - [`from_str(&str)`](Self::from_str) - Parse a constraint from a string, e.g. from a config file
 /// - [`as_ref()`](Self::as_ref) - Get a reference to self
 /// - [`default()`](Self::default) - Create default constraint
 ///   ([`Percentage(100)`](Self::Percentage))
@@ -216,6 +233,32 @@ pub enum Constraint {
     /// └───────────┘└───────────────────────┘└──────────┘
     /// ```
     Fill(u16),
+
+    /// Sizes the element to fit its content
+    ///
+    /// The value is the size of the content, usually reported by a widget that implements
+    /// [`IntrinsicSize`] (see [`Constraint::content_width`] and [`Constraint::content_height`]).
+    /// The element is given exactly that size when there is enough space, and shrinks like
+    /// [`Constraint::Max`] when there isn't.
+    ///
+    /// # Examples
+    ///
+    /// `[Content(20), Fill(1)]`
+    ///
+    /// ```plain
+    /// ┌──────────────────┐┌────────────────────────────┐
+    /// │       20 px      ││            30 px           │
+    /// └──────────────────┘└────────────────────────────┘
+    /// ```
+    ///
+    /// `[Content(60), Fill(1)]`
+    ///
+    /// ```plain
+    /// ┌────────────────────────────────────────────────┐
+    /// │                      50 px                     │
+    /// └────────────────────────────────────────────────┘
+    /// ```
+    Content(u16),
 }
 
 impl Constraint {
@@ -238,7 +281,7 @@ impl Constraint {
                 (percentage * length).min(length) as u16
             }
             Self::Length(l) | Self::Fill(l) => length.min(l),
-            Self::Max(m) => length.min(m),
+            Self::Max(m) | Self::Content(m) => length.min(m),
             Self::Min(m) => length.max(m),
         }
     }
@@ -350,6 +393,71 @@ impl Constraint {
     {
         proportional_factors.into_iter().map(Self::Fill).collect()
     }
+
+    /// Creates a constraint that sizes an element to the width of a widget's content.
+    ///
+    /// Returns [`Constraint::Content`] with the widget's [`IntrinsicSize::width_hint`], or
+    /// [`Constraint::Fill(1)`](Self::Fill) if the widget has no preferred width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
+    /// use ratatui_core::text::Line;
+    ///
+    /// # let area = Rect::default();
+    /// let label = Line::from("Name:");
+    /// let layout = Layout::horizontal([Constraint::content_width(&label), Constraint::Fill(1)]);
+    /// let [label_area, input_area] = layout.areas(area);
+    /// ```
+    pub fn content_width<W: IntrinsicSize + ?Sized>(widget: &W) -> Self {
+        widget.width_hint().map_or(Self::Fill(1), Self::Content)
+    }
+
+    /// Creates a constraint that sizes an element to the height of a widget's content when
+    /// rendered with the given width.
+    ///
+    /// Returns [`Constraint::Content`] with the widget's [`IntrinsicSize::height_hint`], or
+    /// [`Constraint::Fill(1)`](Self::Fill) if the widget has no preferred height.
+    pub fn content_height<W: IntrinsicSize + ?Sized>(widget: &W, width: u16) -> Self {
+        widget
+            .height_hint(width)
+            .map_or(Self::Fill(1), Self::Content)
+    }
+
+    /// Sets the priority of the constraint, relative to the other constraints of the layout.
+    ///
+    /// When a layout is overconstrained, e.g. when the lengths of the elements add up to more than
+    /// the area, the constraints with the lowest priority are broken first. By default, the
+    /// priority of a constraint depends on its kind (see [`Constraint`]). A higher [`Priority`]
+    /// makes the constraint win over the constraints of the same kind and of the kinds just above
+    /// it, and a lower priority makes it give way to them.
+    ///
+    /// The returned [`PrioritizedConstraint`] can be passed to [`Layout`] in place of a
+    /// constraint. Plain constraints convert into it with `into()`, which is needed to mix them in
+    /// the same array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Priority, Rect};
+    ///
+    /// // the sidebar keeps its length, and the content gives way when the area is too small
+    /// let layout = Layout::horizontal([
+    ///     Constraint::Length(20).into(),
+    ///     Constraint::Length(40).with_priority(Priority::Low),
+    /// ]);
+    /// let [sidebar, content] = layout.areas(Rect::new(0, 0, 50, 1));
+    /// assert_eq!((sidebar.width, content.width), (20, 30));
+    /// ```
+    ///
+    /// [`Layout`]: crate::layout::Layout
+    pub const fn with_priority(self, priority: Priority) -> PrioritizedConstraint {
+        PrioritizedConstraint {
+            constraint: self,
+            priority,
+        }
+    }
 }
 
 impl From<u16> for Constraint {
@@ -400,16 +508,209 @@ impl fmt::Display for Constraint {
             Self::Fill(l) => write!(f, "Fill({l})"),
             Self::Max(m) => write!(f, "Max({m})"),
             Self::Min(m) => write!(f, "Min({m})"),
+            Self::Content(c) => write!(f, "Content({c})"),
         }
     }
 }
 
+/// The priority of a [`Constraint`], relative to the other constraints of a layout.
+///
+/// Each level is a thousand times stronger than the level below it, which is enough to move a
+/// constraint above or below the constraints of the neighboring kinds. See
+/// [`Constraint::with_priority`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Priority {
+    /// The constraint gives way to almost every other constraint.
+    Lowest,
+    /// The constraint gives way to the constraints of its own kind and of the kinds above it.
+    Low,
+    /// The default priority, which depends on the kind of the constraint.
+    #[default]
+    Normal,
+    /// The constraint wins over the constraints of its own kind and of the kinds above it.
+    High,
+    /// The constraint wins over almost every other constraint.
+    Highest,
+}
+
+impl Priority {
+    /// Returns the factor applied to the strengths of the constraint in the layout solver.
+    pub(crate) const fn factor(self) -> f64 {
+        match self {
+            Self::Lowest => 1e-6,
+            Self::Low => 1e-3,
+            Self::Normal => 1.0,
+            Self::High => 1e3,
+            Self::Highest => 1e6,
+        }
+    }
+}
+
+/// A [`Constraint`] with a [`Priority`], created with [`Constraint::with_priority`].
+///
+/// Every type that converts into a [`Constraint`] also converts into a `PrioritizedConstraint`
+/// with the [`Normal`](Priority::Normal) priority, so [`Layout`] accepts both.
+///
+/// [`Layout`]: crate::layout::Layout
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct PrioritizedConstraint {
+    /// The constraint.
+    pub constraint: Constraint,
+    /// The priority of the constraint.
+    pub priority: Priority,
+}
+
+impl<C: Into<Constraint>> From<C> for PrioritizedConstraint {
+    fn from(constraint: C) -> Self {
+        constraint.into().with_priority(Priority::Normal)
+    }
+}
+
+/// Error type indicating a failure to parse a [`Constraint`] from a string.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ParseConstraintError;
+
+impl fmt::Display for ParseConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse Constraint")
+    }
+}
+
+impl core::error::Error for ParseConstraintError {}
+
+/// Parses a constraint from a string, so that layouts can be loaded from configuration files at
+/// runtime.
+///
+/// The short forms mirror the syntax of the `constraint!` macro in the `ratatui-macros` crate, with
+/// an optional `==` prefix:
+///
+/// - `"3"` or `"== 3"` is [`Constraint::Length`]
+/// - `"50%"` is [`Constraint::Percentage`]
+/// - `"1/3"` is [`Constraint::Ratio`]
+/// - `">=10"` is [`Constraint::Min`] and `"<=10"` is [`Constraint::Max`]
+/// - `"*=2"` is [`Constraint::Fill`]
+///
+/// The function forms `"length(3)"`, `"percentage(50)"`, `"ratio(1, 3)"`, `"min(10)"`,
+/// `"max(10)"`, `"fill(2)"` and `"content(5)"` are also accepted, case insensitively, so the
+/// [`Display`](fmt::Display) output of a constraint can be parsed back. Whitespace around numbers
+/// and operators is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use ratatui_core::layout::Constraint;
+///
+/// assert_eq!(Constraint::from_str("50%"), Ok(Constraint::Percentage(50)));
+/// assert_eq!(Constraint::from_str("3"), Ok(Constraint::Length(3)));
+/// assert_eq!(Constraint::from_str(">=10"), Ok(Constraint::Min(10)));
+/// assert_eq!(Constraint::from_str("1/3"), Ok(Constraint::Ratio(1, 3)));
+/// assert_eq!(Constraint::from_str("fill(2)"), Ok(Constraint::Fill(2)));
+/// assert_eq!("Max(5)".parse(), Ok(Constraint::Max(5)));
+/// assert!(Constraint::from_str("fill").is_err());
+/// ```
+impl FromStr for Constraint {
+    type Err = ParseConstraintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let number = |s: &str| s.trim().parse::<u16>().ok().ok_or(ParseConstraintError);
+        let ratio = |num: &str, den: &str| -> Result<Self, Self::Err> {
+            let parse = |s: &str| s.trim().parse::<u32>().ok().ok_or(ParseConstraintError);
+            Ok(Self::Ratio(parse(num)?, parse(den)?))
+        };
+        if let Some(value) = s.strip_prefix(">=") {
+            return number(value).map(Self::Min);
+        }
+        if let Some(value) = s.strip_prefix("<=") {
+            return number(value).map(Self::Max);
+        }
+        if let Some(value) = s.strip_prefix("*=") {
+            return number(value).map(Self::Fill);
+        }
+        let s = s.strip_prefix("==").map_or(s, str::trim_start);
+        if let Some(value) = s.strip_suffix('%') {
+            return number(value).map(Self::Percentage);
+        }
+        if let Some((num, den)) = s.split_once('/') {
+            return ratio(num, den);
+        }
+        if let Some((name, args)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+            return match name.trim().to_ascii_lowercase().as_str() {
+                "length" => number(args).map(Self::Length),
+                "percentage" => number(args).map(Self::Percentage),
+                "min" => number(args).map(Self::Min),
+                "max" => number(args).map(Self::Max),
+                "fill" => number(args).map(Self::Fill),
+                "content" => number(args).map(Self::Content),
+                "ratio" => {
+                    let (num, den) = args.split_once(',').ok_or(ParseConstraintError)?;
+                    ratio(num, den)
+                }
+                _ => Err(ParseConstraintError),
+            };
+        }
+        number(s).map(Self::Length)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
     use alloc::vec;
 
+    use rstest::rstest;
+
     use super::*;
+    use crate::text::Line;
+
+    #[rstest]
+    #[case::length("3", Constraint::Length(3))]
+    #[case::length_eq("== 3", Constraint::Length(3))]
+    #[case::percentage("50%", Constraint::Percentage(50))]
+    #[case::percentage_spaces(" == 50 % ", Constraint::Percentage(50))]
+    #[case::ratio("1/3", Constraint::Ratio(1, 3))]
+    #[case::ratio_spaces("1 / 3", Constraint::Ratio(1, 3))]
+    #[case::min(">=10", Constraint::Min(10))]
+    #[case::max("<= 10", Constraint::Max(10))]
+    #[case::fill("*=2", Constraint::Fill(2))]
+    #[case::fill_function("fill(2)", Constraint::Fill(2))]
+    #[case::content_function("Content(5)", Constraint::Content(5))]
+    #[case::ratio_function("Ratio(1, 4)", Constraint::Ratio(1, 4))]
+    fn from_str(#[case] input: &str, #[case] expected: Constraint) {
+        assert_eq!(input.parse(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::negative("-1")]
+    #[case::overflow("65536")]
+    #[case::missing_value("fill()")]
+    #[case::unknown_function("grow(1)")]
+    #[case::ratio_missing_denominator("ratio(1)")]
+    #[case::percentage_float("1.5%")]
+    #[case::min_percentage(">=50%")]
+    fn from_str_invalid(#[case] input: &str) {
+        assert_eq!(input.parse::<Constraint>(), Err(ParseConstraintError));
+    }
+
+    #[test]
+    fn from_str_display_round_trip() {
+        let constraints = [
+            Constraint::Length(1),
+            Constraint::Percentage(2),
+            Constraint::Ratio(3, 4),
+            Constraint::Min(5),
+            Constraint::Max(6),
+            Constraint::Fill(7),
+            Constraint::Content(8),
+        ];
+        for constraint in constraints {
+            assert_eq!(constraint.to_string().parse(), Ok(constraint));
+        }
+    }
 
     #[test]
     fn default() {
@@ -423,6 +724,7 @@ mod tests {
         assert_eq!(Constraint::Length(10).to_string(), "Length(10)");
         assert_eq!(Constraint::Max(10).to_string(), "Max(10)");
         assert_eq!(Constraint::Min(10).to_string(), "Min(10)");
+        assert_eq!(Constraint::Content(10).to_string(), "Content(10)");
     }
 
     #[test]
@@ -486,6 +788,18 @@ mod tests {
         assert_eq!(Constraint::from_fills(vec![1, 2, 3]), expected);
     }
 
+    #[test]
+    fn content_width_and_height() {
+        struct NoHint;
+        impl IntrinsicSize for NoHint {}
+
+        let line = Line::from("hello");
+        assert_eq!(Constraint::content_width(&line), Constraint::Content(5));
+        assert_eq!(Constraint::content_height(&line, 3), Constraint::Content(1));
+        assert_eq!(Constraint::content_width(&NoHint), Constraint::Fill(1));
+        assert_eq!(Constraint::content_height(&NoHint, 3), Constraint::Fill(1));
+    }
+
     #[test]
     #[expect(deprecated)]
     fn apply() {
@@ -9,14 +9,16 @@ use hashbrown::HashMap;
 use itertools::Itertools;
 use kasuari::WeightedRelation::{EQ, GE, LE};
 use kasuari::{AddConstraintError, Expression, Solver, Strength, Variable};
-#[cfg(feature = "layout-cache")]
-use lru::LruCache;
 
 use self::strengths::{
     ALL_SEGMENT_GROW, FILL_GROW, GROW, LENGTH_SIZE_EQ, MAX_SIZE_EQ, MAX_SIZE_LE, MIN_SIZE_EQ,
     MIN_SIZE_GE, PERCENTAGE_SIZE_EQ, RATIO_SIZE_EQ, SPACE_GROW, SPACER_SIZE_EQ,
 };
-use crate::layout::{Constraint, Direction, Flex, Margin, Rect};
+#[cfg(feature = "layout-cache")]
+use super::cache::StatsCache;
+#[cfg(feature = "layout-cache")]
+use crate::layout::LayoutCacheStats;
+use crate::layout::{Constraint, Direction, Flex, Margin, PrioritizedConstraint, Priority, Rect};
 
 type Rects = Rc<[Rect]>;
 type Segments = Rects;
@@ -34,11 +36,11 @@ type Spacers = Rects;
 // Number of spacers will always be one more than number of segments.
 // With std: cache can store Rc directly (no Send needed thanks to thread_local)
 #[cfg(all(feature = "layout-cache", feature = "std"))]
-type Cache = LruCache<(Rect, Layout), (Segments, Spacers)>;
+type Cache = StatsCache<(Rect, Layout), (Segments, Spacers)>;
 
 // Without std: cache stores Vec instead (Send-safe for critical_section::Mutex)
 #[cfg(all(feature = "layout-cache", not(feature = "std")))]
-type Cache = LruCache<(Rect, Layout), (Vec<Rect>, Vec<Rect>)>;
+type Cache = StatsCache<(Rect, Layout), (Vec<Rect>, Vec<Rect>)>;
 
 // Multiplier that decides floating point precision when rounding.
 // The number of zeros in this number is the precision for the rounding of f64 to u16 in layout
@@ -140,6 +142,9 @@ impl From<i16> for Spacing {
 /// When the layout is computed, the result is cached in a thread-local cache, so that subsequent
 /// calls with the same parameters are faster. The cache is a `LruCache`, and the size of the cache
 /// can be configured using [`Layout::init_cache()`] when the `layout-cache` feature is enabled.
+/// Alternatively, [`Layout::init_adaptive_cache()`] lets the cache grow as needed, and
+/// [`Layout::cache_stats()`] reports how well the cache is working. Layouts that are computed only
+/// once can skip the cache with [`Layout::cache`].
 ///
 /// # Construction
 ///
@@ -170,6 +175,10 @@ impl From<i16> for Spacing {
 /// # Cache Management
 ///
 /// - [`init_cache`](Self::init_cache) - Initialize layout cache with custom size
+/// - [`init_adaptive_cache`](Self::init_adaptive_cache) - Let the layout cache grow as needed
+/// - [`cache_stats`](Self::cache_stats) - Get the hits, misses and evictions of the layout cache
+/// - [`reset_cache_stats`](Self::reset_cache_stats) - Reset the layout cache statistics
+/// - [`cache`](Self::cache) - Enable or disable caching for a single layout
 ///
 /// # Example
 ///
@@ -202,9 +211,17 @@ impl From<i16> for Spacing {
 pub struct Layout {
     direction: Direction,
     constraints: Vec<Constraint>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    priorities: Vec<Priority>,
     margin: Margin,
     flex: Flex,
     spacing: Spacing,
+    #[cfg_attr(feature = "serde", serde(default))]
+    min_spacing: u16,
+    #[cfg_attr(feature = "serde", serde(default))]
+    max_spacing: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    skip_cache: bool,
 }
 
 impl Layout {
@@ -222,7 +239,8 @@ impl Layout {
     /// The `constraints` parameter accepts any type that implements `IntoIterator<Item =
     /// Into<Constraint>>`. This includes arrays, slices, vectors, iterators. `Into<Constraint>` is
     /// implemented on `u16`, so you can pass an array, `Vec`, etc. of `u16` to this function to
-    /// create a layout with fixed size chunks.
+    /// create a layout with fixed size chunks. Constraints with a priority, created with
+    /// [`Constraint::with_priority`], are accepted as well.
     ///
     /// Default values for the other fields are:
     ///
@@ -250,13 +268,13 @@ impl Layout {
     pub fn new<I>(direction: Direction, constraints: I) -> Self
     where
         I: IntoIterator,
-        I::Item: Into<Constraint>,
+        I::Item: Into<PrioritizedConstraint>,
     {
         Self {
             direction,
-            constraints: constraints.into_iter().map(Into::into).collect(),
             ..Self::default()
         }
+        .constraints(constraints)
     }
 
     /// Creates a new vertical layout with default values.
@@ -274,7 +292,7 @@ impl Layout {
     pub fn vertical<I>(constraints: I) -> Self
     where
         I: IntoIterator,
-        I::Item: Into<Constraint>,
+        I::Item: Into<PrioritizedConstraint>,
     {
         Self::new(Direction::Vertical, constraints.into_iter().map(Into::into))
     }
@@ -294,7 +312,7 @@ impl Layout {
     pub fn horizontal<I>(constraints: I) -> Self
     where
         I: IntoIterator,
-        I::Item: Into<Constraint>,
+        I::Item: Into<PrioritizedConstraint>,
     {
         Self::new(
             Direction::Horizontal,
@@ -309,23 +327,61 @@ impl Layout {
     /// By default, the cache size is [`Self::DEFAULT_CACHE_SIZE`].
     #[cfg(feature = "layout-cache")]
     pub fn init_cache(cache_size: NonZeroUsize) {
-        Self::resize_cache(cache_size);
+        Self::with_cache(|cache| cache.resize(cache_size));
+    }
+
+    /// Lets the layout cache grow as needed, up to `max_size` entries.
+    ///
+    /// Instead of evicting the least recently used layout when the cache is full, the cache doubles
+    /// its size until `max_size` is reached. This is useful for apps that compute a varying number
+    /// of layouts (e.g. depending on the data being displayed), where a fixed size is hard to
+    /// guess. Use [`Layout::cache_stats()`] to see how large the cache grew.
+    ///
+    /// Calling [`Layout::init_cache()`] switches back to a fixed size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use ratatui_core::layout::Layout;
+    ///
+    /// Layout::init_adaptive_cache(NonZeroUsize::new(10_000).unwrap());
+    /// ```
+    #[cfg(feature = "layout-cache")]
+    pub fn init_adaptive_cache(max_size: NonZeroUsize) {
+        Self::with_cache(|cache| cache.set_adaptive(max_size));
+    }
+
+    /// Returns the statistics of the layout cache.
+    ///
+    /// With the `std` feature, the layout cache is thread-local, so the statistics only cover
+    /// layouts computed on the current thread. See [`LayoutCacheStats`] for an example.
+    #[cfg(feature = "layout-cache")]
+    pub fn cache_stats() -> LayoutCacheStats {
+        Self::with_cache(|cache| cache.stats())
+    }
+
+    /// Resets the hits, misses and evictions of the layout cache to zero, without clearing the
+    /// cached layouts.
+    #[cfg(feature = "layout-cache")]
+    pub fn reset_cache_stats() {
+        Self::with_cache(StatsCache::reset_stats);
     }
 
     #[cfg(all(feature = "layout-cache", feature = "std"))]
-    fn resize_cache(cache_size: NonZeroUsize) {
-        LAYOUT_CACHE.with_borrow_mut(|cache| cache.resize(cache_size));
+    fn with_cache<R>(f: impl FnOnce(&mut Cache) -> R) -> R {
+        LAYOUT_CACHE.with_borrow_mut(f)
     }
 
     #[cfg(all(feature = "layout-cache", not(feature = "std")))]
-    fn resize_cache(cache_size: NonZeroUsize) {
+    fn with_cache<R>(f: impl FnOnce(&mut Cache) -> R) -> R {
         critical_section::with(|cs| {
             let mut cache = LAYOUT_CACHE.borrow(cs).borrow_mut();
-            match cache.as_mut() {
-                Some(c) => c.resize(cache_size),
-                None => *cache = Some(Cache::new(cache_size)),
-            }
-        });
+            f(cache.get_or_insert_with(|| {
+                Cache::new(NonZeroUsize::new(Self::DEFAULT_CACHE_SIZE).unwrap())
+            }))
+        })
     }
 
     /// Set the direction of the layout.
@@ -358,7 +414,8 @@ impl Layout {
     /// The `constraints` parameter accepts any type that implements `IntoIterator<Item =
     /// Into<Constraint>>`. This includes arrays, slices, vectors, iterators. `Into<Constraint>` is
     /// implemented on u16, so you can pass an array or vec of u16 to this function to create a
-    /// layout with fixed size chunks.
+    /// layout with fixed size chunks. Constraints with a priority, created with
+    /// [`Constraint::with_priority`], are accepted as well.
     ///
     /// Note that the constraints are applied to the whole area that is to be split, so using
     /// percentages and ratios with the other constraints may not have the desired effect of
@@ -402,9 +459,18 @@ impl Layout {
     pub fn constraints<I>(mut self, constraints: I) -> Self
     where
         I: IntoIterator,
-        I::Item: Into<Constraint>,
+        I::Item: Into<PrioritizedConstraint>,
     {
-        self.constraints = constraints.into_iter().map(Into::into).collect();
+        (self.constraints, self.priorities) = constraints
+            .into_iter()
+            .map(|constraint| {
+                let PrioritizedConstraint {
+                    constraint,
+                    priority,
+                } = constraint.into();
+                (constraint, priority)
+            })
+            .unzip();
         self
     }
 
@@ -551,6 +617,92 @@ impl Layout {
         self
     }
 
+    /// Sets the minimum size of the gaps between items in the layout.
+    ///
+    /// With [`Flex::SpaceBetween`], [`Flex::SpaceAround`] and [`Flex::SpaceEvenly`], the gaps
+    /// between the items grow to fill the excess space, and this sets how small they can get when
+    /// the space is short. With the other [`Flex`] modes, the gaps have the size set by
+    /// [`Layout::spacing`], and this raises it to at least `min_spacing`, e.g. to reserve room for
+    /// separators regardless of the spacing.
+    ///
+    /// The default is `0`, which sets no minimum, so an [overlap](Spacing::Overlap) is kept. The
+    /// gaps before the first item and after the last item are not affected. See
+    /// [`Layout::split_with_spacers`] to get the areas of the gaps.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Constraint::*;
+    /// use ratatui_core::layout::{Flex, Layout, Rect};
+    ///
+    /// let layout = Layout::horizontal([Length(4), Length(4)])
+    ///     .flex(Flex::SpaceBetween)
+    ///     .min_spacing(1);
+    /// let [left, right] = layout.areas(Rect::new(0, 0, 8, 1));
+    /// assert_eq!((left.x, left.width, right.x), (0, 4, 5));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_spacing(mut self, min_spacing: u16) -> Self {
+        self.min_spacing = min_spacing;
+        self
+    }
+
+    /// Sets the maximum size of the gaps between items in the layout.
+    ///
+    /// With [`Flex::SpaceBetween`], [`Flex::SpaceAround`] and [`Flex::SpaceEvenly`], this stops
+    /// the gaps between the items from growing past `max_spacing`, so that items don't drift too
+    /// far apart in a large area. The items then grow to fill the rest of the space. With the
+    /// other [`Flex`] modes, this lowers the size set by [`Layout::spacing`] to at most
+    /// `max_spacing`.
+    ///
+    /// By default the gaps have no maximum size. The gaps before the first item and after the
+    /// last item are not affected, and [`Layout::min_spacing`] takes precedence if it is larger.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Constraint::*;
+    /// use ratatui_core::layout::{Flex, Layout, Rect};
+    ///
+    /// let layout = Layout::horizontal([Length(4), Length(4), Length(4)])
+    ///     .flex(Flex::SpaceBetween)
+    ///     .max_spacing(2);
+    /// let [left, middle, right] = layout.areas(Rect::new(0, 0, 30, 1));
+    /// assert_eq!(middle.x - left.right(), 2);
+    /// assert_eq!(right.x - middle.right(), 2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_spacing(mut self, max_spacing: u16) -> Self {
+        self.max_spacing = Some(max_spacing);
+        self
+    }
+
+    /// Sets whether the results of this layout are stored in the layout cache.
+    ///
+    /// Caching is enabled by default. Disabling it is useful for layouts that are only computed
+    /// once (e.g. a popup for a particular piece of data), so that they don't evict layouts that
+    /// are computed on every frame. This only has an effect when the `layout-cache` feature is
+    /// enabled.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout};
+    ///
+    /// let layout = Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).cache(false);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn cache(mut self, enabled: bool) -> Self {
+        self.skip_cache = !enabled;
+        self
+    }
+
     /// Split the rect into a number of sub-rects according to the given [`Layout`].
     ///
     /// An ergonomic wrapper around [`Layout::split`] that returns an array of `Rect`s instead of
@@ -738,7 +890,11 @@ impl Layout {
     pub fn split_with_spacers(&self, area: Rect) -> (Segments, Spacers) {
         #[cfg(feature = "layout-cache")]
         {
-            self.cached_split(area)
+            if self.skip_cache {
+                self.split_layout(area)
+            } else {
+                self.cached_split(area)
+            }
         }
 
         #[cfg(not(feature = "layout-cache"))]
@@ -752,7 +908,12 @@ impl Layout {
     fn cached_split(&self, area: Rect) -> (Segments, Spacers) {
         LAYOUT_CACHE.with_borrow_mut(|cache| {
             let key = (area, self.clone());
-            cache.get_or_insert(key, || self.split_layout(area)).clone()
+            if let Some(result) = cache.get(&key) {
+                return result.clone();
+            }
+            let result = self.split_layout(area);
+            cache.put(key, result.clone());
+            result
         })
     }
 
@@ -761,33 +922,22 @@ impl Layout {
     fn cached_split(&self, area: Rect) -> (Segments, Spacers) {
         // Check cache inside critical section, but compute outside to avoid
         // blocking interrupts during the (expensive) constraint solver.
-        let cached = critical_section::with(|cs| {
-            let mut cache = LAYOUT_CACHE.borrow(cs).borrow_mut();
-            let cache = cache.get_or_insert_with(|| {
-                Cache::new(NonZeroUsize::new(Self::DEFAULT_CACHE_SIZE).unwrap())
-            });
+        let cached = Self::with_cache(|cache| {
             let key = (area, self.clone());
             cache
                 .get(&key)
                 .map(|(s, sp)| (Rc::from(s.as_slice()), Rc::from(sp.as_slice())))
         });
 
-        match cached {
-            Some(result) => result,
-            None => {
-                let result = self.split_layout(area);
-
-                critical_section::with(|cs| {
-                    let mut cache = LAYOUT_CACHE.borrow(cs).borrow_mut();
-                    if let Some(cache) = cache.as_mut() {
-                        let key = (area, self.clone());
-                        cache.put(key, (result.0.to_vec(), result.1.to_vec()));
-                    }
-                });
-
-                result
-            }
+        if let Some(result) = cached {
+            return result;
         }
+        let result = self.split_layout(area);
+        Self::with_cache(|cache| {
+            let key = (area, self.clone());
+            cache.put(key, (result.0.to_vec(), result.1.to_vec()));
+        });
+        result
     }
 
     fn split_layout(&self, area: Rect) -> (Segments, Spacers) {
@@ -877,8 +1027,27 @@ impl Layout {
         configure_area(&mut solver, area_size, area_start, area_end)?;
         configure_variable_in_area_constraints(&mut solver, &variables, area_size)?;
         configure_variable_constraints(&mut solver, &variables)?;
-        configure_flex_constraints(&mut solver, area_size, &spacers, flex, spacing)?;
-        configure_constraints(&mut solver, area_size, &segments, constraints, flex)?;
+        configure_flex_constraints(
+            &mut solver,
+            area_size,
+            &spacers,
+            flex,
+            spacing,
+            (self.min_spacing, self.max_spacing),
+        )?;
+        configure_spacing_bounds(
+            &mut solver,
+            &spacers,
+            flex,
+            (self.min_spacing, self.max_spacing),
+        )?;
+        configure_constraints(
+            &mut solver,
+            area_size,
+            &segments,
+            (constraints, &self.priorities),
+            flex,
+        )?;
         configure_fill_constraints(&mut solver, &segments, constraints, flex)?;
 
         if !flex.is_legacy() {
@@ -947,38 +1116,44 @@ fn configure_constraints(
     solver: &mut Solver,
     area: Element,
     segments: &[Element],
-    constraints: &[Constraint],
+    (constraints, priorities): (&[Constraint], &[Priority]),
     flex: Flex,
 ) -> Result<(), AddConstraintError> {
-    for (&constraint, &segment) in constraints.iter().zip(segments.iter()) {
+    for (i, (&constraint, &segment)) in constraints.iter().zip(segments.iter()).enumerate() {
+        let priority = priorities.get(i).copied().unwrap_or_default();
+        // raise or lower the strengths of the constraint, but keep them below the required ones
+        let strength = |strength: Strength| {
+            let value = strength.value() * priority.factor();
+            Strength::new(value.min((Strength::REQUIRED - Strength::WEAK).value()))
+        };
         match constraint {
-            Constraint::Max(max) => {
-                solver.add_constraint(segment.has_max_size(max, MAX_SIZE_LE))?;
-                solver.add_constraint(segment.has_int_size(max, MAX_SIZE_EQ))?;
+            Constraint::Max(max) | Constraint::Content(max) => {
+                solver.add_constraint(segment.has_max_size(max, strength(MAX_SIZE_LE)))?;
+                solver.add_constraint(segment.has_int_size(max, strength(MAX_SIZE_EQ)))?;
             }
             Constraint::Min(min) => {
-                solver.add_constraint(segment.has_min_size(min as i16, MIN_SIZE_GE))?;
+                solver.add_constraint(segment.has_min_size(min as i16, strength(MIN_SIZE_GE)))?;
                 if flex.is_legacy() {
-                    solver.add_constraint(segment.has_int_size(min, MIN_SIZE_EQ))?;
+                    solver.add_constraint(segment.has_int_size(min, strength(MIN_SIZE_EQ)))?;
                 } else {
-                    solver.add_constraint(segment.has_size(area, FILL_GROW))?;
+                    solver.add_constraint(segment.has_size(area, strength(FILL_GROW)))?;
                 }
             }
             Constraint::Length(length) => {
-                solver.add_constraint(segment.has_int_size(length, LENGTH_SIZE_EQ))?;
+                solver.add_constraint(segment.has_int_size(length, strength(LENGTH_SIZE_EQ)))?;
             }
             Constraint::Percentage(p) => {
                 let size = area.size() * f64::from(p) / 100.00;
-                solver.add_constraint(segment.has_size(size, PERCENTAGE_SIZE_EQ))?;
+                solver.add_constraint(segment.has_size(size, strength(PERCENTAGE_SIZE_EQ)))?;
             }
             Constraint::Ratio(num, den) => {
                 // avoid division by zero by using 1 when denominator is 0
                 let size = area.size() * f64::from(num) / f64::from(den.max(1));
-                solver.add_constraint(segment.has_size(size, RATIO_SIZE_EQ))?;
+                solver.add_constraint(segment.has_size(size, strength(RATIO_SIZE_EQ)))?;
             }
             Constraint::Fill(_) => {
                 // given no other constraints, this segment will grow as much as possible.
-                solver.add_constraint(segment.has_size(area, FILL_GROW))?;
+                solver.add_constraint(segment.has_size(area, strength(FILL_GROW)))?;
             }
         }
     }
@@ -991,9 +1166,17 @@ fn configure_flex_constraints(
     spacers: &[Element],
     flex: Flex,
     spacing: i16,
+    (min_spacing, max_spacing): (u16, Option<u16>),
 ) -> Result<(), AddConstraintError> {
     let spacers_except_first_and_last = spacers.get(1..spacers.len() - 1).unwrap_or(&[]);
-    let spacing_f64 = f64::from(spacing) * FLOAT_PRECISION_MULTIPLIER;
+    // the fixed size of the gaps for the modes that don't grow them, a zero minimum spacing keeps
+    // the segments overlapping
+    let requested = i32::from(spacing);
+    let mut fixed_spacing = max_spacing.map_or(requested, |max| requested.min(max.into()));
+    if min_spacing > 0 {
+        fixed_spacing = fixed_spacing.max(min_spacing.into());
+    }
+    let spacing_f64 = f64::from(fixed_spacing) * FLOAT_PRECISION_MULTIPLIER;
     match flex {
         Flex::Legacy => {
             for spacer in spacers_except_first_and_last {
@@ -1104,6 +1287,33 @@ fn configure_flex_constraints(
     Ok(())
 }
 
+/// Keeps the gaps between the segments within the minimum and maximum spacing, for the flex modes
+/// where the gaps grow to fill the remaining space.
+fn configure_spacing_bounds(
+    solver: &mut Solver,
+    spacers: &[Element],
+    flex: Flex,
+    (min_spacing, max_spacing): (u16, Option<u16>),
+) -> Result<(), AddConstraintError> {
+    if !matches!(
+        flex,
+        Flex::SpaceAround | Flex::SpaceEvenly | Flex::SpaceBetween
+    ) {
+        return Ok(());
+    }
+    let spacers_except_first_and_last = spacers.get(1..spacers.len() - 1).unwrap_or(&[]);
+    let min_spacing = i16::try_from(min_spacing).unwrap_or(i16::MAX);
+    for spacer in spacers_except_first_and_last {
+        if let Some(max_spacing) = max_spacing {
+            solver.add_constraint(spacer.has_max_size(max_spacing, SPACER_SIZE_EQ))?;
+        }
+        if min_spacing > 0 {
+            solver.add_constraint(spacer.has_min_size(min_spacing, SPACER_SIZE_EQ))?;
+        }
+    }
+    Ok(())
+}
+
 /// Make every `Fill` constraint proportionally equal to each other
 /// This will make it fill up empty spaces equally
 ///
@@ -1432,6 +1642,58 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg(all(feature = "layout-cache", feature = "std"))]
+    fn cache_stats() {
+        Layout::init_cache(NonZeroUsize::new(2).unwrap());
+        Layout::reset_cache_stats();
+        let area = Rect::new(0, 0, 10, 10);
+        let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]);
+        layout.split(area);
+        layout.split(area);
+        layout.split(Rect::new(0, 0, 10, 5));
+        layout.split(Rect::new(0, 0, 10, 6));
+        assert_eq!(
+            Layout::cache_stats(),
+            LayoutCacheStats {
+                hits: 1,
+                misses: 3,
+                evictions: 1,
+                len: 2,
+                capacity: 2,
+            }
+        );
+
+        Layout::reset_cache_stats();
+        let stats = Layout::cache_stats();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (0, 0, 0));
+    }
+
+    #[test]
+    #[cfg(all(feature = "layout-cache", feature = "std"))]
+    fn adaptive_cache() {
+        Layout::init_cache(NonZeroUsize::new(2).unwrap());
+        Layout::init_adaptive_cache(NonZeroUsize::new(5).unwrap());
+        Layout::reset_cache_stats();
+        let layout = Layout::vertical([Constraint::Fill(1)]);
+        for height in 0..6 {
+            layout.split(Rect::new(0, 0, 10, height));
+        }
+        let stats = Layout::cache_stats();
+        assert_eq!((stats.len, stats.capacity, stats.evictions), (5, 5, 1));
+    }
+
+    #[test]
+    #[cfg(all(feature = "layout-cache", feature = "std"))]
+    fn cache_disabled() {
+        Layout::reset_cache_stats();
+        let layout = Layout::vertical([Constraint::Fill(1)]).cache(false);
+        let [area] = layout.areas(Rect::new(0, 0, 10, 10));
+        assert_eq!(area, Rect::new(0, 0, 10, 10));
+        let stats = Layout::cache_stats();
+        assert_eq!((stats.hits, stats.misses), (0, 0));
+    }
+
     #[test]
     fn default() {
         assert_eq!(
@@ -1440,8 +1702,12 @@ mod tests {
                 direction: Direction::Vertical,
                 margin: Margin::new(0, 0),
                 constraints: vec![],
+                priorities: vec![],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                min_spacing: 0,
+                max_spacing: None,
+                skip_cache: false,
             }
         );
     }
@@ -1485,8 +1751,12 @@ mod tests {
                 direction: Direction::Vertical,
                 margin: Margin::new(0, 0),
                 constraints: vec![Constraint::Min(0)],
+                priorities: vec![Priority::Normal],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                min_spacing: 0,
+                max_spacing: None,
+                skip_cache: false,
             }
         );
     }
@@ -1499,8 +1769,12 @@ mod tests {
                 direction: Direction::Horizontal,
                 margin: Margin::new(0, 0),
                 constraints: vec![Constraint::Min(0)],
+                priorities: vec![Priority::Normal],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                min_spacing: 0,
+                max_spacing: None,
+                skip_cache: false,
             }
         );
     }
@@ -2420,6 +2694,8 @@ mod tests {
         #[case::space_fill10(vec![Fill(0), Length(20)], vec![0..80, 80..100])]
         #[case::space_fill11(vec![Fill(0), Min(20)], vec![0..80, 80..100])]
         #[case::space_fill12(vec![Fill(0), Max(20)], vec![0..80, 80..100])]
+        #[case::space_fill13(vec![Content(20), Fill(1)], vec![0..20, 20..100])]
+        #[case::space_fill14(vec![Content(150), Fill(1)], vec![0..100, 100..100])]
         #[case::fill_collapse1(vec![Fill(1), Fill(1), Fill(1), Min(30), Length(50)], vec![0..7, 7..13, 13..20, 20..50, 50..100])]
         #[case::fill_collapse2(vec![Fill(1), Fill(1), Fill(1), Length(50), Length(50)], vec![0..0, 0..0, 0..0, 0..50, 50..100])]
         #[case::fill_collapse3(vec![Fill(1), Fill(1), Fill(1), Length(75), Length(50)], vec![0..0, 0..0, 0..0, 0..75, 75..100])]
@@ -2602,6 +2878,54 @@ mod tests {
             assert_eq!(result, expected);
         }
 
+        #[rstest]
+        #[case::lengths(vec![(0, 10), (10, 40)], [Length(20).with_priority(Priority::Low), Length(40).into()])]
+        #[case::lengths_high(vec![(0, 20), (20, 30)], [Length(20).with_priority(Priority::High), Length(40).into()])]
+        #[case::min_over_length(vec![(0, 30), (30, 20)], [Min(30).into(), Length(30).into()])]
+        #[case::length_over_min(vec![(0, 20), (20, 30)], [Min(30).with_priority(Priority::Lowest), Length(30).into()])]
+        #[case::length_over_percentage(vec![(0, 30), (30, 20)], [Percentage(80).into(), Length(20).into()])]
+        #[case::percentage_over_length(vec![(0, 40), (40, 10)], [Percentage(80).with_priority(Priority::High), Length(20).into()])]
+        fn constraint_priority(
+            #[case] expected: Vec<(u16, u16)>,
+            #[case] constraints: [PrioritizedConstraint; 2],
+        ) {
+            let result = Layout::horizontal(constraints)
+                .split(Rect::new(0, 0, 50, 1))
+                .iter()
+                .map(|r| (r.x, r.width))
+                .collect::<Vec<(u16, u16)>>();
+            assert_eq!(result, expected);
+        }
+
+        #[rstest]
+        #[case::start_min(vec![(0, 4), (6, 4)], Flex::Start, 0, 2, None)]
+        #[case::start_max(vec![(0, 4), (5, 4)], Flex::Start, 3, 0, Some(1))]
+        #[case::center_min(vec![(5, 4), (11, 4)], Flex::Center, -1, 2, None)]
+        #[case::between_min(vec![(0, 4), (16, 4)], Flex::SpaceBetween, 0, 2, None)]
+        #[case::between_capped(vec![(0, 9), (11, 9)], Flex::SpaceBetween, 0, 0, Some(2))]
+        #[case::evenly_max(vec![(1, 9), (11, 8)], Flex::SpaceEvenly, 0, 0, Some(1))]
+        fn flex_min_max_spacing(
+            #[case] expected: Vec<(u16, u16)>,
+            #[case] flex: Flex,
+            #[case] spacing: i16,
+            #[case] min_spacing: u16,
+            #[case] max_spacing: Option<u16>,
+        ) {
+            let mut layout = Layout::horizontal([Length(4), Length(4)])
+                .flex(flex)
+                .spacing(spacing)
+                .min_spacing(min_spacing);
+            if let Some(max_spacing) = max_spacing {
+                layout = layout.max_spacing(max_spacing);
+            }
+            let result = layout
+                .split(Rect::new(0, 0, 20, 1))
+                .iter()
+                .map(|r| (r.x, r.width))
+                .collect::<Vec<(u16, u16)>>();
+            assert_eq!(result, expected);
+        }
+
         #[rstest]
         #[case::a(vec![(0, 25), (25, 75)], vec![Length(25), Length(25)])]
         #[case::b(vec![(0, 25), (25, 75)], vec![Length(25), Percentage(25)])]
@@ -9,7 +9,7 @@ use crate::layout::{Margin, Offset, Position, Size};
 mod iter;
 mod ops;
 
-use super::{Constraint, Flex, Layout};
+use super::{Constraint, Direction, Flex, Layout};
 
 /// A rectangular area in the terminal.
 ///
@@ -273,6 +273,57 @@ impl Rect {
         }
     }
 
+    /// Returns a new `Rect` inside the current one, with a different padding on each side.
+    ///
+    /// This is the asymmetric version of [`Rect::inner`]. If the padding is larger than the `Rect`,
+    /// the returned `Rect` has no width or height, and stays within the current one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// // leave room for a border on the left and a scrollbar on the right
+    /// let area = Rect::new(0, 0, 20, 10).padded(1, 2, 0, 1);
+    /// assert_eq!(area, Rect::new(1, 0, 17, 9));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn padded(self, left: u16, right: u16, top: u16, bottom: u16) -> Self {
+        let x = self.x.saturating_add(left);
+        let y = self.y.saturating_add(top);
+        Self {
+            x: if x < self.right() { x } else { self.right() },
+            y: if y < self.bottom() { y } else { self.bottom() },
+            width: self.width.saturating_sub(left.saturating_add(right)),
+            height: self.height.saturating_sub(top.saturating_add(bottom)),
+        }
+    }
+
+    /// Returns a new `Rect` outside the current one, with a different margin on each side.
+    ///
+    /// This is the asymmetric version of [`Rect::outer`], and is truncated the same way to keep the
+    /// bounds within `u16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(5, 5, 10, 10).expanded(1, 2, 3, 4);
+    /// assert_eq!(area, Rect::new(4, 2, 13, 17));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn expanded(self, left: u16, right: u16, top: u16, bottom: u16) -> Self {
+        let x = self.x.saturating_sub(left);
+        let y = self.y.saturating_sub(top);
+        Self {
+            x,
+            y,
+            width: self.right().saturating_add(right).saturating_sub(x),
+            height: self.bottom().saturating_add(bottom).saturating_sub(y),
+        }
+    }
+
     /// Moves the `Rect` without modifying its size.
     ///
     /// Moves the `Rect` according to the given offset without modifying its [`width`](Rect::width)
@@ -557,6 +608,74 @@ impl Rect {
             .centered_vertically(vertical_constraint)
     }
 
+    /// Returns the largest `Rect` with the given aspect ratio that fits in the current one,
+    /// centered in it.
+    ///
+    /// The ratio is the width to the height in cells. Terminal cells are usually about twice as
+    /// tall as they are wide, so an area that looks square (e.g. for a canvas) has a ratio of
+    /// `2:1`. If either side of the ratio is zero, the returned `Rect` has no area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// // a square that looks square in an 80x24 terminal
+    /// let area = Rect::new(0, 0, 80, 24).with_aspect_ratio(2, 1);
+    /// assert_eq!(area, Rect::new(16, 0, 48, 24));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub fn with_aspect_ratio(self, width: u16, height: u16) -> Self {
+        if width == 0 || height == 0 {
+            return Self {
+                x: self.x + self.width / 2,
+                y: self.y + self.height / 2,
+                width: 0,
+                height: 0,
+            };
+        }
+        let (ratio_width, ratio_height) = (u32::from(width), u32::from(height));
+        let width_for_height = u32::from(self.height) * ratio_width / ratio_height;
+        let (width, height) = if width_for_height <= u32::from(self.width) {
+            (width_for_height as u16, self.height)
+        } else {
+            let height = u32::from(self.width) * ratio_height / ratio_width;
+            (self.width, height as u16)
+        };
+        Self {
+            x: self.x + (self.width - width) / 2,
+            y: self.y + (self.height - height) / 2,
+            width,
+            height,
+        }
+    }
+
+    /// Splits the `Rect` into `n` parts of (almost) the same size in the given direction.
+    ///
+    /// This is a shortcut for a [`Layout`] of `n` [`Constraint::Fill`] constraints. When the size
+    /// can't be divided evenly, some parts are one cell larger than the others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Direction, Rect};
+    ///
+    /// let columns = Rect::new(0, 0, 9, 2).split_evenly(3, Direction::Horizontal);
+    /// assert_eq!(
+    ///     columns,
+    ///     [
+    ///         Rect::new(0, 0, 3, 2),
+    ///         Rect::new(3, 0, 3, 2),
+    ///         Rect::new(6, 0, 3, 2)
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn split_evenly(self, n: usize, direction: Direction) -> alloc::vec::Vec<Self> {
+        let constraints = core::iter::repeat_n(Constraint::Fill(1), n);
+        self.layout_vec(&Layout::new(direction, constraints))
+    }
+
     /// Split the rect into a number of sub-rects according to the given [`Layout`].
     ///
     /// An ergonomic wrapper around [`Layout::split`] that returns an array of `Rect`s instead of
@@ -1062,6 +1181,60 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::fits(Rect::new(0, 0, 20, 10), Rect::new(1, 3, 17, 6))]
+    #[case::too_wide(Rect::new(5, 5, 2, 10), Rect::new(6, 8, 0, 6))]
+    #[case::too_tall(Rect::new(5, 5, 20, 3), Rect::new(6, 8, 17, 0))]
+    fn padded(#[case] rect: Rect, #[case] expected: Rect) {
+        assert_eq!(rect.padded(1, 2, 3, 1), expected);
+    }
+
+    #[test]
+    fn expanded() {
+        assert_eq!(
+            Rect::new(10, 10, 10, 10).expanded(1, 2, 3, 4),
+            Rect::new(9, 7, 13, 17)
+        );
+        // left / top saturation truncates the size
+        assert_eq!(
+            Rect::new(1, 2, 10, 10).expanded(5, 0, 5, 0),
+            Rect::new(0, 0, 11, 12)
+        );
+        assert_eq!(
+            Rect::new(u16::MAX - 5, 0, 5, 5).expanded(0, 10, 0, 0),
+            Rect::new(u16::MAX - 5, 0, 5, 5)
+        );
+    }
+
+    #[rstest]
+    #[case::wide(Rect::new(0, 0, 80, 24), 2, 1, Rect::new(16, 0, 48, 24))]
+    #[case::tall(Rect::new(0, 0, 20, 40), 2, 1, Rect::new(0, 15, 20, 10))]
+    #[case::offset(Rect::new(10, 10, 16, 9), 16, 9, Rect::new(10, 10, 16, 9))]
+    #[case::zero(Rect::new(0, 0, 10, 10), 0, 1, Rect::new(5, 5, 0, 0))]
+    fn with_aspect_ratio(
+        #[case] rect: Rect,
+        #[case] width: u16,
+        #[case] height: u16,
+        #[case] expected: Rect,
+    ) {
+        assert_eq!(rect.with_aspect_ratio(width, height), expected);
+    }
+
+    #[test]
+    fn split_evenly() {
+        let rect = Rect::new(1, 2, 4, 6);
+        assert_eq!(
+            rect.split_evenly(3, Direction::Vertical),
+            [
+                Rect::new(1, 2, 4, 2),
+                Rect::new(1, 4, 4, 2),
+                Rect::new(1, 6, 4, 2)
+            ]
+        );
+        assert_eq!(rect.split_evenly(2, Direction::Horizontal).len(), 2);
+        assert!(rect.split_evenly(0, Direction::Horizontal).is_empty());
+    }
+
     #[test]
     fn layout() {
         let layout = Layout::horizontal([Constraint::Length(3), Constraint::Min(0)]);
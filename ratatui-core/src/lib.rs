@@ -78,8 +78,12 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod accessibility;
 pub mod backend;
 pub mod buffer;
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
+pub mod event;
 pub mod layout;
 pub mod style;
 pub mod symbols;
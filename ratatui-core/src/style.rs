@@ -72,17 +72,25 @@ use core::fmt;
 
 use bitflags::bitflags;
 pub use color::{Color, ParseColorError};
+pub use color_support::ColorSupport;
+pub use human::ParseStyleError;
 use stylize::ColorDebugKind;
 pub use stylize::{Styled, Stylize};
+pub use theme::Theme;
+pub use underline_style::UnderlineStyle;
 
 #[cfg(feature = "anstyle")]
 mod anstyle;
 mod color;
+mod color_support;
+pub mod human;
 pub mod palette;
 #[cfg(feature = "palette")]
 mod palette_conversion;
 #[macro_use]
 mod stylize;
+mod theme;
+mod underline_style;
 
 bitflags! {
     /// Modifier changes the way a piece of text is displayed.
@@ -111,6 +119,7 @@ bitflags! {
         const REVERSED          = 0b0000_0100_0000;
         const HIDDEN            = 0b0000_1000_0000;
         const CROSSED_OUT       = 0b0001_0000_0000;
+        const OVERLINED         = 0b0010_0000_0000;
     }
 }
 
@@ -149,6 +158,19 @@ impl fmt::Debug for Modifier {
 ///
 /// For more information about the style shorthands, see the [`Stylize`] trait.
 ///
+/// Styles can be written as and parsed from a compact text format, which can also be used by serde
+/// with the [`human`] module. See the [`Display`](fmt::Display) implementation for a description
+/// of the format.
+///
+/// ```rust
+/// use ratatui_core::style::{Style, Stylize};
+///
+/// let style: Style = "bold fg:black bg:green".parse()?;
+/// assert_eq!(style, Style::new().black().on_green().bold());
+/// assert_eq!(style.to_string(), "bold fg:black bg:green");
+/// # Ok::<(), ratatui_core::style::ParseStyleError>(())
+/// ```
+///
 /// We implement conversions from [`Color`] and [`Modifier`] to [`Style`] so you can use them
 /// anywhere that accepts `Into<Style>`.
 ///
@@ -197,6 +219,8 @@ impl fmt::Debug for Modifier {
 ///         bg: Some(Color::Red),
 ///         #[cfg(feature = "underline-color")]
 ///         underline_color: Some(Color::Green),
+///         #[cfg(feature = "underline-style")]
+///         underline_style: Some(ratatui_core::style::UnderlineStyle::Single),
 ///         add_modifier: Modifier::BOLD | Modifier::UNDERLINED,
 ///         sub_modifier: Modifier::empty(),
 ///     },
@@ -228,6 +252,8 @@ impl fmt::Debug for Modifier {
 ///         bg: Some(Color::Reset),
 ///         #[cfg(feature = "underline-color")]
 ///         underline_color: Some(Color::Reset),
+///         #[cfg(feature = "underline-style")]
+///         underline_style: Some(ratatui_core::style::UnderlineStyle::Single),
 ///         add_modifier: Modifier::empty(),
 ///         sub_modifier: Modifier::empty(),
 ///     },
@@ -236,6 +262,9 @@ impl fmt::Debug for Modifier {
 /// ```
 #[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// `underline_style` ends with the name of the struct, but it matches the `underline_color` field,
+// the `Style::underline_style` method and the field of the same name on `Cell`.
+#[cfg_attr(feature = "underline-style", allow(clippy::struct_field_names))]
 pub struct Style {
     /// The foreground color.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
@@ -247,6 +276,13 @@ pub struct Style {
     #[cfg(feature = "underline-color")]
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub underline_color: Option<Color>,
+    /// The underline style.
+    #[cfg(feature = "underline-style")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub underline_style: Option<UnderlineStyle>,
     /// The modifiers to add.
     #[cfg_attr(
         feature = "serde",
@@ -303,6 +339,8 @@ impl Style {
             bg: None,
             #[cfg(feature = "underline-color")]
             underline_color: None,
+            #[cfg(feature = "underline-style")]
+            underline_style: None,
             add_modifier: Modifier::empty(),
             sub_modifier: Modifier::empty(),
         }
@@ -315,6 +353,8 @@ impl Style {
             bg: Some(Color::Reset),
             #[cfg(feature = "underline-color")]
             underline_color: Some(Color::Reset),
+            #[cfg(feature = "underline-style")]
+            underline_style: Some(UnderlineStyle::Single),
             add_modifier: Modifier::empty(),
             sub_modifier: Modifier::all(),
         }
@@ -389,6 +429,36 @@ impl Style {
         self
     }
 
+    /// Changes the underline style, e.g. to a curly underline. The text must be underlined with a
+    /// modifier for this to work.
+    ///
+    /// This uses the non-standard `4:x` SGR sub-parameters. It is implemented in the crossterm and
+    /// termwiz backends and enabled by the `underline-style` feature flag. See [`UnderlineStyle`]
+    /// for more information.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize, UnderlineStyle};
+    ///
+    /// let style = Style::new()
+    ///     .underlined()
+    ///     .underline_style(UnderlineStyle::Curly);
+    /// let diff = Style::new().underline_style(UnderlineStyle::Double);
+    /// assert_eq!(
+    ///     style.patch(diff),
+    ///     Style::new()
+    ///         .underlined()
+    ///         .underline_style(UnderlineStyle::Double)
+    /// );
+    /// ```
+    #[cfg(feature = "underline-style")]
+    #[must_use = "`underline_style` returns the modified style without modifying the original"]
+    pub const fn underline_style(mut self, underline_style: UnderlineStyle) -> Self {
+        self.underline_style = Some(underline_style);
+        self
+    }
+
     /// Changes the text emphasis.
     ///
     /// When applied, it adds the given modifier to the `Style` modifiers.
@@ -478,6 +548,11 @@ impl Style {
             self.underline_color = other.underline_color.or(self.underline_color);
         }
 
+        #[cfg(feature = "underline-style")]
+        {
+            self.underline_style = other.underline_style.or(self.underline_style);
+        }
+
         self.add_modifier.remove(other.sub_modifier);
         self.add_modifier.insert(other.add_modifier);
         self.sub_modifier.remove(other.add_modifier);
@@ -503,6 +578,12 @@ impl Style {
                 .stylize_debug(ColorDebugKind::Underline)
                 .fmt(f)?;
         }
+        #[cfg(feature = "underline-style")]
+        if let Some(underline_style) = self.underline_style {
+            f.write_fmt(format_args!(
+                ".underline_style(UnderlineStyle::{underline_style:?})"
+            ))?;
+        }
         for modifier in self.add_modifier.iter() {
             match modifier {
                 Modifier::BOLD => f.write_str(".bold()")?,
@@ -514,6 +595,7 @@ impl Style {
                 Modifier::REVERSED => f.write_str(".reversed()")?,
                 Modifier::HIDDEN => f.write_str(".hidden()")?,
                 Modifier::CROSSED_OUT => f.write_str(".crossed_out()")?,
+                Modifier::OVERLINED => f.write_str(".overlined()")?,
                 _ => f.write_fmt(format_args!(".add_modifier(Modifier::{modifier:?})"))?,
             }
         }
@@ -528,6 +610,7 @@ impl Style {
                 Modifier::REVERSED => f.write_str(".not_reversed()")?,
                 Modifier::HIDDEN => f.write_str(".not_hidden()")?,
                 Modifier::CROSSED_OUT => f.write_str(".not_crossed_out()")?,
+                Modifier::OVERLINED => f.write_str(".not_overlined()")?,
                 _ => f.write_fmt(format_args!(".remove_modifier(Modifier::{modifier:?})"))?,
             }
         }
@@ -560,6 +643,7 @@ impl Style {
     modifier!(pub const Modifier::REVERSED, reversed(), not_reversed() -> Self);
     modifier!(pub const Modifier::HIDDEN, hidden(), not_hidden() -> Self);
     modifier!(pub const Modifier::CROSSED_OUT, crossed_out(), not_crossed_out() -> Self);
+    modifier!(pub const Modifier::OVERLINED, overlined(), not_overlined() -> Self);
 }
 
 impl From<Color> for Style {
@@ -720,6 +804,27 @@ mod tests {
         assert_eq!(format!("{style:?}"), expected);
     }
 
+    #[cfg(feature = "underline-style")]
+    #[test]
+    fn underline_style() {
+        let style = Style::new()
+            .underlined()
+            .underline_style(UnderlineStyle::Curly);
+        assert_eq!(style.underline_style, Some(UnderlineStyle::Curly));
+        assert_eq!(
+            format!("{style:?}"),
+            "Style::new().underline_style(UnderlineStyle::Curly).underlined()"
+        );
+        assert_eq!(
+            style.patch(Style::new()).underline_style,
+            Some(UnderlineStyle::Curly)
+        );
+        assert_eq!(
+            style.patch(Style::reset()).underline_style,
+            Some(UnderlineStyle::Single)
+        );
+    }
+
     #[test]
     fn combined_patch_gives_same_result_as_individual_patch() {
         let styles = [
@@ -762,6 +867,7 @@ mod tests {
             Modifier::REVERSED,
             Modifier::HIDDEN,
             Modifier::CROSSED_OUT,
+            Modifier::OVERLINED,
         ];
 
         let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
@@ -786,10 +892,11 @@ mod tests {
     #[case(Modifier::REVERSED, "REVERSED")]
     #[case(Modifier::HIDDEN, "HIDDEN")]
     #[case(Modifier::CROSSED_OUT, "CROSSED_OUT")]
+    #[case(Modifier::OVERLINED, "OVERLINED")]
     #[case(Modifier::BOLD | Modifier::DIM, "BOLD | DIM")]
     #[case(
         Modifier::all(),
-        "BOLD | DIM | ITALIC | UNDERLINED | SLOW_BLINK | RAPID_BLINK | REVERSED | HIDDEN | CROSSED_OUT"
+        "BOLD | DIM | ITALIC | UNDERLINED | SLOW_BLINK | RAPID_BLINK | REVERSED | HIDDEN | CROSSED_OUT | OVERLINED"
     )]
     fn modifier_debug(#[case] modifier: Modifier, #[case] expected: &str) {
         assert_eq!(format!("{modifier:?}"), expected);
@@ -904,6 +1011,7 @@ mod tests {
     #[case(Style::new().reversed(), Modifier::REVERSED)]
     #[case(Style::new().hidden(), Modifier::HIDDEN)]
     #[case(Style::new().crossed_out(), Modifier::CROSSED_OUT)]
+    #[case(Style::new().overlined(), Modifier::OVERLINED)]
     fn add_modifier_can_be_stylized(#[case] stylized: Style, #[case] expected: Modifier) {
         assert_eq!(stylized, Style::new().add_modifier(expected));
     }
@@ -918,6 +1026,7 @@ mod tests {
     #[case(Style::new().not_reversed(), Modifier::REVERSED)]
     #[case(Style::new().not_hidden(), Modifier::HIDDEN)]
     #[case(Style::new().not_crossed_out(), Modifier::CROSSED_OUT)]
+    #[case(Style::new().not_overlined(), Modifier::OVERLINED)]
     fn remove_modifier_can_be_stylized(#[case] stylized: Style, #[case] expected: Modifier) {
         assert_eq!(stylized, Style::new().remove_modifier(expected));
     }
@@ -1005,6 +1114,8 @@ mod tests {
             bg: Some(Color::White),
             #[cfg(feature = "underline-color")]
             underline_color: Some(Color::Indexed(3)),
+            #[cfg(feature = "underline-style")]
+            underline_style: None,
             add_modifier: Modifier::UNDERLINED,
             sub_modifier: Modifier::CROSSED_OUT,
         };
@@ -1041,6 +1152,8 @@ mod tests {
             bg: None,
             #[cfg(feature = "underline-color")]
             underline_color: None,
+            #[cfg(feature = "underline-style")]
+            underline_style: None,
             add_modifier: Modifier::empty(),
             sub_modifier: Modifier::empty(),
         };
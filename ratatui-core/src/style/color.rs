@@ -1,6 +1,9 @@
 #![allow(clippy::unreadable_literal)]
 
+use alloc::format;
+use alloc::string::String;
 use core::fmt;
+use core::ops::Range;
 use core::str::FromStr;
 
 use crate::style::stylize::{ColorDebug, ColorDebugKind};
@@ -37,6 +40,16 @@ use crate::style::stylize::{ColorDebug, ColorDebugKind};
 /// - we support `-` and `_` and ` ` as separators for all colors
 /// - we support both `gray` and `grey` spellings
 ///
+/// RGB colors can be written in the following CSS forms:
+///
+/// - `#RRGGBB` and `#RGB` hex notation
+/// - `#RRGGBBAA` and `#RGBA` hex notation, where the alpha channel is ignored
+/// - `rgb(r, g, b)` with components from `0` to `255` or percentages, e.g. `rgb(255, 50%, 0)`
+/// - `hsl(h, s%, l%)` with the hue in degrees and the saturation and lightness in percent
+///
+/// `rgba()` and `hsla()` are accepted as well, ignoring the alpha channel. [`Color::to_hex`] and
+/// the [`Display`](fmt::Display) implementation write RGB colors in the `#RRGGBB` form.
+///
 /// `From<Color> for Style` is implemented by creating a style with the foreground color set to the
 /// given color. This allows you to use colors anywhere that accepts `Into<Style>`.
 ///
@@ -62,6 +75,10 @@ use crate::style::stylize::{ColorDebug, ColorDebugKind};
 /// assert_eq!("light-black".parse(), Ok(Color::DarkGray));
 /// assert_eq!("white".parse(), Ok(Color::White));
 /// assert_eq!("bright white".parse(), Ok(Color::White));
+/// assert_eq!("#f80".parse(), Ok(Color::Rgb(255, 136, 0)));
+/// assert_eq!("#ff880080".parse(), Ok(Color::Rgb(255, 136, 0)));
+/// assert_eq!("rgb(255, 136, 0)".parse(), Ok(Color::Rgb(255, 136, 0)));
+/// assert_eq!("hsl(120, 100%, 25%)".parse(), Ok(Color::Rgb(0, 128, 0)));
 /// ```
 ///
 /// [ANSI color table]: https://en.wikipedia.org/wiki/ANSI_escape_code#Colors
@@ -136,6 +153,318 @@ impl Color {
         let b = u as u8;
         Self::Rgb(r, g, b)
     }
+
+    /// Returns the color in the `#RRGGBB` hex notation, or `None` if it is not an RGB color.
+    ///
+    /// Named and indexed colors don't have a fixed RGB value, as it depends on the terminal's
+    /// color scheme. Use the [`Display`](fmt::Display) implementation to write any color in a
+    /// form that can be parsed back with [`FromStr`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(255, 136, 0).to_hex(), Some("#FF8800".into()));
+    /// assert_eq!(Color::Red.to_hex(), None);
+    /// ```
+    pub fn to_hex(self) -> Option<String> {
+        match self {
+            Self::Rgb(r, g, b) => Some(format!("#{r:02X}{g:02X}{b:02X}")),
+            _ => None,
+        }
+    }
+
+    /// Converts an RGB color to the nearest color of the xterm 256 color palette.
+    ///
+    /// The color is chosen from the 6×6×6 color cube (indexes 16 to 231) and the grayscale ramp
+    /// (indexes 232 to 255), as the first 16 colors depend on the terminal's color scheme. Colors
+    /// other than [`Color::Rgb`] are returned as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(255, 135, 0).to_indexed(), Color::Indexed(208));
+    /// assert_eq!(Color::Rgb(128, 128, 128).to_indexed(), Color::Indexed(244));
+    /// assert_eq!(Color::Red.to_indexed(), Color::Red);
+    /// ```
+    #[must_use]
+    pub const fn to_indexed(self) -> Self {
+        let Self::Rgb(r, g, b) = self else {
+            return self;
+        };
+        let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+        let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+
+        // the grayscale ramp goes from 8 to 238 in steps of 10
+        let average = (r as u16 + g as u16 + b as u16) / 3;
+        let gray_index = if average < 8 {
+            0
+        } else if average > 238 {
+            23
+        } else {
+            (average - 3) / 10
+        };
+        let gray = 8 + gray_index as u8 * 10;
+
+        if distance((r, g, b), (gray, gray, gray)) < distance((r, g, b), cube) {
+            Self::Indexed(232 + gray_index as u8)
+        } else {
+            Self::Indexed(16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8)
+        }
+    }
+
+    /// Converts an RGB or indexed color to the nearest of the 16 named ANSI colors.
+    ///
+    /// The named colors are matched using the RGB values of the default xterm color scheme.
+    /// Named colors and [`Color::Reset`] are returned as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0, 180, 0).to_ansi16(), Color::Green);
+    /// assert_eq!(Color::Indexed(9).to_ansi16(), Color::LightRed);
+    /// assert_eq!(Color::Indexed(231).to_ansi16(), Color::White);
+    /// ```
+    #[must_use]
+    pub const fn to_ansi16(self) -> Self {
+        let rgb = match self {
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Indexed(index) if index < 16 => return ANSI16[index as usize].0,
+            Self::Indexed(index) => indexed_to_rgb(index),
+            _ => return self,
+        };
+        let mut nearest = 0;
+        let mut i = 1;
+        while i < ANSI16.len() {
+            if distance(rgb, ANSI16[i].1) < distance(rgb, ANSI16[nearest].1) {
+                nearest = i;
+            }
+            i += 1;
+        }
+        ANSI16[nearest].0
+    }
+
+    /// Returns the color at `t` between this color and `other`, with `t` from `0.0` (this color) to
+    /// `1.0` (the other color).
+    ///
+    /// The colors are interpolated linearly in RGB space, and `t` is clamped to the `0.0..=1.0`
+    /// range. Indexed and named colors are interpolated using the RGB values of the xterm palette,
+    /// so the result is always a [`Color::Rgb`], unless one of the colors is [`Color::Reset`], in
+    /// which case the nearest of the two colors is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// let yellow = Color::Rgb(255, 255, 0);
+    /// let red = Color::Rgb(255, 0, 0);
+    /// assert_eq!(Color::lerp(yellow, red, 0.5), Color::Rgb(255, 128, 0));
+    /// assert_eq!(yellow.lerp(red, 2.0), red);
+    /// ```
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let (Some(from), Some(to)) = (self.rgb(), other.rgb()) else {
+            return if t < 0.5 { self } else { other };
+        };
+        let channel = |from: u8, to: u8| {
+            let (from, to) = (f32::from(from), f32::from(to));
+            unit_to_u8((from + (to - from) * t) / 255.0)
+        };
+        Self::Rgb(
+            channel(from.0, to.0),
+            channel(from.1, to.1),
+            channel(from.2, to.2),
+        )
+    }
+
+    /// Returns `steps` colors evenly spaced from this color to `other`, both included.
+    ///
+    /// See [`Color::lerp`] for how the colors are interpolated. A single step returns this color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// let gradient: Vec<Color> = Color::Black.gradient(Color::White, 3).collect();
+    /// assert_eq!(
+    ///     gradient,
+    ///     [
+    ///         Color::Rgb(0, 0, 0),
+    ///         Color::Rgb(128, 128, 128),
+    ///         Color::Rgb(255, 255, 255)
+    ///     ]
+    /// );
+    /// ```
+    pub fn gradient(self, other: Self, steps: usize) -> impl ExactSizeIterator<Item = Self> {
+        let last = steps.saturating_sub(1).max(1) as f32;
+        (0..steps).map(move |step| self.lerp(other, step as f32 / last))
+    }
+
+    /// Returns the color with its lightness increased by `amount`, from `0.0` to `1.0`.
+    ///
+    /// The lightness is the one of the HSL representation of the color, so `lighten(1.0)` always
+    /// returns white. See [`Color::lerp`] for how indexed and named colors are converted to RGB.
+    /// [`Color::Reset`] is returned as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0, 0, 128).lighten(0.25), Color::Rgb(0, 0, 255));
+    /// ```
+    #[must_use]
+    pub fn lighten(self, amount: f32) -> Self {
+        self.map_hsl(|hue, saturation, lightness| (hue, saturation, lightness + amount))
+    }
+
+    /// Returns the color with its lightness decreased by `amount`, from `0.0` to `1.0`.
+    ///
+    /// This is the opposite of [`Color::lighten`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0, 0, 255).darken(0.25), Color::Rgb(0, 0, 128));
+    /// ```
+    #[must_use]
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Returns the color with its saturation increased by `amount`, from `0.0` to `1.0`.
+    ///
+    /// The saturation is the one of the HSL representation of the color. See [`Color::lerp`] for
+    /// how indexed and named colors are converted to RGB. [`Color::Reset`] is returned as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::Rgb(96, 64, 64).saturate(0.2),
+    ///     Color::Rgb(112, 48, 48)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn saturate(self, amount: f32) -> Self {
+        self.map_hsl(|hue, saturation, lightness| (hue, saturation + amount, lightness))
+    }
+
+    /// Returns the color with its saturation decreased by `amount`, from `0.0` to `1.0`.
+    ///
+    /// This is the opposite of [`Color::saturate`], and `desaturate(1.0)` returns a gray.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::Rgb(255, 0, 0).desaturate(1.0),
+    ///     Color::Rgb(128, 128, 128)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Returns the RGB value of the color, using the xterm palette for indexed and named colors.
+    fn rgb(self) -> Option<(u8, u8, u8)> {
+        match self {
+            Self::Reset => None,
+            Self::Rgb(r, g, b) => Some((r, g, b)),
+            Self::Indexed(index) => Some(indexed_to_rgb(index)),
+            named => ANSI16
+                .iter()
+                .find(|(color, _)| *color == named)
+                .map(|(_, rgb)| *rgb),
+        }
+    }
+
+    /// Converts the color to HSL, applies `f` and converts the result back to RGB.
+    fn map_hsl(self, f: impl FnOnce(f32, f32, f32) -> (f32, f32, f32)) -> Self {
+        let Some(rgb) = self.rgb() else {
+            return self;
+        };
+        let (hue, saturation, lightness) = rgb_to_hsl(rgb);
+        let (hue, saturation, lightness) = f(hue, saturation, lightness);
+        let (r, g, b) = hsl_to_rgb(hue, saturation, lightness);
+        Self::Rgb(r, g, b)
+    }
+}
+
+/// The named colors with the RGB values of the default xterm color scheme, by ANSI index.
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// The values of each channel in the xterm 6×6×6 color cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Returns the index of the nearest level of the color cube.
+const fn cube_index(value: u8) -> usize {
+    if value < 48 {
+        0
+    } else if value < 115 {
+        1
+    } else {
+        (value as usize - 35) / 40
+    }
+}
+
+/// Returns the RGB value of a color of the xterm 256 color palette.
+const fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..16 => ANSI16[index as usize].1,
+        16..232 => {
+            let index = (index - 16) as usize;
+            (
+                CUBE_LEVELS[index / 36],
+                CUBE_LEVELS[index / 6 % 6],
+                CUBE_LEVELS[index % 6],
+            )
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Returns the squared euclidean distance between two RGB colors.
+const fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = a.0.abs_diff(b.0) as u32;
+    let dg = a.1.abs_diff(b.1) as u32;
+    let db = a.2.abs_diff(b.2) as u32;
+    dr * dr + dg * dg + db * db
 }
 
 #[cfg(feature = "serde")]
@@ -259,7 +588,8 @@ impl core::error::Error for ParseColorError {}
 /// Converts a string representation to a `Color` instance.
 ///
 /// The `from_str` function attempts to parse the given string and convert it to the corresponding
-/// `Color` variant. It supports named colors, RGB values, and indexed colors. If the string cannot
+/// `Color` variant. It supports named colors, RGB values in the CSS hex, `rgb()` and `hsl()` forms,
+/// and indexed colors. If the string cannot
 /// be parsed, a `ParseColorError` is returned.
 ///
 /// See the [`Color`] documentation for more information on the supported color names.
@@ -277,6 +607,9 @@ impl core::error::Error for ParseColorError {}
 /// let color: Color = Color::from_str("#FF0000").unwrap();
 /// assert_eq!(color, Color::Rgb(255, 0, 0));
 ///
+/// let color: Color = Color::from_str("rgb(255, 0, 0)").unwrap();
+/// assert_eq!(color, Color::Rgb(255, 0, 0));
+///
 /// let color: Color = Color::from_str("10").unwrap();
 /// assert_eq!(color, Color::Indexed(10));
 ///
@@ -321,7 +654,9 @@ impl FromStr for Color {
                 _ => {
                     if let Ok(index) = s.parse::<u8>() {
                         Self::Indexed(index)
-                    } else if let Some((r, g, b)) = parse_hex_color(s) {
+                    } else if let Some((r, g, b)) =
+                        parse_hex_color(s).or_else(|| parse_css_function(s))
+                    {
                         Self::Rgb(r, g, b)
                     } else {
                         return Err(ParseColorError);
@@ -332,14 +667,129 @@ impl FromStr for Color {
     }
 }
 
+/// Parses the `#RRGGBB`, `#RRGGBBAA`, `#RGB` and `#RGBA` hex notations, ignoring the alpha channel.
 fn parse_hex_color(input: &str) -> Option<(u8, u8, u8)> {
-    if !input.starts_with('#') || input.len() != 7 {
+    let hex = input.strip_prefix('#')?;
+    let channel = |range: Range<usize>| u8::from_str_radix(hex.get(range)?, 16).ok();
+    // a single hex digit is repeated, so `#f80` is the same as `#ff8800`
+    let short_channel = |index: usize| channel(index..index + 1).map(|c| c * 0x11);
+    match hex.len() {
+        6 | 8 => Some((channel(0..2)?, channel(2..4)?, channel(4..6)?)),
+        3 | 4 => Some((short_channel(0)?, short_channel(1)?, short_channel(2)?)),
+        _ => None,
+    }
+}
+
+/// Parses the `rgb()`, `rgba()`, `hsl()` and `hsla()` CSS functions, ignoring the alpha channel.
+///
+/// Arguments can be separated by commas or whitespace, and the alpha channel by a `/`.
+fn parse_css_function(input: &str) -> Option<(u8, u8, u8)> {
+    let input = input.trim().to_ascii_lowercase();
+    let (name, args) = input.strip_suffix(')')?.split_once('(')?;
+    let mut args = args
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|arg| !arg.is_empty());
+    let (a, b, c) = (args.next()?, args.next()?, args.next()?);
+    let alpha = args.next();
+    if args.next().is_some() {
         return None;
     }
-    let r = u8::from_str_radix(input.get(1..3)?, 16).ok()?;
-    let g = u8::from_str_radix(input.get(3..5)?, 16).ok()?;
-    let b = u8::from_str_radix(input.get(5..7)?, 16).ok()?;
-    Some((r, g, b))
+    if let Some(alpha) = alpha {
+        parse_css_number(alpha.strip_suffix('%').unwrap_or(alpha))?;
+    }
+    match name.trim() {
+        "rgb" | "rgba" => Some((
+            parse_rgb_channel(a)?,
+            parse_rgb_channel(b)?,
+            parse_rgb_channel(c)?,
+        )),
+        "hsl" | "hsla" => {
+            let hue = parse_css_number(a.strip_suffix("deg").unwrap_or(a))?;
+            let saturation = parse_css_number(b.strip_suffix('%').unwrap_or(b))?;
+            let lightness = parse_css_number(c.strip_suffix('%').unwrap_or(c))?;
+            Some(hsl_to_rgb(hue, saturation / 100.0, lightness / 100.0))
+        }
+        _ => None,
+    }
+}
+
+/// Parses an `rgb()` channel, either from `0` to `255` or as a percentage.
+fn parse_rgb_channel(input: &str) -> Option<u8> {
+    match input.strip_suffix('%') {
+        Some(percent) => Some(unit_to_u8(parse_css_number(percent)? / 100.0)),
+        None => Some(unit_to_u8(parse_css_number(input)? / 255.0)),
+    }
+}
+
+fn parse_css_number(input: &str) -> Option<f32> {
+    input.parse::<f32>().ok().filter(|n| n.is_finite())
+}
+
+/// Converts a value from `0.0` to `1.0` to a `u8`, clamping values outside of the range.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn unit_to_u8(value: f32) -> u8 {
+    // float to int casts saturate, so this clamps the value
+    (value * 255.0 + 0.5) as u8
+}
+
+/// Converts HSL to RGB, see <https://www.w3.org/TR/css-color-3/#hsl-color>.
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> (u8, u8, u8) {
+    let hue = (hue % 360.0 + 360.0) % 360.0 / 360.0;
+    let saturation = saturation.clamp(0.0, 1.0);
+    let lightness = lightness.clamp(0.0, 1.0);
+    let t2 = if lightness <= 0.5 {
+        lightness * (saturation + 1.0)
+    } else {
+        lightness + saturation - lightness * saturation
+    };
+    let t1 = lightness * 2.0 - t2;
+    let hue_to_rgb = |h: f32| {
+        let h = if h < 0.0 {
+            h + 1.0
+        } else if h > 1.0 {
+            h - 1.0
+        } else {
+            h
+        };
+        let value = if h * 6.0 < 1.0 {
+            t1 + (t2 - t1) * h * 6.0
+        } else if h * 2.0 < 1.0 {
+            t2
+        } else if h * 3.0 < 2.0 {
+            t1 + (t2 - t1) * (2.0 / 3.0 - h) * 6.0
+        } else {
+            t1
+        };
+        unit_to_u8(value)
+    };
+    (
+        hue_to_rgb(hue + 1.0 / 3.0),
+        hue_to_rgb(hue),
+        hue_to_rgb(hue - 1.0 / 3.0),
+    )
+}
+
+/// Converts RGB to HSL, with the hue in degrees and the saturation and lightness from 0 to 1.
+fn rgb_to_hsl((r, g, b): (u8, u8, u8)) -> (f32, f32, f32) {
+    let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+    let lightness = f32::from(u16::from(max) + u16::from(min)) / 2.0 / 255.0;
+    if max == min {
+        return (0.0, 0.0, lightness);
+    }
+    let delta = f32::from(max - min);
+    let saturation = if lightness <= 0.5 {
+        delta / 255.0 / (lightness * 2.0)
+    } else {
+        delta / 255.0 / (2.0 - lightness * 2.0)
+    };
+    let hue = if max == r {
+        (f32::from(g) - f32::from(b)) / delta
+    } else if max == g {
+        (f32::from(b) - f32::from(r)) / delta + 2.0
+    } else {
+        (f32::from(r) - f32::from(g)) / delta + 4.0
+    };
+    (hue * 60.0, saturation, lightness)
 }
 
 impl fmt::Display for Color {
@@ -511,12 +961,11 @@ impl From<(u8, u8, u8, u8)> for Color {
 #[cfg(test)]
 mod tests {
     use alloc::boxed::Box;
-    use alloc::format;
+    use alloc::vec::Vec;
     use core::error::Error;
 
     #[cfg(feature = "palette")]
     use palette::{Hsl, Hsluv};
-    #[cfg(feature = "palette")]
     use rstest::rstest;
     #[cfg(feature = "serde")]
     use serde::de::{Deserialize, IntoDeserializer};
@@ -648,13 +1097,151 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::hex("#ff8800", Color::Rgb(255, 136, 0))]
+    #[case::hex_uppercase("#FF8800", Color::Rgb(255, 136, 0))]
+    #[case::short_hex("#f80", Color::Rgb(255, 136, 0))]
+    #[case::hex_with_alpha("#ff880080", Color::Rgb(255, 136, 0))]
+    #[case::short_hex_with_alpha("#f808", Color::Rgb(255, 136, 0))]
+    #[case::rgb("rgb(255, 136, 0)", Color::Rgb(255, 136, 0))]
+    #[case::rgb_without_commas("rgb(255 136 0)", Color::Rgb(255, 136, 0))]
+    #[case::rgb_percent("rgb(100%, 50%, 0%)", Color::Rgb(255, 128, 0))]
+    #[case::rgb_clamped("rgb(300, -1, 0)", Color::Rgb(255, 0, 0))]
+    #[case::rgba("rgba(255, 136, 0, 0.5)", Color::Rgb(255, 136, 0))]
+    #[case::rgb_slash_alpha("RGB(255 136 0 / 50%)", Color::Rgb(255, 136, 0))]
+    #[case::hsl_red("hsl(0, 100%, 50%)", Color::Rgb(255, 0, 0))]
+    #[case::hsl_green("hsl(120, 100%, 25%)", Color::Rgb(0, 128, 0))]
+    #[case::hsl_negative_hue("hsl(-120deg, 100%, 50%)", Color::Rgb(0, 0, 255))]
+    #[case::hsl_gray("hsl(0, 0%, 50%)", Color::Rgb(128, 128, 128))]
+    #[case::hsla("hsla(60, 100%, 50%, 1)", Color::Rgb(255, 255, 0))]
+    fn from_css_color(#[case] input: &str, #[case] expected: Color) {
+        assert_eq!(Color::from_str(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::unknown_function("rgbx(1, 2, 3)")]
+    #[case::missing_parenthesis("rgb(1, 2, 3")]
+    #[case::too_few_args("rgb(1, 2)")]
+    #[case::too_many_args("rgb(1, 2, 3, 4, 5)")]
+    #[case::not_a_number("hsl(red, 100%, 50%)")]
+    #[case::invalid_alpha("rgba(1, 2, 3, x)")]
+    fn from_invalid_css_color(#[case] input: &str) {
+        assert_eq!(Color::from_str(input), Err(ParseColorError));
+    }
+
+    #[rstest]
+    #[case::black(Color::Rgb(0, 0, 0), Color::Indexed(16))]
+    #[case::white(Color::Rgb(255, 255, 255), Color::Indexed(231))]
+    #[case::red(Color::Rgb(255, 0, 0), Color::Indexed(196))]
+    #[case::cube(Color::Rgb(100, 140, 170), Color::Indexed(67))]
+    #[case::gray(Color::Rgb(100, 100, 100), Color::Indexed(241))]
+    #[case::near_gray(Color::Rgb(120, 124, 118), Color::Indexed(243))]
+    #[case::named(Color::Blue, Color::Blue)]
+    #[case::indexed(Color::Indexed(42), Color::Indexed(42))]
+    fn to_indexed(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.to_indexed(), expected);
+    }
+
+    #[rstest]
+    #[case::black(Color::Rgb(10, 10, 10), Color::Black)]
+    #[case::white(Color::Rgb(250, 250, 250), Color::White)]
+    #[case::blue(Color::Rgb(0, 0, 200), Color::Blue)]
+    #[case::dark_gray(Color::Rgb(120, 120, 130), Color::DarkGray)]
+    #[case::orange(Color::Rgb(255, 165, 0), Color::Yellow)]
+    #[case::indexed_named(Color::Indexed(4), Color::Blue)]
+    #[case::indexed_cube(Color::Indexed(196), Color::LightRed)]
+    #[case::indexed_gray(Color::Indexed(232), Color::Black)]
+    #[case::named(Color::Cyan, Color::Cyan)]
+    #[case::reset(Color::Reset, Color::Reset)]
+    fn to_ansi16(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.to_ansi16(), expected);
+    }
+
+    #[test]
+    fn indexed_to_rgb_round_trips() {
+        for index in 16..=255 {
+            let (r, g, b) = indexed_to_rgb(index);
+            assert_eq!(Color::Rgb(r, g, b).to_indexed(), Color::Indexed(index));
+        }
+    }
+
+    #[rstest]
+    #[case::start(0.0, Color::Rgb(0, 100, 200))]
+    #[case::middle(0.5, Color::Rgb(100, 150, 200))]
+    #[case::end(1.0, Color::Rgb(200, 200, 200))]
+    #[case::clamped(-1.0, Color::Rgb(0, 100, 200))]
+    fn lerp(#[case] t: f32, #[case] expected: Color) {
+        assert_eq!(
+            Color::Rgb(0, 100, 200).lerp(Color::Rgb(200, 200, 200), t),
+            expected
+        );
+    }
+
+    #[test]
+    fn lerp_non_rgb_colors() {
+        assert_eq!(
+            Color::Black.lerp(Color::Indexed(231), 1.0),
+            Color::Rgb(255, 255, 255)
+        );
+        assert_eq!(Color::Reset.lerp(Color::Red, 0.4), Color::Reset);
+        assert_eq!(Color::Reset.lerp(Color::Red, 0.6), Color::Red);
+    }
+
+    #[test]
+    fn gradient() {
+        let gradient = Color::Rgb(0, 0, 0).gradient(Color::Rgb(0, 0, 90), 4);
+        assert_eq!(gradient.len(), 4);
+        assert_eq!(
+            gradient.collect::<Vec<_>>(),
+            [
+                Color::Rgb(0, 0, 0),
+                Color::Rgb(0, 0, 30),
+                Color::Rgb(0, 0, 60),
+                Color::Rgb(0, 0, 90)
+            ]
+        );
+        assert_eq!(
+            Color::Red.gradient(Color::Blue, 1).collect::<Vec<_>>(),
+            [Color::Rgb(205, 0, 0)]
+        );
+        assert_eq!(Color::Red.gradient(Color::Blue, 0).count(), 0);
+    }
+
+    #[rstest]
+    #[case::red((255, 0, 0), (0.0, 1.0, 0.5))]
+    #[case::dark_green((0, 128, 0), (120.0, 1.0, 128.0 / 255.0 / 2.0))]
+    #[case::gray((128, 128, 128), (0.0, 0.0, 128.0 / 255.0))]
+    fn rgb_to_hsl_round_trips(#[case] rgb: (u8, u8, u8), #[case] hsl: (f32, f32, f32)) {
+        assert_eq!(rgb_to_hsl(rgb), hsl);
+        assert_eq!(hsl_to_rgb(hsl.0, hsl.1, hsl.2), rgb);
+    }
+
+    #[test]
+    fn adjust_hsl() {
+        let color = Color::Rgb(64, 128, 192);
+        assert_eq!(color.lighten(1.0), Color::Rgb(255, 255, 255));
+        assert_eq!(color.darken(1.0), Color::Rgb(0, 0, 0));
+        assert_eq!(color.desaturate(1.0), Color::Rgb(128, 128, 128));
+        assert_eq!(Color::Reset.lighten(0.5), Color::Reset);
+    }
+
+    #[test]
+    fn to_hex() {
+        assert_eq!(Color::Rgb(255, 136, 0).to_hex(), Some("#FF8800".into()));
+        assert_eq!(Color::Indexed(10).to_hex(), None);
+        assert_eq!(Color::Reset.to_hex(), None);
+        let color = Color::Rgb(1, 2, 3);
+        assert_eq!(Color::from_str(&color.to_hex().unwrap()), Ok(color));
+    }
+
     #[test]
     fn from_invalid_colors() {
         let bad_colors = [
             "invalid_color", // not a color string
             "abcdef0",       // 7 chars is not a color
             " bcdefa",       // doesn't start with a '#'
-            "#abcdef00",     // too many chars
+            "#abcdef000",    // too many chars
+            "#abcde",        // 5 chars is not a color
             "#1🦀2",         // len 7 but on char boundaries shouldn't panic
             "resets",        // typo
             "lightblackk",   // typo
@@ -729,7 +1316,7 @@ mod tests {
         assert!(color.is_err());
 
         let color: Result<_, serde::de::value::Error> =
-            Color::deserialize("#00000000".into_deserializer());
+            Color::deserialize("#000000000".into_deserializer());
         assert!(color.is_err());
     }
 
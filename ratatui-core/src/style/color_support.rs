@@ -0,0 +1,175 @@
+use alloc::string::String;
+
+use strum::{Display, EnumString};
+
+use crate::style::Color;
+
+/// The colors that a terminal is able to display.
+///
+/// Terminals without truecolor support display [`Color::Rgb`] colors incorrectly, or not at all.
+/// Set the color support of the [`Terminal`] with [`TerminalOptions::color_support`] or
+/// [`Terminal::set_color_support`] to convert colors to the nearest color the terminal supports
+/// when drawing, so that applications can use RGB colors without providing fallback palettes.
+///
+/// Backends can detect the color support of the terminal with `Backend::color_support`, which
+/// usually uses [`ColorSupport::detect`].
+///
+/// The variants are ordered from the least to the most colors, so they can be compared.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::style::{Color, ColorSupport};
+///
+/// assert_eq!(
+///     ColorSupport::Ansi256.downsample(Color::Rgb(255, 0, 0)),
+///     Color::Indexed(196)
+/// );
+/// assert_eq!(
+///     ColorSupport::Ansi16.downsample(Color::Rgb(255, 0, 0)),
+///     Color::LightRed
+/// );
+/// assert_eq!(ColorSupport::NoColor.downsample(Color::Red), Color::Reset);
+/// ```
+///
+/// [`Terminal`]: crate::terminal::Terminal
+/// [`Terminal::set_color_support`]: crate::terminal::Terminal::set_color_support
+/// [`TerminalOptions::color_support`]: crate::terminal::TerminalOptions::color_support
+#[derive(
+    Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorSupport {
+    /// The terminal doesn't support colors, all colors are reset.
+    NoColor,
+    /// The 16 named ANSI colors are supported.
+    Ansi16,
+    /// The 256 colors of the xterm palette ([`Color::Indexed`]) are supported.
+    Ansi256,
+    /// 24-bit RGB colors are supported. Colors are drawn as is.
+    #[default]
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Converts the color to the nearest color that is supported.
+    pub const fn downsample(self, color: Color) -> Color {
+        match self {
+            Self::NoColor => Color::Reset,
+            Self::Ansi16 => color.to_ansi16(),
+            Self::Ansi256 => color.to_indexed(),
+            Self::TrueColor => color,
+        }
+    }
+
+    /// Detects the color support from the values of the `COLORTERM` and `TERM` environment
+    /// variables.
+    ///
+    /// This follows the conventions used by most terminals: `COLORTERM` is set to `truecolor` or
+    /// `24bit` by terminals that support RGB colors, and `TERM` contains `256color` for terminals
+    /// that support the xterm 256 color palette. A `TERM` of `dumb` has no color support. Any other
+    /// terminal is assumed to support the 16 ANSI colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::ColorSupport;
+    ///
+    /// let support = ColorSupport::from_env_vars(None, Some("xterm-256color"));
+    /// assert_eq!(support, ColorSupport::Ansi256);
+    /// ```
+    pub fn from_env_vars(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return Self::TrueColor;
+        }
+        match term {
+            Some("dumb") => Self::NoColor,
+            Some(term) if term.contains("direct") || term.contains("truecolor") => Self::TrueColor,
+            Some(term) if term.contains("256color") => Self::Ansi256,
+            _ => Self::Ansi16,
+        }
+    }
+
+    /// Detects the color support from environment variables read by `lookup`.
+    ///
+    /// This reads the `COLORTERM` and `TERM` variables and uses [`ColorSupport::from_env_vars`].
+    /// If the `NO_COLOR` variable is set to a non-empty value, [`ColorSupport::NoColor`] is
+    /// returned (see <https://no-color.org>).
+    ///
+    /// `lookup` returns the value of the variable with the given name, which allows this to be
+    /// used without `std`. With `std`, use `|name| std::env::var(name).ok()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::ColorSupport;
+    ///
+    /// let color_support = ColorSupport::detect(|name| std::env::var(name).ok());
+    /// ```
+    pub fn detect<F: Fn(&str) -> Option<String>>(lookup: F) -> Self {
+        if lookup("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return Self::NoColor;
+        }
+        Self::from_env_vars(lookup("COLORTERM").as_deref(), lookup("TERM").as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::truecolor(Some("truecolor"), Some("xterm"), ColorSupport::TrueColor)]
+    #[case::bit24(Some("24bit"), None, ColorSupport::TrueColor)]
+    #[case::direct(None, Some("xterm-direct"), ColorSupport::TrueColor)]
+    #[case::ansi256(None, Some("screen-256color"), ColorSupport::Ansi256)]
+    #[case::ansi16(None, Some("xterm"), ColorSupport::Ansi16)]
+    #[case::unknown(Some("yes"), None, ColorSupport::Ansi16)]
+    #[case::dumb(None, Some("dumb"), ColorSupport::NoColor)]
+    fn from_env_vars(
+        #[case] colorterm: Option<&str>,
+        #[case] term: Option<&str>,
+        #[case] expected: ColorSupport,
+    ) {
+        assert_eq!(ColorSupport::from_env_vars(colorterm, term), expected);
+    }
+
+    #[rstest]
+    #[case::no_color(ColorSupport::NoColor, Color::Reset)]
+    #[case::ansi16(ColorSupport::Ansi16, Color::Green)]
+    #[case::ansi256(ColorSupport::Ansi256, Color::Indexed(34))]
+    #[case::truecolor(ColorSupport::TrueColor, Color::Rgb(0, 180, 0))]
+    fn downsample(#[case] support: ColorSupport, #[case] expected: Color) {
+        assert_eq!(support.downsample(Color::Rgb(0, 180, 0)), expected);
+    }
+
+    #[test]
+    fn detect() {
+        let lookup = |vars: &'static [(&str, &str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| (*value).into())
+            }
+        };
+        let detect = |vars| ColorSupport::detect(lookup(vars));
+        assert_eq!(detect(&[("TERM", "xterm-256color")]), ColorSupport::Ansi256);
+        assert_eq!(
+            detect(&[("COLORTERM", "truecolor"), ("NO_COLOR", "1")]),
+            ColorSupport::NoColor
+        );
+        assert_eq!(
+            detect(&[("COLORTERM", "truecolor"), ("NO_COLOR", "")]),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(detect(&[]), ColorSupport::Ansi16);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(ColorSupport::NoColor < ColorSupport::Ansi16);
+        assert!(ColorSupport::Ansi256 < ColorSupport::TrueColor);
+    }
+}
@@ -0,0 +1,367 @@
+//! The compact text format of [`Style`], e.g. `bold italic fg:#ff0000 bg:blue`.
+//!
+//! Styles are formatted in this format by their [`Display`](fmt::Display) implementation and parsed
+//! by their [`FromStr`] implementation. With the `serde` feature, the [`serialize`] and
+//! [`deserialize`] functions of this module can be used to store a style field in this format
+//! instead of a map of fields, which is easier to write by hand in a theme file:
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # fn main() -> Result<(), serde_json::Error> {
+//! use ratatui_core::style::{Style, Stylize};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Theme {
+//!     #[serde(with = "ratatui_core::style::human")]
+//!     title: Style,
+//! }
+//!
+//! let theme: Theme = serde_json::from_str(r#"{"title":"bold fg:red"}"#)?;
+//! assert_eq!(theme.title, Style::new().red().bold());
+//! assert_eq!(serde_json::to_string(&theme)?, r#"{"title":"bold fg:red"}"#);
+//!
+//! // the map of fields is accepted as well, so existing theme files stay valid
+//! let theme: Theme = serde_json::from_str(r#"{"title":{"fg":"Red","add_modifier":"BOLD"}}"#)?;
+//! assert_eq!(theme.title, Style::new().red().bold());
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+use crate::style::{Color, Modifier, Style};
+
+/// Formats the style in the compact text format, e.g. `bold italic fg:#ff0000 bg:blue`.
+///
+/// The format is a list of whitespace separated tokens, in this order:
+///
+/// - the names of the added modifiers, in lower case with `-` between words (e.g. `bold`,
+///   `crossed-out`)
+/// - the names of the removed modifiers, prefixed with `not-` (e.g. `not-italic`)
+/// - `fg:<color>` and `bg:<color>`
+/// - `underline-color:<color>` and `underline-style:<style>`, when the `underline-color` and
+///   `underline-style` features are enabled
+///
+/// Colors are written as lower case names (e.g. `light-red`), `#rrggbb` hex values or indexes
+/// (e.g. `42`). The default style is formatted as an empty string. Parsing the output with
+/// [`Style::from_str`] always returns the same style.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::style::{Color, Style, Stylize};
+///
+/// let style = Style::new()
+///     .fg(Color::Rgb(255, 0, 0))
+///     .on_blue()
+///     .bold()
+///     .not_italic();
+/// assert_eq!(style.to_string(), "bold not-italic fg:#ff0000 bg:blue");
+/// ```
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        let mut token = |f: &mut fmt::Formatter<'_>, args: fmt::Arguments<'_>| {
+            let result = write!(f, "{separator}{args}");
+            separator = " ";
+            result
+        };
+        for (name, _) in self.add_modifier.iter_names() {
+            token(f, format_args!("{}", ModifierName(name)))?;
+        }
+        for (name, _) in self.sub_modifier.iter_names() {
+            token(f, format_args!("not-{}", ModifierName(name)))?;
+        }
+        if let Some(fg) = self.fg {
+            token(f, format_args!("fg:{}", ColorName(fg)))?;
+        }
+        if let Some(bg) = self.bg {
+            token(f, format_args!("bg:{}", ColorName(bg)))?;
+        }
+        #[cfg(feature = "underline-color")]
+        if let Some(color) = self.underline_color {
+            token(f, format_args!("underline-color:{}", ColorName(color)))?;
+        }
+        #[cfg(feature = "underline-style")]
+        if let Some(style) = self.underline_style {
+            let name = style.to_string().to_ascii_lowercase();
+            token(f, format_args!("underline-style:{name}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a style from the compact text format, e.g. `bold italic fg:#ff0000 bg:blue`.
+///
+/// See the [`Display`](fmt::Display) implementation of [`Style`] for a description of the format.
+/// Modifier names are case-insensitive and accept `_` between words. Colors accept everything
+/// that [`Color::from_str`] does, as long as it doesn't contain whitespace. The
+/// `underline-color` and `underline-style` tokens are ignored when the matching feature is not
+/// enabled, so the same text can be used with any set of features.
+///
+/// # Example
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use ratatui_core::style::{Color, Modifier, Style};
+///
+/// let style = Style::from_str("bold italic fg:#ff0000 bg:blue")?;
+/// assert_eq!(
+///     style,
+///     Style::new()
+///         .fg(Color::Rgb(255, 0, 0))
+///         .bg(Color::Blue)
+///         .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+/// );
+/// assert!(Style::from_str("bold fg:nope").is_err());
+/// # Ok::<(), ratatui_core::style::ParseStyleError>(())
+/// ```
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_whitespace().try_fold(Self::new(), |style, token| {
+            parse_token(style, token).ok_or_else(|| ParseStyleError {
+                token: token.to_string(),
+            })
+        })
+    }
+}
+
+/// Applies a single token of the text format to the style.
+fn parse_token(style: Style, token: &str) -> Option<Style> {
+    if let Some((key, value)) = token.split_once(':') {
+        return match key.to_ascii_lowercase().replace('_', "-").as_str() {
+            "fg" => Some(style.fg(value.parse().ok()?)),
+            "bg" => Some(style.bg(value.parse().ok()?)),
+            "underline-color" => {
+                let color: Color = value.parse().ok()?;
+                #[cfg(feature = "underline-color")]
+                let style = style.underline_color(color);
+                #[cfg(not(feature = "underline-color"))]
+                let _ = color;
+                Some(style)
+            }
+            "underline-style" => {
+                let underline_style: crate::style::UnderlineStyle = value.parse().ok()?;
+                #[cfg(feature = "underline-style")]
+                let style = style.underline_style(underline_style);
+                #[cfg(not(feature = "underline-style"))]
+                let _ = underline_style;
+                Some(style)
+            }
+            _ => None,
+        };
+    }
+    let (name, remove) = match token.split_at_checked(4) {
+        Some((prefix, name)) if prefix.eq_ignore_ascii_case("not-") => (name, true),
+        _ => (token, false),
+    };
+    let modifier = Modifier::from_name(&name.to_ascii_uppercase().replace('-', "_"))?;
+    if remove {
+        Some(style.remove_modifier(modifier))
+    } else {
+        Some(style.add_modifier(modifier))
+    }
+}
+
+/// Formats a modifier name such as `CROSSED_OUT` as `crossed-out`.
+struct ModifierName<'a>(&'a str);
+
+impl fmt::Display for ModifierName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.to_ascii_lowercase().replace('_', "-"))
+    }
+}
+
+/// Formats a color such as `LightRed` as `light-red` and `#FF0000` as `#ff0000`.
+struct ColorName(Color);
+
+impl fmt::Display for ColorName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.0.to_string();
+        let mut previous = None;
+        for c in name.chars() {
+            if c.is_ascii_uppercase() && previous.is_some_and(|p: char| p.is_ascii_lowercase()) {
+                f.write_str("-")?;
+            }
+            write!(f, "{}", c.to_ascii_lowercase())?;
+            previous = Some(c);
+        }
+        Ok(())
+    }
+}
+
+/// Error type indicating a failure to parse a style string.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ParseStyleError {
+    token: String,
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse Style: invalid token `{}`", self.token)
+    }
+}
+
+impl core::error::Error for ParseStyleError {}
+
+/// Serializes a style as a string in the compact text format, e.g. `"bold fg:red"`.
+///
+/// Use this with `#[serde(with = "ratatui_core::style::human")]`, see the [module](self)
+/// documentation.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails to write the string.
+#[cfg(feature = "serde")]
+pub fn serialize<S>(style: &Style, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(style)
+}
+
+/// Deserializes a style from a string in the compact text format, or from the map of fields that
+/// the [`Style`] serde implementation uses.
+///
+/// Use this with `#[serde(with = "ratatui_core::style::human")]`, see the [module](self)
+/// documentation.
+///
+/// # Errors
+///
+/// Returns an error if the string is not a valid style, with the token that failed to parse.
+#[cfg(feature = "serde")]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Style, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_any(StyleVisitor)
+}
+
+/// Visits a string in the compact text format or a map of fields.
+#[cfg(feature = "serde")]
+struct StyleVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for StyleVisitor {
+    type Value = Style;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a style string such as \"bold fg:red\" or a map of style fields")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        serde::Deserialize::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::default(Style::new(), "")]
+    #[case::modifiers(Style::new().bold().italic(), "bold italic")]
+    #[case::removed_modifiers(Style::new().bold().not_crossed_out(), "bold not-crossed-out")]
+    #[case::named_colors(Style::new().light_red().on_dark_gray(), "fg:light-red bg:dark-gray")]
+    #[case::rgb(Style::new().fg(Color::Rgb(255, 0, 16)), "fg:#ff0010")]
+    #[case::indexed(Style::new().bg(Color::Indexed(42)), "bg:42")]
+    #[case::reset(Style::new().fg(Color::Reset).bg(Color::Reset), "fg:reset bg:reset")]
+    fn round_trip(#[case] style: Style, #[case] text: &str) {
+        assert_eq!(style.to_string(), text);
+        assert_eq!(text.parse::<Style>(), Ok(style));
+    }
+
+    #[test]
+    fn from_str_is_lenient() {
+        assert_eq!(
+            "  BOLD   Crossed_Out\tFG:Bright-White not-Italic ".parse(),
+            Ok(Style::new().bold().crossed_out().not_italic().white())
+        );
+        assert_eq!(
+            "fg:rgb(1,2,3)".parse(),
+            Ok(Style::new().fg(Color::Rgb(1, 2, 3)))
+        );
+        assert_eq!("bold not-bold".parse(), Ok(Style::new().not_bold()));
+    }
+
+    #[rstest]
+    #[case::unknown_modifier("bold blinking", "blinking")]
+    #[case::unknown_color("fg:nope", "fg:nope")]
+    #[case::unknown_key("color:red", "color:red")]
+    #[case::unknown_underline_style("underline-style:wavy", "underline-style:wavy")]
+    fn from_str_error(#[case] text: &str, #[case] token: &str) {
+        assert_eq!(
+            text.parse::<Style>(),
+            Err(ParseStyleError {
+                token: token.to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn underline_tokens() {
+        let style: Style = "underlined underline-color:red underline-style:curly"
+            .parse()
+            .unwrap();
+        let expected = Style::new().underlined();
+        #[cfg(feature = "underline-color")]
+        let expected = expected.underline_color(Color::Red);
+        #[cfg(feature = "underline-style")]
+        let expected = expected.underline_style(crate::style::UnderlineStyle::Curly);
+        assert_eq!(style, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Theme {
+        #[serde(with = "crate::style::human")]
+        title: Style,
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let theme = Theme {
+            title: Style::new().fg(Color::Rgb(255, 0, 0)).on_blue().bold(),
+        };
+        let json = serde_json::to_string(&theme).unwrap();
+        assert_eq!(json, r#"{"title":"bold fg:#ff0000 bg:blue"}"#);
+        assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+
+        let fields = r##"{"title":{"fg":"#FF0000","bg":"Blue","add_modifier":"BOLD"}}"##;
+        assert_eq!(serde_json::from_str::<Theme>(fields).unwrap(), theme);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_error() {
+        let error = serde_json::from_str::<Theme>(r#"{"title":"bold fg:nope"}"#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("Failed to parse Style: invalid token `fg:nope`"),
+            "{error}"
+        );
+    }
+}
@@ -1,6 +1,15 @@
 #![allow(clippy::unreadable_literal)]
 
 //! A module for defining color palettes.
+//!
+//! The [`tailwind`] and [`material`] palettes use RGB colors. The palettes can be converted for
+//! terminals with limited color support with their `downsample` and `to_indexed` methods, and
+//! [`ansi256`] has the Tailwind palettes already converted to the xterm 256 color palette.
+//!
+//! Converting a palette lets a theme built from it degrade gracefully on terminals with limited
+//! color support. As the conversion methods are `const fn`, the converted palettes can be
+//! constants.
 
+pub mod ansi256;
 pub mod material;
 pub mod tailwind;
@@ -0,0 +1,75 @@
+//! The Tailwind palettes mapped to the xterm 256 color palette.
+//!
+//! Each shade of the [`tailwind`] palettes is replaced by the nearest [`Color::Indexed`] color, so
+//! that a theme built from these palettes looks the same on terminals that don't support RGB
+//! colors, such as the macOS Terminal app. The colors are computed at compile time with
+//! [`Palette::to_indexed`].
+//!
+//! A theme can pick the palette that matches the color support of the terminal, see
+//! [`ColorSupport::detect`](crate::style::ColorSupport::detect):
+//!
+//! ```rust
+//! use ratatui_core::style::ColorSupport;
+//! use ratatui_core::style::palette::{ansi256, tailwind};
+//!
+//! let support = ColorSupport::from_env_vars(None, Some("xterm-256color"));
+//! let blue = match support {
+//!     ColorSupport::TrueColor => tailwind::BLUE,
+//!     _ => ansi256::BLUE,
+//! };
+//! assert_eq!(blue, tailwind::BLUE.to_indexed());
+//! ```
+//!
+//! [`tailwind`]: super::tailwind
+
+use crate::style::Color;
+use crate::style::palette::tailwind::{self, Palette};
+
+/// [`tailwind::BLACK`] mapped to the xterm 256 color palette
+pub const BLACK: Color = tailwind::BLACK.to_indexed();
+/// [`tailwind::WHITE`] mapped to the xterm 256 color palette
+pub const WHITE: Color = tailwind::WHITE.to_indexed();
+/// [`tailwind::SLATE`] mapped to the xterm 256 color palette
+pub const SLATE: Palette = tailwind::SLATE.to_indexed();
+/// [`tailwind::GRAY`] mapped to the xterm 256 color palette
+pub const GRAY: Palette = tailwind::GRAY.to_indexed();
+/// [`tailwind::ZINC`] mapped to the xterm 256 color palette
+pub const ZINC: Palette = tailwind::ZINC.to_indexed();
+/// [`tailwind::NEUTRAL`] mapped to the xterm 256 color palette
+pub const NEUTRAL: Palette = tailwind::NEUTRAL.to_indexed();
+/// [`tailwind::STONE`] mapped to the xterm 256 color palette
+pub const STONE: Palette = tailwind::STONE.to_indexed();
+/// [`tailwind::RED`] mapped to the xterm 256 color palette
+pub const RED: Palette = tailwind::RED.to_indexed();
+/// [`tailwind::ORANGE`] mapped to the xterm 256 color palette
+pub const ORANGE: Palette = tailwind::ORANGE.to_indexed();
+/// [`tailwind::AMBER`] mapped to the xterm 256 color palette
+pub const AMBER: Palette = tailwind::AMBER.to_indexed();
+/// [`tailwind::YELLOW`] mapped to the xterm 256 color palette
+pub const YELLOW: Palette = tailwind::YELLOW.to_indexed();
+/// [`tailwind::LIME`] mapped to the xterm 256 color palette
+pub const LIME: Palette = tailwind::LIME.to_indexed();
+/// [`tailwind::GREEN`] mapped to the xterm 256 color palette
+pub const GREEN: Palette = tailwind::GREEN.to_indexed();
+/// [`tailwind::EMERALD`] mapped to the xterm 256 color palette
+pub const EMERALD: Palette = tailwind::EMERALD.to_indexed();
+/// [`tailwind::TEAL`] mapped to the xterm 256 color palette
+pub const TEAL: Palette = tailwind::TEAL.to_indexed();
+/// [`tailwind::CYAN`] mapped to the xterm 256 color palette
+pub const CYAN: Palette = tailwind::CYAN.to_indexed();
+/// [`tailwind::SKY`] mapped to the xterm 256 color palette
+pub const SKY: Palette = tailwind::SKY.to_indexed();
+/// [`tailwind::BLUE`] mapped to the xterm 256 color palette
+pub const BLUE: Palette = tailwind::BLUE.to_indexed();
+/// [`tailwind::INDIGO`] mapped to the xterm 256 color palette
+pub const INDIGO: Palette = tailwind::INDIGO.to_indexed();
+/// [`tailwind::VIOLET`] mapped to the xterm 256 color palette
+pub const VIOLET: Palette = tailwind::VIOLET.to_indexed();
+/// [`tailwind::PURPLE`] mapped to the xterm 256 color palette
+pub const PURPLE: Palette = tailwind::PURPLE.to_indexed();
+/// [`tailwind::FUCHSIA`] mapped to the xterm 256 color palette
+pub const FUCHSIA: Palette = tailwind::FUCHSIA.to_indexed();
+/// [`tailwind::PINK`] mapped to the xterm 256 color palette
+pub const PINK: Palette = tailwind::PINK.to_indexed();
+/// [`tailwind::ROSE`] mapped to the xterm 256 color palette
+pub const ROSE: Palette = tailwind::ROSE.to_indexed();
@@ -412,7 +412,7 @@
 //!
 //! [`matdesign-color` crate]: https://crates.io/crates/matdesign-color
 
-use crate::style::Color;
+use crate::style::{Color, ColorSupport};
 
 /// A palette of colors for use in Material design with accent colors
 ///
@@ -478,6 +478,45 @@ impl AccentedPalette {
             a700: Color::from_u32(variants[13]),
         }
     }
+
+    /// Converts each color of the palette to the nearest color that is supported.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::style::palette::material::{self, AccentedPalette};
+    /// use ratatui_core::style::{Color, ColorSupport};
+    ///
+    /// const RED: AccentedPalette = material::RED.downsample(ColorSupport::Ansi16);
+    /// assert_eq!(RED.c500, Color::LightRed);
+    /// ```
+    #[must_use]
+    pub const fn downsample(self, support: ColorSupport) -> Self {
+        Self {
+            c50: support.downsample(self.c50),
+            c100: support.downsample(self.c100),
+            c200: support.downsample(self.c200),
+            c300: support.downsample(self.c300),
+            c400: support.downsample(self.c400),
+            c500: support.downsample(self.c500),
+            c600: support.downsample(self.c600),
+            c700: support.downsample(self.c700),
+            c800: support.downsample(self.c800),
+            c900: support.downsample(self.c900),
+            a100: support.downsample(self.a100),
+            a200: support.downsample(self.a200),
+            a400: support.downsample(self.a400),
+            a700: support.downsample(self.a700),
+        }
+    }
+
+    /// Converts each color of the palette to the nearest color of the xterm 256 color palette.
+    ///
+    /// See [`Color::to_indexed`] and [`AccentedPalette::downsample`].
+    #[must_use]
+    pub const fn to_indexed(self) -> Self {
+        self.downsample(ColorSupport::Ansi256)
+    }
 }
 
 impl NonAccentedPalette {
@@ -498,6 +537,41 @@ impl NonAccentedPalette {
             c900: Color::from_u32(variants[9]),
         }
     }
+
+    /// Converts each color of the palette to the nearest color that is supported.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::style::palette::material::{self, NonAccentedPalette};
+    /// use ratatui_core::style::{Color, ColorSupport};
+    ///
+    /// const BLUE_GRAY: NonAccentedPalette = material::BLUE_GRAY.downsample(ColorSupport::Ansi16);
+    /// assert_eq!(BLUE_GRAY.c500, Color::DarkGray);
+    /// ```
+    #[must_use]
+    pub const fn downsample(self, support: ColorSupport) -> Self {
+        Self {
+            c50: support.downsample(self.c50),
+            c100: support.downsample(self.c100),
+            c200: support.downsample(self.c200),
+            c300: support.downsample(self.c300),
+            c400: support.downsample(self.c400),
+            c500: support.downsample(self.c500),
+            c600: support.downsample(self.c600),
+            c700: support.downsample(self.c700),
+            c800: support.downsample(self.c800),
+            c900: support.downsample(self.c900),
+        }
+    }
+
+    /// Converts each color of the palette to the nearest color of the xterm 256 color palette.
+    ///
+    /// See [`Color::to_indexed`] and [`NonAccentedPalette::downsample`].
+    #[must_use]
+    pub const fn to_indexed(self) -> Self {
+        self.downsample(ColorSupport::Ansi256)
+    }
 }
 
 // Accented palettes
@@ -606,3 +680,38 @@ mod variants {
         0x263238,
     ];
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::no_color(ColorSupport::NoColor, Color::Reset, Color::Reset)]
+    #[case::ansi16(ColorSupport::Ansi16, Color::LightRed, Color::DarkGray)]
+    #[case::true_color(
+        ColorSupport::TrueColor,
+        Color::from_u32(0xF44336),
+        Color::from_u32(0x607D8B)
+    )]
+    fn downsample(#[case] support: ColorSupport, #[case] red: Color, #[case] blue_gray: Color) {
+        let accented = RED.downsample(support);
+        assert_eq!(accented.c500, red);
+        assert_eq!(accented.a700, support.downsample(RED.a700));
+        let non_accented = BLUE_GRAY.downsample(support);
+        assert_eq!(non_accented.c500, blue_gray);
+        assert_eq!(non_accented.c900, support.downsample(BLUE_GRAY.c900));
+    }
+
+    #[test]
+    fn to_indexed() {
+        let accented = RED.to_indexed();
+        assert_eq!(accented, RED.downsample(ColorSupport::Ansi256));
+        assert!(matches!(accented.c500, Color::Indexed(_)));
+        assert!(matches!(accented.a100, Color::Indexed(_)));
+        let non_accented = BLUE_GRAY.to_indexed();
+        assert_eq!(non_accented, BLUE_GRAY.downsample(ColorSupport::Ansi256));
+        assert!(matches!(non_accented.c500, Color::Indexed(_)));
+    }
+}
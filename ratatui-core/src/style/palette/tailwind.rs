@@ -275,8 +275,9 @@
 //! assert_eq!(BLUE.c500, Color::Rgb(59, 130, 246));
 //! ```
 
-use crate::style::Color;
+use crate::style::{Color, ColorSupport};
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Palette {
     pub c50: Color,
@@ -292,6 +293,79 @@ pub struct Palette {
     pub c950: Color,
 }
 
+impl Palette {
+    /// Generates a palette from a base color, which is used as the 500 shade.
+    ///
+    /// The lighter shades are mixed with white and the darker shades with black, using
+    /// [`Color::lerp`]. This gives a palette with the same structure as the Tailwind palettes for
+    /// a brand or theme color that is not part of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Color;
+    /// use ratatui_core::style::palette::tailwind::Palette;
+    ///
+    /// let palette = Palette::from_color(Color::Rgb(0, 128, 255));
+    /// assert_eq!(palette.c500, Color::Rgb(0, 128, 255));
+    /// assert_eq!(palette.c100, Color::Rgb(204, 230, 255));
+    /// assert_eq!(palette.c900, Color::Rgb(0, 32, 64));
+    /// ```
+    pub fn from_color(base: Color) -> Self {
+        let tint = |t| base.lerp(WHITE, t);
+        let shade = |t| base.lerp(BLACK, t);
+        Self {
+            c50: tint(0.9),
+            c100: tint(0.8),
+            c200: tint(0.6),
+            c300: tint(0.4),
+            c400: tint(0.2),
+            c500: base,
+            c600: shade(0.2),
+            c700: shade(0.4),
+            c800: shade(0.6),
+            c900: shade(0.75),
+            c950: shade(0.85),
+        }
+    }
+
+    /// Converts each color of the palette to the nearest color that is supported.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::style::palette::tailwind::{self, Palette};
+    /// use ratatui_core::style::{Color, ColorSupport};
+    ///
+    /// const RED: Palette = tailwind::RED.downsample(ColorSupport::Ansi16);
+    /// assert_eq!(RED.c500, Color::LightRed);
+    /// ```
+    #[must_use]
+    pub const fn downsample(self, support: ColorSupport) -> Self {
+        Self {
+            c50: support.downsample(self.c50),
+            c100: support.downsample(self.c100),
+            c200: support.downsample(self.c200),
+            c300: support.downsample(self.c300),
+            c400: support.downsample(self.c400),
+            c500: support.downsample(self.c500),
+            c600: support.downsample(self.c600),
+            c700: support.downsample(self.c700),
+            c800: support.downsample(self.c800),
+            c900: support.downsample(self.c900),
+            c950: support.downsample(self.c950),
+        }
+    }
+
+    /// Converts each color of the palette to the nearest color of the xterm 256 color palette.
+    ///
+    /// See [`Color::to_indexed`] and [`Palette::downsample`].
+    #[must_use]
+    pub const fn to_indexed(self) -> Self {
+        self.downsample(ColorSupport::Ansi256)
+    }
+}
+
 #[rustfmt::skip]
 /// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #000000"></div></div>
 pub const BLACK: Color = Color::from_u32(0x000000);
@@ -651,3 +725,46 @@ pub const ROSE: Palette = Palette {
     c900: Color::from_u32(0x881337),
     c950: Color::from_u32(0x4c0519),
 };
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::no_color(ColorSupport::NoColor, Color::Reset)]
+    #[case::ansi16(ColorSupport::Ansi16, Color::LightRed)]
+    #[case::ansi256(ColorSupport::Ansi256, Color::Indexed(203))]
+    #[case::true_color(ColorSupport::TrueColor, Color::from_u32(0xef4444))]
+    fn downsample(#[case] support: ColorSupport, #[case] expected: Color) {
+        let palette = RED.downsample(support);
+        assert_eq!(palette.c500, expected);
+        assert_eq!(palette.c50, support.downsample(RED.c50));
+        assert_eq!(palette.c950, support.downsample(RED.c950));
+    }
+
+    #[test]
+    fn to_indexed() {
+        let palette = SLATE.to_indexed();
+        assert_eq!(palette, SLATE.downsample(ColorSupport::Ansi256));
+        let shades = [
+            palette.c50,
+            palette.c100,
+            palette.c200,
+            palette.c300,
+            palette.c400,
+            palette.c500,
+            palette.c600,
+            palette.c700,
+            palette.c800,
+            palette.c900,
+            palette.c950,
+        ];
+        assert!(
+            shades
+                .iter()
+                .all(|color| matches!(color, Color::Indexed(_)))
+        );
+    }
+}
@@ -268,6 +268,7 @@ pub trait Stylize<'a, T>: Sized {
     modifier!(Modifier::REVERSED, reversed(), not_reversed() -> T);
     modifier!(Modifier::HIDDEN, hidden(), not_hidden() -> T);
     modifier!(Modifier::CROSSED_OUT, crossed_out(), not_crossed_out() -> T);
+    modifier!(Modifier::OVERLINED, overlined(), not_overlined() -> T);
 }
 
 impl<T, U> Stylize<'_, T> for U
@@ -0,0 +1,190 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use crate::style::Style;
+
+/// A named set of styles that can be switched as a whole.
+///
+/// A `Theme` maps semantic keys such as [`Theme::BORDER`] or `"error"` to a [`Style`]. Instead of
+/// passing individual styles to every widget, an application keeps one theme per color scheme
+/// (e.g. light and dark) and looks up styles by key when rendering. The theme of the current frame
+/// is set with [`Terminal::set_theme`] and available through [`Frame::theme`].
+///
+/// Widgets in `ratatui-widgets` accept a theme through their `theme` builder method, which applies
+/// the well-known keys defined as associated constants on this type ([`Theme::TEXT`],
+/// [`Theme::BORDER`], [`Theme::TITLE`] and [`Theme::HIGHLIGHT`]). Applications can add any other
+/// keys they need.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::style::{Color, Style, Stylize, Theme};
+///
+/// let dark = Theme::new()
+///     .with_style(Theme::TEXT, Style::new().white().on_black())
+///     .with_style(Theme::BORDER, Color::DarkGray)
+///     .with_style("error", Style::new().red().bold());
+///
+/// assert_eq!(dark.style("error"), Style::new().red().bold());
+/// assert_eq!(dark.style("missing"), Style::new());
+/// ```
+///
+/// [`Frame::theme`]: crate::terminal::Frame::theme
+/// [`Terminal::set_theme`]: crate::terminal::Terminal::set_theme
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Theme {
+    styles: BTreeMap<String, Style>,
+}
+
+impl Theme {
+    /// The key for the base style of a widget's content.
+    pub const TEXT: &'static str = "text";
+
+    /// The key for the style of borders.
+    pub const BORDER: &'static str = "border";
+
+    /// The key for the style of titles.
+    pub const TITLE: &'static str = "title";
+
+    /// The key for the style of selected items, such as the selected row of a list or table.
+    pub const HIGHLIGHT: &'static str = "highlight";
+
+    /// Creates a new empty `Theme`.
+    pub const fn new() -> Self {
+        Self {
+            styles: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the style for the given key.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: crate::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_style<K: Into<String>, S: Into<Style>>(mut self, key: K, style: S) -> Self {
+        self.set_style(key, style);
+        self
+    }
+
+    /// Sets the style for the given key.
+    pub fn set_style<K: Into<String>, S: Into<Style>>(&mut self, key: K, style: S) {
+        self.styles.insert(key.into(), style.into());
+    }
+
+    /// Removes the style for the given key, returning it if it was set.
+    pub fn remove_style(&mut self, key: &str) -> Option<Style> {
+        self.styles.remove(key)
+    }
+
+    /// Returns the style for the given key, or `None` if the theme doesn't define it.
+    pub fn get(&self, key: &str) -> Option<Style> {
+        self.styles.get(key).copied()
+    }
+
+    /// Returns the style for the given key, or the default style if the theme doesn't define it.
+    pub fn style(&self, key: &str) -> Style {
+        self.get(key).unwrap_or_default()
+    }
+
+    /// Returns true if the theme defines a style for the given key.
+    pub fn contains(&self, key: &str) -> bool {
+        self.styles.contains_key(key)
+    }
+
+    /// Adds the styles of `other` to this theme, replacing the styles of keys defined in both.
+    ///
+    /// This is useful to define a variant of a theme that only changes a few keys.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn merge(mut self, other: &Self) -> Self {
+        self.styles.extend(
+            other
+                .styles
+                .iter()
+                .map(|(key, style)| (key.clone(), *style)),
+        );
+        self
+    }
+
+    /// Returns an iterator over the keys and styles of the theme, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Style)> {
+        self.styles
+            .iter()
+            .map(|(key, style)| (key.as_str(), *style))
+    }
+}
+
+impl<K: Into<String>, S: Into<Style>> FromIterator<(K, S)> for Theme {
+    fn from_iter<T: IntoIterator<Item = (K, S)>>(iter: T) -> Self {
+        Self {
+            styles: iter
+                .into_iter()
+                .map(|(key, style)| (key.into(), style.into()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn get_and_style() {
+        let theme = Theme::new().with_style(Theme::BORDER, Color::Blue);
+        assert_eq!(theme.get(Theme::BORDER), Some(Style::new().blue()));
+        assert_eq!(theme.get(Theme::TITLE), None);
+        assert_eq!(theme.style(Theme::TITLE), Style::new());
+        assert!(theme.contains(Theme::BORDER));
+    }
+
+    #[test]
+    fn set_and_remove_style() {
+        let mut theme = Theme::new();
+        theme.set_style("error", Style::new().red());
+        assert_eq!(theme.remove_style("error"), Some(Style::new().red()));
+        assert!(!theme.contains("error"));
+    }
+
+    #[test]
+    fn merge() {
+        let base = Theme::new()
+            .with_style(Theme::TEXT, Color::White)
+            .with_style(Theme::BORDER, Color::Gray);
+        let variant = Theme::new().with_style(Theme::BORDER, Color::Red);
+        let merged = base.merge(&variant);
+        assert_eq!(merged.style(Theme::TEXT), Style::new().white());
+        assert_eq!(merged.style(Theme::BORDER), Style::new().red());
+    }
+
+    #[test]
+    fn from_iter_and_iter() {
+        let theme: Theme = [("b", Color::Blue), ("a", Color::Red)]
+            .into_iter()
+            .collect();
+        let entries: alloc::vec::Vec<_> = theme.iter().collect();
+        assert_eq!(
+            entries,
+            [("a", Style::new().red()), ("b", Style::new().blue())]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let theme = Theme::new().with_style(Theme::BORDER, Color::Blue);
+        let json = serde_json::to_string(&theme).unwrap();
+        assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+    }
+}
@@ -0,0 +1,63 @@
+use strum::{Display, EnumString};
+
+/// The shape of the line drawn under underlined text.
+///
+/// The underline style is set with [`Style::underline_style`] and only has an effect on text that
+/// is underlined with [`Modifier::UNDERLINED`]. It is enabled by the `underline-style` feature.
+///
+/// Styles other than [`UnderlineStyle::Single`] use the `4:x` SGR sub-parameters, which are an
+/// extension supported by many terminal emulators (e.g. kitty, `WezTerm`, `iTerm2`, VTE based
+/// terminals, and foot). Terminals that don't support them usually fall back to a single
+/// underline. They are implemented by the crossterm, termion, and termwiz backends.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "underline-style")]
+/// # {
+/// use ratatui_core::style::{Color, Style, Stylize, UnderlineStyle};
+///
+/// // a spell-check style red squiggly underline
+/// let misspelled = Style::new()
+///     .underlined()
+///     .underline_style(UnderlineStyle::Curly)
+///     .underline_color(Color::Red);
+/// # }
+/// ```
+///
+/// [`Modifier::UNDERLINED`]: crate::style::Modifier::UNDERLINED
+/// [`Style::underline_style`]: crate::style::Style::underline_style
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(ascii_case_insensitive)]
+pub enum UnderlineStyle {
+    /// A single straight line (SGR `4`).
+    #[default]
+    Single,
+    /// Two straight lines (SGR `4:2`).
+    Double,
+    /// A wavy line (SGR `4:3`), often used to highlight spelling mistakes.
+    Curly,
+    /// A dotted line (SGR `4:4`).
+    Dotted,
+    /// A dashed line (SGR `4:5`).
+    Dashed,
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn to_string_and_from_str() {
+        assert_eq!(UnderlineStyle::Curly.to_string(), "Curly");
+        assert_eq!(
+            UnderlineStyle::from_str("Dashed"),
+            Ok(UnderlineStyle::Dashed)
+        );
+        assert!(UnderlineStyle::from_str("Wavy").is_err());
+    }
+}
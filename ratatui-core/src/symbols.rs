@@ -8,6 +8,7 @@ pub mod border;
 pub mod braille;
 pub mod half_block;
 pub mod line;
+pub mod line_gauge;
 pub mod marker;
 pub mod merge;
 pub mod pixel;
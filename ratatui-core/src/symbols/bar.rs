@@ -6,6 +6,7 @@ pub const HALF: &str = "▄";
 pub const THREE_EIGHTHS: &str = "▃";
 pub const ONE_QUARTER: &str = "▂";
 pub const ONE_EIGHTH: &str = "▁";
+pub const UPPER_HALF: &str = "▀";
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Set<'a> {
@@ -49,3 +50,19 @@ pub const NINE_LEVELS: Set = Set {
     one_eighth: ONE_EIGHTH,
     empty: " ",
 };
+
+/// A set for bars that extend down from the top of the cells, e.g. for negative values.
+///
+/// Unicode has no symbols for the other levels of such bars, so the cells are either full, half
+/// filled from the top, or empty.
+pub const INVERTED_THREE_LEVELS: Set = Set {
+    full: FULL,
+    seven_eighths: FULL,
+    three_quarters: UPPER_HALF,
+    five_eighths: UPPER_HALF,
+    half: UPPER_HALF,
+    three_eighths: UPPER_HALF,
+    one_quarter: UPPER_HALF,
+    one_eighth: " ",
+    empty: " ",
+};
@@ -0,0 +1,43 @@
+use crate::symbols::{block, line, shade};
+
+/// Line gauge Set
+/// ```text
+/// ━━━━━━╸━━━━━
+/// ^     ^ ^
+/// │     │ └ unfilled
+/// │     └── half_filled
+/// └──────── filled
+/// ```
+///
+/// `half_filled` is drawn in the cell where the filled part ends when that cell is half filled.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Set<'a> {
+    pub filled: &'a str,
+    pub unfilled: &'a str,
+    pub half_filled: &'a str,
+}
+
+pub const NORMAL: Set = Set {
+    filled: line::HORIZONTAL,
+    unfilled: line::HORIZONTAL,
+    half_filled: "╴",
+};
+
+/// The bar drawn by tools like pip, best used with a dimmed unfilled style.
+pub const THICK: Set = Set {
+    filled: line::THICK_HORIZONTAL,
+    unfilled: line::THICK_HORIZONTAL,
+    half_filled: "╸",
+};
+
+pub const BLOCK: Set = Set {
+    filled: block::FULL,
+    unfilled: " ",
+    half_filled: block::HALF,
+};
+
+pub const SHADE: Set = Set {
+    filled: block::FULL,
+    unfilled: shade::LIGHT,
+    half_filled: block::HALF,
+};
@@ -8,8 +8,13 @@
 //! may need to be combined to create a single, coherent border representation.
 //!
 //! [collapsing borders]: https://ratatui.rs/recipes/layout/collapse-borders
+use alloc::vec::Vec;
 use core::str::FromStr;
 
+use crate::buffer::Buffer;
+use crate::layout::{Position, Rect};
+use crate::widgets::Widget;
+
 /// A strategy for merging two symbols into one.
 ///
 /// This enum defines how two symbols should be merged together, allowing for different behaviors
@@ -317,6 +322,138 @@ impl MergeStrategy {
     }
 }
 
+/// A post-processing pass that joins the borders of adjacent widgets in a [`Buffer`].
+///
+/// [`Block::merge_borders`] merges a block's border with whatever was rendered below it, which
+/// requires every block to opt in and be rendered in a suitable order. `BorderMerger` instead runs
+/// once after all widgets have been rendered: every box drawing symbol that has a line pointing
+/// at it from a neighboring cell is extended in that direction, so a `┐` with a `│` below it and a
+/// `─` to its right becomes a `┼`.
+///
+/// Combined with a layout whose areas overlap by one cell (e.g. `Layout::spacing(-1)`), this
+/// collapses the shared borders of a grid of plain blocks into a single set of lines.
+///
+/// Only lines that end right next to another border are joined, so the borders of blocks that are
+/// merely adjacent (e.g. `┐┌`) are left untouched. The [`MergeStrategy`] decides what happens when
+/// the joined symbol doesn't exist in unicode: [`MergeStrategy::Exact`] (the default) leaves the
+/// cell unchanged and [`MergeStrategy::Fuzzy`] uses the closest existing symbol.
+/// [`MergeStrategy::Replace`] disables merging.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::buffer::Buffer;
+/// use ratatui_core::layout::Rect;
+/// use ratatui_core::symbols::merge::BorderMerger;
+/// use ratatui_core::widgets::Widget;
+///
+/// let mut buffer = Buffer::with_lines([
+///     "┌───┌───┐",
+///     "│   │   │",
+///     "└───└───┘",
+/// ]);
+/// BorderMerger::new().render(buffer.area, &mut buffer);
+/// assert_eq!(
+///     buffer,
+///     Buffer::with_lines(["┌───┬───┐", "│   │   │", "└───┴───┘"])
+/// );
+/// ```
+///
+/// [`Block::merge_borders`]:
+///     https://docs.rs/ratatui/latest/ratatui/widgets/block/struct.Block.html#method.merge_borders
+/// [`Buffer`]: crate::buffer::Buffer
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct BorderMerger {
+    strategy: MergeStrategy,
+}
+
+impl Default for BorderMerger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BorderMerger {
+    /// Creates a new `BorderMerger` using [`MergeStrategy::Exact`].
+    pub const fn new() -> Self {
+        Self {
+            strategy: MergeStrategy::Exact,
+        }
+    }
+
+    /// Sets the strategy used when the joined symbol doesn't exist in unicode.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn strategy(mut self, strategy: MergeStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Returns the joined symbol for the cell at the given position, if it changes.
+    fn joined(self, buf: &Buffer, x: u16, y: u16) -> Option<&'static str> {
+        let neighbor = |x: Option<u16>, y: Option<u16>| {
+            let position = Position::new(x?, y?);
+            let cell = buf.cell(position)?;
+            BorderSymbol::from_str(cell.symbol()).ok()
+        };
+        let original = BorderSymbol::from_str(buf.cell(Position::new(x, y))?.symbol()).ok()?;
+        let mut symbol = original;
+        if symbol.up == LineStyle::Nothing
+            && let Some(above) = neighbor(Some(x), y.checked_sub(1))
+        {
+            symbol.up = above.down;
+        }
+        if symbol.down == LineStyle::Nothing
+            && let Some(below) = neighbor(Some(x), y.checked_add(1))
+        {
+            symbol.down = below.up;
+        }
+        if symbol.left == LineStyle::Nothing
+            && let Some(left) = neighbor(x.checked_sub(1), Some(y))
+        {
+            symbol.left = left.right;
+        }
+        if symbol.right == LineStyle::Nothing
+            && let Some(right) = neighbor(x.checked_add(1), Some(y))
+        {
+            symbol.right = right.left;
+        }
+        if symbol == original {
+            return None;
+        }
+        let symbol = match self.strategy {
+            MergeStrategy::Replace => return None,
+            MergeStrategy::Exact => symbol,
+            MergeStrategy::Fuzzy => symbol.fuzzy(original),
+        };
+        <&str>::try_from(symbol).ok()
+    }
+}
+
+impl Widget for BorderMerger {
+    /// Joins the borders in `area`. Cells outside of `area` are used to decide which lines to
+    /// join, but are not modified.
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        // collect the changes first so that each cell is joined based on the original symbols
+        let joined: Vec<(Position, &str)> = area
+            .positions()
+            .filter_map(|position| {
+                let symbol = self.joined(buf, position.x, position.y)?;
+                Some((position, symbol))
+            })
+            .collect();
+        for (position, symbol) in joined {
+            buf[position].set_symbol(symbol);
+        }
+    }
+}
+
+impl Widget for &BorderMerger {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(*self, area, buf);
+    }
+}
+
 /// Represents a composite border symbol using individual line components.
 ///
 /// This is an internal type for now specifically used to make the merge logic easier to implement.
@@ -745,4 +882,58 @@ mod tests {
         assert_eq!(strategy.merge("a", "╭"), "a");
         assert_eq!(strategy.merge("a", "b"), "b");
     }
+
+    #[test]
+    fn border_merger_joins_grid() {
+        let mut buffer = Buffer::with_lines([
+            "┌───┌───┐",
+            "│   │   │",
+            "┌───┌───┐",
+            "│   │   │",
+            "└───└───┘",
+        ]);
+        BorderMerger::new().render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "┌───┬───┐",
+            "│   │   │",
+            "├───┼───┤",
+            "│   │   │",
+            "└───┴───┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn border_merger_ignores_adjacent_borders() {
+        let lines = ["┌─┐┌─┐", "└─┘└─┘"];
+        let mut buffer = Buffer::with_lines(lines);
+        BorderMerger::new().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(lines));
+    }
+
+    #[test]
+    fn border_merger_strategy() {
+        // there is no symbol joining a plain corner with a double line
+        let mut buffer = Buffer::with_lines(["┐═"]);
+        BorderMerger::new().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┐═"]));
+
+        BorderMerger::new()
+            .strategy(MergeStrategy::Fuzzy)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┬═"]));
+
+        let mut buffer = Buffer::with_lines(["┐─"]);
+        BorderMerger::new()
+            .strategy(MergeStrategy::Replace)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┐─"]));
+    }
+
+    #[test]
+    fn border_merger_only_modifies_area() {
+        let mut buffer = Buffer::with_lines(["┌──┌──┐", "└──└──┘"]);
+        BorderMerger::new().render(Rect::new(0, 0, 7, 1), &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌──┬──┐", "└──└──┘"]));
+    }
 }
@@ -42,22 +42,47 @@
 //! [`Backend`]: crate::backend::Backend
 //! [`Buffer`]: crate::buffer::Buffer
 
+#[cfg(feature = "frame-arena")]
+mod arena;
 mod backend;
 mod buffers;
+mod color_support;
 mod cursor;
+mod diff_strategy;
 mod frame;
 mod init;
 mod inline;
 mod render;
+mod render_cache;
 mod resize;
+mod scratch;
+mod stats;
+mod synchronized_output;
+mod theme;
+#[cfg(feature = "std")]
+mod timing;
 mod viewport;
+mod viewports;
+mod width_policy;
 
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::time::Duration;
+
+#[cfg(feature = "frame-arena")]
+pub use arena::FrameArena;
+pub use diff_strategy::DiffStrategy;
 pub use frame::{CompletedFrame, Frame};
+pub use scratch::Scratch;
+pub use stats::FrameStats;
 pub use viewport::Viewport;
+pub use viewports::ViewportId;
 
+use crate::accessibility::Semantics;
 use crate::backend::Backend;
-use crate::buffer::Buffer;
+use crate::buffer::{Buffer, WidthPolicy};
 use crate::layout::{Position, Rect};
+use crate::style::{ColorSupport, Theme};
 
 /// An interface to interact and draw [`Frame`]s on the user's terminal.
 ///
@@ -190,6 +215,8 @@ use crate::layout::{Position, Rect};
 /// - [`Viewport::Fullscreen`]: the standard TUI case where Ratatui owns the whole terminal window.
 /// - [`Viewport::Inline`]: embed the UI into a larger CLI flow with normal terminal output above
 ///   it.
+/// - [`Viewport::InlineBottom`]: like [`Viewport::Inline`], but pinned to the bottom of the
+///   terminal while output scrolls above it.
 /// - [`Viewport::Fixed`]: render into one region of a larger terminal layout managed elsewhere.
 ///
 /// Choose a viewport at initialization time with [`Terminal::with_options`] and
@@ -215,7 +242,7 @@ use crate::layout::{Position, Rect};
 /// let viewport = Viewport::Fixed(Rect::new(0, 0, 30, 10));
 /// let fixed = Terminal::with_options(
 ///     CrosstermBackend::new(std::io::stdout()),
-///     TerminalOptions { viewport },
+///     TerminalOptions::new().viewport(viewport),
 /// )?;
 ///
 /// fixed.draw(|frame| {
@@ -253,7 +280,11 @@ use crate::layout::{Position, Rect};
 /// While running in inline mode, [`Terminal::insert_before`] can be used to print output above the
 /// viewport without disturbing the UI's logical position. When Ratatui is built with the
 /// `scrolling-regions` feature, `insert_before` can do this without clearing and redrawing the
-/// viewport.
+/// viewport. [`Terminal::insert_after`] prints output below the viewport instead.
+///
+/// [`Viewport::InlineBottom`] keeps the viewport on the bottom rows of the terminal instead of
+/// anchoring it to the cursor row, which suits progress UIs that show a stream of output above a
+/// status area.
 ///
 /// ```rust,no_run
 /// # #![allow(unexpected_cfgs)]
@@ -263,9 +294,7 @@ use crate::layout::{Position, Rect};
 ///
 /// println!("Some output above the UI");
 ///
-/// let options = TerminalOptions {
-///     viewport: Viewport::Inline(10),
-/// };
+/// let options = TerminalOptions::new().viewport(Viewport::Inline(10));
 /// let mut terminal = ratatui::try_init_with_options(options)?;
 ///
 /// terminal.insert_before(1, |buf| {
@@ -291,6 +320,7 @@ use crate::layout::{Position, Rect};
 /// - Manual rendering and testing: [`Terminal::get_frame`], [`Terminal::flush`], and
 ///   [`Terminal::swap_buffers`]
 /// - Printing above an inline UI: [`Terminal::insert_before`]
+/// - Drawing additional viewports, e.g. a status line: [`Terminal::add_viewport`]
 ///
 /// # Initialization
 ///
@@ -358,6 +388,7 @@ use crate::layout::{Position, Rect};
 /// - Fullscreen: `Frame::area` covers the full backend size.
 /// - Fixed: `Frame::area` is the exact rectangle you provided in terminal coordinates.
 /// - Inline: `Frame::area` is a rectangle anchored to the backend cursor row.
+/// - Inline bottom: `Frame::area` is a rectangle covering the bottom rows of the backend.
 ///
 /// For fullscreen and inline viewports, [`Terminal::autoresize`] checks the backend size during
 /// every render pass and calls [`Terminal::resize`] when it changes. Resizing updates the internal
@@ -455,19 +486,207 @@ where
     /// This increments after each successful [`Terminal::draw`] / [`Terminal::try_draw`] and wraps
     /// at `usize::MAX`.
     frame_count: usize,
+    /// The theme passed to each [`Frame`].
+    ///
+    /// This is set by [`Terminal::set_theme`].
+    theme: Theme,
+    /// The semantic description of the last frame, which is passed to
+    /// [`Backend::update_semantics`] with the description of the next frame.
+    semantics: Semantics,
+    /// The cells of the widgets rendered with [`Frame::render_cached`] in the main viewport.
+    ///
+    /// This is cleared by [`Terminal::set_theme`].
+    render_cache: render_cache::RenderCache,
+    /// The colors supported by the terminal, to which cells are downsampled when flushing.
+    ///
+    /// This is set by [`TerminalOptions::color_support`] or [`Terminal::set_color_support`].
+    color_support: ColorSupport,
+    /// How the width of characters is computed.
+    ///
+    /// This is set by [`TerminalOptions::width_policy`] or [`Terminal::set_width_policy`].
+    width_policy: WidthPolicy,
+    /// How the differences between frames are translated into the cells that are drawn.
+    ///
+    /// This is set by [`TerminalOptions::diff_strategy`] or [`Terminal::set_diff_strategy`].
+    diff_strategy: DiffStrategy,
+    /// Whether each frame is drawn in a synchronized update.
+    ///
+    /// This is set by [`TerminalOptions::synchronized_output`] or
+    /// [`Terminal::set_synchronized_output`].
+    synchronized_output: bool,
+    /// Called with the new viewport area after the terminal is resized.
+    ///
+    /// This is set by [`Terminal::on_resize`].
+    on_resize: resize::ResizeHook,
+    /// Called with the statistics of each frame after it is drawn.
+    ///
+    /// This is set by [`Terminal::on_frame`].
+    #[cfg(feature = "std")]
+    on_frame: stats::FrameHook,
+    /// How long [`Terminal::autoresize`] waits for a new size to settle.
+    ///
+    /// This is set by [`TerminalOptions::resize_debounce`] or [`Terminal::set_resize_debounce`].
+    #[cfg(feature = "std")]
+    resize_debounce: Duration,
+    /// A new size detected by [`Terminal::autoresize`] that waits for the resize debounce, and
+    /// when it was first detected.
+    #[cfg(feature = "std")]
+    pending_resize: Option<(Rect, std::time::Instant)>,
+    /// When the terminal was created, which [`Frame::timestamp`] is relative to.
+    #[cfg(feature = "std")]
+    epoch: timing::Clock,
+    /// When the current and last frames of the main viewport were drawn.
+    #[cfg(feature = "std")]
+    timing: timing::FrameTiming,
+    /// Storage for strings allocated with [`Frame::alloc_str`] during the current frame.
+    ///
+    /// This is reset by [`Terminal::swap_buffers`].
+    #[cfg(feature = "frame-arena")]
+    arena: FrameArena,
+    /// Vectors of lines and spans recycled with [`Frame::scratch`], which are kept between
+    /// frames.
+    scratch: Scratch,
+    /// Viewports added with [`Terminal::add_viewport`], which are drawn independently of the main
+    /// viewport.
+    secondary_viewports: Vec<viewports::SecondaryViewport>,
+    /// The id of the next viewport added with [`Terminal::add_viewport`].
+    next_viewport_id: usize,
 }
 
 /// Options to pass to [`Terminal::with_options`]
 ///
 /// Most applications can use [`Terminal::new`]. Use `TerminalOptions` when you need to configure a
 /// non-default [`Viewport`] at initialization time (see [`Terminal`] for an overview).
+///
+/// This struct is marked `#[non_exhaustive]` so that options can be added without breaking
+/// changes. Create it with [`TerminalOptions::new`] or [`TerminalOptions::default`] and configure
+/// it with the builder methods.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::terminal::{TerminalOptions, Viewport};
+///
+/// let options = TerminalOptions::new().viewport(Viewport::Inline(8));
+/// assert_eq!(options.viewport, Viewport::Inline(8));
+/// ```
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub struct TerminalOptions {
     /// Viewport used to draw to the terminal.
     ///
     /// See [`Terminal`] for a higher-level overview, and [`Viewport`] for the per-variant
     /// definition.
     pub viewport: Viewport,
+    /// Colors supported by the terminal.
+    ///
+    /// See [`Terminal::set_color_support`].
+    pub color_support: ColorSupport,
+    /// How the width of characters is computed.
+    ///
+    /// See [`Terminal::set_width_policy`].
+    pub width_policy: WidthPolicy,
+    /// How the differences between frames are translated into the cells that are drawn.
+    ///
+    /// See [`Terminal::set_diff_strategy`].
+    pub diff_strategy: DiffStrategy,
+    /// Whether each frame is drawn in a synchronized update.
+    ///
+    /// See [`Terminal::set_synchronized_output`].
+    pub synchronized_output: bool,
+    /// How long [`Terminal::autoresize`] waits for a new size to settle before resizing.
+    ///
+    /// See [`Terminal::set_resize_debounce`].
+    #[cfg(feature = "std")]
+    pub resize_debounce: Duration,
+}
+
+impl TerminalOptions {
+    /// Creates new `TerminalOptions` with a [`Viewport::Fullscreen`] viewport,
+    /// [`ColorSupport::TrueColor`], the default [`WidthPolicy`], [`DiffStrategy::Cell`], without
+    /// synchronized output, and without resize debounce.
+    pub const fn new() -> Self {
+        Self {
+            viewport: Viewport::Fullscreen,
+            color_support: ColorSupport::TrueColor,
+            width_policy: WidthPolicy::new(),
+            diff_strategy: DiffStrategy::Cell,
+            synchronized_output: false,
+            #[cfg(feature = "std")]
+            resize_debounce: Duration::ZERO,
+        }
+    }
+
+    /// Sets the colors supported by the terminal.
+    ///
+    /// See [`Terminal::set_color_support`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = color_support;
+        self
+    }
+
+    /// Sets how the width of characters is computed.
+    ///
+    /// See [`Terminal::set_width_policy`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width_policy(mut self, width_policy: WidthPolicy) -> Self {
+        self.width_policy = width_policy;
+        self
+    }
+
+    /// Sets how the differences between frames are translated into the cells that are drawn.
+    ///
+    /// See [`Terminal::set_diff_strategy`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn diff_strategy(mut self, diff_strategy: DiffStrategy) -> Self {
+        self.diff_strategy = diff_strategy;
+        self
+    }
+
+    /// Sets whether each frame is drawn in a synchronized update.
+    ///
+    /// See [`Terminal::set_synchronized_output`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn synchronized_output(mut self, synchronized_output: bool) -> Self {
+        self.synchronized_output = synchronized_output;
+        self
+    }
+
+    /// Sets how long [`Terminal::autoresize`] waits for a new size to settle before resizing.
+    ///
+    /// See [`Terminal::set_resize_debounce`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[cfg(feature = "std")]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn resize_debounce(mut self, resize_debounce: Duration) -> Self {
+        self.resize_debounce = resize_debounce;
+        self
+    }
+
+    /// Sets the viewport used to draw to the terminal.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = viewport;
+        self
+    }
+}
+
+impl From<Viewport> for TerminalOptions {
+    fn from(viewport: Viewport) -> Self {
+        Self::new().viewport(viewport)
+    }
 }
 
 impl<B> Drop for Terminal<B>
@@ -0,0 +1,215 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::{Cell, OnceCell, RefCell};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::text::Span;
+
+/// The number of strings stored in each chunk of the arena.
+const CHUNK_LEN: usize = 64;
+
+/// Storage for strings that only live for a single frame.
+///
+/// UIs that format a lot of text during rendering (e.g. `format!("{value:.2}")` for every cell of
+/// a table) allocate and free many small strings on every frame. A `FrameArena` is owned by the
+/// [`Terminal`] and reused between frames: strings allocated with [`FrameArena::alloc_str`] are
+/// kept until the buffers are swapped at the end of the frame, after which their allocations are
+/// recycled for the next frame instead of being returned to the allocator. At a steady state, a
+/// frame doesn't allocate at all.
+///
+/// Use [`Frame::alloc_str`] and [`Frame::alloc_span`] to allocate into the arena of the current
+/// frame. The returned references live as long as the [`Frame`], so they can be used in widgets
+/// that are rendered later in the same frame.
+///
+/// The contents of the arena are transient, so they are ignored when comparing or hashing a
+/// [`Terminal`], and a cloned arena starts empty.
+///
+/// [`Frame`]: crate::terminal::Frame
+/// [`Frame::alloc_str`]: crate::terminal::Frame::alloc_str
+/// [`Frame::alloc_span`]: crate::terminal::Frame::alloc_span
+/// [`Terminal`]: crate::terminal::Terminal
+#[derive(Default)]
+pub struct FrameArena {
+    /// The first chunk of string slots. Further chunks are linked from it and kept between frames.
+    head: Chunk,
+    /// The number of strings allocated in the current frame.
+    len: Cell<usize>,
+    /// Cleared strings from previous frames whose allocations are reused.
+    spare: RefCell<Vec<String>>,
+}
+
+/// A fixed number of string slots, which are only ever written through a shared reference once
+/// per frame so that references to them stay valid.
+struct Chunk {
+    slots: [OnceCell<String>; CHUNK_LEN],
+    next: OnceCell<Box<Self>>,
+}
+
+impl Default for Chunk {
+    fn default() -> Self {
+        Self {
+            slots: [const { OnceCell::new() }; CHUNK_LEN],
+            next: OnceCell::new(),
+        }
+    }
+}
+
+impl FrameArena {
+    /// Creates a new empty `FrameArena`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Formats the arguments into a string stored in the arena.
+    ///
+    /// Use [`format_args!`] to create the arguments. Arguments without any placeholders are
+    /// returned as is, without being copied into the arena.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::terminal::FrameArena;
+    ///
+    /// let arena = FrameArena::new();
+    /// let value = 42;
+    /// assert_eq!(arena.alloc_str(format_args!("value: {value}")), "value: 42");
+    /// ```
+    pub fn alloc_str(&self, args: fmt::Arguments<'_>) -> &str {
+        if let Some(s) = args.as_str() {
+            return s;
+        }
+        let mut string = self.spare.borrow_mut().pop().unwrap_or_default();
+        // writing to a String never fails
+        let _ = fmt::Write::write_fmt(&mut string, args);
+        let index = self.len.get();
+        self.len.set(index + 1);
+        let slot = self.slot(index);
+        slot.get_or_init(|| string)
+    }
+
+    /// Formats the arguments into an unstyled [`Span`] whose content is stored in the arena.
+    pub fn alloc_span(&self, args: fmt::Arguments<'_>) -> Span<'_> {
+        Span::raw(self.alloc_str(args))
+    }
+
+    /// Returns the number of strings allocated since the last reset.
+    pub const fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    /// Returns true if no strings were allocated since the last reset.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Frees all strings, keeping their allocations for reuse.
+    ///
+    /// The [`Terminal`] calls this when swapping buffers at the end of each frame.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    pub fn reset(&mut self) {
+        let spare = self.spare.get_mut();
+        let mut next = Some(&mut self.head);
+        while let Some(chunk) = next {
+            for slot in &mut chunk.slots {
+                if let Some(mut string) = slot.take() {
+                    string.clear();
+                    spare.push(string);
+                }
+            }
+            next = chunk.next.get_mut().map(|chunk| &mut **chunk);
+        }
+        self.len.set(0);
+    }
+
+    /// Returns the slot at the given index, adding chunks as needed.
+    fn slot(&self, mut index: usize) -> &OnceCell<String> {
+        let mut chunk = &self.head;
+        while index >= CHUNK_LEN {
+            chunk = chunk.next.get_or_init(Box::default);
+            index -= CHUNK_LEN;
+        }
+        &chunk.slots[index]
+    }
+}
+
+impl fmt::Debug for FrameArena {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameArena")
+            .field("len", &self.len.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Clone for FrameArena {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for FrameArena {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FrameArena {}
+
+impl Hash for FrameArena {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alloc_str() {
+        let arena = FrameArena::new();
+        let (one, two, three) = (1, 2, 3);
+        let a = arena.alloc_str(format_args!("{one}"));
+        let b = arena.alloc_str(format_args!("{two}-{three}"));
+        assert_eq!((a, b), ("1", "2-3"));
+        assert_eq!(arena.len(), 2);
+    }
+
+    #[test]
+    fn static_str_is_not_copied() {
+        let arena = FrameArena::new();
+        assert_eq!(arena.alloc_str(format_args!("static")), "static");
+        assert!(arena.is_empty());
+    }
+
+    #[test]
+    fn grows_past_a_chunk() {
+        let arena = FrameArena::new();
+        let strings: Vec<&str> = (0..=CHUNK_LEN * 2)
+            .map(|i| arena.alloc_str(format_args!("{i}")))
+            .collect();
+        assert_eq!(strings[CHUNK_LEN * 2], "128");
+        assert_eq!(strings[0], "0");
+    }
+
+    #[test]
+    fn reset_reuses_allocations() {
+        let mut arena = FrameArena::new();
+        let capacity = arena.alloc_str(format_args!("{}", "x".repeat(100))).len();
+        arena.reset();
+        assert!(arena.is_empty());
+        assert_eq!(arena.spare.borrow().len(), 1);
+        assert!(arena.spare.borrow()[0].capacity() >= capacity);
+
+        let one = 1;
+        assert_eq!(arena.alloc_str(format_args!("{one}")), "1");
+        assert!(arena.spare.borrow().is_empty());
+    }
+
+    #[test]
+    fn alloc_span() {
+        let arena = FrameArena::new();
+        let seven = 7;
+        assert_eq!(arena.alloc_span(format_args!("{seven}")), Span::raw("7"));
+    }
+}
@@ -1,3 +1,8 @@
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::accessibility::Semantics;
 use crate::backend::{Backend, ClearType};
 use crate::buffer::{Buffer, Cell};
 use crate::layout::{Position, Rect};
@@ -52,9 +57,21 @@ impl<B: Backend> Terminal<B> {
         let count = self.frame_count;
         Frame {
             cursor_position: None,
+            virtual_cursors: Vec::new(),
+            virtual_cursor_style: Frame::DEFAULT_VIRTUAL_CURSOR_STYLE,
             viewport_area: self.viewport_area,
-            buffer: self.current_buffer_mut(),
+            buffer: &mut self.buffers[self.current],
             count,
+            #[cfg(feature = "std")]
+            timestamp: self.timing.timestamp(),
+            #[cfg(feature = "std")]
+            elapsed_since_last_draw: self.timing.elapsed_since_last_draw(),
+            theme: &self.theme,
+            semantics: Semantics::new(),
+            render_cache: &mut self.render_cache,
+            #[cfg(feature = "frame-arena")]
+            arena: &self.arena,
+            scratch: &self.scratch,
         }
     }
 
@@ -73,12 +90,81 @@ impl<B: Backend> Terminal<B> {
         &mut self.buffers[self.current]
     }
 
+    /// Returns a copy of the buffer that is displayed on the screen.
+    ///
+    /// This is the buffer of the last frame drawn with [`Terminal::draw`] or
+    /// [`Terminal::try_draw`], covering the viewport area. Unlike [`Terminal::current_buffer_mut`],
+    /// which is the buffer that the next frame renders into, it is not affected by a frame being
+    /// rendered. This is useful to take a screenshot of a live session, e.g. to implement a "copy
+    /// screen" command, dump the screen for debugging, or save a golden file, without replacing the
+    /// backend with a `TestBackend`. Before the first draw, the buffer is empty.
+    ///
+    /// See [`Terminal::as_text`] and [`Terminal::as_ansi_text`] to get the screen as text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # mod ratatui {
+    /// #     pub use ratatui_core::backend;
+    /// #     pub use ratatui_core::terminal::Terminal;
+    /// # }
+    /// use ratatui::Terminal;
+    /// use ratatui::backend::TestBackend;
+    ///
+    /// let mut terminal = Terminal::new(TestBackend::new(5, 1))?;
+    /// terminal.draw(|frame| frame.render_widget("Hello", frame.area()))?;
+    /// let screenshot = terminal.current_buffer_cloned();
+    /// assert_eq!(screenshot[(0, 0)].symbol(), "H");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn current_buffer_cloned(&self) -> Buffer {
+        self.buffers[1 - self.current].clone()
+    }
+
+    /// Returns the text displayed on the screen, one line per row, without styles.
+    ///
+    /// This is the text of the buffer returned by [`Terminal::current_buffer_cloned`], see
+    /// [`Buffer::to_plain_text`] for the format.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # mod ratatui {
+    /// #     pub use ratatui_core::backend;
+    /// #     pub use ratatui_core::terminal::Terminal;
+    /// # }
+    /// use ratatui::Terminal;
+    /// use ratatui::backend::TestBackend;
+    ///
+    /// let mut terminal = Terminal::new(TestBackend::new(5, 2))?;
+    /// terminal.draw(|frame| frame.render_widget("Hello", frame.area()))?;
+    /// assert_eq!(terminal.as_text(), "Hello\n     \n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_text(&self) -> String {
+        self.buffers[1 - self.current].to_plain_text()
+    }
+
+    /// Returns the text displayed on the screen, one line per row, styled with ANSI escape
+    /// sequences.
+    ///
+    /// This is the text of the buffer returned by [`Terminal::current_buffer_cloned`], see
+    /// [`Buffer::to_ansi_text`] for the format. The text can be printed to a terminal, or saved to
+    /// a file and viewed with `cat`.
+    pub fn as_ansi_text(&self) -> String {
+        self.buffers[1 - self.current].to_ansi_text()
+    }
+
     /// Applies the current buffer diff to the backend's active display surface.
     ///
     /// This compares the current buffer with the previous buffer and passes only the changed cells
     /// to [`Backend::draw`]. It is one of the building blocks used by [`Terminal::draw`] /
     /// [`Terminal::try_draw`].
     ///
+    /// Rows whose [`LineAttribute`] changed are first updated with
+    /// [`Backend::set_line_attribute`], and all of their cells are drawn again, since terminals
+    /// don't keep the content of a row when its width changes.
+    ///
     /// This method does not swap buffers, does not update cursor visibility or position, and does
     /// not call [`Backend::flush`]. See [`Terminal::swap_buffers`] and [`Backend::flush`].
     ///
@@ -89,22 +175,55 @@ impl<B: Backend> Terminal<B> {
     /// [`Terminal::draw`] / [`Terminal::try_draw`] unless you are intentionally managing the whole
     /// render pipeline yourself.
     ///
+    /// If the terminal doesn't support all colors (see [`Terminal::set_color_support`]), the
+    /// colors of the current buffer are converted to the nearest supported colors first.
+    ///
     /// Implementation note: when there are updates, Ratatui records the position of the last
     /// updated cell as the "last known cursor position". Inline viewports use this to preserve the
     /// cursor's relative position within the viewport across resizes.
     ///
     /// [`Backend::flush`]: crate::backend::Backend::flush
+    /// [`Backend::set_line_attribute`]: crate::backend::Backend::set_line_attribute
+    /// [`LineAttribute`]: crate::buffer::LineAttribute
     pub fn flush(&mut self) -> Result<(), B::Error> {
+        self.apply_width_policy();
+        self.downsample_current_buffer();
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
         let mut last_pos = None;
+        let mut cells_changed = 0;
+
+        // Terminals discard the right half of a row that becomes double width, and show the left
+        // half at single width when it goes back, so rows whose attribute changed are redrawn.
+        let attribute_rows = previous_buffer
+            .line_attributes
+            .keys()
+            .chain(current_buffer.line_attributes.keys())
+            .collect::<BTreeSet<_>>();
+        let area = current_buffer.area;
+        let mut redraw_rows = Vec::new();
+        for &row in attribute_rows {
+            let y = area.y + row;
+            let attribute = current_buffer.line_attribute(y);
+            if previous_buffer.line_attribute(y) != attribute {
+                self.backend.set_line_attribute(y, attribute)?;
+                last_pos = Some(Position { x: 0, y });
+                redraw_rows.push(y);
+            }
+        }
 
-        let updates = previous_buffer
-            .diff_iter(current_buffer)
+        let updates = self
+            .diff_strategy
+            .updates(previous_buffer, current_buffer, redraw_rows)
             .inspect(|(col, row, _)| {
                 last_pos = Some(Position { x: *col, y: *row });
+                cells_changed += 1;
             });
         self.backend.draw(updates)?;
+        #[cfg(feature = "std")]
+        if self.on_frame.is_set() {
+            self.on_frame.stats.cells_changed += cells_changed;
+        }
 
         if let Some(pos) = last_pos {
             self.last_known_cursor_pos = pos;
@@ -121,6 +240,9 @@ impl<B: Backend> Terminal<B> {
     pub fn swap_buffers(&mut self) {
         self.buffers[1 - self.current].reset();
         self.current = 1 - self.current;
+        self.render_cache.end_frame();
+        #[cfg(feature = "frame-arena")]
+        self.arena.reset();
     }
 
     /// Clear the terminal and force a full redraw on the next draw call.
@@ -158,7 +280,7 @@ impl<B: Backend> Terminal<B> {
     pub(super) fn clear_viewport(&mut self) -> Result<(), B::Error> {
         match self.viewport {
             Viewport::Fullscreen => self.backend.clear_region(ClearType::All)?,
-            Viewport::Inline(_) => {
+            Viewport::Inline(_) | Viewport::InlineBottom(_) => {
                 self.backend
                     .set_cursor_position(self.viewport_area.as_position())?;
                 self.backend.clear_region(ClearType::AfterCursor)?;
@@ -170,6 +292,9 @@ impl<B: Backend> Terminal<B> {
         }
         // Reset the back buffer to make sure the next update will redraw everything.
         self.buffers[1 - self.current].reset();
+        if !matches!(self.viewport, Viewport::Fixed(_)) {
+            self.reset_secondary_viewports();
+        }
         Ok(())
     }
 
@@ -219,8 +344,9 @@ impl<B: Backend> Terminal<B> {
 #[cfg(test)]
 mod tests {
     use crate::backend::{Backend, TestBackend};
-    use crate::buffer::{Buffer, Cell};
+    use crate::buffer::{Buffer, Cell, LineAttribute};
     use crate::layout::{Position, Rect};
+    use crate::style::Style;
     use crate::terminal::{Terminal, TerminalOptions, Viewport};
 
     #[test]
@@ -235,6 +361,25 @@ mod tests {
         assert_eq!(frame.buffer.area, frame.area());
     }
 
+    #[test]
+    fn current_buffer_cloned_is_the_last_drawn_frame() {
+        let backend = TestBackend::new(3, 1);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget("abc", frame.area()))
+            .unwrap();
+
+        // rendering the next frame doesn't change the screenshot
+        terminal
+            .get_frame()
+            .render_widget("xyz", Rect::new(0, 0, 3, 1));
+        assert_eq!(
+            terminal.current_buffer_cloned(),
+            Buffer::with_lines(["abc"])
+        );
+        assert_eq!(terminal.as_text(), "abc\n");
+    }
+
     #[test]
     fn flush_writes_updates_and_tracks_last_updated_cell() {
         let backend = TestBackend::new(3, 2);
@@ -261,6 +406,93 @@ mod tests {
         assert_eq!(terminal.last_known_cursor_pos, Position { x: 2, y: 1 });
     }
 
+    #[test]
+    fn flush_sends_changed_line_attributes() {
+        let backend = TestBackend::new(3, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|frame| {
+                frame
+                    .buffer_mut()
+                    .set_line_attribute(1, LineAttribute::DoubleWidth);
+            })
+            .unwrap();
+        assert_eq!(
+            terminal.backend().buffer().line_attribute(1),
+            LineAttribute::DoubleWidth
+        );
+
+        // rows without an attribute in the next frame are reset to single width
+        terminal
+            .draw(|frame| {
+                frame
+                    .buffer_mut()
+                    .set_line_attribute(2, LineAttribute::DoubleHeightTop);
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer.line_attribute(1), LineAttribute::Single);
+        assert_eq!(buffer.line_attribute(2), LineAttribute::DoubleHeightTop);
+    }
+
+    #[test]
+    fn flush_tracks_cursor_after_line_attributes() {
+        let backend = TestBackend::new(3, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        {
+            let frame = terminal.get_frame();
+            frame.buffer[(2, 0)].set_symbol("x");
+            frame
+                .buffer
+                .set_line_attribute(1, LineAttribute::DoubleWidth);
+        }
+
+        terminal.flush().unwrap();
+        // the row whose attribute changed is redrawn after the other changes
+        assert_eq!(terminal.last_known_cursor_pos, Position { x: 2, y: 1 });
+    }
+
+    #[test]
+    fn flush_redraws_rows_whose_line_attribute_changed() {
+        let backend = TestBackend::new(4, 2);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let draw = |terminal: &mut Terminal<TestBackend>, attribute| {
+            terminal
+                .draw(|frame| {
+                    let buffer = frame.buffer_mut();
+                    buffer.set_string(0, 0, "abcd", Style::new());
+                    buffer.set_string(0, 1, "efgh", Style::new());
+                    buffer.set_line_attribute(0, attribute);
+                })
+                .unwrap();
+        };
+        draw(&mut terminal, LineAttribute::Single);
+        draw(&mut terminal, LineAttribute::DoubleWidth);
+        let mut expected = Buffer::with_lines(["ab  ", "efgh"]);
+        expected.set_line_attribute(0, LineAttribute::DoubleWidth);
+        terminal.backend().assert_buffer(&expected);
+
+        draw(&mut terminal, LineAttribute::Single);
+        terminal.backend().assert_buffer_lines(["abcd", "efgh"]);
+    }
+
+    #[cfg(feature = "frame-arena")]
+    #[test]
+    fn swap_buffers_resets_frame_arena() {
+        let backend = TestBackend::new(3, 2);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let frame = terminal.get_frame();
+        let one = 1;
+        assert_eq!(frame.alloc_str(format_args!("{one}")), "1");
+        assert_eq!(frame.arena().len(), 1);
+        terminal.swap_buffers();
+
+        assert!(terminal.get_frame().arena().is_empty());
+    }
+
     #[test]
     fn swap_buffers_resets_new_current_buffer() {
         let backend = TestBackend::new(3, 2);
@@ -314,9 +546,7 @@ mod tests {
         backend
             .set_cursor_position(Position { x: 2, y: 2 })
             .unwrap();
-        let options = TerminalOptions {
-            viewport: Viewport::Inline(2),
-        };
+        let options = TerminalOptions::new().viewport(Viewport::Inline(2));
         let mut terminal = Terminal::with_options(backend, options).unwrap();
         terminal
             .backend_mut()
@@ -352,9 +582,7 @@ mod tests {
         // ClearType::AfterCursor starting at the viewport origin.
         let mut backend = TestBackend::with_lines(["before 1  ", "viewport 1", "viewport 2"]);
         backend.set_cursor_position((2, 0)).unwrap();
-        let options = TerminalOptions {
-            viewport: Viewport::Fixed(Rect::new(0, 1, 10, 2)),
-        };
+        let options = TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(0, 1, 10, 2)));
         let mut terminal = Terminal::with_options(backend, options).unwrap();
 
         terminal.clear().unwrap();
@@ -377,9 +605,7 @@ mod tests {
         let mut backend =
             TestBackend::with_lines(["before 1  ", "viewport 1", "viewport 2", "after 1   "]);
         backend.set_cursor_position((1, 0)).unwrap();
-        let options = TerminalOptions {
-            viewport: Viewport::Fixed(Rect::new(0, 1, 10, 2)),
-        };
+        let options = TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(0, 1, 10, 2)));
         let mut terminal = Terminal::with_options(backend, options).unwrap();
 
         terminal.clear().unwrap();
@@ -401,9 +627,7 @@ mod tests {
         let mut backend =
             TestBackend::with_lines(["before 1  ", "viewport 1", "viewport 2", "after 1   "]);
         backend.set_cursor_position((3, 0)).unwrap();
-        let options = TerminalOptions {
-            viewport: Viewport::Fixed(Rect::new(1, 1, 3, 2)),
-        };
+        let options = TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(1, 1, 3, 2)));
         let mut terminal = Terminal::with_options(backend, options).unwrap();
 
         terminal.clear().unwrap();
@@ -433,9 +657,7 @@ mod tests {
         backend
             .set_cursor_position(Position { x: 2, y: 2 })
             .unwrap();
-        let options = TerminalOptions {
-            viewport: Viewport::Inline(2),
-        };
+        let options = TerminalOptions::new().viewport(Viewport::Inline(2));
         let mut terminal = Terminal::with_options(backend, options).unwrap();
 
         terminal.clear_viewport().unwrap();
@@ -0,0 +1,112 @@
+use crate::backend::Backend;
+use crate::buffer::Buffer;
+use crate::style::ColorSupport;
+use crate::terminal::Terminal;
+
+impl<B: Backend> Terminal<B> {
+    /// Returns the colors supported by the terminal.
+    ///
+    /// This is [`ColorSupport::TrueColor`] unless set otherwise with
+    /// [`TerminalOptions::color_support`] or [`Terminal::set_color_support`].
+    ///
+    /// [`TerminalOptions::color_support`]: crate::terminal::TerminalOptions::color_support
+    pub const fn color_support(&self) -> ColorSupport {
+        self.color_support
+    }
+
+    /// Sets the colors supported by the terminal.
+    ///
+    /// When this is less than [`ColorSupport::TrueColor`], the colors of each frame are converted
+    /// to the nearest supported colors in [`Terminal::flush`], e.g. [`Color::Rgb`] colors are
+    /// converted to [`Color::Indexed`] colors for [`ColorSupport::Ansi256`]. Widgets can use RGB
+    /// colors regardless of the terminal, and [`CompletedFrame::buffer`] contains the colors that
+    /// were drawn.
+    ///
+    /// Use [`Backend::color_support`] to detect the color support of the terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::backend::TestBackend;
+    /// use ratatui_core::backend::Backend;
+    /// use ratatui_core::terminal::Terminal;
+    ///
+    /// # let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// let color_support = terminal.backend().color_support();
+    /// terminal.set_color_support(color_support);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`Color::Rgb`]: crate::style::Color::Rgb
+    /// [`Color::Indexed`]: crate::style::Color::Indexed
+    /// [`CompletedFrame::buffer`]: crate::terminal::CompletedFrame::buffer
+    pub const fn set_color_support(&mut self, color_support: ColorSupport) {
+        self.color_support = color_support;
+    }
+
+    /// Converts the colors of the current buffer to the colors supported by the terminal.
+    pub(crate) fn downsample_current_buffer(&mut self) {
+        downsample_buffer(self.color_support, &mut self.buffers[self.current]);
+    }
+}
+
+/// Converts the colors of the buffer to the colors in `color_support`.
+pub(crate) fn downsample_buffer(color_support: ColorSupport, buffer: &mut Buffer) {
+    if color_support == ColorSupport::TrueColor {
+        return;
+    }
+    for cell in &mut buffer.content {
+        cell.fg = color_support.downsample(cell.fg);
+        cell.bg = color_support.downsample(cell.bg);
+        #[cfg(feature = "underline-color")]
+        {
+            cell.underline_color = color_support.downsample(cell.underline_color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::TestBackend;
+    use crate::style::{Color, ColorSupport, Style};
+    use crate::terminal::{Terminal, TerminalOptions};
+
+    #[test]
+    fn defaults_to_truecolor() {
+        let mut terminal = Terminal::new(TestBackend::new(1, 1)).unwrap();
+        assert_eq!(terminal.color_support(), ColorSupport::TrueColor);
+        terminal
+            .draw(|frame| {
+                frame.buffer_mut()[(0, 0)].set_fg(Color::Rgb(1, 2, 3));
+            })
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(0, 0)].fg, Color::Rgb(1, 2, 3));
+    }
+
+    #[test]
+    fn downsamples_when_drawing() {
+        let options = TerminalOptions::new().color_support(ColorSupport::Ansi16);
+        let mut terminal = Terminal::with_options(TestBackend::new(2, 1), options).unwrap();
+        let style = Style::new()
+            .fg(Color::Rgb(0, 180, 0))
+            .bg(Color::Indexed(196));
+        let completed = terminal
+            .draw(|frame| {
+                frame.buffer_mut().set_string(0, 0, "ab", style);
+            })
+            .unwrap();
+        assert_eq!(completed.buffer[(0, 0)].fg, Color::Green);
+        assert_eq!(completed.buffer[(1, 0)].bg, Color::LightRed);
+        assert_eq!(terminal.backend().buffer()[(0, 0)].fg, Color::Green);
+        assert_eq!(terminal.backend().buffer()[(1, 0)].bg, Color::LightRed);
+
+        terminal.set_color_support(ColorSupport::Ansi256);
+        terminal
+            .draw(|frame| {
+                frame.buffer_mut().set_string(0, 0, "ab", style);
+            })
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(0, 0)].fg, Color::Indexed(34));
+    }
+}
@@ -0,0 +1,237 @@
+use alloc::vec::Vec;
+
+use strum::{Display, EnumString};
+
+use crate::backend::Backend;
+use crate::buffer::{Buffer, BufferDiff, Cell, RowUpdates};
+use crate::terminal::Terminal;
+
+/// How the differences between two frames are translated into the cells that are drawn.
+///
+/// Drawing only the cells that changed writes the fewest bytes, but each group of changed cells
+/// needs a cursor movement and often a change of colors. Over high-latency links (e.g. SSH to a
+/// distant host, or a slow serial line), or on terminals that render many small updates slowly,
+/// it can be faster to rewrite each changed line as a whole, or the whole screen.
+///
+/// Set the strategy with [`TerminalOptions::diff_strategy`] or [`Terminal::set_diff_strategy`].
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui_core::backend::TestBackend;
+/// use ratatui_core::terminal::{DiffStrategy, Terminal, TerminalOptions};
+///
+/// # let backend = TestBackend::new(10, 10);
+/// let options = TerminalOptions::new().diff_strategy(DiffStrategy::Line);
+/// let mut terminal = Terminal::with_options(backend, options)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`TerminalOptions::diff_strategy`]: crate::terminal::TerminalOptions::diff_strategy
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiffStrategy {
+    /// Draws only the cells that changed since the last frame.
+    #[default]
+    Cell,
+    /// Draws every cell of the rows that changed since the last frame.
+    Line,
+    /// Draws every cell of the viewport on every frame.
+    Full,
+}
+
+impl DiffStrategy {
+    /// Returns the cells of `next` that are drawn to update the terminal from `previous`.
+    ///
+    /// All the cells of the rows in `redraw_rows` are drawn, whether they changed or not. This is
+    /// used for rows whose content on the terminal is no longer the content of `previous`, such as
+    /// the rows whose [`LineAttribute`] changed. `redraw_rows` must be sorted and inside `next`.
+    ///
+    /// [`LineAttribute`]: crate::buffer::LineAttribute
+    pub(crate) fn updates<'prev, 'next>(
+        self,
+        previous: &'prev Buffer,
+        next: &'next Buffer,
+        redraw_rows: Vec<u16>,
+    ) -> Updates<'prev, 'next> {
+        match self {
+            Self::Cell => Updates::Cells {
+                cells: previous.diff_iter(next),
+                rows: RowUpdates::new(next, redraw_rows.clone()),
+                redraw_rows,
+            },
+            Self::Line => {
+                let mut rows = redraw_rows;
+                for (_, y, _) in previous.diff_iter(next) {
+                    if rows.last() != Some(&y) {
+                        rows.push(y);
+                    }
+                }
+                rows.sort_unstable();
+                rows.dedup();
+                Updates::Rows(RowUpdates::new(next, rows))
+            }
+            Self::Full => {
+                let rows = next.area.rows().map(|row| row.y).collect();
+                Updates::Rows(RowUpdates::new(next, rows))
+            }
+        }
+    }
+}
+
+/// The cells that are drawn for a frame, depending on the [`DiffStrategy`].
+#[derive(Debug)]
+pub(crate) enum Updates<'prev, 'next> {
+    /// The cells that changed outside of `redraw_rows`, followed by all the cells of
+    /// `redraw_rows`.
+    Cells {
+        cells: BufferDiff<'prev, 'next>,
+        rows: RowUpdates<'next>,
+        redraw_rows: Vec<u16>,
+    },
+    /// All the cells of some rows.
+    Rows(RowUpdates<'next>),
+}
+
+impl<'next> Iterator for Updates<'_, 'next> {
+    type Item = (u16, u16, &'next Cell);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Cells {
+                cells,
+                rows,
+                redraw_rows,
+            } => cells
+                .find(|(_, y, _)| redraw_rows.binary_search(y).is_err())
+                .or_else(|| rows.next()),
+            Self::Rows(rows) => rows.next(),
+        }
+    }
+}
+
+impl<B: Backend> Terminal<B> {
+    /// Returns how the differences between frames are translated into the cells that are drawn.
+    ///
+    /// This is [`DiffStrategy::Cell`] unless set otherwise with
+    /// [`TerminalOptions::diff_strategy`] or [`Terminal::set_diff_strategy`].
+    ///
+    /// [`TerminalOptions::diff_strategy`]: crate::terminal::TerminalOptions::diff_strategy
+    pub const fn diff_strategy(&self) -> DiffStrategy {
+        self.diff_strategy
+    }
+
+    /// Sets how the differences between frames are translated into the cells that are drawn.
+    ///
+    /// This can be changed at any time, e.g. when the app detects that the connection to the
+    /// terminal is slow. The new strategy is used from the next [`Terminal::flush`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::backend::TestBackend;
+    /// use ratatui_core::terminal::{DiffStrategy, Terminal};
+    ///
+    /// # let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// terminal.set_diff_strategy(DiffStrategy::Full);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub const fn set_diff_strategy(&mut self, diff_strategy: DiffStrategy) {
+        self.diff_strategy = diff_strategy;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::backend::TestBackend;
+    use crate::buffer::CellDiffOption;
+    use crate::layout::Rect;
+    use crate::style::Style;
+    use crate::terminal::TerminalOptions;
+
+    fn positions(strategy: DiffStrategy, previous: &Buffer, next: &Buffer) -> Vec<(u16, u16)> {
+        strategy
+            .updates(previous, next, Vec::new())
+            .map(|(x, y, _)| (x, y))
+            .collect()
+    }
+
+    #[test]
+    fn cell() {
+        let previous = Buffer::with_lines(["abc", "def", "ghi"]);
+        let next = Buffer::with_lines(["abc", "dXf", "ghY"]);
+        assert_eq!(
+            positions(DiffStrategy::Cell, &previous, &next),
+            [(1, 1), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn line() {
+        let previous = Buffer::with_lines(["abc", "def", "ghi"]);
+        let next = Buffer::with_lines(["abc", "dXY", "ghi"]);
+        assert_eq!(
+            positions(DiffStrategy::Line, &previous, &next),
+            [(0, 1), (1, 1), (2, 1)]
+        );
+        assert_eq!(positions(DiffStrategy::Line, &previous, &previous), []);
+    }
+
+    #[test]
+    fn full() {
+        let previous = Buffer::with_lines(["ab", "cd"]);
+        assert_eq!(
+            positions(DiffStrategy::Full, &previous, &previous),
+            [(0, 0), (1, 0), (0, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn rows_skip_trailing_and_skipped_cells() {
+        let previous = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let mut next = Buffer::with_lines(["あbc "]);
+        next[(3, 0)].set_diff_option(CellDiffOption::Skip);
+        assert_eq!(
+            positions(DiffStrategy::Full, &previous, &next),
+            [(0, 0), (2, 0), (4, 0)]
+        );
+    }
+
+    #[test]
+    fn terminal_uses_strategy() {
+        let options = TerminalOptions::new().diff_strategy(DiffStrategy::Line);
+        let mut terminal = Terminal::with_options(TestBackend::new(3, 2), options).unwrap();
+        assert_eq!(terminal.diff_strategy(), DiffStrategy::Line);
+        terminal
+            .draw(|frame| frame.buffer_mut().set_string(0, 0, "abc", Style::new()))
+            .unwrap();
+
+        // only the middle cell changed, but the whole line is redrawn
+        overwrite(&mut terminal, "xyz");
+        terminal
+            .draw(|frame| frame.buffer_mut().set_string(0, 0, "aBc", Style::new()))
+            .unwrap();
+        terminal.backend().assert_buffer_lines(["aBc", "   "]);
+
+        terminal.set_diff_strategy(DiffStrategy::Cell);
+        overwrite(&mut terminal, "xyz");
+        terminal
+            .draw(|frame| frame.buffer_mut().set_string(0, 0, "abc", Style::new()))
+            .unwrap();
+        terminal.backend().assert_buffer_lines(["xbz", "   "]);
+    }
+
+    /// Writes to the first line of the backend without going through the terminal's buffers.
+    fn overwrite(terminal: &mut Terminal<TestBackend>, line: &str) {
+        let cells: Vec<Cell> = line
+            .chars()
+            .map(|ch| Cell::default().set_char(ch).clone())
+            .collect();
+        let updates = cells.iter().zip(0..).map(|(cell, x)| (x, 0, cell));
+        terminal.backend_mut().draw(updates).unwrap();
+    }
+}
@@ -1,5 +1,12 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::time::Duration;
+
+use crate::accessibility::{Accessible, SemanticNode, Semantics, StatefulAccessible};
 use crate::buffer::Buffer;
 use crate::layout::{Position, Rect};
+use crate::style::{Modifier, Style, Theme};
+use crate::terminal::render_cache::{RenderCache, SideEffects};
 use crate::widgets::{StatefulWidget, Widget};
 
 /// A consistent view into the terminal state for rendering a single frame.
@@ -27,6 +34,12 @@ pub struct Frame<'a> {
     /// the backend.
     pub(crate) cursor_position: Option<Position>,
 
+    /// Secondary cursors that are drawn as styled cells at the end of the render pass.
+    pub(crate) virtual_cursors: Vec<Position>,
+
+    /// The style that is patched onto the cells of the virtual cursors.
+    pub(crate) virtual_cursor_style: Style,
+
     /// The area of the viewport
     pub(crate) viewport_area: Rect,
 
@@ -35,6 +48,30 @@ pub struct Frame<'a> {
 
     /// The frame count indicating the sequence number of this frame.
     pub(crate) count: usize,
+
+    /// When this frame started to be drawn, relative to the creation of the terminal.
+    #[cfg(feature = "std")]
+    pub(crate) timestamp: Duration,
+
+    /// The time since the previous frame started to be drawn.
+    #[cfg(feature = "std")]
+    pub(crate) elapsed_since_last_draw: Duration,
+
+    /// The theme of the terminal.
+    pub(crate) theme: &'a Theme,
+
+    /// The semantic description of this frame for assistive technologies.
+    pub(crate) semantics: Semantics,
+
+    /// The cells of the widgets rendered with [`Frame::render_cached`] in previous frames.
+    pub(crate) render_cache: &'a mut RenderCache,
+
+    /// Storage for strings that live until the end of this frame.
+    #[cfg(feature = "frame-arena")]
+    pub(crate) arena: &'a crate::terminal::FrameArena,
+
+    /// Vectors of lines and spans that are reused between frames.
+    pub(crate) scratch: &'a crate::terminal::Scratch,
 }
 
 /// `CompletedFrame` represents the state of the terminal after the last successful
@@ -58,6 +95,9 @@ pub struct CompletedFrame<'a> {
 }
 
 impl Frame<'_> {
+    /// The default style of virtual cursors, which reverses the colors of the cell.
+    pub const DEFAULT_VIRTUAL_CURSOR_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
     /// Returns the area of the current frame.
     ///
     /// This is guaranteed not to change during rendering, so may be called multiple times.
@@ -151,6 +191,173 @@ impl Frame<'_> {
         widget.render(area, self.buffer, state);
     }
 
+    /// Renders the widgets drawn by `render` only when their cells may have changed, and otherwise
+    /// reuses the cells drawn in a previous frame.
+    ///
+    /// The cells that `render` draws in `area` are cached with the given `id`. In later frames,
+    /// `render` is not called, and the cached cells are copied into the frame instead, as long as:
+    ///
+    /// - the widget was rendered with the same `id` in the previous frame,
+    /// - `area` is the same as in the previous frame, and
+    /// - `area` doesn't intersect a region passed to [`Frame::invalidate`] earlier in this frame.
+    ///
+    /// This cuts the CPU time spent on expensive widgets that rarely change, such as the static
+    /// panes of a dashboard with one live pane. The application calls [`Frame::invalidate`] with
+    /// the area of a pane when its data changes, before rendering it.
+    ///
+    /// `render` should only draw inside `area`, since cells outside of it are not cached. The other
+    /// changes that `render` makes to the frame are cached as well and made again when the cells
+    /// are reused: the nodes passed to [`Frame::describe`], the cursor position, the virtual
+    /// cursors and the line attributes of the rows of `area`. The cache is cleared when the theme
+    /// of the terminal changes, and widgets that are not rendered during a frame are dropped from
+    /// the cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    /// # let backend = TestBackend::new(20, 2);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let mut renders = 0;
+    /// for frame_number in 0..3 {
+    ///     let data_changed = frame_number == 2;
+    ///     terminal.draw(|frame| {
+    ///         let area = Rect::new(0, 0, 20, 1);
+    ///         if data_changed {
+    ///             frame.invalidate(area);
+    ///         }
+    ///         frame.render_cached("chart", area, |frame| {
+    ///             renders += 1;
+    ///             frame.render_widget("expensive chart", area);
+    ///         });
+    ///     })?;
+    /// }
+    /// assert_eq!(renders, 2);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn render_cached<F>(&mut self, id: &str, area: Rect, render: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let area = area.intersection(self.buffer.area);
+        if let Some(side_effects) = self.render_cache.restore(id, area, self.buffer) {
+            self.semantics
+                .extend(side_effects.semantic_nodes.iter().cloned());
+            if side_effects.changes_cursor_position {
+                self.cursor_position = side_effects.cursor_position;
+            }
+            if side_effects.replaces_virtual_cursors {
+                self.virtual_cursors.clear();
+            }
+            self.virtual_cursors
+                .extend_from_slice(&side_effects.virtual_cursors);
+            for &(y, attribute) in &side_effects.line_attributes {
+                self.buffer.set_line_attribute(y, attribute);
+            }
+            return;
+        }
+
+        let semantic_nodes = self.semantics.nodes().len();
+        let cursor_position = self.cursor_position;
+        let virtual_cursors = self.virtual_cursors.clone();
+        let line_attributes: Vec<_> = (area.top()..area.bottom())
+            .map(|y| self.buffer.line_attribute(y))
+            .collect();
+        render(self);
+
+        let replaces_virtual_cursors = !self.virtual_cursors.starts_with(&virtual_cursors);
+        let first_added_virtual_cursor = if replaces_virtual_cursors {
+            0
+        } else {
+            virtual_cursors.len()
+        };
+        let side_effects = SideEffects {
+            semantic_nodes: self.semantics.nodes()[semantic_nodes..].to_vec(),
+            changes_cursor_position: self.cursor_position != cursor_position,
+            cursor_position: self.cursor_position,
+            virtual_cursors: self.virtual_cursors[first_added_virtual_cursor..].to_vec(),
+            replaces_virtual_cursors,
+            line_attributes: (area.top()..area.bottom())
+                .zip(line_attributes)
+                .filter_map(|(y, before)| {
+                    let after = self.buffer.line_attribute(y);
+                    (after != before).then_some((y, after))
+                })
+                .collect(),
+        };
+        self.render_cache.store(id, area, self.buffer, side_effects);
+    }
+
+    /// Marks a region of the frame as changed, so that the widgets rendered with
+    /// [`Frame::render_cached`] in an area that intersects it are rendered again.
+    ///
+    /// This only applies to the widgets rendered after this call during the current frame. Pass
+    /// [`Frame::area`] to render all cached widgets again.
+    pub fn invalidate(&mut self, area: Rect) {
+        self.render_cache.invalidate(area);
+    }
+
+    /// Adds a node to the semantic description of this frame.
+    ///
+    /// The description is passed to [`Backend::update_semantics`] after the frame is rendered, so
+    /// that backends can export it to screen readers and braille displays. See the
+    /// [`accessibility`](crate::accessibility) module for more details.
+    ///
+    /// [`Backend::update_semantics`]: crate::backend::Backend::update_semantics
+    pub fn describe(&mut self, node: SemanticNode) {
+        self.semantics.push(node);
+    }
+
+    /// Adds the semantic description of an [`Accessible`] widget drawn in `area` to this frame.
+    ///
+    /// This is usually called next to [`Frame::render_widget`] with the same widget and area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    /// # let backend = TestBackend::new(20, 2);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// use ratatui_core::accessibility::{Accessible, Role, SemanticNode, Semantics};
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// struct Clock;
+    ///
+    /// impl Accessible for Clock {
+    ///     fn describe(&self, area: Rect, semantics: &mut Semantics) {
+    ///         semantics.push(SemanticNode::new(Role::Status, area).label("12:00"));
+    ///     }
+    /// }
+    ///
+    /// terminal.draw(|frame| {
+    ///     frame.render_widget("12:00", frame.area());
+    ///     frame.describe_widget(&Clock, frame.area());
+    /// })?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn describe_widget<W: Accessible + ?Sized>(&mut self, widget: &W, area: Rect) {
+        widget.describe(area, &mut self.semantics);
+    }
+
+    /// Adds the semantic description of a [`StatefulAccessible`] widget drawn in `area` with
+    /// `state` to this frame.
+    ///
+    /// This is usually called next to [`Frame::render_stateful_widget`] with the same widget, area
+    /// and state.
+    pub fn describe_stateful_widget<W>(&mut self, widget: &W, area: Rect, state: &W::State)
+    where
+        W: StatefulAccessible + ?Sized,
+    {
+        widget.describe(area, state, &mut self.semantics);
+    }
+
+    /// Returns the semantic description that was added to this frame so far.
+    pub const fn semantics(&self) -> &Semantics {
+        &self.semantics
+    }
+
     /// After this frame is rendered, make the cursor visible and put it at the specified `(x, y)`
     /// coordinates. If this method is not called, the cursor will be hidden.
     ///
@@ -182,6 +389,89 @@ impl Frame<'_> {
         self.set_cursor_position(Position { x, y });
     }
 
+    /// Adds a virtual cursor at the given position.
+    ///
+    /// Terminals only have a single hardware cursor, which is placed with
+    /// [`Frame::set_cursor_position`]. Virtual cursors are drawn instead by patching the
+    /// [virtual cursor style](Frame::set_virtual_cursor_style) onto the cell at their position.
+    /// This is useful for editors with multiple cursors or split panes, where the unfocused panes
+    /// still show where their cursor is.
+    ///
+    /// Virtual cursors are drawn after the render callback returns, so they are not overwritten by
+    /// widgets rendered later in the frame. Positions outside of the frame are ignored, as is a
+    /// virtual cursor at the position of the hardware cursor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    /// # let backend = TestBackend::new(20, 2);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// terminal.draw(|frame| {
+    ///     // the focused pane gets the hardware cursor
+    ///     frame.set_cursor_position((3, 0));
+    ///     // the other pane shows its cursor as a styled cell
+    ///     frame.add_virtual_cursor((5, 1));
+    /// })?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn add_virtual_cursor<P: Into<Position>>(&mut self, position: P) {
+        self.virtual_cursors.push(position.into());
+    }
+
+    /// Sets the positions of all cursors in the frame.
+    ///
+    /// The first position becomes the hardware cursor (see [`Frame::set_cursor_position`]) and the
+    /// remaining positions become virtual cursors (see [`Frame::add_virtual_cursor`]), replacing
+    /// any cursors that were set before. If `positions` is empty, the cursor is hidden.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    /// # let backend = TestBackend::new(20, 3);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// terminal.draw(|frame| {
+    ///     frame.set_cursor_positions([(1, 0), (1, 1), (1, 2)]);
+    /// })?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn set_cursor_positions<I, P>(&mut self, positions: I)
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Position>,
+    {
+        let mut positions = positions.into_iter().map(Into::into);
+        self.cursor_position = positions.next();
+        self.virtual_cursors.clear();
+        self.virtual_cursors.extend(positions);
+    }
+
+    /// Sets the style that is used to draw virtual cursors.
+    ///
+    /// The style is patched onto the existing style of the cell, so only the properties that are
+    /// set in `style` are changed. Defaults to [`Frame::DEFAULT_VIRTUAL_CURSOR_STYLE`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: crate::style::Color
+    pub fn set_virtual_cursor_style<S: Into<Style>>(&mut self, style: S) {
+        self.virtual_cursor_style = style.into();
+    }
+
+    /// Draws the virtual cursors into the buffer.
+    pub(crate) fn apply_virtual_cursors(&mut self) {
+        for &position in &self.virtual_cursors {
+            if Some(position) == self.cursor_position {
+                continue;
+            }
+            if let Some(cell) = self.buffer.cell_mut(position) {
+                cell.set_style(self.virtual_cursor_style);
+            }
+        }
+    }
+
     /// Gets the buffer that this `Frame` draws into as a mutable reference.
     ///
     /// This is an escape hatch for direct buffer manipulation. Normal applications should prefer
@@ -235,4 +525,160 @@ impl Frame<'_> {
     pub const fn count(&self) -> usize {
         self.count
     }
+
+    /// Returns when this frame started to be drawn, relative to the creation of the [`Terminal`].
+    ///
+    /// The timestamp is taken once at the start of [`Terminal::draw`] / [`Terminal::try_draw`],
+    /// so it is the same for all the widgets of a frame, and it never decreases from one frame to
+    /// the next. This makes it suitable to drive animations (e.g. spinners or gradients) from the
+    /// time rather than from the number of frames, so they run at the same speed regardless of how
+    /// often the terminal is drawn.
+    ///
+    /// Frames of viewports added with [`Terminal::add_viewport`] are timestamped when
+    /// [`Terminal::draw_viewport`] is called. A frame obtained with [`Terminal::get_frame`] has the
+    /// timestamp of the last draw.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    /// # let mut terminal = Terminal::new(TestBackend::new(5, 1)).unwrap();
+    /// const SPINNER: [&str; 4] = ["|", "/", "-", "\\"];
+    ///
+    /// terminal.draw(|frame| {
+    ///     let step = frame.timestamp().as_millis() / 100 % 4;
+    ///     frame.render_widget(SPINNER[step as usize], frame.area());
+    /// })?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    /// [`Terminal::draw`]: crate::terminal::Terminal::draw
+    /// [`Terminal::try_draw`]: crate::terminal::Terminal::try_draw
+    /// [`Terminal::add_viewport`]: crate::terminal::Terminal::add_viewport
+    /// [`Terminal::draw_viewport`]: crate::terminal::Terminal::draw_viewport
+    /// [`Terminal::get_frame`]: crate::terminal::Terminal::get_frame
+    #[cfg(feature = "std")]
+    pub const fn timestamp(&self) -> Duration {
+        self.timestamp
+    }
+
+    /// Returns the time between the start of the previous successful draw and the start of this
+    /// frame.
+    ///
+    /// This is zero for the first frame. It can be used to advance animations or simulations by
+    /// the time that has passed, or to measure the frame rate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    /// # let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+    /// terminal.draw(|frame| {
+    ///     let elapsed = frame.elapsed_since_last_draw().as_secs_f64();
+    ///     let fps = if elapsed > 0.0 { 1.0 / elapsed } else { 0.0 };
+    ///     frame.render_widget(format!("{fps:.0} fps"), frame.area());
+    /// })?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub const fn elapsed_since_last_draw(&self) -> Duration {
+        self.elapsed_since_last_draw
+    }
+}
+
+impl<'a> Frame<'a> {
+    /// Returns the theme set with [`Terminal::set_theme`].
+    ///
+    /// Use this to look up styles by key when building widgets, or pass it to the `theme` method
+    /// of widgets that support themes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::backend::TestBackend;
+    /// # use ratatui_core::terminal::Terminal;
+    /// use ratatui_core::style::Theme;
+    /// use ratatui_core::text::Span;
+    ///
+    /// # let mut terminal = Terminal::new(TestBackend::new(20, 1)).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// let error = Span::styled("failed", frame.theme().style("error"));
+    /// frame.render_widget(error, frame.area());
+    /// ```
+    ///
+    /// [`Terminal::set_theme`]: crate::terminal::Terminal::set_theme
+    pub const fn theme(&self) -> &'a Theme {
+        self.theme
+    }
+
+    /// Returns the terminal's [`Scratch`] space, which reuses vectors of lines and spans between
+    /// frames.
+    ///
+    /// Together with `Frame::alloc_str` (with the `frame-arena` feature), this lets a frame build
+    /// its text without allocating once the app has reached a steady state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::backend::TestBackend;
+    /// # use ratatui_core::terminal::Terminal;
+    /// # let mut terminal = Terminal::new(TestBackend::new(20, 3)).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let scratch = frame.scratch();
+    /// let mut lines = scratch.lines();
+    /// for row in ["first", "second", "third"] {
+    ///     lines.push(Line::from(row));
+    /// }
+    /// let text = Text::from(lines);
+    /// frame.render_widget(&text, frame.area());
+    /// scratch.recycle_text(text);
+    /// ```
+    ///
+    /// [`Scratch`]: crate::terminal::Scratch
+    pub const fn scratch(&self) -> &'a crate::terminal::Scratch {
+        self.scratch
+    }
+}
+
+#[cfg(feature = "frame-arena")]
+impl<'a> Frame<'a> {
+    /// Formats the arguments into a string that lives until the end of the frame.
+    ///
+    /// The string is stored in the terminal's [`FrameArena`], which reuses the allocations of
+    /// previous frames. This avoids allocating a new `String` with `format!` for every piece of
+    /// formatted text on every frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::backend::TestBackend;
+    /// # use ratatui_core::terminal::Terminal;
+    /// # let mut terminal = Terminal::new(TestBackend::new(20, 1)).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// let fps = 59.94;
+    /// let text = frame.alloc_str(format_args!("{fps:.1} fps"));
+    /// frame.render_widget(text, frame.area());
+    /// ```
+    ///
+    /// [`FrameArena`]: crate::terminal::FrameArena
+    pub fn alloc_str(&self, args: core::fmt::Arguments<'_>) -> &'a str {
+        self.arena.alloc_str(args)
+    }
+
+    /// Formats the arguments into an unstyled [`Span`] that lives until the end of the frame.
+    ///
+    /// See [`Frame::alloc_str`] for details.
+    ///
+    /// [`Span`]: crate::text::Span
+    pub fn alloc_span(&self, args: core::fmt::Arguments<'_>) -> crate::text::Span<'a> {
+        self.arena.alloc_span(args)
+    }
+
+    /// Returns the arena used by [`Frame::alloc_str`].
+    pub const fn arena(&self) -> &'a crate::terminal::FrameArena {
+        self.arena
+    }
 }
@@ -1,7 +1,13 @@
+use alloc::vec::Vec;
+
+use crate::accessibility::Semantics;
 use crate::backend::Backend;
 use crate::buffer::Buffer;
 use crate::layout::Position;
-use crate::terminal::inline::compute_inline_size;
+use crate::style::Theme;
+use crate::terminal::inline::{compute_inline_bottom_size, compute_inline_size};
+use crate::terminal::render_cache::RenderCache;
+use crate::terminal::resize::ResizeHook;
 use crate::terminal::{Terminal, TerminalOptions, Viewport};
 
 impl<B: Backend> Terminal<B> {
@@ -56,9 +62,7 @@ impl<B: Backend> Terminal<B> {
     pub fn new(backend: B) -> Result<Self, B::Error> {
         Self::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Fullscreen,
-            },
+            TerminalOptions::new().viewport(Viewport::Fullscreen),
         )
     }
 
@@ -99,7 +103,7 @@ impl<B: Backend> Terminal<B> {
     ///
     /// let backend = CrosstermBackend::new(stdout());
     /// let viewport = Viewport::Fixed(Rect::new(0, 0, 10, 10));
-    /// let _terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+    /// let _terminal = Terminal::with_options(backend, TerminalOptions::new().viewport(viewport))?;
     /// # }
     /// # #[cfg(not(feature = "crossterm"))]
     /// # {
@@ -110,7 +114,7 @@ impl<B: Backend> Terminal<B> {
     /// # };
     /// # let backend = TestBackend::new(10, 10);
     /// # let viewport = Viewport::Fixed(Rect::new(0, 0, 10, 10));
-    /// # let _terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+    /// # let _terminal = Terminal::with_options(backend, TerminalOptions::new().viewport(viewport))?;
     /// # }
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
@@ -119,9 +123,19 @@ impl<B: Backend> Terminal<B> {
     /// cursor row at initialization time (always starting at column 0). Ratatui may append lines
     /// and thereby scroll the terminal to make enough room for the requested height so the
     /// viewport stays fully visible.
+    ///
+    /// When the viewport is [`Viewport::InlineBottom`], Ratatui places the viewport on the bottom
+    /// rows of the terminal, appending lines to scroll existing output up if it would otherwise be
+    /// covered by the viewport.
+    ///
+    /// The [`WidthPolicy`](crate::buffer::WidthPolicy) of the options becomes the current width
+    /// policy.
     pub fn with_options(mut backend: B, options: TerminalOptions) -> Result<Self, B::Error> {
+        options.width_policy.set_current();
         let area = match options.viewport {
-            Viewport::Fullscreen | Viewport::Inline(_) => backend.size()?.into(),
+            Viewport::Fullscreen | Viewport::Inline(_) | Viewport::InlineBottom(_) => {
+                backend.size()?.into()
+            }
             Viewport::Fixed(area) => area,
         };
         let (viewport_area, cursor_pos) = match options.viewport {
@@ -129,6 +143,9 @@ impl<B: Backend> Terminal<B> {
             Viewport::Inline(height) => {
                 compute_inline_size(&mut backend, height, area.as_size(), 0)?
             }
+            Viewport::InlineBottom(height) => {
+                compute_inline_bottom_size(&mut backend, height, area.as_size())?
+            }
             Viewport::Fixed(area) => (area, area.as_position()),
         };
         Ok(Self {
@@ -141,6 +158,29 @@ impl<B: Backend> Terminal<B> {
             last_known_area: area,
             last_known_cursor_pos: cursor_pos,
             frame_count: 0,
+            theme: Theme::new(),
+            semantics: Semantics::new(),
+            render_cache: RenderCache::new(),
+            color_support: options.color_support,
+            width_policy: options.width_policy,
+            diff_strategy: options.diff_strategy,
+            synchronized_output: options.synchronized_output,
+            on_resize: ResizeHook::default(),
+            #[cfg(feature = "std")]
+            on_frame: crate::terminal::stats::FrameHook::default(),
+            #[cfg(feature = "std")]
+            resize_debounce: options.resize_debounce,
+            #[cfg(feature = "std")]
+            pending_resize: None,
+            #[cfg(feature = "std")]
+            epoch: crate::terminal::timing::Clock::default(),
+            #[cfg(feature = "std")]
+            timing: crate::terminal::timing::FrameTiming::default(),
+            #[cfg(feature = "frame-arena")]
+            arena: crate::terminal::FrameArena::new(),
+            scratch: crate::terminal::Scratch::new(),
+            secondary_viewports: Vec::new(),
+            next_viewport_id: 0,
         })
     }
 }
@@ -171,13 +211,9 @@ mod tests {
     fn with_options_fixed_uses_fixed_area() {
         let backend = TestBackend::new(10, 10);
         let viewport = Viewport::Fixed(Rect::new(2, 3, 5, 4));
-        let terminal = Terminal::with_options(
-            backend,
-            TerminalOptions {
-                viewport: viewport.clone(),
-            },
-        )
-        .unwrap();
+        let terminal =
+            Terminal::with_options(backend, TerminalOptions::new().viewport(viewport.clone()))
+                .unwrap();
 
         assert_eq!(terminal.viewport, viewport);
         assert_eq!(terminal.viewport_area, Rect::new(2, 3, 5, 4));
@@ -196,9 +232,7 @@ mod tests {
 
         let terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Inline(4),
-            },
+            TerminalOptions::new().viewport(Viewport::Inline(4)),
         )
         .unwrap();
 
@@ -215,9 +249,7 @@ mod tests {
 
         let terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Inline(4),
-            },
+            TerminalOptions::new().viewport(Viewport::Inline(4)),
         )
         .unwrap();
 
@@ -234,12 +266,71 @@ mod tests {
 
         let terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Inline(10),
-            },
+            TerminalOptions::new().viewport(Viewport::Inline(10)),
         )
         .unwrap();
 
         assert_eq!(terminal.viewport_area, Rect::new(0, 0, 10, 3));
     }
+
+    #[test]
+    fn with_options_inline_bottom_pins_to_bottom() {
+        let mut backend = TestBackend::with_lines(["000", "111", "   ", "   ", "   ", "   "]);
+        backend
+            .set_cursor_position(Position { x: 0, y: 2 })
+            .unwrap();
+
+        let terminal = Terminal::with_options(
+            backend,
+            TerminalOptions::new().viewport(Viewport::InlineBottom(2)),
+        )
+        .unwrap();
+
+        assert_eq!(terminal.viewport_area, Rect::new(0, 4, 3, 2));
+        terminal
+            .backend()
+            .assert_buffer_lines(["000", "111", "   ", "   ", "   ", "   "]);
+        terminal.backend().assert_scrollback_empty();
+    }
+
+    #[test]
+    fn with_options_inline_bottom_scrolls_output_above_viewport() {
+        let mut backend = TestBackend::with_lines(["000", "111", "222", "333", "444", "   "]);
+        backend
+            .set_cursor_position(Position { x: 0, y: 5 })
+            .unwrap();
+
+        let terminal = Terminal::with_options(
+            backend,
+            TerminalOptions::new().viewport(Viewport::InlineBottom(3)),
+        )
+        .unwrap();
+
+        assert_eq!(terminal.viewport_area, Rect::new(0, 3, 3, 3));
+        terminal
+            .backend()
+            .assert_buffer_lines(["222", "333", "444", "   ", "   ", "   "]);
+        terminal.backend().assert_scrollback_lines(["000", "111"]);
+    }
+
+    #[test]
+    fn with_options_inline_bottom_clamps_height_to_terminal() {
+        let terminal = Terminal::with_options(
+            TestBackend::new(10, 3),
+            TerminalOptions::new().viewport(Viewport::InlineBottom(10)),
+        )
+        .unwrap();
+
+        assert_eq!(terminal.viewport_area, Rect::new(0, 0, 10, 3));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn terminals_created_at_different_times_are_equal() {
+        let first = Terminal::new(TestBackend::new(10, 5)).unwrap();
+        std::thread::sleep(core::time::Duration::from_millis(1));
+        let second = Terminal::new(TestBackend::new(10, 5)).unwrap();
+
+        assert_eq!(first, second);
+    }
 }
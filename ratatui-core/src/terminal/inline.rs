@@ -5,7 +5,7 @@ use crate::terminal::{Terminal, Viewport};
 
 impl<B: Backend> Terminal<B> {
     /// Insert some content before the current inline viewport. This has no effect when the
-    /// viewport is not inline.
+    /// viewport is not [`Viewport::Inline`] or [`Viewport::InlineBottom`].
     ///
     /// This is intended for inline UIs that want to print output (e.g. logs or status messages)
     /// above the UI without breaking it. See [`Viewport::Inline`] for how inline viewports are
@@ -91,9 +91,7 @@ impl<B: Backend> Terminal<B> {
     /// backend.set_cursor_position(Position::new(0, 3))?;
     /// let mut terminal = Terminal::with_options(
     ///     backend,
-    ///     TerminalOptions {
-    ///         viewport: Viewport::Inline(4),
-    ///     },
+    ///     TerminalOptions::new().viewport(Viewport::Inline(4)),
     /// )?;
     ///
     /// terminal.insert_before(1, |buf| {
@@ -110,15 +108,120 @@ impl<B: Backend> Terminal<B> {
     where
         F: FnOnce(&mut Buffer),
     {
+        self.apply_width_policy();
         match self.viewport {
             #[cfg(feature = "scrolling-regions")]
-            Viewport::Inline(_) => self.insert_before_scrolling_regions(height, draw_fn),
+            Viewport::Inline(_) | Viewport::InlineBottom(_) => {
+                self.insert_before_scrolling_regions(height, draw_fn)
+            }
             #[cfg(not(feature = "scrolling-regions"))]
-            Viewport::Inline(_) => self.insert_before_no_scrolling_regions(height, draw_fn),
+            Viewport::Inline(_) | Viewport::InlineBottom(_) => {
+                self.insert_before_no_scrolling_regions(height, draw_fn)
+            }
             _ => Ok(()),
         }
     }
 
+    /// Insert some content after the current inline viewport. This has no effect when the viewport
+    /// is not [`Viewport::Inline`].
+    ///
+    /// This is the counterpart of [`Terminal::insert_before`] for output that belongs below the UI
+    /// (e.g. a summary that should stay visible after the app exits).
+    ///
+    /// The `draw_fn` closure will be called to draw into a writable `Buffer` that is `height`
+    /// lines tall. The content of that `Buffer` is then drawn on the lines directly below the
+    /// viewport, replacing whatever was there before. Unlike [`Terminal::insert_before`], the
+    /// viewport is neither cleared nor redrawn.
+    ///
+    /// If there isn't enough space below the viewport, the screen (including the viewport) is
+    /// scrolled up to make room, pushing the lines at the top of the screen into the terminal's
+    /// scrollback buffer. Content that is taller than the space left on the screen once the
+    /// viewport is at the top is truncated to the lines that fit.
+    ///
+    /// Before:
+    /// ```text
+    /// +---------------------+
+    /// | pre-existing line 1 |
+    /// | pre-existing line 2 |
+    /// +---------------------+
+    /// |       viewport      |
+    /// +---------------------+
+    /// |                     |
+    /// +---------------------+
+    /// ```
+    ///
+    /// After inserting 2 lines:
+    /// ```text
+    /// +---------------------+
+    /// | pre-existing line 2 |
+    /// +---------------------+
+    /// |       viewport      |
+    /// +---------------------+
+    /// |   inserted line 1   |
+    /// |   inserted line 2   |
+    /// +---------------------+
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # mod ratatui {
+    /// #     pub use ratatui_core::backend;
+    /// #     pub use ratatui_core::terminal::{Terminal, TerminalOptions, Viewport};
+    /// #     pub use ratatui_core::text;
+    /// #     pub use ratatui_core::widgets;
+    /// # }
+    /// use ratatui::backend::TestBackend;
+    /// use ratatui::text::Line;
+    /// use ratatui::widgets::Widget;
+    /// use ratatui::{Terminal, TerminalOptions, Viewport};
+    ///
+    /// let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::with_options(
+    ///     backend,
+    ///     TerminalOptions::new().viewport(Viewport::Inline(4)),
+    /// )?;
+    ///
+    /// terminal.insert_after(1, |buf| {
+    ///     Line::from("Done!").render(buf.area, buf);
+    /// })?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert_after<F>(&mut self, height: u16, draw_fn: F) -> Result<(), B::Error>
+    where
+        F: FnOnce(&mut Buffer),
+    {
+        if !matches!(self.viewport, Viewport::Inline(_)) {
+            return Ok(());
+        }
+        let area = Rect {
+            x: 0,
+            y: 0,
+            width: self.viewport_area.width,
+            height,
+        };
+        let mut buffer = Buffer::empty(area);
+        draw_fn(&mut buffer);
+
+        let screen_height = self.last_known_area.height;
+        let height = height.min(screen_height.saturating_sub(self.viewport_area.height));
+        let space_below = screen_height.saturating_sub(self.viewport_area.bottom());
+
+        // Scroll the whole screen up so that the viewport moves up with it. The viewport content
+        // stays valid, so it doesn't need to be cleared. This can't scroll the viewport off the
+        // screen, as `height` leaves room for it.
+        let scroll_up = height.saturating_sub(space_below);
+        if scroll_up > 0 {
+            self.scroll_up(scroll_up)?;
+            self.set_viewport_area(Rect {
+                y: self.viewport_area.y - scroll_up,
+                ..self.viewport_area
+            });
+        }
+        self.draw_lines(self.viewport_area.bottom(), height, &buffer.content)?;
+        Ok(())
+    }
+
     /// Implement `Self::insert_before` using standard backend capabilities.
     ///
     /// This is the fallback implementation when the `scrolling-regions` feature is disabled. It
@@ -331,11 +434,12 @@ impl<B: Backend> Terminal<B> {
         let width: usize = self.last_known_area.width.into();
         let (to_draw, remainder) = cells.split_at(width * lines_to_draw as usize);
         if lines_to_draw > 0 {
-            let area = Rect::new(0, y_offset, width as u16, y_offset + lines_to_draw);
+            let area = Rect::new(0, y_offset, width as u16, lines_to_draw);
             let old = Buffer::empty(area);
             let new = Buffer {
                 area,
                 content: to_draw.to_vec(),
+                ..Buffer::default()
             };
             self.backend.draw(old.diff_iter(&new))?;
             self.backend.flush()?;
@@ -345,10 +449,9 @@ impl<B: Backend> Terminal<B> {
 
     /// Scroll the whole screen up by the given number of lines.
     ///
-    /// This is used by [`Terminal::insert_before`] when the `scrolling-regions` feature is
-    /// disabled.
+    /// This is used by [`Terminal::insert_after`], and by [`Terminal::insert_before`] when the
+    /// `scrolling-regions` feature is disabled.
     /// It scrolls by moving the cursor to the last row and calling [`Backend::append_lines`].
-    #[cfg(not(feature = "scrolling-regions"))]
     fn scroll_up(&mut self, lines_to_scroll: u16) -> Result<(), B::Error> {
         if lines_to_scroll > 0 {
             self.set_cursor_position(Position::new(
@@ -422,8 +525,51 @@ pub(crate) fn compute_inline_size<B: Backend>(
     ))
 }
 
+/// Compute the on-screen area for an inline viewport that is pinned to the bottom of the screen.
+///
+/// This is used by [`Terminal::with_options`] to translate `Viewport::InlineBottom(height)` into a
+/// concrete [`Rect`].
+///
+/// The backend cursor row is treated like the top row of a [`Viewport::Inline`]: it and everything
+/// below it may be overwritten. If the cursor row is below the top of the viewport, the screen is
+/// scrolled up by appending lines at the bottom so that the output above the cursor stays visible.
+///
+/// This returns the computed viewport area and the position of the top-left corner of the
+/// viewport.
+pub(crate) fn compute_inline_bottom_size<B: Backend>(
+    backend: &mut B,
+    height: u16,
+    size: Size,
+) -> Result<(Rect, Position), B::Error> {
+    let cursor_row = backend.get_cursor_position()?.y;
+    let area = inline_bottom_area(height, size);
+    let lines_to_scroll = cursor_row.saturating_sub(area.y);
+    if lines_to_scroll > 0 {
+        backend.set_cursor_position(Position::new(0, size.height.saturating_sub(1)))?;
+        backend.append_lines(lines_to_scroll)?;
+    }
+    Ok((area, area.as_position()))
+}
+
+/// The area of an inline viewport that occupies the bottom `height` rows of a screen of `size`.
+pub(crate) const fn inline_bottom_area(height: u16, size: Size) -> Rect {
+    let height = if height < size.height {
+        height
+    } else {
+        size.height
+    };
+    Rect {
+        x: 0,
+        y: size.height - height,
+        width: size.width,
+        height,
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+
     use crate::backend::{Backend, TestBackend};
     use crate::layout::{Position, Rect, Size};
     use crate::style::Style;
@@ -484,6 +630,170 @@ mod tests {
         assert_eq!(area, Rect::new(0, 0, 10, 4));
     }
 
+    /// Draws `line` on every line of the viewport.
+    fn draw_viewport(terminal: &mut Terminal<TestBackend>, line: &str) {
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                for y in area.top()..area.bottom() {
+                    frame.buffer.set_string(area.x, y, line, Style::default());
+                }
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn insert_before_keeps_inline_bottom_viewport_at_bottom() {
+        let mut terminal = Terminal::with_options(
+            TestBackend::new(6, 4),
+            TerminalOptions::new().viewport(Viewport::InlineBottom(2)),
+        )
+        .unwrap();
+        draw_viewport(&mut terminal, "VVVVVV");
+
+        for line in ["LOG1", "LOG2", "LOG3"] {
+            terminal
+                .insert_before(1, |buf| {
+                    buf.set_string(0, 0, line, Style::default());
+                })
+                .unwrap();
+            draw_viewport(&mut terminal, "VVVVVV");
+        }
+
+        assert_eq!(terminal.viewport_area, Rect::new(0, 2, 6, 2));
+        terminal
+            .backend()
+            .assert_buffer_lines(["LOG2  ", "LOG3  ", "VVVVVV", "VVVVVV"]);
+    }
+
+    #[test]
+    fn insert_after_is_noop_for_inline_bottom_viewports() {
+        let mut terminal = Terminal::with_options(
+            TestBackend::new(3, 2),
+            TerminalOptions::new().viewport(Viewport::InlineBottom(1)),
+        )
+        .unwrap();
+        terminal
+            .insert_after(1, |buf| {
+                buf.set_string(0, 0, "zzz", Style::default());
+            })
+            .unwrap();
+        assert_eq!(terminal.viewport_area, Rect::new(0, 1, 3, 1));
+        terminal.backend().assert_buffer_lines(["   ", "   "]);
+    }
+
+    #[test]
+    fn insert_after_is_noop_for_non_inline_viewports() {
+        let mut terminal = Terminal::new(TestBackend::new(3, 2)).unwrap();
+        terminal
+            .insert_after(1, |buf| {
+                buf.set_string(0, 0, "zzz", Style::default());
+            })
+            .unwrap();
+        terminal.backend().assert_buffer_lines(["   ", "   "]);
+    }
+
+    #[test]
+    fn insert_after_draws_below_viewport_when_space_available() {
+        // Diagram (screen height = 6, viewport height = 2, cursor row = 1):
+        //
+        // After inserting 2 lines, they replace rows 3..5 directly below the viewport, which
+        // stays in place.
+        let mut backend =
+            TestBackend::with_lines(["000000", "111111", "222222", "333333", "444444", "555555"]);
+        backend
+            .set_cursor_position(Position { x: 0, y: 1 })
+            .unwrap();
+        let mut terminal = Terminal::with_options(
+            backend,
+            TerminalOptions::new().viewport(Viewport::Inline(2)),
+        )
+        .unwrap();
+        draw_viewport(&mut terminal, "VVVVVV");
+
+        terminal
+            .insert_after(2, |buf| {
+                buf.set_string(0, 0, "AFTER1", Style::default());
+                buf.set_string(0, 1, "AFTER2", Style::default());
+            })
+            .unwrap();
+
+        assert_eq!(terminal.viewport_area, Rect::new(0, 1, 6, 2));
+        terminal
+            .backend()
+            .assert_buffer_lines(["000000", "VVVVVV", "VVVVVV", "AFTER1", "AFTER2", "555555"]);
+    }
+
+    #[test]
+    fn insert_after_scrolls_viewport_up_when_at_bottom() {
+        // Diagram (screen height = 6, viewport height = 2, viewport top = 4):
+        //
+        // There is no space below the viewport, so the screen scrolls up by 2 lines, pushing the
+        // top lines into scrollback and moving the viewport up with it.
+        let mut backend =
+            TestBackend::with_lines(["000000", "111111", "222222", "333333", "444444", "555555"]);
+        backend
+            .set_cursor_position(Position { x: 0, y: 4 })
+            .unwrap();
+        let mut terminal = Terminal::with_options(
+            backend,
+            TerminalOptions::new().viewport(Viewport::Inline(2)),
+        )
+        .unwrap();
+        draw_viewport(&mut terminal, "VVVVVV");
+
+        terminal
+            .insert_after(2, |buf| {
+                buf.set_string(0, 0, "AFTER1", Style::default());
+                buf.set_string(0, 1, "AFTER2", Style::default());
+            })
+            .unwrap();
+
+        assert_eq!(terminal.viewport_area, Rect::new(0, 2, 6, 2));
+        terminal
+            .backend()
+            .assert_buffer_lines(["222222", "333333", "VVVVVV", "VVVVVV", "AFTER1", "AFTER2"]);
+        terminal
+            .backend()
+            .assert_scrollback_lines(["000000", "111111"]);
+
+        // the viewport is still drawn in the right place
+        draw_viewport(&mut terminal, "WWWWWW");
+        terminal
+            .backend()
+            .assert_buffer_lines(["222222", "333333", "WWWWWW", "WWWWWW", "AFTER1", "AFTER2"]);
+    }
+
+    #[test]
+    fn insert_after_truncates_content_taller_than_screen() {
+        // Diagram (screen height = 4, viewport height = 2, viewport top = 2):
+        //
+        // Only 2 lines fit below the viewport once it is at the top of the screen.
+        let mut backend = TestBackend::with_lines(["000", "111", "222", "333"]);
+        backend
+            .set_cursor_position(Position { x: 0, y: 2 })
+            .unwrap();
+        let mut terminal = Terminal::with_options(
+            backend,
+            TerminalOptions::new().viewport(Viewport::Inline(2)),
+        )
+        .unwrap();
+        draw_viewport(&mut terminal, "VVV");
+
+        terminal
+            .insert_after(5, |buf| {
+                for y in 0..5 {
+                    buf.set_string(0, y, format!("A{y}"), Style::default());
+                }
+            })
+            .unwrap();
+
+        assert_eq!(terminal.viewport_area, Rect::new(0, 0, 3, 2));
+        terminal
+            .backend()
+            .assert_buffer_lines(["VVV", "VVV", "A0 ", "A1 "]);
+    }
+
     #[cfg(not(feature = "scrolling-regions"))]
     mod no_scrolling_regions {
         use super::*;
@@ -556,9 +866,7 @@ mod tests {
                 .unwrap();
             let mut terminal = Terminal::with_options(
                 backend,
-                TerminalOptions {
-                    viewport: Viewport::Inline(4),
-                },
+                TerminalOptions::new().viewport(Viewport::Inline(4)),
             )
             .unwrap();
 
@@ -620,9 +928,7 @@ mod tests {
                 .unwrap();
             let mut terminal = Terminal::with_options(
                 backend,
-                TerminalOptions {
-                    viewport: Viewport::Inline(4),
-                },
+                TerminalOptions::new().viewport(Viewport::Inline(4)),
             )
             .unwrap();
 
@@ -648,6 +954,39 @@ mod tests {
             ]);
         }
 
+        #[test]
+        fn insert_before_content_taller_than_screen() {
+            // Diagram (screen height = 4, viewport height = 2, cursor row = 1):
+            //
+            // The 6 inserted lines are drawn in screen-sized chunks. The lines that don't fit on
+            // the screen above the viewport end up in scrollback.
+            let mut backend = TestBackend::with_lines(["000", "111", "222", "333"]);
+            backend
+                .set_cursor_position(Position { x: 0, y: 1 })
+                .unwrap();
+            let mut terminal = Terminal::with_options(
+                backend,
+                TerminalOptions::new().viewport(Viewport::Inline(2)),
+            )
+            .unwrap();
+
+            terminal
+                .insert_before(6, |buf| {
+                    for y in 0..6 {
+                        buf.set_string(0, y, format!("I{y}"), Style::default());
+                    }
+                })
+                .unwrap();
+
+            assert_eq!(terminal.viewport_area, Rect::new(0, 2, 3, 2));
+            terminal
+                .backend()
+                .assert_buffer_lines(["I4 ", "I5 ", "   ", "   "]);
+            terminal
+                .backend()
+                .assert_scrollback_lines(["000", "I0 ", "I1 ", "I2 ", "I3 "]);
+        }
+
         #[test]
         fn insert_before_then_draw_repaints_cleared_viewport() {
             // Diagram (screen height = 10, viewport height = 4, cursor row = 6):
@@ -672,9 +1011,7 @@ mod tests {
                 .unwrap();
             let mut terminal = Terminal::with_options(
                 backend,
-                TerminalOptions {
-                    viewport: Viewport::Inline(4),
-                },
+                TerminalOptions::new().viewport(Viewport::Inline(4)),
             )
             .unwrap();
 
@@ -758,9 +1095,7 @@ mod tests {
                 .unwrap();
             let mut terminal = Terminal::with_options(
                 backend,
-                TerminalOptions {
-                    viewport: Viewport::Inline(4),
-                },
+                TerminalOptions::new().viewport(Viewport::Inline(4)),
             )
             .unwrap();
 
@@ -827,9 +1162,7 @@ mod tests {
                 .unwrap();
             let mut terminal = Terminal::with_options(
                 backend,
-                TerminalOptions {
-                    viewport: Viewport::Inline(4),
-                },
+                TerminalOptions::new().viewport(Viewport::Inline(4)),
             )
             .unwrap();
 
@@ -868,6 +1201,41 @@ mod tests {
                 .assert_scrollback_lines(["0000000000", "1111111111"]);
         }
 
+        #[test]
+        fn insert_before_content_taller_than_screen() {
+            // Diagram (screen height = 4, viewport height = 2, cursor row = 1):
+            //
+            // The viewport is first pushed to the bottom of the screen, then the remaining lines
+            // are drawn in chunks into the region above it, which scrolls the earlier lines into
+            // scrollback. The viewport is not cleared.
+            let mut backend = TestBackend::with_lines(["000", "111", "222", "333"]);
+            backend
+                .set_cursor_position(Position { x: 0, y: 1 })
+                .unwrap();
+            let mut terminal = Terminal::with_options(
+                backend,
+                TerminalOptions::new().viewport(Viewport::Inline(2)),
+            )
+            .unwrap();
+            draw_viewport(&mut terminal, "VVV");
+
+            terminal
+                .insert_before(6, |buf| {
+                    for y in 0..6 {
+                        buf.set_string(0, y, format!("I{y}"), Style::default());
+                    }
+                })
+                .unwrap();
+
+            assert_eq!(terminal.viewport_area, Rect::new(0, 2, 3, 2));
+            terminal
+                .backend()
+                .assert_buffer_lines(["I4 ", "I5 ", "VVV", "VVV"]);
+            terminal
+                .backend()
+                .assert_scrollback_lines(["000", "I0 ", "I1 ", "I2 ", "I3 "]);
+        }
+
         #[test]
         fn insert_before_when_viewport_is_fullscreen_appends_to_scrollback() {
             // Diagram (screen height = 4, viewport height = 4):
@@ -884,9 +1252,7 @@ mod tests {
                 .unwrap();
             let mut terminal = Terminal::with_options(
                 backend,
-                TerminalOptions {
-                    viewport: Viewport::Inline(4),
-                },
+                TerminalOptions::new().viewport(Viewport::Inline(4)),
             )
             .unwrap();
 
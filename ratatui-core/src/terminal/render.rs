@@ -28,6 +28,9 @@ impl<B: Backend> Terminal<B> {
     ///
     /// - call [`Terminal::autoresize`] if necessary
     /// - call the render callback, passing it a [`Frame`] reference to render to
+    /// - draw the virtual cursors added with [`Frame::add_virtual_cursor`]
+    /// - call [`Backend::update_semantics`] if the semantic description added with
+    ///   [`Frame::describe`] changed since the previous frame
     /// - call [`Terminal::flush`] to apply the current buffer diff to the backend
     /// - show/hide the cursor based on [`Frame::set_cursor_position`]
     /// - call [`Terminal::swap_buffers`] to prepare for the next render pass
@@ -78,6 +81,7 @@ impl<B: Backend> Terminal<B> {
     /// ```
     ///
     /// [`Backend::flush`]: crate::backend::Backend::flush
+    /// [`Backend::update_semantics`]: crate::backend::Backend::update_semantics
     pub fn draw<F>(&mut self, render_callback: F) -> Result<CompletedFrame<'_>, B::Error>
     where
         F: FnOnce(&mut Frame),
@@ -115,6 +119,9 @@ impl<B: Backend> Terminal<B> {
     ///
     /// - call [`Terminal::autoresize`] if necessary
     /// - call the render callback, passing it a [`Frame`] reference to render to
+    /// - draw the virtual cursors added with [`Frame::add_virtual_cursor`]
+    /// - call [`Backend::update_semantics`] if the semantic description added with
+    ///   [`Frame::describe`] changed since the previous frame
     /// - call [`Terminal::flush`] to apply the current buffer diff to the backend
     /// - show/hide the cursor based on [`Frame::set_cursor_position`]
     /// - call [`Terminal::swap_buffers`] to prepare for the next render pass
@@ -186,21 +193,41 @@ impl<B: Backend> Terminal<B> {
     /// ```
     ///
     /// [`Backend::flush`]: crate::backend::Backend::flush
+    /// [`Backend::update_semantics`]: crate::backend::Backend::update_semantics
     pub fn try_draw<F, E>(&mut self, render_callback: F) -> Result<CompletedFrame<'_>, B::Error>
     where
         F: FnOnce(&mut Frame) -> Result<(), E>,
         E: Into<B::Error>,
     {
+        // The terminal may be drawn from another thread than the one that set the policy.
+        self.apply_width_policy();
+
         // Autoresize - otherwise we get glitches if shrinking or potential desync between widgets
         // and the terminal (if growing), which may OOB.
         self.autoresize()?;
 
+        #[cfg(feature = "std")]
+        self.timing.start(self.epoch.elapsed());
+        #[cfg(feature = "std")]
+        let render_start = self.on_frame.is_set().then(std::time::Instant::now);
         let mut frame = self.get_frame();
 
         render_callback(&mut frame).map_err(Into::into)?;
+        #[cfg(feature = "std")]
+        let render_time = render_start.map(|render_start| render_start.elapsed());
 
+        frame.apply_virtual_cursors();
         let cursor_position = frame.cursor_position;
+        let semantics = core::mem::take(&mut frame.semantics);
 
+        if semantics != self.semantics {
+            self.backend.update_semantics(&semantics, &self.semantics)?;
+            self.semantics = semantics;
+        }
+        #[cfg(feature = "std")]
+        if let Some(render_time) = render_time {
+            self.on_frame.stats.render_time = render_time;
+        }
         self.apply_buffer_with_cursor(cursor_position)
     }
 
@@ -289,24 +316,44 @@ impl<B: Backend> Terminal<B> {
         &mut self,
         cursor_position: Option<Position>,
     ) -> Result<CompletedFrame<'_>, B::Error> {
-        // Apply the buffer diff to the backend (this is the terminal's "flush" step, distinct
-        // from `Backend::flush` below which flushes the backend's output).
-        self.flush()?;
-
-        // The cursor position can only be changed after the frame is flushed to stdout.
-        match cursor_position {
-            None => self.hide_cursor()?,
-            Some(position) => {
-                self.show_cursor()?;
-                self.set_cursor_position(position)?;
+        #[cfg(feature = "std")]
+        let flush_start = self
+            .on_frame
+            .is_set()
+            .then(|| (std::time::Instant::now(), self.backend.bytes_written()));
+        self.synchronized(|terminal| {
+            // Apply the buffer diff to the backend (this is the terminal's "flush" step, distinct
+            // from `Backend::flush` below which flushes the backend's output).
+            terminal.flush()?;
+
+            // The cursor position can only be changed after the frame is flushed to stdout.
+            match cursor_position {
+                None => terminal.hide_cursor(),
+                Some(position) => {
+                    terminal.show_cursor()?;
+                    terminal.set_cursor_position(position)
+                }
             }
-        }
+        })?;
 
         self.swap_buffers();
 
         // Flush any buffered backend output.
         self.backend.flush()?;
 
+        #[cfg(feature = "std")]
+        if let Some((flush_start, bytes_before)) = flush_start {
+            let stats = &mut self.on_frame.stats;
+            stats.count = self.frame_count;
+            stats.interval = self.timing.elapsed_since_last_draw();
+            stats.flush_time = flush_start.elapsed();
+            stats.bytes_written = self
+                .backend
+                .bytes_written()
+                .map(|bytes| bytes - bytes_before.unwrap_or_default());
+            self.on_frame.report();
+        }
+
         let completed_frame = CompletedFrame {
             buffer: &self.buffers[1 - self.current],
             area: self.last_known_area,
@@ -315,6 +362,8 @@ impl<B: Backend> Terminal<B> {
 
         // increment frame count before returning from draw
         self.frame_count = self.frame_count.wrapping_add(1);
+        #[cfg(feature = "std")]
+        self.timing.finish();
 
         Ok(completed_frame)
     }
@@ -324,9 +373,11 @@ impl<B: Backend> Terminal<B> {
 mod tests {
     use core::fmt;
 
+    use crate::accessibility::{Role, SemanticNode};
     use crate::backend::{Backend, ClearType, TestBackend, WindowSize};
-    use crate::buffer::{Buffer, Cell};
+    use crate::buffer::{Buffer, Cell, LineAttribute};
     use crate::layout::{Position, Rect};
+    use crate::style::{Color, Modifier, Style};
     use crate::terminal::{Terminal, TerminalOptions, Viewport};
 
     #[derive(Debug, Clone, Eq, PartialEq)]
@@ -350,11 +401,17 @@ mod tests {
     #[derive(Debug, Clone, Eq, PartialEq)]
     struct FallibleTestBackend {
         inner: TestBackend,
+        fail_draw: bool,
+        synchronized_update: bool,
     }
 
     impl FallibleTestBackend {
         fn new(inner: TestBackend) -> Self {
-            Self { inner }
+            Self {
+                inner,
+                fail_draw: false,
+                synchronized_update: false,
+            }
         }
     }
 
@@ -365,9 +422,22 @@ mod tests {
         where
             I: Iterator<Item = (u16, u16, &'a crate::buffer::Cell)>,
         {
+            if self.fail_draw {
+                return Err(TestError("draw failed"));
+            }
             self.inner.draw(content).map_err(|err| match err {})
         }
 
+        fn begin_synchronized_update(&mut self) -> Result<(), Self::Error> {
+            self.synchronized_update = true;
+            Ok(())
+        }
+
+        fn end_synchronized_update(&mut self) -> Result<(), Self::Error> {
+            self.synchronized_update = false;
+            Ok(())
+        }
+
         fn append_lines(&mut self, n: u16) -> Result<(), Self::Error> {
             self.inner.append_lines(n).map_err(|err| match err {})
         }
@@ -476,6 +546,36 @@ mod tests {
         );
     }
 
+    /// Each frame is timestamped when the draw starts, and knows how long ago the previous
+    /// successful draw started.
+    #[cfg(feature = "std")]
+    #[test]
+    fn draw_times_frames() {
+        use core::time::Duration;
+
+        let mut terminal = Terminal::new(TestBackend::new(3, 2)).unwrap();
+        let mut timestamps = [Duration::ZERO; 2];
+        let mut elapsed = [Duration::MAX; 2];
+        for (timestamp, elapsed) in timestamps.iter_mut().zip(&mut elapsed) {
+            std::thread::sleep(Duration::from_millis(2));
+            terminal
+                .draw(|frame| {
+                    *timestamp = frame.timestamp();
+                    *elapsed = frame.elapsed_since_last_draw();
+                })
+                .unwrap();
+        }
+
+        assert!(timestamps[0] >= Duration::from_millis(2));
+        assert_eq!(
+            elapsed[0],
+            Duration::ZERO,
+            "the first frame has no previous frame"
+        );
+        assert_eq!(Some(elapsed[1]), timestamps[1].checked_sub(timestamps[0]));
+        assert!(elapsed[1] >= Duration::from_millis(2));
+    }
+
     /// `draw` applies the cursor requested by `Frame::set_cursor_position`.
     ///
     /// The cursor is updated after rendering has been flushed, so it appears on top of the drawn
@@ -509,6 +609,163 @@ mod tests {
         );
     }
 
+    /// `draw` patches the virtual cursor style onto the cells of the virtual cursors after the
+    /// render callback, so widgets rendered later in the frame don't overwrite them.
+    #[test]
+    fn draw_renders_virtual_cursors() {
+        let backend = TestBackend::new(3, 2);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|frame| {
+                frame.add_virtual_cursor((0, 1));
+                frame.add_virtual_cursor((2, 0));
+                frame.add_virtual_cursor((5, 5));
+                frame.set_cursor_position((2, 0));
+                frame.render_widget("abc", frame.area());
+            })
+            .unwrap();
+
+        let mut expected = Buffer::with_lines(["abc", "   "]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().reversed());
+        terminal.backend().assert_buffer(&expected);
+        assert_eq!(
+            terminal.backend().cursor_position(),
+            Position { x: 2, y: 0 }
+        );
+    }
+
+    #[test]
+    fn draw_sets_cursor_positions() {
+        let backend = TestBackend::new(3, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|frame| {
+                frame.add_virtual_cursor((2, 2));
+                frame.set_virtual_cursor_style(Color::Red);
+                frame.set_cursor_positions([(1, 0), (1, 1)]);
+            })
+            .unwrap();
+
+        let mut expected = Buffer::with_lines(["   "; 3]);
+        expected.set_style(Rect::new(1, 1, 1, 1), Color::Red);
+        terminal.backend().assert_buffer(&expected);
+        assert!(terminal.backend().cursor_visible());
+        assert_eq!(
+            terminal.backend().cursor_position(),
+            Position { x: 1, y: 0 }
+        );
+
+        terminal
+            .draw(|frame| frame.set_cursor_positions::<_, Position>([]))
+            .unwrap();
+        assert!(!terminal.backend().cursor_visible());
+    }
+
+    #[test]
+    fn draw_reuses_cached_widgets() {
+        let backend = TestBackend::new(3, 2);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let top = Rect::new(0, 0, 3, 1);
+        let renders = core::cell::Cell::new(0);
+        let draw = |terminal: &mut Terminal<TestBackend>, text: &str, invalidate: bool| {
+            terminal
+                .draw(|frame| {
+                    if invalidate {
+                        frame.invalidate(Rect::new(2, 0, 1, 1));
+                    }
+                    frame.render_cached("top", top, |frame| {
+                        renders.set(renders.get() + 1);
+                        frame.render_widget(text, top);
+                    });
+                    frame.render_widget(text, Rect::new(0, 1, 3, 1));
+                })
+                .unwrap();
+        };
+
+        draw(&mut terminal, "abc", false);
+        draw(&mut terminal, "xyz", false);
+        terminal.backend().assert_buffer_lines(["abc", "xyz"]);
+        draw(&mut terminal, "xyz", true);
+        terminal.backend().assert_buffer_lines(["xyz", "xyz"]);
+        terminal.set_theme(crate::style::Theme::new());
+        draw(&mut terminal, "123", false);
+        terminal.backend().assert_buffer_lines(["123", "123"]);
+        assert_eq!(renders.get(), 3);
+    }
+
+    #[test]
+    fn draw_replays_side_effects_of_cached_widgets() {
+        let backend = TestBackend::new(3, 2);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let top = Rect::new(0, 0, 3, 1);
+        let heading = SemanticNode::new(Role::Heading, top).label("abc");
+        let mut renders = 0;
+        for _ in 0..2 {
+            terminal
+                .draw(|frame| {
+                    frame.render_cached("top", top, |frame| {
+                        renders += 1;
+                        frame.render_widget("abc", top);
+                        frame.describe(heading.clone());
+                        frame.set_cursor_position((1, 0));
+                        frame.add_virtual_cursor((2, 0));
+                        frame
+                            .buffer_mut()
+                            .set_line_attribute(0, LineAttribute::DoubleWidth);
+                    });
+                })
+                .unwrap();
+            assert_eq!(terminal.semantics.nodes(), core::slice::from_ref(&heading));
+            assert_eq!(
+                terminal.current_buffer_mut().line_attribute(0),
+                LineAttribute::Single
+            );
+            let drawn = &terminal.buffers[1 - terminal.current];
+            assert_eq!(drawn.line_attribute(0), LineAttribute::DoubleWidth);
+            assert!(drawn[(2, 0)].modifier.contains(Modifier::REVERSED));
+            terminal.backend_mut().assert_cursor_position((1, 0));
+            assert!(terminal.backend().cursor_visible());
+        }
+        assert_eq!(renders, 1);
+    }
+
+    #[test]
+    fn draw_updates_semantics() {
+        let backend = TestBackend::new(3, 2);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let heading = SemanticNode::new(Role::Heading, Rect::new(0, 0, 3, 1)).label("abc");
+
+        terminal
+            .draw(|frame| frame.describe(heading.clone()))
+            .unwrap();
+        assert_eq!(
+            terminal.backend().semantics().nodes(),
+            core::slice::from_ref(&heading)
+        );
+        assert_eq!(terminal.semantics.nodes(), [heading]);
+
+        terminal.draw(|_frame| {}).unwrap();
+        assert!(terminal.backend().semantics().is_empty());
+        assert!(terminal.semantics.is_empty());
+    }
+
+    /// A synchronized update is ended even if drawing the frame fails, as the terminal doesn't
+    /// refresh the screen until then.
+    #[test]
+    fn draw_ends_synchronized_update_on_error() {
+        let mut backend = FallibleTestBackend::new(TestBackend::new(3, 1));
+        backend.fail_draw = true;
+        let options = TerminalOptions::new().synchronized_output(true);
+        let mut terminal = Terminal::with_options(backend, options).unwrap();
+
+        let result = terminal.draw(|frame| frame.render_widget("abc", frame.area()));
+
+        assert_eq!(result.unwrap_err(), TestError("draw failed"));
+        assert!(!terminal.backend().synchronized_update);
+    }
+
     /// When the render callback returns an error, `try_draw` does not update the terminal.
     ///
     /// This is a characterization of the "no partial updates" behavior: backend contents and
@@ -597,9 +854,7 @@ mod tests {
         let backend = TestBackend::new(5, 3);
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Fixed(Rect::new(2, 1, 2, 1)),
-            },
+            TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(2, 1, 2, 1))),
         )
         .unwrap();
 
@@ -630,13 +885,9 @@ mod tests {
     fn draw_inline_completed_frame_reports_terminal_size() {
         let mut inner = TestBackend::new(6, 5);
         inner.set_cursor_position((0, 2)).unwrap();
-        let mut terminal = Terminal::with_options(
-            inner,
-            TerminalOptions {
-                viewport: Viewport::Inline(3),
-            },
-        )
-        .unwrap();
+        let mut terminal =
+            Terminal::with_options(inner, TerminalOptions::new().viewport(Viewport::Inline(3)))
+                .unwrap();
 
         let viewport_area = terminal.viewport_area;
         {
@@ -681,9 +932,7 @@ mod tests {
             .unwrap();
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Inline(3),
-            },
+            TerminalOptions::new().viewport(Viewport::Inline(3)),
         )
         .unwrap();
 
@@ -745,9 +994,7 @@ mod tests {
             .unwrap();
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Inline(4),
-            },
+            TerminalOptions::new().viewport(Viewport::Inline(4)),
         )
         .unwrap();
 
@@ -0,0 +1,174 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::accessibility::SemanticNode;
+use crate::buffer::{Buffer, LineAttribute};
+use crate::layout::{Position, Rect};
+
+/// The cells rendered by the cached widgets of the previous frames, keyed by the id passed to
+/// [`Frame::render_cached`].
+///
+/// An entry is reused while its area stays the same and doesn't intersect a region passed to
+/// [`Frame::invalidate`] during the current frame. Entries that are not rendered during a frame are
+/// dropped at the end of the frame, so the cache only holds the widgets that are on screen.
+///
+/// [`Frame::invalidate`]: crate::terminal::Frame::invalidate
+/// [`Frame::render_cached`]: crate::terminal::Frame::render_cached
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct RenderCache {
+    entries: BTreeMap<String, Entry>,
+    /// The regions invalidated during the current frame.
+    damage: Vec<Rect>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Entry {
+    /// A copy of the cells in the area of the widget.
+    cells: Buffer,
+    /// The other changes that the widget made to the frame.
+    side_effects: SideEffects,
+    /// Whether the widget was rendered during the current frame.
+    used: bool,
+}
+
+/// The changes that a cached widget made to the frame besides drawing its cells, which are made
+/// again when its cells are restored.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct SideEffects {
+    /// The semantic nodes that the widget described.
+    pub(crate) semantic_nodes: Vec<SemanticNode>,
+    /// Whether the widget changed the position of the hardware cursor.
+    pub(crate) changes_cursor_position: bool,
+    /// The position of the hardware cursor after the widget was rendered.
+    pub(crate) cursor_position: Option<Position>,
+    /// The virtual cursors that the widget added.
+    pub(crate) virtual_cursors: Vec<Position>,
+    /// Whether the widget replaced the virtual cursors added before it, e.g. with
+    /// [`Frame::set_cursor_positions`](crate::terminal::Frame::set_cursor_positions).
+    pub(crate) replaces_virtual_cursors: bool,
+    /// The line attributes that the widget changed, by row.
+    pub(crate) line_attributes: Vec<(u16, LineAttribute)>,
+}
+
+impl RenderCache {
+    /// Creates an empty cache.
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            damage: Vec::new(),
+        }
+    }
+
+    /// Marks the region as changed, so that the cached widgets that intersect it are rendered again
+    /// during the current frame.
+    pub(crate) fn invalidate(&mut self, area: Rect) {
+        self.damage.push(area);
+    }
+
+    /// Drops all cached cells, e.g. when the theme changes.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.damage.clear();
+    }
+
+    /// Copies the cached cells of the widget into `buf` and returns the other changes that the
+    /// widget made to the frame, or returns `None` if the widget must be rendered because it is not
+    /// cached or its cells are no longer valid.
+    pub(crate) fn restore(
+        &mut self,
+        id: &str,
+        area: Rect,
+        buf: &mut Buffer,
+    ) -> Option<&SideEffects> {
+        if self.damage.iter().any(|damage| damage.intersects(area)) {
+            return None;
+        }
+        let entry = self.entries.get_mut(id)?;
+        if entry.cells.area != area {
+            return None;
+        }
+        entry.used = true;
+        for position in area.positions() {
+            buf[position].clone_from(&entry.cells[position]);
+        }
+        Some(&entry.side_effects)
+    }
+
+    /// Stores a copy of the cells that the widget rendered in `area` of `buf`, and the other
+    /// changes that it made to the frame.
+    pub(crate) fn store(&mut self, id: &str, area: Rect, buf: &Buffer, side_effects: SideEffects) {
+        let mut cells = Buffer::empty(area);
+        for position in area.positions() {
+            cells[position].clone_from(&buf[position]);
+        }
+        let entry = Entry {
+            cells,
+            side_effects,
+            used: true,
+        };
+        self.entries.insert(id.to_string(), entry);
+    }
+
+    /// Drops the entries that were not used during the frame and forgets the invalidated regions.
+    pub(crate) fn end_frame(&mut self) {
+        self.entries
+            .retain(|_, entry| core::mem::take(&mut entry.used));
+        self.damage.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restore() {
+        let area = Rect::new(1, 0, 2, 1);
+        let mut cache = RenderCache::new();
+        let side_effects = SideEffects {
+            changes_cursor_position: true,
+            cursor_position: Some(Position::new(1, 0)),
+            ..SideEffects::default()
+        };
+        cache.store(
+            "a",
+            area,
+            &Buffer::with_lines(["xyz"]),
+            side_effects.clone(),
+        );
+
+        let mut buf = Buffer::with_lines(["   "]);
+        assert_eq!(cache.restore("a", area, &mut buf), Some(&side_effects));
+        assert_eq!(buf, Buffer::with_lines([" yz"]));
+        assert!(cache.restore("b", area, &mut buf).is_none());
+        assert!(
+            cache
+                .restore("a", Rect::new(0, 0, 2, 1), &mut buf)
+                .is_none()
+        );
+
+        cache.invalidate(Rect::new(2, 0, 1, 1));
+        assert!(cache.restore("a", area, &mut buf).is_none());
+    }
+
+    #[test]
+    fn end_frame_drops_unused_entries() {
+        let buf = Buffer::with_lines(["ab"]);
+        let mut cache = RenderCache::new();
+        cache.store("a", Rect::new(0, 0, 1, 1), &buf, SideEffects::default());
+        cache.store("b", Rect::new(1, 0, 1, 1), &buf, SideEffects::default());
+        cache.invalidate(buf.area);
+        cache.end_frame();
+        assert!(cache.damage.is_empty());
+
+        let mut target = Buffer::empty(buf.area);
+        assert!(
+            cache
+                .restore("a", Rect::new(0, 0, 1, 1), &mut target)
+                .is_some()
+        );
+        cache.end_frame();
+        assert_eq!(cache.entries.keys().collect::<Vec<_>>(), ["a"]);
+    }
+}
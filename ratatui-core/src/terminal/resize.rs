@@ -1,8 +1,49 @@
+use alloc::boxed::Box;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use core::time::Duration;
+
 use crate::backend::{Backend, ClearType};
 use crate::layout::Rect;
-use crate::terminal::inline::compute_inline_size;
+use crate::terminal::inline::{compute_inline_size, inline_bottom_area};
 use crate::terminal::{Terminal, Viewport};
 
+/// A function that is called with the new viewport area when the terminal is resized.
+///
+/// Like the [`FrameArena`], the hook is ignored when comparing or hashing a [`Terminal`], and a
+/// cloned terminal has no hook.
+///
+/// [`FrameArena`]: crate::terminal::FrameArena
+#[derive(Default)]
+pub(crate) struct ResizeHook(Option<Box<dyn FnMut(Rect) + Send + Sync>>);
+
+impl fmt::Debug for ResizeHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ResizeHook")
+            .field(&self.0.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+impl Clone for ResizeHook {
+    fn clone(&self) -> Self {
+        Self(None)
+    }
+}
+
+impl PartialEq for ResizeHook {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ResizeHook {}
+
+impl Hash for ResizeHook {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
 impl<B: Backend> Terminal<B> {
     /// Updates the Terminal so that internal buffers match the requested area.
     ///
@@ -20,6 +61,8 @@ impl<B: Backend> Terminal<B> {
     /// For [`Viewport::Fixed`] and [`Viewport::Fullscreen`], `area` becomes the new viewport area.
     /// For [`Viewport::Inline`], `area` is interpreted as the backend's new terminal size and the
     /// viewport origin may move to preserve the cursor's relative row within the inline UI.
+    /// For [`Viewport::InlineBottom`], `area` is also interpreted as the new terminal size and the
+    /// viewport moves to the bottom rows of the terminal.
     pub fn resize(&mut self, area: Rect) -> Result<(), B::Error> {
         let (mut next_area, cursor_to_restore) = match self.viewport {
             Viewport::Inline(height) => {
@@ -35,12 +78,15 @@ impl<B: Backend> Terminal<B> {
                 )?;
                 (next_area, Some(cursor_position))
             }
+            Viewport::InlineBottom(height) => (inline_bottom_area(height, area.as_size()), None),
             Viewport::Fixed(_) | Viewport::Fullscreen => (area, None),
         };
 
         // clear screen on horizontal shrink to avoid line wrapping issues
         if next_area.width < self.viewport_area.width {
-            next_area.y = 0;
+            if !matches!(self.viewport, Viewport::InlineBottom(_)) {
+                next_area.y = 0;
+            }
             self.backend.clear_region(ClearType::All)?;
         }
 
@@ -51,9 +97,98 @@ impl<B: Backend> Terminal<B> {
         }
 
         self.last_known_area = area;
+        #[cfg(feature = "std")]
+        {
+            self.pending_resize = None;
+        }
+        if let Some(hook) = &mut self.on_resize.0 {
+            hook(self.viewport_area);
+        }
         Ok(())
     }
 
+    /// Returns the last known size of the terminal, without querying the backend.
+    ///
+    /// This is the area that was last passed to [`Terminal::resize`] (or detected by
+    /// [`Terminal::autoresize`]), which is the size of the terminal for fullscreen and inline
+    /// viewports, and the fixed area for fixed viewports. Unlike [`Terminal::size`], this doesn't
+    /// ask the backend (which is usually a system call), so it is cheap to call on every frame.
+    pub const fn last_known_area(&self) -> Rect {
+        self.last_known_area
+    }
+
+    /// Sets a function that is called with the new viewport area whenever the terminal is
+    /// resized.
+    ///
+    /// The function is called by [`Terminal::resize`], including when [`Terminal::draw`] detects
+    /// a new size, after the buffers have been resized and before the next frame is rendered. Use
+    /// it to invalidate caches that depend on the size, or to notify other parts of the app.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::backend::TestBackend;
+    /// use std::sync::mpsc;
+    ///
+    /// use ratatui_core::layout::Rect;
+    /// use ratatui_core::terminal::Terminal;
+    ///
+    /// # let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// let (sender, receiver) = mpsc::channel();
+    /// terminal.on_resize(move |area| sender.send(area).unwrap());
+    /// terminal.resize(Rect::new(0, 0, 20, 5))?;
+    /// assert_eq!(receiver.try_recv(), Ok(Rect::new(0, 0, 20, 5)));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn on_resize<F>(&mut self, hook: F)
+    where
+        F: FnMut(Rect) + Send + Sync + 'static,
+    {
+        self.on_resize = ResizeHook(Some(Box::new(hook)));
+    }
+
+    /// Returns how long [`Terminal::autoresize`] waits for the size of the terminal to settle
+    /// before resizing.
+    ///
+    /// This is zero unless set otherwise with [`TerminalOptions::resize_debounce`] or
+    /// [`Terminal::set_resize_debounce`].
+    ///
+    /// [`TerminalOptions::resize_debounce`]: crate::terminal::TerminalOptions::resize_debounce
+    #[cfg(feature = "std")]
+    pub const fn resize_debounce(&self) -> Duration {
+        self.resize_debounce
+    }
+
+    /// Sets how long [`Terminal::autoresize`] waits for the size of the terminal to settle before
+    /// resizing.
+    ///
+    /// Dragging the edge of a terminal window reports many sizes in quick succession, and each
+    /// resize clears the screen and redraws the whole viewport. With a debounce, a new size is only
+    /// applied once the terminal has reported it for at least this long, and frames are drawn at
+    /// the previous size in the meantime. Since the size is checked when drawing, the app needs to
+    /// draw again after the debounce to apply the final size; [`Terminal::resize_pending`] returns
+    /// how long to wait.
+    ///
+    /// A debounce of zero (the default) resizes as soon as a new size is detected.
+    #[cfg(feature = "std")]
+    pub const fn set_resize_debounce(&mut self, debounce: Duration) {
+        self.resize_debounce = debounce;
+    }
+
+    /// Returns how long until a size that is waiting for the resize debounce is applied, or `None`
+    /// if no resize is pending.
+    ///
+    /// Event loops that only draw in response to events can use this as the timeout when polling
+    /// for the next event, and draw again when it expires.
+    ///
+    /// See [`Terminal::set_resize_debounce`].
+    #[cfg(feature = "std")]
+    pub fn resize_pending(&self) -> Option<Duration> {
+        self.pending_resize
+            .map(|(_, since)| self.resize_debounce.saturating_sub(since.elapsed()))
+    }
+
     /// Queries the backend for size and resizes if it doesn't match the previous size.
     ///
     /// This is called automatically during [`Terminal::draw`] / [`Terminal::try_draw`] for
@@ -63,15 +198,44 @@ impl<B: Backend> Terminal<B> {
     /// region before the next frame is rendered.
     pub fn autoresize(&mut self) -> Result<(), B::Error> {
         // fixed viewports do not get autoresized
-        if matches!(self.viewport, Viewport::Fullscreen | Viewport::Inline(_)) {
-            let area = self.size()?.into();
-            if area != self.last_known_area {
+        if matches!(
+            self.viewport,
+            Viewport::Fullscreen | Viewport::Inline(_) | Viewport::InlineBottom(_)
+        ) {
+            let area: Rect = self.size()?.into();
+            if self.should_resize(area) {
                 self.resize(area)?;
             }
         }
         Ok(())
     }
 
+    /// Returns whether a size detected by [`Terminal::autoresize`] should be applied now.
+    ///
+    /// A new size is only applied once it has been reported for the resize debounce.
+    #[cfg(feature = "std")]
+    fn should_resize(&mut self, area: Rect) -> bool {
+        if area == self.last_known_area {
+            self.pending_resize = None;
+            return false;
+        }
+        if self.resize_debounce.is_zero() {
+            return true;
+        }
+        match self.pending_resize {
+            Some((pending, since)) if pending == area => since.elapsed() >= self.resize_debounce,
+            _ => {
+                self.pending_resize = Some((area, std::time::Instant::now()));
+                false
+            }
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn should_resize(&self, area: Rect) -> bool {
+        area != self.last_known_area
+    }
+
     /// Resize internal buffers and update the current viewport area.
     ///
     /// This is an internal helper used by [`Terminal::with_options`] and [`Terminal::resize`].
@@ -104,6 +268,25 @@ mod tests {
         assert_eq!(terminal.buffers[1 - terminal.current].area, new_area);
     }
 
+    #[test]
+    fn resize_reuses_buffer_allocations() {
+        let backend = TestBackend::new(10, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let capacities = terminal
+            .buffers
+            .each_ref()
+            .map(|buffer| buffer.content.capacity());
+
+        terminal.resize(Rect::new(0, 0, 5, 5)).unwrap();
+        terminal.resize(Rect::new(0, 0, 10, 10)).unwrap();
+
+        let resized = terminal
+            .buffers
+            .each_ref()
+            .map(|buffer| buffer.content.capacity());
+        assert_eq!(resized, capacities);
+    }
+
     #[test]
     fn resize_fullscreen_triggers_clear_and_resets_back_buffer() {
         // This test is specifically about the side effects of `resize`:
@@ -159,9 +342,7 @@ mod tests {
         let backend = TestBackend::with_lines(["xxx", "yyy"]);
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Fixed(Rect::new(1, 0, 2, 2)),
-            },
+            TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(1, 0, 2, 2))),
         )
         .unwrap();
 
@@ -177,9 +358,7 @@ mod tests {
         let backend = TestBackend::new(5, 3);
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Fixed(Rect::new(1, 1, 2, 1)),
-            },
+            TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(1, 1, 2, 1))),
         )
         .unwrap();
 
@@ -205,9 +384,7 @@ mod tests {
             .unwrap();
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Inline(4),
-            },
+            TerminalOptions::new().viewport(Viewport::Inline(4)),
         )
         .unwrap();
 
@@ -257,9 +434,7 @@ mod tests {
             .unwrap();
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Inline(10),
-            },
+            TerminalOptions::new().viewport(Viewport::Inline(10)),
         )
         .unwrap();
 
@@ -277,9 +452,7 @@ mod tests {
             .unwrap();
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Inline(4),
-            },
+            TerminalOptions::new().viewport(Viewport::Inline(4)),
         )
         .unwrap();
 
@@ -305,6 +478,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resize_inline_bottom_moves_viewport_to_new_bottom() {
+        let mut terminal = Terminal::with_options(
+            TestBackend::new(10, 10),
+            TerminalOptions::new().viewport(Viewport::InlineBottom(4)),
+        )
+        .unwrap();
+        assert_eq!(terminal.viewport_area, Rect::new(0, 6, 10, 4));
+
+        terminal.backend_mut().resize(8, 12);
+        terminal.autoresize().unwrap();
+        assert_eq!(terminal.viewport_area, Rect::new(0, 8, 8, 4));
+
+        terminal.backend_mut().resize(8, 3);
+        terminal.autoresize().unwrap();
+        assert_eq!(terminal.viewport_area, Rect::new(0, 0, 8, 3));
+    }
+
     // This tests for the case where the new width is smaller than the old
     // width. The screen should be cleared completely to avoid rendering
     // glitches caused by line wrap.
@@ -316,9 +507,7 @@ mod tests {
             .unwrap();
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Inline(2),
-            },
+            TerminalOptions::new().viewport(Viewport::Inline(2)),
         )
         .unwrap();
 
@@ -337,4 +526,51 @@ mod tests {
             .all(|cell| cell == &crate::buffer::Cell::EMPTY);
         assert!(all_clear, "not all buffer cells are empty");
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn on_resize_is_called_with_new_viewport_area() {
+        use alloc::sync::Arc;
+        use alloc::vec::Vec;
+        use std::sync::Mutex;
+
+        let mut terminal = Terminal::new(TestBackend::new(3, 2)).unwrap();
+        let areas = Arc::new(Mutex::new(Vec::new()));
+        let hook_areas = Arc::clone(&areas);
+        terminal.on_resize(move |area| hook_areas.lock().unwrap().push(area));
+
+        terminal.backend_mut().resize(5, 4);
+        terminal.autoresize().unwrap();
+        terminal.autoresize().unwrap();
+
+        assert_eq!(*areas.lock().unwrap(), [Rect::new(0, 0, 5, 4)]);
+        assert_eq!(terminal.last_known_area(), Rect::new(0, 0, 5, 4));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn autoresize_waits_for_resize_debounce() {
+        use core::time::Duration;
+
+        let options = TerminalOptions::new().resize_debounce(Duration::from_secs(3600));
+        let mut terminal = Terminal::with_options(TestBackend::new(3, 2), options).unwrap();
+        assert_eq!(terminal.resize_pending(), None);
+
+        terminal.backend_mut().resize(5, 4);
+        terminal.autoresize().unwrap();
+        assert_eq!(terminal.last_known_area(), Rect::new(0, 0, 3, 2));
+        assert!(terminal.resize_pending().is_some());
+
+        // going back to the previous size cancels the pending resize
+        terminal.backend_mut().resize(3, 2);
+        terminal.autoresize().unwrap();
+        assert_eq!(terminal.resize_pending(), None);
+
+        terminal.backend_mut().resize(5, 4);
+        terminal.autoresize().unwrap();
+        terminal.set_resize_debounce(Duration::ZERO);
+        terminal.autoresize().unwrap();
+        assert_eq!(terminal.last_known_area(), Rect::new(0, 0, 5, 4));
+        assert_eq!(terminal.resize_pending(), None);
+    }
 }
@@ -0,0 +1,217 @@
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::text::{Line, Span, Text};
+
+/// The maximum number of vectors of each kind that are kept for reuse.
+///
+/// This bounds the memory held by the scratch space when more vectors are recycled than taken,
+/// e.g. when the spans of every line of a text are recycled but lines are built with
+/// [`Line::from`] instead of [`Scratch::spans`].
+const MAX_SPARE: usize = 256;
+
+/// Vectors of lines and spans that are reused between frames.
+///
+/// Building a [`Text`] for a widget allocates a vector of lines, and a vector of spans for each
+/// line, on every frame. A `Scratch` is owned by the [`Terminal`] and keeps these vectors after
+/// they are recycled, so that the next frame can fill them again without allocating. Get one with
+/// [`Scratch::lines`] or [`Scratch::spans`], and give it back with [`Scratch::recycle_lines`],
+/// [`Scratch::recycle_spans`], or [`Scratch::recycle_text`] once the widget that uses it has been
+/// rendered.
+///
+/// Use [`Frame::scratch`] to get the scratch space of the current frame. Vectors that are not
+/// recycled are simply dropped, so recycling is an optimization and never required.
+///
+/// The contents are ignored when comparing or hashing a [`Terminal`], and a cloned `Scratch`
+/// starts empty.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::terminal::Scratch;
+/// use ratatui_core::text::{Line, Text};
+///
+/// let scratch = Scratch::new();
+/// for frame in 0..3 {
+///     let mut lines = scratch.lines();
+///     lines.extend([Line::from("first"), Line::from("second")]);
+///     let text = Text::from(lines);
+///     // render the text...
+///     scratch.recycle_text(text);
+/// }
+/// ```
+///
+/// [`Frame::scratch`]: crate::terminal::Frame::scratch
+/// [`Terminal`]: crate::terminal::Terminal
+#[derive(Default)]
+pub struct Scratch {
+    /// Empty vectors of lines whose allocations are reused.
+    lines: RefCell<Vec<Vec<Line<'static>>>>,
+    /// Empty vectors of spans whose allocations are reused.
+    spans: RefCell<Vec<Vec<Span<'static>>>>,
+}
+
+impl Scratch {
+    /// Creates a new empty `Scratch`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an empty vector of lines, reusing the allocation of a recycled vector if there is
+    /// one.
+    pub fn lines<'a>(&self) -> Vec<Line<'a>> {
+        self.lines.borrow_mut().pop().unwrap_or_default()
+    }
+
+    /// Returns an empty vector of spans, reusing the allocation of a recycled vector if there is
+    /// one.
+    pub fn spans<'a>(&self) -> Vec<Span<'a>> {
+        self.spans.borrow_mut().pop().unwrap_or_default()
+    }
+
+    /// Drops the lines and keeps the allocation of the vector for a later call to
+    /// [`Scratch::lines`].
+    ///
+    /// The spans of the lines are dropped as well. Use [`Scratch::recycle_text`] to also keep the
+    /// vectors of spans.
+    pub fn recycle_lines(&self, lines: Vec<Line<'_>>) {
+        let lines = clear(lines);
+        let mut spare = self.lines.borrow_mut();
+        if lines.capacity() > 0 && spare.len() < MAX_SPARE {
+            spare.push(lines);
+        }
+    }
+
+    /// Drops the spans and keeps the allocation of the vector for a later call to
+    /// [`Scratch::spans`].
+    pub fn recycle_spans(&self, spans: Vec<Span<'_>>) {
+        let spans = clear(spans);
+        let mut spare = self.spans.borrow_mut();
+        if spans.capacity() > 0 && spare.len() < MAX_SPARE {
+            spare.push(spans);
+        }
+    }
+
+    /// Drops the text and keeps the allocations of its vector of lines and the vectors of spans of
+    /// each line.
+    pub fn recycle_text(&self, text: Text<'_>) {
+        let mut lines = text.lines;
+        for line in lines.drain(..) {
+            self.recycle_spans(line.spans);
+        }
+        self.recycle_lines(lines);
+    }
+
+    /// Returns the number of vectors of lines and spans that are kept for reuse.
+    pub fn len(&self) -> usize {
+        self.lines.borrow().len() + self.spans.borrow().len()
+    }
+
+    /// Returns true if no vectors are kept for reuse.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Drops the elements of a vector and converts it to an empty vector of another type with the same
+/// layout, which is used to forget the lifetime of recycled lines and spans without unsafe code.
+///
+/// The allocation is kept because the standard library collects a `vec::IntoIter` into a vector
+/// of a type with the same size and alignment in place. This is an optimization that the standard
+/// library does not guarantee: if it is not applied, the returned vector has no capacity and the
+/// callers drop it instead of keeping it for reuse, so recycling becomes a no-op rather than
+/// incorrect.
+fn clear<T, U>(vec: Vec<T>) -> Vec<U> {
+    vec.into_iter().filter_map(|_| None).collect()
+}
+
+impl fmt::Debug for Scratch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scratch")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Clone for Scratch {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for Scratch {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Scratch {}
+
+impl Hash for Scratch {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn clear_empties_the_vector() {
+        let spans = vec![Span::raw("a"), Span::raw("b")];
+        let capacity = spans.capacity();
+        let spans: Vec<Span<'static>> = clear(spans);
+        assert!(spans.is_empty());
+        // the allocation is either kept or dropped, never replaced by one of another size
+        assert!(spans.capacity() == capacity || spans.capacity() == 0);
+    }
+
+    #[test]
+    fn recycled_lines_are_empty() {
+        let scratch = Scratch::new();
+        let mut lines = scratch.lines();
+        lines.extend((0..100).map(|_| Line::from("line")));
+        scratch.recycle_lines(lines);
+        assert!(scratch.len() <= 1);
+
+        let lines = scratch.lines();
+        assert!(lines.is_empty());
+        assert!(scratch.is_empty());
+    }
+
+    #[test]
+    fn recycle_text_keeps_spans() {
+        let scratch = Scratch::new();
+        let text = Text::from(vec![
+            Line::from(vec![Span::raw("a"), Span::raw("b")]),
+            Line::from(vec![Span::raw("c")]),
+        ]);
+        scratch.recycle_text(text);
+        // the vectors are kept only if their allocation is reused, see `clear`
+        assert!(scratch.lines.borrow().len() <= 1);
+        assert!(scratch.spans.borrow().len() <= 2);
+        assert!(scratch.spans().is_empty());
+        assert!(scratch.lines().is_empty());
+    }
+
+    #[test]
+    fn spare_vectors_are_bounded() {
+        let scratch = Scratch::new();
+        for _ in 0..=MAX_SPARE {
+            scratch.recycle_spans(vec![Span::raw("a")]);
+        }
+        assert_eq!(scratch.len(), MAX_SPARE);
+    }
+
+    #[test]
+    fn empty_vectors_are_not_kept() {
+        let scratch = Scratch::new();
+        scratch.recycle_lines(Vec::new());
+        scratch.recycle_spans(Vec::new());
+        assert!(scratch.is_empty());
+        assert_eq!(scratch.lines().capacity(), 0);
+    }
+}
@@ -0,0 +1,194 @@
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use core::fmt;
+#[cfg(feature = "std")]
+use core::hash::{Hash, Hasher};
+use core::time::Duration;
+
+#[cfg(feature = "std")]
+use crate::backend::Backend;
+#[cfg(feature = "std")]
+use crate::terminal::Terminal;
+
+/// Statistics about a frame drawn by [`Terminal::draw`], reported to the function set with
+/// [`Terminal::on_frame`].
+///
+/// These measure where the time of a frame is spent, so that performance regressions in apps and
+/// widgets can be spotted while the app runs, e.g. by displaying them in a corner of the screen.
+///
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+/// [`Terminal::on_frame`]: crate::terminal::Terminal::on_frame
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct FrameStats {
+    /// The number of the frame, as returned by [`Frame::count`].
+    ///
+    /// [`Frame::count`]: crate::terminal::Frame::count
+    pub count: usize,
+    /// The time between the start of the previous frame and the start of this frame, or zero for
+    /// the first frame.
+    pub interval: Duration,
+    /// The time spent in the render callback passed to [`Terminal::draw`].
+    ///
+    /// [`Terminal::draw`]: crate::terminal::Terminal::draw
+    pub render_time: Duration,
+    /// The number of cells that changed since the previous frame and were drawn to the backend.
+    pub cells_changed: usize,
+    /// The number of bytes written to the terminal, or `None` if the backend doesn't count them
+    /// (see [`Backend::bytes_written`]).
+    ///
+    /// [`Backend::bytes_written`]: crate::backend::Backend::bytes_written
+    pub bytes_written: Option<u64>,
+    /// The time spent writing the changes to the backend and flushing it.
+    pub flush_time: Duration,
+}
+
+impl FrameStats {
+    /// Returns the number of frames per second at the pace of this frame, or `None` for the first
+    /// frame.
+    pub fn fps(&self) -> Option<f64> {
+        (!self.interval.is_zero()).then(|| 1.0 / self.interval.as_secs_f64())
+    }
+}
+
+/// The function set with [`Terminal::on_frame`], and the statistics of the frame being drawn.
+///
+/// Like the resize hook, the function is ignored when comparing or hashing a [`Terminal`], and a
+/// cloned terminal has no function.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub(crate) struct FrameHook {
+    hook: Option<Box<dyn FnMut(FrameStats) + Send + Sync>>,
+    /// The statistics of the frame being drawn, which are filled in as the frame is drawn.
+    pub(crate) stats: FrameStats,
+}
+
+#[cfg(feature = "std")]
+impl FrameHook {
+    /// Returns true if a function is set, so that the frame needs to be measured.
+    pub(crate) const fn is_set(&self) -> bool {
+        self.hook.is_some()
+    }
+
+    /// Calls the function with the statistics of the frame, and resets them for the next frame.
+    pub(crate) fn report(&mut self) {
+        let stats = core::mem::take(&mut self.stats);
+        if let Some(hook) = &mut self.hook {
+            hook(stats);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for FrameHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FrameHook")
+            .field(&self.hook.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clone for FrameHook {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialEq for FrameHook {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "std")]
+impl Eq for FrameHook {}
+
+#[cfg(feature = "std")]
+impl Hash for FrameHook {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(feature = "std")]
+impl<B: Backend> Terminal<B> {
+    /// Sets a function that is called with the [`FrameStats`] of each frame drawn with
+    /// [`Terminal::draw`] or [`Terminal::try_draw`].
+    ///
+    /// The function is called after the frame is flushed to the backend. The measurements are only
+    /// taken while a function is set, so there is no cost otherwise. To display the statistics in
+    /// the app, send them to the render loop (e.g. through a channel or a shared value) and render
+    /// them in the next frame, e.g. with the `FpsWidget` widget.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::backend::TestBackend;
+    /// use std::sync::mpsc;
+    ///
+    /// use ratatui_core::terminal::Terminal;
+    ///
+    /// # let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// let (sender, receiver) = mpsc::channel();
+    /// terminal.on_frame(move |stats| sender.send(stats).unwrap());
+    /// terminal.draw(|frame| frame.render_widget("Hello", frame.area()))?;
+    /// assert_eq!(receiver.try_recv().unwrap().cells_changed, 5);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn on_frame<F>(&mut self, hook: F)
+    where
+        F: FnMut(FrameStats) + Send + Sync + 'static,
+    {
+        self.on_frame.hook = Some(Box::new(hook));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use alloc::sync::Arc;
+    #[cfg(feature = "std")]
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    use std::sync::Mutex;
+
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::backend::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn on_frame_reports_each_frame() {
+        let mut terminal = Terminal::new(TestBackend::new(5, 1)).unwrap();
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let hook_reported = Arc::clone(&reported);
+        terminal.on_frame(move |stats| hook_reported.lock().unwrap().push(stats));
+
+        terminal
+            .draw(|frame| frame.render_widget("abc", frame.area()))
+            .unwrap();
+        terminal
+            .draw(|frame| frame.render_widget("abd", frame.area()))
+            .unwrap();
+
+        let reported = reported.lock().unwrap();
+        let counts: Vec<_> = reported
+            .iter()
+            .map(|stats| (stats.count, stats.cells_changed, stats.bytes_written))
+            .collect();
+        assert_eq!(counts, [(0, 3, None), (1, 1, None)]);
+        assert_eq!(reported[0].interval, Duration::ZERO);
+    }
+
+    #[test]
+    fn fps() {
+        let stats = FrameStats {
+            interval: Duration::from_millis(20),
+            ..FrameStats::default()
+        };
+        assert_eq!(stats.fps(), Some(50.0));
+        assert_eq!(FrameStats::default().fps(), None);
+    }
+}
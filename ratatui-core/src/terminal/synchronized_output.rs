@@ -0,0 +1,78 @@
+use crate::backend::Backend;
+use crate::terminal::Terminal;
+
+impl<B: Backend> Terminal<B> {
+    /// Returns whether each frame is drawn in a synchronized update.
+    ///
+    /// This is `false` unless set otherwise with [`TerminalOptions::synchronized_output`] or
+    /// [`Terminal::set_synchronized_output`].
+    ///
+    /// [`TerminalOptions::synchronized_output`]: crate::terminal::TerminalOptions::synchronized_output
+    pub const fn synchronized_output(&self) -> bool {
+        self.synchronized_output
+    }
+
+    /// Sets whether each frame is drawn in a synchronized update.
+    ///
+    /// When enabled, [`Terminal::draw`] wraps the output of each frame in
+    /// [`Backend::begin_synchronized_update`] and [`Backend::end_synchronized_update`]. Terminals
+    /// that support synchronized output (mode 2026) then display each frame at once, which avoids
+    /// tearing and flickering in apps that redraw large parts of the screen often. Terminals that
+    /// don't support it ignore the escape sequences, and backends that don't implement it draw as
+    /// usual.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::backend::TestBackend;
+    /// use ratatui_core::terminal::Terminal;
+    ///
+    /// # let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// terminal.set_synchronized_output(true);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`Backend::begin_synchronized_update`]: crate::backend::Backend::begin_synchronized_update
+    /// [`Backend::end_synchronized_update`]: crate::backend::Backend::end_synchronized_update
+    pub const fn set_synchronized_output(&mut self, synchronized_output: bool) {
+        self.synchronized_output = synchronized_output;
+    }
+
+    /// Runs `f` in a synchronized update if synchronized output is enabled.
+    ///
+    /// The update is ended even if `f` fails, as the terminal stops refreshing the screen until
+    /// then.
+    pub(crate) fn synchronized<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, B::Error>,
+    ) -> Result<T, B::Error> {
+        if !self.synchronized_output {
+            return f(self);
+        }
+        self.backend.begin_synchronized_update()?;
+        let result = f(self);
+        let ended = self.backend.end_synchronized_update();
+        let value = result?;
+        ended?;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::TestBackend;
+    use crate::terminal::{Terminal, TerminalOptions};
+
+    #[test]
+    fn synchronized_output() {
+        let mut terminal = Terminal::new(TestBackend::new(1, 1)).unwrap();
+        assert!(!terminal.synchronized_output());
+        terminal.set_synchronized_output(true);
+        assert!(terminal.synchronized_output());
+
+        let options = TerminalOptions::new().synchronized_output(true);
+        let terminal = Terminal::with_options(TestBackend::new(1, 1), options).unwrap();
+        assert!(terminal.synchronized_output());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::backend::Backend;
+use crate::style::Theme;
+use crate::terminal::Terminal;
+
+impl<B: Backend> Terminal<B> {
+    /// Returns the theme that is passed to each [`Frame`].
+    ///
+    /// [`Frame`]: crate::terminal::Frame
+    pub const fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Sets the theme that is passed to each [`Frame`], e.g. when the user switches between a
+    /// light and a dark theme.
+    ///
+    /// The theme takes effect on the next [`Terminal::draw`]. Since only changed cells are
+    /// written to the backend, switching themes does not require clearing the terminal. The widgets
+    /// cached with [`Frame::render_cached`] are rendered again with the new theme.
+    ///
+    /// [`Frame`]: crate::terminal::Frame
+    /// [`Frame::render_cached`]: crate::terminal::Frame::render_cached
+    /// [`Terminal::draw`]: crate::terminal::Terminal::draw
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.render_cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::TestBackend;
+    use crate::style::{Color, Theme};
+    use crate::terminal::Terminal;
+
+    #[test]
+    fn frame_uses_terminal_theme() {
+        let mut terminal = Terminal::new(TestBackend::new(2, 1)).unwrap();
+        assert_eq!(terminal.theme(), &Theme::new());
+
+        let theme = Theme::new().with_style(Theme::TEXT, Color::Red);
+        terminal.set_theme(theme.clone());
+        terminal
+            .draw(|frame| {
+                assert_eq!(frame.theme(), &theme);
+                let style = frame.theme().style(Theme::TEXT);
+                frame.buffer_mut().set_string(0, 0, "hi", style);
+            })
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(0, 0)].fg, Color::Red);
+    }
+}
@@ -0,0 +1,92 @@
+use core::hash::{Hash, Hasher};
+use core::time::Duration;
+use std::time::Instant;
+
+/// A monotonic clock that measures the time since the [`Terminal`] was created.
+///
+/// All clocks compare equal and hash to nothing, so that two terminals created at different times
+/// can still be compared.
+///
+/// [`Terminal`]: crate::terminal::Terminal
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Clock(Instant);
+
+impl Clock {
+    /// Returns the time since the clock was created.
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.0.elapsed()
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self(Instant::now())
+    }
+}
+
+impl PartialEq for Clock {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Clock {}
+
+impl Hash for Clock {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// The times at which the frames of a viewport are drawn, relative to the creation of the
+/// [`Terminal`](crate::terminal::Terminal).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub(crate) struct FrameTiming {
+    /// When the current frame started to be drawn.
+    timestamp: Duration,
+    /// When the last frame that was drawn successfully started to be drawn.
+    last_draw: Option<Duration>,
+}
+
+impl FrameTiming {
+    /// Marks the start of a new frame at `timestamp`.
+    pub(crate) const fn start(&mut self, timestamp: Duration) {
+        self.timestamp = timestamp;
+    }
+
+    /// Marks the current frame as drawn successfully.
+    pub(crate) const fn finish(&mut self) {
+        self.last_draw = Some(self.timestamp);
+    }
+
+    /// Returns when the current frame started to be drawn.
+    pub(crate) const fn timestamp(&self) -> Duration {
+        self.timestamp
+    }
+
+    /// Returns the time between the start of the last successful frame and the current frame, or
+    /// zero if no frame has been drawn yet.
+    pub(crate) const fn elapsed_since_last_draw(&self) -> Duration {
+        match self.last_draw {
+            Some(last_draw) => self.timestamp.saturating_sub(last_draw),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elapsed_since_last_draw() {
+        let mut timing = FrameTiming::default();
+        timing.start(Duration::from_millis(5));
+        assert_eq!(timing.elapsed_since_last_draw(), Duration::ZERO);
+        timing.finish();
+
+        // a frame that fails to draw isn't counted
+        timing.start(Duration::from_millis(20));
+        timing.start(Duration::from_millis(30));
+        assert_eq!(timing.timestamp(), Duration::from_millis(30));
+        assert_eq!(timing.elapsed_since_last_draw(), Duration::from_millis(25));
+    }
+}
@@ -14,6 +14,8 @@ use crate::layout::Rect;
 /// - [`Viewport::Fullscreen`] for the standard case: your app owns the whole terminal surface.
 /// - [`Viewport::Inline`] when the UI should live inside a larger CLI flow, with normal terminal
 ///   output above it.
+/// - [`Viewport::InlineBottom`] when the UI should stay at the bottom of the terminal while output
+///   scrolls above it, like a progress bar under a stream of log lines.
 /// - [`Viewport::Fixed`] when Ratatui should render into one region of a terminal layout managed
 ///   elsewhere.
 ///
@@ -36,9 +38,7 @@ use crate::layout::Rect;
 ///
 /// let mut terminal = Terminal::with_options(
 ///     CrosstermBackend::new(std::io::stdout()),
-///     TerminalOptions {
-///         viewport: Viewport::Fixed(Rect::new(10, 5, 20, 4)),
-///     },
+///     TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(10, 5, 20, 4))),
 /// )?;
 ///
 /// terminal.draw(|frame| {
@@ -97,6 +97,24 @@ pub enum Viewport {
     /// [`Terminal::draw`]: crate::terminal::Terminal::draw
     /// [`Terminal::try_draw`]: crate::terminal::Terminal::try_draw
     Inline(u16),
+    /// Draw the application inline, pinned to the bottom of the terminal.
+    ///
+    /// Choose this for status bars and progress UIs that sit under a stream of output (e.g. log
+    /// lines printed with [`Terminal::insert_before`]), like the progress bar of `cargo build`.
+    ///
+    /// Like [`Viewport::Inline`], the viewport spans the full terminal width and the height is
+    /// specified in rows and clamped to the current terminal height. Instead of being anchored to
+    /// the cursor row, the viewport always occupies the bottom rows of the terminal. When the
+    /// terminal is created, Ratatui scrolls existing output up if it would be covered by the
+    /// viewport. When the terminal is resized, the viewport moves to the new bottom of the screen.
+    ///
+    /// Output inserted with [`Terminal::insert_before`] appears directly above the viewport and
+    /// scrolls the rest of the screen up. [`Terminal::insert_after`] has no effect, as there is no
+    /// space below the viewport.
+    ///
+    /// [`Terminal::insert_before`]: crate::terminal::Terminal::insert_before
+    /// [`Terminal::insert_after`]: crate::terminal::Terminal::insert_after
+    InlineBottom(u16),
     /// Draw into a fixed region of the terminal.
     ///
     /// Choose this when Ratatui is responsible for only part of the screen, for example a panel in
@@ -123,6 +141,7 @@ impl fmt::Display for Viewport {
         match self {
             Self::Fullscreen => write!(f, "Fullscreen"),
             Self::Inline(height) => write!(f, "Inline({height})"),
+            Self::InlineBottom(height) => write!(f, "InlineBottom({height})"),
             Self::Fixed(area) => write!(f, "Fixed({area})"),
         }
     }
@@ -138,6 +157,7 @@ mod tests {
     fn viewport_to_string() {
         assert_eq!(Viewport::Fullscreen.to_string(), "Fullscreen");
         assert_eq!(Viewport::Inline(5).to_string(), "Inline(5)");
+        assert_eq!(Viewport::InlineBottom(5).to_string(), "InlineBottom(5)");
         assert_eq!(
             Viewport::Fixed(Rect::new(0, 0, 5, 5)).to_string(),
             "Fixed(5x5+0+0)"
@@ -0,0 +1,395 @@
+use alloc::vec::Vec;
+
+use crate::accessibility::Semantics;
+use crate::backend::Backend;
+use crate::buffer::Buffer;
+use crate::layout::Rect;
+use crate::terminal::color_support::downsample_buffer;
+use crate::terminal::render_cache::RenderCache;
+use crate::terminal::{CompletedFrame, Frame, Terminal};
+
+/// Identifies a viewport that was added to a [`Terminal`] with [`Terminal::add_viewport`].
+///
+/// Ids are unique for the lifetime of a terminal, so the id of a removed viewport is never reused.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct ViewportId(usize);
+
+/// A fixed viewport that is drawn independently of the main viewport of a [`Terminal`].
+///
+/// Each viewport has its own pair of buffers, so that drawing it only sends the cells that changed
+/// since it was last drawn.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct SecondaryViewport {
+    id: ViewportId,
+    buffers: [Buffer; 2],
+    current: usize,
+    frame_count: usize,
+    #[cfg(feature = "std")]
+    timing: crate::terminal::timing::FrameTiming,
+    render_cache: RenderCache,
+}
+
+impl<B: Backend> Terminal<B> {
+    /// Adds a viewport that covers the given area of the terminal.
+    ///
+    /// The main viewport of the terminal (see [`Viewport`]) is drawn with [`Terminal::draw`].
+    /// Additional viewports are drawn independently with [`Terminal::draw_viewport`], each with
+    /// its own buffers, so only the cells that changed since the viewport was last drawn are sent
+    /// to the backend. This allows a wrapper tool to decorate the screen, e.g. with a status line
+    /// at the top of the screen above an inline UI at the bottom, without owning the whole screen.
+    ///
+    /// Like [`Viewport::Fixed`], the area is in terminal coordinates and is not resized
+    /// automatically. Use [`Terminal::resize_viewport`] to move or resize it. Ratatui doesn't
+    /// prevent viewports from overlapping; if they do, the viewport drawn last wins.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::backend::TestBackend;
+    /// use ratatui_core::layout::Rect;
+    /// use ratatui_core::terminal::{Terminal, TerminalOptions, Viewport};
+    ///
+    /// let backend = TestBackend::new(20, 10);
+    /// let options = TerminalOptions::new().viewport(Viewport::InlineBottom(3));
+    /// let mut terminal = Terminal::with_options(backend, options)?;
+    /// let status_line = terminal.add_viewport(Rect::new(0, 0, 20, 1));
+    ///
+    /// terminal.draw_viewport(status_line, |frame| {
+    ///     frame.render_widget("status: ok", frame.area());
+    /// })?;
+    /// terminal.draw(|frame| {
+    ///     frame.render_widget("inline ui", frame.area());
+    /// })?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`Viewport`]: crate::terminal::Viewport
+    /// [`Viewport::Fixed`]: crate::terminal::Viewport::Fixed
+    pub fn add_viewport(&mut self, area: Rect) -> ViewportId {
+        let id = ViewportId(self.next_viewport_id);
+        self.next_viewport_id += 1;
+        self.secondary_viewports.push(SecondaryViewport {
+            id,
+            buffers: [Buffer::empty(area), Buffer::empty(area)],
+            current: 0,
+            frame_count: 0,
+            #[cfg(feature = "std")]
+            timing: crate::terminal::timing::FrameTiming::default(),
+            render_cache: RenderCache::new(),
+        });
+        id
+    }
+
+    /// Removes a viewport added with [`Terminal::add_viewport`].
+    ///
+    /// Returns the area of the removed viewport, or `None` if there is no viewport with this id.
+    /// The content of the viewport is left on the screen.
+    pub fn remove_viewport(&mut self, id: ViewportId) -> Option<Rect> {
+        let index = self.secondary_viewports.iter().position(|v| v.id == id)?;
+        let viewport = self.secondary_viewports.remove(index);
+        Some(viewport.buffers[viewport.current].area)
+    }
+
+    /// Returns the area of a viewport added with [`Terminal::add_viewport`], or `None` if there is
+    /// no viewport with this id.
+    pub fn viewport_area(&self, id: ViewportId) -> Option<Rect> {
+        self.secondary_viewports
+            .iter()
+            .find(|v| v.id == id)
+            .map(|v| v.buffers[v.current].area)
+    }
+
+    /// Moves or resizes a viewport added with [`Terminal::add_viewport`].
+    ///
+    /// The next call to [`Terminal::draw_viewport`] redraws the whole viewport. The area that the
+    /// viewport previously covered is not cleared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no viewport with this id.
+    pub fn resize_viewport(&mut self, id: ViewportId, area: Rect) {
+        let viewport = self.secondary_viewport_mut(id);
+        viewport.buffers[viewport.current].resize(area);
+        viewport.buffers[1 - viewport.current].resize(area);
+        viewport.buffers[1 - viewport.current].reset();
+    }
+
+    /// Draws a single frame to a viewport added with [`Terminal::add_viewport`].
+    ///
+    /// This works like [`Terminal::draw`], except that the [`Frame`] covers the area of the given
+    /// viewport, and the main viewport is left untouched. The cursor is only changed if the render
+    /// callback calls [`Frame::set_cursor_position`], so that drawing a decoration doesn't hide the
+    /// cursor of the main UI.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no viewport with this id.
+    pub fn draw_viewport<F>(
+        &mut self,
+        id: ViewportId,
+        render_callback: F,
+    ) -> Result<CompletedFrame<'_>, B::Error>
+    where
+        F: FnOnce(&mut Frame),
+    {
+        self.try_draw_viewport(id, |frame| {
+            render_callback(frame);
+            Ok::<(), B::Error>(())
+        })
+    }
+
+    /// Tries to draw a single frame to a viewport added with [`Terminal::add_viewport`].
+    ///
+    /// This is the equivalent of [`Terminal::draw_viewport`] but the render callback is a function
+    /// or closure that returns a `Result` instead of nothing. If the render callback returns an
+    /// error, the terminal is not updated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no viewport with this id.
+    pub fn try_draw_viewport<F, E>(
+        &mut self,
+        id: ViewportId,
+        render_callback: F,
+    ) -> Result<CompletedFrame<'_>, B::Error>
+    where
+        F: FnOnce(&mut Frame) -> Result<(), E>,
+        E: Into<B::Error>,
+    {
+        self.apply_width_policy();
+        let index = self.secondary_viewport_index(id);
+        let viewport = &mut self.secondary_viewports[index];
+        let current = viewport.current;
+        #[cfg(feature = "std")]
+        viewport.timing.start(self.epoch.elapsed());
+        let mut frame = Frame {
+            cursor_position: None,
+            virtual_cursors: Vec::new(),
+            virtual_cursor_style: Frame::DEFAULT_VIRTUAL_CURSOR_STYLE,
+            viewport_area: viewport.buffers[current].area,
+            buffer: &mut viewport.buffers[current],
+            count: viewport.frame_count,
+            #[cfg(feature = "std")]
+            timestamp: viewport.timing.timestamp(),
+            #[cfg(feature = "std")]
+            elapsed_since_last_draw: viewport.timing.elapsed_since_last_draw(),
+            theme: &self.theme,
+            semantics: Semantics::new(),
+            render_cache: &mut viewport.render_cache,
+            #[cfg(feature = "frame-arena")]
+            arena: &self.arena,
+            scratch: &self.scratch,
+        };
+        render_callback(&mut frame).map_err(Into::into)?;
+        frame.apply_virtual_cursors();
+        let cursor_position = frame.cursor_position;
+        frame.render_cache.end_frame();
+
+        downsample_buffer(self.color_support, &mut viewport.buffers[current]);
+        self.synchronized(|terminal| {
+            let viewport = &terminal.secondary_viewports[index];
+            let previous_buffer = &viewport.buffers[1 - current];
+            let current_buffer = &viewport.buffers[current];
+            let updates =
+                terminal
+                    .diff_strategy
+                    .updates(previous_buffer, current_buffer, Vec::new());
+            terminal.backend.draw(updates)?;
+            if let Some(position) = cursor_position {
+                terminal.show_cursor()?;
+                terminal.set_cursor_position(position)?;
+            }
+            Ok(())
+        })?;
+
+        let viewport = &mut self.secondary_viewports[index];
+        viewport.buffers[1 - current].reset();
+        viewport.current = 1 - current;
+        #[cfg(feature = "frame-arena")]
+        self.arena.reset();
+        self.backend.flush()?;
+
+        let viewport = &mut self.secondary_viewports[index];
+        let count = viewport.frame_count;
+        viewport.frame_count = count.wrapping_add(1);
+        #[cfg(feature = "std")]
+        viewport.timing.finish();
+        let buffer = &viewport.buffers[current];
+        Ok(CompletedFrame {
+            buffer,
+            area: buffer.area,
+            count,
+        })
+    }
+
+    /// Resets the previous buffers of all viewports added with [`Terminal::add_viewport`], so that
+    /// they are fully redrawn the next time they are drawn.
+    ///
+    /// This is used when the screen is cleared.
+    pub(crate) fn reset_secondary_viewports(&mut self) {
+        for viewport in &mut self.secondary_viewports {
+            viewport.buffers[1 - viewport.current].reset();
+        }
+    }
+
+    fn secondary_viewport_index(&self, id: ViewportId) -> usize {
+        self.secondary_viewports
+            .iter()
+            .position(|v| v.id == id)
+            .unwrap_or_else(|| panic!("no viewport with id {id:?}"))
+    }
+
+    fn secondary_viewport_mut(&mut self, id: ViewportId) -> &mut SecondaryViewport {
+        let index = self.secondary_viewport_index(id);
+        &mut self.secondary_viewports[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::TestBackend;
+    use crate::buffer::Cell;
+    use crate::layout::Position;
+    use crate::terminal::{TerminalOptions, Viewport};
+
+    fn terminal_with_status_line() -> (Terminal<TestBackend>, ViewportId) {
+        let mut terminal = Terminal::with_options(
+            TestBackend::new(6, 4),
+            TerminalOptions::new().viewport(Viewport::InlineBottom(2)),
+        )
+        .unwrap();
+        let status_line = terminal.add_viewport(Rect::new(0, 0, 6, 1));
+        (terminal, status_line)
+    }
+
+    #[test]
+    fn draw_viewport_draws_into_its_area() {
+        let (mut terminal, status_line) = terminal_with_status_line();
+
+        let frame = terminal
+            .draw_viewport(status_line, |frame| {
+                assert_eq!(frame.area(), Rect::new(0, 0, 6, 1));
+                frame.render_widget("status", frame.area());
+            })
+            .unwrap();
+        assert_eq!(frame.area, Rect::new(0, 0, 6, 1));
+        assert_eq!(frame.count, 0);
+        terminal
+            .draw(|frame| frame.render_widget("main", frame.area()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .assert_buffer_lines(["status", "      ", "main  ", "      "]);
+    }
+
+    #[test]
+    fn draw_viewport_only_draws_changes() {
+        let (mut terminal, status_line) = terminal_with_status_line();
+        terminal
+            .draw_viewport(status_line, |frame| {
+                frame.render_widget("abc", frame.area());
+            })
+            .unwrap();
+
+        // overwrite the status line on the backend, so that only the changed cell is redrawn
+        let x = Cell::new("x");
+        terminal
+            .backend_mut()
+            .draw([(0, 0, &x), (1, 0, &x), (2, 0, &x)].into_iter())
+            .unwrap();
+        let frame = terminal
+            .draw_viewport(status_line, |frame| {
+                frame.render_widget("abd", frame.area());
+            })
+            .unwrap();
+        assert_eq!(frame.count, 1);
+        terminal
+            .backend()
+            .assert_buffer_lines(["xxd   ", "      ", "      ", "      "]);
+    }
+
+    #[test]
+    fn draw_viewport_leaves_cursor_unless_set() {
+        let (mut terminal, status_line) = terminal_with_status_line();
+        terminal
+            .draw(|frame| frame.set_cursor_position((1, 3)))
+            .unwrap();
+
+        terminal.draw_viewport(status_line, |_frame| {}).unwrap();
+        assert!(terminal.backend().cursor_visible());
+        assert_eq!(
+            terminal.backend_mut().get_cursor_position().unwrap(),
+            Position::new(1, 3)
+        );
+
+        terminal
+            .draw_viewport(status_line, |frame| frame.set_cursor_position((2, 0)))
+            .unwrap();
+        assert_eq!(
+            terminal.backend_mut().get_cursor_position().unwrap(),
+            Position::new(2, 0)
+        );
+    }
+
+    #[test]
+    fn resize_and_remove_viewport() {
+        let (mut terminal, status_line) = terminal_with_status_line();
+        assert_eq!(
+            terminal.viewport_area(status_line),
+            Some(Rect::new(0, 0, 6, 1))
+        );
+
+        terminal.resize_viewport(status_line, Rect::new(1, 1, 3, 1));
+        terminal
+            .draw_viewport(status_line, |frame| {
+                frame.render_widget("abc", frame.area());
+            })
+            .unwrap();
+        terminal
+            .backend()
+            .assert_buffer_lines(["      ", " abc  ", "      ", "      "]);
+
+        assert_eq!(
+            terminal.remove_viewport(status_line),
+            Some(Rect::new(1, 1, 3, 1))
+        );
+        assert_eq!(terminal.viewport_area(status_line), None);
+        assert_eq!(terminal.remove_viewport(status_line), None);
+
+        let next = terminal.add_viewport(Rect::new(0, 0, 1, 1));
+        assert_ne!(next, status_line);
+    }
+
+    #[test]
+    #[should_panic = "no viewport with id"]
+    fn draw_removed_viewport_panics() {
+        let (mut terminal, status_line) = terminal_with_status_line();
+        terminal.remove_viewport(status_line);
+        let _ = terminal.draw_viewport(status_line, |_frame| {});
+    }
+
+    #[test]
+    fn clear_redraws_viewports() {
+        let (mut terminal, status_line) = terminal_with_status_line();
+        terminal
+            .draw_viewport(status_line, |frame| {
+                frame.render_widget("abc", frame.area());
+            })
+            .unwrap();
+        terminal.resize(Rect::new(0, 0, 5, 4)).unwrap();
+        terminal
+            .backend()
+            .assert_buffer_lines(["      ", "      ", "      ", "      "]);
+
+        terminal
+            .draw_viewport(status_line, |frame| {
+                frame.render_widget("abc", frame.area());
+            })
+            .unwrap();
+        terminal
+            .backend()
+            .assert_buffer_lines(["abc   ", "      ", "      ", "      "]);
+    }
+}
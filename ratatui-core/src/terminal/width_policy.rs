@@ -0,0 +1,92 @@
+use crate::backend::Backend;
+use crate::buffer::WidthPolicy;
+use crate::terminal::Terminal;
+
+impl<B: Backend> Terminal<B> {
+    /// Returns how the width of characters is computed.
+    ///
+    /// This is the default [`WidthPolicy`] unless set otherwise with
+    /// [`TerminalOptions::width_policy`] or [`Terminal::set_width_policy`].
+    ///
+    /// [`TerminalOptions::width_policy`]: crate::terminal::TerminalOptions::width_policy
+    pub const fn width_policy(&self) -> WidthPolicy {
+        self.width_policy
+    }
+
+    /// Sets how the width of characters is computed, to match how the terminal renders ambiguous
+    /// width characters and emoji.
+    ///
+    /// The policy becomes the current [`WidthPolicy`], which is used to measure text and place
+    /// cells while rendering. It is made current again by each [`Terminal::draw`],
+    /// [`Terminal::flush`] and [`Terminal::insert_before`], so that it also applies when the
+    /// terminal is drawn from another thread. Since the width of the cells that are already on the
+    /// screen may change, the next [`Terminal::flush`] redraws the whole viewport.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui_core::backend::TestBackend;
+    /// use ratatui_core::buffer::{AmbiguousWidth, WidthPolicy};
+    /// use ratatui_core::terminal::Terminal;
+    ///
+    /// # let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// terminal.set_width_policy(WidthPolicy::new().ambiguous(AmbiguousWidth::Wide));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn set_width_policy(&mut self, width_policy: WidthPolicy) {
+        width_policy.set_current();
+        if width_policy != self.width_policy {
+            self.width_policy = width_policy;
+            self.buffers[1 - self.current].reset();
+        }
+    }
+
+    /// Makes the policy of the terminal the current [`WidthPolicy`] of this thread.
+    pub(crate) fn apply_width_policy(&self) {
+        self.width_policy.set_current();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::backend::TestBackend;
+    use crate::buffer::{AmbiguousWidth, WidthPolicy};
+    use crate::style::Style;
+    use crate::terminal::{Terminal, TerminalOptions};
+
+    #[test]
+    fn ambiguous_wide_characters_take_two_cells() {
+        let policy = WidthPolicy::new().ambiguous(AmbiguousWidth::Wide);
+        let options = TerminalOptions::new().width_policy(policy);
+        let mut terminal = Terminal::with_options(TestBackend::new(4, 1), options).unwrap();
+        assert_eq!(terminal.width_policy(), policy);
+        terminal
+            .draw(|frame| frame.buffer_mut().set_string(0, 0, "±±", Style::new()))
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(0, 0)].symbol(), "±");
+        assert_eq!(terminal.backend().buffer()[(2, 0)].symbol(), "±");
+
+        terminal.set_width_policy(WidthPolicy::new());
+        assert_eq!(WidthPolicy::current(), WidthPolicy::new());
+    }
+
+    #[test]
+    fn draw_from_another_thread_uses_the_terminal_policy() {
+        let policy = WidthPolicy::new().ambiguous(AmbiguousWidth::Wide);
+        let options = TerminalOptions::new().width_policy(policy);
+        let terminal = Terminal::with_options(TestBackend::new(4, 1), options).unwrap();
+        WidthPolicy::new().set_current();
+
+        let terminal = std::thread::spawn(move || {
+            let mut terminal = terminal;
+            terminal
+                .draw(|frame| frame.buffer_mut().set_string(0, 0, "±±", Style::new()))
+                .unwrap();
+            terminal
+        })
+        .join()
+        .unwrap();
+        assert_eq!(terminal.backend().buffer()[(2, 0)].symbol(), "±");
+    }
+}
@@ -48,6 +48,9 @@
 //! ]);
 //! ```
 
+#[cfg(feature = "ansi-parser")]
+mod ansi;
+
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
@@ -62,3 +65,9 @@ pub use span::{Span, ToSpan};
 
 mod text;
 pub use text::{Text, ToText};
+
+mod truncate;
+pub use truncate::TruncateStyle;
+
+mod wrapped_cursor;
+pub use wrapped_cursor::WrappedCursor;
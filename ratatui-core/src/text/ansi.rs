@@ -0,0 +1,337 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::Peekable;
+use core::str::{Chars, Split};
+
+#[cfg(feature = "underline-style")]
+use crate::style::UnderlineStyle;
+use crate::style::{Color, Modifier, Style};
+use crate::text::{Line, Span, Text};
+
+/// The colors of the SGR codes `30`–`37` and `90`–`97`, in order.
+const NAMED_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+impl Text<'static> {
+    /// Converts text with ANSI escape sequences into styled [`Text`].
+    ///
+    /// This is useful to display the colored output of other programs, such as `git`, `cargo` or
+    /// `ls --color`. The SGR sequences (`ESC [ ... m`) are converted into the [`Style`] of the
+    /// spans:
+    ///
+    /// - `0` resets the style to [`Style::new`], so the text inherits the style of the widget
+    /// - the modifiers `1`–`9` are added and the codes `21`–`29` remove them
+    /// - the named (`30`–`37`, `90`–`97`), indexed (`38;5;n`) and RGB (`38;2;r;g;b`) foreground
+    ///   colors, and the matching background colors, are supported in both the `;` and `:` forms
+    /// - `39` and `49` reset the foreground and background colors
+    ///
+    /// The underline color (`58`) and underline styles (`4:x`) are supported when the
+    /// `underline-color` and `underline-style` features are enabled. Other escape sequences, such
+    /// as cursor movements and hyperlinks, are removed, as well as carriage returns and other
+    /// control characters except tabs. The style carries over from one line to the next, like in a
+    /// terminal.
+    ///
+    /// This is only available when the `ansi-parser` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Stylize};
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let text = Text::from_ansi("\x1b[1;31merror\x1b[0m: oops\n\x1b[38;5;8mnote\x1b[m");
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(vec![
+    ///         Line::from(vec!["error".red().bold(), ": oops".into()]),
+    ///         Line::from("note".fg(Color::Indexed(8))),
+    ///     ])
+    /// );
+    /// ```
+    pub fn from_ansi(input: &str) -> Self {
+        let mut parser = AnsiParser::default();
+        parser.parse(input);
+        parser.finish()
+    }
+}
+
+/// Accumulates styled lines while going through the input.
+#[derive(Default)]
+struct AnsiParser {
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    content: String,
+    /// The style of `content`.
+    content_style: Style,
+    /// The style set by the escape sequences so far.
+    style: Style,
+}
+
+impl AnsiParser {
+    fn parse(&mut self, input: &str) {
+        let mut chars = input.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\x1b' => self.escape_sequence(&mut chars),
+                '\n' => self.push_line(),
+                '\t' => self.push_char(c),
+                c if c.is_control() => {}
+                c => self.push_char(c),
+            }
+        }
+    }
+
+    fn finish(mut self) -> Text<'static> {
+        if self.lines.is_empty() || !self.content.is_empty() || !self.spans.is_empty() {
+            self.push_line();
+        }
+        Text::from(self.lines)
+    }
+
+    fn push_char(&mut self, c: char) {
+        if self.content_style != self.style {
+            self.push_span();
+            self.content_style = self.style;
+        }
+        self.content.push(c);
+    }
+
+    fn push_span(&mut self) {
+        if !self.content.is_empty() {
+            let content = core::mem::take(&mut self.content);
+            self.spans.push(Span::styled(content, self.content_style));
+        }
+    }
+
+    fn push_line(&mut self) {
+        self.push_span();
+        self.lines
+            .push(Line::from(core::mem::take(&mut self.spans)));
+    }
+
+    /// Handles the sequence that follows an `ESC` character, ignoring anything other than SGR.
+    fn escape_sequence(&mut self, chars: &mut Peekable<Chars>) {
+        match chars.next() {
+            // Control Sequence Introducer: parameter bytes, intermediate bytes and a final byte
+            Some('[') => {
+                let mut params = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !matches!(c, '\x20'..='\x3f') {
+                        break;
+                    }
+                    params.push(c);
+                    chars.next();
+                }
+                if chars.next() == Some('m') {
+                    self.select_graphic_rendition(&params);
+                }
+            }
+            // Operating System Command (e.g. hyperlinks), terminated by BEL or ST (`ESC \`)
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn select_graphic_rendition(&mut self, params: &str) {
+        let style = &mut self.style;
+        let mut params = params.split(';');
+        while let Some(param) = params.next() {
+            let mut subparams = param.split(':');
+            // an empty parameter is the same as 0
+            let code = subparams.next().and_then(|code| code.parse().ok());
+            match code.unwrap_or(0_u16) {
+                0 => *style = Style::new(),
+                1 => style.add_modifier.insert(Modifier::BOLD),
+                2 => style.add_modifier.insert(Modifier::DIM),
+                3 => style.add_modifier.insert(Modifier::ITALIC),
+                4 => match subparams.next() {
+                    Some("0") => remove_underline(style),
+                    underline => add_underline(style, underline),
+                },
+                5 => style.add_modifier.insert(Modifier::SLOW_BLINK),
+                6 => style.add_modifier.insert(Modifier::RAPID_BLINK),
+                7 => style.add_modifier.insert(Modifier::REVERSED),
+                8 => style.add_modifier.insert(Modifier::HIDDEN),
+                9 => style.add_modifier.insert(Modifier::CROSSED_OUT),
+                21 => add_underline(style, Some("2")),
+                22 => style.add_modifier.remove(Modifier::BOLD | Modifier::DIM),
+                23 => style.add_modifier.remove(Modifier::ITALIC),
+                24 => remove_underline(style),
+                25 => style
+                    .add_modifier
+                    .remove(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+                27 => style.add_modifier.remove(Modifier::REVERSED),
+                28 => style.add_modifier.remove(Modifier::HIDDEN),
+                29 => style.add_modifier.remove(Modifier::CROSSED_OUT),
+                code @ 30..=37 => style.fg = Some(NAMED_COLORS[usize::from(code - 30)]),
+                38 => style.fg = extended_color(subparams, &mut params).or(style.fg),
+                39 => style.fg = None,
+                code @ 40..=47 => style.bg = Some(NAMED_COLORS[usize::from(code - 40)]),
+                48 => style.bg = extended_color(subparams, &mut params).or(style.bg),
+                49 => style.bg = None,
+                #[cfg(feature = "underline-color")]
+                58 => {
+                    style.underline_color =
+                        extended_color(subparams, &mut params).or(style.underline_color);
+                }
+                #[cfg(feature = "underline-color")]
+                59 => style.underline_color = None,
+                code @ 90..=97 => style.fg = Some(NAMED_COLORS[usize::from(code - 90 + 8)]),
+                code @ 100..=107 => style.bg = Some(NAMED_COLORS[usize::from(code - 100 + 8)]),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Adds the underline modifier, with the style of the `4:x` sub-parameter if there is one.
+fn add_underline(style: &mut Style, subparam: Option<&str>) {
+    style.add_modifier.insert(Modifier::UNDERLINED);
+    #[cfg(feature = "underline-style")]
+    {
+        style.underline_style = match subparam {
+            Some("2") => Some(UnderlineStyle::Double),
+            Some("3") => Some(UnderlineStyle::Curly),
+            Some("4") => Some(UnderlineStyle::Dotted),
+            Some("5") => Some(UnderlineStyle::Dashed),
+            _ => None,
+        };
+    }
+    #[cfg(not(feature = "underline-style"))]
+    let _ = subparam;
+}
+
+fn remove_underline(style: &mut Style) {
+    style.add_modifier.remove(Modifier::UNDERLINED);
+    #[cfg(feature = "underline-style")]
+    {
+        style.underline_style = None;
+    }
+}
+
+/// Parses the indexed (`5;n`) or RGB (`2;r;g;b`) color that follows the codes `38`, `48` and `58`.
+///
+/// The color is either in the sub-parameters of the code (`38:5:n`, `38:2:r:g:b` or
+/// `38:2::r:g:b` with a color space) or in the following parameters (`38;5;n`).
+fn extended_color(subparams: Split<char>, params: &mut Split<char>) -> Option<Color> {
+    let subparams: Vec<&str> = subparams.collect();
+    let values: Vec<&str> = if subparams.is_empty() {
+        match params.next()? {
+            "5" => params.next().into_iter().collect(),
+            "2" => params.take(3).collect(),
+            _ => return None,
+        }
+    } else {
+        let (mode, values) = subparams.split_first()?;
+        match *mode {
+            // skip the color space id if there is one
+            "2" if values.len() > 3 => values[values.len() - 3..].to_vec(),
+            "2" | "5" => values.to_vec(),
+            _ => return None,
+        }
+    };
+    let values: Option<Vec<u8>> = values.iter().map(|value| value.parse().ok()).collect();
+    match *values?.as_slice() {
+        [index] => Some(Color::Indexed(index)),
+        [r, g, b] => Some(Color::Rgb(r, g, b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[rstest]
+    #[case::empty("", Text::from(""))]
+    #[case::plain("hello\nworld", Text::from("hello\nworld"))]
+    #[case::trailing_newline("hello\n", Text::from("hello"))]
+    #[case::crlf("a\r\nb", Text::from("a\nb"))]
+    #[case::tab("a\tb\x07", Text::from("a\tb"))]
+    fn unstyled(#[case] input: &str, #[case] expected: Text) {
+        assert_eq!(Text::from_ansi(input), expected);
+    }
+
+    #[rstest]
+    #[case::reset("\x1b[31mx\x1b[0my", vec!["x".red(), "y".into()])]
+    #[case::empty_reset("\x1b[1mx\x1b[my", vec!["x".bold(), "y".into()])]
+    #[case::combined("\x1b[1;4;32mx", vec!["x".bold().underlined().green()])]
+    #[case::modifiers("\x1b[2;3;5;6;7;8;9mx", vec![
+        "x".dim().italic().slow_blink().rapid_blink().reversed().hidden().crossed_out(),
+    ])]
+    #[case::remove_modifiers("\x1b[1;2;3mx\x1b[22my\x1b[23mz", vec![
+        "x".bold().dim().italic(),
+        "y".italic(),
+        "z".into(),
+    ])]
+    #[case::bright("\x1b[91;104mx", vec!["x".light_red().on_light_blue()])]
+    #[case::background("\x1b[30;47mx\x1b[49my", vec!["x".black().on_gray(), "y".black()])]
+    #[case::indexed("\x1b[38;5;208mx", vec!["x".fg(Color::Indexed(208))])]
+    #[case::rgb("\x1b[48;2;1;2;3mx", vec!["x".bg(Color::Rgb(1, 2, 3))])]
+    #[case::rgb_colon("\x1b[38:2:1:2:3mx", vec!["x".fg(Color::Rgb(1, 2, 3))])]
+    #[case::rgb_color_space("\x1b[38:2::1:2:3mx", vec!["x".fg(Color::Rgb(1, 2, 3))])]
+    #[case::invalid_color("\x1b[38;5mx", vec!["x".into()])]
+    #[case::merge_same_style("\x1b[31mx\x1b[31my", vec!["xy".red()])]
+    #[case::ignored_sequences("\x1b[2K\x1b[1Gx\x1b]8;;https://ratatui.rs\x1b\\y\x1b]0;title\x07z", vec![
+        "xyz".into(),
+    ])]
+    fn styled(#[case] input: &str, #[case] expected: Vec<Span>) {
+        assert_eq!(Text::from_ansi(input), Text::from(Line::from(expected)));
+    }
+
+    #[test]
+    fn style_carries_over_lines() {
+        let text = Text::from_ansi("\x1b[32mhello\nworld\x1b[0m!");
+        let expected = Text::from(vec![
+            Line::from("hello".green()),
+            Line::from(vec!["world".green(), "!".into()]),
+        ]);
+        assert_eq!(text, expected);
+    }
+
+    #[cfg(feature = "underline-style")]
+    #[test]
+    fn underline_style() {
+        let text = Text::from_ansi("\x1b[4:3mx\x1b[4:0my");
+        let style = Style::new()
+            .underlined()
+            .underline_style(UnderlineStyle::Curly);
+        let expected = Line::from(vec![Span::styled("x", style), "y".into()]);
+        assert_eq!(text, Text::from(expected));
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn underline_color() {
+        let text = Text::from_ansi("\x1b[4;58;5;1mx");
+        let style = Style::new().underlined().underline_color(Color::Indexed(1));
+        assert_eq!(text, Text::from(Span::styled("x", style)));
+    }
+}
@@ -12,8 +12,9 @@ use unicode_width::UnicodeWidthStr;
 use crate::buffer::Buffer;
 use crate::layout::{Alignment, Rect};
 use crate::style::{Style, Styled};
-use crate::text::{Span, StyledGrapheme, Text};
-use crate::widgets::Widget;
+use crate::text::truncate::truncate_spans;
+use crate::text::{Span, StyledGrapheme, Text, TruncateStyle};
+use crate::widgets::{IntrinsicSize, Widget};
 
 /// A line of text, consisting of one or more [`Span`]s.
 ///
@@ -64,6 +65,8 @@ use crate::widgets::Widget;
 /// - [`Line::width`] returns the unicode width of the content held by this line.
 /// - [`Line::styled_graphemes`] returns an iterator over the graphemes held by this line.
 /// - [`Line::push_span`] adds a span to the line.
+/// - [`Line::push_span_inheriting`] adds a span that inherits the style of the line.
+/// - [`Line::push_span_isolated`] adds a span that ignores the style of the line.
 ///
 /// # Compatibility Notes
 ///
@@ -114,6 +117,27 @@ use crate::widgets::Widget;
 /// let line = Line::from("Hello world!").yellow().italic();
 /// ```
 ///
+/// ### Style Inheritance
+///
+/// The style of a span is [patched](Style::patch) onto the style of its line when rendered: the
+/// colors set on the span replace those of the line, the colors not set on the span are inherited
+/// from the line, and the modifiers added or removed by the span are applied on top of those of
+/// the line. The line's style is itself patched onto the style of the [`Text`] or widget that
+/// renders it. As a consequence, a span added to a bold red line is bold and red unless it sets
+/// other values. [`Line::push_span_isolated`] adds a span whose unset values are reset instead,
+/// so that it is rendered with its own style only.
+///
+/// ```rust
+/// use ratatui_core::style::{Style, Stylize};
+/// use ratatui_core::text::{Line, Span};
+///
+/// let mut line = Line::from("Error: ").red().bold();
+/// line.push_span_inheriting("inherits red and bold");
+/// line.push_span_isolated(Span::raw("is rendered with the default style"));
+/// assert_eq!(line.spans[1].style, Style::new());
+/// assert_eq!(line.spans[2].style, Style::reset());
+/// ```
+///
 /// ## Aligning Lines
 ///
 /// The line's [`Alignment`] is used by the rendering widget to determine how to align the line
@@ -428,6 +452,8 @@ impl<'a> Line<'a> {
 
     /// Returns the width of the underlying string.
     ///
+    /// The width is computed with the current [`WidthPolicy`](crate::buffer::WidthPolicy).
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -439,7 +465,40 @@ impl<'a> Line<'a> {
     /// ```
     #[must_use]
     pub fn width(&self) -> usize {
-        UnicodeWidthStr::width(self)
+        self.spans.iter().map(Span::width).sum()
+    }
+
+    /// Truncates the line to `max_width` columns, replacing the removed text with an ellipsis
+    /// (`…`).
+    ///
+    /// The [`TruncateStyle`] selects whether the start, the middle or the end of the line is
+    /// removed. The line is cut between grapheme clusters, and the width of wide characters is
+    /// taken into account, so the truncated line is never wider than `max_width` (it can be one
+    /// column narrower when a wide character does not fit). The ellipsis has the style of the span
+    /// in which the line is cut, and the style and alignment of the line are kept. A line that
+    /// fits is returned unchanged.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::{Line, TruncateStyle};
+    ///
+    /// let path = Line::from(vec!["/home/user/".dim(), "main.rs".bold()]);
+    /// let truncated = path.clone().truncate(12, TruncateStyle::Start);
+    /// assert_eq!(truncated.to_string(), "…ser/main.rs");
+    /// let truncated = path.truncate(12, TruncateStyle::Middle);
+    /// assert_eq!(truncated.to_string(), "/home/…in.rs");
+    ///
+    /// let line = Line::from("日本語のテキスト").truncate(6, TruncateStyle::End);
+    /// assert_eq!(line.to_string(), "日本…");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncate(mut self, max_width: usize, truncate_style: TruncateStyle) -> Self {
+        self.spans = truncate_spans(self.spans, max_width, truncate_style);
+        self
     }
 
     /// Returns an iterator over the graphemes held by this line.
@@ -563,6 +622,50 @@ impl<'a> Line<'a> {
     pub fn push_span<T: Into<Span<'a>>>(&mut self, span: T) {
         self.spans.push(span.into());
     }
+
+    /// Adds a span to the line, which inherits the style of the line.
+    ///
+    /// This is the same as [`Line::push_span`], and makes the intent explicit when composing a
+    /// line from spans with different styles: the span is rendered with the style of the line,
+    /// [patched](Style::patch) with the style of the span. See [Style
+    /// Inheritance](Line#style-inheritance).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let mut line = Line::from("Warning: ").yellow();
+    /// line.push_span_inheriting("disk almost full".bold());
+    /// // rendered yellow and bold
+    /// ```
+    pub fn push_span_inheriting<T: Into<Span<'a>>>(&mut self, span: T) {
+        self.push_span(span);
+    }
+
+    /// Adds a span to the line, which ignores the style of the line.
+    ///
+    /// The values that are not set in the style of the span are [reset](Style::reset), so that
+    /// the span is rendered with its own style only, instead of inheriting the colors and
+    /// modifiers of the line (and of the text or widget that renders the line). See [Style
+    /// Inheritance](Line#style-inheritance).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Style, Stylize};
+    /// use ratatui_core::text::Line;
+    ///
+    /// let mut line = Line::from("Error: ").red().bold();
+    /// line.push_span_isolated("only blue".blue());
+    /// assert_eq!(line.spans[1].style, Style::reset().fg(Color::Blue));
+    /// ```
+    pub fn push_span_isolated<T: Into<Span<'a>>>(&mut self, span: T) {
+        let mut span = span.into();
+        span.style = Style::reset().patch(span.style);
+        self.push_span(span);
+    }
 }
 
 impl UnicodeWidthStr for Line<'_> {
@@ -696,6 +799,16 @@ impl<'a> Extend<Span<'a>> for Line<'a> {
     }
 }
 
+impl IntrinsicSize for Line<'_> {
+    fn width_hint(&self) -> Option<u16> {
+        Some(u16::try_from(self.width()).unwrap_or(u16::MAX))
+    }
+
+    fn height_hint(&self, _width: u16) -> Option<u16> {
+        Some(1)
+    }
+}
+
 impl Widget for Line<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -966,6 +1079,61 @@ mod tests {
         assert_eq!(0, empty_line.width());
     }
 
+    #[rstest]
+    #[case::fits("hello world", 11, TruncateStyle::End, "hello world")]
+    #[case::end("hello world", 8, TruncateStyle::End, "hello w…")]
+    #[case::start("hello world", 8, TruncateStyle::Start, "…o world")]
+    #[case::middle("hello world", 8, TruncateStyle::Middle, "hell…rld")]
+    #[case::only_ellipsis("hello world", 1, TruncateStyle::Middle, "…")]
+    #[case::zero_width("hello world", 0, TruncateStyle::End, "")]
+    #[case::cjk_end("日本語", 5, TruncateStyle::End, "日本…")]
+    #[case::cjk_end_wide_char_does_not_fit("日本語", 4, TruncateStyle::End, "日…")]
+    #[case::cjk_start("日本語", 4, TruncateStyle::Start, "…語")]
+    #[case::cjk_middle("日本語", 4, TruncateStyle::Middle, "日…")]
+    #[case::flags("🇺🇸🇬🇧🇫🇷", 5, TruncateStyle::End, "🇺🇸🇬🇧…")]
+    #[case::combining_marks("e\u{301}e\u{301}e\u{301}", 2, TruncateStyle::End, "e\u{301}…")]
+    fn truncate(
+        #[case] content: &str,
+        #[case] max_width: usize,
+        #[case] truncate_style: TruncateStyle,
+        #[case] expected: &str,
+    ) {
+        let line = Line::from(content).truncate(max_width, truncate_style);
+        assert_eq!(line.to_string(), expected);
+        assert!(line.width() <= max_width);
+    }
+
+    #[test]
+    fn truncate_keeps_styles() {
+        let line = Line::from(vec!["abc".red(), "def".blue()])
+            .italic()
+            .centered();
+        assert_eq!(
+            line.clone().truncate(5, TruncateStyle::End),
+            Line::from(vec!["abc".red(), "d".blue(), "…".blue()])
+                .italic()
+                .centered()
+        );
+        assert_eq!(
+            line.clone().truncate(5, TruncateStyle::Start),
+            Line::from(vec!["…".red(), "c".red(), "def".blue()])
+                .italic()
+                .centered()
+        );
+        assert_eq!(
+            line.truncate(4, TruncateStyle::Middle),
+            Line::from(vec!["ab".red(), "…".red(), "f".blue()])
+                .italic()
+                .centered()
+        );
+    }
+
+    #[test]
+    fn truncate_keeps_borrowed_content() {
+        let line = Line::from("hello world").truncate(6, TruncateStyle::End);
+        assert!(matches!(line.spans[0].content, Cow::Borrowed("hello")));
+    }
+
     #[test]
     fn patch_style() {
         let raw_line = Line::styled("foobar", Color::Yellow);
@@ -1248,6 +1416,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn push_span_inheriting_and_isolated() {
+        let mut line = Line::from("A").red().bold();
+        line.push_span_inheriting("B".italic());
+        line.push_span_isolated("C".italic());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        line.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["ABC"]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red().bold());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().italic());
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::reset().italic());
+        assert_eq!(buf, expected);
+    }
+
     mod widget {
         use unicode_segmentation::UnicodeSegmentation;
         use unicode_width::UnicodeWidthStr;
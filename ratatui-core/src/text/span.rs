@@ -1,15 +1,17 @@
 use alloc::borrow::Cow;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
+use alloc::vec;
 use core::fmt;
 
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use crate::buffer::{Buffer, CellWidth};
+use crate::buffer::{Buffer, CellWidth, WidthPolicy};
 use crate::layout::Rect;
 use crate::style::{Style, Styled};
-use crate::text::{Line, StyledGrapheme};
-use crate::widgets::Widget;
+use crate::text::truncate::truncate_spans;
+use crate::text::{Line, StyledGrapheme, TruncateStyle};
+use crate::widgets::{IntrinsicSize, Widget};
 
 /// Represents a part of a line that is contiguous and where all characters share the same style.
 ///
@@ -268,8 +270,43 @@ impl<'a> Span<'a> {
     }
 
     /// Returns the unicode width of the content held by this span.
+    ///
+    /// The width is computed with the current [`WidthPolicy`].
+    ///
+    /// [`WidthPolicy`]: crate::buffer::WidthPolicy
     pub fn width(&self) -> usize {
-        UnicodeWidthStr::width(self)
+        WidthPolicy::current().str_width(&self.content)
+    }
+
+    /// Truncates the span to `max_width` columns, replacing the removed text with an ellipsis
+    /// (`…`).
+    ///
+    /// The [`TruncateStyle`] selects whether the start, the middle or the end of the span is
+    /// removed. The span is cut between grapheme clusters, and the width of wide characters is
+    /// taken into account, so the truncated span is never wider than `max_width`. A span that fits
+    /// is returned unchanged. See [`Line::truncate`] for truncating a line of several spans.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{Span, TruncateStyle};
+    ///
+    /// let span = Span::raw("Hello, world!").truncate(8, TruncateStyle::End);
+    /// assert_eq!(span.content, "Hello, …");
+    /// let span = Span::raw("👋 Hello").truncate(4, TruncateStyle::Start);
+    /// assert_eq!(span.content, "…llo");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncate(self, max_width: usize, truncate_style: TruncateStyle) -> Self {
+        let style = self.style;
+        let mut spans = truncate_spans(vec![self], max_width, truncate_style);
+        if spans.len() == 1 {
+            return spans.remove(0);
+        }
+        let content: String = spans.iter().map(|span| span.content.as_ref()).collect();
+        Self::styled(content, style)
     }
 
     /// Returns an iterator over the graphemes held by this span.
@@ -415,6 +452,16 @@ impl Styled for Span<'_> {
     }
 }
 
+impl IntrinsicSize for Span<'_> {
+    fn width_hint(&self) -> Option<u16> {
+        Some(u16::try_from(self.width()).unwrap_or(u16::MAX))
+    }
+
+    fn height_hint(&self, _width: u16) -> Option<u16> {
+        Some(1)
+    }
+}
+
 impl Widget for Span<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -631,6 +678,21 @@ mod tests {
         assert_eq!(Span::raw("test\ncontent").width(), 12);
     }
 
+    #[test]
+    fn truncate() {
+        let span = "hello world".green();
+        assert_eq!(span.clone().truncate(20, TruncateStyle::End), span);
+        assert_eq!(
+            span.clone().truncate(8, TruncateStyle::Middle),
+            "hell…rld".green()
+        );
+        assert_eq!(span.truncate(1, TruncateStyle::Start), "…".green());
+        assert_eq!(
+            Span::raw("日本語").truncate(3, TruncateStyle::Start),
+            Span::raw("…語")
+        );
+    }
+
     #[test]
     fn stylize() {
         let span = Span::raw("test content").green();
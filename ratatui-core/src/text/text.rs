@@ -11,7 +11,7 @@ use crate::buffer::Buffer;
 use crate::layout::{Alignment, Rect};
 use crate::style::{Style, Styled};
 use crate::text::{Line, Span};
-use crate::widgets::Widget;
+use crate::widgets::{IntrinsicSize, Widget};
 
 /// A string split over one or more lines.
 ///
@@ -61,6 +61,8 @@ use crate::widgets::Widget;
 /// - [`Text::reset_style`] resets the style of the `Text`.
 /// - [`Text::push_line`] adds a line to the text.
 /// - [`Text::push_span`] adds a span to the last line of the text.
+/// - [`Text::push_line_isolated`] adds a line that ignores the style of the text.
+/// - [`Text::extend_with_style`] adds lines that inherit the given style.
 ///
 /// # Examples
 ///
@@ -126,6 +128,30 @@ use crate::widgets::Widget;
 /// .italic();
 /// ```
 ///
+/// ### Style Inheritance
+///
+/// The style of a line is [patched](Style::patch) onto the style of the text when rendered, and
+/// the style of each span onto the style of its line (see [`Line`]). Values set on the line
+/// replace those of the text, and values not set are inherited. The style belongs to the `Text`
+/// and not to its lines, so lines moved from one text to another (e.g. with [`Extend`]) lose the
+/// style of the text they came from and inherit the style of the text they are moved to.
+/// [`Text::extend_with_style`] keeps a style on the lines being added, and
+/// [`Text::push_line_isolated`] adds a line that doesn't inherit the style of the text.
+///
+/// ```rust
+/// use ratatui_core::style::{Style, Stylize};
+/// use ratatui_core::text::Text;
+///
+/// let mut text = Text::from("Log").bold();
+/// let errors = Text::from("error 1\nerror 2").red();
+///
+/// // the lines keep the red style of `errors`, and inherit bold from `text`
+/// text.extend_with_style(errors.lines, errors.style);
+/// assert_eq!(text.lines[1].style, Style::new().red());
+/// ```
+///
+/// [`Extend`]: core::iter::Extend
+///
 /// ## Aligning Text
 /// The text's [`Alignment`] can be set using [`Text::alignment`] or the related helper methods.
 /// Lines composing the text can also be individually aligned with [`Line::alignment`].
@@ -560,6 +586,73 @@ impl<'a> Text<'a> {
             self.lines.push(Line::from(span));
         }
     }
+
+    /// Adds a line to the text, which ignores the style of the text.
+    ///
+    /// The values that are not set in the style of the line are [reset](Style::reset), so that
+    /// the line is rendered with its own style only, instead of inheriting the colors and
+    /// modifiers of the text (and of the widget that renders it). See [Style
+    /// Inheritance](Text#style-inheritance).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Style, Stylize};
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let mut text = Text::from("Title").bold();
+    /// text.push_line_isolated(Line::from("not bold").green());
+    /// assert_eq!(text.lines[1].style, Style::reset().fg(Color::Green));
+    /// ```
+    pub fn push_line_isolated<T: Into<Line<'a>>>(&mut self, line: T) {
+        let mut line = line.into();
+        line.style = Style::reset().patch(line.style);
+        self.push_line(line);
+    }
+
+    /// Adds lines to the text, which inherit the given style.
+    ///
+    /// Each line is rendered with the style of the text, [patched](Style::patch) with `style`, and
+    /// then with the style of the line. This keeps the style of the lines of another [`Text`]
+    /// when they are added to this one, which [`Extend`] doesn't since the style belongs to the
+    /// `Text` and not to its lines. See [Style Inheritance](Text#style-inheritance).
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Modifier, Style, Stylize};
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let mut text = Text::from("Summary");
+    /// text.extend_with_style(["first", "second"], Color::Yellow);
+    /// text.extend_with_style([Line::from("third").italic()], Color::Yellow);
+    /// assert_eq!(text.lines[1].style, Style::new().fg(Color::Yellow));
+    /// assert_eq!(
+    ///     text.lines[3].style,
+    ///     Style::new()
+    ///         .fg(Color::Yellow)
+    ///         .add_modifier(Modifier::ITALIC)
+    /// );
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    /// [`Extend`]: core::iter::Extend
+    pub fn extend_with_style<I, S>(&mut self, lines: I, style: S)
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+        S: Into<Style>,
+    {
+        let style = style.into();
+        self.lines.extend(lines.into_iter().map(|line| {
+            let mut line = line.into();
+            line.style = style.patch(line.style);
+            line
+        }));
+    }
 }
 
 impl UnicodeWidthStr for Text<'_> {
@@ -759,6 +852,16 @@ impl fmt::Display for Text<'_> {
     }
 }
 
+impl IntrinsicSize for Text<'_> {
+    fn width_hint(&self) -> Option<u16> {
+        Some(u16::try_from(self.width()).unwrap_or(u16::MAX))
+    }
+
+    fn height_hint(&self, _width: u16) -> Option<u16> {
+        Some(u16::try_from(self.height()).unwrap_or(u16::MAX))
+    }
+}
+
 impl Widget for Text<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -1232,6 +1335,28 @@ mod tests {
         assert_eq!(text.lines, [Line::from(Span::raw("Hello, world!"))]);
     }
 
+    #[test]
+    fn push_line_isolated() {
+        let mut text = Text::from("A").bold();
+        text.push_line_isolated(Line::from("B").green());
+        assert_eq!(text.lines[1].style, Style::reset().green());
+    }
+
+    #[test]
+    fn extend_with_style() {
+        let mut text = Text::from("A").bold();
+        let other = Text::from(vec![Line::from("B"), Line::from("C").italic()]).red();
+        text.extend_with_style(other.lines, other.style);
+        assert_eq!(
+            text.lines,
+            [
+                Line::from("A"),
+                Line::from("B").red(),
+                Line::from("C").red().italic(),
+            ]
+        );
+    }
+
     mod widget {
         use super::*;
 
@@ -0,0 +1,132 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use strum::{Display, EnumString};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::buffer::CellWidth;
+use crate::style::Style;
+use crate::text::Span;
+
+/// The ellipsis that replaces the truncated part of a line.
+const ELLIPSIS: &str = "…";
+
+/// Which part of a [`Line`] or [`Span`] is replaced with an ellipsis when it is truncated.
+///
+/// Truncation never splits a grapheme cluster, and counts the width of wide characters such as CJK
+/// characters and emoji, so the truncated text never takes more columns than requested.
+///
+/// See [`Line::truncate`] and [`Span::truncate`].
+///
+/// [`Line`]: crate::text::Line
+/// [`Line::truncate`]: crate::text::Line::truncate
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TruncateStyle {
+    /// Keeps the end of the text, e.g. `…/src/main.rs`
+    Start,
+    /// Keeps the start and the end of the text, e.g. `/home/…/main.rs`
+    Middle,
+    /// Keeps the start of the text, e.g. `/home/user/…`
+    #[default]
+    End,
+}
+
+/// Truncates the spans to `max_width` columns, replacing the removed text with an ellipsis.
+///
+/// The ellipsis has the style of the span in which the text is cut. Spans that fit are returned
+/// unchanged.
+pub(crate) fn truncate_spans(
+    spans: Vec<Span<'_>>,
+    max_width: usize,
+    truncate_style: TruncateStyle,
+) -> Vec<Span<'_>> {
+    let width: usize = spans.iter().map(Span::width).sum();
+    if width <= max_width {
+        return spans;
+    }
+    let Some(budget) = max_width.checked_sub(usize::from(ELLIPSIS.cell_width())) else {
+        return Vec::new();
+    };
+    match truncate_style {
+        TruncateStyle::Start => {
+            let (mut end, _, cut_style) = take_end(&spans, budget);
+            end.insert(0, Span::styled(ELLIPSIS, cut_style));
+            end
+        }
+        TruncateStyle::Middle => {
+            let (mut start, start_width, cut_style) = take_start(&spans, budget.div_ceil(2));
+            let (end, _, _) = take_end(&spans, budget.saturating_sub(start_width));
+            start.push(Span::styled(ELLIPSIS, cut_style));
+            start.extend(end);
+            start
+        }
+        TruncateStyle::End => {
+            let (mut start, _, cut_style) = take_start(&spans, budget);
+            start.push(Span::styled(ELLIPSIS, cut_style));
+            start
+        }
+    }
+}
+
+/// Returns the spans that fit in `max_width` columns from the start of the spans, their width,
+/// and the style of the span in which they were cut.
+fn take_start<'a>(spans: &[Span<'a>], max_width: usize) -> (Vec<Span<'a>>, usize, Style) {
+    let mut taken = Vec::new();
+    let mut width = 0;
+    for span in spans {
+        let mut end = 0;
+        for (index, grapheme) in span.content.grapheme_indices(true) {
+            let grapheme_width = usize::from(grapheme.cell_width());
+            if width + grapheme_width > max_width {
+                push_slice(&mut taken, span, 0..end);
+                return (taken, width, span.style);
+            }
+            width += grapheme_width;
+            end = index + grapheme.len();
+        }
+        taken.push(span.clone());
+    }
+    let style = spans.last().map_or_else(Style::new, |span| span.style);
+    (taken, width, style)
+}
+
+/// Returns the spans that fit in `max_width` columns from the end of the spans, their width, and
+/// the style of the span in which they were cut.
+fn take_end<'a>(spans: &[Span<'a>], max_width: usize) -> (Vec<Span<'a>>, usize, Style) {
+    let mut taken = Vec::new();
+    let mut width = 0;
+    for span in spans.iter().rev() {
+        let mut start = span.content.len();
+        for (index, grapheme) in span.content.grapheme_indices(true).rev() {
+            let grapheme_width = usize::from(grapheme.cell_width());
+            if width + grapheme_width > max_width {
+                push_slice(&mut taken, span, start..span.content.len());
+                taken.reverse();
+                return (taken, width, span.style);
+            }
+            width += grapheme_width;
+            start = index;
+        }
+        taken.push(span.clone());
+    }
+    taken.reverse();
+    let style = spans.first().map_or_else(Style::new, |span| span.style);
+    (taken, width, style)
+}
+
+/// Pushes the part of the span in the byte `range`, if it is not empty, keeping borrowed content
+/// borrowed.
+fn push_slice<'a>(spans: &mut Vec<Span<'a>>, span: &Span<'a>, range: core::ops::Range<usize>) {
+    if range.is_empty() {
+        return;
+    }
+    // the range is always on grapheme boundaries, so the slice always exists
+    let content = match &span.content {
+        Cow::Borrowed(content) => content.get(range).map(Cow::Borrowed),
+        Cow::Owned(content) => content.get(range).map(|content| Cow::Owned(content.into())),
+    };
+    if let Some(content) = content {
+        spans.push(Span::styled(content, span.style));
+    }
+}
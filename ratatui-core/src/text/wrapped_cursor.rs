@@ -0,0 +1,212 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::layout::Position;
+
+/// Maps a logical cursor index in a string to its on-screen position after wrapping, and back.
+///
+/// Text input widgets store the cursor as an index into the edited value, but render the value
+/// wrapped to the width of their area. `WrappedCursor` performs the conversion between the two so
+/// that the hardware cursor (see `Frame::set_cursor_position`) lines up with the rendered text.
+///
+/// The cursor index counts grapheme clusters rather than bytes or chars, so that a cursor never
+/// ends up in the middle of a combined emoji or an accented character. Wrapping happens at
+/// grapheme boundaries (character wrapping, not word wrapping): a grapheme that does not fit in
+/// the remaining columns of a row is moved to the start of the next row, and wide graphemes occupy
+/// two columns. A `'\n'` (or `"\r\n"`) starts a new row.
+///
+/// A cursor placed directly after a grapheme that fills the last column of a row is reported at
+/// the start of the next row, which is where the next typed character would appear. If the row
+/// is followed by a newline, the next row belongs to the line after the newline, so the cursor is
+/// reported past the last column of the row instead (with `x` equal to the width).
+///
+/// Positions are relative to the top-left corner of the area the text is rendered in, with `x`
+/// being the column and `y` being the row.
+///
+/// A width of `0` disables wrapping.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::layout::Position;
+/// use ratatui_core::text::WrappedCursor;
+///
+/// let cursor = WrappedCursor::new(5);
+/// let value = "hello world";
+///
+/// assert_eq!(cursor.position(value, 7), Position::new(2, 1));
+/// assert_eq!(cursor.index(value, Position::new(2, 1)), 7);
+/// assert_eq!(cursor.rows(value), 3);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WrappedCursor {
+    width: u16,
+}
+
+impl WrappedCursor {
+    /// Creates a new `WrappedCursor` that wraps text at the given width.
+    pub const fn new(width: u16) -> Self {
+        Self { width }
+    }
+
+    /// The width at which text is wrapped.
+    pub const fn width(self) -> u16 {
+        self.width
+    }
+
+    /// Returns the on-screen position of the cursor at the given grapheme index.
+    ///
+    /// Indexes past the end of the text are clamped to the position after the last grapheme.
+    pub fn position(self, text: &str, index: usize) -> Position {
+        let mut last = Position::ORIGIN;
+        for (i, position) in self.positions(text).enumerate() {
+            if i == index {
+                return position;
+            }
+            last = position;
+        }
+        last
+    }
+
+    /// Returns the grapheme index of the cursor at the given on-screen position.
+    ///
+    /// This is the inverse of [`WrappedCursor::position`], useful for placing the cursor where the
+    /// user clicked. Positions past the end of a row resolve to the end of that row, and positions
+    /// below the last row resolve to the end of the text.
+    pub fn index(self, text: &str, position: Position) -> usize {
+        let mut result = None;
+        for (i, candidate) in self.positions(text).enumerate() {
+            if candidate.y > position.y {
+                break;
+            }
+            if candidate.y < position.y || candidate.x <= position.x {
+                result = Some(i);
+            }
+        }
+        result.unwrap_or_default()
+    }
+
+    /// Returns the number of rows the text occupies when wrapped, including the row that holds
+    /// the cursor at the end of the text.
+    pub fn rows(self, text: &str) -> u16 {
+        self.positions(text)
+            .last()
+            .map_or(1, |position| position.y.saturating_add(1))
+    }
+
+    /// Returns the position of each cursor index in the text, followed by the position at the end
+    /// of the text.
+    fn positions(self, text: &str) -> impl Iterator<Item = Position> + '_ {
+        let width = if self.width == 0 {
+            u16::MAX
+        } else {
+            self.width
+        };
+        let mut graphemes = text.graphemes(true);
+        let mut current = Position::ORIGIN;
+        let mut done = false;
+        core::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let Some(grapheme) = graphemes.next() else {
+                done = true;
+                return Some(wrap(current, width));
+            };
+            if grapheme == "\n" || grapheme == "\r\n" {
+                let position = current;
+                current = Position::new(0, current.y.saturating_add(1));
+                return Some(position);
+            }
+            let grapheme_width = u16::try_from(grapheme.width()).unwrap_or(u16::MAX);
+            if current.x > 0 && current.x.saturating_add(grapheme_width) > width {
+                current = Position::new(0, current.y.saturating_add(1));
+            }
+            let position = current;
+            current.x = current.x.saturating_add(grapheme_width);
+            Some(position)
+        })
+    }
+}
+
+/// Moves a cursor that sits past the last column to the start of the next row.
+const fn wrap(position: Position, width: u16) -> Position {
+    if position.x >= width {
+        Position::new(0, position.y.saturating_add(1))
+    } else {
+        position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::start("hello", 0, Position::new(0, 0))]
+    #[case::middle("hello", 2, Position::new(2, 0))]
+    #[case::end_of_full_row("hello", 5, Position::new(0, 1))]
+    #[case::second_row("hello world", 7, Position::new(2, 1))]
+    #[case::past_end("hi", 10, Position::new(2, 0))]
+    #[case::newline("ab\ncd", 2, Position::new(2, 0))]
+    #[case::after_newline("ab\ncd", 3, Position::new(0, 1))]
+    #[case::crlf("ab\r\ncd", 3, Position::new(0, 1))]
+    #[case::full_row_then_newline("hello\nab", 5, Position::new(5, 0))]
+    #[case::after_full_row_and_newline("hello\nab", 6, Position::new(0, 1))]
+    #[case::wide_wraps_early("abcd界", 4, Position::new(0, 1))]
+    #[case::after_wide("界界a", 2, Position::new(4, 0))]
+    #[case::combining("e\u{301}x", 1, Position::new(1, 0))]
+    fn position(#[case] text: &str, #[case] index: usize, #[case] expected: Position) {
+        assert_eq!(WrappedCursor::new(5).position(text, index), expected);
+    }
+
+    #[rstest]
+    #[case::start(Position::new(0, 0), 0)]
+    #[case::second_row(Position::new(2, 1), 7)]
+    #[case::past_row_end(Position::new(4, 2), 11)]
+    #[case::below_text(Position::new(0, 9), 11)]
+    fn index(#[case] position: Position, #[case] expected: usize) {
+        assert_eq!(
+            WrappedCursor::new(5).index("hello world", position),
+            expected
+        );
+    }
+
+    #[test]
+    fn index_inside_wide_grapheme() {
+        let cursor = WrappedCursor::new(10);
+        assert_eq!(cursor.index("a界b", Position::new(2, 0)), 1);
+        assert_eq!(cursor.index("a界b", Position::new(3, 0)), 2);
+    }
+
+    #[rstest]
+    #[case::wrapped(4, "the quick\nbrown 狐 jumps")]
+    #[case::full_row_then_newline(5, "hello\nab")]
+    fn index_round_trips_position(#[case] width: u16, #[case] text: &str) {
+        let cursor = WrappedCursor::new(width);
+        let count = text.graphemes(true).count();
+        for index in 0..=count {
+            let position = cursor.position(text, index);
+            assert_eq!(cursor.index(text, position), index, "index {index}");
+        }
+    }
+
+    #[rstest]
+    #[case::empty("", 1)]
+    #[case::short("hi", 1)]
+    #[case::exact("hello", 2)]
+    #[case::wrapped("hello world", 3)]
+    #[case::newlines("a\nb\nc", 3)]
+    fn rows(#[case] text: &str, #[case] expected: u16) {
+        assert_eq!(WrappedCursor::new(5).rows(text), expected);
+    }
+
+    #[test]
+    fn zero_width_disables_wrapping() {
+        let cursor = WrappedCursor::new(0);
+        assert_eq!(cursor.position("hello world", 11), Position::new(11, 0));
+        assert_eq!(cursor.rows("hello world"), 1);
+    }
+}
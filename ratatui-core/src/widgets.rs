@@ -1,9 +1,16 @@
 #![warn(missing_docs)]
 //! The `widgets` module contains the `Widget` and `StatefulWidget` traits, which are used to
-//! render UI elements on the screen.
+//! render UI elements on the screen, and the `WidgetRef` and `StatefulWidgetRef` traits, which are
+//! used to render them by reference.
 
+pub use self::intrinsic_size::IntrinsicSize;
 pub use self::stateful_widget::StatefulWidget;
+pub use self::stateful_widget_ref::StatefulWidgetRef;
 pub use self::widget::Widget;
+pub use self::widget_ref::WidgetRef;
 
+mod intrinsic_size;
 mod stateful_widget;
+mod stateful_widget_ref;
 mod widget;
+mod widget_ref;
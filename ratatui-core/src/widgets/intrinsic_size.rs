@@ -0,0 +1,62 @@
+/// A widget that can report the size it needs to render its content.
+///
+/// Implementing this trait allows a layout to size an area to fit a widget with
+/// [`Constraint::Content`], which is usually created with [`Constraint::content_width`] or
+/// [`Constraint::content_height`].
+///
+/// Both methods return `None` by default, which means the widget doesn't have a preferred size in
+/// that direction and should be given whatever space is available.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint, Layout, Rect};
+/// use ratatui_core::widgets::IntrinsicSize;
+///
+/// struct Label(&'static str);
+///
+/// impl IntrinsicSize for Label {
+///     fn width_hint(&self) -> Option<u16> {
+///         u16::try_from(self.0.len()).ok()
+///     }
+///
+///     fn height_hint(&self, _width: u16) -> Option<u16> {
+///         Some(1)
+///     }
+/// }
+///
+/// let label = Label("Name:");
+/// let [label_area, input_area] =
+///     Layout::horizontal([Constraint::content_width(&label), Constraint::Fill(1)])
+///         .areas(Rect::new(0, 0, 20, 1));
+/// assert_eq!(label_area.width, 5);
+/// assert_eq!(input_area.width, 15);
+/// ```
+///
+/// [`Constraint::Content`]: crate::layout::Constraint::Content
+/// [`Constraint::content_width`]: crate::layout::Constraint::content_width
+/// [`Constraint::content_height`]: crate::layout::Constraint::content_height
+pub trait IntrinsicSize {
+    /// Returns the width needed to render the content without truncating or wrapping it, or `None`
+    /// if the widget has no preferred width.
+    fn width_hint(&self) -> Option<u16> {
+        None
+    }
+
+    /// Returns the height needed to render the content in an area of the given width, or `None`
+    /// if the widget has no preferred height.
+    fn height_hint(&self, width: u16) -> Option<u16> {
+        let _ = width;
+        None
+    }
+}
+
+impl<W: IntrinsicSize + ?Sized> IntrinsicSize for &W {
+    fn width_hint(&self) -> Option<u16> {
+        (**self).width_hint()
+    }
+
+    fn height_hint(&self, width: u16) -> Option<u16> {
+        (**self).height_hint(width)
+    }
+}
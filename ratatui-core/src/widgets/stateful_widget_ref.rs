@@ -1,16 +1,13 @@
-use ratatui_core::widgets::StatefulWidget;
-
 use crate::buffer::Buffer;
 use crate::layout::Rect;
+use crate::widgets::StatefulWidget;
 
 /// A `StatefulWidgetRef` is a trait that allows rendering a stateful widget by reference.
 ///
 /// This is the stateful equivalent of `WidgetRef`. It is useful when you need to store a reference
 /// to a stateful widget and render it later. It also allows you to render boxed stateful widgets.
 ///
-/// This trait was introduced in Ratatui 0.26.0. It is currently marked as unstable as we are still
-/// evaluating the API and may make changes in the future. See
-/// <https://github.com/ratatui/ratatui/issues/1287> for more information.
+/// This trait was introduced in Ratatui 0.26.0.
 ///
 /// A blanket implementation of `StatefulWidgetRef` for `&W` where `W` implements `StatefulWidget`
 /// is provided. Most of the time you will want to implement `StatefulWidget` against a reference to
@@ -22,18 +19,16 @@ use crate::layout::Rect;
 /// For comprehensive information about widget implementation patterns, rendering, and usage,
 /// see the [`widgets`] module documentation.
 ///
-/// [`widgets`]: crate::widgets
+/// [`widgets`]: ../../ratatui/widgets/index.html
 ///
 /// # Examples
 ///
 /// ```rust
-/// # #[cfg(feature = "unstable-widget-ref")] {
-/// use ratatui::widgets::StatefulWidgetRef;
 /// use ratatui_core::buffer::Buffer;
 /// use ratatui_core::layout::Rect;
 /// use ratatui_core::style::Stylize;
 /// use ratatui_core::text::Line;
-/// use ratatui_core::widgets::{StatefulWidget, Widget};
+/// use ratatui_core::widgets::{StatefulWidget, StatefulWidgetRef, Widget};
 ///
 /// struct PersonalGreeting;
 ///
@@ -56,9 +51,7 @@ use crate::layout::Rect;
 ///     let mut state = "world".to_string();
 ///     widget.render(area, buf, &mut state);
 /// }
-/// # }
 /// ```
-#[instability::unstable(feature = "widget-ref")]
 pub trait StatefulWidgetRef {
     /// State associated with the stateful widget.
     ///
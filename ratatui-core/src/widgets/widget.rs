@@ -19,11 +19,10 @@ use crate::style::Style;
 /// themselves. This allows you to store a reference to a widget and render it later. Widget crates
 /// should consider also doing this to allow for more flexibility in how widgets are used.
 ///
-/// In Ratatui 0.26.0, we also added an unstable `WidgetRef` trait and implemented this on all the
+/// In Ratatui 0.26.0, we also added the [`WidgetRef`] trait and implemented this on all the
 /// internal widgets. In addition to the above benefit of rendering references to widgets, this also
 /// allows you to render boxed widgets. This is useful when you want to store a collection of
 /// widgets with different types. You can then iterate over the collection and render each widget.
-/// See <https://github.com/ratatui/ratatui/issues/1287> for more information.
 ///
 /// In general where you expect a widget to immutably work on its data, we recommended to implement
 /// `Widget` for a reference to the widget (`impl Widget for &MyWidget`). If you need to store state
@@ -32,7 +31,7 @@ use crate::style::Style;
 /// you want the widget to be mutable. The mutable widget pattern is used infrequently in apps, but
 /// can be quite useful.
 ///
-/// A blanket implementation of `Widget` for `&W` where `W` implements `WidgetRef` is provided.
+/// A blanket implementation of [`WidgetRef`] for `&W` where `&W` implements `Widget` is provided.
 /// Widget is also implemented for `&str` and `String` types.
 ///
 /// # Examples
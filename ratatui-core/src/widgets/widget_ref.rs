@@ -14,11 +14,10 @@ use crate::style::Style;
 /// useful when you want to store a collection of widgets with different types. You can then iterate
 /// over the collection and render each widget.
 ///
-/// This trait was introduced in Ratatui 0.26.0 and is implemented for all the internal widgets. It
-/// is currently marked as unstable as we are still evaluating the API and may make changes in the
-/// future. See <https://github.com/ratatui/ratatui/issues/1287> for more information.
+/// This trait was introduced in Ratatui 0.26.0 and is implemented for all the internal widgets.
 ///
-/// A blanket implementation of `Widget` for `&W` where `W` implements `WidgetRef` is provided.
+/// A blanket implementation of `WidgetRef` for `&W` where `&W` implements [`Widget`] is provided,
+/// so implementing `Widget` for a reference to a widget is enough to render it by reference.
 ///
 /// A blanket implementation of `WidgetRef` for `Option<W>` where `W` implements `WidgetRef` is
 /// provided. This is a convenience approach to make it easier to attach child widgets to parent
@@ -27,17 +26,15 @@ use crate::style::Style;
 /// For comprehensive information about widget implementation patterns, rendering, and usage,
 /// see the [`widgets`] module documentation.
 ///
-/// [`widgets`]: crate::widgets
+/// [`widgets`]: ../../ratatui/widgets/index.html
 ///
 /// # Examples
 ///
 /// ```rust
-/// # #[cfg(feature = "unstable-widget-ref")] {
-/// use ratatui::widgets::WidgetRef;
 /// use ratatui_core::buffer::Buffer;
 /// use ratatui_core::layout::Rect;
 /// use ratatui_core::text::Line;
-/// use ratatui_core::widgets::Widget;
+/// use ratatui_core::widgets::{Widget, WidgetRef};
 ///
 /// struct Greeting;
 ///
@@ -83,9 +80,7 @@ use crate::style::Style;
 ///     widget.render_ref(area, buf);
 /// }
 /// # }
-/// # }
 /// ```
-#[instability::unstable(feature = "widget-ref")]
 pub trait WidgetRef {
     /// Draws the current state of the widget in the given buffer. That is the only method required
     /// to implement a custom widget.
@@ -125,24 +120,21 @@ impl WidgetRef for String {
     }
 }
 
-/// A blanket implementation of `WidgetExt` for `Option<W>` where `W` implements `WidgetRef`.
+/// A blanket implementation of `WidgetRef` for `Option<W>` where `W` implements `WidgetRef`.
 ///
 /// This is a convenience implementation that makes it easy to attach child widgets to parent
 /// widgets. It allows you to render an optional widget by reference.
 ///
 /// The internal widgets use this pattern to render the optional `Block` widgets that are included
 /// on most widgets.
-/// Blanket implementation of `WidgetExt` for `Option<W>` where `W` implements `WidgetRef`.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # #[cfg(feature = "unstable-widget-ref")] {
-/// use ratatui::widgets::WidgetRef;
 /// use ratatui_core::buffer::Buffer;
 /// use ratatui_core::layout::Rect;
 /// use ratatui_core::text::Line;
-/// use ratatui_core::widgets::Widget;
+/// use ratatui_core::widgets::{Widget, WidgetRef};
 ///
 /// struct Parent {
 ///     child: Option<Child>,
@@ -161,7 +153,6 @@ impl WidgetRef for String {
 ///         self.child.render_ref(area, buf);
 ///     }
 /// }
-/// # }
 /// ```
 impl<W: WidgetRef> WidgetRef for Option<W> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
@@ -73,16 +73,17 @@
 
 use std::io::{self, Write};
 
-use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::cursor::{Hide, MoveDown, MoveRight, MoveTo, MoveToColumn, MoveUp, Show};
 #[cfg(feature = "underline-color")]
 use crossterm::style::SetUnderlineColor;
 use crossterm::style::{
     Attribute as CrosstermAttribute, Attributes as CrosstermAttributes, Color as CrosstermColor,
-    Colors as CrosstermColors, ContentStyle, Print, SetAttribute, SetBackgroundColor, SetColors,
-    SetForegroundColor,
+    Colors as CrosstermColors, ContentStyle, Print, SetAttribute, SetColors,
 };
 use crossterm::terminal::{self, Clear};
 use crossterm::{execute, queue};
+#[cfg(feature = "underline-style")]
+use ratatui_core::style::UnderlineStyle;
 cfg_if::cfg_if! {
     // Re-export the selected Crossterm crate making sure to choose the latest version. We do this
     // to make it possible to easily enable all features when compiling `ratatui-crossterm`.
@@ -97,9 +98,10 @@ cfg_if::cfg_if! {
     }
 }
 use ratatui_core::backend::{Backend, ClearType, WindowSize};
-use ratatui_core::buffer::Cell;
+use ratatui_core::buffer::{Cell, CellWidth, LineAttribute};
+use ratatui_core::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui_core::layout::{Position, Size};
-use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::style::{Color, ColorSupport, Modifier, Style};
 
 /// A [`Backend`] implementation that uses [Crossterm] to render to the terminal.
 ///
@@ -159,7 +161,7 @@ use ratatui_core::style::{Color, Modifier, Style};
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct CrosstermBackend<W: Write> {
     /// The writer used to send commands to the terminal.
-    writer: W,
+    writer: CountingWriter<W>,
 }
 
 impl<W> CrosstermBackend<W>
@@ -183,7 +185,12 @@ where
     /// let backend = CrosstermBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer: CountingWriter {
+                inner: writer,
+                bytes_written: 0,
+            },
+        }
     }
 
     /// Gets the writer.
@@ -192,19 +199,20 @@ where
         issue = "https://github.com/ratatui/ratatui/pull/991"
     )]
     pub const fn writer(&self) -> &W {
-        &self.writer
+        &self.writer.inner
     }
 
     /// Gets the writer as a mutable reference.
     ///
     /// Note: writing to the writer may cause incorrect output after the write. This is due to the
-    /// way that the Terminal implements diffing Buffers.
+    /// way that the Terminal implements diffing Buffers. The bytes written to the writer directly
+    /// are not counted by [`Backend::bytes_written`].
     #[instability::unstable(
         feature = "backend-writer",
         issue = "https://github.com/ratatui/ratatui/pull/991"
     )]
     pub const fn writer_mut(&mut self) -> &mut W {
-        &mut self.writer
+        &mut self.writer.inner
     }
 }
 
@@ -223,6 +231,25 @@ where
     }
 }
 
+/// A writer that counts the bytes written to the inner writer, for [`Backend::bytes_written`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+struct CountingWriter<W> {
+    inner: W,
+    bytes_written: u64,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.bytes_written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 impl<W> Backend for CrosstermBackend<W>
 where
     W: Write,
@@ -237,22 +264,42 @@ where
         let mut bg = Color::Reset;
         #[cfg(feature = "underline-color")]
         let mut underline_color = Color::Reset;
+        #[cfg(feature = "underline-style")]
+        let mut underline_style = UnderlineStyle::Single;
         let mut modifier = Modifier::empty();
         let mut last_pos: Option<Position> = None;
+        // The position of the cursor after the last cell, when it is known. It is only known after
+        // one column wide cells, as terminals disagree on the width of some wide characters.
+        let mut cursor: Option<Position> = None;
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
             if !matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y) {
-                queue!(self.writer, MoveTo(x, y))?;
+                queue_move_to(&mut self.writer, cursor, Position { x, y })?;
             }
             last_pos = Some(Position { x, y });
+            cursor = (cell.cell_width() == 1).then(|| Position {
+                x: x.saturating_add(1),
+                y,
+            });
             if cell.modifier != modifier {
                 let diff = ModifierDiff {
                     from: modifier,
                     to: cell.modifier,
                 };
                 diff.queue(&mut self.writer)?;
+                // (re)adding the underline modifier draws a single underline
+                #[cfg(feature = "underline-style")]
+                if !modifier.contains(Modifier::UNDERLINED) {
+                    underline_style = UnderlineStyle::Single;
+                }
                 modifier = cell.modifier;
             }
+            #[cfg(feature = "underline-style")]
+            if modifier.contains(Modifier::UNDERLINED) && cell.underline_style != underline_style {
+                let attribute = cell.underline_style.into_crossterm();
+                queue!(self.writer, SetAttribute(attribute))?;
+                underline_style = cell.underline_style;
+            }
             if cell.fg != fg || cell.bg != bg {
                 queue!(
                     self.writer,
@@ -274,21 +321,15 @@ where
             queue!(self.writer, Print(cell.symbol()))?;
         }
 
+        // A single reset restores the colors and attributes, and is only needed when they changed
         #[cfg(feature = "underline-color")]
-        return queue!(
-            self.writer,
-            SetForegroundColor(CrosstermColor::Reset),
-            SetBackgroundColor(CrosstermColor::Reset),
-            SetUnderlineColor(CrosstermColor::Reset),
-            SetAttribute(CrosstermAttribute::Reset),
-        );
+        let is_reset = underline_color == Color::Reset;
         #[cfg(not(feature = "underline-color"))]
-        return queue!(
-            self.writer,
-            SetForegroundColor(CrosstermColor::Reset),
-            SetBackgroundColor(CrosstermColor::Reset),
-            SetAttribute(CrosstermAttribute::Reset),
-        );
+        let is_reset = true;
+        if !(is_reset && fg == Color::Reset && bg == Color::Reset && modifier.is_empty()) {
+            queue!(self.writer, SetAttribute(CrosstermAttribute::Reset))?;
+        }
+        Ok(())
     }
 
     fn hide_cursor(&mut self) -> io::Result<()> {
@@ -334,6 +375,30 @@ where
         self.writer.flush()
     }
 
+    fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) -> io::Result<()> {
+        queue!(
+            self.writer,
+            MoveTo(0, y),
+            Print(attribute.escape_sequence())
+        )
+    }
+
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        queue!(self.writer, terminal::BeginSynchronizedUpdate)
+    }
+
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        queue!(self.writer, terminal::EndSynchronizedUpdate)
+    }
+
+    fn color_support(&self) -> ColorSupport {
+        ColorSupport::detect(|name| std::env::var(name).ok())
+    }
+
+    fn bytes_written(&self) -> Option<u64> {
+        Some(self.writer.bytes_written)
+    }
+
     fn size(&self) -> io::Result<Size> {
         let (width, height) = terminal::size()?;
         Ok(Size { width, height })
@@ -346,13 +411,10 @@ where
             width,
             height,
         } = terminal::window_size()?;
-        Ok(WindowSize {
-            columns_rows: Size {
-                width: columns,
-                height: rows,
-            },
-            pixels: Size { width, height },
-        })
+        Ok(WindowSize::new(
+            Size::new(columns, rows),
+            Size::new(width, height),
+        ))
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -445,7 +507,11 @@ impl IntoCrossterm<ContentStyle> for Style {
             attributes.set(CrosstermAttribute::Italic);
         }
         if self.add_modifier.contains(Modifier::UNDERLINED) {
-            attributes.set(CrosstermAttribute::Underlined);
+            #[cfg(feature = "underline-style")]
+            let underline = self.underline_style.unwrap_or_default().into_crossterm();
+            #[cfg(not(feature = "underline-style"))]
+            let underline = CrosstermAttribute::Underlined;
+            attributes.set(underline);
         }
         if self.add_modifier.contains(Modifier::SLOW_BLINK) {
             attributes.set(CrosstermAttribute::SlowBlink);
@@ -462,6 +528,9 @@ impl IntoCrossterm<ContentStyle> for Style {
         if self.add_modifier.contains(Modifier::CROSSED_OUT) {
             attributes.set(CrosstermAttribute::CrossedOut);
         }
+        if self.add_modifier.contains(Modifier::OVERLINED) {
+            attributes.set(CrosstermAttribute::OverLined);
+        }
 
         // Sub modifiers (remove modifiers)
         if self.sub_modifier.contains(Modifier::BOLD) {
@@ -490,6 +559,9 @@ impl IntoCrossterm<ContentStyle> for Style {
         if self.sub_modifier.contains(Modifier::CROSSED_OUT) {
             attributes.set(CrosstermAttribute::NotCrossedOut);
         }
+        if self.sub_modifier.contains(Modifier::OVERLINED) {
+            attributes.set(CrosstermAttribute::NotOverLined);
+        }
 
         ContentStyle {
             foreground_color: self.fg.map(IntoCrossterm::into_crossterm),
@@ -529,6 +601,28 @@ impl FromCrossterm<CrosstermColor> for Color {
     }
 }
 
+/// Moves the cursor from its current position, if it is known, to the given position.
+///
+/// A relative move (e.g. `CSI n C` to move right) is written with fewer bytes than an absolute move
+/// (`CSI row ; column H`), so it is used whenever the target is on the same row or column as the
+/// cursor.
+///
+/// After a cell is printed in the last column of the terminal, the cursor stays on that cell until
+/// the next character is printed (the "pending wrap" state) instead of moving past it. The tracked
+/// position is then one column too far right, which only matters when moving left on the same row,
+/// as there are no cells to move to right of the last column. Moving left is done with an absolute
+/// column (`CSI column G`) instead, which is as short and doesn't depend on the cursor position.
+fn queue_move_to<W: Write>(mut w: W, from: Option<Position>, to: Position) -> io::Result<()> {
+    match from {
+        Some(from) if from.y == to.y && from.x < to.x => queue!(w, MoveRight(to.x - from.x)),
+        Some(from) if from.y == to.y && from.x > to.x => queue!(w, MoveToColumn(to.x)),
+        Some(from) if from.x == to.x && from.y < to.y => queue!(w, MoveDown(to.y - from.y)),
+        Some(from) if from.x == to.x && from.y > to.y => queue!(w, MoveUp(from.y - to.y)),
+        Some(from) if from == to => Ok(()),
+        _ => queue!(w, MoveTo(to.x, to.y)),
+    }
+}
+
 /// The `ModifierDiff` struct is used to calculate the difference between two `Modifier`
 /// values. This is useful when updating the terminal display, as it allows for more
 /// efficient updates by only sending the necessary changes.
@@ -572,6 +666,9 @@ impl ModifierDiff {
         if removed.contains(Modifier::CROSSED_OUT) {
             queue!(w, SetAttribute(CrosstermAttribute::NotCrossedOut))?;
         }
+        if removed.contains(Modifier::OVERLINED) {
+            queue!(w, SetAttribute(CrosstermAttribute::NotOverLined))?;
+        }
         if removed.contains(Modifier::HIDDEN) {
             queue!(w, SetAttribute(CrosstermAttribute::NoHidden))?;
         }
@@ -598,6 +695,9 @@ impl ModifierDiff {
         if added.contains(Modifier::CROSSED_OUT) {
             queue!(w, SetAttribute(CrosstermAttribute::CrossedOut))?;
         }
+        if added.contains(Modifier::OVERLINED) {
+            queue!(w, SetAttribute(CrosstermAttribute::OverLined))?;
+        }
         if added.contains(Modifier::HIDDEN) {
             queue!(w, SetAttribute(CrosstermAttribute::Hidden))?;
         }
@@ -612,6 +712,38 @@ impl ModifierDiff {
     }
 }
 
+#[cfg(feature = "underline-style")]
+impl IntoCrossterm<CrosstermAttribute> for UnderlineStyle {
+    fn into_crossterm(self) -> CrosstermAttribute {
+        match self {
+            Self::Single => CrosstermAttribute::Underlined,
+            Self::Double => CrosstermAttribute::DoubleUnderlined,
+            Self::Curly => CrosstermAttribute::Undercurled,
+            Self::Dotted => CrosstermAttribute::Underdotted,
+            Self::Dashed => CrosstermAttribute::Underdashed,
+        }
+    }
+}
+
+#[cfg(feature = "underline-style")]
+impl FromCrossterm<CrosstermAttributes> for Option<UnderlineStyle> {
+    fn from_crossterm(value: CrosstermAttributes) -> Self {
+        if value.has(CrosstermAttribute::DoubleUnderlined) {
+            Some(UnderlineStyle::Double)
+        } else if value.has(CrosstermAttribute::Undercurled) {
+            Some(UnderlineStyle::Curly)
+        } else if value.has(CrosstermAttribute::Underdotted) {
+            Some(UnderlineStyle::Dotted)
+        } else if value.has(CrosstermAttribute::Underdashed) {
+            Some(UnderlineStyle::Dashed)
+        } else if value.has(CrosstermAttribute::Underlined) {
+            Some(UnderlineStyle::Single)
+        } else {
+            None
+        }
+    }
+}
+
 impl FromCrossterm<CrosstermAttribute> for Modifier {
     fn from_crossterm(value: CrosstermAttribute) -> Self {
         // `Attribute*s*` (note the *s*) contains multiple `Attribute` We convert `Attribute` to
@@ -655,6 +787,9 @@ impl FromCrossterm<CrosstermAttributes> for Modifier {
         if value.has(CrosstermAttribute::CrossedOut) {
             res |= Self::CROSSED_OUT;
         }
+        if value.has(CrosstermAttribute::OverLined) {
+            res |= Self::OVERLINED;
+        }
         res
     }
 }
@@ -671,6 +806,9 @@ impl FromCrossterm<ContentStyle> for Style {
         if value.attributes.has(CrosstermAttribute::NotCrossedOut) {
             sub_modifier |= Modifier::CROSSED_OUT;
         }
+        if value.attributes.has(CrosstermAttribute::NotOverLined) {
+            sub_modifier |= Modifier::OVERLINED;
+        }
         if value.attributes.has(CrosstermAttribute::NoUnderline) {
             sub_modifier |= Modifier::UNDERLINED;
         }
@@ -689,12 +827,65 @@ impl FromCrossterm<ContentStyle> for Style {
             bg: value.background_color.map(FromCrossterm::from_crossterm),
             #[cfg(feature = "underline-color")]
             underline_color: value.underline_color.map(FromCrossterm::from_crossterm),
+            #[cfg(feature = "underline-style")]
+            underline_style: Option::from_crossterm(value.attributes),
             add_modifier: Modifier::from_crossterm(value.attributes),
             sub_modifier,
         }
     }
 }
 
+impl FromCrossterm<crossterm::event::KeyModifiers> for KeyModifiers {
+    /// Converts the crossterm modifiers, ignoring the Hyper and Meta keys.
+    fn from_crossterm(value: crossterm::event::KeyModifiers) -> Self {
+        use crossterm::event::KeyModifiers as CrosstermModifiers;
+
+        let mut modifiers = Self::empty();
+        modifiers.set(Self::CTRL, value.contains(CrosstermModifiers::CONTROL));
+        modifiers.set(Self::ALT, value.contains(CrosstermModifiers::ALT));
+        modifiers.set(Self::SHIFT, value.contains(CrosstermModifiers::SHIFT));
+        modifiers.set(Self::SUPER, value.contains(CrosstermModifiers::SUPER));
+        modifiers
+    }
+}
+
+impl FromCrossterm<crossterm::event::MouseButton> for MouseButton {
+    fn from_crossterm(value: crossterm::event::MouseButton) -> Self {
+        use crossterm::event::MouseButton as CrosstermButton;
+
+        match value {
+            CrosstermButton::Left => Self::Left,
+            CrosstermButton::Right => Self::Right,
+            CrosstermButton::Middle => Self::Middle,
+        }
+    }
+}
+
+impl FromCrossterm<crossterm::event::MouseEvent> for MouseEvent {
+    fn from_crossterm(value: crossterm::event::MouseEvent) -> Self {
+        use crossterm::event::MouseEventKind as CrosstermKind;
+
+        let kind = match value.kind {
+            CrosstermKind::Down(button) => {
+                MouseEventKind::Down(MouseButton::from_crossterm(button))
+            }
+            CrosstermKind::Up(button) => {
+                MouseEventKind::Up(Some(MouseButton::from_crossterm(button)))
+            }
+            CrosstermKind::Drag(button) => {
+                MouseEventKind::Drag(Some(MouseButton::from_crossterm(button)))
+            }
+            CrosstermKind::Moved => MouseEventKind::Moved,
+            CrosstermKind::ScrollDown => MouseEventKind::ScrollDown,
+            CrosstermKind::ScrollUp => MouseEventKind::ScrollUp,
+            CrosstermKind::ScrollLeft => MouseEventKind::ScrollLeft,
+            CrosstermKind::ScrollRight => MouseEventKind::ScrollRight,
+        };
+        Self::new(kind, Position::new(value.column, value.row))
+            .modifiers(KeyModifiers::from_crossterm(value.modifiers))
+    }
+}
+
 /// A command that scrolls the terminal screen a given number of rows up in a specific scrolling
 /// region.
 ///
@@ -797,6 +988,167 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn from_crossterm_mouse_event() {
+        use crossterm::event::{
+            KeyModifiers as CrosstermModifiers, MouseButton as CrosstermButton,
+            MouseEvent as CrosstermEvent, MouseEventKind as CrosstermKind,
+        };
+
+        let event = |kind| CrosstermEvent {
+            kind,
+            column: 3,
+            row: 1,
+            modifiers: CrosstermModifiers::NONE,
+        };
+        assert_eq!(
+            MouseEvent::from_crossterm(event(CrosstermKind::Down(CrosstermButton::Left))),
+            MouseEvent::new(MouseEventKind::Down(MouseButton::Left), Position::new(3, 1))
+        );
+        assert_eq!(
+            MouseEvent::from_crossterm(event(CrosstermKind::Up(CrosstermButton::Right))).kind,
+            MouseEventKind::Up(Some(MouseButton::Right))
+        );
+        assert_eq!(
+            MouseEvent::from_crossterm(event(CrosstermKind::ScrollUp)).kind,
+            MouseEventKind::ScrollUp
+        );
+        let ctrl_click = CrosstermEvent {
+            modifiers: CrosstermModifiers::CONTROL | CrosstermModifiers::SHIFT,
+            ..event(CrosstermKind::Down(CrosstermButton::Left))
+        };
+        assert_eq!(
+            MouseEvent::from_crossterm(ctrl_click).modifiers,
+            KeyModifiers::CTRL | KeyModifiers::SHIFT
+        );
+    }
+
+    #[test]
+    fn from_crossterm_key_modifiers() {
+        use crossterm::event::KeyModifiers as CrosstermModifiers;
+
+        assert_eq!(
+            KeyModifiers::from_crossterm(CrosstermModifiers::NONE),
+            KeyModifiers::empty()
+        );
+        assert_eq!(
+            KeyModifiers::from_crossterm(
+                CrosstermModifiers::CONTROL
+                    | CrosstermModifiers::ALT
+                    | CrosstermModifiers::SUPER
+                    | CrosstermModifiers::META
+            ),
+            KeyModifiers::CTRL | KeyModifiers::ALT | KeyModifiers::SUPER
+        );
+    }
+
+    #[test]
+    fn set_line_attribute() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend
+            .set_line_attribute(2, LineAttribute::DoubleWidth)
+            .unwrap();
+        assert_eq!(backend.writer(), b"\x1b[3;1H\x1b#6");
+    }
+
+    #[test]
+    fn synchronized_update() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.begin_synchronized_update().unwrap();
+        backend.end_synchronized_update().unwrap();
+        assert_eq!(backend.writer(), b"\x1b[?2026h\x1b[?2026l");
+    }
+
+    #[test]
+    fn bytes_written() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        assert_eq!(backend.bytes_written(), Some(0));
+        backend.begin_synchronized_update().unwrap();
+        assert_eq!(backend.bytes_written(), Some(8));
+        backend.write_all(b"abc").unwrap();
+        assert_eq!(backend.bytes_written(), Some(11));
+    }
+
+    #[cfg(feature = "underline-style")]
+    #[test]
+    fn draw_underline_style() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut curly = Cell::new("a");
+        curly.set_style(
+            Style::new()
+                .underlined()
+                .underline_style(UnderlineStyle::Curly),
+        );
+        let single = Cell::new("b").set_style(Style::new().underlined()).clone();
+        let plain = Cell::new("c");
+        let cells = [
+            (0, 0, &curly),
+            (1, 0, &single),
+            (2, 0, &plain),
+            (3, 0, &curly),
+        ];
+        backend.draw(cells.into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        assert_eq!(
+            output,
+            "\x1b[1;1H\x1b[4m\x1b[4:3ma\x1b[4mb\x1b[24mc\x1b[4m\x1b[4:3ma\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn draw_relative_cursor_moves() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let cell = Cell::new("a");
+        let wide = Cell::new("あ");
+        let cells = [
+            (2, 1, &cell),
+            (10, 1, &cell),
+            (11, 5, &cell),
+            (4, 5, &cell),
+            (5, 6, &wide),
+            (0, 7, &cell),
+        ];
+        backend.draw(cells.into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        assert_eq!(
+            output,
+            "\x1b[2;3Ha\x1b[7Ca\x1b[4Ba\x1b[5Ga\x1b[1Bあ\x1b[8;1Ha"
+        );
+    }
+
+    #[test]
+    fn draw_after_last_column() {
+        // in a 10 column terminal, the cursor stays on the last column after printing "b"
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let cell = Cell::new("a");
+        let last = Cell::new("b");
+        let cells = [
+            (8, 0, &cell),
+            (9, 0, &last),
+            (2, 0, &cell),
+            (9, 1, &last),
+            (3, 2, &cell),
+        ];
+        backend.draw(cells.into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        assert_eq!(output, "\x1b[1;9Hab\x1b[3Ga\x1b[2;10Hb\x1b[3;4Ha");
+    }
+
+    #[test]
+    fn draw_resets_only_changed_style() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let plain = Cell::new("a");
+        backend.draw([(0, 0, &plain)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        assert_eq!(output, "\x1b[1;1Ha");
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let red = Cell::new("b").set_fg(Color::Red).clone();
+        backend.draw([(0, 0, &red)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        assert_eq!(output, "\x1b[1;1H\x1b[38;5;1;49mb\x1b[0m");
+    }
+
     #[rstest]
     #[case(CrosstermColor::Reset, Color::Reset)]
     #[case(CrosstermColor::Black, Color::Black)]
@@ -835,6 +1187,8 @@ mod tests {
     #[case(Modifier::REVERSED, Modifier::empty(), &[CrosstermAttribute::NoReverse])]
     #[case(Modifier::SLOW_BLINK, Modifier::RAPID_BLINK, &[CrosstermAttribute::NoBlink, CrosstermAttribute::RapidBlink])]
     #[case(Modifier::empty(), Modifier::CROSSED_OUT, &[CrosstermAttribute::CrossedOut])]
+    #[case(Modifier::empty(), Modifier::OVERLINED, &[CrosstermAttribute::OverLined])]
+    #[case(Modifier::OVERLINED, Modifier::BOLD, &[CrosstermAttribute::NotOverLined, CrosstermAttribute::Bold])]
     #[case(Modifier::empty(), Modifier::HIDDEN, &[CrosstermAttribute::Hidden])]
     #[case(Modifier::empty(), Modifier::REVERSED, &[CrosstermAttribute::Reverse])]
     fn queue_modifier_diff(
@@ -866,7 +1220,7 @@ mod tests {
         #[case(CrosstermAttribute::NoItalic, Modifier::empty())]
         #[case(CrosstermAttribute::Underlined, Modifier::UNDERLINED)]
         #[case(CrosstermAttribute::NoUnderline, Modifier::empty())]
-        #[case(CrosstermAttribute::OverLined, Modifier::empty())]
+        #[case(CrosstermAttribute::OverLined, Modifier::OVERLINED)]
         #[case(CrosstermAttribute::NotOverLined, Modifier::empty())]
         #[case(CrosstermAttribute::DoubleUnderlined, Modifier::UNDERLINED)]
         #[case(CrosstermAttribute::Undercurled, Modifier::UNDERLINED)]
@@ -997,6 +1351,25 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "underline-style")]
+    fn content_style_underline_style() {
+        let style = Style::new()
+            .underlined()
+            .underline_style(UnderlineStyle::Dashed);
+        let content_style = style.into_crossterm();
+        assert!(
+            content_style
+                .attributes
+                .has(CrosstermAttribute::Underdashed)
+        );
+        assert!(!content_style.attributes.has(CrosstermAttribute::Underlined));
+        assert_eq!(
+            Style::from_crossterm(content_style).underline_style,
+            Some(UnderlineStyle::Dashed)
+        );
+    }
+
     #[rstest]
     #[case(Style::default(), ContentStyle::default())]
     #[case(
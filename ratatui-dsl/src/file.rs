@@ -0,0 +1,150 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::{Error, Ui};
+
+/// A [`Ui`] that is loaded from a file and can be reloaded when the file changes.
+///
+/// Call [`reload_if_changed`] regularly, e.g. once per frame or on a timer, to pick up changes to
+/// the file while the application is running. When the new contents can't be read or parsed, the
+/// error is returned and the previous user interface is kept, so a typo in the file doesn't bring
+/// down the application.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_core::layout::Rect;
+/// use ratatui_dsl::UiFile;
+///
+/// let mut file = UiFile::load("dashboard.toml")?;
+/// loop {
+///     if let Err(err) = file.reload_if_changed() {
+///         eprintln!("{err}");
+///     }
+///     // draw `file.ui()` in the terminal
+///     # break;
+/// }
+/// # Ok::<(), ratatui_dsl::Error>(())
+/// ```
+///
+/// [`reload_if_changed`]: Self::reload_if_changed
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiFile {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    ui: Ui,
+}
+
+impl UiFile {
+    /// Loads the user interface from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or parsed.
+    pub fn load<P: Into<PathBuf>>(path: P) -> Result<Self, Error> {
+        let path = path.into();
+        let modified = modified(&path);
+        let ui = Ui::from_toml(&fs::read_to_string(&path)?)?;
+        Ok(Self { path, modified, ui })
+    }
+
+    /// Reads and parses the file again, whether or not it changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or parsed, in which case the previous user
+    /// interface is kept.
+    pub fn reload(&mut self) -> Result<(), Error> {
+        // the modification time is updated first, so that a broken file is only reported once
+        self.modified = modified(&self.path);
+        self.ui = Ui::from_toml(&fs::read_to_string(&self.path)?)?;
+        Ok(())
+    }
+
+    /// Reloads the file if its modification time changed since it was last loaded.
+    ///
+    /// Returns whether the user interface was reloaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file changed but can't be read or parsed, in which case the
+    /// previous user interface is kept.
+    pub fn reload_if_changed(&mut self) -> Result<bool, Error> {
+        if modified(&self.path) == self.modified {
+            return Ok(false);
+        }
+        self.reload()?;
+        Ok(true)
+    }
+
+    /// Returns the current user interface.
+    pub const fn ui(&self) -> &Ui {
+        &self.ui
+    }
+
+    /// Returns the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Returns the modification time of the file, or `None` if it can't be determined.
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const PARAGRAPH: &str = "type = \"paragraph\"\ntext = \"one\"";
+
+    /// Writes the file and moves its modification time forward, as file systems with a coarse
+    /// timestamp resolution may not register a change between quick writes.
+    fn write(path: &Path, contents: &str, seconds: u64) {
+        fs::write(path, contents).unwrap();
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000 + seconds);
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    #[test]
+    fn reload_if_changed() {
+        let dir = std::env::temp_dir().join(format!("ratatui-dsl-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ui.toml");
+        write(&path, PARAGRAPH, 0);
+
+        let mut file = UiFile::load(&path).unwrap();
+        assert_eq!(file.path(), path);
+        assert!(!file.reload_if_changed().unwrap());
+
+        let changed = PARAGRAPH.replace("one", "two");
+        write(&path, &changed, 1);
+        assert!(file.reload_if_changed().unwrap());
+        assert_eq!(file.ui(), &Ui::from_toml(&changed).unwrap());
+
+        // an invalid file is reported once and the previous user interface is kept
+        write(&path, "type = ", 2);
+        assert!(file.reload_if_changed().is_err());
+        assert!(!file.reload_if_changed().unwrap());
+        assert_eq!(file.ui(), &Ui::from_toml(&changed).unwrap());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let error = UiFile::load("does-not-exist.toml").unwrap_err();
+        assert!(matches!(error, Error::Io(_)));
+    }
+}
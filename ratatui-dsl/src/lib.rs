@@ -0,0 +1,351 @@
+// show the feature flags in the generated documentation
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/ratatui/ratatui/main/assets/logo.png",
+    html_favicon_url = "https://raw.githubusercontent.com/ratatui/ratatui/main/assets/favicon.ico"
+)]
+#![warn(missing_docs)]
+//! Build Ratatui user interfaces from declarative layout files.
+//!
+//! A [`Ui`] is a tree of layouts and built-in widgets that is described in TOML rather than in
+//! code, so the structure, sizes and styles of a dashboard can be changed without recompiling the
+//! application. [`UiFile`] loads the description from a file and reloads it when the file
+//! changes, which lets designers iterate on a running application.
+//!
+//! ```toml
+//! type = "layout"
+//! direction = "vertical"
+//!
+//! [[children]]
+//! type = "paragraph"
+//! constraint = "length(3)"
+//! text = "Dashboard"
+//! style = "bold fg:yellow"
+//! alignment = "center"
+//! block = { borders = "all", border_type = "rounded" }
+//!
+//! [[children]]
+//! type = "layout"
+//! direction = "horizontal"
+//!
+//! [[children.children]]
+//! type = "gauge"
+//! constraint = "50%"
+//! ratio = 0.42
+//! label = "CPU"
+//! gauge_style = "fg:green"
+//!
+//! [[children.children]]
+//! id = "logs"
+//! type = "empty"
+//! ```
+//!
+//! # Format
+//!
+//! Every node has a `type` and these optional keys:
+//!
+//! - `id`: a name used to look up the area of the node with [`Ui::areas`], so the application can
+//!   render its own widgets there (e.g. in a node of type `empty`)
+//! - `constraint`: the size of the node in its parent layout, in any format that [`Constraint`]'s
+//!   `FromStr` implementation accepts, such as `"length(3)"`, `"50%"`, `">=10"` or `"fill(2)"`.
+//!   Defaults to `"fill(1)"`.
+//!
+//! The types of nodes and their keys are:
+//!
+//! - `layout`: `direction` (`"vertical"` or `"horizontal"`), `margin`, `spacing`, `flex`
+//!   (`"legacy"`, `"start"`, `"end"`, `"center"`, `"space-between"`, `"space-around"` or
+//!   `"space-evenly"`), `block` and `children`, an array of nodes
+//! - `empty`: renders nothing
+//! - `block`: the keys of a block table (see below)
+//! - `paragraph`: `text`, `style`, `alignment` (`"left"`, `"center"` or `"right"`), `wrap` and
+//!   `block`
+//! - `list`: `items`, an array of strings, `style` and `block`
+//! - `gauge`: `ratio` (between 0 and 1), `label`, `style`, `gauge_style` and `block`
+//! - `sparkline`: `data`, an array of integers, `max`, `style` and `block`
+//! - `tabs`: `titles`, an array of strings, `selected`, `style`, `highlight_style` and `block`
+//!
+//! A `block` table surrounds a node with a [`Block`] and has the keys `title`, `title_alignment`,
+//! `borders` (e.g. `"all"`, `"none"` or `"top bottom"`, defaults to `"all"`), `border_type`
+//! (`"plain"`, `"rounded"`, `"double"` or `"thick"`), `border_style` and `style`.
+//!
+//! Styles are written in the compact text format of [`Style`], such as `"bold fg:#ff0000
+//! bg:blue"`.
+//!
+//! Keys that the type of a node doesn't accept are rejected, and numbers must be finite, so that a
+//! typo such as `ratoi = 0.5` is reported with its line rather than ignored.
+//!
+//! [`Block`]: ratatui_widgets::block::Block
+//! [`Constraint`]: ratatui_core::layout::Constraint
+//! [`Style`]: ratatui_core::style::Style
+#![cfg_attr(feature = "document-features", doc = "\n## Features")]
+#![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
+
+use std::collections::HashMap;
+use std::io;
+use std::str::FromStr;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::widgets::Widget;
+
+pub use crate::file::UiFile;
+use crate::node::Element;
+
+mod file;
+mod node;
+
+/// A user interface described by a declarative layout file.
+///
+/// A `Ui` is parsed from TOML with [`Ui::from_toml`] (or [`str::parse`]) and rendered as a widget.
+/// See the [crate documentation](crate) for the format.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::buffer::Buffer;
+/// use ratatui_core::layout::Rect;
+/// use ratatui_core::widgets::Widget;
+/// use ratatui_dsl::Ui;
+///
+/// let ui = Ui::from_toml(
+///     r#"
+///     type = "layout"
+///     direction = "horizontal"
+///
+///     [[children]]
+///     type = "paragraph"
+///     constraint = "length(5)"
+///     text = "Hello"
+///
+///     [[children]]
+///     id = "content"
+///     type = "empty"
+///     "#,
+/// )?;
+///
+/// let area = Rect::new(0, 0, 20, 1);
+/// let mut buf = Buffer::empty(area);
+/// (&ui).render(area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["Hello               "]));
+/// assert_eq!(ui.areas(area)["content"], Rect::new(5, 0, 15, 1));
+/// # Ok::<(), ratatui_dsl::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ui {
+    root: Element,
+}
+
+impl Ui {
+    /// Parses a user interface from a TOML document.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] if the document is not valid TOML or doesn't describe a valid tree
+    /// of nodes.
+    pub fn from_toml(toml: &str) -> Result<Self, Error> {
+        let root = toml::from_str(toml)?;
+        Ok(Self { root })
+    }
+
+    /// Returns the areas of the nodes that have an `id` when the user interface is rendered to
+    /// `area`.
+    ///
+    /// This is used to render application widgets, such as stateful or frequently updated ones,
+    /// into the places that the layout file reserves for them.
+    pub fn areas(&self, area: Rect) -> HashMap<&str, Rect> {
+        let mut areas = HashMap::new();
+        self.root.collect_areas(area, &mut areas);
+        areas
+    }
+}
+
+impl FromStr for Ui {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_toml(s)
+    }
+}
+
+impl Widget for Ui {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Ui {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.root.render(area, buf);
+    }
+}
+
+/// An error that occurs while loading a user interface.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The layout file could not be read.
+    #[error("failed to read the layout file: {0}")]
+    Io(#[from] io::Error),
+    /// The layout description is not valid.
+    #[error("failed to parse the layout: {0}")]
+    Parse(#[from] toml::de::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::Style;
+
+    use super::*;
+
+    fn render(ui: &Ui, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        ui.render(buf.area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn nested_layouts() {
+        let ui: Ui = r#"
+            type = "layout"
+
+            [[children]]
+            type = "paragraph"
+            constraint = "length(3)"
+            text = "Title"
+            alignment = "center"
+            block = { border_type = "rounded" }
+
+            [[children]]
+            type = "layout"
+            direction = "horizontal"
+            spacing = 1
+
+            [[children.children]]
+            type = "list"
+            constraint = "length(3)"
+            items = ["a", "b"]
+
+            [[children.children]]
+            type = "tabs"
+            titles = ["one", "two"]
+            selected = 1
+            "#
+        .parse()
+        .unwrap();
+        assert_eq!(
+            render(&ui, 15, 5),
+            Buffer::with_lines([
+                "╭─────────────╮",
+                "│    Title    │",
+                "╰─────────────╯",
+                "a    one │ two ",
+                "b              ",
+            ])
+        );
+    }
+
+    #[test]
+    fn styles() {
+        let ui = Ui::from_toml(
+            r#"
+            type = "paragraph"
+            text = "hi"
+            style = "bold fg:red"
+            "#,
+        )
+        .unwrap();
+        let mut expected = Buffer::with_lines(["hi "]);
+        expected.set_style(expected.area, Style::new().red().bold());
+        assert_eq!(render(&ui, 3, 1), expected);
+    }
+
+    #[test]
+    fn block_node() {
+        let ui = Ui::from_toml(
+            r#"
+            type = "block"
+            title = "Box"
+            borders = "top bottom"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            render(&ui, 5, 3),
+            Buffer::with_lines(["Box──", "     ", "─────"])
+        );
+    }
+
+    #[test]
+    fn areas() {
+        let ui = Ui::from_toml(
+            r#"
+            type = "layout"
+            id = "root"
+            block = {}
+
+            [[children]]
+            id = "header"
+            type = "empty"
+            constraint = "length(1)"
+
+            [[children]]
+            type = "layout"
+            direction = "horizontal"
+            flex = "start"
+
+            [[children.children]]
+            id = "left"
+            type = "empty"
+            constraint = "25%"
+            "#,
+        )
+        .unwrap();
+        let area = Rect::new(0, 0, 10, 6);
+        let areas = ui.areas(area);
+        assert_eq!(areas.len(), 3);
+        assert_eq!(areas["root"], area);
+        assert_eq!(areas["header"], Rect::new(1, 1, 8, 1));
+        assert_eq!(areas["left"], Rect::new(1, 2, 2, 3));
+    }
+
+    #[test]
+    fn invalid_values() {
+        let error = |toml| Ui::from_toml(toml).unwrap_err().to_string();
+        assert!(error(r#"type = "chart""#).contains("unknown variant `chart`"));
+        assert!(error("type = \"empty\"\nconstraint = \"big\"").contains("Constraint"));
+        assert!(error("type = \"paragraph\"\nstyle = \"fg:nope\"").contains("fg:nope"));
+        assert!(error("type = \"block\"\nborders = \"up\"").contains("unknown border `up`"));
+    }
+
+    #[test]
+    fn non_finite_numbers() {
+        let error = Ui::from_toml("type = \"gauge\"\nratio = nan")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("line 2"), "{error}");
+        assert!(
+            error.contains("expected a finite number, found `NaN`"),
+            "{error}"
+        );
+        assert!(Ui::from_toml("type = \"gauge\"\nratio = -inf").is_err());
+
+        let error =
+            Ui::from_toml("type = \"layout\"\n\n[[children]]\ntype = \"gauge\"\nratio = inf")
+                .unwrap_err()
+                .to_string();
+        assert!(error.contains("line 5"), "{error}");
+    }
+
+    #[test]
+    fn unknown_keys() {
+        let error = |toml| Ui::from_toml(toml).unwrap_err().to_string();
+        let message = error("type = \"gauge\"\nratoi = 0.5");
+        assert!(message.contains("line 2"), "{message}");
+        assert!(message.contains("unknown field `ratoi`"), "{message}");
+        assert!(error("type = \"empty\"\ntext = \"hi\"").contains("unknown field `text`"));
+        assert!(
+            error("type = \"paragraph\"\nblock = { border = \"all\" }")
+                .contains("unknown field `border`")
+        );
+    }
+}
@@ -0,0 +1,698 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Constraint, Direction, Flex, HorizontalAlignment, Layout, Rect};
+use ratatui_core::style::Style;
+use ratatui_core::text::Text;
+use ratatui_core::widgets::Widget;
+use ratatui_widgets::block::Block;
+use ratatui_widgets::borders::{BorderType, Borders};
+use ratatui_widgets::gauge::Gauge;
+use ratatui_widgets::list::List;
+use ratatui_widgets::paragraph::{Paragraph, Wrap};
+use ratatui_widgets::sparkline::Sparkline;
+use ratatui_widgets::tabs::Tabs;
+use serde::Deserialize;
+use serde::de::{self, DeserializeSeed, MapAccess, Visitor};
+
+/// An element of the tree: a node with the properties that place it in its parent.
+///
+/// Elements are deserialized by [`ElementVisitor`] rather than with `#[serde(flatten)]` and an
+/// internally tagged enum, as those buffer the table and lose the location of errors.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Element {
+    /// The name used to look up the area of the element.
+    id: Option<String>,
+    /// The size of the element in the layout of its parent.
+    constraint: Constraint,
+    node: Node,
+}
+
+const fn fill() -> Constraint {
+    Constraint::Fill(1)
+}
+
+/// The kinds of nodes, selected by the `type` key.
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Layout(LayoutNode),
+    Empty,
+    Block(BlockSpec),
+    Paragraph(ParagraphNode),
+    List(ListNode),
+    Gauge(GaugeNode),
+    Sparkline(SparklineNode),
+    Tabs(TabsNode),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct LayoutNode {
+    direction: DirectionSpec,
+    margin: u16,
+    spacing: u16,
+    flex: FlexSpec,
+    block: Option<BlockSpec>,
+    children: Vec<Element>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ParagraphNode {
+    text: String,
+    style: Style,
+    alignment: AlignmentSpec,
+    wrap: bool,
+    block: Option<BlockSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ListNode {
+    items: Vec<String>,
+    style: Style,
+    block: Option<BlockSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct GaugeNode {
+    ratio: f64,
+    label: Option<String>,
+    style: Style,
+    gauge_style: Style,
+    block: Option<BlockSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SparklineNode {
+    data: Vec<u64>,
+    max: Option<u64>,
+    style: Style,
+    block: Option<BlockSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TabsNode {
+    titles: Vec<String>,
+    selected: Option<usize>,
+    style: Style,
+    highlight_style: Style,
+    block: Option<BlockSpec>,
+}
+
+/// The properties of a [`Block`], used by `type = "block"` nodes and the `block` tables of other
+/// nodes.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BlockSpec {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    title_alignment: AlignmentSpec,
+    #[serde(default = "all_borders", deserialize_with = "borders")]
+    borders: Borders,
+    #[serde(default)]
+    border_type: BorderTypeSpec,
+    #[serde(default, deserialize_with = "from_str")]
+    border_style: Style,
+    #[serde(default, deserialize_with = "from_str")]
+    style: Style,
+}
+
+const fn all_borders() -> Borders {
+    Borders::ALL
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum DirectionSpec {
+    Horizontal,
+    #[default]
+    Vertical,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum FlexSpec {
+    #[default]
+    Legacy,
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum AlignmentSpec {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum BorderTypeSpec {
+    #[default]
+    Plain,
+    Rounded,
+    Double,
+    Thick,
+}
+
+/// The value of the `type` key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum NodeType {
+    Layout,
+    Empty,
+    Block,
+    Paragraph,
+    List,
+    Gauge,
+    Sparkline,
+    Tabs,
+}
+
+/// The keys of all the types of nodes.
+const ALL_KEYS: &[&str] = &[
+    "type",
+    "id",
+    "constraint",
+    "direction",
+    "margin",
+    "spacing",
+    "flex",
+    "block",
+    "children",
+    "text",
+    "style",
+    "alignment",
+    "wrap",
+    "items",
+    "ratio",
+    "label",
+    "gauge_style",
+    "data",
+    "max",
+    "titles",
+    "selected",
+    "highlight_style",
+    "title",
+    "title_alignment",
+    "borders",
+    "border_type",
+    "border_style",
+];
+
+impl NodeType {
+    /// Returns the keys that a node of this type accepts.
+    const fn keys(self) -> &'static [&'static str] {
+        match self {
+            Self::Layout => &[
+                "type",
+                "id",
+                "constraint",
+                "direction",
+                "margin",
+                "spacing",
+                "flex",
+                "block",
+                "children",
+            ],
+            Self::Empty => &["type", "id", "constraint"],
+            Self::Block => &[
+                "type",
+                "id",
+                "constraint",
+                "title",
+                "title_alignment",
+                "borders",
+                "border_type",
+                "border_style",
+                "style",
+            ],
+            Self::Paragraph => &[
+                "type",
+                "id",
+                "constraint",
+                "text",
+                "style",
+                "alignment",
+                "wrap",
+                "block",
+            ],
+            Self::List => &["type", "id", "constraint", "items", "style", "block"],
+            Self::Gauge => &[
+                "type",
+                "id",
+                "constraint",
+                "ratio",
+                "label",
+                "style",
+                "gauge_style",
+                "block",
+            ],
+            Self::Sparkline => &["type", "id", "constraint", "data", "max", "style", "block"],
+            Self::Tabs => &[
+                "type",
+                "id",
+                "constraint",
+                "titles",
+                "selected",
+                "style",
+                "highlight_style",
+                "block",
+            ],
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Element {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ElementVisitor)
+    }
+}
+
+/// Deserializes an [`Element`] from the keys of a table, which can be in any order.
+///
+/// Each value is deserialized as soon as its key is read, so that errors point at the value in the
+/// document.
+struct ElementVisitor;
+
+impl<'de> Visitor<'de> for ElementVisitor {
+    type Value = Element;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a table with a `type` key")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut node_type = None;
+        let mut keys_before_type = Vec::new();
+        let mut id = None;
+        let mut constraint = None;
+        let mut fields = Fields::default();
+        while let Some(key) = map.next_key_seed(KeySeed(node_type))? {
+            match key.as_str() {
+                "type" => node_type = Some(map.next_value()?),
+                "id" => id = Some(map.next_value()?),
+                "constraint" => constraint = Some(map.next_value::<Parsed<_>>()?.0),
+                "direction" => fields.direction = Some(map.next_value()?),
+                "margin" => fields.margin = Some(map.next_value()?),
+                "spacing" => fields.spacing = Some(map.next_value()?),
+                "flex" => fields.flex = Some(map.next_value()?),
+                "block" => fields.block = Some(map.next_value()?),
+                "children" => fields.children = Some(map.next_value()?),
+                "text" => fields.text = Some(map.next_value()?),
+                "style" => fields.style = Some(map.next_value::<Parsed<_>>()?.0),
+                "alignment" => fields.alignment = Some(map.next_value()?),
+                "wrap" => fields.wrap = Some(map.next_value()?),
+                "items" => fields.items = Some(map.next_value()?),
+                "ratio" => fields.ratio = Some(map.next_value::<Finite>()?.0),
+                "label" => fields.label = Some(map.next_value()?),
+                "gauge_style" => fields.gauge_style = Some(map.next_value::<Parsed<_>>()?.0),
+                "data" => fields.data = Some(map.next_value()?),
+                "max" => fields.max = Some(map.next_value()?),
+                "titles" => fields.titles = Some(map.next_value()?),
+                "selected" => fields.selected = Some(map.next_value()?),
+                "highlight_style" => {
+                    fields.highlight_style = Some(map.next_value::<Parsed<_>>()?.0);
+                }
+                "title" => fields.title = Some(map.next_value()?),
+                "title_alignment" => fields.title_alignment = Some(map.next_value()?),
+                "borders" => fields.borders = Some(map.next_value::<BorderSides>()?.0),
+                "border_type" => fields.border_type = Some(map.next_value()?),
+                "border_style" => fields.border_style = Some(map.next_value::<Parsed<_>>()?.0),
+                _ => return Err(de::Error::unknown_field(&key, ALL_KEYS)),
+            }
+            if node_type.is_none() {
+                keys_before_type.push(key);
+            }
+        }
+        let node_type: NodeType = node_type.ok_or_else(|| de::Error::missing_field("type"))?;
+        // the keys that came before `type` could not be checked when they were read
+        if let Some(key) = keys_before_type
+            .iter()
+            .find(|key| !node_type.keys().contains(&key.as_str()))
+        {
+            return Err(de::Error::unknown_field(key, node_type.keys()));
+        }
+        Ok(Element {
+            id,
+            constraint: constraint.unwrap_or(fill()),
+            node: fields.into_node(node_type),
+        })
+    }
+}
+
+/// Deserializes a key of a node table, rejecting the keys that the type of the node doesn't
+/// accept.
+///
+/// Checking the key here rather than in [`ElementVisitor`] reports the error at the key. Before the
+/// `type` key is read, the keys of all the types of nodes are accepted.
+struct KeySeed(Option<NodeType>);
+
+impl<'de> DeserializeSeed<'de> for KeySeed {
+    type Value = String;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let key = String::deserialize(deserializer)?;
+        let keys = self.0.map_or(ALL_KEYS, NodeType::keys);
+        if keys.contains(&key.as_str()) {
+            Ok(key)
+        } else {
+            Err(de::Error::unknown_field(&key, keys))
+        }
+    }
+}
+
+/// The values of the keys of a node table, which are used once the type of the node is known.
+#[derive(Default)]
+struct Fields {
+    direction: Option<DirectionSpec>,
+    margin: Option<u16>,
+    spacing: Option<u16>,
+    flex: Option<FlexSpec>,
+    block: Option<BlockSpec>,
+    children: Option<Vec<Element>>,
+    text: Option<String>,
+    style: Option<Style>,
+    alignment: Option<AlignmentSpec>,
+    wrap: Option<bool>,
+    items: Option<Vec<String>>,
+    ratio: Option<f64>,
+    label: Option<String>,
+    gauge_style: Option<Style>,
+    data: Option<Vec<u64>>,
+    max: Option<u64>,
+    titles: Option<Vec<String>>,
+    selected: Option<usize>,
+    highlight_style: Option<Style>,
+    title: Option<String>,
+    title_alignment: Option<AlignmentSpec>,
+    borders: Option<Borders>,
+    border_type: Option<BorderTypeSpec>,
+    border_style: Option<Style>,
+}
+
+impl Fields {
+    /// Builds a node of the given type, using the default value of each key that is not set.
+    fn into_node(self, node_type: NodeType) -> Node {
+        let style = self.style.unwrap_or_default();
+        match node_type {
+            NodeType::Layout => Node::Layout(LayoutNode {
+                direction: self.direction.unwrap_or_default(),
+                margin: self.margin.unwrap_or_default(),
+                spacing: self.spacing.unwrap_or_default(),
+                flex: self.flex.unwrap_or_default(),
+                block: self.block,
+                children: self.children.unwrap_or_default(),
+            }),
+            NodeType::Empty => Node::Empty,
+            NodeType::Block => Node::Block(BlockSpec {
+                title: self.title,
+                title_alignment: self.title_alignment.unwrap_or_default(),
+                borders: self.borders.unwrap_or(all_borders()),
+                border_type: self.border_type.unwrap_or_default(),
+                border_style: self.border_style.unwrap_or_default(),
+                style,
+            }),
+            NodeType::Paragraph => Node::Paragraph(ParagraphNode {
+                text: self.text.unwrap_or_default(),
+                style,
+                alignment: self.alignment.unwrap_or_default(),
+                wrap: self.wrap.unwrap_or_default(),
+                block: self.block,
+            }),
+            NodeType::List => Node::List(ListNode {
+                items: self.items.unwrap_or_default(),
+                style,
+                block: self.block,
+            }),
+            NodeType::Gauge => Node::Gauge(GaugeNode {
+                ratio: self.ratio.unwrap_or_default(),
+                label: self.label,
+                style,
+                gauge_style: self.gauge_style.unwrap_or_default(),
+                block: self.block,
+            }),
+            NodeType::Sparkline => Node::Sparkline(SparklineNode {
+                data: self.data.unwrap_or_default(),
+                max: self.max,
+                style,
+                block: self.block,
+            }),
+            NodeType::Tabs => Node::Tabs(TabsNode {
+                titles: self.titles.unwrap_or_default(),
+                selected: self.selected,
+                style,
+                highlight_style: self.highlight_style.unwrap_or_default(),
+                block: self.block,
+            }),
+        }
+    }
+}
+
+impl Element {
+    /// Renders the element and its children to the area.
+    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
+        match &self.node {
+            Node::Layout(layout) => {
+                let inner = render_block(layout.block.as_ref(), area, buf);
+                for (child, area) in layout.children.iter().zip(layout.split(inner)) {
+                    child.render(area, buf);
+                }
+            }
+            Node::Empty => {}
+            Node::Block(block) => block.to_block().render(area, buf),
+            Node::Paragraph(node) => {
+                let mut paragraph = Paragraph::new(Text::raw(node.text.as_str()))
+                    .style(node.style)
+                    .alignment(node.alignment.into());
+                if node.wrap {
+                    paragraph = paragraph.wrap(Wrap { trim: false });
+                }
+                if let Some(block) = &node.block {
+                    paragraph = paragraph.block(block.to_block());
+                }
+                paragraph.render(area, buf);
+            }
+            Node::List(node) => {
+                let mut list = List::new(node.items.iter().map(String::as_str)).style(node.style);
+                if let Some(block) = &node.block {
+                    list = list.block(block.to_block());
+                }
+                Widget::render(list, area, buf);
+            }
+            Node::Gauge(node) => {
+                let mut gauge = Gauge::default()
+                    .ratio(node.ratio.clamp(0.0, 1.0))
+                    .style(node.style)
+                    .gauge_style(node.gauge_style);
+                if let Some(label) = &node.label {
+                    gauge = gauge.label(label.as_str());
+                }
+                if let Some(block) = &node.block {
+                    gauge = gauge.block(block.to_block());
+                }
+                gauge.render(area, buf);
+            }
+            Node::Sparkline(node) => {
+                let mut sparkline = Sparkline::default().data(&node.data).style(node.style);
+                if let Some(max) = node.max {
+                    sparkline = sparkline.max(max);
+                }
+                if let Some(block) = &node.block {
+                    sparkline = sparkline.block(block.to_block());
+                }
+                sparkline.render(area, buf);
+            }
+            Node::Tabs(node) => {
+                let mut tabs = Tabs::new(node.titles.iter().map(String::as_str))
+                    .select(node.selected)
+                    .style(node.style)
+                    .highlight_style(node.highlight_style);
+                if let Some(block) = &node.block {
+                    tabs = tabs.block(block.to_block());
+                }
+                tabs.render(area, buf);
+            }
+        }
+    }
+
+    /// Adds the areas of the element and its descendants that have an id.
+    pub(crate) fn collect_areas<'a>(&'a self, area: Rect, areas: &mut HashMap<&'a str, Rect>) {
+        if let Some(id) = &self.id {
+            areas.insert(id, area);
+        }
+        if let Node::Layout(layout) = &self.node {
+            let inner = layout
+                .block
+                .as_ref()
+                .map_or(area, |block| block.to_block().inner(area));
+            for (child, area) in layout.children.iter().zip(layout.split(inner)) {
+                child.collect_areas(area, areas);
+            }
+        }
+    }
+}
+
+impl LayoutNode {
+    fn split(&self, area: Rect) -> Vec<Rect> {
+        let direction = match self.direction {
+            DirectionSpec::Horizontal => Direction::Horizontal,
+            DirectionSpec::Vertical => Direction::Vertical,
+        };
+        let flex = match self.flex {
+            FlexSpec::Legacy => Flex::Legacy,
+            FlexSpec::Start => Flex::Start,
+            FlexSpec::End => Flex::End,
+            FlexSpec::Center => Flex::Center,
+            FlexSpec::SpaceBetween => Flex::SpaceBetween,
+            FlexSpec::SpaceAround => Flex::SpaceAround,
+            FlexSpec::SpaceEvenly => Flex::SpaceEvenly,
+        };
+        Layout::new(
+            direction,
+            self.children.iter().map(|child| child.constraint),
+        )
+        .margin(self.margin)
+        .spacing(self.spacing)
+        .flex(flex)
+        .split(area)
+        .to_vec()
+    }
+}
+
+impl BlockSpec {
+    fn to_block(&self) -> Block<'_> {
+        let border_type = match self.border_type {
+            BorderTypeSpec::Plain => BorderType::Plain,
+            BorderTypeSpec::Rounded => BorderType::Rounded,
+            BorderTypeSpec::Double => BorderType::Double,
+            BorderTypeSpec::Thick => BorderType::Thick,
+        };
+        let mut block = Block::new()
+            .borders(self.borders)
+            .border_type(border_type)
+            .border_style(self.border_style)
+            .style(self.style)
+            .title_alignment(self.title_alignment.into());
+        if let Some(title) = &self.title {
+            block = block.title(title.as_str());
+        }
+        block
+    }
+}
+
+/// Renders the block, if any, and returns the area inside it.
+fn render_block(block: Option<&BlockSpec>, area: Rect, buf: &mut Buffer) -> Rect {
+    let Some(block) = block else {
+        return area;
+    };
+    let block = block.to_block();
+    let inner = block.inner(area);
+    block.render(area, buf);
+    inner
+}
+
+impl From<AlignmentSpec> for HorizontalAlignment {
+    fn from(alignment: AlignmentSpec) -> Self {
+        match alignment {
+            AlignmentSpec::Left => Self::Left,
+            AlignmentSpec::Center => Self::Center,
+            AlignmentSpec::Right => Self::Right,
+        }
+    }
+}
+
+/// Deserializes a value from a string with its [`FromStr`] implementation.
+///
+/// This is used for styles such as `"bold fg:red"` and constraints such as `"length(3)"`.
+fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(serde::de::Error::custom)
+}
+
+/// Deserializes borders from a list of sides separated by whitespace, such as `"top bottom"`, or
+/// `"all"` or `"none"`.
+fn borders<'de, D>(deserializer: D) -> Result<Borders, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    value
+        .split_whitespace()
+        .try_fold(Borders::NONE, |borders, side| {
+            if side.eq_ignore_ascii_case("none") {
+                return Ok(borders);
+            }
+            Borders::from_name(&side.to_ascii_uppercase())
+                .map(|side| borders | side)
+                .ok_or_else(|| serde::de::Error::custom(format!("unknown border `{side}`")))
+        })
+}
+
+/// A value deserialized with [`from_str`].
+struct Parsed<T>(T);
+
+impl<'de, T> Deserialize<'de> for Parsed<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        from_str(deserializer).map(Self)
+    }
+}
+
+/// Borders deserialized with [`borders`].
+struct BorderSides(Borders);
+
+impl<'de> Deserialize<'de> for BorderSides {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        borders(deserializer).map(Self)
+    }
+}
+
+/// A number that is neither `nan` nor infinite.
+struct Finite(f64);
+
+impl<'de> Deserialize<'de> for Finite {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = f64::deserialize(deserializer)?;
+        if value.is_finite() {
+            Ok(Self(value))
+        } else {
+            Err(de::Error::custom(format!(
+                "expected a finite number, found `{value}`"
+            )))
+        }
+    }
+}
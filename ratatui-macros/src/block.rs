@@ -0,0 +1,175 @@
+/// A macro for creating a [`Block`] with borders, titles and padding in one expression.
+///
+/// `block!` takes a comma-separated list of items, which are applied in order:
+///
+/// - `bordered` shows all the borders, like [`Block::bordered`].
+/// - `plain`, `rounded`, `double` and `thick` set the [`BorderType`].
+/// - `title_left = title`, `title_center = title` and `title_right = title` add a title with the
+///   given alignment. The title is anything that can be converted into a [`Line`].
+/// - `padding = n` sets a uniform [`Padding`], `padding = (x, y)` sets a symmetric padding, and
+///   `padding = (left, right, top, bottom)` sets each side of the padding.
+/// - `method = value` calls any other method of [`Block`] with a single argument, e.g.
+///   `title = "Files"`, `title_bottom = "q: quit"`, `borders = Borders::TOP`,
+///   `border_style = Color::Blue` or `padding = Padding::ZERO`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// # use ratatui_widgets::block::{Block, Padding};
+/// # use ratatui_widgets::borders::BorderType;
+/// use ratatui_macros::block;
+///
+/// let block = block!(bordered, rounded, title = "Files", padding = (1, 1));
+/// assert_eq!(
+///     block,
+///     Block::bordered()
+///         .border_type(BorderType::Rounded)
+///         .title("Files")
+///         .padding(Padding::symmetric(1, 1))
+/// );
+///
+/// let block = block!(
+///     bordered,
+///     title_center = "Centered",
+///     title_bottom = "q: quit",
+///     border_style = Color::Blue,
+/// );
+/// ```
+///
+/// * Create a [`Block`] without borders:
+///
+/// ```rust
+/// # use ratatui_macros::block;
+/// let block = block!(padding = 2);
+/// let empty = block!();
+/// ```
+///
+/// [`Block`]: ratatui_widgets::block::Block
+/// [`Block::bordered`]: ratatui_widgets::block::Block::bordered
+/// [`BorderType`]: ratatui_widgets::borders::BorderType
+/// [`Padding`]: ratatui_widgets::block::Padding
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! block {
+    // Each rule applies the first item to the block built so far and continues with the rest of
+    // the items, until there are none left.
+    (@ $block:expr ;) => {
+        $block
+    };
+    (@ $block:expr ; bordered $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.borders(::ratatui_widgets::borders::Borders::ALL) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; plain $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.border_type(::ratatui_widgets::borders::BorderType::Plain) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; rounded $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.border_type(::ratatui_widgets::borders::BorderType::Rounded) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; double $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.border_type(::ratatui_widgets::borders::BorderType::Double) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; thick $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.border_type(::ratatui_widgets::borders::BorderType::Thick) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; title_left = $title:expr $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.title($crate::ratatui_core::text::Line::from($title).left_aligned()) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; title_center = $title:expr $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.title($crate::ratatui_core::text::Line::from($title).centered()) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; title_right = $title:expr $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.title($crate::ratatui_core::text::Line::from($title).right_aligned()) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; padding = ($x:expr, $y:expr) $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.padding(::ratatui_widgets::block::Padding::symmetric($x, $y)) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; padding = ($left:expr, $right:expr, $top:expr, $bottom:expr) $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.padding(::ratatui_widgets::block::Padding::new($left, $right, $top, $bottom)) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; padding = $value:literal $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.padding(::ratatui_widgets::block::Padding::uniform($value)) ; $($($rest)*)?)
+    };
+    (@ $block:expr ; $method:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::block!(@ $block.$method($value) ; $($($rest)*)?)
+    };
+    ($($items:tt)*) => {
+        $crate::block!(@ ::ratatui_widgets::block::Block::new() ; $($items)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Color;
+    use ratatui_core::text::Line;
+    use ratatui_widgets::block::{Block, Padding};
+    use ratatui_widgets::borders::{BorderType, Borders};
+
+    #[test]
+    fn block_empty() {
+        assert_eq!(block!(), Block::new());
+    }
+
+    #[test]
+    fn block_borders() {
+        assert_eq!(block!(bordered), Block::bordered());
+        assert_eq!(
+            block!(bordered, thick),
+            Block::bordered().border_type(BorderType::Thick)
+        );
+        assert_eq!(
+            block!(borders = Borders::TOP | Borders::LEFT, double),
+            Block::new()
+                .borders(Borders::TOP | Borders::LEFT)
+                .border_type(BorderType::Double)
+        );
+    }
+
+    #[test]
+    fn block_titles() {
+        let block = block!(
+            title = "a",
+            title_left = "b",
+            title_center = "c",
+            title_right = "d",
+            title_bottom = "e",
+        );
+        let expected = Block::new()
+            .title("a")
+            .title(Line::from("b").left_aligned())
+            .title(Line::from("c").centered())
+            .title(Line::from("d").right_aligned())
+            .title_bottom("e");
+        assert_eq!(block, expected);
+    }
+
+    #[test]
+    fn block_padding() {
+        assert_eq!(
+            block!(padding = 1),
+            Block::new().padding(Padding::uniform(1))
+        );
+        assert_eq!(
+            block!(padding = (1, 2)),
+            Block::new().padding(Padding::symmetric(1, 2))
+        );
+        assert_eq!(
+            block!(padding = (1, 2, 3, 4)),
+            Block::new().padding(Padding::new(1, 2, 3, 4))
+        );
+        assert_eq!(
+            block!(padding = Padding::horizontal(1)),
+            Block::new().padding(Padding::horizontal(1))
+        );
+    }
+
+    #[test]
+    fn block_methods() {
+        assert_eq!(
+            block!(bordered, border_style = Color::Red, style = Color::Blue),
+            Block::bordered()
+                .border_style(Color::Red)
+                .style(Color::Blue)
+        );
+    }
+}
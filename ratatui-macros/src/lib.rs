@@ -9,7 +9,9 @@
 //!
 //! - [Text macros](#text-macros) for easily defining styled [`Text`]s, [`Line`]s, and [`Span`]s.
 //! - [Layout macros](#layout-macros) for defining [`Layout`]s with [`Constraint`]s and directions.
-//! - [Table macros](#table-macros) for creating [`Row`]s and [`Cell`]s.
+//! - [Table macros](#table-macros) for creating [`Table`]s, [`Row`]s and [`Cell`]s.
+//! - [Block macros](#block-macros) for creating [`Block`]s with borders, titles and padding.
+//! - [Palette macros](#palette-macros) for declaring themes of [`Color`]s and [`Style`]s.
 //!
 //! # Getting Started
 //!
@@ -22,7 +24,9 @@
 //! Then, import the macros in your Rust file:
 //!
 //! ```rust
-//! use ratatui_macros::{constraint, constraints, horizontal, line, row, span, text, vertical};
+//! use ratatui_macros::{
+//!     block, constraint, constraints, horizontal, line, palette, row, span, table, text, vertical,
+//! };
 //! ```
 //!
 //! # Text Macros
@@ -116,6 +120,46 @@
 //! let table = Table::new(rows, constraints![==20, *=1]);
 //! ```
 //!
+//! The [`table!`] macro creates a complete [`Table`] from a header, column widths in the
+//! [`constraints!`] syntax, and rows in the [`row!`] syntax.
+//!
+//! ```rust
+//! # use ratatui_macros::table;
+//! let table = table![
+//!     header: ["Name", "Age"];
+//!     widths: [==20, *=1];
+//!     rows: [["Alice", "31"], ["Bob", "27"]]
+//! ];
+//! ```
+//!
+//! # Block Macros
+//!
+//! The [`block!`] macro creates a [`Block`] from a list of borders, titles and padding.
+//!
+//! ```rust
+//! # use ratatui_macros::block;
+//! let block = block!(bordered, rounded, title = "Files", padding = (1, 1));
+//! let block = block!(bordered, title_center = "Centered", title_bottom = "q: quit");
+//! ```
+//!
+//! # Palette Macros
+//!
+//! The [`palette!`] macro declares a struct of named [`Color`]s and [`Style`]s with a `DEFAULT`
+//! constant, and accepts hex colors as string literals.
+//!
+//! ```rust
+//! # use ratatui_core::style::{Color, Style};
+//! # use ratatui_macros::palette;
+//! palette! {
+//!     pub struct Theme {
+//!         pub background: Color = "#0f172a",
+//!         pub highlight: Style = Style::new().fg(Color::Yellow),
+//!     }
+//! }
+//!
+//! const THEME: Theme = Theme::DEFAULT;
+//! ```
+//!
 //! # Contributing
 //!
 //! Contributions to `ratatui-macros` are welcome! Whether it's submitting a bug report, a feature
@@ -163,17 +207,26 @@
 //! [`Row`]: ratatui_widgets::table::Row
 //! [`Cell`]: ratatui_widgets::table::Cell
 //! [`Table`]: ratatui_widgets::table::Table
+//! [`Block`]: ratatui_widgets::block::Block
+//! [`Color`]: ratatui_core::style::Color
+//! [`Style`]: ratatui_core::style::Style
 
 extern crate alloc;
 
 #[doc(hidden)]
 pub use alloc::{format, vec};
 
+mod block;
 mod layout;
 mod line;
+mod palette;
 mod row;
 mod span;
+mod table;
 mod text;
 
+#[doc(hidden)]
+pub use palette::hex_color;
+
 // Re-export the core crate to use the types in macros
 pub use ratatui_core;
@@ -0,0 +1,187 @@
+use ratatui_core::style::Color;
+
+/// A macro for declaring a theme struct of named [`Color`]s and [`Style`]s with const values.
+///
+/// `palette!` declares a struct with one field per entry, and an associated `DEFAULT` constant
+/// that holds the declared values. The struct derives `Debug`, `Clone`, `Copy`, `PartialEq` and
+/// `Eq`, and implements [`Default`] by returning `DEFAULT`, so it can be stored in a `const` or a
+/// `static` and copied into widgets without any runtime initialization.
+///
+/// Each entry is written as `name: Type = value`. The value is any const expression, or a string
+/// literal with a hex color in the `"#RRGGBB"` or `"#RGB"` notation, which is converted to a
+/// [`Color::Rgb`] at compile time. An invalid hex color is a compile error.
+///
+/// Attributes and doc comments on the struct and its fields are kept, and the visibility of the
+/// struct and each field is the one written in the macro.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::{Color, Modifier, Style};
+/// use ratatui_macros::palette;
+///
+/// palette! {
+///     /// The colors of the application
+///     pub struct Theme {
+///         pub background: Color = "#0f172a",
+///         pub accent: Color = "#f80",
+///         pub text: Color = Color::White,
+///         pub title: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+///     }
+/// }
+///
+/// const THEME: Theme = Theme::DEFAULT;
+/// assert_eq!(THEME.background, Color::Rgb(0x0f, 0x17, 0x2a));
+/// assert_eq!(THEME.accent, Color::Rgb(0xff, 0x88, 0x00));
+/// assert_eq!(Theme::default(), THEME);
+/// ```
+///
+/// Hex colors must be written as string literals:
+///
+/// ```compile_fail
+/// # use ratatui_core::style::Color;
+/// # use ratatui_macros::palette;
+/// palette! {
+///     struct Theme {
+///         background: Color = "#0f17",
+///     }
+/// }
+/// const THEME: Theme = Theme::DEFAULT;
+/// ```
+///
+/// [`Color`]: ratatui_core::style::Color
+/// [`Color::Rgb`]: ratatui_core::style::Color::Rgb
+/// [`Style`]: ratatui_core::style::Style
+#[macro_export]
+macro_rules! palette {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $ty:ty = $value:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl $name {
+            /// The values this palette was declared with.
+            #[allow(dead_code)]
+            $vis const DEFAULT: Self = Self {
+                $($field: $crate::__palette_value!($value),)*
+            };
+        }
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+    };
+}
+
+/// Converts a hex color string literal to a [`Color`] and passes any other value through.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __palette_value {
+    ($hex:literal) => {
+        $crate::hex_color($hex)
+    };
+    ($value:expr) => {
+        $value
+    };
+}
+
+/// Parses a color in the `#RRGGBB` or `#RGB` notation in a const context.
+///
+/// This panics on invalid input, which is a compile error when used in a const.
+#[doc(hidden)]
+pub const fn hex_color(hex: &str) -> Color {
+    let bytes = hex.as_bytes();
+    match bytes {
+        [b'#', r1, r2, g1, g2, b1, b2] => Color::Rgb(
+            hex_digit(*r1) * 16 + hex_digit(*r2),
+            hex_digit(*g1) * 16 + hex_digit(*g2),
+            hex_digit(*b1) * 16 + hex_digit(*b2),
+        ),
+        // a single hex digit is repeated, so `#f80` is the same as `#ff8800`
+        [b'#', r, g, b] => Color::Rgb(
+            hex_digit(*r) * 0x11,
+            hex_digit(*g) * 0x11,
+            hex_digit(*b) * 0x11,
+        ),
+        _ => panic!("hex colors must be in the #RRGGBB or #RGB notation"),
+    }
+}
+
+const fn hex_digit(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => panic!("invalid hex digit in color"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Modifier, Style};
+
+    use super::hex_color;
+
+    palette! {
+        /// A test palette
+        struct Theme {
+            /// The background color
+            background: Color = "#1E3A8A",
+            accent: Color = "#f80",
+            text: Color = Color::Indexed(7),
+            pub(crate) title: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        }
+    }
+
+    const THEME: Theme = Theme::DEFAULT;
+
+    #[test]
+    fn palette_values() {
+        assert_eq!(THEME.background, Color::Rgb(0x1e, 0x3a, 0x8a));
+        assert_eq!(THEME.accent, Color::Rgb(0xff, 0x88, 0x00));
+        assert_eq!(THEME.text, Color::Indexed(7));
+        assert_eq!(
+            THEME.title,
+            Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        );
+    }
+
+    #[test]
+    fn palette_default() {
+        assert_eq!(Theme::default(), THEME);
+    }
+
+    #[test]
+    fn hex_color_notations() {
+        assert_eq!(hex_color("#000000"), Color::Rgb(0, 0, 0));
+        assert_eq!(hex_color("#fFfFfF"), Color::Rgb(255, 255, 255));
+        assert_eq!(hex_color("#09f"), Color::Rgb(0x00, 0x99, 0xff));
+    }
+
+    #[test]
+    #[should_panic = "hex colors must be in the #RRGGBB or #RGB notation"]
+    fn hex_color_invalid_length() {
+        hex_color("#12345");
+    }
+
+    #[test]
+    #[should_panic = "invalid hex digit in color"]
+    fn hex_color_invalid_digit() {
+        hex_color("#12345g");
+    }
+}
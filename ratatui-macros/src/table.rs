@@ -0,0 +1,84 @@
+/// A macro for creating a [`Table`] with a header, column widths and rows.
+///
+/// `table!` takes up to three sections separated by semicolons, in this order:
+///
+/// - `header: [..]` (optional) contains the cells of the header, using the [`row!`] syntax.
+/// - `widths: [..]` (optional) contains the widths of the columns, using the [`constraints!`]
+///   syntax. Without widths, the columns share the width of the table equally.
+/// - `rows: [[..], [..]]` contains the rows of the table, each one using the [`row!`] syntax.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// use ratatui_macros::{span, table};
+///
+/// let table = table![
+///     header: ["Name", "Age".bold()];
+///     widths: [==10, *=1];
+///     rows: [
+///         ["Alice", "31"],
+///         ["Bob", span!("{}", 27)],
+///     ]
+/// ];
+/// ```
+///
+/// * Create a [`Table`] without a header or widths:
+///
+/// ```rust
+/// # use ratatui_macros::table;
+/// let table = table![rows: [["hello", "world"], ["goodbye"; 2]]];
+/// ```
+///
+/// [`Table`]: ratatui_widgets::table::Table
+/// [`row!`]: crate::row
+/// [`constraints!`]: crate::constraints
+#[macro_export]
+macro_rules! table {
+    (
+        $(header: [$($header:tt)*] ;)?
+        $(widths: [$($width:tt)*] ;)?
+        rows: [$([$($cell:tt)*]),* $(,)?] $(;)?
+    ) => {
+        ::ratatui_widgets::table::Table::default()
+            .rows([$($crate::row![$($cell)*]),*])
+            $(.widths($crate::constraints![$($width)*]))?
+            $(.header($crate::row![$($header)*]))?
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::layout::Constraint;
+    use ratatui_widgets::table::{Row, Table};
+
+    #[test]
+    fn table() {
+        let table = table![
+            header: ["a", "b"];
+            widths: [==10, *=1];
+            rows: [
+                ["1", "2"],
+                ["3"; 2],
+            ]
+        ];
+        let expected = Table::new(
+            [Row::new(["1", "2"]), Row::new(["3", "3"])],
+            [Constraint::Length(10), Constraint::Fill(1)],
+        )
+        .header(Row::new(["a", "b"]));
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn table_without_header_and_widths() {
+        let table = table![rows: [["1", "2"]];];
+        assert_eq!(table, Table::default().rows([Row::new(["1", "2"])]));
+    }
+
+    #[test]
+    fn table_without_rows() {
+        let table = table![header: ["a"]; rows: []];
+        assert_eq!(table, Table::default().header(Row::new(["a"])));
+    }
+}
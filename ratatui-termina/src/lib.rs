@@ -27,9 +27,9 @@ use std::fmt::{self, Write as FmtWrite};
 use std::io::{self, Write};
 
 use ratatui_core::backend::{Backend, ClearType, WindowSize};
-use ratatui_core::buffer::Cell;
+use ratatui_core::buffer::{Cell, LineAttribute};
 use ratatui_core::layout::{Position, Size};
-use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::style::{Color, ColorSupport, Modifier, Style};
 pub use termina;
 use termina::escape::csi::{
     Csi, Cursor, DecPrivateMode, DecPrivateModeCode, Edit, EraseInDisplay, EraseInLine, Mode, Sgr,
@@ -174,6 +174,11 @@ where
                     to: cell.modifier,
                 }
                 .into_termina();
+                // overline is not one of the termina SGR modifiers, so it is written separately
+                let overlined = cell.modifier.contains(Modifier::OVERLINED);
+                if overlined != modifier.contains(Modifier::OVERLINED) {
+                    write!(string, "{}", Csi::Sgr(Sgr::Overline(overlined))).unwrap();
+                }
                 modifier = cell.modifier;
             }
             if !attributes.is_empty() {
@@ -249,6 +254,23 @@ where
         self.terminal.flush()
     }
 
+    fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) -> io::Result<()> {
+        let command = Csi::Cursor(cursor_position(Position { x: 0, y })?);
+        write!(self.terminal, "{command}{}", attribute.escape_sequence())
+    }
+
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.terminal, "{}", decset!(SynchronizedOutput))
+    }
+
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.terminal, "{}", decreset!(SynchronizedOutput))
+    }
+
+    fn color_support(&self) -> ColorSupport {
+        ColorSupport::detect(|name| std::env::var(name).ok())
+    }
+
     fn size(&self) -> io::Result<Size> {
         let size = self.terminal.get_dimensions()?;
         Ok(Size::new(size.cols, size.rows))
@@ -256,13 +278,13 @@ where
 
     fn window_size(&mut self) -> io::Result<WindowSize> {
         let size = self.terminal.get_dimensions()?;
-        Ok(WindowSize {
-            columns_rows: Size::new(size.cols, size.rows),
-            pixels: Size::new(
+        Ok(WindowSize::new(
+            Size::new(size.cols, size.rows),
+            Size::new(
                 size.pixel_width.unwrap_or_default(),
                 size.pixel_height.unwrap_or_default(),
             ),
-        })
+        ))
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -688,10 +710,7 @@ mod tests {
         assert_eq!(backend.size().unwrap(), Size::new(80, 24));
         assert_eq!(
             backend.window_size().unwrap(),
-            WindowSize {
-                columns_rows: Size::new(80, 24),
-                pixels: Size::new(800, 480),
-            }
+            WindowSize::new(Size::new(80, 24), Size::new(800, 480))
         );
     }
 
@@ -703,6 +722,17 @@ mod tests {
         assert_eq!(backend.terminal.output(), "\n\n\n");
     }
 
+    #[test]
+    fn sets_line_attribute() {
+        let mut backend = backend();
+        backend
+            .set_line_attribute(2, LineAttribute::DoubleHeightTop)
+            .unwrap();
+
+        let cursor = Csi::Cursor(cursor_position(Position::new(0, 2)).unwrap());
+        assert_eq!(backend.terminal.output(), format!("{cursor}\x1b#3"));
+    }
+
     #[test]
     fn draws_cells_with_grouped_sgr_attributes() {
         let mut backend = backend();
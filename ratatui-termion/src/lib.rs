@@ -41,9 +41,12 @@ use std::fmt;
 use std::io::{self, Write};
 
 use ratatui_core::backend::{Backend, ClearType, WindowSize};
-use ratatui_core::buffer::Cell;
+use ratatui_core::buffer::{Cell, LineAttribute};
+use ratatui_core::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui_core::layout::{Position, Size};
-use ratatui_core::style::{Color, Modifier, Style};
+#[cfg(feature = "underline-style")]
+use ratatui_core::style::UnderlineStyle;
+use ratatui_core::style::{Color, ColorSupport, Modifier, Style};
 pub use termion;
 use termion::color::Color as _;
 use termion::{color as tcolor, style as tstyle};
@@ -98,7 +101,7 @@ pub struct TermionBackend<W>
 where
     W: Write,
 {
-    writer: W,
+    writer: CountingWriter<W>,
 }
 
 impl<W> TermionBackend<W>
@@ -122,7 +125,12 @@ where
     /// let backend = TermionBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer: CountingWriter {
+                inner: writer,
+                bytes_written: 0,
+            },
+        }
     }
 
     /// Gets the writer.
@@ -131,18 +139,19 @@ where
         issue = "https://github.com/ratatui/ratatui/pull/991"
     )]
     pub const fn writer(&self) -> &W {
-        &self.writer
+        &self.writer.inner
     }
 
     /// Gets the writer as a mutable reference.
     /// Note: writing to the writer may cause incorrect output after the write. This is due to the
-    /// way that the Terminal implements diffing Buffers.
+    /// way that the Terminal implements diffing Buffers. The bytes written to the writer directly
+    /// are not counted by [`Backend::bytes_written`].
     #[instability::unstable(
         feature = "backend-writer",
         issue = "https://github.com/ratatui/ratatui/pull/991"
     )]
     pub const fn writer_mut(&mut self) -> &mut W {
-        &mut self.writer
+        &mut self.writer.inner
     }
 }
 
@@ -159,6 +168,25 @@ where
     }
 }
 
+/// A writer that counts the bytes written to the inner writer, for [`Backend::bytes_written`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+struct CountingWriter<W> {
+    inner: W,
+    bytes_written: u64,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.bytes_written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 impl<W> Backend for TermionBackend<W>
 where
     W: Write,
@@ -187,6 +215,15 @@ where
         self.writer.flush()
     }
 
+    fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) -> io::Result<()> {
+        write!(
+            self.writer,
+            "{}{}",
+            termion::cursor::Goto(1, y + 1),
+            attribute.escape_sequence()
+        )
+    }
+
     fn hide_cursor(&mut self) -> io::Result<()> {
         write!(self.writer, "{}", termion::cursor::Hide)?;
         self.writer.flush()
@@ -217,6 +254,10 @@ where
         let mut string = String::with_capacity(content.size_hint().0 * 3);
         let mut fg = Color::Reset;
         let mut bg = Color::Reset;
+        #[cfg(feature = "underline-color")]
+        let mut underline_color = Color::Reset;
+        #[cfg(feature = "underline-style")]
+        let mut underline_style = UnderlineStyle::Single;
         let mut modifier = Modifier::empty();
         let mut last_pos: Option<Position> = None;
         for (x, y, cell) in content {
@@ -235,8 +276,18 @@ where
                     }
                 )
                 .unwrap();
+                // (re)adding the underline modifier draws a single underline
+                #[cfg(feature = "underline-style")]
+                if !modifier.contains(Modifier::UNDERLINED) {
+                    underline_style = UnderlineStyle::Single;
+                }
                 modifier = cell.modifier;
             }
+            #[cfg(feature = "underline-style")]
+            if modifier.contains(Modifier::UNDERLINED) && cell.underline_style != underline_style {
+                write!(string, "{}", Underline(cell.underline_style)).unwrap();
+                underline_style = cell.underline_style;
+            }
             if cell.fg != fg {
                 write!(string, "{}", Fg(cell.fg)).unwrap();
                 fg = cell.fg;
@@ -245,6 +296,11 @@ where
                 write!(string, "{}", Bg(cell.bg)).unwrap();
                 bg = cell.bg;
             }
+            #[cfg(feature = "underline-color")]
+            if cell.underline_color != underline_color {
+                write!(string, "{}", UnderlineColor(cell.underline_color)).unwrap();
+                underline_color = cell.underline_color;
+            }
             string.push_str(cell.symbol());
         }
         write!(
@@ -256,16 +312,32 @@ where
         )
     }
 
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.writer, "\x1b[?2026h")
+    }
+
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.writer, "\x1b[?2026l")
+    }
+
+    fn color_support(&self) -> ColorSupport {
+        ColorSupport::detect(|name| std::env::var(name).ok())
+    }
+
+    fn bytes_written(&self) -> Option<u64> {
+        Some(self.writer.bytes_written)
+    }
+
     fn size(&self) -> io::Result<Size> {
         let terminal = termion::terminal_size()?;
         Ok(Size::new(terminal.0, terminal.1))
     }
 
     fn window_size(&mut self) -> io::Result<WindowSize> {
-        Ok(WindowSize {
-            columns_rows: termion::terminal_size()?.into(),
-            pixels: termion::terminal_size_pixels()?.into(),
-        })
+        Ok(WindowSize::new(
+            termion::terminal_size()?.into(),
+            termion::terminal_size_pixels()?.into(),
+        ))
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -300,6 +372,14 @@ struct Fg(Color);
 
 struct Bg(Color);
 
+/// Sets the underline color, which termion has no sequence for.
+#[cfg(feature = "underline-color")]
+struct UnderlineColor(Color);
+
+/// Sets the shape of the underline, which termion has no sequence for.
+#[cfg(feature = "underline-style")]
+struct Underline(UnderlineStyle);
+
 /// The `ModifierDiff` struct is used to calculate the difference between two `Modifier`
 /// values. This is useful when updating the terminal display, as it allows for more
 /// efficient updates by only sending the necessary changes.
@@ -359,6 +439,48 @@ impl fmt::Display for Bg {
     }
 }
 
+#[cfg(feature = "underline-color")]
+impl fmt::Display for UnderlineColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // there are no 16-color codes for the underline color, so named colors use their index
+        let index = match self.0 {
+            Color::Reset => return write!(f, "\x1B[59m"),
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::Gray => 7,
+            Color::DarkGray => 8,
+            Color::LightRed => 9,
+            Color::LightGreen => 10,
+            Color::LightYellow => 11,
+            Color::LightBlue => 12,
+            Color::LightMagenta => 13,
+            Color::LightCyan => 14,
+            Color::White => 15,
+            Color::Indexed(i) => i,
+            Color::Rgb(r, g, b) => return write!(f, "\x1B[58;2;{r};{g};{b}m"),
+        };
+        write!(f, "\x1B[58;5;{index}m")
+    }
+}
+
+#[cfg(feature = "underline-style")]
+impl fmt::Display for Underline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            UnderlineStyle::Single => write!(f, "{}", termion::style::Underline),
+            UnderlineStyle::Double => write!(f, "\x1B[4:2m"),
+            UnderlineStyle::Curly => write!(f, "\x1B[4:3m"),
+            UnderlineStyle::Dotted => write!(f, "\x1B[4:4m"),
+            UnderlineStyle::Dashed => write!(f, "\x1B[4:5m"),
+        }
+    }
+}
+
 /// A trait for converting a Termion type to a Ratatui type.
 ///
 /// This trait is necessary to avoid the orphan rule, as we cannot implement a trait for a type
@@ -453,6 +575,39 @@ impl FromTermion<tcolor::Fg<tcolor::Rgb>> for Style {
     }
 }
 
+impl FromTermion<termion::event::MouseEvent> for MouseEvent {
+    /// Converts a termion mouse event.
+    ///
+    /// Termion reports one-based coordinates, while Ratatui positions are zero-based (the same as
+    /// the `column` and `row` of crossterm mouse events), so the position can be compared with the
+    /// areas that widgets were rendered to. Termion doesn't report which button is released or
+    /// held, so releases and drags have no button.
+    fn from_termion(event: termion::event::MouseEvent) -> Self {
+        use termion::event::{MouseButton as TermionButton, MouseEvent as TermionEvent};
+
+        let (kind, x, y) = match event {
+            TermionEvent::Press(button, x, y) => {
+                let kind = match button {
+                    TermionButton::Left => MouseEventKind::Down(MouseButton::Left),
+                    TermionButton::Right => MouseEventKind::Down(MouseButton::Right),
+                    TermionButton::Middle => MouseEventKind::Down(MouseButton::Middle),
+                    TermionButton::WheelUp => MouseEventKind::ScrollUp,
+                    TermionButton::WheelDown => MouseEventKind::ScrollDown,
+                    TermionButton::WheelLeft => MouseEventKind::ScrollLeft,
+                    TermionButton::WheelRight => MouseEventKind::ScrollRight,
+                };
+                (kind, x, y)
+            }
+            TermionEvent::Release(x, y) => (MouseEventKind::Up(None), x, y),
+            TermionEvent::Hold(x, y) => (MouseEventKind::Drag(None), x, y),
+        };
+        Self::new(
+            kind,
+            Position::new(x.saturating_sub(1), y.saturating_sub(1)),
+        )
+    }
+}
+
 impl fmt::Display for ModifierDiff {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let remove = self.from - self.to;
@@ -487,6 +642,10 @@ impl fmt::Display for ModifierDiff {
         if remove.contains(Modifier::CROSSED_OUT) {
             write!(f, "{}", termion::style::NoCrossedOut)?;
         }
+        if remove.contains(Modifier::OVERLINED) {
+            // termion has no overline style, so the SGR sequence is written directly
+            write!(f, "\x1B[55m")?;
+        }
         if remove.contains(Modifier::SLOW_BLINK) || remove.contains(Modifier::RAPID_BLINK) {
             write!(f, "{}", termion::style::NoBlink)?;
         }
@@ -510,6 +669,9 @@ impl fmt::Display for ModifierDiff {
         if add.contains(Modifier::CROSSED_OUT) {
             write!(f, "{}", termion::style::CrossedOut)?;
         }
+        if add.contains(Modifier::OVERLINED) {
+            write!(f, "\x1B[53m")?;
+        }
         if add.contains(Modifier::SLOW_BLINK) || add.contains(Modifier::RAPID_BLINK) {
             write!(f, "{}", termion::style::Blink)?;
         }
@@ -598,6 +760,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_termion_mouse_event() {
+        use termion::event::{MouseButton as TermionButton, MouseEvent as TermionEvent};
+
+        assert_eq!(
+            MouseEvent::from_termion(TermionEvent::Press(TermionButton::Left, 1, 1)),
+            MouseEvent::new(MouseEventKind::Down(MouseButton::Left), Position::ORIGIN)
+        );
+        assert_eq!(
+            MouseEvent::from_termion(TermionEvent::Press(TermionButton::WheelDown, 4, 2)),
+            MouseEvent::new(MouseEventKind::ScrollDown, Position::new(3, 1))
+        );
+        assert_eq!(
+            MouseEvent::from_termion(TermionEvent::Release(10, 5)),
+            MouseEvent::new(MouseEventKind::Up(None), Position::new(9, 4))
+        );
+        assert_eq!(
+            MouseEvent::from_termion(TermionEvent::Hold(0, 0)),
+            MouseEvent::new(MouseEventKind::Drag(None), Position::ORIGIN)
+        );
+    }
+
     #[test]
     fn from_termion_bg() {
         use tc::Bg;
@@ -742,4 +926,66 @@ mod tests {
         assert_eq!(Modifier::from_termion(tstyle::Blink), Modifier::SLOW_BLINK);
         assert_eq!(Modifier::from_termion(tstyle::Reset), Modifier::empty());
     }
+
+    #[test]
+    fn modifier_diff_overlined() {
+        let add = ModifierDiff {
+            from: Modifier::empty(),
+            to: Modifier::OVERLINED,
+        };
+        assert_eq!(add.to_string(), "\x1B[53m");
+
+        let remove = ModifierDiff {
+            from: Modifier::OVERLINED,
+            to: Modifier::empty(),
+        };
+        assert_eq!(remove.to_string(), "\x1B[55m");
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn draw_underline_color() {
+        let mut backend = TermionBackend::new(Vec::new());
+        let cell = Cell::new("a")
+            .set_style(Style::new().underline_color(Color::Red))
+            .clone();
+        let rgb = Cell::new("b")
+            .set_style(Style::new().underline_color(Color::Rgb(1, 2, 3)))
+            .clone();
+        backend
+            .draw([(0, 0, &cell), (1, 0, &rgb)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert!(output.starts_with("\x1B[1;1H\x1B[58;5;1ma\x1B[58;2;1;2;3mb"));
+    }
+
+    #[cfg(feature = "underline-style")]
+    #[test]
+    fn draw_underline_style() {
+        let mut backend = TermionBackend::new(Vec::new());
+        let curly = Cell::new("a")
+            .set_style(
+                Style::new()
+                    .underlined()
+                    .underline_style(UnderlineStyle::Curly),
+            )
+            .clone();
+        let single = Cell::new("b").set_style(Style::new().underlined()).clone();
+        let plain = Cell::new("c");
+        backend
+            .draw([(0, 0, &curly), (1, 0, &single), (2, 0, &plain)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert!(output.starts_with("\x1B[1;1H\x1B[4m\x1B[4:3ma\x1B[4mb\x1B[24mc"));
+    }
+
+    #[test]
+    fn bytes_written() {
+        let mut backend = TermionBackend::new(Vec::new());
+        assert_eq!(backend.bytes_written(), Some(0));
+        backend.begin_synchronized_update().unwrap();
+        assert_eq!(backend.bytes_written(), Some(8));
+        backend.write_all(b"abc").unwrap();
+        assert_eq!(backend.bytes_written(), Some(11));
+    }
 }
@@ -42,14 +42,18 @@ use std::error::Error;
 use std::io;
 
 use ratatui_core::backend::{Backend, ClearType, WindowSize};
-use ratatui_core::buffer::Cell;
+use ratatui_core::buffer::{Cell, LineAttribute};
 use ratatui_core::layout::{Position, Size};
-use ratatui_core::style::{Color, Modifier, Style};
+#[cfg(feature = "underline-style")]
+use ratatui_core::style::UnderlineStyle;
+use ratatui_core::style::{Color, ColorSupport, Modifier, Style};
 pub use termwiz;
 use termwiz::caps::Capabilities;
-use termwiz::cell::{AttributeChange, Blink, CellAttributes, Intensity, Underline};
+use termwiz::cell::{Blink, CellAttributes, Intensity, Underline};
 use termwiz::color::{AnsiColor, ColorAttribute, ColorSpec, LinearRgba, RgbColor, SrgbaTuple};
-use termwiz::surface::{Change, CursorVisibility, Position as TermwizPosition};
+use termwiz::surface::{
+    Change, CursorVisibility, LineAttribute as TermwizLineAttribute, Position as TermwizPosition,
+};
 use termwiz::terminal::buffered::BufferedTerminal;
 use termwiz::terminal::{ScreenSize, SystemTerminal, Terminal};
 
@@ -63,9 +67,21 @@ use termwiz::terminal::{ScreenSize, SystemTerminal, Terminal};
 /// use the [`Terminal`] struct, which provides a more ergonomic interface.
 ///
 /// This backend automatically enables raw mode and switches to the alternate screen when it is
-/// created using the [`TermwizBackend::new`] method (and disables raw mode and returns to the main
-/// screen when dropped). Use the [`TermwizBackend::with_buffered_terminal`] to create a new
-/// instance with a custom [`BufferedTerminal`] if this is not desired.
+/// created using the [`TermwizBackend::new`] or [`TermwizBackend::with_terminal`] methods (and
+/// disables raw mode and returns to the main screen when dropped). Use the
+/// [`TermwizBackend::with_buffered_terminal`] to create a new instance with a custom
+/// [`BufferedTerminal`] if this is not desired.
+///
+/// The backend renders to a [`SystemTerminal`] on stdout by default. Any other implementation of
+/// [`termwiz::terminal::Terminal`] can be used instead, e.g. a [`UnixTerminal`] that writes to
+/// stderr, or a terminal that renders to an embedded pane.
+///
+/// [`Modifier::OVERLINED`] is drawn with the overline attribute of termwiz [`CellAttributes`], and
+/// is read from it when converting them to a [`Style`].
+///
+/// [`Backend::bytes_written`] returns `None` for this backend: the
+/// [`termwiz::terminal::Terminal`] encodes the changes and writes them itself without reporting
+/// the number of bytes written, so the [`FrameStats`] of its frames don't include them.
 ///
 /// # Example
 ///
@@ -87,12 +103,15 @@ use termwiz::terminal::{ScreenSize, SystemTerminal, Terminal};
 /// for more details on raw mode and alternate screen.
 ///
 /// [`backend`]: ratatui_core::backend
+/// [`FrameStats`]: ratatui_core::terminal::FrameStats
 /// [`Terminal`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html
 /// [`BufferedTerminal`]: termwiz::terminal::buffered::BufferedTerminal
+/// [`SystemTerminal`]: termwiz::terminal::SystemTerminal
+/// [`UnixTerminal`]: https://docs.rs/termwiz/latest/termwiz/terminal/struct.UnixTerminal.html
 /// [Termwiz]: https://crates.io/crates/termwiz
 /// [Examples]: https://github.com/ratatui/ratatui/tree/main/ratatui/examples/README.md
-pub struct TermwizBackend {
-    buffered_terminal: BufferedTerminal<SystemTerminal>,
+pub struct TermwizBackend<T: Terminal = SystemTerminal> {
+    buffered_terminal: BufferedTerminal<T>,
 }
 
 impl TermwizBackend {
@@ -117,101 +136,85 @@ impl TermwizBackend {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn new() -> Result<Self, Box<dyn Error>> {
-        let mut buffered_terminal =
-            BufferedTerminal::new(SystemTerminal::new(Capabilities::new_from_env()?)?)?;
+        Self::with_terminal(SystemTerminal::new(Capabilities::new_from_env()?)?)
+    }
+}
+
+impl<T: Terminal> TermwizBackend<T> {
+    /// Creates a new Termwiz backend instance that renders to the given terminal.
+    ///
+    /// Like [`TermwizBackend::new`], the backend will automatically enable raw mode and enter the
+    /// alternate screen.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if unable to do any of the following:
+    /// - enter raw mode.
+    /// - enter the alternate screen.
+    /// - create the buffered terminal.
+    ///
+    /// # Example
+    ///
+    /// Render to stderr instead of stdout:
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(unix)]
+    /// # {
+    /// use std::io::{stderr, stdin};
+    ///
+    /// use ratatui::backend::TermwizBackend;
+    /// use ratatui::termwiz::caps::Capabilities;
+    /// use ratatui::termwiz::terminal::UnixTerminal;
+    ///
+    /// let terminal = UnixTerminal::new_with(Capabilities::new_from_env()?, &stdin(), &stderr())?;
+    /// let backend = TermwizBackend::with_terminal(terminal)?;
+    /// # }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_terminal(terminal: T) -> Result<Self, Box<dyn Error>> {
+        let mut buffered_terminal = BufferedTerminal::new(terminal)?;
         buffered_terminal.terminal().set_raw_mode()?;
         buffered_terminal.terminal().enter_alternate_screen()?;
         Ok(Self { buffered_terminal })
     }
 
     /// Creates a new Termwiz backend instance with the given buffered terminal.
-    pub const fn with_buffered_terminal(instance: BufferedTerminal<SystemTerminal>) -> Self {
+    pub const fn with_buffered_terminal(instance: BufferedTerminal<T>) -> Self {
         Self {
             buffered_terminal: instance,
         }
     }
 
     /// Returns a reference to the buffered terminal used by the backend.
-    pub const fn buffered_terminal(&self) -> &BufferedTerminal<SystemTerminal> {
+    pub const fn buffered_terminal(&self) -> &BufferedTerminal<T> {
         &self.buffered_terminal
     }
 
     /// Returns a mutable reference to the buffered terminal used by the backend.
-    pub const fn buffered_terminal_mut(&mut self) -> &mut BufferedTerminal<SystemTerminal> {
+    pub const fn buffered_terminal_mut(&mut self) -> &mut BufferedTerminal<T> {
         &mut self.buffered_terminal
     }
 }
 
-impl Backend for TermwizBackend {
+impl<T: Terminal> Backend for TermwizBackend<T> {
     type Error = io::Error;
 
     fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
-        for (x, y, cell) in content {
-            self.buffered_terminal.add_changes(vec![
-                Change::CursorPosition {
-                    x: TermwizPosition::Absolute(x as usize),
-                    y: TermwizPosition::Absolute(y as usize),
-                },
-                Change::Attribute(AttributeChange::Foreground(cell.fg.into_termwiz())),
-                Change::Attribute(AttributeChange::Background(cell.bg.into_termwiz())),
-            ]);
-
-            self.buffered_terminal
-                .add_change(Change::Attribute(AttributeChange::Intensity(
-                    if cell.modifier.contains(Modifier::BOLD) {
-                        Intensity::Bold
-                    } else if cell.modifier.contains(Modifier::DIM) {
-                        Intensity::Half
-                    } else {
-                        Intensity::Normal
-                    },
-                )));
-
-            self.buffered_terminal
-                .add_change(Change::Attribute(AttributeChange::Italic(
-                    cell.modifier.contains(Modifier::ITALIC),
-                )));
-
-            self.buffered_terminal
-                .add_change(Change::Attribute(AttributeChange::Underline(
-                    if cell.modifier.contains(Modifier::UNDERLINED) {
-                        Underline::Single
-                    } else {
-                        Underline::None
-                    },
-                )));
-
-            self.buffered_terminal
-                .add_change(Change::Attribute(AttributeChange::Reverse(
-                    cell.modifier.contains(Modifier::REVERSED),
-                )));
-
-            self.buffered_terminal
-                .add_change(Change::Attribute(AttributeChange::Invisible(
-                    cell.modifier.contains(Modifier::HIDDEN),
-                )));
-
-            self.buffered_terminal
-                .add_change(Change::Attribute(AttributeChange::StrikeThrough(
-                    cell.modifier.contains(Modifier::CROSSED_OUT),
-                )));
-
-            self.buffered_terminal
-                .add_change(Change::Attribute(AttributeChange::Blink(
-                    if cell.modifier.contains(Modifier::SLOW_BLINK) {
-                        Blink::Slow
-                    } else if cell.modifier.contains(Modifier::RAPID_BLINK) {
-                        Blink::Rapid
-                    } else {
-                        Blink::None
-                    },
-                )));
+        self.buffered_terminal.add_changes(draw_changes(content));
+        Ok(())
+    }
 
-            self.buffered_terminal.add_change(cell.symbol());
-        }
+    fn set_line_attribute(&mut self, y: u16, attribute: LineAttribute) -> io::Result<()> {
+        self.buffered_terminal.add_changes(vec![
+            Change::CursorPosition {
+                x: TermwizPosition::Absolute(0),
+                y: TermwizPosition::Absolute(y as usize),
+            },
+            Change::LineAttribute(attribute.into_termwiz()),
+        ]);
         Ok(())
     }
 
@@ -250,14 +253,61 @@ impl Backend for TermwizBackend {
 
     fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
         match clear_type {
-            ClearType::All => self.clear(),
-            ClearType::AfterCursor
-            | ClearType::BeforeCursor
-            | ClearType::CurrentLine
-            | ClearType::UntilNewLine => Err(io::Error::other(format!(
-                "clear_type [{clear_type:?}] not supported with this backend"
-            ))),
+            ClearType::All => return self.clear(),
+            ClearType::AfterCursor => {
+                self.buffered_terminal
+                    .add_change(Change::ClearToEndOfScreen(ColorAttribute::Default));
+            }
+            ClearType::UntilNewLine => {
+                self.buffered_terminal
+                    .add_change(Change::ClearToEndOfLine(ColorAttribute::Default));
+            }
+            ClearType::CurrentLine => {
+                let (x, y) = self.buffered_terminal.cursor_position();
+                self.buffered_terminal.add_changes(vec![
+                    Change::CursorPosition {
+                        x: TermwizPosition::Absolute(0),
+                        y: TermwizPosition::Absolute(y),
+                    },
+                    Change::ClearToEndOfLine(ColorAttribute::Default),
+                    Change::CursorPosition {
+                        x: TermwizPosition::Absolute(x),
+                        y: TermwizPosition::Absolute(y),
+                    },
+                ]);
+            }
+            ClearType::BeforeCursor => {
+                // termwiz has no change that clears before the cursor, so the lines above the
+                // cursor are cleared and the start of the cursor line is overwritten with spaces
+                let (x, y) = self.buffered_terminal.cursor_position();
+                let mut changes = Vec::with_capacity(2 * y + 3);
+                changes.push(Change::AllAttributes(CellAttributes::default()));
+                for row in 0..y {
+                    changes.push(Change::CursorPosition {
+                        x: TermwizPosition::Absolute(0),
+                        y: TermwizPosition::Absolute(row),
+                    });
+                    changes.push(Change::ClearToEndOfLine(ColorAttribute::Default));
+                }
+                changes.extend([
+                    Change::CursorPosition {
+                        x: TermwizPosition::Absolute(0),
+                        y: TermwizPosition::Absolute(y),
+                    },
+                    Change::Text(" ".repeat(x + 1)),
+                    Change::CursorPosition {
+                        x: TermwizPosition::Absolute(x),
+                        y: TermwizPosition::Absolute(y),
+                    },
+                ]);
+                self.buffered_terminal.add_changes(changes);
+            }
         }
+        Ok(())
+    }
+
+    fn color_support(&self) -> ColorSupport {
+        ColorSupport::detect(|name| std::env::var(name).ok())
     }
 
     fn size(&self) -> io::Result<Size> {
@@ -276,16 +326,10 @@ impl Backend for TermwizBackend {
             .terminal()
             .get_screen_size()
             .map_err(io::Error::other)?;
-        Ok(WindowSize {
-            columns_rows: Size {
-                width: u16_max(cols),
-                height: u16_max(rows),
-            },
-            pixels: Size {
-                width: u16_max(xpixel),
-                height: u16_max(ypixel),
-            },
-        })
+        Ok(WindowSize::new(
+            Size::new(u16_max(cols), u16_max(rows)),
+            Size::new(u16_max(xpixel), u16_max(ypixel)),
+        ))
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -344,6 +388,11 @@ impl Backend for TermwizBackend {
 ///
 /// This trait replaces the `From` trait for converting types from Termwiz to Ratatui. It is
 /// necessary because the `From` trait is not implemented for types defined in external crates.
+///
+/// Termwiz mouse events can't be converted to a [`ratatui_core::event::MouseEvent`]: they report
+/// which buttons are held rather than which button was pressed or released, so a single event
+/// doesn't say whether it is a click, a drag or a release. Applications using this backend need to
+/// compare each event with the previous one to tell them apart.
 pub trait FromTermwiz<T> {
     /// Converts the given Termwiz type to the Ratatui type.
     fn from_termwiz(termwiz: T) -> Self;
@@ -393,6 +442,9 @@ impl FromTermwiz<CellAttributes> for Style {
         if value.strikethrough() {
             style.add_modifier |= Modifier::CROSSED_OUT;
         }
+        if value.overline() {
+            style.add_modifier |= Modifier::OVERLINED;
+        }
         if value.invisible() {
             style.add_modifier |= Modifier::HIDDEN;
         }
@@ -403,6 +455,12 @@ impl FromTermwiz<CellAttributes> for Style {
         {
             style.underline_color = Some(value.underline_color().into_ratatui());
         }
+        #[cfg(feature = "underline-style")]
+        {
+            // a single underline is implied by the underlined modifier
+            let underline_style: Option<UnderlineStyle> = value.underline().into_ratatui();
+            style.underline_style = underline_style.filter(|&s| s != UnderlineStyle::Single);
+        }
 
         style
     }
@@ -427,6 +485,33 @@ impl FromTermwiz<Underline> for Modifier {
     }
 }
 
+#[cfg(feature = "underline-style")]
+impl FromTermwiz<Underline> for Option<UnderlineStyle> {
+    fn from_termwiz(value: Underline) -> Self {
+        match value {
+            Underline::None => None,
+            Underline::Single => Some(UnderlineStyle::Single),
+            Underline::Double => Some(UnderlineStyle::Double),
+            Underline::Curly => Some(UnderlineStyle::Curly),
+            Underline::Dotted => Some(UnderlineStyle::Dotted),
+            Underline::Dashed => Some(UnderlineStyle::Dashed),
+        }
+    }
+}
+
+#[cfg(feature = "underline-style")]
+impl IntoTermwiz<Underline> for UnderlineStyle {
+    fn into_termwiz(self) -> Underline {
+        match self {
+            Self::Single => Underline::Single,
+            Self::Double => Underline::Double,
+            Self::Curly => Underline::Curly,
+            Self::Dotted => Underline::Dotted,
+            Self::Dashed => Underline::Dashed,
+        }
+    }
+}
+
 impl FromTermwiz<Blink> for Modifier {
     fn from_termwiz(value: Blink) -> Self {
         match value {
@@ -437,6 +522,17 @@ impl FromTermwiz<Blink> for Modifier {
     }
 }
 
+impl IntoTermwiz<TermwizLineAttribute> for LineAttribute {
+    fn into_termwiz(self) -> TermwizLineAttribute {
+        match self {
+            Self::Single => TermwizLineAttribute::SingleWidthLine,
+            Self::DoubleWidth => TermwizLineAttribute::DoubleWidthLine,
+            Self::DoubleHeightTop => TermwizLineAttribute::DoubleHeightTopHalfLine,
+            Self::DoubleHeightBottom => TermwizLineAttribute::DoubleHeightBottomHalfLine,
+        }
+    }
+}
+
 impl IntoTermwiz<ColorAttribute> for Color {
     fn into_termwiz(self) -> ColorAttribute {
         match self {
@@ -529,6 +625,91 @@ impl FromTermwiz<LinearRgba> for Color {
     }
 }
 
+/// Returns the changes that draw the cells.
+///
+/// The cursor is only moved when a cell doesn't follow the previous one, the attributes are only
+/// set when the style of a cell differs from the previous one, and the symbols of consecutive cells
+/// with the same style are printed as a single change.
+fn draw_changes<'a, I>(content: I) -> Vec<Change>
+where
+    I: Iterator<Item = (u16, u16, &'a Cell)>,
+{
+    let mut changes = Vec::with_capacity(content.size_hint().0);
+    let mut text = String::new();
+    let mut attributes: Option<CellAttributes> = None;
+    let mut last_pos: Option<Position> = None;
+    for (x, y, cell) in content {
+        // Move the cursor if the previous location was not (x - 1, y)
+        let is_next = matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y);
+        let cell_attributes = cell_attributes(cell);
+        let is_same_style = attributes.as_ref() == Some(&cell_attributes);
+        // consecutive cells with the same style are printed as a single change
+        let is_continued = is_next && is_same_style;
+        if !is_continued && !text.is_empty() {
+            changes.push(Change::Text(std::mem::take(&mut text)));
+        }
+        if !is_next {
+            changes.push(Change::CursorPosition {
+                x: TermwizPosition::Absolute(x as usize),
+                y: TermwizPosition::Absolute(y as usize),
+            });
+        }
+        if !is_same_style {
+            changes.push(Change::AllAttributes(cell_attributes.clone()));
+            attributes = Some(cell_attributes);
+        }
+        last_pos = Some(Position { x, y });
+        text.push_str(cell.symbol());
+    }
+    if !text.is_empty() {
+        changes.push(Change::Text(text));
+    }
+    changes
+}
+
+/// Returns the termwiz attributes that draw a cell with its style.
+fn cell_attributes(cell: &Cell) -> CellAttributes {
+    let modifier = cell.modifier;
+    let intensity = if modifier.contains(Modifier::BOLD) {
+        Intensity::Bold
+    } else if modifier.contains(Modifier::DIM) {
+        Intensity::Half
+    } else {
+        Intensity::Normal
+    };
+    let underline = if modifier.contains(Modifier::UNDERLINED) {
+        #[cfg(feature = "underline-style")]
+        let underline = cell.underline_style.into_termwiz();
+        #[cfg(not(feature = "underline-style"))]
+        let underline = Underline::Single;
+        underline
+    } else {
+        Underline::None
+    };
+    let blink = if modifier.contains(Modifier::SLOW_BLINK) {
+        Blink::Slow
+    } else if modifier.contains(Modifier::RAPID_BLINK) {
+        Blink::Rapid
+    } else {
+        Blink::None
+    };
+    let mut attributes = CellAttributes::default();
+    attributes
+        .set_foreground(cell.fg.into_termwiz())
+        .set_background(cell.bg.into_termwiz())
+        .set_intensity(intensity)
+        .set_underline(underline)
+        .set_blink(blink)
+        .set_italic(modifier.contains(Modifier::ITALIC))
+        .set_reverse(modifier.contains(Modifier::REVERSED))
+        .set_invisible(modifier.contains(Modifier::HIDDEN))
+        .set_strikethrough(modifier.contains(Modifier::CROSSED_OUT))
+        .set_overline(modifier.contains(Modifier::OVERLINED));
+    #[cfg(feature = "underline-color")]
+    attributes.set_underline_color(cell.underline_color.into_termwiz());
+    attributes
+}
+
 #[inline]
 fn u16_max(i: usize) -> u16 {
     u16::try_from(i).unwrap_or(u16::MAX)
@@ -538,6 +719,61 @@ fn u16_max(i: usize) -> u16 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn draw_changes_batches_cells() {
+        let plain = Cell::new("a");
+        let bold = Cell::new("b").set_style(Style::new().bold()).clone();
+        let content = [
+            (0, 0, &plain),
+            (1, 0, &plain),
+            (2, 0, &bold),
+            (5, 0, &bold),
+            (0, 1, &plain),
+        ];
+        let position = |x, y| Change::CursorPosition {
+            x: TermwizPosition::Absolute(x),
+            y: TermwizPosition::Absolute(y),
+        };
+        let plain_attributes = CellAttributes::default();
+        let mut bold_attributes = CellAttributes::default();
+        bold_attributes.set_intensity(Intensity::Bold);
+        assert_eq!(
+            draw_changes(content.into_iter()),
+            [
+                position(0, 0),
+                Change::AllAttributes(plain_attributes.clone()),
+                Change::Text("aa".into()),
+                Change::AllAttributes(bold_attributes),
+                Change::Text("b".into()),
+                position(5, 0),
+                Change::Text("b".into()),
+                position(0, 1),
+                Change::AllAttributes(plain_attributes),
+                Change::Text("a".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn into_line_attribute() {
+        assert_eq!(
+            LineAttribute::Single.into_termwiz(),
+            TermwizLineAttribute::SingleWidthLine
+        );
+        assert_eq!(
+            LineAttribute::DoubleWidth.into_termwiz(),
+            TermwizLineAttribute::DoubleWidthLine
+        );
+        assert_eq!(
+            LineAttribute::DoubleHeightTop.into_termwiz(),
+            TermwizLineAttribute::DoubleHeightTopHalfLine
+        );
+        assert_eq!(
+            LineAttribute::DoubleHeightBottom.into_termwiz(),
+            TermwizLineAttribute::DoubleHeightBottomHalfLine
+        );
+    }
+
     mod into_color {
         use Color as C;
 
@@ -794,6 +1030,24 @@ mod tests {
             );
         }
 
+        #[test]
+        #[cfg(feature = "underline-style")]
+        fn underline_style() {
+            assert_eq!(
+                Option::<UnderlineStyle>::from_termwiz(Underline::None),
+                None
+            );
+            for style in [
+                UnderlineStyle::Single,
+                UnderlineStyle::Double,
+                UnderlineStyle::Curly,
+                UnderlineStyle::Dotted,
+                UnderlineStyle::Dashed,
+            ] {
+                assert_eq!(Option::from_termwiz(style.into_termwiz()), Some(style));
+            }
+        }
+
         #[test]
         fn from_blink() {
             assert_eq!(Modifier::from_termwiz(Blink::None), Modifier::empty());
@@ -871,6 +1125,11 @@ mod tests {
             Style::from_termwiz(CellAttributes::default().set_strikethrough(true).to_owned()),
             STYLE.crossed_out()
         );
+        // overline
+        assert_eq!(
+            Style::from_termwiz(CellAttributes::default().set_overline(true).to_owned()),
+            STYLE.overlined()
+        );
         // hidden
         assert_eq!(
             Style::from_termwiz(CellAttributes::default().set_invisible(true).to_owned()),
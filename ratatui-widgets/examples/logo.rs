@@ -24,12 +24,12 @@ use ratatui::{DefaultTerminal, Frame, TerminalOptions, Viewport};
 
 fn main() -> Result<()> {
     color_eyre::install()?;
-    let terminal = ratatui::init_with_options(TerminalOptions {
-        viewport: Viewport::Inline(3),
-    });
+    let terminal = ratatui::init_with_options(TerminalOptions::new().viewport(Viewport::Inline(5)));
     let size = match args().nth(1).as_deref() {
         Some("small") => RatatuiLogoSize::Small,
         Some("tiny") => RatatuiLogoSize::Tiny,
+        Some("medium") => RatatuiLogoSize::Medium,
+        Some("large") => RatatuiLogoSize::Large,
         _ => RatatuiLogoSize::default(),
     };
     let result = run(terminal, size);
@@ -0,0 +1,344 @@
+//! The [`Animation`] type is used to animate values, such as the progress of a [`Gauge`] or the
+//! scroll offset of a [`List`], with an [`Easing`] curve.
+//!
+//! An animation transitions a value from a start value to a target value over a duration. It is
+//! driven by calling [`Animation::tick`] with the time elapsed since the last tick (e.g. once per
+//! frame), and the current value is read with [`Animation::value`] when rendering. Calling
+//! [`Animation::animate_to`] starts a new transition from the current value, so animations can be
+//! retargeted while they are running, e.g. to scroll smoothly while a key is held down.
+//!
+//! Any type that implements [`Interpolate`] can be animated. It is implemented for floats and
+//! integers, which covers the values of most widgets:
+//!
+//! - [`Gauge::ratio`] and [`LineGauge::ratio`] take an `f64`
+//! - [`ListState::offset_mut`] and [`TableState::offset_mut`] take a `usize`
+//! - [`ScrollbarState::position`] takes a `usize`
+//!
+//! # Example
+//!
+//! ```rust
+//! use core::time::Duration;
+//!
+//! use ratatui::widgets::{Gauge, ListState};
+//! use ratatui_widgets::animation::{Animation, Easing};
+//!
+//! let mut progress = Animation::new(0.0, Duration::from_millis(500)).easing(Easing::EaseInOut);
+//! progress.animate_to(0.8);
+//!
+//! let mut scroll = Animation::new(0, Duration::from_millis(200));
+//! scroll.animate_to(10);
+//!
+//! // on each frame
+//! let delta = Duration::from_millis(100);
+//! progress.tick(delta);
+//! scroll.tick(delta);
+//! let gauge = Gauge::default().ratio(progress.value());
+//! let mut list_state = ListState::default();
+//! *list_state.offset_mut() = scroll.value();
+//! assert_eq!(list_state.offset(), 5);
+//! ```
+//!
+//! [`Gauge`]: crate::gauge::Gauge
+//! [`Gauge::ratio`]: crate::gauge::Gauge::ratio
+//! [`LineGauge::ratio`]: crate::gauge::LineGauge::ratio
+//! [`List`]: crate::list::List
+//! [`ListState::offset_mut`]: crate::list::ListState::offset_mut
+//! [`TableState::offset_mut`]: crate::table::TableState::offset_mut
+//! [`ScrollbarState::position`]: crate::scrollbar::ScrollbarState::position
+use core::f64::consts::PI;
+use core::time::Duration;
+
+use strum::{Display, EnumString};
+
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// A curve that maps the progress of an [`Animation`] to the progress of its value.
+///
+/// The progress goes from `0.0` at the start of the animation to `1.0` at the end. Most curves stay
+/// within that range, but [`Easing::Spring`] overshoots the target before settling on it.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Easing {
+    /// The value changes at a constant speed.
+    #[default]
+    Linear,
+    /// The value starts slowly and speeds up.
+    EaseIn,
+    /// The value starts quickly and slows down towards the target.
+    EaseOut,
+    /// The value starts slowly, speeds up and slows down towards the target.
+    EaseInOut,
+    /// The value overshoots the target and oscillates around it before settling, like a spring.
+    Spring,
+}
+
+impl Easing {
+    /// Returns the eased progress for the linear progress `t`, which is clamped to `0.0..=1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_widgets::animation::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    /// assert_eq!(Easing::EaseIn.apply(0.5), 0.125);
+    /// assert_eq!(Easing::EaseInOut.apply(1.0), 1.0);
+    /// ```
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t) * (1.0 - t) * (1.0 - t),
+            Self::EaseInOut if t < 0.5 => 4.0 * t * t * t,
+            Self::EaseInOut => 1.0 - 4.0 * (1.0 - t) * (1.0 - t) * (1.0 - t),
+            // a decaying oscillation that is exactly 0 at the start and 1 at the end
+            Self::Spring => 1.0 - (1.0 - t) * (1.0 - t) * (1.0 - t) * (2.5 * PI * t).cos(),
+        }
+    }
+}
+
+/// A value that can be animated by an [`Animation`].
+pub trait Interpolate: Copy {
+    /// Returns the value at `t` between `self` (at `0.0`) and `to` (at `1.0`).
+    ///
+    /// `t` can be outside `0.0..=1.0` when an [`Easing`] overshoots the target.
+    #[must_use]
+    fn interpolate(self, to: Self, t: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(self, to: Self, t: f64) -> Self {
+        (to - self).mul_add(t, self)
+    }
+}
+
+impl Interpolate for f32 {
+    #[expect(clippy::cast_possible_truncation)]
+    fn interpolate(self, to: Self, t: f64) -> Self {
+        f64::from(self).interpolate(f64::from(to), t) as Self
+    }
+}
+
+macro_rules! interpolate_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Interpolate for $ty {
+                /// Interpolates the value and rounds it to the nearest integer, saturating at the
+                /// bounds of the type.
+                #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+                fn interpolate(self, to: Self, t: f64) -> Self {
+                    (self as f64).interpolate(to as f64, t).round() as Self
+                }
+            }
+        )*
+    };
+}
+
+interpolate_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// An animated value that transitions to a target value with an [`Easing`] curve.
+///
+/// The animation is driven by [`Animation::tick`], so it doesn't depend on a clock and can be used
+/// in `no_std` environments and tests. See the [module documentation](crate::animation) for more
+/// details.
+///
+/// # Example
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use ratatui_widgets::animation::{Animation, Easing};
+///
+/// let mut animation = Animation::new(0.0, Duration::from_secs(1)).easing(Easing::EaseOut);
+/// animation.animate_to(100.0);
+/// animation.tick(Duration::from_millis(500));
+/// assert_eq!(animation.value(), 87.5);
+/// animation.tick(Duration::from_millis(500));
+/// assert_eq!(animation.value(), 100.0);
+/// assert!(animation.is_finished());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+}
+
+impl<T: Interpolate> Animation<T> {
+    /// Creates a finished animation at `value`, whose transitions take `duration`.
+    pub const fn new(value: T, duration: Duration) -> Self {
+        Self {
+            from: value,
+            to: value,
+            duration,
+            elapsed: duration,
+            easing: Easing::Linear,
+        }
+    }
+
+    /// Sets the [`Easing`] curve of the transitions. Defaults to [`Easing::Linear`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the duration of the transitions.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        if self.is_finished() {
+            self.elapsed = duration;
+        }
+        self.duration = duration;
+        self
+    }
+
+    /// Starts a transition from the current value to `to`.
+    ///
+    /// If a transition is running, the new one starts from the value it has reached.
+    pub fn animate_to(&mut self, to: T) {
+        self.from = self.value();
+        self.to = to;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Sets the value immediately, finishing any running transition.
+    pub const fn set(&mut self, value: T) {
+        self.from = value;
+        self.to = value;
+        self.elapsed = self.duration;
+    }
+
+    /// Advances the animation by `delta`, the time elapsed since the last tick.
+    pub fn tick(&mut self, delta: Duration) {
+        self.elapsed = self.elapsed.saturating_add(delta).min(self.duration);
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> T {
+        if self.is_finished() {
+            return self.to;
+        }
+        self.from
+            .interpolate(self.to, self.easing.apply(self.progress()))
+    }
+
+    /// Returns the value the animation transitions to.
+    pub const fn target(&self) -> T {
+        self.to
+    }
+
+    /// Returns the progress of the transition, from `0.0` at the start to `1.0` when finished.
+    pub fn progress(&self) -> f64 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        self.elapsed.as_secs_f64() / self.duration.as_secs_f64()
+    }
+
+    /// Returns whether the transition is finished.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::linear(Easing::Linear)]
+    #[case::ease_in(Easing::EaseIn)]
+    #[case::ease_out(Easing::EaseOut)]
+    #[case::ease_in_out(Easing::EaseInOut)]
+    #[case::spring(Easing::Spring)]
+    fn easing_bounds(#[case] easing: Easing) {
+        assert!(easing.apply(0.0).abs() < f64::EPSILON);
+        assert!((easing.apply(1.0) - 1.0).abs() < f64::EPSILON);
+        assert!((easing.apply(2.0) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn ease_in_out_is_symmetric() {
+        let easing = Easing::EaseInOut;
+        assert!((easing.apply(0.5) - 0.5).abs() < f64::EPSILON);
+        assert!((easing.apply(0.25) + easing.apply(0.75) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn spring_overshoots() {
+        assert!(Easing::Spring.apply(0.5) > 1.0);
+    }
+
+    #[test]
+    fn interpolate_integers() {
+        assert_eq!(0_usize.interpolate(10, 0.26), 3);
+        assert_eq!(10_u16.interpolate(0, 0.5), 5);
+        assert_eq!(0_u8.interpolate(255, 1.5), 255);
+        assert_eq!(5_usize.interpolate(0, 1.5), 0);
+        assert_eq!((-4_i32).interpolate(4, 0.25), -2);
+    }
+
+    #[test]
+    fn animate_to() {
+        let mut animation = Animation::new(0_usize, Duration::from_millis(100));
+        assert!(animation.is_finished());
+        animation.animate_to(10);
+        assert_eq!(animation.value(), 0);
+        assert_eq!(animation.target(), 10);
+
+        animation.tick(Duration::from_millis(40));
+        assert_eq!(animation.value(), 4);
+        assert!(!animation.is_finished());
+
+        // retargeting starts from the current value
+        animation.animate_to(0);
+        assert_eq!(animation.value(), 4);
+        animation.tick(Duration::from_millis(50));
+        assert_eq!(animation.value(), 2);
+
+        animation.tick(Duration::from_secs(1));
+        assert_eq!(animation.value(), 0);
+        assert!((animation.progress() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn set_finishes_transition() {
+        let mut animation = Animation::new(0.0_f64, Duration::from_secs(1));
+        animation.animate_to(1.0);
+        animation.set(0.5);
+        assert!(animation.is_finished());
+        assert!((animation.value() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn longer_duration_stays_finished() {
+        let animation = Animation::new(3_u8, Duration::ZERO).duration(Duration::from_secs(1));
+        assert!(animation.is_finished());
+        assert_eq!(animation.value(), 3);
+    }
+
+    #[test]
+    fn zero_duration() {
+        let mut animation = Animation::new(1_u16, Duration::ZERO);
+        animation.animate_to(5);
+        assert_eq!(animation.value(), 5);
+    }
+
+    #[test]
+    fn easing_from_str() {
+        assert_eq!("EaseInOut".parse::<Easing>(), Ok(Easing::EaseInOut));
+        assert_eq!(Easing::Spring.to_string(), "Spring");
+    }
+}
@@ -55,18 +55,24 @@ macro_rules! impl_as_ref {
     };
 }
 
+impl_as_ref!(crate::backdrop::Backdrop);
 impl_as_ref!(crate::barchart::BarChart<'a>, <'a>);
+#[cfg(feature = "big-text")]
+impl_as_ref!(crate::big_text::BigText<'a>, <'a>);
 impl_as_ref!(crate::block::Block<'a>, <'a>);
 impl_as_ref!(crate::canvas::Canvas<'a, F>, <'a, F> where F: Fn(&mut crate::canvas::Context));
 impl_as_ref!(crate::chart::Chart<'a>, <'a>);
 impl_as_ref!(crate::clear::Clear);
+impl_as_ref!(crate::fps::FpsWidget);
 impl_as_ref!(crate::gauge::Gauge<'a>, <'a>);
 impl_as_ref!(crate::gauge::LineGauge<'a>, <'a>);
+impl_as_ref!(crate::input::TextInput<'a>, <'a>);
 impl_as_ref!(crate::list::List<'a>, <'a>);
 impl_as_ref!(crate::logo::RatatuiLogo);
 impl_as_ref!(crate::mascot::RatatuiMascot);
 impl_as_ref!(crate::paragraph::Paragraph<'a>, <'a>);
 impl_as_ref!(crate::scrollbar::Scrollbar<'a>, <'a>);
+impl_as_ref!(crate::separator::Separator<'a>, <'a>);
 impl_as_ref!(crate::sparkline::Sparkline<'a>, <'a>);
 impl_as_ref!(crate::table::Table<'a>, <'a>);
 impl_as_ref!(crate::tabs::Tabs<'a>, <'a>);
@@ -82,23 +88,33 @@ mod tests {
 
     #[test]
     fn widgets_implement_as_ref() {
+        let _ = crate::backdrop::Backdrop::new().as_ref();
         let _ = crate::barchart::BarChart::default().as_ref();
         let _ = crate::block::Block::new().as_ref();
         let _ = crate::canvas::Canvas::default().paint(|_| {}).as_ref();
         let _ = crate::chart::Chart::new(vec![]).as_ref();
         let _ = crate::clear::Clear.as_ref();
+        let _ = crate::fps::FpsWidget::default().as_ref();
         let _ = crate::gauge::Gauge::default().as_ref();
         let _ = crate::gauge::LineGauge::default().as_ref();
+        let _ = crate::input::TextInput::new().as_ref();
         let _ = crate::list::List::new(["foo"]).as_ref();
         let _ = crate::logo::RatatuiLogo::default().as_ref();
         let _ = crate::mascot::RatatuiMascot::default().as_ref();
         let _ = crate::paragraph::Paragraph::new("").as_ref();
         let _ = crate::scrollbar::Scrollbar::default().as_ref();
+        let _ = crate::separator::Separator::vertical().as_ref();
         let _ = crate::sparkline::Sparkline::default().as_ref();
         let _ = crate::table::Table::default().as_ref();
         let _ = crate::tabs::Tabs::default().as_ref();
     }
 
+    #[cfg(feature = "big-text")]
+    #[test]
+    fn big_text_widget_implements_as_ref() {
+        let _ = crate::big_text::BigText::new("").as_ref();
+    }
+
     #[cfg(feature = "calendar")]
     #[test]
     fn calendar_widget_implements_as_ref() {
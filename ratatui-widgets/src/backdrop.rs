@@ -0,0 +1,182 @@
+//! The [`Backdrop`] widget dims the content behind a modal, such as a popup or a dialog.
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Modifier, Style, Styled};
+use ratatui_core::widgets::Widget;
+
+/// A widget that dims and desaturates the content already rendered in its area.
+///
+/// Unlike [`Clear`], which blanks the area, `Backdrop` keeps the symbols and colors of the cells
+/// but fades them, so the content behind a modal stays visible while the focus moves to the
+/// modal. It is usually rendered over the whole frame, before the modal is rendered on top.
+///
+/// By default the backdrop dims the cells: the [`DIM`](Modifier::DIM) modifier is added, which
+/// terminals apply to the foreground, and RGB backgrounds are darkened to half their brightness.
+/// [`Backdrop::desaturate`] also turns RGB colors to gray. The [style](Backdrop::style) of the
+/// backdrop is added to the cells after these effects.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::Frame;
+/// use ratatui::layout::{Constraint, Flex, Layout, Rect};
+/// use ratatui::widgets::{Backdrop, Block, Clear};
+///
+/// fn draw_modal(frame: &mut Frame) {
+///     let area = frame.area();
+///     let [modal] = Layout::vertical([Constraint::Length(5)])
+///         .flex(Flex::Center)
+///         .areas(area);
+///
+///     frame.render_widget(Backdrop::new().desaturate(true), area);
+///     frame.render_widget(Clear, modal);
+///     frame.render_widget(Block::bordered().title("Confirm"), modal);
+/// }
+/// ```
+///
+/// [`Clear`]: crate::clear::Clear
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Backdrop {
+    dim: bool,
+    desaturate: bool,
+    style: Style,
+}
+
+impl Default for Backdrop {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Backdrop {
+    /// Creates a new `Backdrop` that dims the cells.
+    pub const fn new() -> Self {
+        Self {
+            dim: true,
+            desaturate: false,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets whether the cells are dimmed
+    ///
+    /// Dimmed cells get the [`DIM`](Modifier::DIM) modifier, and their RGB background is darkened
+    /// to half its brightness. Enabled by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn dim(mut self, dim: bool) -> Self {
+        self.dim = dim;
+        self
+    }
+
+    /// Sets whether the RGB colors of the cells are turned to gray
+    ///
+    /// Named and indexed colors are left unchanged, as their actual color depends on the terminal.
+    /// Disabled by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn desaturate(mut self, desaturate: bool) -> Self {
+        self.desaturate = desaturate;
+        self
+    }
+
+    /// Sets the style added to the cells after dimming and desaturating them
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Widget for &Backdrop {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (*self).render(area, buf);
+    }
+}
+
+impl Widget for Backdrop {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(*buf.area());
+        for position in area.positions() {
+            let cell = &mut buf[position];
+            if self.desaturate {
+                cell.fg = gray(cell.fg);
+                cell.bg = gray(cell.bg);
+            }
+            if self.dim {
+                cell.modifier.insert(Modifier::DIM);
+                if let Color::Rgb(r, g, b) = cell.bg {
+                    cell.bg = Color::Rgb(r / 2, g / 2, b / 2);
+                }
+            }
+            cell.set_style(self.style);
+        }
+    }
+}
+
+impl Styled for Backdrop {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// Returns the gray with the same luma as the color, if it is an RGB color.
+fn gray(color: Color) -> Color {
+    let Color::Rgb(r, g, b) = color else {
+        return color;
+    };
+    let luma = (u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114) / 1000;
+    let luma = luma as u8;
+    Color::Rgb(luma, luma, luma)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn render_dims_cells() {
+        let mut buffer = Buffer::with_lines(["abc"]);
+        buffer.set_style(buffer.area, Style::new().red().bg(Color::Rgb(100, 50, 200)));
+        Backdrop::new().render(Rect::new(1, 0, 5, 1), &mut buffer);
+
+        assert_eq!(buffer[(0, 0)].modifier, Modifier::empty());
+        assert_eq!(buffer[(1, 0)].symbol(), "b");
+        assert_eq!(buffer[(1, 0)].modifier, Modifier::DIM);
+        assert_eq!(buffer[(1, 0)].fg, Color::Red);
+        assert_eq!(buffer[(2, 0)].bg, Color::Rgb(50, 25, 100));
+    }
+
+    #[test]
+    fn render_desaturates_cells() {
+        let mut buffer = Buffer::with_lines(["ab"]);
+        buffer.set_style(
+            buffer.area,
+            Style::new().fg(Color::Rgb(255, 0, 0)).on_blue(),
+        );
+        Backdrop::new()
+            .dim(false)
+            .desaturate(true)
+            .italic()
+            .render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer[(0, 0)].fg, Color::Rgb(76, 76, 76));
+        assert_eq!(buffer[(0, 0)].bg, Color::Blue);
+        assert_eq!(buffer[(1, 0)].modifier, Modifier::ITALIC);
+    }
+}
@@ -1,7 +1,10 @@
 //! The [`BarChart`] widget and its related types (e.g. [`Bar`], [`BarGroup`]).
 
-use alloc::vec;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::panic::{RefUnwindSafe, UnwindSafe};
 
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Direction, Rect};
@@ -13,6 +16,7 @@ use ratatui_core::widgets::Widget;
 pub use self::bar::Bar;
 pub use self::bar_group::BarGroup;
 use crate::block::{Block, BlockExt};
+use crate::shared_fn::SharedFn;
 
 mod bar;
 mod bar_group;
@@ -44,6 +48,10 @@ mod bar_group;
 /// The chart can have a [`Direction`] (by default the bars are [`Vertical`](Direction::Vertical)).
 /// This is set using [`BarChart::direction`].
 ///
+/// Bars can have a negative value, set using [`Bar::signed_value`]. In this case the chart has a
+/// baseline, placed so that the largest positive and negative values fit, and the negative bars
+/// extend down (or left) from it.
+///
 /// Note: this is the only widget that doesn't implement `Widget` for `&T` because the current
 /// implementation modifies the internal state of self. This will be fixed in the future.
 ///
@@ -106,6 +114,54 @@ pub struct BarChart<'a> {
     max: Option<u64>,
     /// direction of the bars
     direction: Direction,
+    /// How the values of the bars without a text value are formatted
+    value_format: ValueFormat<'a>,
+}
+
+/// Formats the values of the bars of a [`BarChart`] at render time.
+///
+/// This is implemented for closures that take the value of a bar and return its text, see
+/// [`BarChart::value_formatter`]. Like the widgets that store them, implementations must be
+/// [`Send`], [`Sync`], [`UnwindSafe`], and [`RefUnwindSafe`].
+pub trait ValueFormatter: Send + Sync + UnwindSafe + RefUnwindSafe {
+    /// Returns the text shown for the given value.
+    fn format_value(&self, value: u64) -> String;
+}
+
+impl<F> ValueFormatter for F
+where
+    F: Fn(u64) -> String + Send + Sync + UnwindSafe + RefUnwindSafe,
+{
+    fn format_value(&self, value: u64) -> String {
+        self(value)
+    }
+}
+
+/// How the values of the bars are formatted when they don't have a text value.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+enum ValueFormat<'a> {
+    /// The value as a number
+    #[default]
+    Number,
+    /// The value as a percentage of the maximum value of the chart
+    Percentage,
+    /// The value formatted by a user defined function, shared by the clones of the chart so that
+    /// cloning it doesn't require the formatter to implement `Clone`
+    Custom(SharedFn<dyn ValueFormatter + 'a>),
+}
+
+impl ValueFormat<'_> {
+    fn format(&self, value: u64, max: u64) -> String {
+        match self {
+            Self::Number => value.to_string(),
+            Self::Percentage => {
+                let max = u128::from(max.max(1));
+                let percent = (u128::from(value) * 100 + max / 2) / max;
+                format!("{percent}%")
+            }
+            Self::Custom(formatter) => formatter.0.format_value(value),
+        }
+    }
 }
 
 impl Default for BarChart<'_> {
@@ -123,6 +179,7 @@ impl Default for BarChart<'_> {
             bar_set: symbols::bar::NINE_LEVELS,
             style: Style::default(),
             direction: Direction::Vertical,
+            value_format: ValueFormat::Number,
         }
     }
 }
@@ -355,6 +412,56 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Sets a function that formats the values shown on the bars, e.g. to show units.
+    ///
+    /// The function receives the value of each bar that doesn't have a
+    /// [`text_value`](Bar::text_value), so the text doesn't need to be computed for every bar when
+    /// the data is built. This replaces the [percentage mode](BarChart::value_percentage).
+    ///
+    /// Any type that implements [`ValueFormatter`] can be used instead of a closure.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::BarChart;
+    ///
+    /// let chart = BarChart::default()
+    ///     .data(&[("disk", 1288490188), ("ram", 536870912)])
+    ///     .value_formatter(|bytes| format!("{:.1} GiB", bytes as f64 / 1073741824.0));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_formatter<F: ValueFormatter + 'a>(mut self, formatter: F) -> Self {
+        self.value_format = ValueFormat::Custom(SharedFn(Arc::new(formatter)));
+        self
+    }
+
+    /// Shows the values of the bars as a percentage of the maximum value of the chart.
+    ///
+    /// The maximum value is the one set with [`BarChart::max`], or the largest value in the data
+    /// if it is not set, so a full bar shows `100%`. Percentages are rounded to the nearest
+    /// integer. Bars with a [`text_value`](Bar::text_value) still show it. This replaces the
+    /// [value formatter](BarChart::value_formatter).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::BarChart;
+    ///
+    /// // shows "45%" and "100%"
+    /// let chart = BarChart::default()
+    ///     .data(&[("used", 45), ("total", 100)])
+    ///     .value_percentage();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_percentage(mut self) -> Self {
+        self.value_format = ValueFormat::Percentage;
+        self
+    }
+
     /// Set the default label style of the groups and bars.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -431,9 +538,17 @@ struct LabelInfo {
 impl BarChart<'_> {
     /// Returns the visible bars length in ticks. A cell contains 8 ticks.
     /// `available_space` used to calculate how many bars can fit in the space
-    /// `bar_max_length` is the maximal length a bar can take.
-    fn group_ticks(&self, available_space: u16, bar_max_length: u16) -> Vec<Vec<u64>> {
+    /// `bar_max_length` is the maximal length a bar above the baseline can take, and
+    /// `negative_max_length` the maximal length a bar below the baseline can take.
+    /// Negative bars are rounded to whole cells.
+    fn group_ticks(
+        &self,
+        available_space: u16,
+        bar_max_length: u16,
+        negative_max_length: u16,
+    ) -> Vec<Vec<u64>> {
         let max: u64 = self.maximum_data_value();
+        let negative_max = self.negative_extent();
         self.data
             .iter()
             .scan(available_space, |space, group| {
@@ -461,13 +576,34 @@ impl BarChart<'_> {
                         .bars
                         .iter()
                         .take(n as usize)
-                        .map(|bar| Self::scale_ticks(bar.value, max, bar_max_length))
+                        .map(|bar| {
+                            if bar.negative {
+                                let ticks =
+                                    Self::scale_ticks(bar.value, negative_max, negative_max_length);
+                                (ticks + 4) / 8 * 8
+                            } else {
+                                Self::scale_ticks(bar.value, max, bar_max_length)
+                            }
+                        })
                         .collect()
                 })
             })
             .collect()
     }
 
+    /// Splits the length of the bars area between the bars above and below the baseline, in
+    /// proportion to the largest positive and negative values. Returns `(positive, negative)`.
+    fn split_length(&self, length: u16) -> (u16, u16) {
+        let negative = u128::from(self.negative_extent());
+        if negative == 0 {
+            return (length, 0);
+        }
+        let total = u128::from(self.positive_extent()) + negative;
+        let negative_length = (u128::from(length) * negative + total / 2) / total;
+        let negative_length = negative_length as u16;
+        (length - negative_length, negative_length)
+    }
+
     fn scale_ticks(value: u64, max: u64, max_length: u16) -> u64 {
         let max_ticks = u128::from(max_length) * 8;
         let ticks = u128::from(value) * max_ticks / u128::from(max);
@@ -534,7 +670,11 @@ impl BarChart<'_> {
             }
         };
 
-        let group_ticks = self.group_ticks(bars_area.height, bars_area.width);
+        let (positive_width, negative_width) = self.split_length(bars_area.width);
+        let baseline = bars_area.left() + negative_width;
+        let group_ticks = self.group_ticks(bars_area.height, positive_width, negative_width);
+        let max = self.maximum_data_value();
+        let negative_max = self.negative_extent();
 
         // print all visible bars, label and values
         let mut bar_y = bars_area.top();
@@ -543,24 +683,40 @@ impl BarChart<'_> {
                 let bar_length = (ticks / 8) as u16;
                 let bar_style = self.bar_style.patch(bar.style);
 
+                // negative bars extend to the left of the baseline
+                let full_cells = if bar.negative {
+                    baseline.saturating_sub(bar_length)..baseline
+                } else {
+                    baseline..baseline.saturating_add(bar_length)
+                };
+
                 for y in 0..self.bar_width {
-                    let bar_y = bar_y + y;
-                    for x in 0..bars_area.width {
-                        let symbol = if x < bar_length {
+                    let columns = bars_area.left()..bars_area.right();
+                    for (x, cell) in columns.clone().zip(buf.row_cells_mut(bar_y + y, columns)) {
+                        let symbol = if full_cells.contains(&x) {
                             self.bar_set.full
                         } else {
                             self.bar_set.empty
                         };
-                        buf[(bars_area.left() + x, bar_y)]
-                            .set_symbol(symbol)
-                            .set_style(bar_style);
+                        cell.set_symbol(symbol).set_style(bar_style);
                     }
                 }
 
-                let bar_value_area = Rect {
-                    y: bar_y + (self.bar_width >> 1),
-                    ..bars_area
+                let bar_value_area = if bar.negative {
+                    Rect {
+                        y: bar_y + (self.bar_width >> 1),
+                        width: negative_width,
+                        ..bars_area
+                    }
+                } else {
+                    Rect {
+                        x: baseline,
+                        y: bar_y + (self.bar_width >> 1),
+                        width: positive_width,
+                        ..bars_area
+                    }
                 };
+                let value_max = if bar.negative { negative_max } else { max };
 
                 // label
                 if let Some(label) = &bar.label {
@@ -573,6 +729,7 @@ impl BarChart<'_> {
                     bar_length as usize,
                     self.value_style,
                     self.bar_style,
+                    &bar.value_text(&self.value_format, value_max),
                 );
 
                 bar_y += self.bar_gap + self.bar_width;
@@ -600,39 +757,46 @@ impl BarChart<'_> {
             ..area
         };
 
-        let group_ticks = self.group_ticks(bars_area.width, bars_area.height);
-        self.render_vertical_bars(bars_area, buf, &group_ticks);
-        self.render_labels_and_values(area, buf, label_info, &group_ticks);
+        let (positive_height, negative_height) = self.split_length(bars_area.height);
+        let group_ticks = self.group_ticks(bars_area.width, positive_height, negative_height);
+        let baseline = bars_area.top() + positive_height;
+        self.render_vertical_bars(bars_area, buf, &group_ticks, baseline);
+        self.render_labels_and_values(area, buf, label_info, &group_ticks, baseline);
     }
 
-    fn render_vertical_bars(&self, area: Rect, buf: &mut Buffer, group_ticks: &[Vec<u64>]) {
+    /// `baseline` is the first row below the bars with a positive value.
+    fn render_vertical_bars(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        group_ticks: &[Vec<u64>],
+        baseline: u16,
+    ) {
         // print all visible bars (without labels and values)
         let mut bar_x = area.left();
         for (ticks_vec, group) in group_ticks.iter().zip(&self.data) {
             for (ticks, bar) in ticks_vec.iter().zip(&group.bars) {
-                let mut ticks = *ticks;
-                for j in (0..area.height).rev() {
-                    let symbol = match ticks {
-                        0 => self.bar_set.empty,
-                        1 => self.bar_set.one_eighth,
-                        2 => self.bar_set.one_quarter,
-                        3 => self.bar_set.three_eighths,
-                        4 => self.bar_set.half,
-                        5 => self.bar_set.five_eighths,
-                        6 => self.bar_set.three_quarters,
-                        7 => self.bar_set.seven_eighths,
-                        _ => self.bar_set.full,
-                    };
-
-                    let bar_style = self.bar_style.patch(bar.style);
-
-                    for x in 0..self.bar_width {
-                        buf[(bar_x + x, area.top() + j)]
-                            .set_symbol(symbol)
-                            .set_style(bar_style);
+                let (mut positive_ticks, mut negative_ticks) = if bar.negative {
+                    (0, *ticks)
+                } else {
+                    (*ticks, 0)
+                };
+                let bar_style = self.bar_style.patch(bar.style);
+                let columns = bar_x..bar_x.saturating_add(self.bar_width);
+                // positive bars grow up from the baseline, negative bars grow down from it
+                for y in (area.top()..baseline).rev() {
+                    let symbol = self.bar_symbol(positive_ticks);
+                    for cell in buf.row_cells_mut(y, columns.clone()) {
+                        cell.set_symbol(symbol).set_style(bar_style);
                     }
-
-                    ticks = ticks.saturating_sub(8);
+                    positive_ticks = positive_ticks.saturating_sub(8);
+                }
+                for y in baseline..area.bottom() {
+                    let symbol = self.bar_symbol(negative_ticks);
+                    for cell in buf.row_cells_mut(y, columns.clone()) {
+                        cell.set_symbol(symbol).set_style(bar_style);
+                    }
+                    negative_ticks = negative_ticks.saturating_sub(8);
                 }
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -640,17 +804,45 @@ impl BarChart<'_> {
         }
     }
 
+    /// Returns the symbol of a bar cell filled with the given number of ticks.
+    const fn bar_symbol(&self, ticks: u64) -> &str {
+        match ticks {
+            0 => self.bar_set.empty,
+            1 => self.bar_set.one_eighth,
+            2 => self.bar_set.one_quarter,
+            3 => self.bar_set.three_eighths,
+            4 => self.bar_set.half,
+            5 => self.bar_set.five_eighths,
+            6 => self.bar_set.three_quarters,
+            7 => self.bar_set.seven_eighths,
+            _ => self.bar_set.full,
+        }
+    }
+
     /// get the maximum data value. the returned value is always greater equal 1
     fn maximum_data_value(&self) -> u64 {
-        self.max
-            .unwrap_or_else(|| {
-                self.data
-                    .iter()
-                    .map(|group| group.max().unwrap_or_default())
-                    .max()
-                    .unwrap_or_default()
-            })
-            .max(1)
+        self.positive_extent().max(1)
+    }
+
+    /// get the value a bar needs to reach from the baseline to the end of the chart: the `max`
+    /// value if set, or else the maximum positive data value
+    fn positive_extent(&self) -> u64 {
+        self.max.unwrap_or_else(|| {
+            self.data
+                .iter()
+                .map(|group| group.max().unwrap_or_default())
+                .max()
+                .unwrap_or_default()
+        })
+    }
+
+    /// get the largest magnitude of the negative data values, or 0 if there are none
+    fn negative_extent(&self) -> u64 {
+        self.data
+            .iter()
+            .map(|group| group.max_negative().unwrap_or_default())
+            .max()
+            .unwrap_or_default()
     }
 
     fn render_labels_and_values(
@@ -659,10 +851,13 @@ impl BarChart<'_> {
         buf: &mut Buffer,
         label_info: LabelInfo,
         group_ticks: &[Vec<u64>],
+        baseline: u16,
     ) {
         // print labels and values in one go
+        let max = self.maximum_data_value();
+        let negative_max = self.negative_extent();
         let mut bar_x = area.left();
-        let bar_y = area.bottom() - label_info.height - 1;
+        let label_y = area.bottom() - label_info.height;
         for (group, ticks_vec) in self.data.iter().zip(group_ticks) {
             if group.bars.is_empty() {
                 continue;
@@ -683,10 +878,24 @@ impl BarChart<'_> {
             // print the bar values and numbers
             for (bar, ticks) in group.bars.iter().zip(ticks_vec) {
                 if label_info.bar_label_visible {
-                    bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
+                    bar.render_label(buf, self.bar_width, bar_x, label_y, self.label_style);
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                // values are printed next to the baseline, inside the bars
+                let (value_y, value_max) = if bar.negative {
+                    (Some(baseline).filter(|&y| y < label_y), negative_max)
+                } else {
+                    (baseline.checked_sub(1).filter(|&y| y >= area.top()), max)
+                };
+                if let Some(value_y) = value_y {
+                    bar.render_value(
+                        buf,
+                        Rect::new(bar_x, value_y, self.bar_width, 1),
+                        self.value_style,
+                        *ticks,
+                        &bar.value_text(&self.value_format, value_max),
+                    );
+                }
 
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -1462,6 +1671,75 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn value_formatter() {
+        let chart = BarChart::default()
+            .data(&[("a", 1), ("b", 2)])
+            .data(BarGroup::new([Bar::new(2).text_value("x")]))
+            .bar_width(3)
+            .value_formatter(|value| format!("{value}k"));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 3));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["    ███ ███", "1k█ 2k█ █x█", " a   b     "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn value_percentage() {
+        let chart = BarChart::default()
+            .data(&[("a", 1), ("b", 2), ("c", 4)])
+            .bar_gap(0)
+            .direction(Direction::Horizontal)
+            .value_percentage();
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["a 25%     ", "b 50%█    ", "c 100%████"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn negative_values() {
+        let chart = BarChart::default()
+            .data(BarGroup::new([
+                Bar::with_label("a", 0).signed_value(4),
+                Bar::with_label("b", 0).signed_value(-2),
+                Bar::with_label("c", 0).signed_value(-1),
+            ]))
+            .bar_width(2);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 7));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "██      ",
+            "██      ",
+            "██      ",
+            "4█      ",
+            "   -2 -1",
+            "   ██   ",
+            "a  b  c ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn negative_values_horizontal() {
+        let chart = BarChart::default()
+            .data(BarGroup::new([
+                Bar::with_label("a", 0).signed_value(4),
+                Bar::with_label("b", 0).signed_value(-2),
+                Bar::with_label("c", 0).signed_value(-1),
+            ]))
+            .bar_gap(0)
+            .direction(Direction::Horizontal);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["a   4███", "b -2    ", "c -1    "]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn render_handles_u64_max_value() {
         let chart = BarChart::new([Bar::new(u64::MAX)]).max(u64::MAX);
@@ -1,4 +1,6 @@
-use alloc::string::{String, ToString};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
 
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
@@ -7,6 +9,8 @@ use ratatui_core::text::Line;
 use ratatui_core::widgets::Widget;
 use unicode_width::UnicodeWidthStr;
 
+use super::ValueFormat;
+
 /// A bar to be shown by the [`BarChart`](super::BarChart) widget.
 ///
 /// Here is an explanation of a `Bar`'s components.
@@ -42,6 +46,8 @@ pub struct Bar<'a> {
     pub(super) value_style: Style,
     /// optional `text_value` to be shown on the bar instead of the actual value
     pub(super) text_value: Option<String>,
+    /// whether the value is negative, in which case the bar extends below the baseline
+    pub(super) negative: bool,
 }
 
 impl<'a> Bar<'a> {
@@ -61,6 +67,7 @@ impl<'a> Bar<'a> {
             style: Style::new(),
             value_style: Style::new(),
             text_value: None,
+            negative: false,
         }
     }
 
@@ -82,6 +89,7 @@ impl<'a> Bar<'a> {
             style: Style::new(),
             value_style: Style::new(),
             text_value: None,
+            negative: false,
         }
     }
 
@@ -96,6 +104,38 @@ impl<'a> Bar<'a> {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn value(mut self, value: u64) -> Self {
         self.value = value;
+        self.negative = false;
+        self
+    }
+
+    /// Set a value of this bar that can be negative.
+    ///
+    /// Bars with a negative value extend down (or left for
+    /// [`Horizontal`](ratatui_core::layout::Direction::Horizontal) bars) from the baseline of the
+    /// chart, which is placed so that the largest positive and negative values fit. This allows
+    /// showing profit/loss or deltas in a single chart.
+    ///
+    /// Negative bars are drawn with whole cells, as the bar symbols only fill cells from the
+    /// bottom.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Bar, BarChart, BarGroup};
+    ///
+    /// let chart = BarChart::default().data(BarGroup::new([
+    ///     Bar::with_label("Q1", 0).signed_value(120),
+    ///     Bar::with_label("Q2", 0).signed_value(-40),
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// [`Bar::value`] to set a positive value.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn signed_value(mut self, value: i64) -> Self {
+        self.value = value.unsigned_abs();
+        self.negative = value < 0;
         self
     }
 
@@ -169,8 +209,13 @@ impl<'a> Bar<'a> {
     ///
     /// `text_value` can be a [`&str`], `Number` or anything that can be converted into [`String`].
     ///
-    /// If `text_value` is not set, then the [`ToString`] representation of `value` will be shown on
-    /// the bar.
+    /// If `text_value` is not set, then `value` is shown on the bar, formatted as configured by
+    /// [`BarChart::value_formatter`] or [`BarChart::value_percentage`] (by default, its
+    /// [`ToString`] representation).
+    ///
+    /// [`BarChart::value_formatter`]: super::BarChart::value_formatter
+    /// [`BarChart::value_percentage`]: super::BarChart::value_percentage
+    /// [`ToString`]: alloc::string::ToString
     ///
     /// # Examples
     ///
@@ -192,12 +237,29 @@ impl<'a> Bar<'a> {
         self
     }
 
+    /// Returns the text shown on the bar: [`text_value`](Bar::text_value) if set, otherwise the
+    /// value formatted with the chart's `value_format`, preceded by a minus sign if it is negative.
+    pub(super) fn value_text(&self, value_format: &ValueFormat<'_>, max: u64) -> Cow<'_, str> {
+        self.text_value.as_deref().map_or_else(
+            || {
+                let text = value_format.format(self.value, max);
+                Cow::Owned(if self.negative {
+                    format!("-{text}")
+                } else {
+                    text
+                })
+            },
+            Cow::Borrowed,
+        )
+    }
+
     /// Render the value of the bar.
     ///
-    /// [`text_value`](Bar::text_value) is used if set, otherwise the value is converted to string.
+    /// `text` is the [`value_text`](Bar::value_text) of the bar.
     /// The value is rendered using `value_style`. If the value width is greater than the
     /// bar width, then the value is split into 2 parts. the first part is rendered in the bar
-    /// using `value_style`. The second part is rendered outside the bar using `bar_style`
+    /// using `value_style`. The second part is rendered outside the bar using `bar_style`.
+    /// The value of a negative bar is aligned to the right of `area`, where the bar starts.
     pub(super) fn render_value_with_different_styles(
         &self,
         buf: &mut Buffer,
@@ -205,9 +267,38 @@ impl<'a> Bar<'a> {
         bar_length: usize,
         default_value_style: Style,
         bar_style: Style,
+        text: &str,
     ) {
-        let value = self.value.to_string();
-        let text = self.text_value.as_ref().unwrap_or(&value);
+        if self.negative {
+            // The bar ends at the right of the area, so the value is aligned to the right and the
+            // part that is inside the bar is restyled with the value style
+            let width = (text.width() as u16).min(area.width);
+            let text_area = Rect {
+                x: area.right() - width,
+                width,
+                height: 1,
+                ..area
+            };
+            buf.set_stringn(
+                text_area.x,
+                text_area.y,
+                text,
+                width as usize,
+                bar_style.patch(self.style),
+            );
+            let bar_length = (bar_length as u16).min(area.width);
+            let bar_area = Rect {
+                x: area.right() - bar_length,
+                width: bar_length,
+                height: 1,
+                ..area
+            };
+            buf.set_style(
+                bar_area.intersection(text_area),
+                default_value_style.patch(self.value_style),
+            );
+            return;
+        }
 
         if !text.is_empty() {
             let style = default_value_style.patch(self.value_style);
@@ -237,26 +328,25 @@ impl<'a> Bar<'a> {
         }
     }
 
+    /// Render the value of the bar centered in `area`, which is one row high and as wide as the
+    /// bar.
     pub(super) fn render_value(
         &self,
         buf: &mut Buffer,
-        max_width: u16,
-        x: u16,
-        y: u16,
+        area: Rect,
         default_value_style: Style,
         ticks: u64,
+        value_label: &str,
     ) {
         if self.value != 0 {
             const TICKS_PER_LINE: u64 = 8;
-            let value = self.value.to_string();
-            let value_label = self.text_value.as_ref().unwrap_or(&value);
             let width = value_label.width() as u16;
             // if we have enough space or the ticks are greater equal than 1 cell (8)
             // then print the value
-            if width < max_width || (width == max_width && ticks >= TICKS_PER_LINE) {
+            if width < area.width || (width == area.width && ticks >= TICKS_PER_LINE) {
                 buf.set_string(
-                    x + (max_width.saturating_sub(width) >> 1),
-                    y,
+                    area.x + (area.width.saturating_sub(width) >> 1),
+                    area.y,
                     value_label,
                     default_value_style.patch(self.value_style),
                 );
@@ -318,6 +408,16 @@ mod tests {
         assert_eq!(bar.value, 42);
     }
 
+    #[test]
+    fn signed_value() {
+        let bar = Bar::new(1).signed_value(-42);
+        assert_eq!(bar.value, 42);
+        assert!(bar.negative);
+        let bar = bar.value(3);
+        assert_eq!(bar.value, 3);
+        assert!(!bar.negative);
+    }
+
     #[test]
     fn test_bar_with_label() {
         let bar = Bar::with_label("Label", 42);
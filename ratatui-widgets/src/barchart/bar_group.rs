@@ -101,9 +101,22 @@ impl<'a> BarGroup<'a> {
         self
     }
 
-    /// The maximum bar value of this group
+    /// The maximum positive bar value of this group
     pub(super) fn max(&self) -> Option<u64> {
-        self.bars.iter().max_by_key(|v| v.value).map(|v| v.value)
+        self.bars
+            .iter()
+            .filter(|bar| !bar.negative)
+            .map(|bar| bar.value)
+            .max()
+    }
+
+    /// The maximum magnitude of the negative bar values of this group
+    pub(super) fn max_negative(&self) -> Option<u64> {
+        self.bars
+            .iter()
+            .filter(|bar| bar.negative)
+            .map(|bar| bar.value)
+            .max()
     }
 
     pub(super) fn render_label(&self, buf: &mut Buffer, area: Rect, default_label_style: Style) {
@@ -0,0 +1,363 @@
+//! The [`BigText`] widget renders text in large letters using block characters.
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Alignment, Rect};
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::symbols::half_block;
+use ratatui_core::symbols::pixel::QUADRANTS;
+use ratatui_core::text::{Line, Text};
+use ratatui_core::widgets::Widget;
+use strum::{Display, EnumString};
+
+use crate::block::{Block, BlockExt};
+
+mod font;
+
+/// A widget that renders text in large letters using block characters.
+///
+/// Each character is drawn using a built-in 8x8 pixel font covering the printable ASCII
+/// characters. Characters that are not in the font are rendered as blank space. The
+/// [`PixelSize`] determines how many terminal cells a glyph takes: from 8x8 cells per character
+/// with [`PixelSize::Full`] down to 4x4 cells with [`PixelSize::Quadrant`].
+///
+/// Each [`Line`] of the text is rendered as a separate row of glyphs. The style of the widget, the
+/// line and each span are combined, so a word can be highlighted by giving its span a different
+/// color. Lines are aligned using the line's alignment or, if not set, the widget's
+/// [`BigText::alignment`]. Glyphs that don't fit in the area are clipped.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::layout::Alignment;
+/// use ratatui::style::Stylize;
+/// use ratatui::text::Line;
+/// use ratatui::widgets::{BigText, PixelSize};
+///
+/// let big_text = BigText::new(vec![Line::from("Hello"), Line::from("World".red())])
+///     .pixel_size(PixelSize::HalfHeight)
+///     .alignment(Alignment::Center);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct BigText<'a> {
+    /// The lines to render
+    text: Text<'a>,
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// The number of pixels drawn in each cell
+    pixel_size: PixelSize,
+    /// Alignment of lines that don't set their own alignment
+    alignment: Alignment,
+}
+
+/// The number of font pixels drawn in each terminal cell by [`BigText`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PixelSize {
+    /// One pixel per cell, using full blocks (`█`). Each glyph takes 8x8 cells.
+    #[default]
+    Full,
+    /// Two vertically stacked pixels per cell, using half blocks (`▀`, `▄`). Each glyph takes 8x4
+    /// cells.
+    HalfHeight,
+    /// Four pixels per cell, using quadrant blocks (`▚`, `▙`, ...). Each glyph takes 4x4 cells.
+    Quadrant,
+}
+
+impl PixelSize {
+    /// Returns the number of pixels drawn in each cell as `(columns, rows)`.
+    pub const fn pixels_per_cell(self) -> (u16, u16) {
+        match self {
+            Self::Full => (1, 1),
+            Self::HalfHeight => (1, 2),
+            Self::Quadrant => (2, 2),
+        }
+    }
+
+    /// Returns the size of a single glyph in cells as `(width, height)`.
+    pub const fn glyph_size(self) -> (u16, u16) {
+        let (columns, rows) = self.pixels_per_cell();
+        (font::GLYPH_SIZE / columns, font::GLYPH_SIZE / rows)
+    }
+
+    /// Returns the symbol for the cell at `(x, y)` (in cells) of the glyph.
+    fn symbol(self, glyph: [u8; 8], x: u16, y: u16) -> char {
+        let pixel = |px: u16, py: u16| glyph[usize::from(py)] >> px & 1 == 1;
+        match self {
+            Self::Full => {
+                if pixel(x, y) {
+                    half_block::FULL
+                } else {
+                    ' '
+                }
+            }
+            Self::HalfHeight => match (pixel(x, y * 2), pixel(x, y * 2 + 1)) {
+                (true, true) => half_block::FULL,
+                (true, false) => half_block::UPPER,
+                (false, true) => half_block::LOWER,
+                (false, false) => ' ',
+            },
+            Self::Quadrant => {
+                let (x, y) = (x * 2, y * 2);
+                let index = usize::from(pixel(x, y))
+                    | usize::from(pixel(x + 1, y)) << 1
+                    | usize::from(pixel(x, y + 1)) << 2
+                    | usize::from(pixel(x + 1, y + 1)) << 3;
+                QUADRANTS[index]
+            }
+        }
+    }
+}
+
+impl<'a> BigText<'a> {
+    /// Creates a new `BigText` widget with the given text.
+    ///
+    /// `text` accepts any type that is convertible to [`Text`] (e.g. `&str`, [`Line`], or a
+    /// `Vec<Line>`).
+    pub fn new<T>(text: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Surrounds the `BigText` with a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the number of font pixels drawn in each cell.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn pixel_size(mut self, pixel_size: PixelSize) -> Self {
+        self.pixel_size = pixel_size;
+        self
+    }
+
+    /// Sets the alignment of lines that don't set their own alignment.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    fn render_line(&self, line: &Line, area: Rect, buf: &mut Buffer) {
+        let (glyph_width, glyph_height) = self.pixel_size.glyph_size();
+        let glyphs: Vec<(char, Style)> = line
+            .spans
+            .iter()
+            .flat_map(|span| {
+                let style = self.style.patch(line.style).patch(span.style);
+                span.content.chars().map(move |c| (c, style))
+            })
+            .collect();
+        let line_width = u16::try_from(glyphs.len())
+            .unwrap_or(u16::MAX)
+            .saturating_mul(glyph_width);
+        let offset = match line.alignment.unwrap_or(self.alignment) {
+            Alignment::Left => 0,
+            Alignment::Center => area.width.saturating_sub(line_width) / 2,
+            Alignment::Right => area.width.saturating_sub(line_width),
+        };
+
+        let mut x = area.x + offset;
+        for (c, style) in glyphs {
+            if x >= area.right() {
+                break;
+            }
+            let glyph = font::glyph(c).unwrap_or_default();
+            for cell_y in 0..glyph_height.min(area.height) {
+                for cell_x in 0..glyph_width.min(area.right() - x) {
+                    buf[(x + cell_x, area.y + cell_y)]
+                        .set_char(self.pixel_size.symbol(glyph, cell_x, cell_y))
+                        .set_style(style);
+                }
+            }
+            x = x.saturating_add(glyph_width);
+        }
+    }
+}
+
+impl Widget for BigText<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &BigText<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        let (_, glyph_height) = self.pixel_size.glyph_size();
+        let mut y = area.y;
+        for line in &self.text.lines {
+            if y >= area.bottom() || area.is_empty() {
+                break;
+            }
+            let line_area = Rect::new(area.x, y, area.width, area.bottom() - y);
+            self.render_line(line, line_area, buf);
+            y = y.saturating_add(glyph_height);
+        }
+    }
+}
+
+impl Styled for BigText<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use ratatui_core::style::{Color, Stylize};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(PixelSize::Full, (8, 8))]
+    #[case(PixelSize::HalfHeight, (8, 4))]
+    #[case(PixelSize::Quadrant, (4, 4))]
+    fn glyph_size(#[case] pixel_size: PixelSize, #[case] expected: (u16, u16)) {
+        assert_eq!(pixel_size.glyph_size(), expected);
+    }
+
+    #[test]
+    fn render_full() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 8));
+        BigText::new("A").render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "  ██    ",
+            " ████   ",
+            "██  ██  ",
+            "██  ██  ",
+            "██████  ",
+            "██  ██  ",
+            "██  ██  ",
+            "        ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_half_height() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 4));
+        BigText::new("Hi")
+            .pixel_size(PixelSize::HalfHeight)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "██  ██    ▀▀    ",
+            "██▄▄██   ▀██    ",
+            "██  ██    ██    ",
+            "▀▀  ▀▀   ▀▀▀▀   ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_quadrant() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
+        BigText::new("Hi")
+            .pixel_size(PixelSize::Quadrant)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "█ █  ▀  ",
+            "█▄█ ▝█  ",
+            "█ █  █  ",
+            "▀ ▀ ▝▀▘ ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_multiple_lines_with_alignment() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 8));
+        BigText::new(vec![Line::from("-"), Line::from("-").right_aligned()])
+            .pixel_size(PixelSize::Quadrant)
+            .alignment(Alignment::Center)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "            ",
+            "    ▄▄▄     ",
+            "            ",
+            "            ",
+            "            ",
+            "        ▄▄▄ ",
+            "            ",
+            "            ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_clips_to_area() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        BigText::new("HH")
+            .pixel_size(PixelSize::Quadrant)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["█ █ █ ", "█▄█ █▄"]));
+    }
+
+    #[test]
+    fn render_span_styles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        let line = Line::from(vec!["-".red(), "-".blue()]);
+        BigText::new(line)
+            .pixel_size(PixelSize::Quadrant)
+            .style(Style::new().on_black())
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["        "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().red().on_black());
+        expected.set_style(Rect::new(4, 0, 4, 1), Style::new().blue().on_black());
+        assert_eq!(buffer, expected);
+        assert_eq!(buffer[(0, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn unknown_characters_are_blank() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        BigText::new("界")
+            .pixel_size(PixelSize::Quadrant)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["    "; 4]));
+    }
+}
@@ -8,13 +8,14 @@
 use alloc::vec::Vec;
 
 use itertools::Itertools;
+use ratatui_core::accessibility::{Accessible, Role, SemanticNode, Semantics};
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Alignment, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::symbols::border;
 use ratatui_core::symbols::merge::MergeStrategy;
 use ratatui_core::text::Line;
-use ratatui_core::widgets::Widget;
+use ratatui_core::widgets::{IntrinsicSize, Widget};
 use strum::{Display, EnumString};
 
 pub use self::padding::Padding;
@@ -564,6 +565,38 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Applies the styles of a [`Theme`] to the block.
+    ///
+    /// The [`Theme::TEXT`] style is used as the block [`style`](Block::style), [`Theme::BORDER`]
+    /// as the [`border_style`](Block::border_style) and [`Theme::TITLE`] as the
+    /// [`title_style`](Block::title_style). Styles that are not defined by the theme are left
+    /// unchanged, so they can be set before or after calling this method.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Theme};
+    /// use ratatui::widgets::Block;
+    ///
+    /// let theme = Theme::new().with_style(Theme::BORDER, Color::Blue);
+    /// let block = Block::bordered().theme(&theme).title("Themed");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        if let Some(style) = theme.get(Theme::TEXT) {
+            self.style = style;
+        }
+        if let Some(style) = theme.get(Theme::BORDER) {
+            self.border_style = style;
+        }
+        if let Some(style) = theme.get(Theme::TITLE) {
+            self.titles_style = style;
+        }
+        self
+    }
+
     /// Defines which borders to display.
     ///
     /// [`Borders`] can also be styled with [`Block::border_style`] and [`Block::border_type`].
@@ -712,6 +745,8 @@ impl<'a> Block<'a> {
     /// Adds a shadow behind the block.
     ///
     /// The shadow is rendered using the block area plus the shadow's configured offset.
+    /// It is clipped to the buffer, so a popup at the edge of the screen only shows part of it.
+    /// [`Shadow::elevation`] gives popups a dimmed drop shadow that grows with their elevation.
     ///
     /// # Example
     ///
@@ -802,6 +837,23 @@ impl Widget for Block<'_> {
     }
 }
 
+/// Describes the block as a region labeled with its titles.
+impl Accessible for Block<'_> {
+    fn describe(&self, area: Rect, semantics: &mut Semantics) {
+        let label = self.titles.iter().map(|(_, title)| title).join(" ");
+        semantics.push(SemanticNode::new(Role::Region, area).label(label));
+    }
+}
+
+/// Adds the description of the block, if any, and returns the nesting level of the nodes that
+/// describe the content inside it.
+pub(crate) fn describe_block(block: Option<&Block>, area: Rect, semantics: &mut Semantics) -> u16 {
+    block.map_or(0, |block| {
+        block.describe(area, semantics);
+        1
+    })
+}
+
 impl Widget for &Block<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let area = area.intersection(buf.area);
@@ -1139,6 +1191,57 @@ impl Block<'_> {
         let bottom = self.padding.bottom.saturating_add(u16::from(has_bottom));
         (top, bottom)
     }
+
+    /// Calculate the width of the block around content of the given width.
+    ///
+    /// The result is wide enough to render the titles without truncating them.
+    pub(crate) fn outer_width(&self, content_width: u16) -> u16 {
+        let (left, right) = self.horizontal_space();
+        let borders = u16::from(self.borders.contains(Borders::LEFT))
+            .saturating_add(u16::from(self.borders.contains(Borders::RIGHT)));
+        let titles_width = self
+            .titles_width(TitlePosition::Top)
+            .max(self.titles_width(TitlePosition::Bottom))
+            .saturating_add(borders);
+        content_width
+            .saturating_add(left)
+            .saturating_add(right)
+            .max(titles_width)
+    }
+
+    /// Calculate the height of the block around content of the given height.
+    pub(crate) fn outer_height(&self, content_height: u16) -> u16 {
+        let (top, bottom) = self.vertical_space();
+        content_height.saturating_add(top).saturating_add(bottom)
+    }
+
+    /// Calculate the width of the content inside a block of the given width.
+    pub(crate) fn inner_width(&self, width: u16) -> u16 {
+        let (left, right) = self.horizontal_space();
+        width.saturating_sub(left).saturating_sub(right)
+    }
+
+    /// The width of the titles at the given position, including the space between them.
+    fn titles_width(&self, position: TitlePosition) -> u16 {
+        self.titles
+            .iter()
+            .filter(|(pos, _)| pos.unwrap_or(self.titles_position) == position)
+            .map(|(_, title)| Self::line_width_u16(title).saturating_add(1))
+            .fold(0, u16::saturating_add)
+            .saturating_sub(1)
+    }
+}
+
+impl IntrinsicSize for Block<'_> {
+    /// Returns the width of the borders, padding and titles.
+    fn width_hint(&self) -> Option<u16> {
+        Some(self.outer_width(0))
+    }
+
+    /// Returns the height of the borders, padding and titles.
+    fn height_hint(&self, _width: u16) -> Option<u16> {
+        Some(self.outer_height(0))
+    }
 }
 
 /// An extension trait for [`Block`] that provides some convenience methods.
@@ -1172,6 +1275,7 @@ impl Styled for Block<'_> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
     use alloc::{format, vec};
 
     use itertools::iproduct;
@@ -1568,6 +1672,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn theme() {
+        let theme = Theme::new()
+            .with_style(Theme::BORDER, Color::Blue)
+            .with_style(Theme::TITLE, Color::Yellow);
+        let block = Block::bordered().style(Color::Red).theme(&theme);
+        assert_eq!(block.style, Style::new().red());
+        assert_eq!(block.border_style, Style::new().blue());
+        assert_eq!(block.titles_style, Style::new().yellow());
+
+        let block = block.theme(&Theme::new().with_style(Theme::TEXT, Color::Green));
+        assert_eq!(block.style, Style::new().green());
+        assert_eq!(block.border_style, Style::new().blue());
+    }
+
+    #[test]
+    fn intrinsic_size() {
+        let block = Block::bordered().padding(Padding::horizontal(1));
+        assert_eq!(block.width_hint(), Some(4));
+        assert_eq!(block.height_hint(10), Some(2));
+        assert_eq!(block.outer_width(5), 9);
+        assert_eq!(block.inner_width(9), 5);
+
+        // titles at the same position are separated by a space
+        let block = Block::bordered()
+            .title("Left")
+            .title(Line::from("Right").right_aligned())
+            .title_bottom("Bottom");
+        assert_eq!(block.width_hint(), Some(12));
+        assert_eq!(block.outer_width(20), 22);
+    }
+
     #[test]
     fn title_style_overrides_block_title_style() {
         for alignment in [Alignment::Left, Alignment::Center, Alignment::Right] {
@@ -2368,4 +2504,12 @@ mod tests {
             assert_eq!(block.vertical_space(), expected);
         }
     }
+
+    #[test]
+    fn describe() {
+        let block = Block::bordered().title("Top").title_bottom("Bottom");
+        let mut semantics = Semantics::new();
+        block.describe(Rect::new(0, 0, 10, 3), &mut semantics);
+        assert_eq!(semantics.to_string(), "region: Top Bottom\n");
+    }
 }
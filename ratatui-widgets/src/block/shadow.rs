@@ -21,6 +21,7 @@ use ratatui_core::widgets::Widget;
 /// - [`Shadow::block`] fills with full block symbols
 /// - [`Shadow::light_shade`], [`Shadow::medium_shade`], and [`Shadow::dark_shade`] fill with shade
 ///   symbols
+/// - [`Shadow::elevation`] dims the cells beneath the block, further away the higher the block is
 ///
 /// ```plain
 /// ┌Popup─────┐
@@ -71,6 +72,8 @@ enum Effect {
     Overlay,
     /// Fills the shadow area with a single symbol.
     Symbol(&'static str),
+    /// Dims the cells with the [`Dimmed`] effect.
+    Dimmed,
     /// Applies a user-defined shadow effect.
     Custom(Arc<dyn CellEffect>),
 }
@@ -97,6 +100,7 @@ impl Effect {
                     buf[(x, y)].set_symbol(symbol);
                 });
             }
+            Self::Dimmed => Dimmed.apply(shadow_area, base_area, buf),
             Self::Custom(filter) => filter.apply(shadow_area, base_area, buf),
         }
     }
@@ -105,7 +109,7 @@ impl Effect {
 impl PartialEq for Effect {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Overlay, Self::Overlay) => true,
+            (Self::Overlay, Self::Overlay) | (Self::Dimmed, Self::Dimmed) => true,
             (Self::Symbol(lhs), Self::Symbol(rhs)) => lhs == rhs,
             (Self::Custom(lhs), Self::Custom(rhs)) => Arc::ptr_eq(lhs, rhs),
             _ => false,
@@ -123,6 +127,7 @@ impl Hash for Effect {
                 "symbol".hash(state);
                 symbol.hash(state);
             }
+            Self::Dimmed => "dimmed".hash(state),
             Self::Custom(filter) => {
                 "custom".hash(state);
                 ptr::hash(Arc::as_ptr(filter), state);
@@ -244,6 +249,33 @@ impl Shadow {
         }
     }
 
+    /// Creates a shadow that dims the cells beneath a block raised to the given elevation.
+    ///
+    /// The shadow is offset by `level` rows and twice as many columns, as terminal cells are
+    /// usually about twice as tall as they are wide, so that stacked popups can be given
+    /// increasing elevations to show which one is on top. The cells are dimmed with the [`Dimmed`]
+    /// effect, so the content behind the shadow stays visible. A level of `0` renders no shadow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Block, Shadow};
+    ///
+    /// let dialog = Block::bordered().shadow(Shadow::elevation(1));
+    /// let confirmation = Block::bordered().shadow(Shadow::elevation(2));
+    /// ```
+    pub fn elevation(level: u8) -> Self {
+        if level == 0 {
+            return Self::overlay().offset(Offset::ZERO);
+        }
+        let level = i32::from(level);
+        Self {
+            effect: Effect::Dimmed,
+            style: Style::default(),
+            offset: Offset::new(level * 2, level),
+        }
+    }
+
     /// Creates a new shadow from a custom cell effect.
     ///
     /// The effect receives the shadow area, the original block area, and the target buffer. It is
@@ -437,6 +469,32 @@ mod tests {
         assert_eq!(buffer[(2, 2)].symbol(), "+");
     }
 
+    #[test]
+    fn elevation_offsets_dimmed_shadow() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        let shadow = Shadow::elevation(2);
+
+        (&shadow).render(Rect::new(0, 0, 2, 2), &mut buffer);
+
+        assert_eq!(buffer[(4, 2)].bg, Color::Black);
+        assert!(buffer[(5, 3)].modifier.contains(Modifier::DIM));
+        assert_eq!(buffer[(3, 2)].bg, Color::Reset);
+        assert_eq!(buffer[(2, 1)].bg, Color::Reset);
+        assert_eq!(Shadow::elevation(2), Shadow::elevation(2));
+        assert_ne!(Shadow::elevation(1), Shadow::elevation(2));
+    }
+
+    #[test]
+    fn elevation_zero_renders_no_shadow() {
+        let mut buffer = Buffer::with_lines(["abc", "def", "ghi"]);
+        buffer.set_style(buffer.area, Style::new().bg(Color::Rgb(100, 120, 140)));
+        let expected = buffer.clone();
+
+        (&Shadow::elevation(0)).render(Rect::new(0, 0, 2, 2), &mut buffer);
+
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn dimmed_filter_dims_background() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
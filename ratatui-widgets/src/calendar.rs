@@ -17,7 +17,7 @@ use ratatui_core::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui_core::style::Style;
 use ratatui_core::text::{Line, Span};
 use ratatui_core::widgets::Widget;
-use time::{Date, Duration};
+use time::{Date, Duration, Month, Weekday};
 
 use crate::block::{Block, BlockExt};
 
@@ -253,9 +253,83 @@ pub trait DateStyler {
     fn get_style(&self, date: Date) -> Style;
 }
 
-/// A simple `DateStyler` based on a [`HashMap`]
+/// A rule for dates that repeat, used by [`CalendarEventStore::add_recurring`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Recurrence {
+    /// Every week on the given day, e.g. `Weekly(Weekday::Saturday)`.
+    Weekly(Weekday),
+    /// Every month on the given day of the month. Months that don't have this day are skipped.
+    Monthly(u8),
+    /// Every year on the given month and day, e.g. `Yearly(Month::December, 25)`.
+    Yearly(Month, u8),
+    /// Every year on the nth weekday of the given month, e.g. `NthWeekday(Month::November, 4,
+    /// Weekday::Thursday)` for the fourth Thursday of November. Years in which the month has no
+    /// nth weekday are skipped.
+    NthWeekday(Month, u8, Weekday),
+}
+
+impl Recurrence {
+    /// Returns true if the date is one of the dates of the rule.
+    pub fn matches(self, date: Date) -> bool {
+        match self {
+            Self::Weekly(weekday) => date.weekday() == weekday,
+            Self::Monthly(day) => date.day() == day,
+            Self::Yearly(month, day) => date.month() == month && date.day() == day,
+            Self::NthWeekday(month, n, weekday) => {
+                date.month() == month && date.weekday() == weekday && (date.day() - 1) / 7 + 1 == n
+            }
+        }
+    }
+}
+
+/// A rule of a [`CalendarEventStore`] and the style of its dates.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Rule {
+    Recurring(Recurrence),
+    Range(Date, Date),
+}
+
+impl Rule {
+    fn matches(self, date: Date) -> bool {
+        match self {
+            Self::Recurring(recurrence) => recurrence.matches(date),
+            Self::Range(start, end) => start <= date && date <= end,
+        }
+    }
+}
+
+/// A simple `DateStyler` based on a [`HashMap`] of dates, and rules for recurring dates and date
+/// ranges.
+///
+/// The style of a date is the style of the date if it was [added](CalendarEventStore::add), or
+/// else the style of the last [recurring](CalendarEventStore::add_recurring) or
+/// [range](CalendarEventStore::add_range) rule that matches it.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::{Color, Style};
+/// use ratatui::widgets::calendar::{CalendarEventStore, Recurrence};
+/// use time::{Date, Month, Weekday};
+///
+/// let mut events = CalendarEventStore::default();
+/// events.add_recurring(Recurrence::Weekly(Weekday::Sunday), Color::DarkGray);
+/// events.add_recurring(Recurrence::Yearly(Month::December, 25), Color::Red);
+/// events.add_recurring(
+///     Recurrence::NthWeekday(Month::November, 4, Weekday::Thursday),
+///     Color::Yellow,
+/// );
+/// let start = Date::from_calendar_date(2024, Month::July, 15)?;
+/// let end = Date::from_calendar_date(2024, Month::July, 26)?;
+/// events.add_range(start, end, Style::new().bg(Color::Blue));
+///
+/// let november = events.events_in(2024, Month::November);
+/// // four sundays and thanksgiving
+/// assert_eq!(november.len(), 5);
+/// # Ok::<(), time::error::ComponentRange>(())
+/// ```
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct CalendarEventStore(pub HashMap<Date, Style>);
+pub struct CalendarEventStore(pub HashMap<Date, Style>, Vec<(Rule, Style)>);
 
 impl CalendarEventStore {
     /// Construct a store that has the current date styled.
@@ -288,9 +362,60 @@ impl CalendarEventStore {
         let _ = self.0.insert(date, style.into());
     }
 
+    /// Add a rule for recurring dates and their style to the store.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    pub fn add_recurring<S: Into<Style>>(&mut self, recurrence: Recurrence, style: S) {
+        self.1.push((Rule::Recurring(recurrence), style.into()));
+    }
+
+    /// Add a range of dates, from `start` to `end` inclusive, and their style to the store.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    pub fn add_range<S: Into<Style>>(&mut self, start: Date, end: Date, style: S) {
+        self.1.push((Rule::Range(start, end), style.into()));
+    }
+
+    /// Returns the dates of the given month that have a style, with their style, in order.
+    ///
+    /// This includes the dates that were added and the dates that match a rule.
+    pub fn events_in(&self, year: i32, month: Month) -> Vec<(Date, Style)> {
+        let Ok(mut date) = Date::from_calendar_date(year, month, 1) else {
+            return Vec::new();
+        };
+        let mut events = Vec::new();
+        while date.month() == month {
+            if let Some(style) = self.find_style(date) {
+                events.push((date, style));
+            }
+            let Some(next) = date.next_day() else {
+                break;
+            };
+            date = next;
+        }
+        events
+    }
+
+    /// Returns the style of a date, if it has one.
+    fn find_style(&self, date: Date) -> Option<Style> {
+        self.0.get(&date).copied().or_else(|| {
+            self.1
+                .iter()
+                .rev()
+                .find(|(rule, _)| rule.matches(date))
+                .map(|(_, style)| *style)
+        })
+    }
+
     /// Helper for trait impls
     fn lookup_style(&self, date: Date) -> Style {
-        self.0.get(&date).copied().unwrap_or_default()
+        self.find_style(date).unwrap_or_default()
     }
 }
 
@@ -308,7 +433,7 @@ impl DateStyler for &CalendarEventStore {
 
 impl Default for CalendarEventStore {
     fn default() -> Self {
-        Self(HashMap::with_capacity(4))
+        Self(HashMap::with_capacity(4), Vec::new())
     }
 }
 
@@ -345,6 +470,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn recurrence() {
+        let date = |month, day| Date::from_calendar_date(2024, month, day).unwrap();
+        assert!(Recurrence::Weekly(Weekday::Monday).matches(date(Month::January, 1)));
+        assert!(!Recurrence::Weekly(Weekday::Monday).matches(date(Month::January, 2)));
+        assert!(Recurrence::Monthly(31).matches(date(Month::March, 31)));
+        assert!(Recurrence::Yearly(Month::February, 29).matches(date(Month::February, 29)));
+        let thanksgiving = Recurrence::NthWeekday(Month::November, 4, Weekday::Thursday);
+        assert!(thanksgiving.matches(date(Month::November, 28)));
+        assert!(!thanksgiving.matches(date(Month::November, 21)));
+    }
+
+    #[test]
+    fn event_store_rules() {
+        let date = |month, day| Date::from_calendar_date(2024, month, day).unwrap();
+        let red = Style::new().fg(Color::Red);
+        let blue = Style::new().fg(Color::Blue);
+        let mut store = CalendarEventStore::default();
+        store.add_recurring(Recurrence::Monthly(30), red);
+        store.add_range(date(Month::February, 28), date(Month::March, 1), blue);
+        store.add(date(Month::March, 1), Style::new());
+
+        assert_eq!(store.get_style(date(Month::April, 30)), red);
+        // the last rule wins, and added dates win over rules
+        assert_eq!(
+            store.events_in(2024, Month::February),
+            [
+                (date(Month::February, 28), blue),
+                (date(Month::February, 29), blue)
+            ]
+        );
+        assert_eq!(
+            store.events_in(2024, Month::March),
+            [
+                (date(Month::March, 1), Style::new()),
+                (date(Month::March, 30), red)
+            ]
+        );
+    }
+
     #[test]
     fn test_today() {
         CalendarEventStore::today(Style::default());
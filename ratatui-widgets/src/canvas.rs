@@ -6,6 +6,7 @@
 //! The available shapes are:
 //!
 //! - [`Circle`]: A basic circle
+//! - [`ImageData`]: An image made of RGB pixels
 //! - [`Line`]: A line between two points
 //! - [`Map`]: A world map
 //! - [`Points`]: A scatter of points
@@ -30,7 +31,8 @@ use ratatui_core::text::Line as TextLine;
 use ratatui_core::widgets::Widget;
 
 pub use self::circle::Circle;
-pub use self::line::{FilledLine, Line};
+pub use self::image::ImageData;
+pub use self::line::{AntiAliasedLine, FilledLine, Line};
 pub use self::map::{Map, MapResolution};
 pub use self::points::Points;
 pub use self::rectangle::Rectangle;
@@ -39,6 +41,7 @@ use crate::block::{Block, BlockExt};
 use crate::polyfills::F64Polyfills;
 
 mod circle;
+mod image;
 mod line;
 mod map;
 mod points;
@@ -447,6 +450,12 @@ impl Painter<'_, '_> {
     /// assert_eq!(point, Some((0, 0)));
     /// ```
     pub fn get_point(&self, x: f64, y: f64) -> Option<(usize, usize)> {
+        let (x, y) = self.get_subpixel_point(x, y)?;
+        Some((x.round() as usize, y.round() as usize))
+    }
+
+    /// Convert the `(x, y)` coordinates to a location on the grid, without rounding to a point.
+    fn get_subpixel_point(&self, x: f64, y: f64) -> Option<(f64, f64)> {
         let [left, right] = self.context.x_bounds;
         let [bottom, top] = self.context.y_bounds;
         if x < left || x > right || y < bottom || y > top {
@@ -457,8 +466,8 @@ impl Painter<'_, '_> {
         if width <= 0.0 || height <= 0.0 {
             return None;
         }
-        let x = ((x - left) * (self.resolution.0 - 1.0) / width).round() as usize;
-        let y = ((top - y) * (self.resolution.1 - 1.0) / height).round() as usize;
+        let x = (x - left) * (self.resolution.0 - 1.0) / width;
+        let y = (top - y) * (self.resolution.1 - 1.0) / height;
         Some((x, y))
     }
 
@@ -729,6 +738,7 @@ where
     paint_func: Option<F>,
     background_color: Color,
     marker: Marker,
+    cell_aspect_ratio: Option<f64>,
 }
 
 impl<F> Default for Canvas<'_, F>
@@ -743,6 +753,7 @@ where
             paint_func: None,
             background_color: Color::Reset,
             marker: Marker::Braille,
+            cell_aspect_ratio: None,
         }
     }
 }
@@ -784,6 +795,50 @@ where
         self
     }
 
+    /// Keeps the same scale on both axes, so that circles are drawn round and squares square.
+    ///
+    /// Terminal cells are not square, so the bounds usually map to a different number of units per
+    /// cell on each axis, which stretches the drawing. With this option, the axis that would be
+    /// stretched less is widened around its center until one unit has the same length on screen on
+    /// both axes. The bounds of the [`Context`] (see [`Painter::bounds`]) are the widened bounds.
+    ///
+    /// `cell_aspect_ratio` is the height of a cell divided by its width, which is about `2.0` in
+    /// most terminals.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::canvas::{Canvas, Circle};
+    ///
+    /// Canvas::default()
+    ///     .x_bounds([-10.0, 10.0])
+    ///     .y_bounds([-10.0, 10.0])
+    ///     .preserve_aspect_ratio(2.0)
+    ///     .paint(|ctx| {
+    ///         ctx.draw(&Circle {
+    ///             radius: 10.0,
+    ///             ..Default::default()
+    ///         });
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn preserve_aspect_ratio(mut self, cell_aspect_ratio: f64) -> Self {
+        self.cell_aspect_ratio = Some(cell_aspect_ratio);
+        self
+    }
+
+    /// Returns the bounds of the canvas when it is drawn in the given area.
+    fn bounds_in(&self, area: Rect) -> ([f64; 2], [f64; 2]) {
+        match self.cell_aspect_ratio {
+            Some(cell_aspect_ratio) => {
+                aspect_bounds(self.x_bounds, self.y_bounds, area, cell_aspect_ratio)
+            }
+            None => (self.x_bounds, self.y_bounds),
+        }
+    }
+
     /// Store the closure that will be used to draw to the [`Canvas`]
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -877,11 +932,12 @@ where
         };
 
         // Create a blank context that match the size of the canvas
+        let (x_bounds, y_bounds) = self.bounds_in(canvas_area);
         let mut ctx = Context::new(
             canvas_area.width,
             canvas_area.height,
-            self.x_bounds,
-            self.y_bounds,
+            x_bounds,
+            y_bounds,
             self.marker,
         );
         // Paint to this context
@@ -910,12 +966,10 @@ where
         }
 
         // Finally draw the labels
-        let left = self.x_bounds[0];
-        let right = self.x_bounds[1];
-        let top = self.y_bounds[1];
-        let bottom = self.y_bounds[0];
-        let width = (self.x_bounds[1] - self.x_bounds[0]).abs();
-        let height = (self.y_bounds[1] - self.y_bounds[0]).abs();
+        let [left, right] = x_bounds;
+        let [bottom, top] = y_bounds;
+        let width = (right - left).abs();
+        let height = (top - bottom).abs();
         let resolution = {
             let width = f64::from(canvas_area.width - 1);
             let height = f64::from(canvas_area.height - 1);
@@ -933,6 +987,35 @@ where
     }
 }
 
+/// Widens the bounds of one axis around its center, so that both axes have the same number of
+/// units per length on screen when drawn in the area.
+fn aspect_bounds(
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    area: Rect,
+    cell_aspect_ratio: f64,
+) -> ([f64; 2], [f64; 2]) {
+    let screen_width = f64::from(area.width);
+    let screen_height = f64::from(area.height) * cell_aspect_ratio;
+    let x_scale = (x_bounds[1] - x_bounds[0]) / screen_width;
+    let y_scale = (y_bounds[1] - y_bounds[0]) / screen_height;
+    if !(x_scale.is_normal() && y_scale.is_normal()) {
+        return (x_bounds, y_bounds);
+    }
+    let widen = |[min, max]: [f64; 2], screen_length: f64, scale: f64| {
+        let center = f64::midpoint(min, max);
+        let half_length = screen_length * scale / 2.0;
+        [center - half_length, center + half_length]
+    };
+    if x_scale.abs() < y_scale.abs() {
+        let scale = y_scale.abs() * x_scale.signum();
+        (widen(x_bounds, screen_width, scale), y_bounds)
+    } else {
+        let scale = x_scale.abs() * y_scale.signum();
+        (x_bounds, widen(y_bounds, screen_height, scale))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -1218,4 +1301,39 @@ mod tests {
         // This should not panic, even if the buffer has zero size.
         canvas.render(buffer.area, &mut buffer);
     }
+
+    #[rstest]
+    #[case::widen_x([0.0, 10.0], [0.0, 20.0], ([-5.0, 15.0], [0.0, 20.0]))]
+    #[case::widen_y([0.0, 10.0], [0.0, 5.0], ([0.0, 10.0], [-2.5, 7.5]))]
+    #[case::unchanged([0.0, 10.0], [0.0, 10.0], ([0.0, 10.0], [0.0, 10.0]))]
+    #[case::empty([0.0, 0.0], [0.0, 10.0], ([0.0, 0.0], [0.0, 10.0]))]
+    fn aspect_bounds(
+        #[case] x_bounds: [f64; 2],
+        #[case] y_bounds: [f64; 2],
+        #[case] expected: ([f64; 2], [f64; 2]),
+    ) {
+        // 10x5 cells that are twice as tall as wide are 10x10 on screen
+        let area = Rect::new(0, 0, 10, 5);
+        assert_eq!(
+            super::aspect_bounds(x_bounds, y_bounds, area, 2.0),
+            expected
+        );
+    }
+
+    #[test]
+    fn preserve_aspect_ratio_widens_context_bounds() {
+        let bounds = core::cell::Cell::new(([0.0; 2], [0.0; 2]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        Canvas::default()
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 20.0])
+            .preserve_aspect_ratio(2.0)
+            .paint(|ctx| {
+                let painter = Painter::from(ctx);
+                let (x, y) = painter.bounds();
+                bounds.set((*x, *y));
+            })
+            .render(buffer.area, &mut buffer);
+        assert_eq!(bounds.get(), ([-5.0, 15.0], [0.0, 20.0]));
+    }
 }
@@ -0,0 +1,139 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Painter, Shape};
+
+/// An image made of RGB pixels, painted one pixel per point of the canvas grid
+///
+/// The pixels are stored row by row in `data`, with three bytes (red, green and blue) per pixel.
+/// The image is drawn with its top left corner at `(x, y)`, and each pixel is painted on one point
+/// of the grid regardless of the bounds of the canvas, so the image is not scaled.
+///
+/// This is meant to be used with the [`HalfBlock`] marker, which paints each point with its own
+/// color by using the foreground and background colors of upper half block characters. This can
+/// display low resolution images and sprites (two pixels per cell) without a graphics protocol.
+/// Other markers can only show one color per cell.
+///
+/// Pixels that fall outside the canvas are not drawn. If the top left corner is outside the
+/// canvas, nothing is drawn. An incomplete last pixel is ignored.
+///
+/// [`HalfBlock`]: ratatui_core::symbols::Marker::HalfBlock
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::symbols::Marker;
+/// # use ratatui_widgets::canvas::{Canvas, ImageData};
+/// // a 2x2 image with a red, green, blue and white pixel
+/// let data = [255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255];
+/// Canvas::default()
+///     .marker(Marker::HalfBlock)
+///     .x_bounds([0.0, 10.0])
+///     .y_bounds([0.0, 10.0])
+///     .paint(|ctx| {
+///         ctx.draw(&ImageData::new(0.0, 10.0, 2, &data));
+///     });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImageData<'a> {
+    /// `x` of the top left corner of the image
+    pub x: f64,
+    /// `y` of the top left corner of the image
+    pub y: f64,
+    /// Width of the image in pixels
+    pub width: usize,
+    /// RGB bytes of the pixels, row by row
+    pub data: &'a [u8],
+}
+
+impl<'a> ImageData<'a> {
+    /// Create a new image with its top left corner at `(x, y)` from the RGB bytes of its pixels
+    pub const fn new(x: f64, y: f64, width: usize, data: &'a [u8]) -> Self {
+        Self { x, y, width, data }
+    }
+
+    /// Returns the height of the image in pixels
+    pub const fn height(&self) -> usize {
+        match (self.data.len() / 3).checked_div(self.width) {
+            Some(height) => height,
+            None => 0,
+        }
+    }
+}
+
+impl Shape for ImageData<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        if self.width == 0 {
+            return;
+        }
+        let Some((left, top)) = painter.get_point(self.x, self.y) else {
+            return;
+        };
+        let (width, height) = painter.resolution;
+        for (i, rgb) in self.data.chunks_exact(3).enumerate() {
+            let x = left + i % self.width;
+            let y = top + i / self.width;
+            if x as f64 >= width || y as f64 >= height {
+                continue;
+            }
+            painter.paint(x, y, Color::Rgb(rgb[0], rgb[1], rgb[2]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::buffer::Buffer;
+    use ratatui_core::layout::Rect;
+    use ratatui_core::style::Style;
+    use ratatui_core::symbols::Marker;
+    use ratatui_core::widgets::Widget;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    const RED: Color = Color::Rgb(255, 0, 0);
+    const BLUE: Color = Color::Rgb(0, 0, 255);
+
+    fn render(image: &ImageData) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        Canvas::default()
+            .marker(Marker::HalfBlock)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 3.0])
+            .paint(|ctx| ctx.draw(image))
+            .render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn paints_pixels_with_half_blocks() {
+        // red, blue on the first row, blue, blue on the second row
+        let data = [255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 255];
+        let buffer = render(&ImageData::new(1.0, 3.0, 2, &data));
+
+        let mut expected = Buffer::with_lines([" ▀█", "   "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().fg(RED).bg(BLUE));
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().fg(BLUE).bg(BLUE));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn clips_to_canvas() {
+        // a 4x1 image that starts at the last column
+        let data = [255, 0, 0].repeat(4);
+        let image = ImageData::new(2.0, 0.0, 4, &data);
+        assert_eq!(image.height(), 1);
+        let buffer = render(&image);
+
+        let mut expected = Buffer::with_lines(["   ", "  ▄"]);
+        expected.set_style(Rect::new(2, 1, 1, 1), Style::new().fg(RED));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn empty_image() {
+        let image = ImageData::new(0.0, 0.0, 0, &[1, 2, 3]);
+        assert_eq!(image.height(), 0);
+        assert_eq!(render(&image), Buffer::empty(Rect::new(0, 0, 3, 2)));
+    }
+}
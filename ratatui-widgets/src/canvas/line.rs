@@ -2,6 +2,8 @@ use line_clipping::{LineSegment, Point, Window, cohen_sutherland};
 use ratatui_core::style::Color;
 
 use crate::canvas::{Painter, Shape};
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
 
 /// A line from `(x1, y1)` to `(x2, y2)` with the given color
 ///
@@ -168,6 +170,117 @@ where
     }
 }
 
+/// An anti-aliased line from `(x1, y1)` to `(x2, y2)` with the given color
+///
+/// A [`Line`] paints a single point at each step along the line, which makes diagonal lines look
+/// jagged, especially with the high resolution [`Braille`] marker. Like [Xiaolin Wu's line
+/// algorithm], this shape computes how much the line covers each of the two points it passes
+/// between. As the grid can't paint points with partial intensity, both points are painted when
+/// the line passes close to the middle of them. This draws diagonal lines with a denser, smoother
+/// pattern.
+///
+/// [`Braille`]: ratatui_core::symbols::Marker::Braille
+/// [Xiaolin Wu's line algorithm]: https://en.wikipedia.org/wiki/Xiaolin_Wu%27s_line_algorithm
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// # use ratatui_widgets::canvas::{AntiAliasedLine, Canvas};
+/// Canvas::default().paint(|ctx| {
+///     ctx.draw(&AntiAliasedLine::new(0.0, 0.0, 1.0, 0.3, Color::Red));
+/// });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AntiAliasedLine {
+    /// `x` of the starting point
+    pub x1: f64,
+    /// `y` of the starting point
+    pub y1: f64,
+    /// `x` of the ending point
+    pub x2: f64,
+    /// `y` of the ending point
+    pub y2: f64,
+    /// Color of the line
+    pub color: Color,
+}
+
+impl AntiAliasedLine {
+    /// The minimum coverage of a point for it to be painted.
+    const MIN_COVERAGE: f64 = 0.25;
+
+    /// Create a new anti-aliased line from `(x1, y1)` to `(x2, y2)` with the given color
+    pub const fn new(x1: f64, y1: f64, x2: f64, y2: f64, color: Color) -> Self {
+        Self {
+            x1,
+            y1,
+            x2,
+            y2,
+            color,
+        }
+    }
+}
+
+impl Shape for AntiAliasedLine {
+    #[expect(clippy::similar_names)]
+    fn draw(&self, painter: &mut Painter) {
+        let (x_bounds, y_bounds) = painter.bounds();
+        let Some((world_x1, world_y1, world_x2, world_y2)) =
+            clip_line(x_bounds, y_bounds, self.x1, self.y1, self.x2, self.y2)
+        else {
+            return;
+        };
+        let Some((x1, y1)) = painter.get_subpixel_point(world_x1, world_y1) else {
+            return;
+        };
+        let Some((x2, y2)) = painter.get_subpixel_point(world_x2, world_y2) else {
+            return;
+        };
+
+        // step along the major axis and paint the points on both sides of the line on the minor
+        // axis, depending on how close the line passes to them
+        let steep = (y2 - y1).abs() > (x2 - x1).abs();
+        let (major1, minor1, major2, minor2) = if steep {
+            (y1, x1, y2, x2)
+        } else {
+            (x1, y1, x2, y2)
+        };
+        let (major1, minor1, major2, minor2) = if major1 > major2 {
+            (major2, minor2, major1, minor1)
+        } else {
+            (major1, minor1, major2, minor2)
+        };
+        let gradient = if major2 > major1 {
+            (minor2 - minor1) / (major2 - major1)
+        } else {
+            0.0
+        };
+        let (width, height) = painter.resolution;
+        let minor_max = if steep { width - 1.0 } else { height - 1.0 };
+        for major in major1.round() as usize..=major2.round() as usize {
+            let minor = gradient
+                .mul_add(major as f64 - major1, minor1)
+                .clamp(0.0, minor_max);
+            let before = minor.floor();
+            let coverage = minor - before;
+            let before = before as usize;
+            let mut paint = |minor: usize| {
+                if steep {
+                    painter.paint(minor, major, self.color);
+                } else {
+                    painter.paint(major, minor, self.color);
+                }
+            };
+            if coverage <= 1.0 - Self::MIN_COVERAGE {
+                paint(before);
+            }
+            if coverage >= Self::MIN_COVERAGE {
+                paint(before + 1);
+            }
+        }
+    }
+}
+
 /// A filled line from `(x1, y1)` to `(x2, y2)` that fills the area under/over the line
 /// to `fill_to_y` with the given color.
 ///
@@ -600,4 +713,80 @@ mod tests {
         }
         assert_eq!(buffer, expected);
     }
+
+    #[rstest]
+    #[case::horizontal(&AntiAliasedLine::new(0.0, 0.0, 10.0, 0.0, Color::Red), [
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "••••••••••",
+    ])]
+    #[case::off_grid(&AntiAliasedLine::new(-5.0, -5.0, 20.0, 3.0, Color::Red), ["          "; 10])]
+    #[case::diagonal(&AntiAliasedLine::new(0.0, 0.0, 10.0, 10.0, Color::Red), [
+        "         •",
+        "        • ",
+        "       •  ",
+        "      •   ",
+        "     •    ",
+        "    •     ",
+        "   •      ",
+        "  •       ",
+        " •        ",
+        "•         ",
+    ])]
+    // dy < dx
+    #[case::shallow(&AntiAliasedLine::new(0.0, 0.0, 10.0, 5.0, Color::Red), [
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+        "         •",
+        "       •••",
+        "     •••  ",
+        "   •••    ",
+        " •••      ",
+        "••        ",
+    ])]
+    // dy > dx
+    #[case::steep(&AntiAliasedLine::new(0.0, 10.0, 5.0, 0.0, Color::Red), [
+        "•         ",
+        "••        ",
+        " •        ",
+        " ••       ",
+        "  •       ",
+        "  ••      ",
+        "   •      ",
+        "   ••     ",
+        "    •     ",
+        "    ••    ",
+    ])]
+    fn tests_anti_aliased<'expected_line, ExpectedLines>(
+        #[case] line: &AntiAliasedLine,
+        #[case] expected: ExpectedLines,
+    ) where
+        ExpectedLines: IntoIterator,
+        ExpectedLines::Item: Into<ratatui_core::text::Line<'expected_line>>,
+    {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let canvas = Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|context| context.draw(line));
+        canvas.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(expected);
+        for cell in &mut expected.content {
+            if cell.symbol() == "•" {
+                cell.set_style(Style::new().red());
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
 }
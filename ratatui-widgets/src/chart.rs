@@ -11,8 +11,11 @@ use ratatui_core::text::Line;
 use ratatui_core::widgets::Widget;
 use strum::{Display, EnumString};
 
+pub use self::streaming::StreamingDataset;
 use crate::block::{Block, BlockExt};
-use crate::canvas::{Canvas, FilledLine, Line as CanvasLine, Points};
+use crate::canvas::{AntiAliasedLine, Canvas, FilledLine, Line as CanvasLine, Points};
+
+mod streaming;
 
 /// An X or Y axis for the [`Chart`] widget
 ///
@@ -303,7 +306,8 @@ impl LegendPosition {
 /// (`(f64, f64)`), the first element being X and the second Y. It's also worth noting that, unlike
 /// the [`Rect`], here the Y axis is bottom to top, as in math.
 ///
-/// You can also customize the rendering by using [`Dataset::marker`] and [`Dataset::graph_type`].
+/// You can also customize the rendering by using [`Dataset::marker`], [`Dataset::graph_type`] and
+/// [`Dataset::anti_alias`].
 ///
 /// # Example
 ///
@@ -335,6 +339,8 @@ pub struct Dataset<'a> {
     style: Style,
     /// The y-coordinate to fill area to when using [`GraphType::Area`]
     fill_to_y: f64,
+    /// Whether lines are drawn with [`AntiAliasedLine`] when using [`GraphType::Line`]
+    anti_alias: bool,
 }
 
 impl<'a> Dataset<'a> {
@@ -441,6 +447,33 @@ impl<'a> Dataset<'a> {
         self.fill_to_y = fill_to_y;
         self
     }
+
+    /// Sets whether the lines of a [`GraphType::Line`] chart are anti-aliased
+    ///
+    /// Lines are drawn one point per step by default, which makes diagonal lines look jagged,
+    /// especially with the [`Braille`](symbols::Marker::Braille) marker. Anti-aliased lines also
+    /// paint the neighboring point when the line passes between two points, which gives dense plots
+    /// a smoother look. See [`AntiAliasedLine`] for more details. The default is `false`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::symbols::Marker;
+    /// use ratatui::widgets::{Dataset, GraphType};
+    ///
+    /// let dataset = Dataset::default()
+    ///     .data(&[(0.0, 0.0), (10.0, 3.0)])
+    ///     .marker(Marker::Braille)
+    ///     .graph_type(GraphType::Line)
+    ///     .anti_alias(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn anti_alias(mut self, anti_alias: bool) -> Self {
+        self.anti_alias = anti_alias;
+        self
+    }
 }
 
 /// A container that holds all the infos about where to display each elements of the chart (axis,
@@ -1055,6 +1088,17 @@ impl Widget for &Chart<'_> {
                         color,
                     });
                     match dataset.graph_type {
+                        GraphType::Line if dataset.anti_alias => {
+                            for data in dataset.data.windows(2) {
+                                ctx.draw(&AntiAliasedLine {
+                                    x1: data[0].0,
+                                    y1: data[0].1,
+                                    x2: data[1].0,
+                                    y2: data[1].1,
+                                    color,
+                                });
+                            }
+                        }
                         GraphType::Line => {
                             for data in dataset.data.windows(2) {
                                 ctx.draw(&CanvasLine {
@@ -1651,6 +1695,25 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[rstest]
+    #[case::aliased(false, ["  ⢀⠤⠊", "⡠⠒⠁  "])]
+    #[case::anti_aliased(true, ["  ⢀⡴⠊", "⡠⠞⠁  "])]
+    fn anti_aliased_line(#[case] anti_alias: bool, #[case] expected: [&str; 2]) {
+        let data = [(0.0, 0.0), (10.0, 3.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&data)
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .anti_alias(anti_alias),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 10.0]))
+        .y_axis(Axis::default().bounds([0.0, 3.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
     #[test]
     fn filled_line() {
         let data = [(0.0, 0.0), (5.0, 5.0), (10.0, 5.0)];
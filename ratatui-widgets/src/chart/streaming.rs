@@ -0,0 +1,176 @@
+use alloc::vec::Vec;
+
+/// A fixed-capacity buffer of points for charts of live data.
+///
+/// Monitoring apps usually add a few points to a chart on every frame and drop the oldest ones.
+/// Doing that with a `Vec` means shifting every point on each update, and keeping track of the
+/// window of x values shown by the chart by hand. A `StreamingDataset` keeps at most `capacity`
+/// points, dropping the oldest ones as new ones are pushed, without allocating after it is
+/// created. The points are always stored contiguously, so [`StreamingDataset::data`] can be
+/// passed directly to [`Dataset::data`].
+///
+/// Points are expected to be pushed in increasing order of x. With
+/// [`StreamingDataset::window`], points that are more than the window width behind the latest
+/// point are dropped too, and [`StreamingDataset::x_bounds`] returns the bounds of the window,
+/// so the chart scrolls as points are pushed.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{Axis, Chart, Dataset, StreamingDataset};
+///
+/// let mut cpu = StreamingDataset::new(1000).window(60.0);
+/// for second in 0..100 {
+///     cpu.push(f64::from(second), 42.0);
+/// }
+/// assert_eq!(cpu.x_bounds(), [39.0, 99.0]);
+///
+/// let chart = Chart::new(vec![Dataset::default().data(cpu.data())])
+///     .x_axis(Axis::default().bounds(cpu.x_bounds()))
+///     .y_axis(Axis::default().bounds([0.0, 100.0]));
+/// ```
+///
+/// [`Dataset::data`]: crate::chart::Dataset::data
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamingDataset {
+    /// The points, of which the ones before `start` have been dropped. This has room for twice
+    /// the capacity so that dropped points only need to be removed once in a while.
+    points: Vec<(f64, f64)>,
+    start: usize,
+    capacity: usize,
+    window: Option<f64>,
+}
+
+impl StreamingDataset {
+    /// Creates an empty dataset that keeps at most `capacity` points.
+    ///
+    /// A capacity of `0` is treated as `1`.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            points: Vec::with_capacity(capacity * 2),
+            start: 0,
+            capacity,
+            window: None,
+        }
+    }
+
+    /// Sets the width of the window of x values to keep.
+    ///
+    /// Points whose x is less than the x of the latest point minus `width` are dropped.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn window(mut self, width: f64) -> Self {
+        self.window = Some(width);
+        self
+    }
+
+    /// Adds a point after the existing points, dropping the oldest points if needed.
+    pub fn push(&mut self, x: f64, y: f64) {
+        if self.len() == self.capacity {
+            self.start += 1;
+        }
+        if self.points.len() == self.capacity * 2 {
+            self.points.drain(..self.start);
+            self.start = 0;
+        }
+        self.points.push((x, y));
+        if let Some(width) = self.window {
+            let min_x = x - width;
+            self.start += self.data().partition_point(|&(x, _)| x < min_x);
+        }
+    }
+
+    /// Returns the points, from the oldest to the latest.
+    pub fn data(&self) -> &[(f64, f64)] {
+        &self.points[self.start..]
+    }
+
+    /// Returns the bounds of the x values to show on the x axis.
+    ///
+    /// With a [window](StreamingDataset::window), these are the bounds of the window ending at
+    /// the latest point (or starting at `0.0` when there are no points). Otherwise, these are
+    /// the x values of the oldest and latest points.
+    pub fn x_bounds(&self) -> [f64; 2] {
+        let last = self.data().last().map_or(0.0, |&(x, _)| x);
+        match self.window {
+            Some(width) if self.is_empty() => [0.0, width],
+            Some(width) => [last - width, last],
+            None => [self.data().first().map_or(0.0, |&(x, _)| x), last],
+        }
+    }
+
+    /// Returns the smallest and largest y values of the points, or `None` if there are no points.
+    pub fn y_bounds(&self) -> Option<[f64; 2]> {
+        self.data().iter().fold(None, |bounds, &(_, y)| {
+            Some(bounds.map_or([y, y], |[min, max]: [f64; 2]| [min.min(y), max.max(y)]))
+        })
+    }
+
+    /// Returns the maximum number of points kept.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of points.
+    pub const fn len(&self) -> usize {
+        self.points.len() - self.start
+    }
+
+    /// Returns true if there are no points.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all the points.
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.start = 0;
+    }
+}
+
+impl Extend<(f64, f64)> for StreamingDataset {
+    fn extend<T: IntoIterator<Item = (f64, f64)>>(&mut self, iter: T) {
+        for (x, y) in iter {
+            self.push(x, y);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_oldest_points() {
+        let mut dataset = StreamingDataset::new(3);
+        dataset.extend((0..10).map(|x| (f64::from(x), 0.0)));
+        assert_eq!(dataset.data(), [(7.0, 0.0), (8.0, 0.0), (9.0, 0.0)]);
+        assert_eq!(dataset.len(), 3);
+        assert_eq!(dataset.x_bounds()[..], [7.0, 9.0]);
+        // the points never outgrow the initial allocation
+        assert_eq!(dataset.points.capacity(), 6);
+    }
+
+    #[test]
+    fn window() {
+        let mut dataset = StreamingDataset::new(100).window(2.0);
+        assert_eq!(dataset.x_bounds()[..], [0.0, 2.0]);
+        dataset.extend([(0.0, 1.0), (1.0, -1.0), (2.5, 3.0), (3.0, 2.0)]);
+        assert_eq!(dataset.data(), [(1.0, -1.0), (2.5, 3.0), (3.0, 2.0)]);
+        assert_eq!(dataset.x_bounds()[..], [1.0, 3.0]);
+        assert_eq!(dataset.y_bounds(), Some([-1.0, 3.0]));
+    }
+
+    #[test]
+    fn clear() {
+        let mut dataset = StreamingDataset::new(0);
+        dataset.push(1.0, 1.0);
+        dataset.push(2.0, 2.0);
+        assert_eq!(dataset.data(), [(2.0, 2.0)]);
+        dataset.clear();
+        assert!(dataset.is_empty());
+        assert_eq!(dataset.y_bounds(), None);
+    }
+}
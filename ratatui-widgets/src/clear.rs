@@ -1,6 +1,7 @@
 //! The [`Clear`] widget allows you to clear a certain area to allow overdrawing (e.g. for popups).
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
+use ratatui_core::style::{Style, Styled};
 use ratatui_core::widgets::Widget;
 
 /// A widget to clear/reset a certain area to allow overdrawing (e.g. for popups).
@@ -25,10 +26,43 @@ use ratatui_core::widgets::Widget;
 /// # Popup Example
 ///
 /// For a more complete example how to utilize `Clear` to realize popups see
-/// the example `examples/popup.rs`
+/// the example `examples/popup.rs`. To dim the content around a popup instead of clearing it, see
+/// [`Backdrop`].
+///
+/// [`Backdrop`]: crate::backdrop::Backdrop
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Clear;
 
+impl Clear {
+    /// Returns a widget that clears the area and sets the style of the cleared cells.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is useful for popups that have a different background than the rest of the screen, as
+    /// the background is also set on the cells that the popup content doesn't cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::Frame;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui::widgets::Clear;
+    ///
+    /// fn draw_popup(frame: &mut Frame, area: Rect) {
+    ///     frame.render_widget(Clear::with_style(Style::new().bg(Color::Blue)), area);
+    /// }
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    pub fn with_style<S: Into<Style>>(style: S) -> StyledClear {
+        StyledClear {
+            style: style.into(),
+        }
+    }
+}
+
 impl Widget for Clear {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -49,6 +83,55 @@ impl Widget for &Clear {
     }
 }
 
+/// A widget that clears an area like [`Clear`] and then sets the style of the cleared cells.
+///
+/// This is created with [`Clear::with_style`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct StyledClear {
+    style: Style,
+}
+
+impl StyledClear {
+    /// Set the style of the cleared cells
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Widget for &StyledClear {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (*self).render(area, buf);
+    }
+}
+
+impl Widget for StyledClear {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        buf.set_style(area.intersection(*buf.area()), self.style);
+    }
+}
+
+impl Styled for StyledClear {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ratatui_core::buffer::Buffer;
@@ -91,4 +174,15 @@ mod tests {
         let expected = Buffer::with_lines(["xxxxxxxxxxxxxxx"; 7]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn render_with_style() {
+        let mut buffer = Buffer::with_lines(["xxxxx"; 3]);
+        buffer.set_style(buffer.area, Style::new().red().bold());
+        Clear::with_style(Style::new().on_blue()).render(Rect::new(1, 1, 3, 10), &mut buffer);
+        let mut expected = Buffer::with_lines(["xxxxx", "x   x", "x   x"]);
+        expected.set_style(expected.area, Style::new().red().bold());
+        expected.set_style(Rect::new(1, 1, 3, 2), Style::reset().on_blue());
+        assert_eq!(buffer, expected);
+    }
 }
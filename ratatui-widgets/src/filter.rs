@@ -0,0 +1,98 @@
+//! The filter of the [`ListState`](crate::list::ListState) and
+//! [`TableState`](crate::table::TableState).
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A filter string and the indices of the items that match it.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct Filter {
+    /// The filter entered by the user
+    pub(crate) text: String,
+    /// The indices of the items that matched the filter when it was last applied, in order, or
+    /// `None` if no filter is applied
+    pub(crate) indices: Option<Vec<usize>>,
+}
+
+impl Filter {
+    pub(crate) const fn new() -> Self {
+        Self {
+            text: String::new(),
+            indices: None,
+        }
+    }
+
+    /// Returns the index in the unfiltered items of the item at `index` in the filtered items
+    ///
+    /// Indices past the end of the filtered items map to the last filtered item, as the selection
+    /// is only clamped when rendering.
+    pub(crate) fn unfiltered_index(&self, index: usize) -> Option<usize> {
+        match &self.indices {
+            Some(indices) => indices.get(index).or_else(|| indices.last()).copied(),
+            None => Some(index),
+        }
+    }
+
+    /// Keeps the items that match and returns the new index of the selected item
+    ///
+    /// If the selected item no longer matches, the next matching item is selected, or the last one
+    /// if there is none after it.
+    pub(crate) fn apply<I, F>(
+        &mut self,
+        selected: Option<usize>,
+        items: I,
+        mut matches: F,
+    ) -> Option<usize>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> bool,
+    {
+        let selected = selected.and_then(|index| self.unfiltered_index(index));
+        let indices: Vec<usize> = items
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, item)| matches(item).then_some(index))
+            .collect();
+        let selected = selected.filter(|_| !indices.is_empty()).map(|selected| {
+            indices
+                .partition_point(|&index| index < selected)
+                .min(indices.len() - 1)
+        });
+        self.indices = Some(indices);
+        selected
+    }
+
+    /// Removes the filter and returns the index of the selected item in the unfiltered items
+    pub(crate) fn clear(&mut self, selected: Option<usize>) -> Option<usize> {
+        let selected = selected.and_then(|index| self.unfiltered_index(index));
+        self.text.clear();
+        self.indices = None;
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn apply_keeps_selected_item() {
+        let items = ["apple", "banana", "cherry", "date"];
+        let mut filter = Filter::new();
+
+        let selected = filter.apply(Some(2), items, |item| item.contains('a'));
+        assert_eq!(filter.indices, Some(vec![0, 1, 3]));
+        // cherry doesn't match, so the next matching item is selected
+        assert_eq!(selected, Some(2));
+
+        let selected = filter.apply(selected, items, |item| item.contains('e'));
+        assert_eq!(filter.indices, Some(vec![0, 2, 3]));
+        assert_eq!(selected, Some(2));
+        assert_eq!(filter.unfiltered_index(1), Some(2));
+
+        assert_eq!(filter.apply(selected, items, |_| false), None);
+        assert_eq!(filter.clear(Some(0)), None);
+        assert_eq!(filter.indices, None);
+    }
+}
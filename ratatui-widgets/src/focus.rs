@@ -0,0 +1,434 @@
+//! The [`FocusManager`] type keeps track of which part of an application has the input focus.
+use alloc::vec::Vec;
+
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::Style;
+
+/// An ordered registry of focusable regions that keeps track of which one has the focus.
+///
+/// Each region is identified by an id of any type, such as an enum of the fields of a form. The
+/// order in which the ids are [registered](FocusManager::register) is the order in which
+/// [`FocusManager::focus_next`] and [`FocusManager::focus_prev`] move the focus, which is what
+/// applications usually do when Tab and Shift-Tab are pressed. The focus wraps around at the ends
+/// unless [`FocusManager::wrap_around`] is disabled. Regions that are
+/// [disabled](FocusManager::set_enabled) are skipped.
+///
+/// The area of each region can be updated when rendering with [`FocusManager::set_area`], which
+/// makes it possible to move the focus to the region under the mouse with
+/// [`FocusManager::focus_at`].
+///
+/// To style the focused region differently, set a [`FocusManager::focused_style`] and use
+/// [`FocusManager::style_for`] when rendering each region.
+///
+/// `FocusManager` doesn't handle input events itself, so it can be used with any backend.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::FocusManager;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Field {
+///     Name,
+///     Email,
+///     Submit,
+/// }
+///
+/// let mut focus = FocusManager::from_iter([Field::Name, Field::Email, Field::Submit])
+///     .focused_style(Style::new().reversed());
+/// assert_eq!(focus.focused(), Some(&Field::Name));
+///
+/// // Tab
+/// focus.focus_next();
+/// assert_eq!(focus.focused(), Some(&Field::Email));
+///
+/// // Shift-Tab twice wraps around to the last field
+/// focus.focus_prev();
+/// focus.focus_prev();
+/// assert_eq!(focus.focused(), Some(&Field::Submit));
+///
+/// // style the focused field when rendering
+/// assert_eq!(
+///     focus.style_for(&Field::Submit, Style::new()),
+///     Style::new().reversed()
+/// );
+///
+/// // focus the field under the mouse
+/// focus.set_area(&Field::Name, Rect::new(0, 0, 20, 1));
+/// focus.focus_at((5, 0));
+/// assert!(focus.is_focused(&Field::Name));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FocusManager<Id> {
+    regions: Vec<FocusRegion<Id>>,
+    /// The index of the focused region
+    focused: Option<usize>,
+    wrap_around: bool,
+    focused_style: Style,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct FocusRegion<Id> {
+    id: Id,
+    area: Rect,
+    enabled: bool,
+}
+
+impl<Id> Default for FocusManager<Id> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Id: PartialEq> FromIterator<Id> for FocusManager<Id> {
+    /// Creates a `FocusManager` with the given ids, focusing the first one.
+    fn from_iter<T: IntoIterator<Item = Id>>(iter: T) -> Self {
+        let mut focus = Self::new();
+        for id in iter {
+            focus.register(id);
+        }
+        focus.focus_first();
+        focus
+    }
+}
+
+impl<Id> FocusManager<Id> {
+    /// Creates a new `FocusManager` without any regions.
+    pub const fn new() -> Self {
+        Self {
+            regions: Vec::new(),
+            focused: None,
+            wrap_around: true,
+            focused_style: Style::new(),
+        }
+    }
+
+    /// Sets whether the focus wraps around when moving past the first or last region. Defaults
+    /// to `true`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Sets the style that [`FocusManager::style_for`] applies to the focused region.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_style = style.into();
+        self
+    }
+
+    /// Returns the ids of the registered regions, in focus order.
+    pub fn ids(&self) -> impl Iterator<Item = &Id> {
+        self.regions.iter().map(|region| &region.id)
+    }
+
+    /// Returns the id of the focused region, if any.
+    pub fn focused(&self) -> Option<&Id> {
+        self.focused.map(|index| &self.regions[index].id)
+    }
+
+    /// Removes the focus from all regions.
+    pub const fn blur(&mut self) {
+        self.focused = None;
+    }
+
+    /// Focuses the first enabled region and returns its id.
+    pub fn focus_first(&mut self) -> Option<&Id> {
+        self.focused = self.regions.iter().position(|region| region.enabled);
+        self.focused()
+    }
+
+    /// Focuses the last enabled region and returns its id.
+    pub fn focus_last(&mut self) -> Option<&Id> {
+        self.focused = self.regions.iter().rposition(|region| region.enabled);
+        self.focused()
+    }
+
+    /// Moves the focus to the next enabled region and returns its id.
+    ///
+    /// If no region is focused, the first enabled region is focused. If the last enabled region
+    /// is focused, the focus wraps around to the first one, or stays on the last one if
+    /// [`FocusManager::wrap_around`] is disabled.
+    pub fn focus_next(&mut self) -> Option<&Id> {
+        let Some(current) = self.focused else {
+            return self.focus_first();
+        };
+        let next = self.regions[current + 1..]
+            .iter()
+            .position(|region| region.enabled)
+            .map(|offset| current + 1 + offset);
+        match next {
+            Some(index) => self.focused = Some(index),
+            None if self.wrap_around => return self.focus_first(),
+            None => {}
+        }
+        self.focused()
+    }
+
+    /// Moves the focus to the previous enabled region and returns its id.
+    ///
+    /// If no region is focused, the last enabled region is focused. If the first enabled region
+    /// is focused, the focus wraps around to the last one, or stays on the first one if
+    /// [`FocusManager::wrap_around`] is disabled.
+    pub fn focus_prev(&mut self) -> Option<&Id> {
+        let Some(current) = self.focused else {
+            return self.focus_last();
+        };
+        let prev = self.regions[..current]
+            .iter()
+            .rposition(|region| region.enabled);
+        match prev {
+            Some(index) => self.focused = Some(index),
+            None if self.wrap_around => return self.focus_last(),
+            None => {}
+        }
+        self.focused()
+    }
+
+    /// Focuses the enabled region whose area contains `position` and returns its id.
+    ///
+    /// This is useful to move the focus with the mouse. The focus doesn't change if there is no
+    /// region at `position`. See [`FocusManager::set_area`].
+    pub fn focus_at<P: Into<Position>>(&mut self, position: P) -> Option<&Id> {
+        let position = position.into();
+        let index = self
+            .regions
+            .iter()
+            .position(|region| region.enabled && region.area.contains(position))?;
+        self.focused = Some(index);
+        self.focused()
+    }
+
+    /// Removes all regions.
+    pub fn clear(&mut self) {
+        self.regions.clear();
+        self.focused = None;
+    }
+}
+
+impl<Id: PartialEq> FocusManager<Id> {
+    /// Adds a region at the end of the focus order.
+    ///
+    /// Registering an id that is already registered does nothing.
+    pub fn register(&mut self, id: Id) {
+        if self.index_of(&id).is_none() {
+            self.regions.push(FocusRegion {
+                id,
+                area: Rect::ZERO,
+                enabled: true,
+            });
+        }
+    }
+
+    /// Removes a region.
+    ///
+    /// If the region was focused, the focus moves to the next enabled region, or to the previous
+    /// one if there is no next enabled region.
+    ///
+    /// Returns `false` if the id is not registered.
+    pub fn remove(&mut self, id: &Id) -> bool {
+        let Some(index) = self.index_of(id) else {
+            return false;
+        };
+        self.regions.remove(index);
+        match self.focused {
+            Some(focused) if focused > index => self.focused = Some(focused - 1),
+            Some(focused) if focused == index => self.focus_near(index),
+            _ => {}
+        }
+        true
+    }
+
+    /// Returns whether the region with the given id has the focus.
+    pub fn is_focused(&self, id: &Id) -> bool {
+        self.focused() == Some(id)
+    }
+
+    /// Focuses the region with the given id.
+    ///
+    /// Returns `false` and leaves the focus unchanged if the id is not registered or the region
+    /// is disabled.
+    pub fn focus(&mut self, id: &Id) -> bool {
+        match self.index_of(id) {
+            Some(index) if self.regions[index].enabled => {
+                self.focused = Some(index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Enables or disables a region. Disabled regions are skipped when moving the focus.
+    ///
+    /// Disabling the focused region moves the focus to the next enabled region, or to the previous
+    /// one if there is no next enabled region.
+    pub fn set_enabled(&mut self, id: &Id, enabled: bool) {
+        let Some(index) = self.index_of(id) else {
+            return;
+        };
+        self.regions[index].enabled = enabled;
+        if !enabled && self.focused == Some(index) {
+            self.focus_near(index);
+        }
+    }
+
+    /// Sets the area of a region, which is used by [`FocusManager::focus_at`].
+    ///
+    /// This is usually called when rendering the region.
+    pub fn set_area(&mut self, id: &Id, area: Rect) {
+        if let Some(index) = self.index_of(id) {
+            self.regions[index].area = area;
+        }
+    }
+
+    /// Returns the area of a region, as set by [`FocusManager::set_area`].
+    pub fn area(&self, id: &Id) -> Option<Rect> {
+        self.index_of(id).map(|index| self.regions[index].area)
+    }
+
+    /// Returns `style` patched with the [`FocusManager::focused_style`] if the region with the
+    /// given id has the focus, or `style` otherwise.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    pub fn style_for<S: Into<Style>>(&self, id: &Id, style: S) -> Style {
+        let style = style.into();
+        if self.is_focused(id) {
+            style.patch(self.focused_style)
+        } else {
+            style
+        }
+    }
+
+    /// Focuses the first enabled region at or after `index`, or the last one before it.
+    fn focus_near(&mut self, index: usize) {
+        self.focused = self.regions[index..]
+            .iter()
+            .position(|region| region.enabled)
+            .map(|offset| index + offset)
+            .or_else(|| {
+                self.regions[..index]
+                    .iter()
+                    .rposition(|region| region.enabled)
+            });
+    }
+
+    fn index_of(&self, id: &Id) -> Option<usize> {
+        self.regions.iter().position(|region| region.id == *id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager() -> FocusManager<char> {
+        FocusManager::from_iter(['a', 'b', 'c'])
+    }
+
+    #[test]
+    fn from_iter_focuses_first() {
+        let focus = manager();
+        assert_eq!(focus.focused(), Some(&'a'));
+        assert!(focus.ids().eq(&['a', 'b', 'c']));
+        assert_eq!(FocusManager::<char>::default().focused(), None);
+    }
+
+    #[test]
+    fn next_and_prev_wrap_around() {
+        let mut focus = manager();
+        assert_eq!(focus.focus_next(), Some(&'b'));
+        assert_eq!(focus.focus_next(), Some(&'c'));
+        assert_eq!(focus.focus_next(), Some(&'a'));
+        assert_eq!(focus.focus_prev(), Some(&'c'));
+    }
+
+    #[test]
+    fn next_and_prev_without_wrap_around() {
+        let mut focus = manager().wrap_around(false);
+        assert_eq!(focus.focus_prev(), Some(&'a'));
+        focus.focus_last();
+        assert_eq!(focus.focus_next(), Some(&'c'));
+    }
+
+    #[test]
+    fn next_and_prev_without_focus() {
+        let mut focus = manager();
+        focus.blur();
+        assert_eq!(focus.focus_next(), Some(&'a'));
+        focus.blur();
+        assert_eq!(focus.focus_prev(), Some(&'c'));
+    }
+
+    #[test]
+    fn skips_disabled_regions() {
+        let mut focus = manager();
+        focus.set_enabled(&'b', false);
+        assert_eq!(focus.focus_next(), Some(&'c'));
+        assert_eq!(focus.focus_prev(), Some(&'a'));
+        assert!(!focus.focus(&'b'));
+        assert_eq!(focus.focused(), Some(&'a'));
+
+        // disabling the focused region moves the focus
+        focus.set_enabled(&'a', false);
+        assert_eq!(focus.focused(), Some(&'c'));
+        focus.set_enabled(&'c', false);
+        assert_eq!(focus.focused(), None);
+    }
+
+    #[test]
+    fn register_and_remove() {
+        let mut focus = manager();
+        focus.register('a');
+        focus.register('d');
+        assert!(focus.ids().eq(&['a', 'b', 'c', 'd']));
+
+        assert!(focus.focus(&'c'));
+        assert!(focus.remove(&'a'));
+        assert_eq!(focus.focused(), Some(&'c'));
+        assert!(focus.remove(&'c'));
+        assert_eq!(focus.focused(), Some(&'d'));
+        assert!(!focus.remove(&'c'));
+
+        focus.clear();
+        assert_eq!(focus.focused(), None);
+    }
+
+    #[test]
+    fn focus_at() {
+        let mut focus = manager();
+        focus.set_area(&'b', Rect::new(0, 1, 10, 1));
+        focus.set_area(&'c', Rect::new(0, 2, 10, 1));
+        assert_eq!(focus.area(&'c'), Some(Rect::new(0, 2, 10, 1)));
+        assert_eq!(focus.focus_at((3, 2)), Some(&'c'));
+        assert_eq!(focus.focus_at((3, 5)), None);
+        assert_eq!(focus.focused(), Some(&'c'));
+    }
+
+    #[test]
+    fn style_for() {
+        let focus = manager().focused_style(Style::new().bold());
+        assert_eq!(
+            focus.style_for(&'a', Style::new().red()),
+            Style::new().red().bold()
+        );
+        assert_eq!(
+            focus.style_for(&'b', Style::new().red()),
+            Style::new().red()
+        );
+    }
+}
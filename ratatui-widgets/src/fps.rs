@@ -0,0 +1,159 @@
+//! The [`FpsWidget`] widget displays the frame rate and timings reported by the terminal.
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Write as _;
+use core::time::Duration;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::terminal::FrameStats;
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+/// A widget that displays the [`FrameStats`] of a frame on a single line.
+///
+/// The line shows the frame rate, the time spent rendering and flushing the frame, the number of
+/// cells that changed and, if the backend counts them, the number of bytes written, e.g.
+/// `60.0 fps · render 1.20 ms · flush 0.30 ms · 42 cells · 512 B`. It is meant to be rendered in a
+/// corner of the screen while measuring the performance of an app. The line is truncated to the
+/// width of the area.
+///
+/// The statistics are reported by [`Terminal::on_frame`] after a frame is drawn, so the widget
+/// shows the statistics of the previous frame.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+///
+/// use ratatui::backend::TestBackend;
+/// use ratatui::layout::{Constraint, Layout};
+/// use ratatui::widgets::FpsWidget;
+/// use ratatui::{FrameStats, Terminal};
+///
+/// let mut terminal = Terminal::new(TestBackend::new(60, 10))?;
+/// let last_stats = Arc::new(Mutex::new(FrameStats::default()));
+/// let hook_stats = Arc::clone(&last_stats);
+/// terminal.on_frame(move |stats| *hook_stats.lock().unwrap() = stats);
+///
+/// terminal.draw(|frame| {
+///     let [_, footer] =
+///         Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(frame.area());
+///     let stats = *last_stats.lock().unwrap();
+///     frame.render_widget(FpsWidget::new(stats), footer);
+/// })?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`Terminal::on_frame`]: ratatui_core::terminal::Terminal::on_frame
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FpsWidget {
+    stats: FrameStats,
+    style: Style,
+}
+
+impl FpsWidget {
+    /// Creates a new `FpsWidget` that displays the given statistics.
+    pub const fn new(stats: FrameStats) -> Self {
+        Self {
+            stats,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the style of the line
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Widget for &FpsWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (*self).render(area, buf);
+    }
+}
+
+impl Widget for FpsWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let stats = self.stats;
+        let fps = stats
+            .fps()
+            .map_or_else(|| "-".into(), |fps| format!("{fps:.1}"));
+        let mut text = format!(
+            "{fps} fps · render {} · flush {} · {} cells",
+            millis(stats.render_time),
+            millis(stats.flush_time),
+            stats.cells_changed,
+        );
+        if let Some(bytes) = stats.bytes_written {
+            let _ = write!(text, " · {bytes} B");
+        }
+        Line::styled(text, self.style).render(area, buf);
+    }
+}
+
+impl Styled for FpsWidget {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// Formats a duration in milliseconds with two decimals.
+fn millis(duration: Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    fn stats() -> FrameStats {
+        let mut stats = FrameStats::default();
+        stats.interval = Duration::from_millis(20);
+        stats.render_time = Duration::from_micros(1200);
+        stats.flush_time = Duration::from_micros(300);
+        stats.cells_changed = 42;
+        stats
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 56, 1));
+        FpsWidget::new(stats()).green().render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["50.0 fps · render 1.20 ms · flush 0.30 ms · 42 cells    "]);
+        expected.set_style(expected.area, Color::Green);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_bytes_written_and_first_frame() {
+        let mut stats = stats();
+        stats.interval = Duration::ZERO;
+        stats.bytes_written = Some(512);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 60, 1));
+        FpsWidget::new(stats).render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["- fps · render 1.20 ms · flush 0.30 ms · 42 cells · 512 B   "])
+        );
+    }
+}
@@ -1,14 +1,15 @@
-//! The [`Gauge`] widget is used to display a horizontal progress bar.
+//! The [`Gauge`] widget is used to display a horizontal or vertical progress bar.
 use alloc::format;
 
+use ratatui_core::accessibility::{Accessible, Role, SemanticNode, Semantics};
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::Rect;
+use ratatui_core::layout::{Direction, Rect};
 use ratatui_core::style::{Color, Style, Styled};
 use ratatui_core::symbols;
 use ratatui_core::text::{Line, Span};
 use ratatui_core::widgets::Widget;
 
-use crate::block::{Block, BlockExt};
+use crate::block::{Block, BlockExt, describe_block};
 #[cfg(not(feature = "std"))]
 use crate::polyfills::F64Polyfills;
 
@@ -21,7 +22,13 @@ use crate::polyfills::F64Polyfills;
 /// The associated label is always centered horizontally and vertically. If not set with
 /// [`Gauge::label`], the label is the percentage of the bar filled.
 ///
-/// You might want to have a higher precision bar using [`Gauge::use_unicode`].
+/// You might want to have a higher precision bar using [`Gauge::use_unicode`]. The symbols used to
+/// draw the bar can be changed with [`Gauge::block_set`] (or [`Gauge::bar_set`] for a vertical
+/// gauge).
+///
+/// The bar fills from left to right by default. Use [`Gauge::direction`] with
+/// [`Direction::Vertical`] for a bar that fills from bottom to top, e.g. for audio meters or
+/// battery indicators.
 ///
 /// This can be useful to indicate the progression of a task, like a download.
 ///
@@ -41,7 +48,7 @@ use crate::polyfills::F64Polyfills;
 ///
 /// - [`LineGauge`] for a thin progress bar
 #[expect(clippy::struct_field_names)] // gauge_style needs to be differentiated to style
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Gauge<'a> {
     block: Option<Block<'a>>,
     ratio: f64,
@@ -49,6 +56,27 @@ pub struct Gauge<'a> {
     use_unicode: bool,
     style: Style,
     gauge_style: Style,
+    unfilled_style: Style,
+    direction: Direction,
+    block_set: symbols::block::Set<'a>,
+    bar_set: symbols::bar::Set<'a>,
+}
+
+impl Default for Gauge<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            ratio: 0.0,
+            label: None,
+            use_unicode: false,
+            style: Style::default(),
+            gauge_style: Style::default(),
+            unfilled_style: Style::default(),
+            direction: Direction::Horizontal,
+            block_set: symbols::block::NINE_LEVELS,
+            bar_set: symbols::bar::NINE_LEVELS,
+        }
+    }
 }
 
 impl<'a> Gauge<'a> {
@@ -139,6 +167,34 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Sets the style of the unfilled part of the bar.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is added to the [gauge style](Gauge::gauge_style). The background color is also
+    /// used behind the partially filled cell when [`Gauge::use_unicode`] is enabled, so that the
+    /// bar can be drawn with a different color for its track, like the progress bars of tools such
+    /// as pip.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::Gauge;
+    ///
+    /// let gauge = Gauge::default()
+    ///     .gauge_style(Style::new().green())
+    ///     .unfilled_style(Style::new().on_dark_gray())
+    ///     .use_unicode(true)
+    ///     .ratio(0.42);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unfilled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.unfilled_style = style.into();
+        self
+    }
+
     /// Sets whether to use unicode characters to display the progress bar.
     ///
     /// This enables the use of
@@ -149,6 +205,48 @@ impl<'a> Gauge<'a> {
         self.use_unicode = unicode;
         self
     }
+
+    /// Sets the direction in which the bar fills.
+    ///
+    /// [`Direction::Horizontal`] (the default) fills from left to right and
+    /// [`Direction::Vertical`] fills from bottom to top.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::layout::Direction;
+    /// use ratatui::widgets::Gauge;
+    ///
+    /// let gauge = Gauge::default()
+    ///     .direction(Direction::Vertical)
+    ///     .use_unicode(true)
+    ///     .percent(75);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the symbols used to draw a horizontal bar.
+    ///
+    /// The fractional symbols are only used when [`Gauge::use_unicode`] is enabled. Defaults to
+    /// [`symbols::block::NINE_LEVELS`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn block_set(mut self, set: symbols::block::Set<'a>) -> Self {
+        self.block_set = set;
+        self
+    }
+
+    /// Sets the symbols used to draw a vertical bar.
+    ///
+    /// The fractional symbols are only used when [`Gauge::use_unicode`] is enabled. Defaults to
+    /// [`symbols::bar::NINE_LEVELS`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bar_set(mut self, set: symbols::bar::Set<'a>) -> Self {
+        self.bar_set = set;
+        self
+    }
 }
 
 impl Widget for Gauge<'_> {
@@ -157,6 +255,20 @@ impl Widget for Gauge<'_> {
     }
 }
 
+/// Describes the gauge as a progress bar labeled with its label, whose value is the percentage.
+impl Accessible for Gauge<'_> {
+    fn describe(&self, area: Rect, semantics: &mut Semantics) {
+        let level = describe_block(self.block.as_ref(), area, semantics);
+        let mut node = SemanticNode::new(Role::ProgressBar, self.block.inner_if_some(area))
+            .value(format!("{}%", f64::round(self.ratio * 100.0)))
+            .level(level);
+        if let Some(label) = &self.label {
+            node = node.label(label.content.as_ref());
+        }
+        semantics.push(node);
+    }
+}
+
 impl Widget for &Gauge<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
@@ -182,51 +294,83 @@ impl Gauge<'_> {
         let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
         let label_row = gauge_area.top() + gauge_area.height / 2;
 
-        // the gauge will be filled proportionally to the ratio
-        let filled_width = f64::from(gauge_area.width) * self.ratio;
+        // the gauge will be filled proportionally to the ratio, from the left or the bottom
+        let length = match self.direction {
+            Direction::Horizontal => gauge_area.width,
+            Direction::Vertical => gauge_area.height,
+        };
+        let filled_length = f64::from(length) * self.ratio;
         let end = if self.use_unicode {
-            gauge_area.left() + filled_width.floor() as u16
+            filled_length.floor() as u16
         } else {
-            gauge_area.left() + filled_width.round() as u16
+            filled_length.round() as u16
+        };
+        let (full, empty) = match self.direction {
+            Direction::Horizontal => (self.block_set.full, self.block_set.empty),
+            Direction::Vertical => (self.bar_set.full, self.bar_set.empty),
         };
         for y in gauge_area.top()..gauge_area.bottom() {
-            // render the filled area (left to end)
-            for x in gauge_area.left()..end {
-                // Use full block for the filled part of the gauge and spaces for the part that is
-                // covered by the label. Note that the background and foreground colors are swapped
-                // for the label part, otherwise the gauge will be inverted
-                if x < label_col || x > label_col + clamped_label_width || y != label_row {
-                    buf[(x, y)]
-                        .set_symbol(symbols::block::FULL)
-                        .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+            for x in gauge_area.left()..gauge_area.right() {
+                let position = match self.direction {
+                    Direction::Horizontal => x - gauge_area.left(),
+                    Direction::Vertical => gauge_area.bottom() - 1 - y,
+                };
+                if position < end {
+                    // Use full block for the filled part of the gauge and spaces for the part that
+                    // is covered by the label. Note that the background and foreground colors are
+                    // swapped for the label part, otherwise the gauge will be inverted
+                    if x < label_col || x > label_col + clamped_label_width || y != label_row {
+                        buf[(x, y)]
+                            .set_symbol(full)
+                            .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
+                            .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+                    } else {
+                        buf[(x, y)]
+                            .set_symbol(" ")
+                            .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
+                            .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                    }
+                } else if position == end && self.use_unicode && self.ratio < 1.0 {
+                    let cell = buf[(x, y)].set_symbol(self.partial_symbol(filled_length % 1.0));
+                    if let Some(bg) = self.unfilled_style.bg {
+                        cell.set_bg(bg);
+                    }
                 } else {
-                    buf[(x, y)]
-                        .set_symbol(" ")
-                        .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                    buf[(x, y)].set_symbol(empty).set_style(self.unfilled_style);
                 }
             }
-            if self.use_unicode && self.ratio < 1.0 {
-                buf[(end, y)].set_symbol(get_unicode_block(filled_width % 1.0));
-            }
         }
         // render the label
         buf.set_span(label_col, label_row, label, clamped_label_width);
     }
-}
 
-fn get_unicode_block<'a>(frac: f64) -> &'a str {
-    match (frac * 8.0).round() as u16 {
-        1 => symbols::block::ONE_EIGHTH,
-        2 => symbols::block::ONE_QUARTER,
-        3 => symbols::block::THREE_EIGHTHS,
-        4 => symbols::block::HALF,
-        5 => symbols::block::FIVE_EIGHTHS,
-        6 => symbols::block::THREE_QUARTERS,
-        7 => symbols::block::SEVEN_EIGHTHS,
-        8 => symbols::block::FULL,
-        _ => " ",
+    /// Returns the symbol for a cell that is filled by the given fraction.
+    fn partial_symbol(&self, frac: f64) -> &str {
+        let levels = match self.direction {
+            Direction::Horizontal => [
+                self.block_set.empty,
+                self.block_set.one_eighth,
+                self.block_set.one_quarter,
+                self.block_set.three_eighths,
+                self.block_set.half,
+                self.block_set.five_eighths,
+                self.block_set.three_quarters,
+                self.block_set.seven_eighths,
+                self.block_set.full,
+            ],
+            Direction::Vertical => [
+                self.bar_set.empty,
+                self.bar_set.one_eighth,
+                self.bar_set.one_quarter,
+                self.bar_set.three_eighths,
+                self.bar_set.half,
+                self.bar_set.five_eighths,
+                self.bar_set.three_quarters,
+                self.bar_set.seven_eighths,
+                self.bar_set.full,
+            ],
+        };
+        levels[((frac * 8.0).round() as usize).min(8)]
     }
 }
 
@@ -242,7 +386,8 @@ fn get_unicode_block<'a>(frac: f64) -> &'a str {
 /// The associated label is always left-aligned. If not set with [`LineGauge::label`], the label is
 /// the percentage of the bar filled.
 ///
-/// You can also set the symbols used to draw the bar with [`LineGauge::line_set`].
+/// You can also set the symbols used to draw the bar with [`LineGauge::symbol_set`], which also
+/// sets a symbol for a half filled cell to display a higher precision bar.
 ///
 /// To style the gauge line use [`LineGauge::filled_style`] and [`LineGauge::unfilled_style`] which
 /// let you pick a color for foreground (i.e. line) and background of the filled and unfilled part
@@ -273,6 +418,7 @@ pub struct LineGauge<'a> {
     style: Style,
     filled_symbol: &'a str,
     unfilled_symbol: &'a str,
+    half_filled_symbol: Option<&'a str>,
     filled_style: Style,
     unfilled_style: Style,
 }
@@ -286,6 +432,7 @@ impl Default for LineGauge<'_> {
             style: Style::default(),
             filled_symbol: symbols::line::HORIZONTAL,
             unfilled_symbol: symbols::line::HORIZONTAL,
+            half_filled_symbol: None,
             filled_style: Style::default(),
             unfilled_style: Style::default(),
         }
@@ -350,6 +497,43 @@ impl<'a> LineGauge<'a> {
         self
     }
 
+    /// Sets the symbol for the cell where the filled part ends when that cell is half filled.
+    ///
+    /// The symbol is drawn with the [filled style](LineGauge::filled_style). By default there is
+    /// no such symbol and the filled part is rounded down to whole cells.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn half_filled_symbol(mut self, symbol: &'a str) -> Self {
+        self.half_filled_symbol = Some(symbol);
+        self
+    }
+
+    /// Sets the symbols for the filled, half filled and unfilled parts of the gauge.
+    ///
+    /// See [`symbols::line_gauge::Set`] for more information. Predefined sets are also available,
+    /// see [`NORMAL`](symbols::line_gauge::NORMAL), [`THICK`](symbols::line_gauge::THICK),
+    /// [`BLOCK`](symbols::line_gauge::BLOCK) and [`SHADE`](symbols::line_gauge::SHADE).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::symbols;
+    /// use ratatui::widgets::LineGauge;
+    ///
+    /// let gauge = LineGauge::default()
+    ///     .symbol_set(symbols::line_gauge::THICK)
+    ///     .filled_style(Style::new().magenta())
+    ///     .unfilled_style(Style::new().dark_gray())
+    ///     .ratio(0.42);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol_set(mut self, set: symbols::line_gauge::Set<'a>) -> Self {
+        self.filled_symbol = set.filled;
+        self.unfilled_symbol = set.unfilled;
+        self.half_filled_symbol = Some(set.half_filled);
+        self
+    }
+
     /// Sets the label to display.
     ///
     /// With `LineGauge`, labels are only on the left, see [`Gauge`] for a centered label.
@@ -439,13 +623,25 @@ impl Widget for &LineGauge<'_> {
             return;
         }
 
-        let end = start
-            + (f64::from(gauge_area.right().saturating_sub(start)) * self.ratio).floor() as u16;
+        // the filled part is counted in half cells when there is a half filled symbol
+        let filled_length = f64::from(gauge_area.right().saturating_sub(start)) * self.ratio;
+        let half_filled = self
+            .half_filled_symbol
+            .filter(|_| filled_length % 1.0 >= 0.5);
+        let end = start + filled_length.floor() as u16;
         for col in start..end {
             buf[(col, row)]
                 .set_symbol(self.filled_symbol)
                 .set_style(self.filled_style);
         }
+        let end = if let Some(symbol) = half_filled {
+            buf[(end, row)]
+                .set_symbol(symbol)
+                .set_style(self.filled_style);
+            end + 1
+        } else {
+            end
+        };
         for col in end..gauge_area.right() {
             buf[(col, row)]
                 .set_symbol(self.unfilled_symbol)
@@ -480,6 +676,8 @@ impl Styled for LineGauge<'_> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     use ratatui_core::style::{Color, Modifier, Style, Stylize};
     use ratatui_core::symbols;
 
@@ -515,6 +713,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_vertical() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 5));
+        Gauge::default()
+            .direction(Direction::Vertical)
+            .use_unicode(true)
+            .ratio(0.3)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["   ", "   ", "30%", "▄▄▄", "███"])
+        );
+    }
+
+    #[test]
+    fn render_custom_block_set() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Gauge::default()
+            .block_set(symbols::block::THREE_LEVELS)
+            .use_unicode(true)
+            .ratio(0.6)
+            .label("")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["██▌ "]));
+    }
+
+    #[test]
+    fn render_unfilled_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Gauge::default()
+            .gauge_style(Color::Green)
+            .unfilled_style(Style::new().on_blue())
+            .use_unicode(true)
+            .ratio(0.6)
+            .label("")
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["██▍ "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().green().bg(Color::Reset));
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().green().on_blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_line_gauge_symbol_set() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        LineGauge::default()
+            .symbol_set(symbols::line_gauge::THICK)
+            .ratio(0.5)
+            .label("")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" ━━━━╸━━━━"]));
+
+        LineGauge::default()
+            .symbol_set(symbols::line_gauge::SHADE)
+            .ratio(0.4)
+            .label("")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" ███▌░░░░░"]));
+    }
+
     #[test]
     fn line_gauge_can_be_stylized() {
         assert_eq!(
@@ -581,6 +839,7 @@ mod tests {
                 style: Style::default(),
                 filled_symbol: symbols::line::HORIZONTAL,
                 unfilled_symbol: symbols::line::HORIZONTAL,
+                half_filled_symbol: None,
                 filled_style: Style::default(),
                 unfilled_style: Style::default()
             }
@@ -620,4 +879,20 @@ mod tests {
         // This should not panic, even if the buffer has zero size.
         line_gauge.render(buffer.area, &mut buffer);
     }
+
+    #[test]
+    fn describe() {
+        let mut semantics = Semantics::new();
+        Gauge::default()
+            .percent(42)
+            .label("CPU")
+            .describe(Rect::new(0, 0, 10, 1), &mut semantics);
+        Gauge::default()
+            .ratio(0.5)
+            .describe(Rect::new(0, 1, 10, 1), &mut semantics);
+        assert_eq!(
+            semantics.to_string(),
+            "progress bar: CPU, 42%\nprogress bar: 50%\n"
+        );
+    }
 }
@@ -0,0 +1,378 @@
+//! The [`Heatmap`] widget is used to display a 2D array of values as colors.
+
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Style, Styled};
+use ratatui_core::symbols::half_block;
+use ratatui_core::widgets::Widget;
+
+use crate::block::{Block, BlockExt};
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// A widget to display a 2D array of values as colors, e.g. the load of each core over time or
+/// the time spent in each function of a profile.
+///
+/// Each value is mapped to a color of a [`ColorScale`], from the lowest value to the highest. The
+/// bounds of the values default to the minimum and maximum of the data, and can be set with
+/// [`Heatmap::bounds`].
+///
+/// Each value is drawn as half a cell using the `▀` character, so that each line of the terminal
+/// shows two rows of values: the foreground color shows the upper row and the background color
+/// the lower row. The first value of the first row is drawn at the top left of the area, and the
+/// values that don't fit in the area are not drawn. `NaN` values are not drawn, and show the
+/// style of the widget.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{Block, ColorScale, Heatmap};
+///
+/// let load = [
+///     [0.1, 0.5, 0.9, 0.7],
+///     [0.2, 0.4, 0.8, 1.0],
+///     [0.0, 0.3, 0.6, 0.5],
+/// ];
+/// let heatmap = Heatmap::new(load)
+///     .block(Block::bordered().title("CPU load"))
+///     .bounds([0.0, 1.0])
+///     .scale(ColorScale::Magma);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Heatmap<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// The rows of values
+    data: Vec<Vec<f64>>,
+    /// The values mapped to the first and last color of the scale, or the bounds of the data
+    bounds: Option<[f64; 2]>,
+    /// The colors the values are mapped to
+    scale: ColorScale<'a>,
+}
+
+/// The colors that the values of a [`Heatmap`] are mapped to.
+///
+/// The built-in scales approximate the perceptually uniform color maps of matplotlib, which stay
+/// readable when printed in grayscale or seen with color blindness. They use RGB colors, so they
+/// need a terminal with true color support.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ColorScale<'a> {
+    /// From dark purple through blue and green to yellow.
+    #[default]
+    Viridis,
+    /// From black through purple and red to pale yellow.
+    Magma,
+    /// From black through purple and orange to pale yellow.
+    Inferno,
+    /// From dark blue through purple and orange to yellow.
+    Plasma,
+    /// From black to white.
+    Grayscale,
+    /// Evenly spaced colors from the lowest value to the highest.
+    ///
+    /// RGB colors are interpolated between the given colors, while other colors are used as is
+    /// for the values closest to them.
+    Custom(&'a [Color]),
+}
+
+const VIRIDIS: &[Color] = &[
+    Color::Rgb(68, 1, 84),
+    Color::Rgb(59, 82, 139),
+    Color::Rgb(33, 145, 140),
+    Color::Rgb(94, 201, 98),
+    Color::Rgb(253, 231, 37),
+];
+
+const MAGMA: &[Color] = &[
+    Color::Rgb(0, 0, 4),
+    Color::Rgb(59, 15, 112),
+    Color::Rgb(140, 41, 129),
+    Color::Rgb(222, 73, 104),
+    Color::Rgb(254, 159, 109),
+    Color::Rgb(252, 253, 191),
+];
+
+const INFERNO: &[Color] = &[
+    Color::Rgb(0, 0, 4),
+    Color::Rgb(66, 10, 104),
+    Color::Rgb(147, 38, 103),
+    Color::Rgb(221, 81, 58),
+    Color::Rgb(252, 165, 10),
+    Color::Rgb(252, 255, 164),
+];
+
+const PLASMA: &[Color] = &[
+    Color::Rgb(13, 8, 135),
+    Color::Rgb(106, 0, 168),
+    Color::Rgb(177, 42, 144),
+    Color::Rgb(225, 100, 98),
+    Color::Rgb(252, 166, 54),
+    Color::Rgb(240, 249, 33),
+];
+
+const GRAYSCALE: &[Color] = &[Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)];
+
+impl ColorScale<'_> {
+    /// Returns the color of a position on the scale, from `0.0` for the lowest value to `1.0` for
+    /// the highest. Positions outside of this range are clamped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::ColorScale;
+    ///
+    /// assert_eq!(ColorScale::Grayscale.color(0.0), Color::Rgb(0, 0, 0));
+    /// assert_eq!(ColorScale::Grayscale.color(0.5), Color::Rgb(128, 128, 128));
+    /// ```
+    pub fn color(self, position: f64) -> Color {
+        let colors = match self {
+            Self::Viridis => VIRIDIS,
+            Self::Magma => MAGMA,
+            Self::Inferno => INFERNO,
+            Self::Plasma => PLASMA,
+            Self::Grayscale => GRAYSCALE,
+            Self::Custom(colors) => colors,
+        };
+        let Some(last) = colors.len().checked_sub(1) else {
+            return Color::Reset;
+        };
+        let position = if position.is_nan() {
+            0.0
+        } else {
+            position.clamp(0.0, 1.0) * last as f64
+        };
+        let index = (position.floor() as usize).min(last);
+        let fraction = position - index as f64;
+        let from = colors[index];
+        let to = colors[(index + 1).min(last)];
+        match (from, to) {
+            (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => Color::Rgb(
+                lerp(r1, r2, fraction),
+                lerp(g1, g2, fraction),
+                lerp(b1, b2, fraction),
+            ),
+            _ if fraction < 0.5 => from,
+            _ => to,
+        }
+    }
+}
+
+/// Interpolates between two color components.
+fn lerp(from: u8, to: u8, fraction: f64) -> u8 {
+    let value = f64::from(from) + (f64::from(to) - f64::from(from)) * fraction;
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+impl<'a> Heatmap<'a> {
+    /// Creates a new `Heatmap` with the given rows of values.
+    ///
+    /// The rows may have different lengths.
+    pub fn new<R, V>(data: R) -> Self
+    where
+        R: IntoIterator<Item = V>,
+        V: IntoIterator<Item = f64>,
+    {
+        Self {
+            data: data
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Wraps the heatmap with the given `block`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the entire widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The background shows where there is no value.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the values mapped to the first and last color of the scale, as `[min, max]`.
+    ///
+    /// Values outside of the bounds get the first or last color. Defaults to the minimum and
+    /// maximum of the data, which makes the colors change when the data changes. Setting the
+    /// bounds keeps the colors stable, e.g. `[0.0, 100.0]` for percentages.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds(mut self, bounds: [f64; 2]) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the colors that the values are mapped to. Defaults to [`ColorScale::Viridis`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scale(mut self, scale: ColorScale<'a>) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns the bounds of the values, which are the minimum and maximum of the data unless set.
+    fn bounds_or_data(&self) -> [f64; 2] {
+        self.bounds.unwrap_or_else(|| {
+            self.data
+                .iter()
+                .flatten()
+                .filter(|value| !value.is_nan())
+                .fold([f64::INFINITY, f64::NEG_INFINITY], |[min, max], &value| {
+                    [min.min(value), max.max(value)]
+                })
+        })
+    }
+
+    /// Returns the color of a value, or `None` if there is no value.
+    fn color(&self, [min, max]: [f64; 2], row: usize, column: usize) -> Option<Color> {
+        let value = *self.data.get(row)?.get(column)?;
+        if value.is_nan() {
+            return None;
+        }
+        let range = max - min;
+        let position = if range > 0.0 {
+            (value - min) / range
+        } else {
+            0.0
+        };
+        Some(self.scale.color(position))
+    }
+}
+
+impl Styled for Heatmap<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for Heatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Heatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        let bounds = self.bounds_or_data();
+        for (y, row) in (inner.top()..inner.bottom()).zip((0..).step_by(2)) {
+            for (x, column) in (inner.left()..inner.right()).zip(0..) {
+                let upper = self.color(bounds, row, column);
+                let lower = self.color(bounds, row + 1, column);
+                let (symbol, fg, bg) = match (upper, lower) {
+                    (Some(upper), Some(lower)) => (half_block::UPPER, upper, Some(lower)),
+                    (Some(upper), None) => (half_block::UPPER, upper, None),
+                    (None, Some(lower)) => (half_block::LOWER, lower, None),
+                    (None, None) => continue,
+                };
+                let cell = &mut buf[(x, y)];
+                cell.set_char(symbol).set_fg(fg);
+                if let Some(bg) = bg {
+                    cell.set_bg(bg);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_scale_ends() {
+        assert_eq!(ColorScale::Viridis.color(0.0), Color::Rgb(68, 1, 84));
+        assert_eq!(ColorScale::Viridis.color(1.0), Color::Rgb(253, 231, 37));
+        assert_eq!(ColorScale::Viridis.color(-1.0), Color::Rgb(68, 1, 84));
+        assert_eq!(ColorScale::Viridis.color(2.0), Color::Rgb(253, 231, 37));
+        assert_eq!(ColorScale::Viridis.color(f64::NAN), Color::Rgb(68, 1, 84));
+    }
+
+    #[test]
+    fn custom_color_scale() {
+        let colors = [Color::Rgb(0, 0, 0), Color::Rgb(100, 200, 0), Color::Red];
+        let scale = ColorScale::Custom(&colors);
+        assert_eq!(scale.color(0.25), Color::Rgb(50, 100, 0));
+        assert_eq!(scale.color(0.5), Color::Rgb(100, 200, 0));
+        assert_eq!(scale.color(0.7), Color::Rgb(100, 200, 0));
+        assert_eq!(scale.color(0.8), Color::Red);
+        assert_eq!(ColorScale::Custom(&[]).color(0.5), Color::Reset);
+    }
+
+    #[test]
+    fn render() {
+        let data = [[0.0, 1.0, 2.0], [2.0, f64::NAN, 0.0], [1.0, 1.0, 1.0]];
+        let heatmap = Heatmap::new(data)
+            .scale(ColorScale::Grayscale)
+            .style(Style::new().on_blue());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 3));
+        heatmap.render(buf.area, &mut buf);
+
+        let black = Color::Rgb(0, 0, 0);
+        let gray = Color::Rgb(128, 128, 128);
+        let white = Color::Rgb(255, 255, 255);
+        let mut expected = Buffer::with_lines(["▀▀", "▀▀", "  "]);
+        expected.set_style(buf.area, Style::new().on_blue());
+        expected[(0, 0)].set_fg(black).set_bg(white);
+        expected[(1, 0)].set_fg(gray);
+        expected[(0, 1)].set_fg(gray);
+        expected[(1, 1)].set_fg(gray);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_with_bounds_and_block() {
+        let heatmap = Heatmap::new([[-1.0, 5.0], [0.5, 0.5]])
+            .bounds([0.0, 1.0])
+            .scale(ColorScale::Grayscale)
+            .block(Block::bordered());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        heatmap.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["┌──┐", "│▀▀│", "└──┘"]);
+        let gray = Color::Rgb(128, 128, 128);
+        expected[(1, 1)].set_fg(Color::Rgb(0, 0, 0)).set_bg(gray);
+        expected[(2, 1)]
+            .set_fg(Color::Rgb(255, 255, 255))
+            .set_bg(gray);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_lower_half_only() {
+        let heatmap = Heatmap::new([alloc::vec![f64::NAN], alloc::vec![1.0]]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        heatmap.render(buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "▄");
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(68, 1, 84));
+    }
+}
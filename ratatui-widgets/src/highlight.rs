@@ -0,0 +1,378 @@
+//! Syntax highlighting of source code for code viewers.
+//!
+//! A [`Highlighter`] turns lines of source code into styled [`Line`]s. Highlighting is usually
+//! stateful (a line that is inside a multi-line comment is highlighted differently from one that
+//! is not), so the state at the end of each line is passed on to the next line.
+//!
+//! [`Highlighted`] adapts a highlighter to a viewer that only shows a part of the source at a
+//! time: it highlights lines on demand for the visible range and remembers the highlighter state
+//! at regular intervals, so scrolling through a large file never highlights the whole file up
+//! front, and jumping back only re-highlights the lines from the nearest remembered state. The
+//! visible lines are returned as [`Text`], which can be rendered with a [`Paragraph`].
+//!
+//! When the `syntect` feature is enabled, [`SyntectHighlighter`] implements [`Highlighter`] with
+//! the [`syntect`] crate.
+//!
+//! [`highlight_matches`] highlights the occurrences of a search string in a [`Text`], e.g. the
+//! filter of a picker.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::layout::Rect;
+//! use ratatui::text::Line;
+//! use ratatui::widgets::highlight::{Highlighted, Highlighter};
+//! use ratatui::widgets::{Paragraph, Widget};
+//! # let mut buf = ratatui::buffer::Buffer::empty(Rect::new(0, 0, 20, 2));
+//!
+//! /// Highlights the lines that are comments.
+//! struct Comments;
+//!
+//! impl Highlighter for Comments {
+//!     type State = ();
+//!
+//!     fn start(&self) -> Self::State {}
+//!
+//!     fn highlight_line<'a>(&self, _state: &mut Self::State, line: &'a str) -> Line<'a> {
+//!         if line.trim_start().starts_with("//") {
+//!             Line::styled(line, ratatui::style::Color::DarkGray)
+//!         } else {
+//!             Line::raw(line)
+//!         }
+//!     }
+//! }
+//!
+//! let mut code = Highlighted::new("// comment\nfn main() {}\n", Comments);
+//! let scroll = 0;
+//! let text = code.text(scroll..scroll + usize::from(buf.area.height));
+//! Paragraph::new(text).render(buf.area, &mut buf);
+//! ```
+//!
+//! [`Paragraph`]: crate::paragraph::Paragraph
+//! [`syntect`]: https://crates.io/crates/syntect
+use alloc::borrow::{Cow, ToOwned};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span, Text};
+
+#[cfg(feature = "syntect")]
+mod syntect;
+
+#[cfg(feature = "syntect")]
+pub use self::syntect::SyntectHighlighter;
+
+/// Converts lines of source code into styled [`Line`]s.
+///
+/// Lines are always highlighted in order, starting from the [`start`](Self::start) state. The state
+/// is updated by each line and passed to the next one, which allows highlighting constructs that
+/// span several lines. Highlighters without such constructs can use `()` as their state.
+///
+/// See the [module documentation](self) for an example.
+pub trait Highlighter {
+    /// The state of the highlighter between two lines.
+    ///
+    /// The state is cloned to remember it at regular intervals, so it should be reasonably cheap to
+    /// clone.
+    type State: Clone;
+
+    /// Returns the state at the start of the source.
+    fn start(&self) -> Self::State;
+
+    /// Highlights a line of source, without its line ending, and updates `state` for the next line.
+    fn highlight_line<'a>(&self, state: &mut Self::State, line: &'a str) -> Line<'a>;
+}
+
+impl<H: Highlighter + ?Sized> Highlighter for &H {
+    type State = H::State;
+
+    fn start(&self) -> Self::State {
+        (**self).start()
+    }
+
+    fn highlight_line<'a>(&self, state: &mut Self::State, line: &'a str) -> Line<'a> {
+        (**self).highlight_line(state, line)
+    }
+}
+
+/// Source code that is highlighted lazily, one visible range at a time.
+///
+/// The source is split into lines when it is created, but no line is highlighted until it is
+/// requested with [`Highlighted::lines`] or [`Highlighted::text`]. The state of the highlighter is
+/// remembered every [`Highlighted::CHECKPOINT_INTERVAL`] lines, so requesting a range only
+/// highlights the lines from the closest remembered state before it.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct Highlighted<'a, H: Highlighter> {
+    lines: Vec<&'a str>,
+    highlighter: H,
+    /// The state before the lines `0`, `CHECKPOINT_INTERVAL`, `2 * CHECKPOINT_INTERVAL`, etc.
+    checkpoints: Vec<H::State>,
+}
+
+impl<'a, H: Highlighter> Highlighted<'a, H> {
+    /// The number of lines between two remembered highlighter states.
+    pub const CHECKPOINT_INTERVAL: usize = 64;
+
+    /// Creates highlighted source code from the source and a highlighter.
+    pub fn new(source: &'a str, highlighter: H) -> Self {
+        let checkpoints = alloc::vec![highlighter.start()];
+        Self {
+            lines: source.lines().collect(),
+            highlighter,
+            checkpoints,
+        }
+    }
+
+    /// Returns the highlighter.
+    pub const fn highlighter(&self) -> &H {
+        &self.highlighter
+    }
+
+    /// Returns the number of lines of the source.
+    pub const fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns whether the source has no lines.
+    pub const fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns the highlighted lines in `range`.
+    ///
+    /// The range is clamped to the lines of the source, so the range of the visible lines can be
+    /// passed without checking the length of the source first.
+    pub fn lines(&mut self, range: Range<usize>) -> Vec<Line<'a>> {
+        let end = range.end.min(self.lines.len());
+        let start = range.start.min(end);
+        let checkpoint = (start / Self::CHECKPOINT_INTERVAL).min(self.checkpoints.len() - 1);
+        let mut state = self.checkpoints[checkpoint].clone();
+        let mut highlighted = Vec::with_capacity(end - start);
+        for index in checkpoint * Self::CHECKPOINT_INTERVAL..end {
+            if index % Self::CHECKPOINT_INTERVAL == 0
+                && index / Self::CHECKPOINT_INTERVAL == self.checkpoints.len()
+            {
+                self.checkpoints.push(state.clone());
+            }
+            let line = self
+                .highlighter
+                .highlight_line(&mut state, self.lines[index]);
+            if index >= start {
+                highlighted.push(line);
+            }
+        }
+        highlighted
+    }
+
+    /// Returns the highlighted lines in `range` as [`Text`].
+    ///
+    /// This is a convenience method for rendering the visible lines with a [`Paragraph`].
+    ///
+    /// [`Paragraph`]: crate::paragraph::Paragraph
+    pub fn text(&mut self, range: Range<usize>) -> Text<'a> {
+        Text::from(self.lines(range))
+    }
+}
+
+/// Returns the text with `style` added to the occurrences of `pattern`, ignoring ASCII case.
+///
+/// An occurrence can span several spans of a line, but not several lines. The text is returned
+/// unchanged if `pattern` is empty. This is used by [`List::match_style`] and
+/// [`Table::match_style`] to highlight the filter of their state.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::text::{Line, Span, Text};
+/// use ratatui::widgets::highlight::highlight_matches;
+///
+/// let text = highlight_matches(&Text::raw("Cargo.toml"), "TOML", Style::new().bold());
+/// assert_eq!(
+///     text,
+///     Text::from(Line::from(vec![Span::raw("Cargo."), "toml".bold()]))
+/// );
+/// ```
+///
+/// [`List::match_style`]: crate::list::List::match_style
+/// [`Table::match_style`]: crate::table::Table::match_style
+pub fn highlight_matches<'a>(text: &Text<'a>, pattern: &str, style: Style) -> Text<'a> {
+    Text {
+        lines: text
+            .lines
+            .iter()
+            .map(|line| highlight_line_matches(line, pattern, style))
+            .collect(),
+        ..*text
+    }
+}
+
+fn highlight_line_matches<'a>(line: &Line<'a>, pattern: &str, style: Style) -> Line<'a> {
+    let content: String = line
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect();
+    let matches = find_matches(&content, pattern);
+    if matches.is_empty() {
+        return line.clone();
+    }
+
+    // split the spans at the start and end of each match
+    let mut spans = Vec::with_capacity(line.spans.len() + matches.len() * 2);
+    let mut span_start = 0;
+    for span in &line.spans {
+        let span_end = span_start + span.content.len();
+        let boundaries = matches
+            .iter()
+            .flat_map(|m| [m.start, m.end])
+            .filter(|&boundary| span_start < boundary && boundary < span_end)
+            .chain([span_end]);
+        let mut start = span_start;
+        for end in boundaries {
+            let highlighted = matches.iter().any(|m| m.contains(&start));
+            let range = start - span_start..end - span_start;
+            #[expect(clippy::string_slice)] // Is safe as the matches are at character boundaries
+            let content = match &span.content {
+                Cow::Borrowed(content) => Cow::Borrowed(&content[range]),
+                Cow::Owned(content) => Cow::Owned(content[range].to_owned()),
+            };
+            let piece_style = if highlighted {
+                span.style.patch(style)
+            } else {
+                span.style
+            };
+            spans.push(Span::styled(content, piece_style));
+            start = end;
+        }
+        span_start = span_end;
+    }
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+/// Returns the byte ranges of the non-overlapping occurrences of `pattern`, ignoring ASCII case.
+///
+/// Since only ASCII characters are folded, the ranges are always at character boundaries.
+fn find_matches(haystack: &str, pattern: &str) -> Vec<Range<usize>> {
+    let (haystack, pattern) = (haystack.as_bytes(), pattern.as_bytes());
+    let mut matches = Vec::new();
+    if pattern.is_empty() {
+        return matches;
+    }
+    let mut start = 0;
+    while start + pattern.len() <= haystack.len() {
+        let end = start + pattern.len();
+        if haystack[start..end].eq_ignore_ascii_case(pattern) {
+            matches.push(start..end);
+            start = end;
+        } else {
+            start += 1;
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use core::cell::Cell;
+
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    /// Highlights the lines inside `/* */` comments, and counts the highlighted lines.
+    #[derive(Default)]
+    struct BlockComments {
+        highlighted: Cell<usize>,
+    }
+
+    impl Highlighter for BlockComments {
+        type State = bool;
+
+        fn start(&self) -> Self::State {
+            false
+        }
+
+        fn highlight_line<'a>(&self, in_comment: &mut bool, line: &'a str) -> Line<'a> {
+            self.highlighted.set(self.highlighted.get() + 1);
+            *in_comment |= line.starts_with("/*");
+            let highlighted = if *in_comment {
+                line.dark_gray().into()
+            } else {
+                Line::raw(line)
+            };
+            *in_comment &= !line.ends_with("*/");
+            highlighted
+        }
+    }
+
+    #[test]
+    fn highlights_lines_with_state() {
+        let mut code = Highlighted::new("a\n/* b\nc */\nd", BlockComments::default());
+        assert_eq!(code.len(), 4);
+        assert_eq!(
+            code.lines(1..3),
+            [
+                Line::from("/* b".dark_gray()),
+                Line::from("c */".dark_gray())
+            ]
+        );
+        assert_eq!(code.text(3..4), Text::raw("d"));
+    }
+
+    #[test]
+    fn highlight_matches_across_spans() {
+        let text = Text::from(vec![
+            Line::from(vec!["Foo".red(), "bar baR".into()]),
+            Line::raw("none"),
+        ]);
+        assert_eq!(
+            highlight_matches(&text, "oB", Style::new().bold()),
+            Text::from(vec![
+                Line::from(vec![
+                    "Fo".red(),
+                    "o".red().bold(),
+                    "b".bold(),
+                    "ar baR".into()
+                ]),
+                Line::raw("none"),
+            ])
+        );
+        assert_eq!(highlight_matches(&text, "", Style::new().bold()), text);
+    }
+
+    #[test]
+    fn clamps_range() {
+        let mut code = Highlighted::new("a\nb", BlockComments::default());
+        assert_eq!(code.lines(1..10), [Line::raw("b")]);
+        assert_eq!(code.lines(5..10), []);
+        assert!(Highlighted::new("", BlockComments::default()).is_empty());
+    }
+
+    #[test]
+    fn resumes_from_checkpoint() {
+        let source = (0..200).map(|i| i.to_string()).collect::<Vec<String>>();
+        let source = source.join("\n");
+        let mut code = Highlighted::new(&source, BlockComments::default());
+
+        assert_eq!(code.lines(150..151), [Line::raw("150")]);
+        assert_eq!(code.highlighter().highlighted.get(), 151);
+
+        // the state before line 128 is remembered, so only lines 128..=140 are highlighted
+        assert_eq!(code.lines(140..141), [Line::raw("140")]);
+        assert_eq!(code.highlighter().highlighted.get(), 151 + 13);
+
+        assert_eq!(code.lines(0..2), vec![Line::raw("0"), Line::raw("1")]);
+        assert_eq!(code.highlighter().highlighted.get(), 151 + 13 + 2);
+    }
+}
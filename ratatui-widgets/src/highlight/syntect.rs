@@ -0,0 +1,133 @@
+use ::syntect::highlighting::{self, FontStyle, HighlightIterator, HighlightState, Theme};
+use ::syntect::parsing::{ParseState, ScopeStack, SyntaxReference, SyntaxSet};
+use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::text::{Line, Span};
+
+use super::Highlighter;
+
+/// A [`Highlighter`] that uses the [`syntect`] crate.
+///
+/// The highlighter borrows the syntax definitions and the theme, which are usually loaded once when
+/// the application starts. The syntax set must be loaded for lines without line endings, e.g. with
+/// [`SyntaxSet::load_defaults_nonewlines`].
+///
+/// The foreground colors and font styles of the theme are applied to the lines. The background
+/// color of the theme is ignored so that the code is shown with the background of the widget,
+/// unless [`SyntectHighlighter::background`] is enabled.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::highlight::{Highlighted, SyntectHighlighter};
+/// use syntect::highlighting::ThemeSet;
+/// use syntect::parsing::SyntaxSet;
+///
+/// let syntaxes = SyntaxSet::load_defaults_nonewlines();
+/// let themes = ThemeSet::load_defaults();
+/// let syntax = syntaxes.find_syntax_by_extension("rs").unwrap();
+/// let highlighter =
+///     SyntectHighlighter::new(&syntaxes, syntax, &themes.themes["base16-ocean.dark"]);
+///
+/// let mut code = Highlighted::new("fn main() {}", highlighter);
+/// let visible = code.text(0..10);
+/// ```
+///
+/// [`syntect`]: https://crates.io/crates/syntect
+#[derive(Debug)]
+pub struct SyntectHighlighter<'s> {
+    syntaxes: &'s SyntaxSet,
+    syntax: &'s SyntaxReference,
+    highlighter: highlighting::Highlighter<'s>,
+    background: bool,
+}
+
+impl<'s> SyntectHighlighter<'s> {
+    /// Creates a highlighter for a syntax of the syntax set, with the colors of the theme.
+    pub fn new(syntaxes: &'s SyntaxSet, syntax: &'s SyntaxReference, theme: &'s Theme) -> Self {
+        Self {
+            syntaxes,
+            syntax,
+            highlighter: highlighting::Highlighter::new(theme),
+            background: false,
+        }
+    }
+
+    /// Sets whether the background colors of the theme are applied to the lines.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn background(mut self, background: bool) -> Self {
+        self.background = background;
+        self
+    }
+
+    fn style(&self, style: highlighting::Style) -> Style {
+        let mut result = Style::new().fg(color(style.foreground));
+        if self.background {
+            result = result.bg(color(style.background));
+        }
+        for (font_style, modifier) in [
+            (FontStyle::BOLD, Modifier::BOLD),
+            (FontStyle::ITALIC, Modifier::ITALIC),
+            (FontStyle::UNDERLINE, Modifier::UNDERLINED),
+        ] {
+            if style.font_style.contains(font_style) {
+                result = result.add_modifier(modifier);
+            }
+        }
+        result
+    }
+}
+
+impl Highlighter for SyntectHighlighter<'_> {
+    type State = (ParseState, HighlightState);
+
+    fn start(&self) -> Self::State {
+        let parse_state = ParseState::new(self.syntax);
+        let highlight_state = HighlightState::new(&self.highlighter, ScopeStack::new());
+        (parse_state, highlight_state)
+    }
+
+    fn highlight_line<'a>(&self, state: &mut Self::State, line: &'a str) -> Line<'a> {
+        let (parse_state, highlight_state) = state;
+        // a line that fails to parse is shown without highlighting rather than failing the render
+        let Ok(ops) = parse_state.parse_line(line, self.syntaxes) else {
+            return Line::raw(line);
+        };
+        HighlightIterator::new(highlight_state, &ops, line, &self.highlighter)
+            .map(|(style, content)| Span::styled(content, self.style(style)))
+            .collect()
+    }
+}
+
+const fn color(color: highlighting::Color) -> Color {
+    Color::Rgb(color.r, color.g, color.b)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use ::syntect::highlighting::ThemeSet;
+
+    use super::*;
+    use crate::highlight::Highlighted;
+
+    #[test]
+    fn highlights_rust() {
+        let syntaxes = SyntaxSet::load_defaults_nonewlines();
+        let themes = ThemeSet::load_defaults();
+        let syntax = syntaxes.find_syntax_by_extension("rs").unwrap();
+        let theme = &themes.themes["InspiredGitHub"];
+        let highlighter = SyntectHighlighter::new(&syntaxes, syntax, theme);
+
+        let mut code = Highlighted::new("/* a\nb */\nfn main() {}", highlighter);
+        let lines = code.lines(0..3);
+        let comment = lines[1].spans[0].style;
+        assert_eq!(lines[1].to_string(), "b */");
+        assert_eq!(lines[0].spans[0].style, comment);
+        assert_eq!(lines[2].to_string(), "fn main() {}");
+        assert_ne!(lines[2].spans[0].style, comment);
+        assert_eq!(comment.bg, None);
+    }
+}
@@ -0,0 +1,257 @@
+//! The [`History`] type provides undo and redo for editable widget states.
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// A bounded undo/redo stack of snapshots.
+///
+/// `History` stores snapshots of a value taken before each change. Calling [`History::undo`] with
+/// the current value returns the previous snapshot and keeps the current value for
+/// [`History::redo`]. Recording a new change clears the redo stack.
+///
+/// The number of undo steps is bounded by the capacity; when it is exceeded, the oldest snapshot
+/// is dropped. A capacity of `0` disables the history.
+///
+/// # Coalescing
+///
+/// Recording every keystroke as a separate undo step is usually not what users expect. Changes
+/// recorded with [`History::record_coalesced`] using the same key as the previous change are
+/// merged into a single step, so undoing removes a whole run of typed characters at once. Any
+/// other call to [`History::record`], [`History::undo`], [`History::redo`] or
+/// [`History::break_coalescing`] (e.g. when the cursor is moved) ends the run.
+///
+/// `History` is used by [`TextInputState`] and can be used for application state as well.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::History;
+///
+/// let mut history = History::new();
+/// let mut value = String::from("a");
+///
+/// // typing coalesces into a single step
+/// for c in ['b', 'c'] {
+///     history.record_coalesced(value.clone(), 0);
+///     value.push(c);
+/// }
+///
+/// value = history.undo(value).unwrap();
+/// assert_eq!(value, "a");
+/// value = history.redo(value).unwrap();
+/// assert_eq!(value, "abc");
+/// ```
+///
+/// [`TextInputState`]: crate::input::TextInputState
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct History<T> {
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    capacity: usize,
+    coalesce_key: Option<u32>,
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> History<T> {
+    /// The default number of undo steps.
+    pub const DEFAULT_CAPACITY: usize = 100;
+
+    /// Creates a new empty `History` with [`History::DEFAULT_CAPACITY`] undo steps.
+    pub const fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates a new empty `History` with the given number of undo steps.
+    pub const fn with_capacity(capacity: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            capacity,
+            coalesce_key: None,
+        }
+    }
+
+    /// Returns the maximum number of undo steps.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of undo steps, dropping the oldest snapshots if needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.undo.len() > capacity {
+            self.undo.pop_front();
+        }
+        let excess = self.redo.len().saturating_sub(capacity);
+        self.redo.drain(..excess);
+    }
+
+    /// Returns true if there is a change to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns true if there is an undone change to redo.
+    pub const fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Records a snapshot of the value before a change, as a separate undo step.
+    pub fn record(&mut self, snapshot: T) {
+        self.coalesce_key = None;
+        self.push(snapshot);
+    }
+
+    /// Records a snapshot of the value before a change, merging it with the previous step if that
+    /// was recorded with the same key.
+    ///
+    /// When merged, the snapshot is dropped, as the previous step already holds the value from
+    /// before the run of changes started.
+    pub fn record_coalesced(&mut self, snapshot: T, key: u32) {
+        if self.coalesce_key == Some(key) && self.can_undo() {
+            return;
+        }
+        self.push(snapshot);
+        self.coalesce_key = Some(key);
+    }
+
+    /// Ends the current run of coalesced changes.
+    pub const fn break_coalescing(&mut self) {
+        self.coalesce_key = None;
+    }
+
+    /// Returns the value before the last change, storing `current` for [`History::redo`].
+    ///
+    /// Returns `None` if there is nothing to undo, in which case `current` is dropped.
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        self.coalesce_key = None;
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    /// Returns the value before the last undo, storing `current` for [`History::undo`].
+    ///
+    /// Returns `None` if there is nothing to redo, in which case `current` is dropped.
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        self.coalesce_key = None;
+        let next = self.redo.pop()?;
+        self.push_undo(current);
+        Some(next)
+    }
+
+    /// Removes all undo and redo steps.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.coalesce_key = None;
+    }
+
+    fn push(&mut self, snapshot: T) {
+        self.redo.clear();
+        self.push_undo(snapshot);
+    }
+
+    /// Pushes an undo step, dropping the oldest steps to stay within the capacity.
+    fn push_undo(&mut self, snapshot: T) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.undo.len() >= self.capacity {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_redo() {
+        let mut history = History::new();
+        history.record(1);
+        history.record(2);
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), None);
+        assert_eq!(history.redo(1), Some(2));
+        assert_eq!(history.redo(2), Some(3));
+        assert_eq!(history.redo(3), None);
+    }
+
+    #[test]
+    fn redo_is_bounded() {
+        let mut history = History::with_capacity(3);
+        history.record(1);
+        history.record(2);
+        history.record(3);
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), Some(2));
+        history.set_capacity(2);
+        assert_eq!(history.redo(2), Some(3));
+        assert_eq!(history.redo(3), Some(4));
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), None);
+    }
+
+    #[test]
+    fn record_clears_redo() {
+        let mut history = History::new();
+        history.record(1);
+        assert_eq!(history.undo(2), Some(1));
+        assert!(history.can_redo());
+        history.record(1);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn coalescing() {
+        let mut history = History::new();
+        history.record_coalesced(0, 1);
+        history.record_coalesced(1, 1);
+        history.record_coalesced(2, 2);
+        history.break_coalescing();
+        history.record_coalesced(3, 2);
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), Some(0));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn undo_ends_coalescing() {
+        let mut history = History::new();
+        history.record_coalesced(0, 1);
+        assert_eq!(history.undo(1), Some(0));
+        history.record_coalesced(0, 1);
+        assert!(history.can_undo());
+    }
+
+    #[test]
+    fn capacity_drops_oldest() {
+        let mut history = History::with_capacity(2);
+        history.record(1);
+        history.record(2);
+        history.record(3);
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), None);
+
+        history.set_capacity(1);
+        assert_eq!(history.redo(2), Some(3));
+    }
+
+    #[test]
+    fn zero_capacity_disables_history() {
+        let mut history = History::with_capacity(0);
+        history.record(1);
+        assert!(!history.can_undo());
+    }
+}
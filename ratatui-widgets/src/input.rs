@@ -0,0 +1,291 @@
+//! The [`TextInput`] widget is used to edit a single line of text.
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::WrappedCursor;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::block::{Block, BlockExt};
+
+mod numeric;
+mod state;
+
+pub use numeric::NumericInput;
+pub use state::{InputAction, TextInputState};
+
+/// A widget to edit a single line of text.
+///
+/// The value and cursor are stored in a [`TextInputState`], which also provides the editing
+/// methods. The widget renders the value on the first row of its area and scrolls it horizontally
+/// to keep the cursor visible. After rendering, [`TextInputState::cursor_position`] returns where
+/// the terminal cursor should be placed.
+///
+/// # Variants
+///
+/// - A numeric input is created by configuring the state with [`TextInputState::with_numeric`]. The
+///   value is displayed with the [`NumericInput::thousands_separator`], if any.
+/// - A masked input (e.g. for passwords) is created with [`TextInput::masked`] or
+///   [`TextInput::mask`]. Each character of the value is rendered as the mask character unless
+///   [`TextInput::reveal`] is set, which can be bound to a "show password" toggle.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Block, TextInput, TextInputState};
+///
+/// # fn ui(frame: &mut Frame, show_password: bool) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TextInputState::new();
+///
+/// let input = TextInput::new()
+///     .block(Block::bordered().title("Password"))
+///     .masked()
+///     .reveal(show_password);
+/// frame.render_stateful_widget(input, area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor_position(position);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TextInput<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// The character displayed in place of each character of the value
+    mask: Option<char>,
+    /// Whether the value is displayed even when a mask is set
+    reveal: bool,
+}
+
+impl<'a> TextInput<'a> {
+    /// The default mask character, a bullet (`•`).
+    pub const DEFAULT_MASK: char = '•';
+
+    /// Creates a new `TextInput` with the default style and no mask.
+    pub const fn new() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            mask: None,
+            reveal: false,
+        }
+    }
+
+    /// Surrounds the `TextInput` with a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Hides the value by rendering each character as [`TextInput::DEFAULT_MASK`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn masked(self) -> Self {
+        self.mask(Self::DEFAULT_MASK)
+    }
+
+    /// Hides the value by rendering each character as `mask`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Displays the value of a masked input as is.
+    ///
+    /// This has no effect when no mask is set.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn reveal(mut self, reveal: bool) -> Self {
+        self.reveal = reveal;
+        self
+    }
+
+    /// Returns the text to display and the display grapheme index of the cursor.
+    fn display(&self, state: &TextInputState) -> (String, usize) {
+        match (self.mask, state.numeric) {
+            (Some(mask), _) if !self.reveal => {
+                let len = state.value.graphemes(true).count();
+                (core::iter::repeat_n(mask, len).collect(), state.cursor)
+            }
+            (_, Some(numeric)) => numeric.format(&state.value, state.cursor),
+            _ => (state.value.clone(), state.cursor),
+        }
+    }
+}
+
+impl StatefulWidget for TextInput<'_> {
+    type State = TextInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &TextInput<'_> {
+    type State = TextInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        state.cursor_position = None;
+        if area.is_empty() {
+            return;
+        }
+
+        let (display, cursor) = self.display(state);
+        let graphemes: Vec<&str> = display.graphemes(true).collect();
+        let cursor = cursor.min(graphemes.len());
+        let width = usize::from(area.width);
+
+        // scroll so that the cursor, and the cell it occupies, stay within the area
+        let mut offset = state.offset.min(cursor);
+        while offset < cursor && graphemes[offset..cursor].concat().width() + 1 > width {
+            offset += 1;
+        }
+        state.offset = offset;
+
+        let visible = graphemes[offset..].concat();
+        buf.set_stringn(area.x, area.y, &visible, width, self.style);
+
+        // the value is rendered on a single row, so the cursor is mapped without wrapping
+        let cursor_x = WrappedCursor::new(0).position(&visible, cursor - offset).x;
+        if cursor_x < area.width {
+            state.cursor_position = Some(Position::new(area.x + cursor_x, area.y));
+        }
+    }
+}
+
+impl Styled for TextInput<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(input: &TextInput, state: &mut TextInputState, width: u16) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, 1));
+        StatefulWidget::render(input, buffer.area, &mut buffer, state);
+        buffer
+    }
+
+    #[test]
+    fn renders_value_and_cursor() {
+        let mut state = TextInputState::new().with_value("hello");
+        let buffer = render(&TextInput::new(), &mut state, 10);
+        assert_eq!(buffer, Buffer::with_lines(["hello     "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 0)));
+    }
+
+    #[test]
+    fn scrolls_to_keep_cursor_visible() {
+        let mut state = TextInputState::new().with_value("hello world");
+        let buffer = render(&TextInput::new(), &mut state, 6);
+        assert_eq!(buffer, Buffer::with_lines(["world "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 0)));
+
+        // moving the cursor back within the visible text doesn't scroll
+        state.set_cursor(8);
+        let buffer = render(&TextInput::new(), &mut state, 6);
+        assert_eq!(buffer, Buffer::with_lines(["world "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(2, 0)));
+
+        // moving the cursor before the visible text scrolls back
+        state.move_to_start();
+        let buffer = render(&TextInput::new(), &mut state, 6);
+        assert_eq!(buffer, Buffer::with_lines(["hello "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn scrolls_past_wide_characters() {
+        let mut state = TextInputState::new().with_value("界界界");
+        let buffer = render(&TextInput::new(), &mut state, 4);
+        assert_eq!(buffer, Buffer::with_lines(["界  "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(2, 0)));
+    }
+
+    #[test]
+    fn masked() {
+        let mut state = TextInputState::new().with_value("secret");
+        let buffer = render(&TextInput::new().masked(), &mut state, 8);
+        assert_eq!(buffer, Buffer::with_lines(["••••••  "]));
+
+        let buffer = render(&TextInput::new().mask('*').reveal(true), &mut state, 8);
+        assert_eq!(buffer, Buffer::with_lines(["secret  "]));
+    }
+
+    #[test]
+    fn numeric_with_thousands_separator() {
+        let numeric = NumericInput::new().thousands_separator(',');
+        let mut state = TextInputState::new().with_numeric(numeric);
+        state.insert_str("1234567");
+        state.set_cursor(1);
+        let buffer = render(&TextInput::new(), &mut state, 10);
+        assert_eq!(buffer, Buffer::with_lines(["1,234,567 "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(2, 0)));
+    }
+
+    #[test]
+    fn renders_block_and_style() {
+        let input = TextInput::new()
+            .block(Block::bordered())
+            .style(Style::new().red());
+        let mut state = TextInputState::new().with_value("hi");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        StatefulWidget::render(&input, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["┌────┐", "│hi  │", "└────┘"]);
+        expected.set_style(expected.area, Style::new().red());
+        assert_eq!(buffer, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 1)));
+    }
+
+    #[test]
+    fn empty_area() {
+        let mut state = TextInputState::new().with_value("hi");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 0, 0));
+        StatefulWidget::render(TextInput::new(), buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.cursor_position(), None);
+    }
+}
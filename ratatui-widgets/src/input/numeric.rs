@@ -0,0 +1,224 @@
+use alloc::string::String;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Configuration for a [`TextInputState`] that only accepts whole numbers.
+///
+/// A numeric input only accepts digits (and a leading `-` when negative values are allowed).
+/// Pasted text is filtered down to the accepted characters, so pasting `"1,234"` results in
+/// `1234`. Values are limited to the range given by [`NumericInput::min`] and
+/// [`NumericInput::max`]: typing is rejected when it would move the value past the bound in the
+/// direction away from zero, and [`TextInputState::increment`] / [`TextInputState::decrement`]
+/// clamp the result.
+///
+/// The value is stored without separators. When a [`NumericInput::thousands_separator`] is set,
+/// the [`TextInput`] widget inserts it when rendering, e.g. `1234567` is displayed as
+/// `1,234,567`.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{NumericInput, TextInputState};
+///
+/// let numeric = NumericInput::new()
+///     .min(0)
+///     .max(10_000)
+///     .with_step(100)
+///     .thousands_separator(',');
+/// let mut state = TextInputState::new().with_numeric(numeric);
+///
+/// state.insert_str("1,200");
+/// state.increment();
+/// assert_eq!(state.number(), Some(1300));
+/// ```
+///
+/// [`TextInput`]: super::TextInput
+/// [`TextInputState`]: super::TextInputState
+/// [`TextInputState::increment`]: super::TextInputState::increment
+/// [`TextInputState::decrement`]: super::TextInputState::decrement
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumericInput {
+    min: i64,
+    max: i64,
+    step: i64,
+    thousands_separator: Option<char>,
+}
+
+impl Default for NumericInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumericInput {
+    /// Creates a new `NumericInput` that accepts any `i64` and steps by 1.
+    pub const fn new() -> Self {
+        Self {
+            min: i64::MIN,
+            max: i64::MAX,
+            step: 1,
+            thousands_separator: None,
+        }
+    }
+
+    /// Sets the smallest accepted value.
+    ///
+    /// Negative values can only be entered when this is below zero.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min(mut self, min: i64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the largest accepted value.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max(mut self, max: i64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the amount added or subtracted by [`TextInputState::increment`] and
+    /// [`TextInputState::decrement`].
+    ///
+    /// [`TextInputState::increment`]: super::TextInputState::increment
+    /// [`TextInputState::decrement`]: super::TextInputState::decrement
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_step(mut self, step: i64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the character displayed between groups of three digits.
+    ///
+    /// The separator is only used for display; it is never part of the value.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Returns the amount added or subtracted by a single step.
+    pub const fn step(self) -> i64 {
+        self.step
+    }
+
+    /// Restricts `value` to the accepted range.
+    pub fn clamp(self, value: i64) -> i64 {
+        value.clamp(self.min, self.max.max(self.min))
+    }
+
+    /// Returns true if inserting `c` at the grapheme index `index` of `value` results in an
+    /// accepted value.
+    pub(crate) fn accepts(self, value: &str, index: usize, c: char) -> bool {
+        let mut candidate = String::with_capacity(value.len() + c.len_utf8());
+        let mut graphemes = value.graphemes(true);
+        candidate.extend(graphemes.by_ref().take(index));
+        candidate.push(c);
+        candidate.extend(graphemes);
+        match c {
+            '-' => index == 0 && self.min < 0 && !value.starts_with('-'),
+            '0'..='9' => match candidate.parse::<i64>() {
+                Ok(number) if number >= 0 => number <= self.max || self.max < 0,
+                Ok(number) => number >= self.min,
+                Err(_) => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns the display form of `value` and the display grapheme index of the cursor at the
+    /// grapheme index `cursor`.
+    pub(crate) fn format(self, value: &str, cursor: usize) -> (String, usize) {
+        let Some(separator) = self.thousands_separator else {
+            return (String::from(value), cursor);
+        };
+        let is_digit = |grapheme: &str| grapheme.bytes().all(|byte| byte.is_ascii_digit());
+        let digit_count = value.graphemes(true).filter(|g| is_digit(g)).count();
+        let mut display = String::with_capacity(value.len() + digit_count / 3);
+        let mut display_len = 0;
+        let mut display_cursor = None;
+        let mut digits = 0;
+        for (index, grapheme) in value.graphemes(true).enumerate() {
+            if is_digit(grapheme) {
+                if digits > 0 && (digit_count - digits) % 3 == 0 {
+                    display.push(separator);
+                    display_len += 1;
+                }
+                digits += 1;
+            }
+            if index == cursor {
+                // the cursor sits directly before this grapheme (after any separator)
+                display_cursor = Some(display_len);
+            }
+            display.push_str(grapheme);
+            display_len += 1;
+        }
+        (display, display_cursor.unwrap_or(display_len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::digit("1", 1, '2', true)]
+    #[case::letter("12", 2, 'a', false)]
+    #[case::separator("12", 2, ',', false)]
+    #[case::leading_minus("12", 0, '-', true)]
+    #[case::inner_minus("12", 1, '-', false)]
+    #[case::second_minus("-12", 0, '-', false)]
+    #[case::digit_before_minus("-12", 0, '1', false)]
+    #[case::above_max("99", 2, '9', false)]
+    #[case::below_min("-99", 3, '9', false)]
+    #[case::below_positive_min("", 0, '1', true)]
+    #[case::overflow("9223372036854775807", 0, '1', false)]
+    fn accepts(#[case] value: &str, #[case] index: usize, #[case] c: char, #[case] ok: bool) {
+        let numeric = NumericInput::new().min(-100).max(100);
+        assert_eq!(numeric.accepts(value, index, c), ok);
+    }
+
+    #[test]
+    fn minus_requires_negative_min() {
+        let numeric = NumericInput::new().min(0);
+        assert!(!numeric.accepts("", 0, '-'));
+    }
+
+    #[rstest]
+    #[case::short("123", 3, "123", 3)]
+    #[case::thousands("1234", 4, "1,234", 5)]
+    #[case::cursor_after_separator("1234", 1, "1,234", 2)]
+    #[case::millions("1234567", 0, "1,234,567", 0)]
+    #[case::negative("-1234567", 2, "-1,234,567", 3)]
+    #[case::empty("", 0, "", 0)]
+    #[case::grapheme_cursor("e\u{301}1234", 2, "e\u{301}1,234", 3)]
+    fn format(
+        #[case] value: &str,
+        #[case] cursor: usize,
+        #[case] display: &str,
+        #[case] display_cursor: usize,
+    ) {
+        let numeric = NumericInput::new().thousands_separator(',');
+        assert_eq!(
+            numeric.format(value, cursor),
+            (String::from(display), display_cursor)
+        );
+    }
+
+    #[test]
+    fn format_without_separator() {
+        let numeric = NumericInput::new();
+        assert_eq!(numeric.format("1234", 2), (String::from("1234"), 2));
+    }
+
+    #[test]
+    fn clamp() {
+        let numeric = NumericInput::new().min(-5).max(5);
+        assert_eq!(numeric.clamp(10), 5);
+        assert_eq!(numeric.clamp(-10), -5);
+        assert_eq!(numeric.clamp(3), 3);
+    }
+}
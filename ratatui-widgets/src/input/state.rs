@@ -0,0 +1,656 @@
+use alloc::string::String;
+use core::hash::{Hash, Hasher};
+
+use ratatui_core::layout::Position;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::history::History;
+use crate::input::NumericInput;
+
+/// [`History`] coalescing keys for the different kinds of edits.
+const INSERT: u32 = 0;
+const DELETE_BACKWARD: u32 = 1;
+const DELETE_FORWARD: u32 = 2;
+
+/// State of the [`TextInput`] widget
+///
+/// The state holds the edited value and the cursor, which is stored as an index in grapheme
+/// clusters (not bytes) so that it never splits a combined character. Editing methods such as
+/// [`insert_char`](Self::insert_char), [`delete_backward`](Self::delete_backward) and the
+/// movement methods are meant to be called from the application's key handling.
+///
+/// When configured with [`with_numeric`](Self::with_numeric), the state only accepts whole
+/// numbers. See [`NumericInput`] for details.
+///
+/// Edits are recorded in a [`History`], so [`undo`](Self::undo) and [`redo`](Self::redo) can be
+/// bound to keys such as `Ctrl+Z` and `Ctrl+Y`. Consecutive typed characters up to the next
+/// whitespace, and consecutive deletions, are undone as a single step. Moving the cursor ends the
+/// step. The history is not compared when comparing two states.
+///
+/// Instead of calling the editing methods, key presses can be converted to [`InputAction`]s and
+/// passed to [`apply`](Self::apply). With the `ratatui` crate, a `Key` converts to the
+/// action it is bound to by default, including `Ctrl+Z` for undo and `Ctrl+Y` for redo.
+///
+/// After the [`TextInput`] is rendered, [`cursor_position`](Self::cursor_position) returns the
+/// screen position of the cursor, which can be passed to `Frame::set_cursor_position`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{TextInput, TextInputState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TextInputState::new().with_value("Hello");
+/// state.insert_str(", World!");
+///
+/// frame.render_stateful_widget(TextInput::new(), area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor_position(position);
+/// }
+/// # }
+/// ```
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextInputState {
+    pub(crate) value: String,
+    pub(crate) cursor: usize,
+    pub(crate) numeric: Option<NumericInput>,
+    pub(crate) offset: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) history: History<(String, usize)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) cursor_position: Option<Position>,
+}
+
+/// An editing action of a [`TextInputState`], usually bound to a key.
+///
+/// Actions are passed to [`TextInputState::apply`]. With the `ratatui` crate, a `Key` converts to
+/// the action it is bound to by default:
+///
+/// | Key                       | Action                                   |
+/// | ------------------------- | ---------------------------------------- |
+/// | a character               | [`Insert`](Self::Insert)                 |
+/// | `Backspace`               | [`DeleteBackward`](Self::DeleteBackward) |
+/// | `Delete`                  | [`DeleteForward`](Self::DeleteForward)   |
+/// | `Left`                    | [`MoveLeft`](Self::MoveLeft)             |
+/// | `Right`                   | [`MoveRight`](Self::MoveRight)           |
+/// | `Home`                    | [`MoveToStart`](Self::MoveToStart)       |
+/// | `End`                     | [`MoveToEnd`](Self::MoveToEnd)           |
+/// | `Ctrl+Z`                  | [`Undo`](Self::Undo)                     |
+/// | `Ctrl+Y` / `Ctrl+Shift+Z` | [`Redo`](Self::Redo)                     |
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::keymap::Key;
+/// use ratatui::widgets::{InputAction, TextInputState};
+///
+/// let mut state = TextInputState::new();
+/// for key in ["h", "i", "ctrl-z"] {
+///     let key: Key = key.parse()?;
+///     if let Ok(action) = InputAction::try_from(key) {
+///         state.apply(action);
+///     }
+/// }
+/// assert_eq!(state.value(), "");
+/// # Ok::<(), ratatui::keymap::ParseKeyError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum InputAction {
+    /// Inserts a character at the cursor, see [`TextInputState::insert_char`].
+    Insert(char),
+    /// Deletes the grapheme before the cursor, see [`TextInputState::delete_backward`].
+    DeleteBackward,
+    /// Deletes the grapheme after the cursor, see [`TextInputState::delete_forward`].
+    DeleteForward,
+    /// Moves the cursor one grapheme to the left.
+    MoveLeft,
+    /// Moves the cursor one grapheme to the right.
+    MoveRight,
+    /// Moves the cursor to the start of the value.
+    MoveToStart,
+    /// Moves the cursor to the end of the value.
+    MoveToEnd,
+    /// Reverts the last change, see [`TextInputState::undo`].
+    Undo,
+    /// Reapplies the last undone change, see [`TextInputState::redo`].
+    Redo,
+}
+
+impl PartialEq for TextInputState {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && self.cursor == other.cursor
+            && self.numeric == other.numeric
+            && self.offset == other.offset
+            && self.cursor_position == other.cursor_position
+    }
+}
+
+impl Eq for TextInputState {}
+
+impl Hash for TextInputState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+        self.cursor.hash(state);
+        self.numeric.hash(state);
+        self.offset.hash(state);
+        self.cursor_position.hash(state);
+    }
+}
+
+impl TextInputState {
+    /// Creates a new empty `TextInputState`.
+    pub const fn new() -> Self {
+        Self {
+            value: String::new(),
+            cursor: 0,
+            numeric: None,
+            offset: 0,
+            history: History::new(),
+            cursor_position: None,
+        }
+    }
+
+    /// Sets the value and moves the cursor to the end of it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.value = value.into();
+        self.cursor = self.len();
+        self
+    }
+
+    /// Restricts the input to whole numbers.
+    ///
+    /// Characters in the current value that are not accepted are removed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_numeric(mut self, numeric: NumericInput) -> Self {
+        self.numeric = Some(numeric);
+        let value = core::mem::take(&mut self.value);
+        self.cursor = 0;
+        self.insert_str(&value);
+        self.history.clear();
+        self
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value and moves the cursor to the end of it.
+    ///
+    /// The value is not filtered, so this can be used to restore a previously saved value. The
+    /// change can be undone.
+    pub fn set_value<T: Into<String>>(&mut self, value: T) {
+        self.history.record(self.snapshot());
+        self.value = value.into();
+        self.cursor = self.len();
+    }
+
+    /// Removes the value. The change can be undone.
+    pub fn clear(&mut self) {
+        if !self.value.is_empty() {
+            self.history.record(self.snapshot());
+        }
+        self.value.clear();
+        self.cursor = 0;
+    }
+
+    /// Returns the numeric configuration, if the input is restricted to numbers.
+    pub const fn numeric(&self) -> Option<&NumericInput> {
+        self.numeric.as_ref()
+    }
+
+    /// Returns the cursor index in grapheme clusters.
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given grapheme index, clamped to the end of the value.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        self.history.break_coalescing();
+        self.cursor = cursor.min(self.len());
+    }
+
+    /// Returns the screen position of the cursor from the last render, if it was visible.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// Inserts a character at the cursor and moves the cursor after it.
+    ///
+    /// Returns `false` if the character was rejected (control characters, or characters that are
+    /// not accepted by the numeric configuration).
+    pub fn insert_char(&mut self, c: char) -> bool {
+        if !self.accepts(c) {
+            return false;
+        }
+        self.history.record_coalesced(self.snapshot(), INSERT);
+        self.insert_unrecorded(c);
+        if c.is_whitespace() {
+            self.history.break_coalescing();
+        }
+        true
+    }
+
+    /// Inserts a string at the cursor, e.g. when the user pastes text.
+    ///
+    /// Characters that would be rejected by [`insert_char`](Self::insert_char) are skipped, so
+    /// pasting a multi-line string into a single-line input drops the line breaks. Returns the
+    /// number of characters inserted. The whole string is undone as a single step.
+    pub fn insert_str(&mut self, s: &str) -> usize {
+        let snapshot = self.snapshot();
+        let count = s
+            .chars()
+            .filter(|&c| self.accepts(c) && self.insert_unrecorded(c))
+            .count();
+        if count > 0 {
+            self.history.record(snapshot);
+        }
+        count
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        if c.is_control() {
+            return false;
+        }
+        self.numeric
+            .is_none_or(|numeric| numeric.accepts(&self.value, self.cursor, c))
+    }
+
+    fn insert_unrecorded(&mut self, c: char) -> bool {
+        let index = self.byte_index(self.cursor);
+        self.value.insert(index, c);
+        // a combining character merges with the previous grapheme instead of adding a new one, so
+        // the cursor is computed from the graphemes that follow the inserted character
+        let rest = self.value.get(index + c.len_utf8()..).unwrap_or_default();
+        let after = rest.graphemes(true).count();
+        self.cursor = self.len() - after;
+        true
+    }
+
+    /// Deletes the grapheme before the cursor (backspace).
+    ///
+    /// Returns `false` if the cursor is at the start of the value.
+    pub fn delete_backward(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.history
+            .record_coalesced(self.snapshot(), DELETE_BACKWARD);
+        self.cursor -= 1;
+        self.remove_at_cursor();
+        true
+    }
+
+    /// Deletes the grapheme after the cursor (delete).
+    ///
+    /// Returns `false` if the cursor is at the end of the value.
+    pub fn delete_forward(&mut self) -> bool {
+        if self.cursor >= self.len() {
+            return false;
+        }
+        self.history
+            .record_coalesced(self.snapshot(), DELETE_FORWARD);
+        self.remove_at_cursor();
+        true
+    }
+
+    /// Moves the cursor one grapheme to the left.
+    pub const fn move_left(&mut self) {
+        self.history.break_coalescing();
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Moves the cursor one grapheme to the right.
+    pub fn move_right(&mut self) {
+        self.set_cursor(self.cursor.saturating_add(1));
+    }
+
+    /// Moves the cursor to the start of the value.
+    pub const fn move_to_start(&mut self) {
+        self.history.break_coalescing();
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor to the end of the value.
+    pub fn move_to_end(&mut self) {
+        self.history.break_coalescing();
+        self.cursor = self.len();
+    }
+
+    /// Returns the value as a number.
+    ///
+    /// Returns `None` if the input is not numeric or the value is not a valid number. The result
+    /// is clamped to the range of the [`NumericInput`].
+    pub fn number(&self) -> Option<i64> {
+        let numeric = self.numeric?;
+        self.value.parse().ok().map(|number| numeric.clamp(number))
+    }
+
+    /// Replaces the value with the given number, clamped to the range of the [`NumericInput`].
+    ///
+    /// Does nothing if the input is not numeric.
+    pub fn set_number(&mut self, number: i64) {
+        if let Some(numeric) = self.numeric {
+            self.set_value(alloc::format!("{}", numeric.clamp(number)));
+        }
+    }
+
+    /// Adds one step to the number. An empty value is treated as zero.
+    pub fn increment(&mut self) {
+        self.step_by(1);
+    }
+
+    /// Subtracts one step from the number. An empty value is treated as zero.
+    pub fn decrement(&mut self) {
+        self.step_by(-1);
+    }
+
+    fn step_by(&mut self, direction: i64) {
+        if let Some(numeric) = self.numeric {
+            let number = self.value.parse::<i64>().unwrap_or_default();
+            let step = numeric.step().saturating_mul(direction);
+            self.set_number(number.saturating_add(step));
+        }
+    }
+
+    /// Reverts the last change. Returns `false` if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some((value, cursor)) = self.history.undo(self.snapshot()) else {
+            return false;
+        };
+        self.value = value;
+        self.cursor = cursor;
+        true
+    }
+
+    /// Reapplies the last undone change. Returns `false` if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some((value, cursor)) = self.history.redo(self.snapshot()) else {
+            return false;
+        };
+        self.value = value;
+        self.cursor = cursor;
+        true
+    }
+
+    /// Applies an editing action, usually bound to a key.
+    ///
+    /// Returns `false` if the action changed neither the value nor the cursor, e.g. when a
+    /// character is rejected or there is nothing to undo.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{InputAction, TextInputState};
+    ///
+    /// let mut state = TextInputState::new();
+    /// state.apply(InputAction::Insert('a'));
+    /// state.apply(InputAction::Insert('b'));
+    /// assert!(state.apply(InputAction::Undo));
+    /// assert_eq!(state.value(), "");
+    /// ```
+    pub fn apply(&mut self, action: InputAction) -> bool {
+        let cursor = self.cursor;
+        match action {
+            InputAction::Insert(c) => self.insert_char(c),
+            InputAction::DeleteBackward => self.delete_backward(),
+            InputAction::DeleteForward => self.delete_forward(),
+            InputAction::Undo => self.undo(),
+            InputAction::Redo => self.redo(),
+            InputAction::MoveLeft => {
+                self.move_left();
+                self.cursor != cursor
+            }
+            InputAction::MoveRight => {
+                self.move_right();
+                self.cursor != cursor
+            }
+            InputAction::MoveToStart => {
+                self.move_to_start();
+                self.cursor != cursor
+            }
+            InputAction::MoveToEnd => {
+                self.move_to_end();
+                self.cursor != cursor
+            }
+        }
+    }
+
+    /// Returns the undo history.
+    pub const fn history(&self) -> &History<(String, usize)> {
+        &self.history
+    }
+
+    /// Returns a mutable reference to the undo history, e.g. to change its capacity.
+    pub const fn history_mut(&mut self) -> &mut History<(String, usize)> {
+        &mut self.history
+    }
+
+    fn snapshot(&self) -> (String, usize) {
+        (self.value.clone(), self.cursor)
+    }
+
+    /// The number of graphemes in the value.
+    fn len(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    /// The byte index of the grapheme at the given grapheme index.
+    fn byte_index(&self, index: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .nth(index)
+            .map_or(self.value.len(), |(i, _)| i)
+    }
+
+    fn remove_at_cursor(&mut self) {
+        let start = self.byte_index(self.cursor);
+        let end = self.byte_index(self.cursor + 1);
+        self.value.replace_range(start..end, "");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_value_moves_cursor_to_end() {
+        let state = TextInputState::new().with_value("héllo");
+        assert_eq!(state.value(), "héllo");
+        assert_eq!(state.cursor(), 5);
+    }
+
+    #[test]
+    fn insert_char() {
+        let mut state = TextInputState::new().with_value("hllo");
+        state.set_cursor(1);
+        assert!(state.insert_char('e'));
+        assert_eq!(state.value(), "hello");
+        assert_eq!(state.cursor(), 2);
+    }
+
+    #[test]
+    fn insert_combining_char_keeps_grapheme_cursor() {
+        let mut state = TextInputState::new().with_value("e");
+        assert!(state.insert_char('\u{301}'));
+        assert_eq!(state.value(), "e\u{301}");
+        assert_eq!(state.cursor(), 1);
+    }
+
+    #[test]
+    fn insert_str_filters_control_characters() {
+        let mut state = TextInputState::new();
+        assert_eq!(state.insert_str("one\ntwo\tthree"), 11);
+        assert_eq!(state.value(), "onetwothree");
+    }
+
+    #[test]
+    fn delete_backward() {
+        let mut state = TextInputState::new().with_value("a界b");
+        state.set_cursor(2);
+        assert!(state.delete_backward());
+        assert_eq!(state.value(), "ab");
+        assert_eq!(state.cursor(), 1);
+
+        state.move_to_start();
+        assert!(!state.delete_backward());
+    }
+
+    #[test]
+    fn delete_forward() {
+        let mut state = TextInputState::new().with_value("a界b");
+        state.set_cursor(1);
+        assert!(state.delete_forward());
+        assert_eq!(state.value(), "ab");
+        assert_eq!(state.cursor(), 1);
+
+        state.move_to_end();
+        assert!(!state.delete_forward());
+    }
+
+    #[test]
+    fn apply() {
+        let mut state = TextInputState::new();
+        assert!(state.apply(InputAction::Insert('a')));
+        assert!(state.apply(InputAction::Insert('b')));
+        assert!(state.apply(InputAction::MoveLeft));
+        assert!(state.apply(InputAction::DeleteForward));
+        assert!(!state.apply(InputAction::MoveRight));
+        assert_eq!(state.value(), "a");
+        assert!(state.apply(InputAction::Undo));
+        assert_eq!(state.value(), "ab");
+        assert!(state.apply(InputAction::Redo));
+        assert_eq!(state.value(), "a");
+        assert!(!state.apply(InputAction::Redo));
+    }
+
+    #[test]
+    fn eq_ignores_history() {
+        let mut edited = TextInputState::new();
+        edited.insert_str("ab");
+        assert_eq!(edited, TextInputState::new().with_value("ab"));
+    }
+
+    #[test]
+    fn movement() {
+        let mut state = TextInputState::new().with_value("abc");
+        state.move_left();
+        assert_eq!(state.cursor(), 2);
+        state.move_right();
+        state.move_right();
+        assert_eq!(state.cursor(), 3);
+        state.move_to_start();
+        state.move_left();
+        assert_eq!(state.cursor(), 0);
+        state.move_to_end();
+        assert_eq!(state.cursor(), 3);
+    }
+
+    #[test]
+    fn numeric_paste_is_filtered() {
+        let mut state = TextInputState::new().with_numeric(NumericInput::new());
+        assert_eq!(state.insert_str("$1,234.00"), 6);
+        assert_eq!(state.value(), "123400");
+        assert_eq!(state.number(), Some(123_400));
+    }
+
+    #[test]
+    fn with_numeric_filters_existing_value() {
+        let state = TextInputState::new()
+            .with_value("a1b2")
+            .with_numeric(NumericInput::new());
+        assert_eq!(state.value(), "12");
+        assert_eq!(state.cursor(), 2);
+    }
+
+    #[test]
+    fn increment_and_decrement_clamp() {
+        let numeric = NumericInput::new().min(-10).max(10).with_step(4);
+        let mut state = TextInputState::new().with_numeric(numeric);
+        state.increment();
+        assert_eq!(state.value(), "4");
+        state.increment();
+        state.increment();
+        assert_eq!(state.number(), Some(10));
+        state.set_number(-9);
+        state.decrement();
+        assert_eq!(state.value(), "-10");
+    }
+
+    #[test]
+    fn undo_typing_by_word() {
+        let mut state = TextInputState::new();
+        state.insert_char('a');
+        state.insert_char('b');
+        state.insert_char(' ');
+        state.insert_char('c');
+        assert!(state.undo());
+        assert_eq!(state.value(), "ab ");
+        assert!(state.undo());
+        assert_eq!(state.value(), "");
+        assert!(!state.undo());
+        assert!(state.redo());
+        assert_eq!(state.value(), "ab ");
+        assert_eq!(state.cursor(), 3);
+    }
+
+    #[test]
+    fn undo_paste_and_deletions() {
+        let mut state = TextInputState::new().with_value("ab");
+        state.insert_str("cde");
+        state.delete_backward();
+        state.delete_backward();
+        assert!(state.undo());
+        assert_eq!(state.value(), "abcde");
+        assert!(state.undo());
+        assert_eq!(state.value(), "ab");
+        assert!(!state.undo());
+    }
+
+    #[test]
+    fn cursor_movement_ends_undo_step() {
+        let mut state = TextInputState::new();
+        state.insert_str("ac");
+        state.move_left();
+        state.insert_char('b');
+        state.move_to_end();
+        state.insert_char('d');
+        assert!(state.undo());
+        assert_eq!(state.value(), "abc");
+        assert!(state.undo());
+        assert_eq!(state.value(), "ac");
+        assert_eq!(state.cursor(), 1);
+    }
+
+    #[test]
+    fn undo_clear() {
+        let mut state = TextInputState::new().with_value("abc");
+        state.clear();
+        assert!(state.undo());
+        assert_eq!(state.value(), "abc");
+    }
+
+    #[test]
+    fn number_is_none_for_text_input() {
+        let mut state = TextInputState::new().with_value("12");
+        assert_eq!(state.number(), None);
+        state.set_number(5);
+        assert_eq!(state.value(), "12");
+    }
+}
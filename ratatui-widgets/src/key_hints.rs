@@ -0,0 +1,257 @@
+//! The [`KeyHints`] widget shows the keybindings of an application in a single line, e.g. as a
+//! footer.
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{HorizontalAlignment, Rect};
+use ratatui_core::style::{Modifier, Style, Styled};
+use ratatui_core::text::{Line, Span};
+use ratatui_core::widgets::Widget;
+use unicode_width::UnicodeWidthStr;
+
+/// A widget that shows keybindings as a line of hints, such as `q Quit │ ↑↓ Move │ ⏎ Select`.
+///
+/// Each hint is a key (or several keys) and a description of what it does. The hints are shown in
+/// order, separated by [`KeyHints::separator`]. When the area is too narrow to show every hint,
+/// the hints at the end are left out and replaced by [`KeyHints::ellipsis`], so the most important
+/// hints should come first.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::{KeyHints, Widget};
+/// # let mut buf = ratatui::buffer::Buffer::empty(Rect::new(0, 0, 40, 1));
+///
+/// KeyHints::new([("q", "Quit"), ("↑↓", "Move"), ("⏎", "Select")])
+///     .key_style(Style::new().bold().yellow())
+///     .render(buf.area, &mut buf);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct KeyHints<'a> {
+    hints: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    style: Style,
+    key_style: Style,
+    description_style: Style,
+    separator: Span<'a>,
+    ellipsis: Span<'a>,
+    alignment: HorizontalAlignment,
+}
+
+impl Default for KeyHints<'_> {
+    fn default() -> Self {
+        Self {
+            hints: Vec::new(),
+            style: Style::new(),
+            key_style: Style::new().add_modifier(Modifier::BOLD),
+            description_style: Style::new(),
+            separator: Span::raw(" │ "),
+            ellipsis: Span::raw(" …"),
+            alignment: HorizontalAlignment::Left,
+        }
+    }
+}
+
+impl<'a> KeyHints<'a> {
+    /// Creates a new widget with the given hints, each a key and a description.
+    pub fn new<I, K, D>(hints: I) -> Self
+    where
+        I: IntoIterator<Item = (K, D)>,
+        K: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+    {
+        Self {
+            hints: hints
+                .into_iter()
+                .map(|(key, description)| (key.into(), description.into()))
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a hint after the existing hints.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hint<K, D>(mut self, key: K, description: D) -> Self
+    where
+        K: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+    {
+        self.hints.push((key.into(), description.into()));
+        self
+    }
+
+    /// Sets the style of the whole line.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the keys, which are bold by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.key_style = style.into();
+        self
+    }
+
+    /// Sets the style of the descriptions.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn description_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.description_style = style.into();
+        self
+    }
+
+    /// Sets the span shown between hints, which is ` │ ` by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator<T: Into<Span<'a>>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the span shown in place of the hints that don't fit, which is ` …` by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ellipsis<T: Into<Span<'a>>>(mut self, ellipsis: T) -> Self {
+        self.ellipsis = ellipsis.into();
+        self
+    }
+
+    /// Sets the alignment of the hints in the area.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alignment(mut self, alignment: HorizontalAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Returns the line of the hints that fit in the given width.
+    fn line(&self, width: usize) -> Line<'_> {
+        let hint_width = |(key, description): &(Cow<'a, str>, Cow<'a, str>)| {
+            key.width() + 1 + description.width()
+        };
+        let separator_width = self.separator.width();
+        let ellipsis_width = self.ellipsis.width();
+
+        // the number of hints that fit, keeping room for the ellipsis unless all of them fit
+        let mut shown = 0;
+        let mut used = 0;
+        let mut fits = 0;
+        for (index, hint) in self.hints.iter().enumerate() {
+            let gap = if index == 0 { 0 } else { separator_width };
+            used += gap + hint_width(hint);
+            if used > width {
+                break;
+            }
+            shown += 1;
+            let is_last = index + 1 == self.hints.len();
+            if is_last || used + ellipsis_width <= width {
+                fits = shown;
+            }
+        }
+        let shown = if shown == self.hints.len() {
+            shown
+        } else {
+            fits
+        };
+
+        let mut spans = Vec::with_capacity(shown * 4 + 1);
+        for (index, (key, description)) in self.hints[..shown].iter().enumerate() {
+            if index > 0 {
+                spans.push(self.separator.clone());
+            }
+            spans.push(Span::styled(key.as_ref(), self.key_style));
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(description.as_ref(), self.description_style));
+        }
+        if shown < self.hints.len() && ellipsis_width <= width {
+            spans.push(self.ellipsis.clone());
+        }
+        Line::from(spans).alignment(self.alignment)
+    }
+}
+
+impl Styled for KeyHints<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for KeyHints<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &KeyHints<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let line = self.line(usize::from(area.width));
+        line.render(Rect { height: 1, ..area }, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn hints() -> KeyHints<'static> {
+        KeyHints::new([("q", "Quit"), ("↑↓", "Move"), ("⏎", "Select")]).key_style(Style::new())
+    }
+
+    #[rstest]
+    #[case::all(29, "q Quit │ ↑↓ Move │ ⏎ Select  ")]
+    #[case::exact(27, "q Quit │ ↑↓ Move │ ⏎ Select")]
+    #[case::elided(20, "q Quit │ ↑↓ Move …  ")]
+    #[case::first_only(14, "q Quit …      ")]
+    #[case::none(5, " …   ")]
+    fn render(#[case] width: u16, #[case] expected: &str) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        hints().render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_styles() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        KeyHints::default()
+            .hint("q", "Quit")
+            .alignment(HorizontalAlignment::Right)
+            .description_style(Style::new().italic())
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["  q Quit"]);
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().bold());
+        expected.set_style(Rect::new(4, 0, 4, 1), Style::new().italic());
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,308 @@
+//! The [`Knob`] widget is used to display a value within a range as a circular dial.
+use alloc::format;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Style, Styled};
+use ratatui_core::symbols::Marker;
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+use crate::block::{Block, BlockExt};
+use crate::canvas::{Canvas, Points};
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// The angle at which the arc of a [`Knob`] starts, i.e. the bottom left.
+const START_ANGLE: f64 = 1.25 * PI;
+
+/// The angle covered by the arc of a [`Knob`], leaving a gap at the bottom.
+const SWEEP_ANGLE: f64 = 1.5 * PI;
+
+/// A widget to display a value within a range as a circular dial.
+///
+/// A `Knob` draws an arc that goes clockwise from the bottom left to the bottom right of the area.
+/// The part of the arc up to the value given to [`Knob::value`] is drawn with
+/// [`Knob::filled_color`] and the rest with [`Knob::unfilled_color`]. The arc is drawn with
+/// [Braille](Marker::Braille) characters by default, see [`Knob::marker`].
+///
+/// The label is centered in the dial. If not set with [`Knob::label`], the label is the percentage
+/// of the range covered by the value.
+///
+/// This takes less horizontal space than a [`Gauge`](crate::gauge::Gauge), which is useful for
+/// dashboards that show many values, such as CPU usage or volume.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::Color;
+/// use ratatui::widgets::{Block, Knob};
+///
+/// Knob::default()
+///     .block(Block::bordered().title("Volume"))
+///     .bounds([0.0, 11.0])
+///     .value(7.0)
+///     .label("7")
+///     .filled_color(Color::Green);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Knob<'a> {
+    block: Option<Block<'a>>,
+    value: f64,
+    bounds: [f64; 2],
+    label: Option<Line<'a>>,
+    style: Style,
+    filled_color: Color,
+    unfilled_color: Color,
+    marker: Marker,
+}
+
+impl Default for Knob<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            value: 0.0,
+            bounds: [0.0, 100.0],
+            label: None,
+            style: Style::default(),
+            filled_color: Color::Reset,
+            unfilled_color: Color::DarkGray,
+            marker: Marker::Braille,
+        }
+    }
+}
+
+impl<'a> Knob<'a> {
+    /// Surrounds the `Knob` with a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the value shown by the dial.
+    ///
+    /// Values outside of the [bounds](Knob::bounds) are shown as the nearest bound.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn value(mut self, value: f64) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the minimum and maximum values of the dial, which are `[0.0, 100.0]` by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds(mut self, bounds: [f64; 2]) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    /// Sets the label to display in the center of the dial.
+    ///
+    /// If the label is not defined, it is the percentage of the range covered by the value.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T: Into<Line<'a>>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the widget style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This will style the block (if any non-styled) and background of the widget. [`Block`] style
+    /// set with [`Knob::block`] takes precedence.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the color of the arc up to the value.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn filled_color(mut self, color: Color) -> Self {
+        self.filled_color = color;
+        self
+    }
+
+    /// Sets the color of the arc after the value, which is [`Color::DarkGray`] by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn unfilled_color(mut self, color: Color) -> Self {
+        self.unfilled_color = color;
+        self
+    }
+
+    /// Sets the marker used to draw the arc, which is [`Marker::Braille`] by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Returns the part of the range covered by the value, between 0 and 1.
+    fn ratio(&self) -> f64 {
+        let [min, max] = self.bounds;
+        if max <= min {
+            return 0.0;
+        }
+        ((self.value - min) / (max - min)).clamp(0.0, 1.0)
+    }
+}
+
+impl Styled for Knob<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for Knob<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Knob<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+
+        // sample the arc densely enough that there are no gaps at any size
+        let ratio = self.ratio();
+        let samples = usize::from(inner.width.max(inner.height)) * 16;
+        let (filled, unfilled): (Vec<_>, Vec<_>) = (0..=samples)
+            .map(|sample| sample as f64 / samples as f64)
+            .partition(|position| *position <= ratio);
+        let point = |position: f64| {
+            let angle = SWEEP_ANGLE.mul_add(-position, START_ANGLE);
+            (angle.cos(), angle.sin())
+        };
+        let filled: Vec<_> = filled.into_iter().map(point).collect();
+        let unfilled: Vec<_> = unfilled.into_iter().map(point).collect();
+
+        Canvas::default()
+            .marker(self.marker)
+            .x_bounds([-1.05, 1.05])
+            .y_bounds([-1.05, 1.05])
+            .preserve_aspect_ratio(2.0)
+            .background_color(self.style.bg.unwrap_or(Color::Reset))
+            .paint(|ctx| {
+                ctx.draw(&Points::new(&unfilled, self.unfilled_color));
+                ctx.layer();
+                ctx.draw(&Points::new(&filled, self.filled_color));
+            })
+            .render(inner, buf);
+
+        let default_label = Line::raw(format!("{}%", f64::round(ratio * 100.0)));
+        let label = self.label.as_ref().unwrap_or(&default_label);
+        let label_area = Rect {
+            y: inner.top() + inner.height / 2,
+            height: 1,
+            ..inner
+        };
+        label.clone().centered().render(label_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use ratatui_core::buffer::Cell;
+    use ratatui_core::style::{Modifier, Stylize};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::start(Knob::default().value(25.0), 0.25)]
+    #[case::negative_bounds(Knob::default().bounds([-1.0, 1.0]).value(0.0), 0.5)]
+    #[case::above_max(Knob::default().value(150.0), 1.0)]
+    #[case::below_min(Knob::default().value(-5.0), 0.0)]
+    #[case::empty_bounds(Knob::default().bounds([1.0, 1.0]).value(1.0), 0.0)]
+    fn ratio(#[case] knob: Knob, #[case] expected: f64) {
+        assert!((knob.ratio() - expected).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            Knob::default().black().on_white().bold().style,
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        );
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        Knob::default()
+            .value(50.0)
+            .filled_color(Color::Green)
+            .render(buf.area, &mut buf);
+        // the label is centered, and the arc starts at the bottom left and ends at the bottom right
+        let lines: Vec<_> = buf
+            .content
+            .chunks(10)
+            .map(|row| row.iter().map(Cell::symbol).collect::<String>())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                " ⢀⡴⠒⠊⠁⠒⢦⡀ ",
+                "⢰⠋      ⠙⡆",
+                "⢮  50%   ⡵",
+                "⠸⣄      ⣠⠇",
+                " ⠈      ⠁ ",
+            ]
+        );
+        // the arc is filled clockwise from the bottom left up to the top
+        assert_eq!(buf[(0, 2)].fg, Color::Green);
+        assert_eq!(buf[(1, 0)].fg, Color::Green);
+        assert_eq!(buf[(9, 2)].fg, Color::DarkGray);
+    }
+
+    #[test]
+    fn render_in_minimal_buffer() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        // This should not panic, even if the buffer is too small to render the knob.
+        Knob::default().value(50.0).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["0"]));
+    }
+
+    #[test]
+    fn render_in_zero_size_buffer() {
+        let mut buf = Buffer::empty(Rect::ZERO);
+        // This should not panic, even if the buffer has zero size.
+        Knob::default().value(50.0).render(buf.area, &mut buf);
+    }
+}
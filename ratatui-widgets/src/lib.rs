@@ -33,41 +33,73 @@
 //!
 //! # Available Widgets
 //!
+//! - [`Backdrop`]: dims the content behind a modal, such as a popup or a dialog.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
+//! - [`BigText`]: renders text in large letters using block characters.
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles, and styles.
 //! - [`calendar::Monthly`]: displays a single month.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as lines or scatter graphs.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Fill`]: paints every cell in its area with a single repeated symbol and style.
+//! - [`FpsWidget`]: displays the frame rate and timings reported by the terminal.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`Heatmap`]: displays a 2D array of values as colors.
+//! - [`KeyHints`]: shows the keybindings of an application in a single line.
+//! - [`Knob`]: displays a value within a range as a circular dial.
 //! - [`LineGauge`]: displays progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`LogView`]: shows the log records of an application, captured in a log buffer.
+//! - [`Markdown`]: renders Markdown as styled text.
+//! - [`NodeMap`]: displays a graph of nodes connected by edges.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
+//! - [`Pager`]: splits a long text into pages that fit its area.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
 //! - [`Scrollbar`]: displays a scrollbar.
+//! - [`Separator`]: draws a rule between the segments of a layout.
 //! - [`Sparkline`]: displays a single dataset as a sparkline.
+//! - [`Splits`]: divides an area into panes that can be resized with the mouse or the keyboard.
+//! - [`Stack`]: lays out widgets of different types in a row or a column.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`TerminalPane`]: shows the screen of a terminal emulator, such as a shell running in a PTY.
+//! - [`TextInput`]: edits a single line of text, with numeric and masked variants.
+//! - [`Toasts`]: shows transient notifications stacked in a corner over the UI.
 //!
+//! [`Backdrop`]: crate::backdrop::Backdrop
 //! [`BarChart`]: crate::barchart::BarChart
+//! [`BigText`]: crate::big_text::BigText
 //! [`Block`]: crate::block::Block
 //! [`calendar::Monthly`]: crate::calendar::Monthly
 //! [`Canvas`]: crate::canvas::Canvas
 //! [`Chart`]: crate::chart::Chart
 //! [`Clear`]: crate::clear::Clear
 //! [`Fill`]: crate::fill::Fill
+//! [`FpsWidget`]: crate::fps::FpsWidget
 //! [`Gauge`]: crate::gauge::Gauge
+//! [`Heatmap`]: crate::heatmap::Heatmap
+//! [`KeyHints`]: crate::key_hints::KeyHints
+//! [`Knob`]: crate::knob::Knob
 //! [`LineGauge`]: crate::gauge::LineGauge
 //! [`List`]: crate::list::List
+//! [`LogView`]: crate::log_view::LogView
+//! [`Markdown`]: crate::markdown::Markdown
+//! [`NodeMap`]: crate::node_map::NodeMap
 //! [`RatatuiLogo`]: crate::logo::RatatuiLogo
 //! [`RatatuiMascot`]: crate::mascot::RatatuiMascot
+//! [`Pager`]: crate::pager::Pager
 //! [`Paragraph`]: crate::paragraph::Paragraph
 //! [`Scrollbar`]: crate::scrollbar::Scrollbar
+//! [`Separator`]: crate::separator::Separator
 //! [`Sparkline`]: crate::sparkline::Sparkline
+//! [`Splits`]: crate::splits::Splits
+//! [`Stack`]: crate::stack::Stack
 //! [`Table`]: crate::table::Table
 //! [`Tabs`]: crate::tabs::Tabs
+//! [`TerminalPane`]: crate::terminal_pane::TerminalPane
+//! [`TextInput`]: crate::input::TextInput
+//! [`Toasts`]: crate::toast::Toasts
 //!
 //! All these widgets are re-exported directly under `ratatui::widgets` in the `ratatui` crate.
 //!
@@ -112,26 +144,53 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "animation")]
+pub mod animation;
+pub mod backdrop;
 pub mod barchart;
+#[cfg(feature = "big-text")]
+pub mod big_text;
 pub mod block;
 pub mod borders;
 pub mod canvas;
 pub mod chart;
 pub mod clear;
 pub mod fill;
+pub mod focus;
+pub mod fps;
 pub mod gauge;
+pub mod heatmap;
+pub mod highlight;
+pub mod history;
+pub mod input;
+pub mod key_hints;
+pub mod knob;
 pub mod list;
+pub mod log_view;
 pub mod logo;
+#[cfg(feature = "markdown")]
+pub mod markdown;
 pub mod mascot;
+pub mod node_map;
+pub mod pager;
 pub mod paragraph;
+pub mod scroll_memory;
 pub mod scrollbar;
+pub mod separator;
 pub mod sparkline;
+pub mod splits;
+pub mod stack;
 pub mod table;
 pub mod tabs;
+#[cfg(feature = "terminal-pane")]
+pub mod terminal_pane;
+pub mod toast;
 
+mod filter;
 #[cfg(not(feature = "std"))]
 mod polyfills;
 mod reflow;
+mod shared_fn;
 
 mod as_ref;
 #[cfg(feature = "calendar")]
@@ -3,12 +3,13 @@
 
 use alloc::vec::Vec;
 
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::text::Line;
+use ratatui_core::widgets::IntrinsicSize;
 use strum::{Display, EnumString};
 
 pub use self::item::ListItem;
-pub use self::state::ListState;
+pub use self::state::{ListState, Reorder};
 use crate::block::Block;
 use crate::table::HighlightSpacing;
 
@@ -43,6 +44,8 @@ mod state;
 /// - [`List::repeat_highlight_symbol`] sets whether to repeat the symbol and style over selected
 ///   multi-line items
 /// - [`List::direction`] sets the list direction
+/// - [`List::grabbed_style`] sets the style of the selected item while it is grabbed to be moved
+/// - [`List::on_reorder`] renders the items with a pending move applied
 ///
 /// # Examples
 ///
@@ -125,6 +128,10 @@ pub struct List<'a> {
     pub(crate) highlight_spacing: HighlightSpacing,
     /// How many items to try to keep visible before and after the selected item
     pub(crate) scroll_padding: usize,
+    /// Style used to render the selected item while it is grabbed
+    pub(crate) grabbed_style: Style,
+    /// Style added to the occurrences of the filter of the state in the items
+    pub(crate) match_style: Option<Style>,
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -283,6 +290,26 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Applies the styles of a [`Theme`] to the widget and its block.
+    ///
+    /// The [`Theme::TEXT`] style is used as the [`style`](List::style) and [`Theme::HIGHLIGHT`] as
+    /// the [`highlight_style`](List::highlight_style). Styles that are not defined by the theme
+    /// are left unchanged. The block is themed with [`Block::theme`], so it must be set before
+    /// calling this method.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        if let Some(style) = theme.get(Theme::TEXT) {
+            self.style = style;
+        }
+        if let Some(style) = theme.get(Theme::HIGHLIGHT) {
+            self.highlight_style = style;
+        }
+        self.block = self.block.map(|block| block.theme(theme));
+        self
+    }
+
     /// Set the symbol to be displayed in front of the selected item
     ///
     /// By default there are no highlight symbol.
@@ -417,6 +444,119 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets the style of the selected item while it is grabbed to be moved
+    ///
+    /// This style is patched over the [highlight style](List::highlight_style) when an item is
+    /// grabbed with [`ListState::grab`], so that the user can tell that moving the selection moves
+    /// the item.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items)
+    ///     .highlight_style(Style::new().reversed())
+    ///     .grabbed_style(Style::new().yellow());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn grabbed_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.grabbed_style = style.into();
+        self
+    }
+
+    /// Moves an item of the list according to a pending [`Reorder`]
+    ///
+    /// This is meant to be used with [`ListState::reorder`], so that the list shows the grabbed
+    /// item at its new position while the application data is only changed once the item is
+    /// released. Does nothing if `reorder` is `None`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListState};
+    ///
+    /// let mut state = ListState::default().with_selected(Some(0));
+    /// state.grab();
+    /// state.move_down();
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items).on_reorder(state.reorder());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn on_reorder(mut self, reorder: Option<Reorder>) -> Self {
+        if let Some(reorder) = reorder {
+            reorder.apply(&mut self.items);
+        }
+        self
+    }
+
+    /// Keeps the items at the given indices, in that order
+    ///
+    /// This is meant to be used with [`ListState::filtered_indices`], so that the list only shows
+    /// the items that match the filter of the state. Indices that are out of bounds are ignored.
+    /// Does nothing if `indices` is `None`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListState};
+    ///
+    /// let items = ["Item 1", "Item 2", "Item 3"];
+    /// let mut state = ListState::default().with_filter("3");
+    /// state.apply_filter(items, |item| item.contains('3'));
+    ///
+    /// let list = List::new(items).on_filter(state.filtered_indices());
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn on_filter(mut self, indices: Option<&[usize]>) -> Self {
+        if let Some(indices) = indices {
+            let mut items: Vec<Option<ListItem<'a>>> = self.items.into_iter().map(Some).collect();
+            self.items = indices
+                .iter()
+                .filter_map(|&index| items.get_mut(index)?.take())
+                .collect();
+        }
+        self
+    }
+
+    /// Sets the style added to the occurrences of the filter in the items
+    ///
+    /// When the list is rendered with a [`ListState`] that has a [filter](ListState::filter), the
+    /// occurrences of the filter in the items are highlighted with this style, ignoring ASCII
+    /// case. See [`highlight_matches`] for more details.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::List;
+    ///
+    /// let list = List::new(["Item 1", "Item 2"]).match_style(Style::new().bold().yellow());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    /// [`highlight_matches`]: crate::highlight::highlight_matches
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn match_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.match_style = Some(style.into());
+        self
+    }
+
     /// Returns the number of [`ListItem`]s in the list
     pub const fn len(&self) -> usize {
         self.items.len()
@@ -440,6 +580,38 @@ impl Styled for List<'_> {
     }
 }
 
+impl IntrinsicSize for List<'_> {
+    /// Returns the width of the widest item, plus the width of the highlight symbol and the space
+    /// taken by the [`Block`].
+    ///
+    /// The highlight symbol is included unless the [`HighlightSpacing`] is `Never`, so that
+    /// selecting an item doesn't truncate it.
+    fn width_hint(&self) -> Option<u16> {
+        let items_width = self.items.iter().map(ListItem::width).max().unwrap_or(0);
+        let symbol_width = match &self.highlight_symbol {
+            Some(symbol) if self.highlight_spacing.should_add(true) => symbol.width(),
+            _ => 0,
+        };
+        let width = u16::try_from(items_width + symbol_width).unwrap_or(u16::MAX);
+        Some(
+            self.block
+                .as_ref()
+                .map_or(width, |block| block.outer_width(width)),
+        )
+    }
+
+    /// Returns the total height of the items, plus the space taken by the [`Block`].
+    fn height_hint(&self, _width: u16) -> Option<u16> {
+        let height = self.items.iter().map(ListItem::height).sum::<usize>();
+        let height = u16::try_from(height).unwrap_or(u16::MAX);
+        Some(
+            self.block
+                .as_ref()
+                .map_or(height, |block| block.outer_height(height)),
+        )
+    }
+}
+
 impl Styled for ListItem<'_> {
     type Item = Self;
 
@@ -588,6 +760,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn theme() {
+        let theme = Theme::new()
+            .with_style(Theme::TEXT, Color::White)
+            .with_style(Theme::HIGHLIGHT, Color::Red)
+            .with_style(Theme::BORDER, Color::Blue);
+        let list = List::new(["Item 1"]).block(Block::bordered()).theme(&theme);
+        assert_eq!(list.style, Style::new().white());
+        assert_eq!(list.highlight_style, Style::new().red());
+        assert_eq!(list.block, Some(Block::bordered().theme(&theme)));
+    }
+
+    #[test]
+    fn intrinsic_size() {
+        let list = List::new(["Item 1", "Item\n2"]);
+        assert_eq!(list.width_hint(), Some(6));
+        assert_eq!(list.height_hint(10), Some(3));
+
+        let list = list.highlight_symbol(">> ").block(Block::bordered());
+        assert_eq!(list.width_hint(), Some(11));
+        assert_eq!(list.height_hint(10), Some(5));
+
+        let list = list.highlight_spacing(HighlightSpacing::Never);
+        assert_eq!(list.width_hint(), Some(8));
+    }
+
     #[test]
     fn style_inheritance() {
         let bold = Modifier::BOLD;
@@ -1,9 +1,12 @@
+use itertools::Itertools;
+use ratatui_core::accessibility::{Accessible, Role, SemanticNode, Semantics, StatefulAccessible};
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
 use ratatui_core::text::{Line, ToLine};
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
-use crate::block::BlockExt;
+use crate::block::{BlockExt, describe_block};
+use crate::highlight::highlight_matches;
 use crate::list::{List, ListDirection, ListState};
 
 impl Widget for List<'_> {
@@ -19,6 +22,41 @@ impl Widget for &List<'_> {
     }
 }
 
+/// Describes the list as a list node followed by a list item node for each item, of which none are
+/// selected.
+impl Accessible for List<'_> {
+    fn describe(&self, area: Rect, semantics: &mut Semantics) {
+        self.describe_items(area, None, semantics);
+    }
+}
+
+/// Describes the list as a list node followed by a list item node for each item, of which the
+/// selected item of the state is marked as selected.
+///
+/// The item nodes describe all items, including the ones that are scrolled out of view, and use
+/// the area of the list.
+impl StatefulAccessible for List<'_> {
+    type State = ListState;
+
+    fn describe(&self, area: Rect, state: &Self::State, semantics: &mut Semantics) {
+        self.describe_items(area, state.selected, semantics);
+    }
+}
+
+impl List<'_> {
+    fn describe_items(&self, area: Rect, selected: Option<usize>, semantics: &mut Semantics) {
+        let level = describe_block(self.block.as_ref(), area, semantics);
+        let inner = self.block.inner_if_some(area);
+        semantics.push(SemanticNode::new(Role::List, inner).level(level));
+        semantics.extend(self.items.iter().enumerate().map(|(i, item)| {
+            SemanticNode::new(Role::ListItem, inner)
+                .label(item.content.iter().join(" "))
+                .selected(selected == Some(i))
+                .level(level + 1)
+        }));
+    }
+}
+
 impl StatefulWidget for List<'_> {
     type State = ListState;
 
@@ -101,10 +139,19 @@ impl StatefulWidget for &List<'_> {
             } else {
                 row_area
             };
-            Widget::render(&item.content, item_area, buf);
+            match self.match_style.filter(|_| !state.filter().is_empty()) {
+                Some(match_style) => {
+                    let content = highlight_matches(&item.content, state.filter(), match_style);
+                    Widget::render(&content, item_area, buf);
+                }
+                None => Widget::render(&item.content, item_area, buf),
+            }
 
             if is_selected {
                 buf.set_style(row_area, self.highlight_style);
+                if state.is_grabbed() {
+                    buf.set_style(row_area, self.grabbed_style);
+                }
             }
             if selection_spacing {
                 for j in 0..item.content.height() {
@@ -256,6 +303,7 @@ impl List<'_> {
 #[cfg(test)]
 mod tests {
     use alloc::borrow::ToOwned;
+    use alloc::string::ToString;
     use alloc::vec;
     use alloc::vec::Vec;
 
@@ -669,6 +717,45 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn grabbed_item_with_pending_reorder() {
+        let mut state = ListState::default().with_selected(Some(0));
+        state.grab();
+        state.move_down();
+        let list = List::new(["Item 0", "Item 1", "Item 2"])
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().italic())
+            .grabbed_style(Style::new().yellow())
+            .on_reorder(state.reorder());
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        let expected = Buffer::with_lines([
+            "  Item 1  ".into(),
+            ">>Item 0  ".yellow().italic(),
+            "  Item 2  ".into(),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn filtered_items_with_match_style() {
+        let items = ["Apple", "Banana", "Cherry", "Orange"];
+        let mut state = ListState::default()
+            .with_selected(Some(3))
+            .with_filter("an");
+        state.apply_filter(items, |item| item.to_lowercase().contains("an"));
+        let list = List::new(items)
+            .highlight_symbol(">>")
+            .match_style(Style::new().yellow())
+            .on_filter(state.filtered_indices());
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        let expected = Buffer::with_lines([
+            Line::from_iter(["  B".into(), "an".yellow(), "an".yellow(), "a  ".into()]),
+            Line::from_iter([">>Or".into(), "an".yellow(), "ge  ".into()]),
+            Line::from("          "),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn highlight_symbol_style_and_style() {
         let list = List::new(["Item 0", "Item 1", "Item 2"])
@@ -1269,4 +1356,24 @@ mod tests {
         StatefulWidget::render(list, single_line_buf.area, &mut single_line_buf, &mut state);
         assert_eq!(single_line_buf, Buffer::with_lines([expected]));
     }
+
+    #[test]
+    fn describe() {
+        let list = List::new(["Item 0", "Item 1"]).block(Block::bordered().title("Items"));
+        let area = Rect::new(0, 0, 10, 4);
+        let mut semantics = Semantics::new();
+        Accessible::describe(&list, area, &mut semantics);
+        assert_eq!(
+            semantics.to_string(),
+            "region: Items\n  list\n    list item: Item 0\n    list item: Item 1\n"
+        );
+
+        let mut semantics = Semantics::new();
+        let state = ListState::default().with_selected(Some(1));
+        StatefulAccessible::describe(&list, area, &state, &mut semantics);
+        assert_eq!(
+            semantics.nodes()[3].to_string(),
+            "    list item: Item 1, selected"
+        );
+    }
 }
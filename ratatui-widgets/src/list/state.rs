@@ -1,3 +1,7 @@
+use alloc::string::String;
+
+use crate::filter::Filter;
+
 /// State of the [`List`] widget
 ///
 /// This state can be used to scroll through items and select one. When the list is rendered as a
@@ -5,12 +9,16 @@
 /// that the selected item is visible. This will modify the [`ListState`] object passed to the
 /// `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of the following fields:
 /// - [`offset`]: the index of the first item to be displayed
 /// - [`selected`]: the index of the selected item, which can be `None` if no item is selected
+/// - [`grabbed`]: whether the selected item is grabbed to be moved, see [Reordering](#reordering)
+/// - [`filter`]: the filter entered by the user, see [Filtering](#filtering)
 ///
 /// [`offset`]: ListState::offset()
 /// [`selected`]: ListState::selected()
+/// [`grabbed`]: ListState::is_grabbed()
+/// [`filter`]: ListState::filter()
 ///
 /// See the list in the [Examples] directory for a more in depth example of the various
 /// configuration options and for how to handle state.
@@ -39,12 +47,149 @@
 /// # }
 /// ```
 ///
+/// # Reordering
+///
+/// The selected item can be grabbed with [`ListState::grab`] and moved with
+/// [`ListState::move_up`] and [`ListState::move_down`], e.g. to let the user reorder a playlist or
+/// a task list. While an item is grabbed, it is highlighted with [`List::grabbed_style`] and the
+/// pending move is returned by [`ListState::reorder`], which can be passed to
+/// [`List::on_reorder`] to render the items in their new order. [`ListState::release`] ends the
+/// move and returns the [`Reorder`] to apply to the application data, while
+/// [`ListState::cancel_grab`] moves the item back to where it was grabbed.
+///
+/// ```rust
+/// use ratatui::widgets::ListState;
+///
+/// let mut tracks = vec!["Intro", "Verse", "Chorus"];
+/// let mut state = ListState::default().with_selected(Some(2));
+///
+/// state.grab();
+/// state.move_up();
+/// state.move_up();
+/// if let Some(reorder) = state.release() {
+///     reorder.apply(&mut tracks);
+/// }
+/// assert_eq!(tracks, ["Chorus", "Intro", "Verse"]);
+/// assert_eq!(state.selected(), Some(0));
+/// ```
+///
+/// # Filtering
+///
+/// The state can hold a filter, e.g. the text typed in the search box of a picker. Set it with
+/// [`ListState::set_filter`], then call [`ListState::apply_filter`] with the items and a function
+/// that returns whether an item matches. The state remembers the indices of the matching items,
+/// which are passed to [`List::on_filter`] to only render those items, and keeps the same item
+/// selected when the filter changes. While a filter is applied, the selected index refers to the
+/// filtered items, and [`ListState::selected_unfiltered`] returns the index of the selected item
+/// in the application data. [`List::match_style`] highlights the filter in the rendered items.
+///
+/// ```rust
+/// use ratatui::widgets::{List, ListState};
+///
+/// let fruits = ["Apple", "Banana", "Cherry"];
+/// let mut state = ListState::default().with_selected(Some(1));
+///
+/// state.set_filter("an");
+/// let filter = state.filter().to_lowercase();
+/// state.apply_filter(fruits, |fruit| fruit.to_lowercase().contains(&filter));
+/// assert_eq!(state.selected(), Some(0));
+/// assert_eq!(state.selected_unfiltered(), Some(1));
+///
+/// let list = List::new(fruits).on_filter(state.filtered_indices());
+/// ```
+///
 /// [`List`]: super::List
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+/// [`List::grabbed_style`]: super::List::grabbed_style
+/// [`List::on_reorder`]: super::List::on_reorder
+/// [`List::on_filter`]: super::List::on_filter
+/// [`List::match_style`]: super::List::match_style
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
+    /// The index that the grabbed item had when it was grabbed
+    ///
+    /// Grabbing is transient, so it is not saved with the rest of the state.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) grabbed: Option<usize>,
+    /// The filter and the indices of the matching items
+    ///
+    /// The filter is applied to the items of the application, so it is not saved with the rest of
+    /// the state.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) filter: Filter,
+}
+
+/// A move of an item of a list from one index to another.
+///
+/// The items between the two indices are shifted by one position to make room for the moved
+/// item. A `Reorder` is returned by [`ListState::reorder`] and [`ListState::release`] when the
+/// user moves a grabbed item, see [`ListState`] for more details.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::Reorder;
+///
+/// let mut items = ['a', 'b', 'c', 'd'];
+/// let reorder = Reorder::new(0, 2);
+/// reorder.apply(&mut items);
+/// assert_eq!(items, ['b', 'c', 'a', 'd']);
+/// assert_eq!(reorder.map_index(0), 2);
+/// assert_eq!(reorder.map_index(1), 0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reorder {
+    /// The index of the item before it is moved
+    pub from: usize,
+    /// The index of the item after it is moved
+    pub to: usize,
+}
+
+impl Reorder {
+    /// Creates a move of the item at index `from` to index `to`
+    pub const fn new(from: usize, to: usize) -> Self {
+        Self { from, to }
+    }
+
+    /// Returns true if the item is moved to the index it already has
+    pub const fn is_noop(&self) -> bool {
+        self.from == self.to
+    }
+
+    /// Moves the item in the slice, shifting the items in between
+    ///
+    /// If `to` is out of bounds, the item is moved to the end of the slice. If `from` is out of
+    /// bounds, the slice is not modified.
+    pub fn apply<T>(&self, items: &mut [T]) {
+        if self.from >= items.len() {
+            return;
+        }
+        let to = self.to.min(items.len() - 1);
+        if self.from < to {
+            items[self.from..=to].rotate_left(1);
+        } else {
+            items[to..=self.from].rotate_right(1);
+        }
+    }
+
+    /// Returns the index that the item at `index` has after the move
+    ///
+    /// This can be used to map indices that refer to the items before the move, e.g. the indices
+    /// of marked items, to the items after the move.
+    pub const fn map_index(&self, index: usize) -> usize {
+        if index == self.from {
+            self.to
+        } else if self.from < index && index <= self.to {
+            index - 1
+        } else if self.to <= index && index < self.from {
+            index + 1
+        } else {
+            index
+        }
+    }
 }
 
 impl ListState {
@@ -158,6 +303,7 @@ impl ListState {
         self.selected = index;
         if index.is_none() {
             self.offset = 0;
+            self.grabbed = None;
         }
     }
 
@@ -268,13 +414,294 @@ impl ListState {
         let selected = self.selected.unwrap_or_default();
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Grabs the selected item so that it can be moved with [`ListState::move_up`] and
+    /// [`ListState::move_down`]
+    ///
+    /// Does nothing if no item is selected or an item is already grabbed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.grab();
+    /// assert!(state.is_grabbed());
+    /// ```
+    pub const fn grab(&mut self) {
+        if self.grabbed.is_none() {
+            self.grabbed = self.selected;
+        }
+    }
+
+    /// Returns true if the selected item is grabbed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default();
+    /// assert!(!state.is_grabbed());
+    /// ```
+    pub const fn is_grabbed(&self) -> bool {
+        self.grabbed.is_some()
+    }
+
+    /// Moves the grabbed item up by one position
+    ///
+    /// Does nothing if no item is grabbed or the item is already the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.grab();
+    /// state.move_up();
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub const fn move_up(&mut self) {
+        if let (Some(_), Some(selected)) = (self.grabbed, self.selected) {
+            self.selected = Some(selected.saturating_sub(1));
+        }
+    }
+
+    /// Moves the grabbed item down by one position
+    ///
+    /// Does nothing if no item is grabbed.
+    ///
+    /// Note: until the list is rendered, the number of items is not known, so the item can be moved
+    /// past the last item. The selected index will be corrected when the list is rendered, and the
+    /// [`Reorder`] returned by [`ListState::reorder`] moves the item to the end of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.grab();
+    /// state.move_down();
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    pub const fn move_down(&mut self) {
+        if let (Some(_), Some(selected)) = (self.grabbed, self.selected) {
+            self.selected = Some(selected.saturating_add(1));
+        }
+    }
+
+    /// Returns the pending move of the grabbed item, or `None` if no item is grabbed
+    ///
+    /// Pass this to [`List::on_reorder`] to render the items in their new order while the item is
+    /// grabbed.
+    ///
+    /// [`List::on_reorder`]: super::List::on_reorder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{ListState, Reorder};
+    ///
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.grab();
+    /// state.move_down();
+    /// assert_eq!(state.reorder(), Some(Reorder::new(1, 2)));
+    /// ```
+    pub fn reorder(&self) -> Option<Reorder> {
+        Some(Reorder::new(self.grabbed?, self.selected?))
+    }
+
+    /// Releases the grabbed item and returns its move, or `None` if no item is grabbed
+    ///
+    /// The returned [`Reorder`] should be applied to the application data, so that the item stays
+    /// at its new position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{ListState, Reorder};
+    ///
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.grab();
+    /// state.move_up();
+    /// assert_eq!(state.release(), Some(Reorder::new(1, 0)));
+    /// assert!(!state.is_grabbed());
+    /// ```
+    pub fn release(&mut self) -> Option<Reorder> {
+        let reorder = self.reorder();
+        self.grabbed = None;
+        reorder
+    }
+
+    /// Releases the grabbed item and selects it at the position where it was grabbed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.grab();
+    /// state.move_down();
+    /// state.cancel_grab();
+    /// assert_eq!(state.selected(), Some(1));
+    /// ```
+    pub const fn cancel_grab(&mut self) {
+        if let Some(grabbed) = self.grabbed.take() {
+            self.selected = Some(grabbed);
+        }
+    }
+
+    /// Sets the filter
+    ///
+    /// The filter is only applied to the items by [`ListState::apply_filter`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default().with_filter("foo");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_filter<T: Into<String>>(mut self, filter: T) -> Self {
+        self.filter.text = filter.into();
+        self
+    }
+
+    /// The filter, which is empty if no filter is set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default();
+    /// assert_eq!(state.filter(), "");
+    /// ```
+    pub fn filter(&self) -> &str {
+        &self.filter.text
+    }
+
+    /// Sets the filter
+    ///
+    /// The filter is only applied to the items by [`ListState::apply_filter`], which should be
+    /// called after the filter changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.set_filter("foo");
+    /// assert_eq!(state.filter(), "foo");
+    /// ```
+    pub fn set_filter<T: Into<String>>(&mut self, filter: T) {
+        self.filter.text = filter.into();
+    }
+
+    /// Keeps the items for which `matches` returns true and returns their indices
+    ///
+    /// `matches` is usually a function of the [filter](ListState::filter), but can also filter on
+    /// other criteria. The indices are remembered and returned by
+    /// [`ListState::filtered_indices`], to pass to [`List::on_filter`].
+    ///
+    /// The selected item stays selected if it matches, otherwise the next matching item is
+    /// selected. The offset is reset and any grabbed item is released, see [`ListState::grab`].
+    ///
+    /// [`List::on_filter`]: super::List::on_filter
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_filter("b");
+    /// let filter = state.filter().to_string();
+    /// let indices = state.apply_filter(["a", "b", "ab"], |item| item.contains(&filter));
+    /// assert_eq!(indices, [1, 2]);
+    /// ```
+    pub fn apply_filter<I, F>(&mut self, items: I, matches: F) -> &[usize]
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> bool,
+    {
+        self.selected = self.filter.apply(self.selected, items, matches);
+        self.offset = 0;
+        self.grabbed = None;
+        self.filter.indices.as_deref().unwrap_or_default()
+    }
+
+    /// Removes the filter and shows all items again
+    ///
+    /// The selected item stays selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_filter("b");
+    /// state.apply_filter(["a", "b"], |item| item == "b");
+    /// state.clear_filter();
+    /// assert_eq!(state.filter(), "");
+    /// assert_eq!(state.filtered_indices(), None);
+    /// ```
+    pub fn clear_filter(&mut self) {
+        self.selected = self.filter.clear(self.selected);
+        self.offset = 0;
+        self.grabbed = None;
+    }
+
+    /// The indices of the items that matched when the filter was last applied, or `None` if no
+    /// filter is applied
+    ///
+    /// Pass this to [`List::on_filter`] to only render the matching items.
+    ///
+    /// [`List::on_filter`]: super::List::on_filter
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default();
+    /// assert_eq!(state.filtered_indices(), None);
+    /// ```
+    pub fn filtered_indices(&self) -> Option<&[usize]> {
+        self.filter.indices.as_deref()
+    }
+
+    /// Index of the selected item in the unfiltered items
+    ///
+    /// This is the same as [`ListState::selected`] if no filter is applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(0));
+    /// state.apply_filter(["a", "b"], |item| item == "b");
+    /// assert_eq!(state.selected(), Some(0));
+    /// assert_eq!(state.selected_unfiltered(), Some(1));
+    /// ```
+    pub fn selected_unfiltered(&self) -> Option<usize> {
+        self.filter.unfiltered_index(self.selected?)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
 
-    use crate::list::ListState;
+    use crate::list::{ListState, Reorder};
 
     #[test]
     fn selected() {
@@ -354,4 +781,80 @@ mod tests {
         state.scroll_up_by(4);
         assert_eq!(state.selected, Some(0));
     }
+
+    #[test]
+    fn grab_and_move() {
+        let mut state = ListState::default();
+        state.grab(); // nothing is selected
+        assert!(!state.is_grabbed());
+
+        state.select(Some(1));
+        state.move_down(); // nothing is grabbed
+        assert_eq!(state.selected, Some(1));
+
+        state.grab();
+        state.move_up();
+        state.move_up(); // should not go below 0
+        assert_eq!(state.reorder(), Some(Reorder::new(1, 0)));
+
+        state.move_down();
+        state.move_down();
+        state.move_down();
+        assert_eq!(state.reorder(), Some(Reorder::new(1, 3)));
+
+        assert_eq!(state.release(), Some(Reorder::new(1, 3)));
+        assert_eq!(state.selected, Some(3));
+        assert_eq!(state.release(), None);
+    }
+
+    #[test]
+    fn cancel_grab() {
+        let mut state = ListState::default().with_selected(Some(2));
+        state.grab();
+        state.move_up();
+        state.cancel_grab();
+        assert_eq!(state.selected, Some(2));
+        assert!(!state.is_grabbed());
+
+        state.grab();
+        state.select(None);
+        assert!(!state.is_grabbed());
+    }
+
+    #[test]
+    fn apply_filter() {
+        let items = ["apple", "banana", "cherry"];
+        let mut state = ListState::default().with_selected(Some(1)).with_offset(1);
+        state.grab();
+
+        assert_eq!(state.apply_filter(items, |item| item.contains('e')), [0, 2]);
+        assert_eq!(state.selected, Some(1));
+        assert_eq!(state.selected_unfiltered(), Some(2));
+        assert_eq!(state.offset, 0);
+        assert!(!state.is_grabbed());
+
+        state.clear_filter();
+        assert_eq!(state.selected, Some(2));
+        assert_eq!(state.filtered_indices(), None);
+    }
+
+    #[test]
+    fn reorder_apply() {
+        let mut items = [0, 1, 2, 3];
+        Reorder::new(3, 1).apply(&mut items);
+        assert_eq!(items, [0, 3, 1, 2]);
+        // moving past the end moves to the last index
+        Reorder::new(0, 10).apply(&mut items);
+        assert_eq!(items, [3, 1, 2, 0]);
+        // moving an item that doesn't exist does nothing
+        Reorder::new(4, 0).apply(&mut items);
+        assert_eq!(items, [3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn reorder_map_index() {
+        let reorder = Reorder::new(3, 1);
+        let mapped: [usize; 5] = core::array::from_fn(|index| reorder.map_index(index));
+        assert_eq!(mapped, [0, 2, 3, 1, 4]);
+    }
 }
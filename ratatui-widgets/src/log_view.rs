@@ -0,0 +1,557 @@
+//! The [`LogView`] widget shows the log records of an application, captured in a [`LogBuffer`].
+//!
+//! Applications usually log with the [`log`] or [`tracing`] crates, whose output can't be written
+//! to the terminal while it shows the UI. With the `log` feature, [`LogCollector`] is a logger that
+//! captures records into a shared [`LogBuffer`], and with the `tracing` feature, [`LogLayer`] is a
+//! [`tracing_subscriber`] layer that does the same for events. The [`LogView`] renders the records
+//! of the buffer, filtered by level.
+//!
+//! [`log`]: https://docs.rs/log
+//! [`tracing`]: https://docs.rs/tracing
+//! [`tracing_subscriber`]: https://docs.rs/tracing-subscriber
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Style, Styled, Stylize};
+use ratatui_core::text::{Line, Span};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use strum::{Display, EnumString};
+
+#[cfg(feature = "log")]
+pub use self::collector::LogCollector;
+#[cfg(feature = "tracing")]
+pub use self::layer::LogLayer;
+use crate::block::{Block, BlockExt};
+
+#[cfg(feature = "log")]
+mod collector;
+#[cfg(feature = "tracing")]
+mod layer;
+
+/// The level of a [`LogRecord`], from the most to the least severe.
+///
+/// Levels are ordered by verbosity, so [`LogLevel::Error`] is the smallest and
+/// [`LogLevel::Trace`] the largest, like the levels of the `log` crate.
+#[derive(
+    Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogLevel {
+    /// A serious error.
+    Error,
+    /// A hazardous situation.
+    Warn,
+    /// Useful information.
+    #[default]
+    Info,
+    /// Lower priority information.
+    Debug,
+    /// Very low priority, often extremely verbose, information.
+    Trace,
+}
+
+impl LogLevel {
+    /// All the levels, from the most to the least severe.
+    pub const ALL: [Self; 5] = [
+        Self::Error,
+        Self::Warn,
+        Self::Info,
+        Self::Debug,
+        Self::Trace,
+    ];
+
+    /// Returns the default style of the level tag of records with this level.
+    pub const fn style(self) -> Style {
+        match self {
+            Self::Error => Style::new().fg(Color::Red),
+            Self::Warn => Style::new().fg(Color::Yellow),
+            Self::Info => Style::new().fg(Color::Green),
+            Self::Debug => Style::new().fg(Color::Blue),
+            Self::Trace => Style::new().fg(Color::DarkGray),
+        }
+    }
+
+    /// Returns the name of the level padded to the same width as the other levels.
+    const fn tag(self) -> &'static str {
+        match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN ",
+            Self::Info => "INFO ",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        }
+    }
+}
+
+/// A log message with its level and target, as stored in a [`LogBuffer`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogRecord {
+    level: LogLevel,
+    target: String,
+    message: String,
+}
+
+impl LogRecord {
+    /// Creates a new record.
+    ///
+    /// The target is usually the module path of the code that logged the message.
+    pub fn new<T, M>(level: LogLevel, target: T, message: M) -> Self
+    where
+        T: Into<String>,
+        M: Into<String>,
+    {
+        Self {
+            level,
+            target: target.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Returns the level of the record.
+    pub const fn level(&self) -> LogLevel {
+        self.level
+    }
+
+    /// Returns the target of the record.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Returns the message of the record.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// A bounded ring buffer of [`LogRecord`]s.
+///
+/// When the buffer is full, pushing a record drops the oldest one. With the `std` feature, use
+/// [`LogBuffer::shared`] to create a buffer that a logger can write to from any thread while the
+/// UI renders it.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{LogBuffer, LogLevel, LogRecord};
+///
+/// let mut buffer = LogBuffer::new(2);
+/// buffer.push(LogRecord::new(LogLevel::Info, "app", "started"));
+/// buffer.push(LogRecord::new(LogLevel::Warn, "app::net", "retrying"));
+/// buffer.push(LogRecord::new(
+///     LogLevel::Error,
+///     "app::net",
+///     "connection lost",
+/// ));
+/// assert_eq!(buffer.len(), 2);
+/// assert_eq!(buffer.iter().next().unwrap().message(), "retrying");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LogBuffer {
+    records: VecDeque<LogRecord>,
+    capacity: usize,
+}
+
+impl Default for LogBuffer {
+    /// Creates a buffer that keeps the last 1000 records.
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+impl LogBuffer {
+    /// Creates a new empty buffer that keeps at most `capacity` records.
+    pub const fn new(capacity: usize) -> Self {
+        Self {
+            records: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Creates a new empty buffer that keeps at most `capacity` records, which can be shared
+    /// between threads.
+    ///
+    /// Pass a clone of the returned buffer to [`LogCollector`] or [`LogLayer`], and lock it to
+    /// render a [`LogView`].
+    #[cfg(feature = "std")]
+    pub fn shared(capacity: usize) -> alloc::sync::Arc<std::sync::Mutex<Self>> {
+        alloc::sync::Arc::new(std::sync::Mutex::new(Self::new(capacity)))
+    }
+
+    /// Adds a record to the buffer, dropping the oldest record if the buffer is full.
+    pub fn push(&mut self, record: LogRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    /// Returns an iterator over the records, from the oldest to the newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &LogRecord> + ExactSizeIterator {
+        self.records.iter()
+    }
+
+    /// Returns the maximum number of records kept in the buffer.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of records in the buffer.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if the buffer has no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Removes all the records.
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+}
+
+/// State of a [`LogView`], which records the scroll position and whether the view follows new
+/// records.
+///
+/// A new state follows the newest records, so that they are always shown at the bottom of the
+/// view. Scrolling up stops following, and scrolling back down to the newest record (or calling
+/// [`LogViewState::follow`]) follows again.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogViewState {
+    offset: usize,
+    follow: bool,
+}
+
+impl Default for LogViewState {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            follow: true,
+        }
+    }
+}
+
+impl LogViewState {
+    /// Creates a new state that follows the newest records.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the first shown record, among the records that pass the level filter.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns true if the view follows the newest records.
+    pub const fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Follows the newest records, scrolling to the bottom on the next render.
+    pub const fn follow(&mut self) {
+        self.follow = true;
+    }
+
+    /// Scrolls up by the given number of records and stops following the newest records.
+    pub const fn scroll_up(&mut self, amount: usize) {
+        self.follow = false;
+        self.offset = self.offset.saturating_sub(amount);
+    }
+
+    /// Scrolls down by the given number of records.
+    ///
+    /// Scrolling past the newest record follows the newest records again.
+    pub const fn scroll_down(&mut self, amount: usize) {
+        self.offset = self.offset.saturating_add(amount);
+    }
+
+    /// Scrolls to the oldest record and stops following the newest records.
+    pub const fn scroll_to_top(&mut self) {
+        self.follow = false;
+        self.offset = 0;
+    }
+}
+
+/// A widget that shows the records of a [`LogBuffer`], one per line, from the oldest to the newest.
+///
+/// Each record is shown as its level tag, styled by level, followed by its target and message.
+/// Records more verbose than [`LogView::max_level`] are hidden. Only the first line of multi-line
+/// messages is shown, and long lines are truncated.
+///
+/// Rendered as a [`StatefulWidget`] with a [`LogViewState`], the view can be scrolled and follows
+/// the newest records until scrolled up. Rendered as a [`Widget`], it always shows the newest
+/// records.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{
+///     Block, LogBuffer, LogLevel, LogRecord, LogView, LogViewState, StatefulWidget,
+/// };
+/// # let mut buf = ratatui::buffer::Buffer::empty(Rect::new(0, 0, 40, 10));
+///
+/// let mut buffer = LogBuffer::new(100);
+/// buffer.push(LogRecord::new(LogLevel::Debug, "app", "loading config"));
+/// let mut state = LogViewState::new();
+/// LogView::new(&buffer)
+///     .block(Block::bordered().title("Logs"))
+///     .max_level(LogLevel::Info)
+///     .render(buf.area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LogView<'a> {
+    buffer: &'a LogBuffer,
+    block: Option<Block<'a>>,
+    style: Style,
+    level_styles: [Style; 5],
+    max_level: LogLevel,
+    show_target: bool,
+}
+
+impl<'a> LogView<'a> {
+    /// Creates a new view of the records of the buffer.
+    ///
+    /// All levels are shown by default.
+    pub const fn new(buffer: &'a LogBuffer) -> Self {
+        Self {
+            buffer,
+            block: None,
+            style: Style::new(),
+            level_styles: [
+                LogLevel::Error.style(),
+                LogLevel::Warn.style(),
+                LogLevel::Info.style(),
+                LogLevel::Debug.style(),
+                LogLevel::Trace.style(),
+            ],
+            max_level: LogLevel::Trace,
+            show_target: true,
+        }
+    }
+
+    /// Wraps the view in a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole view.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the level tag of the records with the given level.
+    ///
+    /// The default styles are returned by [`LogLevel::style`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn level_style<S: Into<Style>>(mut self, level: LogLevel, style: S) -> Self {
+        self.level_styles[level as usize] = style.into();
+        self
+    }
+
+    /// Hides the records that are more verbose than the given level.
+    ///
+    /// For example, [`LogLevel::Info`] shows errors, warnings and info records, and hides debug and
+    /// trace records.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_level(mut self, level: LogLevel) -> Self {
+        self.max_level = level;
+        self
+    }
+
+    /// Sets whether the target of each record is shown before its message.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_target(mut self, show_target: bool) -> Self {
+        self.show_target = show_target;
+        self
+    }
+
+    fn line(&self, record: &'a LogRecord) -> Line<'a> {
+        let level = record.level();
+        let mut spans = Vec::with_capacity(4);
+        spans.push(Span::styled(level.tag(), self.level_styles[level as usize]));
+        spans.push(Span::raw(" "));
+        if self.show_target {
+            spans.push(Span::raw(record.target()).dim());
+            spans.push(Span::raw(" "));
+        }
+        let message = record.message().lines().next().unwrap_or_default();
+        spans.push(Span::raw(message));
+        Line::from(spans)
+    }
+}
+
+impl Styled for LogView<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for LogView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &LogView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = LogViewState::new();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for LogView<'_> {
+    type State = LogViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &LogView<'_> {
+    type State = LogViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+
+        let records: Vec<&LogRecord> = self
+            .buffer
+            .iter()
+            .filter(|record| record.level() <= self.max_level)
+            .collect();
+        let last_offset = records.len().saturating_sub(usize::from(inner.height));
+        if state.follow || state.offset >= last_offset {
+            state.follow = true;
+            state.offset = last_offset;
+        }
+
+        for (record, row) in records[state.offset..].iter().zip(inner.rows()) {
+            buf.set_line(row.x, row.y, &self.line(record), row.width);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn buffer() -> LogBuffer {
+        let mut buffer = LogBuffer::new(10);
+        buffer.push(LogRecord::new(LogLevel::Info, "app", "one"));
+        buffer.push(LogRecord::new(LogLevel::Debug, "app", "two"));
+        buffer.push(LogRecord::new(LogLevel::Error, "net", "three"));
+        buffer.push(LogRecord::new(LogLevel::Warn, "app", "four\nmore"));
+        buffer
+    }
+
+    #[test]
+    fn buffer_drops_oldest() {
+        let mut buffer = LogBuffer::new(2);
+        for message in ["a", "b", "c"] {
+            buffer.push(LogRecord::new(LogLevel::Info, "app", message));
+        }
+        let messages: Vec<&str> = buffer.iter().map(LogRecord::message).collect();
+        assert_eq!(messages, ["b", "c"]);
+
+        let mut buffer = LogBuffer::new(0);
+        buffer.push(LogRecord::new(LogLevel::Info, "app", "a"));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn render_follows_newest() {
+        let buffer = buffer();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        Widget::render(LogView::new(&buffer), buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "ERROR net three ".into(),
+            Line::from(vec![
+                Span::styled("WARN ", LogLevel::Warn.style()),
+                " ".into(),
+                "app".dim(),
+                " four".into(),
+            ]),
+        ]);
+        expected.set_style(Rect::new(0, 0, 5, 1), LogLevel::Error.style());
+        expected.set_style(Rect::new(6, 0, 3, 1), Style::new().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_filters_levels() {
+        let buffer = buffer();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        let view = LogView::new(&buffer)
+            .max_level(LogLevel::Info)
+            .show_target(false)
+            .level_style(LogLevel::Error, Style::new())
+            .level_style(LogLevel::Warn, Style::new())
+            .level_style(LogLevel::Info, Style::new());
+        Widget::render(view, buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["INFO  one   ", "ERROR three ", "WARN  four  "])
+        );
+    }
+
+    #[test]
+    fn scrolling_stops_and_resumes_following() {
+        let buffer = buffer();
+        let view = LogView::new(&buffer).show_target(false);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        let mut state = LogViewState::new();
+        StatefulWidget::render(&view, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 2);
+
+        state.scroll_up(1);
+        assert!(!state.is_following());
+        StatefulWidget::render(&view, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 1);
+        assert!(!state.is_following());
+
+        state.scroll_down(5);
+        StatefulWidget::render(&view, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 2);
+        assert!(state.is_following());
+    }
+}
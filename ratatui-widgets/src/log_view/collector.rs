@@ -0,0 +1,138 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::sync::Arc;
+use std::sync::Mutex;
+
+use super::{LogBuffer, LogLevel, LogRecord};
+
+/// A logger for the [`log`] crate that captures records into a shared [`LogBuffer`].
+///
+/// Records of all levels up to [`LogCollector::max_level`] are captured, and can then be shown
+/// with a [`LogView`](super::LogView).
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_widgets::log_view::{LogBuffer, LogCollector};
+///
+/// let buffer = LogBuffer::shared(1000);
+/// LogCollector::new(buffer.clone()).init()?;
+/// log::info!("started");
+/// // render `LogView::new(&buffer.lock().unwrap())` in the UI
+/// # Ok::<(), log::SetLoggerError>(())
+/// ```
+///
+/// [`log`]: https://docs.rs/log
+#[derive(Debug, Clone)]
+pub struct LogCollector {
+    buffer: Arc<Mutex<LogBuffer>>,
+    max_level: LogLevel,
+}
+
+impl LogCollector {
+    /// Creates a new collector that captures records of all levels into the buffer.
+    pub const fn new(buffer: Arc<Mutex<LogBuffer>>) -> Self {
+        Self {
+            buffer,
+            max_level: LogLevel::Trace,
+        }
+    }
+
+    /// Ignores the records that are more verbose than the given level.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_level(mut self, level: LogLevel) -> Self {
+        self.max_level = level;
+        self
+    }
+
+    /// Sets the collector as the global logger, and sets the maximum level of the `log` crate to
+    /// the maximum level of the collector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a global logger was already set.
+    pub fn init(self) -> Result<(), log::SetLoggerError> {
+        let level = log::Level::from(self.max_level).to_level_filter();
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(level);
+        Ok(())
+    }
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => Self::Error,
+            log::Level::Warn => Self::Warn,
+            log::Level::Info => Self::Info,
+            log::Level::Debug => Self::Debug,
+            log::Level::Trace => Self::Trace,
+        }
+    }
+}
+
+impl From<LogLevel> for log::Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => Self::Error,
+            LogLevel::Warn => Self::Warn,
+            LogLevel::Info => Self::Info,
+            LogLevel::Debug => Self::Debug,
+            LogLevel::Trace => Self::Trace,
+        }
+    }
+}
+
+impl log::Log for LogCollector {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        LogLevel::from(metadata.level()) <= self.max_level
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let record = LogRecord::new(
+            record.level().into(),
+            record.target(),
+            format!("{}", record.args()),
+        );
+        // a poisoned buffer only means that a thread panicked while pushing a record
+        let mut buffer = self
+            .buffer
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        buffer.push(record);
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use log::Log;
+
+    use super::*;
+
+    #[test]
+    fn captures_enabled_records() {
+        let buffer = LogBuffer::shared(10);
+        let collector = LogCollector::new(buffer.clone()).max_level(LogLevel::Info);
+        for level in [log::Level::Info, log::Level::Debug] {
+            collector.log(
+                &log::Record::builder()
+                    .level(level)
+                    .target("app")
+                    .args(format_args!("value: {}", 42))
+                    .build(),
+            );
+        }
+        let buffer = buffer.lock().unwrap();
+        assert_eq!(
+            buffer.iter().collect::<alloc::vec::Vec<_>>(),
+            [&LogRecord::new(LogLevel::Info, "app", "value: 42")]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::fmt::{self, Write};
+use std::sync::Mutex;
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+use super::{LogBuffer, LogLevel, LogRecord};
+
+/// A [`tracing_subscriber`] layer that captures events into a shared [`LogBuffer`].
+///
+/// The message of each event is followed by its other fields, formatted as `name=value`. The
+/// captured records can then be shown with a [`LogView`](super::LogView).
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_widgets::log_view::{LogBuffer, LogLayer};
+/// use tracing_subscriber::prelude::*;
+///
+/// let buffer = LogBuffer::shared(1000);
+/// tracing_subscriber::registry()
+///     .with(LogLayer::new(buffer.clone()))
+///     .init();
+/// tracing::info!(port = 8080, "listening");
+/// // render `LogView::new(&buffer.lock().unwrap())` in the UI
+/// ```
+///
+/// [`tracing_subscriber`]: https://docs.rs/tracing-subscriber
+#[derive(Debug, Clone)]
+pub struct LogLayer {
+    buffer: Arc<Mutex<LogBuffer>>,
+}
+
+impl LogLayer {
+    /// Creates a new layer that captures events into the buffer.
+    ///
+    /// Use the filters of [`tracing_subscriber`] to choose which events are captured.
+    ///
+    /// [`tracing_subscriber`]: https://docs.rs/tracing-subscriber
+    pub const fn new(buffer: Arc<Mutex<LogBuffer>>) -> Self {
+        Self { buffer }
+    }
+}
+
+impl From<&tracing::Level> for LogLevel {
+    fn from(level: &tracing::Level) -> Self {
+        match *level {
+            tracing::Level::ERROR => Self::Error,
+            tracing::Level::WARN => Self::Warn,
+            tracing::Level::INFO => Self::Info,
+            tracing::Level::DEBUG => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        let record = LogRecord::new(metadata.level().into(), metadata.target(), visitor.message);
+        // a poisoned buffer only means that a thread panicked while pushing a record
+        let mut buffer = self
+            .buffer
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        buffer.push(record);
+    }
+}
+
+/// Formats the fields of an event into a message.
+#[derive(Debug, Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let message = core::mem::take(&mut self.message);
+            let _ = write!(self.message, "{value:?}");
+            if !message.is_empty() {
+                self.message.push(' ');
+                self.message.push_str(&message);
+            }
+        } else {
+            if !self.message.is_empty() {
+                self.message.push(' ');
+            }
+            let _ = write!(self.message, "{}={value:?}", field.name());
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.record_debug(field, &format_args!("{value}"));
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use tracing_subscriber::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn captures_events() {
+        let buffer = LogBuffer::shared(10);
+        let subscriber = tracing_subscriber::registry().with(LogLayer::new(buffer.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(target: "app", port = 8080, "listening on {}", "localhost");
+            tracing::debug!(target: "app::net", name = "x");
+        });
+        let buffer = buffer.lock().unwrap();
+        assert_eq!(
+            buffer.iter().collect::<Vec<_>>(),
+            [
+                &LogRecord::new(LogLevel::Warn, "app", "listening on localhost port=8080"),
+                &LogRecord::new(LogLevel::Debug, "app::net", "name=\"x\""),
+            ]
+        );
+    }
+}
@@ -7,8 +7,9 @@ use ratatui_core::widgets::Widget;
 
 /// A widget that renders the Ratatui logo
 ///
-/// The Ratatui logo takes up two lines of text and comes in two sizes: `Tiny` and `Small`. This may
-/// be used in an application's help or about screen to show that it is powered by Ratatui.
+/// The Ratatui logo comes in four sizes: `Tiny` and `Small` take up two lines of text, while
+/// `Medium` and `Large` take up three and four lines, e.g. for splash screens. This may be used in
+/// an application's help or about screen to show that it is powered by Ratatui.
 ///
 /// # Examples
 ///
@@ -55,6 +56,43 @@ use ratatui_core::widgets::Widget;
 /// █▀▀▄ ▄▀▀▄▝▜▛▘▄▀▀▄▝▜▛▘█  █ █
 /// █▀▀▄ █▀▀█ ▐▌ █▀▀█ ▐▌ ▀▄▄▀ █
 /// ```
+///
+/// ## Medium (3x29 characters)
+///
+/// ```
+/// use ratatui::widgets::RatatuiLogo;
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// frame.render_widget(RatatuiLogo::medium(), frame.area());
+/// # }
+/// ```
+///
+/// Renders:
+///
+/// ```text
+/// █▀▀▄ ▄▀▀▄ ▀█▀ ▄▀▀▄ ▀█▀ █  █ █
+/// █▄▄▀ █▄▄█  █  █▄▄█  █  █  █ █
+/// █ ▀▄ █  █  █  █  █  █  ▀▄▄▀ █
+/// ```
+///
+/// ## Large (4x41 characters)
+///
+/// ```
+/// use ratatui::widgets::RatatuiLogo;
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// frame.render_widget(RatatuiLogo::large(), frame.area());
+/// # }
+/// ```
+///
+/// Renders:
+///
+/// ```text
+/// █▀▀▀█▄ ▄█▀▀█▄ ▀▀█▀▀ ▄█▀▀█▄ ▀▀█▀▀ █    █ █
+/// █▄▄▄█▀ █▄▄▄▄█   █   █▄▄▄▄█   █   █    █ █
+/// █  ▀█▄ █    █   █   █    █   █   █    █ █
+/// ▀    ▀ ▀    ▀   ▀   ▀    ▀   ▀    ▀▀▀▀  ▀
+/// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct RatatuiLogo {
     size: Size,
@@ -83,6 +121,27 @@ pub enum Size {
     /// █▀▀▄ █▀▀█ ▐▌ █▀▀█ ▐▌ ▀▄▄▀ █
     /// ```
     Small,
+    /// A medium logo
+    ///
+    /// A logo that is three lines tall (3x29 characters)
+    ///
+    /// ```text
+    /// █▀▀▄ ▄▀▀▄ ▀█▀ ▄▀▀▄ ▀█▀ █  █ █
+    /// █▄▄▀ █▄▄█  █  █▄▄█  █  █  █ █
+    /// █ ▀▄ █  █  █  █  █  █  ▀▄▄▀ █
+    /// ```
+    Medium,
+    /// A large logo
+    ///
+    /// A logo that is four lines tall (4x41 characters)
+    ///
+    /// ```text
+    /// █▀▀▀█▄ ▄█▀▀█▄ ▀▀█▀▀ ▄█▀▀█▄ ▀▀█▀▀ █    █ █
+    /// █▄▄▄█▀ █▄▄▄▄█   █   █▄▄▄▄█   █   █    █ █
+    /// █  ▀█▄ █    █   █   █    █   █   █    █ █
+    /// ▀    ▀ ▀    ▀   ▀   ▀    ▀   ▀    ▀▀▀▀  ▀
+    /// ```
+    Large,
 }
 
 impl RatatuiLogo {
@@ -139,6 +198,32 @@ impl RatatuiLogo {
     pub const fn small() -> Self {
         Self::new(Size::Small)
     }
+
+    /// Create a new Ratatui logo widget with a medium size
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::RatatuiLogo;
+    ///
+    /// let logo = RatatuiLogo::medium();
+    /// ```
+    pub const fn medium() -> Self {
+        Self::new(Size::Medium)
+    }
+
+    /// Create a new Ratatui logo widget with a large size
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::RatatuiLogo;
+    ///
+    /// let logo = RatatuiLogo::large();
+    /// ```
+    pub const fn large() -> Self {
+        Self::new(Size::Large)
+    }
 }
 
 impl Widget for RatatuiLogo {
@@ -153,6 +238,8 @@ impl Size {
         match self {
             Self::Tiny => Self::tiny(),
             Self::Small => Self::small(),
+            Self::Medium => Self::medium(),
+            Self::Large => Self::large(),
         }
     }
 
@@ -169,6 +256,23 @@ impl Size {
             █▀▀▄ █▀▀█ ▐▌ █▀▀█ ▐▌ ▀▄▄▀ █
         "}
     }
+
+    const fn medium() -> &'static str {
+        indoc! {"
+            █▀▀▄ ▄▀▀▄ ▀█▀ ▄▀▀▄ ▀█▀ █  █ █
+            █▄▄▀ █▄▄█  █  █▄▄█  █  █  █ █
+            █ ▀▄ █  █  █  █  █  █  ▀▄▄▀ █
+        "}
+    }
+
+    const fn large() -> &'static str {
+        indoc! {"
+            █▀▀▀█▄ ▄█▀▀█▄ ▀▀█▀▀ ▄█▀▀█▄ ▀▀█▀▀ █    █ █
+            █▄▄▄█▀ █▄▄▄▄█   █   █▄▄▄▄█   █   █    █ █
+            █  ▀█▄ █    █   █   █    █   █   █    █ █
+            ▀    ▀ ▀    ▀   ▀   ▀    ▀   ▀    ▀▀▀▀  ▀
+        "}
+    }
 }
 
 #[cfg(test)]
@@ -180,6 +284,8 @@ mod tests {
     #[rstest]
     #[case::tiny(Size::Tiny)]
     #[case::small(Size::Small)]
+    #[case::medium(Size::Medium)]
+    #[case::large(Size::Large)]
     fn new_size(#[case] size: Size) {
         let logo = RatatuiLogo::new(size);
         assert_eq!(logo.size, size);
@@ -237,9 +343,42 @@ mod tests {
         );
     }
 
+    #[test]
+    #[rustfmt::skip]
+    fn render_medium() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 29, 3));
+        RatatuiLogo::medium().render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "█▀▀▄ ▄▀▀▄ ▀█▀ ▄▀▀▄ ▀█▀ █  █ █",
+                "█▄▄▀ █▄▄█  █  █▄▄█  █  █  █ █",
+                "█ ▀▄ █  █  █  █  █  █  ▀▄▄▀ █",
+            ])
+        );
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn render_large() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 41, 4));
+        RatatuiLogo::large().render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "█▀▀▀█▄ ▄█▀▀█▄ ▀▀█▀▀ ▄█▀▀█▄ ▀▀█▀▀ █    █ █",
+                "█▄▄▄█▀ █▄▄▄▄█   █   █▄▄▄▄█   █   █    █ █",
+                "█  ▀█▄ █    █   █   █    █   █   █    █ █",
+                "▀    ▀ ▀    ▀   ▀   ▀    ▀   ▀    ▀▀▀▀  ▀",
+            ])
+        );
+    }
+
     #[rstest]
     #[case::tiny(Size::Tiny, Buffer::with_lines(["▛"]))]
     #[case::small(Size::Small, Buffer::with_lines(["█"]))]
+    #[case::medium(Size::Medium, Buffer::with_lines(["█"]))]
+    #[case::large(Size::Large, Buffer::with_lines(["█"]))]
     fn render_in_minimal_buffer(#[case] size: Size, #[case] expected: Buffer) {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
         let logo = RatatuiLogo::new(size);
@@ -251,6 +390,8 @@ mod tests {
     #[rstest]
     #[case::tiny(Size::Tiny)]
     #[case::small(Size::Small)]
+    #[case::medium(Size::Medium)]
+    #[case::large(Size::Large)]
     fn render_in_zero_size_buffer(#[case] size: Size) {
         let mut buffer = Buffer::empty(Rect::ZERO);
         let logo = RatatuiLogo::new(size);
@@ -0,0 +1,644 @@
+//! The [`Markdown`] widget renders Markdown as styled text.
+//!
+//! It supports a useful subset of [CommonMark] for help screens and README viewers: headings,
+//! emphasis, strong emphasis, strikethrough, inline code, links, ordered and unordered lists
+//! (including task lists), code blocks, block quotes and horizontal rules. Other elements, such as
+//! HTML and images, are ignored. The styles of the elements are configured with
+//! [`MarkdownStyles`].
+//!
+//! The Markdown is parsed with [`pulldown-cmark`], so this module is only available when the
+//! `markdown` feature is enabled.
+//!
+//! [CommonMark]: https://commonmark.org
+//! [`pulldown-cmark`]: https://crates.io/crates/pulldown-cmark
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use pulldown_cmark::{CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Modifier, Style, Styled};
+use ratatui_core::text::{Line, Span, Text};
+use ratatui_core::widgets::Widget;
+
+use crate::block::{Block, BlockExt};
+use crate::paragraph::{Paragraph, Wrap};
+
+/// The styles of the elements of a [`Markdown`] widget.
+///
+/// The styles of inline elements, such as [`emphasis`](Self::emphasis), are patched onto the style
+/// of the block that contains them, such as a heading or a block quote.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::{Color, Style, Stylize};
+/// use ratatui_widgets::markdown::{Markdown, MarkdownStyles};
+///
+/// let styles = MarkdownStyles {
+///     code: Style::new().green(),
+///     link: Style::new().light_blue().underlined(),
+///     ..MarkdownStyles::default()
+/// };
+/// let markdown =
+///     Markdown::new("Run `cargo test` as described in the [guide](guide.md)").styles(styles);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MarkdownStyles {
+    /// The styles of the headings, from level 1 to level 6
+    pub headings: [Style; 6],
+    /// The style of emphasized text (`*text*`)
+    pub emphasis: Style,
+    /// The style of strongly emphasized text (`**text**`)
+    pub strong: Style,
+    /// The style of struck through text (`~~text~~`)
+    pub strikethrough: Style,
+    /// The style of inline code (`` `code` ``)
+    pub code: Style,
+    /// The style of code blocks
+    pub code_block: Style,
+    /// The style of block quotes, including the `│` marker in front of each line
+    pub block_quote: Style,
+    /// The style of the bullets and numbers of list items
+    pub list_marker: Style,
+    /// The style of the text of links
+    pub link: Style,
+    /// The style of horizontal rules
+    pub rule: Style,
+}
+
+impl Default for MarkdownStyles {
+    fn default() -> Self {
+        let heading = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+        Self {
+            headings: [
+                heading.add_modifier(Modifier::UNDERLINED),
+                heading,
+                heading,
+                heading.remove_modifier(Modifier::BOLD),
+                heading.remove_modifier(Modifier::BOLD),
+                heading.remove_modifier(Modifier::BOLD),
+            ],
+            emphasis: Style::new().add_modifier(Modifier::ITALIC),
+            strong: Style::new().add_modifier(Modifier::BOLD),
+            strikethrough: Style::new().add_modifier(Modifier::CROSSED_OUT),
+            code: Style::new().fg(Color::Yellow),
+            code_block: Style::new().fg(Color::Yellow),
+            block_quote: Style::new().fg(Color::Gray).add_modifier(Modifier::ITALIC),
+            list_marker: Style::new().fg(Color::Blue),
+            link: Style::new()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::UNDERLINED),
+            rule: Style::new().fg(Color::DarkGray),
+        }
+    }
+}
+
+/// A widget that renders Markdown as styled text.
+///
+/// The Markdown is converted into a [`Text`] with [`Markdown::to_text`] and rendered like a
+/// [`Paragraph`] that wraps long lines. Headings are shown with their `#` markers, list items
+/// with a bullet or their number, block quotes with a `│` marker, and blocks are separated by
+/// empty lines. Horizontal rules span the width of the widget.
+///
+/// See the [module documentation](crate::markdown) for the supported elements.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::Block;
+/// use ratatui_widgets::markdown::Markdown;
+///
+/// let help = Markdown::new(
+///     "# Help
+///
+/// Press **q** to quit.
+///
+/// - `j` / `k`: move down / up
+/// - `?`: toggle this help",
+/// )
+/// .block(Block::bordered().title("Help"))
+/// .scroll((0, 0));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Markdown<'a> {
+    source: &'a str,
+    styles: MarkdownStyles,
+    block: Option<Block<'a>>,
+    style: Style,
+    scroll: (u16, u16),
+}
+
+impl<'a> Markdown<'a> {
+    /// The width of horizontal rules when the text is not rendered in an area.
+    const RULE_WIDTH: usize = 3;
+
+    /// Creates a new `Markdown` widget from Markdown source.
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the styles of the Markdown elements.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn styles(mut self, styles: MarkdownStyles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Surrounds the widget with a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the scroll offset as a `(y, x)` tuple, like [`Paragraph::scroll`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scroll(mut self, offset: (u16, u16)) -> Self {
+        self.scroll = offset;
+        self
+    }
+
+    /// Converts the Markdown into styled [`Text`].
+    ///
+    /// This is useful to combine the Markdown with other text, or to render it with another
+    /// widget. Horizontal rules are three characters wide.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use ratatui::text::{Line, Text};
+    /// use ratatui_widgets::markdown::Markdown;
+    ///
+    /// let text = Markdown::new("Some *emphasis*").to_text();
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(Line::from(vec!["Some ".into(), "emphasis".italic()]))
+    /// );
+    /// ```
+    pub fn to_text(&self) -> Text<'a> {
+        self.convert(Self::RULE_WIDTH)
+    }
+
+    fn convert(&self, rule_width: usize) -> Text<'a> {
+        let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        let mut writer = TextWriter::new(&self.styles, rule_width);
+        for event in Parser::new_ext(self.source, options) {
+            writer.event(event);
+        }
+        writer.finish()
+    }
+}
+
+impl Widget for Markdown<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Markdown<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        Paragraph::new(self.convert(usize::from(area.width)))
+            .wrap(Wrap { trim: false })
+            .scroll(self.scroll)
+            .render(area, buf);
+    }
+}
+
+impl Styled for Markdown<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// Converts the events of the Markdown parser into lines of styled spans.
+struct TextWriter<'a, 's> {
+    styles: &'s MarkdownStyles,
+    rule_width: usize,
+    lines: Vec<Line<'a>>,
+    /// The line being written, if any.
+    line: Option<Line<'a>>,
+    /// Whether the line being written has content other than its prefix.
+    line_has_content: bool,
+    /// The styles of the inline elements that contain the current text.
+    inline_styles: Vec<Style>,
+    /// The next number of each nested list, or `None` for unordered lists.
+    lists: Vec<Option<u64>>,
+    quote_depth: usize,
+    in_code_block: bool,
+    /// Whether an empty line must be written before the next block.
+    needs_blank_line: bool,
+}
+
+impl<'a, 's> TextWriter<'a, 's> {
+    const fn new(styles: &'s MarkdownStyles, rule_width: usize) -> Self {
+        Self {
+            styles,
+            rule_width,
+            lines: Vec::new(),
+            line: None,
+            line_has_content: false,
+            inline_styles: Vec::new(),
+            lists: Vec::new(),
+            quote_depth: 0,
+            in_code_block: false,
+            needs_blank_line: false,
+        }
+    }
+
+    fn finish(mut self) -> Text<'a> {
+        self.end_line();
+        Text::from(self.lines)
+    }
+
+    fn event(&mut self, event: Event<'a>) {
+        match event {
+            Event::Start(tag) => self.start_tag(&tag),
+            Event::End(tag) => self.end_tag(tag),
+            Event::Text(text) if self.in_code_block => self.code_block_text(&text),
+            Event::Text(text) => self.push_span(text, self.style()),
+            Event::Code(code) => self.push_span(code, self.style().patch(self.styles.code)),
+            Event::SoftBreak => self.push_span(CowStr::Borrowed(" "), self.style()),
+            Event::HardBreak => {
+                self.end_line();
+                self.start_line(None);
+            }
+            Event::Rule => {
+                self.start_block();
+                let rule = "─".repeat(self.rule_width);
+                self.push_span(CowStr::from(rule), self.styles.rule);
+                self.end_block();
+            }
+            Event::TaskListMarker(checked) => {
+                let marker = if checked { "[x] " } else { "[ ] " };
+                self.push_span(CowStr::Borrowed(marker), self.styles.list_marker);
+            }
+            _ => {}
+        }
+    }
+
+    fn start_tag(&mut self, tag: &Tag<'a>) {
+        match *tag {
+            Tag::Paragraph => self.start_block(),
+            Tag::Heading { level, .. } => {
+                self.start_block();
+                let style = self.styles.headings[level as usize - 1];
+                self.inline_styles.push(style);
+                let marker = format!("{} ", "#".repeat(heading_level(level)));
+                self.push_span(CowStr::from(marker), style);
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(_) => {
+                self.start_block();
+                self.in_code_block = true;
+            }
+            Tag::List(start) => {
+                if self.lists.is_empty() {
+                    self.start_block();
+                } else {
+                    self.end_line();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.end_line();
+                self.blank_line();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => String::from("• "),
+                };
+                self.start_line(Some(marker));
+            }
+            Tag::Emphasis => self.push_inline_style(self.styles.emphasis),
+            Tag::Strong => self.push_inline_style(self.styles.strong),
+            Tag::Strikethrough => self.push_inline_style(self.styles.strikethrough),
+            Tag::Link { .. } => self.push_inline_style(self.styles.link),
+            _ => {}
+        }
+    }
+
+    fn end_tag(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.end_block(),
+            TagEnd::Heading(_) => {
+                self.inline_styles.pop();
+                self.end_block();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.end_line();
+                self.quote_depth -= 1;
+                self.needs_blank_line = true;
+            }
+            TagEnd::CodeBlock => {
+                self.in_code_block = false;
+                self.end_block();
+            }
+            TagEnd::List(_) => {
+                self.end_line();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.needs_blank_line = true;
+                }
+            }
+            TagEnd::Item => self.end_line(),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Link => {
+                self.inline_styles.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /// The style of the current text, combining the block quote and inline styles.
+    fn style(&self) -> Style {
+        let style = if self.quote_depth > 0 {
+            self.styles.block_quote
+        } else {
+            Style::new()
+        };
+        self.inline_styles
+            .iter()
+            .fold(style, |style, inline| style.patch(*inline))
+    }
+
+    fn push_inline_style(&mut self, style: Style) {
+        let style = self.style().patch(style);
+        self.inline_styles.push(style);
+    }
+
+    fn code_block_text(&mut self, text: &CowStr<'a>) {
+        let style = self.style().patch(self.styles.code_block);
+        for line in text.lines() {
+            self.start_line(None);
+            self.push_span(CowStr::from(String::from(line)), style);
+            self.end_line();
+        }
+    }
+
+    fn push_span(&mut self, content: CowStr<'a>, style: Style) {
+        if self.line.is_none() {
+            self.start_line(None);
+        }
+        let content = match content {
+            CowStr::Borrowed(content) => Cow::Borrowed(content),
+            content => Cow::Owned(content.into_string()),
+        };
+        if let Some(line) = &mut self.line {
+            line.push_span(Span::styled(content, style));
+        }
+        self.line_has_content = true;
+    }
+
+    /// Starts a new line with the block quote markers and the indentation of the current list.
+    ///
+    /// The first line of a list item has a `marker` in place of the last level of indentation.
+    fn start_line(&mut self, marker: Option<String>) {
+        let mut line = Line::default();
+        for _ in 0..self.quote_depth {
+            line.push_span(Span::styled("│ ", self.styles.block_quote));
+        }
+        let depth = self.lists.len();
+        match marker {
+            Some(marker) => {
+                let indent = "  ".repeat(depth.saturating_sub(1));
+                line.push_span(Span::raw(indent));
+                line.push_span(Span::styled(marker, self.styles.list_marker));
+            }
+            None if depth > 0 => line.push_span(Span::raw("  ".repeat(depth))),
+            None => {}
+        }
+        self.line = Some(line);
+        self.line_has_content = false;
+    }
+
+    fn end_line(&mut self) {
+        if let Some(line) = self.line.take() {
+            self.lines.push(line);
+        }
+    }
+
+    /// Ends the current line and separates the next block from the previous one.
+    ///
+    /// A block that starts on the first line of a list item continues that line.
+    fn start_block(&mut self) {
+        if self.line.is_some() && !self.line_has_content {
+            return;
+        }
+        self.end_line();
+        self.blank_line();
+    }
+
+    /// Writes an empty line, with only the block quote markers, if one is needed.
+    fn blank_line(&mut self) {
+        if self.needs_blank_line {
+            let marker = Span::styled("│ ", self.styles.block_quote);
+            let markers = core::iter::repeat_n(marker, self.quote_depth);
+            self.lines.push(markers.collect());
+        }
+        self.needs_blank_line = false;
+    }
+
+    fn end_block(&mut self) {
+        self.end_line();
+        self.needs_blank_line = true;
+    }
+}
+
+const fn heading_level(level: HeadingLevel) -> usize {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use indoc::indoc;
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    fn lines(markdown: &str) -> Vec<String> {
+        Markdown::new(markdown)
+            .to_text()
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn blocks() {
+        let markdown = indoc! {"
+            # Title
+
+            Some text
+            on two lines
+
+            > quoted
+            > text
+
+            ```rust
+            fn main() {}
+            ```
+
+            ---
+        "};
+        assert_eq!(
+            lines(markdown),
+            [
+                "# Title",
+                "",
+                "Some text on two lines",
+                "",
+                "│ quoted text",
+                "",
+                "fn main() {}",
+                "",
+                "───",
+            ]
+        );
+    }
+
+    #[test]
+    fn lists() {
+        let markdown = indoc! {"
+            - one
+            - two
+              - nested
+            - [x] done
+
+            3. three
+            4. four
+        "};
+        assert_eq!(
+            lines(markdown),
+            [
+                "• one",
+                "• two",
+                "  • nested",
+                "• [x] done",
+                "",
+                "3. three",
+                "4. four",
+            ]
+        );
+    }
+
+    #[test]
+    fn loose_list() {
+        let markdown = indoc! {"
+            - one
+
+              continued
+            - two
+        "};
+        assert_eq!(lines(markdown), ["• one", "", "  continued", "", "• two"]);
+    }
+
+    #[test]
+    fn inline_styles() {
+        let text = Markdown::new("*a **b** ~~c~~* `d` [e](f)").to_text();
+        let expected = Line::from(vec![
+            "a ".italic(),
+            "b".italic().bold(),
+            " ".italic(),
+            "c".italic().crossed_out(),
+            " ".into(),
+            "d".yellow(),
+            " ".into(),
+            "e".blue().underlined(),
+        ]);
+        assert_eq!(text, Text::from(expected));
+    }
+
+    #[test]
+    fn heading_and_quote_styles() {
+        let styles = MarkdownStyles::default();
+        let text = Markdown::new("## *Title*\n\n> quote").to_text();
+        let heading = styles.headings[1];
+        let quote = styles.block_quote;
+        let expected = Text::from(vec![
+            Line::from(vec![
+                Span::styled("## ", heading),
+                Span::styled("Title", heading.patch(styles.emphasis)),
+            ]),
+            Line::default(),
+            Line::from(vec![
+                Span::styled("│ ", quote),
+                Span::styled("quote", quote),
+            ]),
+        ]);
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn render() {
+        let markdown = Markdown::new("# Hi\n\n---\n\nwrapped text")
+            .block(Block::bordered())
+            .styles(MarkdownStyles {
+                headings: [Style::new(); 6],
+                rule: Style::new(),
+                ..MarkdownStyles::default()
+            });
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 7));
+        markdown.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "┌───────┐",
+                "│# Hi   │",
+                "│       │",
+                "│───────│",
+                "│       │",
+                "│wrapped│",
+                "└───────┘",
+            ])
+        );
+    }
+}
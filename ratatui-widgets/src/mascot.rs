@@ -1,6 +1,7 @@
 //! A Ratatui mascot widget
 //!
-//! The mascot takes 32x16 cells and is rendered using half block characters.
+//! The mascot takes 32x16 cells ([`MascotSize::Regular`]) or 16x8 cells ([`MascotSize::Small`])
+//! and is rendered using half block characters.
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
@@ -42,6 +43,29 @@ const RATATUI_MASCOT: &str = indoc::indoc! {"
       ▒░░░░░░░░░░░░░░░░░░░░░▒ █"
 };
 
+/// [`RATATUI_MASCOT`] at half the width and height, as computed by `downsample`.
+///
+/// The lines keep the trailing spaces of `downsample`, as each pair of lines is only drawn up to
+/// the width of its first line.
+const RATATUI_MASCOT_SMALL: [&str; 16] = [
+    "       hh ",
+    "      hhhh",
+    "     hhhhh",
+    "    hhhhhh ",
+    "    hhhhhh  ████",
+    "       hh█ee████",
+    "      █████████ ",
+    "     █████████",
+    "      ███████ ",
+    "     ░  █████",
+    "    ░░░▒█████",
+    "   ░░░░░▒█████",
+    "  ░░░░░░░▒█████",
+    " ░░░░░░░░░▒█ ██",
+    "░░░░░░░░░░░▒ ██",
+    " ░░░░░░░░░░░▒█",
+];
+
 const EMPTY: char = ' ';
 const RAT: char = '█';
 const HAT: char = 'h';
@@ -61,10 +85,47 @@ pub enum MascotEyeColor {
     Red,
 }
 
+/// The size of the mascot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum MascotSize {
+    /// The default size of the mascot (32x16 cells)
+    #[default]
+    Regular,
+
+    /// A smaller version of the mascot, at half the width and height (16x8 cells)
+    Small,
+}
+
 /// A widget that renders the Ratatui mascot
+///
+/// The mascot can blink, e.g. on a splash screen: pass an increasing tick to
+/// [`RatatuiMascot::tick`] each time the mascot is rendered, such as the frame count or the time
+/// in tenths of a second. The eye is closed for the last eighth of every
+/// [blink period](RatatuiMascot::blink_period).
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{MascotSize, RatatuiMascot};
+///
+/// # fn draw(frame: &mut ratatui::Frame) {
+/// let tick = frame.timestamp().as_millis() / 100;
+/// let mascot = RatatuiMascot::new()
+///     .size(MascotSize::Small)
+///     .tick(tick as usize);
+/// frame.render_widget(mascot, frame.area());
+/// # }
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RatatuiMascot {
     eye_state: MascotEyeColor,
+    /// The size of the mascot
+    size: MascotSize,
+    /// The current step of the animation
+    tick: usize,
+    /// The number of ticks between the starts of two blinks, or zero to never blink
+    blink_period: usize,
     /// The color of the rat
     rat_color: Color,
     /// The color of the rat's eye
@@ -92,11 +153,17 @@ impl Default for RatatuiMascot {
             term_border_color: Color::Indexed(237), // gray  #808080
             term_cursor_color: Color::Indexed(248), // dark_gray #a8a8a8
             eye_state: MascotEyeColor::Default,
+            size: MascotSize::Regular,
+            tick: 0,
+            blink_period: Self::DEFAULT_BLINK_PERIOD,
         }
     }
 }
 
 impl RatatuiMascot {
+    /// The default number of ticks between two blinks of the eye.
+    pub const DEFAULT_BLINK_PERIOD: usize = 40;
+
     /// Create a new Ratatui mascot widget
     pub fn new() -> Self {
         Self {
@@ -113,10 +180,51 @@ impl RatatuiMascot {
         }
     }
 
+    /// Set the size of the mascot
+    #[must_use]
+    pub const fn size(self, size: MascotSize) -> Self {
+        Self { size, ..self }
+    }
+
+    /// Set the current step of the animation
+    ///
+    /// The tick should increase each time the mascot is rendered, e.g. the frame count or the
+    /// time in tenths of a second. The mascot doesn't blink while the tick stays at zero.
+    #[must_use]
+    pub const fn tick(self, tick: usize) -> Self {
+        Self { tick, ..self }
+    }
+
+    /// Set the number of ticks between two blinks of the eye
+    ///
+    /// This is [`RatatuiMascot::DEFAULT_BLINK_PERIOD`] by default. A period of zero disables
+    /// blinking.
+    #[must_use]
+    pub const fn blink_period(self, blink_period: usize) -> Self {
+        Self {
+            blink_period,
+            ..self
+        }
+    }
+
+    /// Returns true if the eye is closed at the current tick
+    const fn is_blinking(&self) -> bool {
+        if self.blink_period == 0 {
+            return false;
+        }
+        let duration = if self.blink_period >= 8 {
+            self.blink_period / 8
+        } else {
+            1
+        };
+        self.tick % self.blink_period >= self.blink_period - duration
+    }
+
     const fn color_for(&self, c: char) -> Option<Color> {
         match c {
             RAT => Some(self.rat_color),
             HAT => Some(self.hat_color),
+            EYE if self.is_blinking() => Some(self.rat_color),
             EYE => Some(match self.eye_state {
                 MascotEyeColor::Default => self.rat_eye_color,
                 MascotEyeColor::Red => self.rat_eye_blink,
@@ -127,20 +235,10 @@ impl RatatuiMascot {
             _ => None,
         }
     }
-}
-
-impl Widget for RatatuiMascot {
-    /// Use half block characters to render a logo based on the `RATATUI_LOGO` const.
-    ///
-    /// The logo colors are hardcorded in the widget.
-    /// The eye color depends on whether it's open / blinking
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let area = area.intersection(buf.area);
-        if area.is_empty() {
-            return;
-        }
 
-        for (y, (line1, line2)) in RATATUI_MASCOT.lines().tuples().enumerate() {
+    /// Renders each pair of lines of the art as a row of half block characters.
+    fn render_art<'a>(&self, lines: impl Iterator<Item = &'a str>, area: Rect, buf: &mut Buffer) {
+        for (y, (line1, line2)) in lines.tuples().enumerate() {
             for (x, (ch1, ch2)) in line1.chars().zip(line2.chars()).enumerate() {
                 let x = area.left() + x as u16;
                 let y = area.top() + y as u16;
@@ -183,12 +281,65 @@ impl Widget for RatatuiMascot {
     }
 }
 
+impl Widget for RatatuiMascot {
+    /// Use half block characters to render a logo based on the `RATATUI_LOGO` const.
+    ///
+    /// The logo colors are hardcorded in the widget.
+    /// The eye color depends on whether it's open / blinking
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        match self.size {
+            MascotSize::Regular => self.render_art(RATATUI_MASCOT.lines(), area, buf),
+            MascotSize::Small => self.render_art(RATATUI_MASCOT_SMALL.into_iter(), area, buf),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::String;
+    use alloc::vec::Vec;
 
     use super::*;
 
+    /// Halves the width and height of the art, using the most common non-empty character of each
+    /// 2x2 block of characters.
+    ///
+    /// The eye is kept wherever it appears, so that it doesn't get lost at the smaller size. A
+    /// block with less than two non-empty characters becomes empty.
+    fn downsample(art: &str) -> Vec<Vec<char>> {
+        let lines: Vec<Vec<char>> = art.lines().map(|line| line.chars().collect()).collect();
+        lines
+            .chunks(2)
+            .map(|rows| {
+                let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+                (0..width.div_ceil(2))
+                    .map(|x| {
+                        let block: Vec<char> = rows
+                            .iter()
+                            .flat_map(|row| row.iter().skip(x * 2).take(2))
+                            .copied()
+                            .filter(|&c| c != EMPTY)
+                            .collect();
+                        if block.contains(&EYE) {
+                            return EYE;
+                        }
+                        block
+                            .iter()
+                            .copied()
+                            .max_by_key(|&c| block.iter().filter(|&&d| d == c).count())
+                            .filter(|_| block.len() >= 2)
+                            .unwrap_or(EMPTY)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
     #[test]
     fn new_mascot() {
         let mascot = RatatuiMascot::new();
@@ -241,6 +392,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn small_mascot_is_downsampled() {
+        let downsampled: Vec<String> = downsample(RATATUI_MASCOT)
+            .iter()
+            .map(|line| line.iter().collect::<String>())
+            .collect();
+        assert_eq!(downsampled, RATATUI_MASCOT_SMALL);
+    }
+
+    #[test]
+    fn render_small_mascot() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 8));
+        RatatuiMascot::new()
+            .size(MascotSize::Small)
+            .render(buf.area, &mut buf);
+        let lines: Vec<String> = buf
+            .content
+            .chunks(16)
+            .map(|row| row.iter().map(ratatui_core::buffer::Cell::symbol).collect())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "      ▄██▄      ",
+                "    ▄█████      ",
+                "    ▀▀▀██▀▄▄████",
+                "     ▄████████  ",
+                "     ▀▀▀█████   ",
+                "   ▀   ▀▀████   ",
+                " ▀       ▀▀█▀██ ",
+                "▀          ▀▄█  ",
+            ]
+        );
+        // the eye is kept at the smaller size
+        assert!(
+            buf.content
+                .iter()
+                .any(|cell| cell.fg == Color::Indexed(236) || cell.bg == Color::Indexed(236))
+        );
+    }
+
+    #[test]
+    fn blink() {
+        let mascot = RatatuiMascot::new().blink_period(16);
+        let closed: Vec<_> = (0..32)
+            .filter(|&tick| mascot.tick(tick).is_blinking())
+            .collect();
+        assert_eq!(closed, [14, 15, 30, 31]);
+        assert!(!mascot.blink_period(0).tick(15).is_blinking());
+        assert!(RatatuiMascot::new().blink_period(3).tick(2).is_blinking());
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 32, 16));
+        mascot.tick(15).render(buf.area, &mut buf);
+        assert_eq!(buf[(21, 5)].bg, Color::Indexed(252));
+    }
+
     #[test]
     fn render_in_minimal_buffer() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
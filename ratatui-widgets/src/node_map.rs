@@ -0,0 +1,516 @@
+//! The [`NodeMap`] widget is used to display a graph of nodes connected by edges.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::{Color, Style, Styled};
+use ratatui_core::symbols::Marker;
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+use strum::{Display, EnumString};
+
+use crate::block::{Block, BlockExt};
+use crate::borders::BorderType;
+use crate::canvas::{self, Canvas};
+
+/// A widget to display a graph of nodes connected by edges, e.g. the dependencies of a build or
+/// the topology of a network.
+///
+/// Each [`Node`] is drawn as a box around its label, and each [`Edge`] as a straight line between
+/// the centers of two nodes, drawn with the points of a [`Canvas`] (Braille patterns by default,
+/// see [`NodeMap::marker`]). Edges are drawn below the nodes, so they start and end at the borders
+/// of the boxes.
+///
+/// The nodes are placed in layers by a simple layered layout: a node is placed one layer after
+/// the furthest node that has an edge to it, so that edges go from one layer to the next one (see
+/// [`NodeLayout`]). Nodes with an explicit [`Node::position`] are placed there instead. The nodes
+/// that don't fit in the area are clipped.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::Color;
+/// use ratatui::widgets::{Block, Edge, Node, NodeLayout, NodeMap};
+///
+/// let nodes = [
+///     Node::new("app"),
+///     Node::new("core"),
+///     Node::new("widgets"),
+///     Node::new("backend").position((30, 0)),
+/// ];
+/// let edges = [
+///     Edge::new(0, 1),
+///     Edge::new(0, 2),
+///     Edge::new(2, 1).color(Color::Yellow),
+///     Edge::new(0, 3),
+/// ];
+/// let node_map = NodeMap::new(nodes, edges)
+///     .block(Block::bordered().title("Dependencies"))
+///     .layout(NodeLayout::LeftRight);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NodeMap<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// The nodes, referenced by their index in the edges
+    nodes: Vec<Node<'a>>,
+    /// The edges between the nodes
+    edges: Vec<Edge>,
+    /// The direction of the layers
+    layout: NodeLayout,
+    /// The borders of the nodes
+    border_type: BorderType,
+    /// The points of the edges
+    marker: Marker,
+    /// The space between the layers
+    layer_spacing: u16,
+    /// The space between the nodes of a layer
+    node_spacing: u16,
+}
+
+/// A node of a [`NodeMap`], drawn as a box around its label.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Node<'a> {
+    label: Line<'a>,
+    position: Option<Position>,
+    style: Style,
+}
+
+/// An edge of a [`NodeMap`] between two nodes, given by their index in the nodes of the map.
+///
+/// Edges that refer to nodes that don't exist are ignored.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Edge {
+    from: usize,
+    to: usize,
+    color: Option<Color>,
+}
+
+/// The direction in which the layers of a [`NodeMap`] are placed.
+///
+/// See [`NodeMap::layout`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NodeLayout {
+    /// The layers are rows from top to bottom, and the nodes of a layer are placed from left to
+    /// right.
+    #[default]
+    TopDown,
+    /// The layers are columns from left to right, and the nodes of a layer are placed from top to
+    /// bottom.
+    LeftRight,
+}
+
+impl<'a> Node<'a> {
+    /// Creates a new `Node` with the given label.
+    pub fn new<T: Into<Line<'a>>>(label: T) -> Self {
+        Self {
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the position of the top left corner of the node, relative to the area of the map.
+    ///
+    /// `position` accepts any type that is convertible to [`Position`], such as a `(x, y)` tuple.
+    /// Nodes without a position are placed by the layout of the map.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn position<P: Into<Position>>(mut self, position: P) -> Self {
+        self.position = Some(position.into());
+        self
+    }
+
+    /// Sets the style of the node, including its borders.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the width and height of the box of the node.
+    fn size(&self) -> (u16, u16) {
+        let width = u16::try_from(self.label.width()).unwrap_or(u16::MAX);
+        (width.saturating_add(2), 3)
+    }
+}
+
+impl<'a, T: Into<Line<'a>>> From<T> for Node<'a> {
+    fn from(label: T) -> Self {
+        Self::new(label)
+    }
+}
+
+impl Edge {
+    /// Creates a new `Edge` from the node at index `from` to the node at index `to`.
+    pub const fn new(from: usize, to: usize) -> Self {
+        Self {
+            from,
+            to,
+            color: None,
+        }
+    }
+
+    /// Sets the color of the edge. Defaults to the foreground color of the map.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl From<(usize, usize)> for Edge {
+    fn from((from, to): (usize, usize)) -> Self {
+        Self::new(from, to)
+    }
+}
+
+impl Default for NodeMap<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            layout: NodeLayout::default(),
+            border_type: BorderType::default(),
+            marker: Marker::Braille,
+            layer_spacing: 2,
+            node_spacing: 2,
+        }
+    }
+}
+
+impl<'a> NodeMap<'a> {
+    /// Creates a new `NodeMap` with the given nodes and edges.
+    ///
+    /// `nodes` accepts any iterator of values that are convertible to [`Node`], such as labels,
+    /// and `edges` any iterator of values that are convertible to [`Edge`], such as `(from, to)`
+    /// tuples of node indexes.
+    pub fn new<N, E>(nodes: N, edges: E) -> Self
+    where
+        N: IntoIterator,
+        N::Item: Into<Node<'a>>,
+        E: IntoIterator,
+        E::Item: Into<Edge>,
+    {
+        Self {
+            nodes: nodes.into_iter().map(Into::into).collect(),
+            edges: edges.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Wraps the map with the given `block`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the entire widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The foreground color is the default color of the edges.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the direction in which the layers are placed. Defaults to [`NodeLayout::TopDown`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn layout(mut self, layout: NodeLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the borders of the nodes. Defaults to [`BorderType::Plain`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    /// Sets the points used to draw the edges. Defaults to [`Marker::Braille`].
+    ///
+    /// See [`Canvas::marker`] for the available markers.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Sets the space between the layers, in rows for [`NodeLayout::TopDown`] and in columns for
+    /// [`NodeLayout::LeftRight`]. Defaults to 2.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn layer_spacing(mut self, spacing: u16) -> Self {
+        self.layer_spacing = spacing;
+        self
+    }
+
+    /// Sets the space between the nodes of a layer. Defaults to 2.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn node_spacing(mut self, spacing: u16) -> Self {
+        self.node_spacing = spacing;
+        self
+    }
+
+    /// Returns the layer of each node, which is one more than the layer of the furthest node that
+    /// has an edge to it.
+    ///
+    /// The layers of nodes in a cycle stop growing after as many passes as there are nodes.
+    fn layers(&self) -> Vec<usize> {
+        let mut layers = vec![0; self.nodes.len()];
+        for _ in 0..self.nodes.len() {
+            let mut changed = false;
+            for edge in &self.edges {
+                let (Some(&from), Some(&to)) = (layers.get(edge.from), layers.get(edge.to)) else {
+                    continue;
+                };
+                if edge.from != edge.to && to <= from {
+                    layers[edge.to] = from + 1;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        layers
+    }
+
+    /// Returns the area of each node relative to the area of the map.
+    fn node_areas(&self, area: Rect) -> Vec<Rect> {
+        let layers = self.layers();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for (index, &layer) in layers.iter().enumerate() {
+            if groups.len() <= layer {
+                groups.resize_with(layer + 1, Vec::new);
+            }
+            groups[layer].push(index);
+        }
+        let mut areas = vec![Rect::ZERO; self.nodes.len()];
+        let mut layer_offset = 0u16;
+        for group in groups.iter().filter(|group| !group.is_empty()) {
+            let sizes = group.iter().map(|&index| self.nodes[index].size());
+            let (length, depth) = match self.layout {
+                NodeLayout::TopDown => sizes.fold((0u16, 0u16), |(length, depth), (w, h)| {
+                    (length.saturating_add(w), depth.max(h))
+                }),
+                NodeLayout::LeftRight => sizes.fold((0u16, 0u16), |(length, depth), (w, h)| {
+                    (length.saturating_add(h), depth.max(w))
+                }),
+            };
+            let gaps = u16::try_from(group.len() - 1).unwrap_or(u16::MAX);
+            let length = length.saturating_add(gaps.saturating_mul(self.node_spacing));
+            let available = match self.layout {
+                NodeLayout::TopDown => area.width,
+                NodeLayout::LeftRight => area.height,
+            };
+            let mut node_offset = available.saturating_sub(length) / 2;
+            for &index in group {
+                let (width, height) = self.nodes[index].size();
+                let (x, y, node_length) = match self.layout {
+                    NodeLayout::TopDown => (node_offset, layer_offset, width),
+                    NodeLayout::LeftRight => (layer_offset, node_offset, height),
+                };
+                areas[index] = Rect::new(x, y, width, height);
+                node_offset = node_offset
+                    .saturating_add(node_length)
+                    .saturating_add(self.node_spacing);
+            }
+            layer_offset = layer_offset
+                .saturating_add(depth)
+                .saturating_add(self.layer_spacing);
+        }
+        for (node, node_area) in self.nodes.iter().zip(&mut areas) {
+            if let Some(position) = node.position {
+                node_area.x = position.x;
+                node_area.y = position.y;
+            }
+        }
+        areas
+    }
+
+    /// Draws the edges between the centers of the nodes.
+    fn render_edges(&self, area: Rect, buf: &mut Buffer, node_areas: &[Rect]) {
+        let width = f64::from(area.width);
+        let height = f64::from(area.height);
+        // the center of a cell, in the coordinates of the canvas where y goes up
+        let center = |node_area: &Rect| {
+            let x = f64::from(node_area.x) + f64::from(node_area.width / 2) + 0.5;
+            let y = f64::from(node_area.y) + f64::from(node_area.height / 2) + 0.5;
+            (x, height - y)
+        };
+        let edge_color = self.style.fg.unwrap_or(Color::Reset);
+        let canvas = Canvas::default()
+            .x_bounds([0.0, width])
+            .y_bounds([0.0, height])
+            .marker(self.marker)
+            .background_color(self.style.bg.unwrap_or(Color::Reset))
+            .paint(|ctx| {
+                for edge in &self.edges {
+                    let (Some(from), Some(to)) =
+                        (node_areas.get(edge.from), node_areas.get(edge.to))
+                    else {
+                        continue;
+                    };
+                    let ((x1, y1), (x2, y2)) = (center(from), center(to));
+                    let color = edge.color.unwrap_or(edge_color);
+                    ctx.draw(&canvas::Line::new(x1, y1, x2, y2, color));
+                }
+            });
+        canvas.render(area, buf);
+    }
+
+    /// Draws the boxes of the nodes over the edges.
+    fn render_nodes(&self, area: Rect, buf: &mut Buffer, node_areas: &[Rect]) {
+        for (node, node_area) in self.nodes.iter().zip(node_areas) {
+            let node_area = Rect {
+                x: area.x.saturating_add(node_area.x),
+                y: area.y.saturating_add(node_area.y),
+                ..*node_area
+            }
+            .intersection(area);
+            if node_area.is_empty() {
+                continue;
+            }
+            // hide the edges below the node
+            for position in node_area.positions() {
+                buf[position].reset();
+            }
+            buf.set_style(node_area, self.style);
+            let block = Block::bordered()
+                .border_type(self.border_type)
+                .style(node.style);
+            let inner = block.inner(node_area);
+            block.render(node_area, buf);
+            (&node.label).render(inner, buf);
+        }
+    }
+}
+
+impl Styled for NodeMap<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for NodeMap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &NodeMap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+        let node_areas = self.node_areas(inner);
+        self.render_edges(inner, buf, &node_areas);
+        self.render_nodes(inner, buf, &node_areas);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layers() {
+        let node_map = NodeMap::new(
+            ["a", "b", "c", "d"],
+            [(0, 1), (1, 2), (0, 2), (3, 3), (0, 9)],
+        );
+        assert_eq!(node_map.layers(), [0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn layers_with_cycle() {
+        let node_map = NodeMap::new(["a", "b"], [(0, 1), (1, 0)]);
+        assert_eq!(node_map.layers().len(), 2);
+    }
+
+    #[test]
+    fn node_areas_top_down() {
+        let node_map = NodeMap::new(["a", "bb", "c"], [(0, 1), (0, 2)]);
+        assert_eq!(
+            node_map.node_areas(Rect::new(0, 0, 14, 8)),
+            [
+                Rect::new(5, 0, 3, 3),
+                Rect::new(2, 5, 4, 3),
+                Rect::new(8, 5, 3, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn node_areas_left_right_with_position() {
+        let node_map = NodeMap::new([Node::new("a"), Node::new("b").position((0, 4))], [(0, 1)])
+            .layout(NodeLayout::LeftRight);
+        assert_eq!(
+            node_map.node_areas(Rect::new(0, 0, 10, 7)),
+            [Rect::new(0, 2, 3, 3), Rect::new(0, 4, 3, 3)]
+        );
+    }
+
+    #[test]
+    fn render() {
+        let node_map = NodeMap::new(["a", "b"], [(0, 1)]).layer_spacing(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 7));
+        node_map.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(["┌─┐", "│a│", "└─┘", " ⢸ ", "┌─┐", "│b│", "└─┘"]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_left_right_with_block_marker() {
+        let node_map = NodeMap::new(["a", "b"], [Edge::new(0, 1).color(Color::Red)])
+            .layout(NodeLayout::LeftRight)
+            .marker(Marker::Block);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        node_map.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["┌─┐  ┌─┐", "│a│██│b│", "└─┘  └─┘"]);
+        expected.set_style(Rect::new(3, 1, 2, 1), Style::new().red().on_red());
+        assert_eq!(buf, expected);
+    }
+}
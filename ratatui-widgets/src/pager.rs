@@ -0,0 +1,399 @@
+//! The [`Pager`] widget splits a long text into pages that fit its area, like the `less` and `man`
+//! commands.
+use alloc::format;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Alignment, Rect};
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::{Line, Text};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+
+use crate::block::{Block, BlockExt};
+use crate::paragraph::{Paragraph, Wrap};
+
+/// State of a [`Pager`], which records the current page.
+///
+/// The number of pages depends on the size of the area the pager is rendered in, so it is only
+/// known after the pager is rendered. Rendering clamps the current page to the last page, so
+/// [`PagerState::last_page`] and [`PagerState::next_page`] can be called without knowing the number
+/// of pages.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::PagerState;
+///
+/// let mut state = PagerState::new();
+/// state.next_page();
+/// assert_eq!(state.page(), 1);
+/// state.previous_page();
+/// state.previous_page();
+/// assert_eq!(state.page(), 0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PagerState {
+    page: usize,
+    page_count: usize,
+}
+
+impl PagerState {
+    /// Creates a new state that shows the first page.
+    pub const fn new() -> Self {
+        Self {
+            page: 0,
+            page_count: 0,
+        }
+    }
+
+    /// Sets the index of the current page, starting at 0.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Returns the index of the current page, starting at 0.
+    pub const fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the number of pages when the pager was last rendered, or 0 if it was not rendered
+    /// yet.
+    pub const fn page_count(&self) -> usize {
+        self.page_count
+    }
+
+    /// Shows the page with the given index, starting at 0.
+    pub const fn select_page(&mut self, page: usize) {
+        self.page = page;
+    }
+
+    /// Shows the next page, if any.
+    pub const fn next_page(&mut self) {
+        if self.page_count == 0 || self.page + 1 < self.page_count {
+            self.page = self.page.saturating_add(1);
+        }
+    }
+
+    /// Shows the previous page, if any.
+    pub const fn previous_page(&mut self) {
+        self.page = self.page.saturating_sub(1);
+    }
+
+    /// Shows the first page.
+    pub const fn first_page(&mut self) {
+        self.page = 0;
+    }
+
+    /// Shows the last page.
+    pub const fn last_page(&mut self) {
+        self.page = usize::MAX;
+    }
+}
+
+/// A widget that splits a text into pages that fit its area, and shows one page at a time with a
+/// "Page X of N" indicator on the last line.
+///
+/// This is useful for help screens and man-page style viewers, where scrolling by whole pages is
+/// easier to follow than scrolling by lines. The pages are recomputed on every render, so resizing
+/// the area changes the number of pages. Lines that are wider than the area are truncated, unless
+/// wrapping is enabled with [`Pager::wrap`].
+///
+/// Rendered as a [`StatefulWidget`] with a [`PagerState`], the current page can be changed with
+/// [`PagerState::next_page`] and [`PagerState::previous_page`]. Rendered as a [`Widget`], it shows
+/// the first page.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Block, Pager, PagerState, StatefulWidget};
+/// # let mut buf = ratatui::buffer::Buffer::empty(Rect::new(0, 0, 40, 10));
+///
+/// let mut state = PagerState::new();
+/// state.next_page();
+/// Pager::new("a long help text\n...")
+///     .block(Block::bordered().title("Help"))
+///     .render(buf.area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Pager<'a> {
+    paragraph: Paragraph<'a>,
+    block: Option<Block<'a>>,
+    style: Style,
+    indicator_style: Style,
+    indicator_alignment: Alignment,
+    show_indicator: bool,
+}
+
+impl<'a> Pager<'a> {
+    /// Creates a new pager for the given text.
+    ///
+    /// `text` accepts any type that is convertible to [`Text`] (e.g. [`&str`], [`String`],
+    /// [`Line`], or a [`Text`]). Newlines in strings start new lines.
+    ///
+    /// [`String`]: alloc::string::String
+    pub fn new<T: Into<Text<'a>>>(text: T) -> Self {
+        Self {
+            paragraph: Paragraph::new(text),
+            block: None,
+            style: Style::new(),
+            indicator_style: Style::new(),
+            indicator_alignment: Alignment::Right,
+            show_indicator: true,
+        }
+    }
+
+    /// Wraps the pager in a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole pager.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Wraps lines that are wider than the area instead of truncating them.
+    ///
+    /// Wrapped lines count towards the height of the pages. See [`Paragraph::wrap`] for details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.paragraph = self.paragraph.wrap(wrap);
+        self
+    }
+
+    /// Sets the style of the page indicator.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn indicator_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.indicator_style = style.into();
+        self
+    }
+
+    /// Sets the alignment of the page indicator. Defaults to [`Alignment::Right`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn indicator_alignment(mut self, alignment: Alignment) -> Self {
+        self.indicator_alignment = alignment;
+        self
+    }
+
+    /// Sets whether the page indicator is shown. Defaults to `true`.
+    ///
+    /// Without the indicator, the pages use the whole height of the area.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_indicator(mut self, show_indicator: bool) -> Self {
+        self.show_indicator = show_indicator;
+        self
+    }
+
+    /// Returns the number of pages of the text when rendered in `area`.
+    ///
+    /// This accounts for the block and the page indicator. A text that fits a single page, and an
+    /// empty text, have one page.
+    pub fn page_count(&self, area: Rect) -> usize {
+        let (content, _) = self.layout(self.block.inner_if_some(area));
+        if content.is_empty() {
+            return 1;
+        }
+        let lines = self.paragraph.text_line_count(content.width);
+        lines.div_ceil(usize::from(content.height)).max(1)
+    }
+
+    /// Splits the area inside the block into the area of the pages and the row of the indicator.
+    const fn layout(&self, area: Rect) -> (Rect, Option<Rect>) {
+        if !self.show_indicator || area.is_empty() {
+            return (area, None);
+        }
+        let content = Rect {
+            height: area.height - 1,
+            ..area
+        };
+        let indicator = Rect {
+            y: area.bottom() - 1,
+            height: 1,
+            ..area
+        };
+        (content, Some(indicator))
+    }
+}
+
+impl Styled for Pager<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for Pager<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Pager<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = PagerState::new();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Pager<'_> {
+    type State = PagerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Pager<'_> {
+    type State = PagerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+
+        state.page_count = self.page_count(area);
+        state.page = state.page.min(state.page_count - 1);
+        let (content, indicator) = self.layout(inner);
+        if !content.is_empty() {
+            let first_line = state.page.saturating_mul(usize::from(content.height));
+            self.paragraph.render_text(content, buf, first_line);
+        }
+        if let Some(indicator) = indicator {
+            let text = format!("Page {} of {}", state.page + 1, state.page_count);
+            let line = Line::styled(text, self.indicator_style).alignment(self.indicator_alignment);
+            line.render(indicator, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use itertools::Itertools;
+    use ratatui_core::style::Color;
+
+    use super::*;
+
+    const TEXT: &str = "one\ntwo\nthree\nfour\nfive";
+
+    fn render(pager: &Pager, state: &mut PagerState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(pager, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn pages() {
+        let pager = Pager::new(TEXT);
+        let mut state = PagerState::new();
+        assert_eq!(
+            render(&pager, &mut state, 12, 3),
+            Buffer::with_lines(["one         ", "two         ", " Page 1 of 3"])
+        );
+        assert_eq!(state.page_count(), 3);
+
+        state.next_page();
+        state.next_page();
+        assert_eq!(
+            render(&pager, &mut state, 12, 3),
+            Buffer::with_lines(["five        ", "            ", " Page 3 of 3"])
+        );
+
+        // the state doesn't move past the last page once the number of pages is known
+        state.next_page();
+        assert_eq!(state.page(), 2);
+    }
+
+    #[test]
+    fn last_page_is_clamped() {
+        let pager = Pager::new(TEXT).show_indicator(false);
+        let mut state = PagerState::new();
+        state.last_page();
+        assert_eq!(
+            render(&pager, &mut state, 5, 2),
+            Buffer::with_lines(["five ", "     "])
+        );
+        assert_eq!(state.page(), 2);
+
+        // a bigger area has fewer pages
+        render(&pager, &mut state, 5, 5);
+        assert_eq!((state.page(), state.page_count()), (0, 1));
+    }
+
+    #[test]
+    fn pages_past_u16_max_lines() {
+        let text = (0..70_000).map(|n| n.to_string()).join("\n");
+        let pager = Pager::new(text).show_indicator(false);
+        let mut state = PagerState::new().with_page(34_999);
+        assert_eq!(
+            render(&pager, &mut state, 5, 2),
+            Buffer::with_lines(["69998", "69999"])
+        );
+        assert_eq!(state.page_count(), 35_000);
+    }
+
+    #[test]
+    fn page_count() {
+        let pager = Pager::new(TEXT);
+        assert_eq!(pager.page_count(Rect::new(0, 0, 10, 6)), 1);
+        assert_eq!(pager.page_count(Rect::new(0, 0, 10, 2)), 5);
+        assert_eq!(pager.page_count(Rect::new(0, 0, 10, 1)), 1);
+        assert_eq!(Pager::new("").page_count(Rect::new(0, 0, 10, 3)), 1);
+
+        let wrapped = Pager::new("aaa bbb ccc").wrap(Wrap { trim: true });
+        assert_eq!(wrapped.page_count(Rect::new(0, 0, 3, 2)), 3);
+        let bordered = Pager::new(TEXT).block(Block::bordered());
+        assert_eq!(bordered.page_count(Rect::new(0, 0, 10, 5)), 3);
+    }
+
+    #[test]
+    fn indicator_style_and_alignment() {
+        let pager = Pager::new("one")
+            .indicator_style(Color::Yellow)
+            .indicator_alignment(Alignment::Left);
+        let mut expected = Buffer::with_lines(["one         ", "Page 1 of 1 "]);
+        expected.set_style(Rect::new(0, 1, 12, 1), Color::Yellow);
+        assert_eq!(render(&pager, &mut PagerState::new(), 12, 2), expected);
+    }
+}
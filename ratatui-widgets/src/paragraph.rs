@@ -1,13 +1,19 @@
 //! The [`Paragraph`] widget and related types allows displaying a block of text with optional
 //! wrapping, alignment, and block styling.
+use alloc::sync::Arc;
+use core::panic::{RefUnwindSafe, UnwindSafe};
+
+use itertools::Itertools;
+use ratatui_core::accessibility::{Accessible, Role, SemanticNode, Semantics};
 use ratatui_core::buffer::{Buffer, CellWidth};
 use ratatui_core::layout::{Alignment, Position, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::text::{Line, StyledGrapheme, Text};
-use ratatui_core::widgets::Widget;
+use ratatui_core::widgets::{IntrinsicSize, Widget};
 
-use crate::block::{Block, BlockExt};
+use crate::block::{Block, BlockExt, describe_block};
 use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
+use crate::shared_fn::SharedFn;
 
 /// A widget to display some text.
 ///
@@ -86,6 +92,28 @@ pub struct Paragraph<'a> {
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// Style of each line, applied at render time by a decorator that is shared by the clones of
+    /// the paragraph, as it may capture data that is expensive or impossible to clone
+    line_style: Option<SharedFn<dyn LineDecorator + 'a>>,
+}
+
+/// Styles the lines of a [`Paragraph`] at render time.
+///
+/// This is implemented for closures that take the index of a line and return its style, see
+/// [`Paragraph::line_style`]. Like the widgets that store them, implementations must be [`Send`],
+/// [`Sync`], [`UnwindSafe`], and [`RefUnwindSafe`].
+pub trait LineDecorator: Send + Sync + UnwindSafe + RefUnwindSafe {
+    /// Returns the style of the line at the given index.
+    fn line_style(&self, index: usize) -> Style;
+}
+
+impl<F> LineDecorator for F
+where
+    F: Fn(usize) -> Style + Send + Sync + UnwindSafe + RefUnwindSafe,
+{
+    fn line_style(&self, index: usize) -> Style {
+        self(index)
+    }
 }
 
 /// Describes how to wrap text across lines.
@@ -159,6 +187,7 @@ impl<'a> Paragraph<'a> {
             text,
             scroll: Position::ORIGIN,
             alignment,
+            line_style: None,
         }
     }
 
@@ -201,6 +230,22 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Applies the styles of a [`Theme`] to the widget and its block.
+    ///
+    /// The [`Theme::TEXT`] style is used as the [`style`](Paragraph::style).
+    /// Styles that are not defined by the theme are left unchanged. The block is themed with
+    /// [`Block::theme`], so it must be set before calling this method.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        if let Some(style) = theme.get(Theme::TEXT) {
+            self.style = style;
+        }
+        self.block = self.block.map(|block| block.theme(theme));
+        self
+    }
+
     /// Sets the wrapping configuration for the widget.
     ///
     /// See [`Wrap`] for more information on the different options.
@@ -238,6 +283,41 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets a function that returns the style of each line, applied when the paragraph is rendered.
+    ///
+    /// The function receives the index of the line, counted from the first line of the text after
+    /// wrapping, so the style of a line doesn't change when the paragraph is scrolled. The style is
+    /// applied to the whole row under the line, and the styles of the text are added to it. This
+    /// allows zebra striping or highlighting the current line of a viewer without rebuilding the
+    /// [`Text`] for each frame.
+    ///
+    /// Any type that implements [`LineDecorator`] can be used instead of a closure.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let current_line = 3;
+    /// let paragraph = Paragraph::new("one\ntwo\nthree\nfour\nfive").line_style(move |index| {
+    ///     if index == current_line {
+    ///         Style::new().reversed()
+    ///     } else if index % 2 == 1 {
+    ///         Style::new().on_dark_gray()
+    ///     } else {
+    ///         Style::new()
+    ///     }
+    /// });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn line_style<D: LineDecorator + 'a>(mut self, decorator: D) -> Self {
+        self.line_style = Some(SharedFn(Arc::new(decorator)));
+        self
+    }
+
     /// Set the text alignment for the given paragraph
     ///
     /// The alignment is a variant of the [`Alignment`] enum which can be one of Left, Right, or
@@ -340,30 +420,36 @@ impl<'a> Paragraph<'a> {
             .map(Block::vertical_space)
             .unwrap_or_default();
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
-            let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
-                let alignment = line.alignment.unwrap_or(self.alignment);
-                (graphemes, alignment)
-            });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
-            let mut count = 0;
-            while line_composer.next_line().is_some() {
-                count += 1;
-            }
-            count
-        } else {
-            self.text.height()
-        };
-
-        count
+        self.text_line_count(width)
             .saturating_add(top as usize)
             .saturating_add(bottom as usize)
     }
 
+    /// Calculates the number of lines of text when wrapped to the given width, excluding the
+    /// [`Block`].
+    pub(crate) fn text_line_count(&self, width: u16) -> usize {
+        let Some(Wrap { trim }) = self.wrap else {
+            return self.text.height();
+        };
+        if width < 1 {
+            return 0;
+        }
+        let styled = self.text.iter().map(|line| {
+            let graphemes = line
+                .spans
+                .iter()
+                .flat_map(|span| span.styled_graphemes(self.style));
+            let alignment = line.alignment.unwrap_or(self.alignment);
+            (graphemes, alignment)
+        });
+        let mut line_composer = WordWrapper::new(styled, width, trim);
+        let mut count = 0;
+        while line_composer.next_line().is_some() {
+            count += 1;
+        }
+        count
+    }
+
     /// Calculates the shortest line width needed to avoid any word being wrapped or truncated.
     ///
     /// Accounts for the [`Block`] if a block is set through [`Self::block`].
@@ -399,12 +485,49 @@ impl<'a> Paragraph<'a> {
     }
 }
 
+impl IntrinsicSize for Paragraph<'_> {
+    /// Returns the width of the longest line, plus the space taken by the [`Block`].
+    fn width_hint(&self) -> Option<u16> {
+        let width = u16::try_from(self.text.width()).unwrap_or(u16::MAX);
+        Some(
+            self.block
+                .as_ref()
+                .map_or(width, |block| block.outer_width(width)),
+        )
+    }
+
+    /// Returns the number of lines when the text is wrapped to fit the given width, plus the space
+    /// taken by the [`Block`].
+    fn height_hint(&self, width: u16) -> Option<u16> {
+        let inner_width = self
+            .block
+            .as_ref()
+            .map_or(width, |block| block.inner_width(width));
+        let height = u16::try_from(self.text_line_count(inner_width)).unwrap_or(u16::MAX);
+        Some(
+            self.block
+                .as_ref()
+                .map_or(height, |block| block.outer_height(height)),
+        )
+    }
+}
+
 impl Widget for Paragraph<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
     }
 }
 
+/// Describes the paragraph as text, with its lines joined by spaces, inside its block.
+impl Accessible for Paragraph<'_> {
+    fn describe(&self, area: Rect, semantics: &mut Semantics) {
+        let level = describe_block(self.block.as_ref(), area, semantics);
+        let label = self.text.iter().join(" ");
+        let node = SemanticNode::new(Role::Text, self.block.inner_if_some(area));
+        semantics.push(node.label(label).level(level));
+    }
+}
+
 impl Widget for &Paragraph<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let area = area.intersection(buf.area);
@@ -417,6 +540,14 @@ impl Widget for &Paragraph<'_> {
 
 impl Paragraph<'_> {
     fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
+        self.render_text(text_area, buf, usize::from(self.scroll.y));
+    }
+
+    /// Renders the text without the block, starting at the line with the given index.
+    ///
+    /// This is used instead of the vertical scroll offset by widgets that show a part of a text
+    /// that can be more than `u16::MAX` lines long, such as the [`Pager`](crate::pager::Pager).
+    pub(crate) fn render_text(&self, text_area: Rect, buf: &mut Buffer, first_line: usize) {
         if text_area.is_empty() {
             return;
         }
@@ -428,28 +559,40 @@ impl Paragraph<'_> {
             (graphemes, alignment)
         });
 
+        let line_style = self.line_style.as_ref().map(|line_style| &*line_style.0);
         if let Some(Wrap { trim }) = self.wrap {
             let mut line_composer = WordWrapper::new(styled, text_area.width, trim);
             // compute the lines iteratively until we reach the desired scroll offset.
-            for _ in 0..self.scroll.y {
+            for _ in 0..first_line {
                 if line_composer.next_line().is_none() {
                     return;
                 }
             }
-            render_lines(line_composer, text_area, buf);
+            render_lines(line_composer, text_area, buf, first_line, line_style);
         } else {
             // avoid unnecessary work by skipping directly to the relevant line before rendering
-            let lines = styled.skip(self.scroll.y as usize);
+            let lines = styled.skip(first_line);
             let mut line_composer = LineTruncator::new(lines, text_area.width);
             line_composer.set_horizontal_offset(self.scroll.x);
-            render_lines(line_composer, text_area, buf);
+            render_lines(line_composer, text_area, buf, first_line, line_style);
         }
     }
 }
 
-fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut Buffer) {
+fn render_lines<'a, C: LineComposer<'a>>(
+    mut composer: C,
+    area: Rect,
+    buf: &mut Buffer,
+    first_index: usize,
+    line_style: Option<&dyn LineDecorator>,
+) {
     let mut y = 0;
     while let Some(ref wrapped) = composer.next_line() {
+        if let Some(line_style) = line_style {
+            let index = first_index + usize::from(y);
+            let row = Rect::new(area.x, area.y + y, area.width, 1);
+            buf.set_style(row, line_style.line_style(index));
+        }
         render_line(wrapped, area, buf, y);
         y += 1;
         if y >= area.height {
@@ -481,6 +624,111 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
     }
 }
 
+/// An extension trait for [`Buffer`] that writes wrapped text directly, without a [`Paragraph`].
+///
+/// The methods wrap the text like a [`Paragraph`] with [`Paragraph::wrap`], write it at the top of
+/// an area, and return the part of the area below the text that is still unused. This allows custom
+/// widgets to compose flows of text, e.g. a heading followed by several paragraphs, where each
+/// piece of text takes as many rows as it needs.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Stylize;
+/// use ratatui::text::{Line, Text};
+/// use ratatui::widgets::{BufferExt, Wrap};
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 12, 5));
+/// let area = buf.area;
+/// let area = buf.set_line_wrapped(area, &Line::from("Heading").bold(), Wrap { trim: true });
+/// let area = buf.set_text_wrapped(
+///     area,
+///     &Text::from("Some text that wraps"),
+///     Wrap { trim: true },
+/// );
+/// assert_eq!(area, Rect::new(0, 3, 12, 2));
+/// ```
+pub trait BufferExt {
+    /// Writes the text wrapped to the width of `area`, starting at its top, and returns the rows of
+    /// `area` below the text.
+    ///
+    /// The rows that contain text are styled with the style of the text, and each line is aligned
+    /// with its own alignment or the alignment of the text. Text that does not fit in `area` is
+    /// cut off, in which case the returned area has a height of zero.
+    fn set_text_wrapped(&mut self, area: Rect, text: &Text<'_>, wrap: Wrap) -> Rect;
+
+    /// Writes the line wrapped to the width of `area`, starting at its top, and returns the rows of
+    /// `area` below the line.
+    ///
+    /// See [`BufferExt::set_text_wrapped`].
+    fn set_line_wrapped(&mut self, area: Rect, line: &Line<'_>, wrap: Wrap) -> Rect;
+}
+
+impl BufferExt for Buffer {
+    fn set_text_wrapped(&mut self, area: Rect, text: &Text<'_>, wrap: Wrap) -> Rect {
+        let alignment = text.alignment.unwrap_or_default();
+        let styled = || {
+            text.iter().map(move |line| {
+                let graphemes = line.styled_graphemes(text.style);
+                (graphemes, line.alignment.unwrap_or(alignment))
+            })
+        };
+        set_wrapped(self, area, styled, text.style, wrap)
+    }
+
+    fn set_line_wrapped(&mut self, area: Rect, line: &Line<'_>, wrap: Wrap) -> Rect {
+        let styled = || {
+            core::iter::once((
+                line.styled_graphemes(Style::new()),
+                line.alignment.unwrap_or_default(),
+            ))
+        };
+        set_wrapped(self, area, styled, Style::new(), wrap)
+    }
+}
+
+/// Writes the wrapped lines at the top of `area` and returns the rows below them.
+///
+/// The lines are wrapped twice, so `lines` creates a new iterator over them each time it is called.
+fn set_wrapped<'a, F, O, I>(
+    buf: &mut Buffer,
+    area: Rect,
+    lines: F,
+    style: Style,
+    Wrap { trim }: Wrap,
+) -> Rect
+where
+    F: Fn() -> O,
+    O: Iterator<Item = (I, Alignment)>,
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    let area = area.intersection(buf.area);
+    if area.is_empty() {
+        return area;
+    }
+    // the rows are styled before the text is written, so count them first
+    let mut counter = WordWrapper::new(lines(), area.width, trim);
+    let mut height = 0;
+    while height < area.height && counter.next_line().is_some() {
+        height += 1;
+    }
+    buf.set_style(Rect { height, ..area }, style);
+    render_lines(
+        WordWrapper::new(lines(), area.width, trim),
+        area,
+        buf,
+        0,
+        None,
+    );
+    Rect {
+        y: area.y + height,
+        height: area.height - height,
+        ..area
+    }
+}
+
 impl Styled for Paragraph<'_> {
     type Item = Self;
 
@@ -495,6 +743,7 @@ impl Styled for Paragraph<'_> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
     use alloc::vec;
 
     use ratatui_core::buffer::{Buffer, CellWidth};
@@ -1131,6 +1380,20 @@ mod tests {
         assert_eq!(paragraph.line_count(6), 200);
     }
 
+    #[test]
+    fn intrinsic_size() {
+        let paragraph = Paragraph::new("Hello World\nHi").wrap(Wrap { trim: true });
+        assert_eq!(paragraph.width_hint(), Some(11));
+        assert_eq!(paragraph.height_hint(20), Some(2));
+        assert_eq!(paragraph.height_hint(5), Some(3));
+
+        // the block borders reduce the width available for wrapping
+        let paragraph = paragraph.block(Block::bordered());
+        assert_eq!(paragraph.width_hint(), Some(13));
+        assert_eq!(paragraph.height_hint(13), Some(4));
+        assert_eq!(paragraph.height_hint(7), Some(5));
+    }
+
     #[test]
     fn widgets_paragraph_rendered_line_count_accounts_block() {
         let block = Block::new();
@@ -1319,6 +1582,40 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::truncated(None, ["two  ", "three"])]
+    #[case::wrapped(Some(Wrap { trim: true }), ["two  ", "three"])]
+    fn render_line_style(#[case] wrap: Option<Wrap>, #[case] lines: [&str; 2]) {
+        let mut paragraph = Paragraph::new("one\ntwo\nthree\nfour")
+            .scroll((1, 0))
+            .line_style(|index| {
+                if index % 2 == 1 {
+                    Style::new().bg(Color::Blue)
+                } else {
+                    Style::new()
+                }
+            });
+        if let Some(wrap) = wrap {
+            paragraph = paragraph.wrap(wrap);
+        }
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        paragraph.render(buf.area, &mut buf);
+        // the striping follows the lines of the text, not the rows of the area
+        let mut expected = Buffer::with_lines(lines);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().bg(Color::Blue));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn line_style_compares_by_identity() {
+        let paragraph = Paragraph::new("text").line_style(|_| Style::new());
+        assert_eq!(paragraph.clone(), paragraph);
+        assert_ne!(
+            paragraph,
+            Paragraph::new("text").line_style(|_| Style::new())
+        );
+    }
+
     #[test]
     fn render_in_minimal_buffer() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
@@ -1335,4 +1632,49 @@ mod tests {
         // This should not panic, even if the buffer has zero size.
         paragraph.render(buffer.area, &mut buffer);
     }
+
+    #[test]
+    fn set_text_wrapped_returns_remaining_area() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let text = Text::from(vec![
+            Line::from("Hello world"),
+            Line::from("Bye").right_aligned(),
+        ])
+        .on_blue();
+        let area = buffer.set_text_wrapped(Rect::new(0, 1, 10, 4), &text, Wrap { trim: true });
+        assert_eq!(area, Rect::new(0, 4, 10, 1));
+        let mut expected = Buffer::with_lines([
+            "          ",
+            "Hello     ",
+            "world     ",
+            "       Bye",
+            "          ",
+        ]);
+        expected.set_style(Rect::new(0, 1, 10, 3), Style::new().on_blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn set_line_wrapped_cuts_off_text_that_does_not_fit() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let line = Line::from("one two three").centered();
+        let area = buffer.set_line_wrapped(buffer.area, &line, Wrap { trim: true });
+        assert_eq!(area, Rect::new(0, 2, 5, 0));
+        assert_eq!(buffer, Buffer::with_lines([" one ", " two "]));
+
+        let area = buffer.set_line_wrapped(area, &line, Wrap { trim: true });
+        assert_eq!(area, Rect::new(0, 2, 5, 0));
+    }
+
+    #[test]
+    fn describe() {
+        let paragraph = Paragraph::new("Hello\nworld").block(Block::bordered().title("Greeting"));
+        let mut semantics = Semantics::new();
+        paragraph.describe(Rect::new(0, 0, 10, 4), &mut semantics);
+        assert_eq!(
+            semantics.to_string(),
+            "region: Greeting\n  text: Hello world\n"
+        );
+        assert_eq!(semantics.nodes()[1].area, Rect::new(1, 1, 8, 2));
+    }
 }
@@ -0,0 +1,161 @@
+//! The [`ScrollMemory`] type remembers the scroll position and selection of several views.
+use alloc::collections::BTreeMap;
+
+/// Remembers a widget state for each view of an application, keyed by an application-provided
+/// key.
+///
+/// Applications that show different content in the same widget (e.g. the files of different
+/// directories in a [`List`], or the rows of different tables in a [`Table`]) usually want each
+/// view to come back with the scroll position and selection it had when the user left it.
+/// `ScrollMemory` maps a key identifying the content (a path, an id, an enum variant, ...) to
+/// the state of the view, which can be any state such as [`ListState`], [`TableState`] or
+/// [`ScrollbarState`].
+///
+/// The state of the current view can either be kept by the application and swapped in and out
+/// with [`ScrollMemory::switch`], or kept in the memory and accessed with
+/// [`ScrollMemory::state_mut`]. Views that were never visited start with the default state.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{ListState, ScrollMemory};
+///
+/// let mut memory = ScrollMemory::new();
+/// let mut state = ListState::default().with_selected(Some(3));
+///
+/// // leave the "src" directory for the "docs" directory, which was never visited
+/// memory.switch("src", &"docs", &mut state);
+/// assert_eq!(state.selected(), None);
+///
+/// // coming back to "src" restores its selection
+/// state.select(Some(1));
+/// memory.switch("docs", &"src", &mut state);
+/// assert_eq!(state.selected(), Some(3));
+/// assert_eq!(memory.get(&"docs").and_then(ListState::selected), Some(1));
+/// ```
+///
+/// [`List`]: crate::list::List
+/// [`Table`]: crate::table::Table
+/// [`ListState`]: crate::list::ListState
+/// [`TableState`]: crate::table::TableState
+/// [`ScrollbarState`]: crate::scrollbar::ScrollbarState
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ScrollMemory<K, S> {
+    states: BTreeMap<K, S>,
+}
+
+impl<K, S> Default for ScrollMemory<K, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, S> ScrollMemory<K, S> {
+    /// Creates an empty `ScrollMemory`.
+    pub const fn new() -> Self {
+        Self {
+            states: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of remembered views.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns true if no view is remembered.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Forgets all views.
+    pub fn clear(&mut self) {
+        self.states.clear();
+    }
+}
+
+impl<K: Ord, S> ScrollMemory<K, S> {
+    /// Returns the remembered state of the view, or `None` if the view was never saved.
+    pub fn get(&self, key: &K) -> Option<&S> {
+        self.states.get(key)
+    }
+
+    /// Remembers the state of the view, returning the state previously remembered for it.
+    pub fn save(&mut self, key: K, state: S) -> Option<S> {
+        self.states.insert(key, state)
+    }
+
+    /// Forgets the view, e.g. when its content is deleted, returning its remembered state.
+    pub fn remove(&mut self, key: &K) -> Option<S> {
+        self.states.remove(key)
+    }
+
+    /// Returns the remembered state of the view, starting it from the default state if the view
+    /// was never visited.
+    ///
+    /// This is useful to keep the state of every view in the memory, and pass the state of the
+    /// current view to `Frame::render_stateful_widget`.
+    pub fn state_mut(&mut self, key: K) -> &mut S
+    where
+        S: Default,
+    {
+        self.states.entry(key).or_default()
+    }
+
+    /// Returns a copy of the remembered state of the view, or the default state if the view was
+    /// never visited.
+    pub fn restore(&self, key: &K) -> S
+    where
+        S: Clone + Default,
+    {
+        self.get(key).cloned().unwrap_or_default()
+    }
+
+    /// Remembers `state` as the state of the view `from`, and replaces it with the state of the
+    /// view `to`.
+    ///
+    /// The state of `to` is also kept in the memory, so switching to a view and back without
+    /// changing the state is a no-op.
+    pub fn switch(&mut self, from: K, to: &K, state: &mut S)
+    where
+        S: Clone + Default,
+    {
+        let next = self.restore(to);
+        self.save(from, core::mem::replace(state, next));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::list::ListState;
+
+    #[test]
+    fn switch() {
+        let mut memory = ScrollMemory::new();
+        let mut state = ListState::default().with_offset(5).with_selected(Some(7));
+
+        memory.switch(1, &2, &mut state);
+        assert_eq!(state, ListState::default());
+
+        *state.offset_mut() = 2;
+        memory.switch(2, &1, &mut state);
+        assert_eq!((state.offset(), state.selected()), (5, Some(7)));
+        assert_eq!(memory.get(&2).map(ListState::offset), Some(2));
+        assert_eq!(memory.len(), 2);
+    }
+
+    #[test]
+    fn state_mut() {
+        let mut memory = ScrollMemory::<_, ListState>::new();
+        memory.state_mut("a").select_next();
+        memory.state_mut("a").select_next();
+        memory.state_mut("b").select_last();
+        assert_eq!(memory.restore(&"a").selected(), Some(1));
+        assert_eq!(memory.restore(&"c"), ListState::default());
+
+        assert!(memory.remove(&"a").is_some());
+        memory.clear();
+        assert!(memory.is_empty());
+    }
+}
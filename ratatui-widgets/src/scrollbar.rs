@@ -90,6 +90,7 @@ pub struct Scrollbar<'a> {
     begin_style: Style,
     end_symbol: Option<&'a str>,
     end_style: Style,
+    auto_hide: bool,
 }
 
 /// This is the position of the scrollbar around a given area.
@@ -202,6 +203,7 @@ impl<'a> Scrollbar<'a> {
             begin_style: Style::new(),
             end_symbol: Some(symbols.end),
             end_style: Style::new(),
+            auto_hide: false,
         }
     }
 
@@ -408,6 +410,28 @@ impl<'a> Scrollbar<'a> {
         self.end_style = style;
         self
     }
+
+    /// Sets whether the scrollbar is hidden when the content fits in the viewport.
+    ///
+    /// When enabled, nothing is rendered if the [`ScrollbarState`] is not
+    /// [scrollable](ScrollbarState::is_scrollable). If the state has no
+    /// [`ScrollbarState::viewport_content_length`], the length of the scrollbar area is used as the
+    /// viewport length.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Scrollbar, ScrollbarOrientation};
+    ///
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight).auto_hide(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
 }
 
 impl ScrollbarState {
@@ -424,6 +448,38 @@ impl ScrollbarState {
         }
     }
 
+    /// Constructs a new [`ScrollbarState`] from the length of the content, the length of the
+    /// viewport it is shown in, and the scroll position.
+    ///
+    /// This is the same as setting [`ScrollbarState::content_length`],
+    /// [`ScrollbarState::viewport_content_length`] and [`ScrollbarState::position`]. Use
+    /// [`ScrollbarState::is_scrollable`] to find out whether a scrollbar is needed, e.g. to leave
+    /// room for it next to the content.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ScrollbarState;
+    ///
+    /// let state = ScrollbarState::from_lengths(100, 20, 0);
+    /// assert!(state.is_scrollable());
+    ///
+    /// let state = ScrollbarState::from_lengths(10, 20, 0);
+    /// assert!(!state.is_scrollable());
+    /// ```
+    #[must_use = "creates the ScrollbarState"]
+    pub const fn from_lengths(
+        content_length: usize,
+        viewport_length: usize,
+        position: usize,
+    ) -> Self {
+        Self {
+            content_length,
+            position,
+            viewport_content_length: viewport_length,
+        }
+    }
+
     /// Sets the scroll position of the scrollbar.
     ///
     /// This represents the number of scrolled items.
@@ -496,6 +552,21 @@ impl ScrollbarState {
     pub const fn get_position(&self) -> usize {
         self.position
     }
+
+    /// Returns `true` if the content doesn't fit in the viewport, or is scrolled.
+    ///
+    /// This is always `false` if the [`ScrollbarState::viewport_content_length`] is not set and
+    /// the content is not scrolled.
+    #[must_use = "returns whether the content is scrollable"]
+    pub const fn is_scrollable(&self) -> bool {
+        self.is_scrollable_in(self.viewport_content_length)
+    }
+
+    /// Returns `true` if the content doesn't fit in a viewport of the given length, or is
+    /// scrolled.
+    const fn is_scrollable_in(&self, viewport_length: usize) -> bool {
+        self.position > 0 || self.content_length > viewport_length
+    }
 }
 
 impl StatefulWidget for Scrollbar<'_> {
@@ -505,6 +576,9 @@ impl StatefulWidget for Scrollbar<'_> {
         if state.content_length == 0 || self.track_length_excluding_arrow_heads(area) == 0 {
             return;
         }
+        if self.auto_hide && !state.is_scrollable_in(self.viewport_length(state, area)) {
+            return;
+        }
 
         if let Some(area) = self.scrollbar_area(area) {
             let areas = area.columns().flat_map(Rect::rows);
@@ -726,6 +800,32 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines([expected]));
     }
 
+    #[rstest]
+    #[case::fits("    ", ScrollbarState::new(4))]
+    #[case::fits_viewport("    ", ScrollbarState::from_lengths(3, 3, 0))]
+    #[case::overflows("##--", ScrollbarState::new(5))]
+    #[case::overflows_viewport("#---", ScrollbarState::from_lengths(12, 3, 0))]
+    #[case::scrolled("-###", ScrollbarState::new(2).position(1))]
+    fn render_scrollbar_auto_hide(
+        #[case] expected: &str,
+        #[case] mut state: ScrollbarState,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        scrollbar_no_arrows
+            .auto_hide(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn is_scrollable() {
+        assert!(ScrollbarState::from_lengths(10, 5, 0).is_scrollable());
+        assert!(ScrollbarState::from_lengths(5, 5, 1).is_scrollable());
+        assert!(!ScrollbarState::from_lengths(5, 5, 0).is_scrollable());
+        assert!(!ScrollbarState::new(0).is_scrollable());
+    }
+
     #[rstest]
     #[case::position_0("#####-----", 0, 10)]
     #[case::position_1("-#####----", 1, 10)]
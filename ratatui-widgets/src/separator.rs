@@ -0,0 +1,199 @@
+//! The [`Separator`] widget draws a line between the segments of a layout.
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Direction, Rect};
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::symbols::line;
+use ratatui_core::widgets::Widget;
+
+/// A widget that draws a vertical or horizontal rule through the middle of its area.
+///
+/// `Separator` is meant to be rendered into the spacers returned by
+/// [`Layout::split_with_spacers`], to draw rules between the segments of a layout. A vertical
+/// separator draws a vertical line, which separates segments laid out horizontally, and a
+/// horizontal separator draws a horizontal line. [`Separator::render_spacers`] renders the
+/// separator into every spacer that lies between two segments, skipping the spacers before the
+/// first segment and after the last one, and the spacers that are empty.
+///
+/// Use [`Layout::min_spacing`] to make sure the spacers are wide enough to hold the separator.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Constraint, Flex, Layout, Rect};
+/// use ratatui::style::Stylize;
+/// use ratatui::widgets::Separator;
+///
+/// let area = Rect::new(0, 0, 11, 1);
+/// let (_segments, spacers) = Layout::horizontal([Constraint::Length(3); 3])
+///     .flex(Flex::SpaceBetween)
+///     .min_spacing(1)
+///     .split_with_spacers(area);
+///
+/// let mut buf = Buffer::empty(area);
+/// Separator::vertical()
+///     .dark_gray()
+///     .render_spacers(&spacers, &mut buf);
+/// assert_eq!(buf[(3, 0)].symbol(), "│");
+/// assert_eq!(buf[(7, 0)].symbol(), "│");
+/// ```
+///
+/// [`Layout::split_with_spacers`]: ratatui_core::layout::Layout::split_with_spacers
+/// [`Layout::min_spacing`]: ratatui_core::layout::Layout::min_spacing
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Separator<'a> {
+    direction: Direction,
+    symbol: &'a str,
+    style: Style,
+}
+
+impl Separator<'_> {
+    /// Creates a separator that draws a vertical line, to separate segments laid out
+    /// horizontally.
+    pub const fn vertical() -> Self {
+        Self {
+            direction: Direction::Vertical,
+            symbol: line::VERTICAL,
+            style: Style::new(),
+        }
+    }
+
+    /// Creates a separator that draws a horizontal line, to separate segments laid out
+    /// vertically.
+    pub const fn horizontal() -> Self {
+        Self {
+            direction: Direction::Horizontal,
+            symbol: line::HORIZONTAL,
+            style: Style::new(),
+        }
+    }
+}
+
+impl<'a> Separator<'a> {
+    /// Sets the symbol used to draw the line
+    ///
+    /// Defaults to [`line::VERTICAL`] for vertical separators and [`line::HORIZONTAL`] for
+    /// horizontal separators.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets the style of the line
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Renders the separator into the spacers that lie between two segments of a layout.
+    ///
+    /// `spacers` are the spacers returned by [`Layout::split_with_spacers`]. The first and the last
+    /// spacers, which surround the segments rather than separate them, are skipped, and so are
+    /// the spacers that are empty.
+    ///
+    /// [`Layout::split_with_spacers`]: ratatui_core::layout::Layout::split_with_spacers
+    pub fn render_spacers(&self, spacers: &[Rect], buf: &mut Buffer) {
+        let inner = spacers.get(1..spacers.len().saturating_sub(1));
+        for spacer in inner.unwrap_or_default() {
+            if !spacer.is_empty() {
+                self.render(*spacer, buf);
+            }
+        }
+    }
+}
+
+impl Widget for &Separator<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (*self).render(area, buf);
+    }
+}
+
+impl Widget for Separator<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(*buf.area());
+        if area.is_empty() {
+            return;
+        }
+        let line = match self.direction {
+            Direction::Vertical => Rect {
+                x: area.x + area.width.saturating_sub(1) / 2,
+                width: 1,
+                ..area
+            },
+            Direction::Horizontal => Rect {
+                y: area.y + area.height.saturating_sub(1) / 2,
+                height: 1,
+                ..area
+            },
+        };
+        for position in line.positions() {
+            buf[position].set_symbol(self.symbol).set_style(self.style);
+        }
+    }
+}
+
+impl Styled for Separator<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::layout::{Constraint, Flex, Layout};
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn render_vertical() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Separator::vertical().red().render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([" │  ", " │  "]);
+        expected.set_style(Rect::new(1, 0, 1, 2), Color::Red);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_horizontal() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Separator::horizontal()
+            .symbol("=")
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["   ", "===", "   "]));
+    }
+
+    #[test]
+    fn render_spacers() {
+        let area = Rect::new(0, 0, 11, 1);
+        let (_, spacers) = Layout::horizontal([Constraint::Length(3); 3])
+            .flex(Flex::SpaceAround)
+            .min_spacing(1)
+            .split_with_spacers(area);
+        let mut buf = Buffer::empty(area);
+        Separator::vertical().render_spacers(&spacers, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["   │   │   "]));
+
+        let mut buf = Buffer::empty(area);
+        Separator::vertical().render_spacers(&[Rect::ZERO; 4], &mut buf);
+        Separator::vertical().render_spacers(&[], &mut buf);
+        assert_eq!(buf, Buffer::empty(area));
+    }
+}
@@ -0,0 +1,51 @@
+//! The [`SharedFn`] type, used to store user defined functions in widgets.
+use alloc::sync::Arc;
+use core::hash::{Hash, Hasher};
+use core::{fmt, ptr};
+
+/// A user defined function (or trait object) that is shared by the clones of a widget.
+///
+/// Widgets derive [`Clone`], [`Debug`](fmt::Debug), [`PartialEq`], [`Eq`] and [`Hash`], which
+/// functions don't implement. A `SharedFn` is cloned by reference counting, and compared and hashed
+/// by identity: two widgets are equal only if they share the same function.
+pub(crate) struct SharedFn<T: ?Sized>(pub(crate) Arc<T>);
+
+impl<T: ?Sized> Clone for SharedFn<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for SharedFn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedFn(..)")
+    }
+}
+
+impl<T: ?Sized> PartialEq for SharedFn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: ?Sized> Eq for SharedFn<T> {}
+
+impl<T: ?Sized> Hash for SharedFn<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ptr::hash(Arc::as_ptr(&self.0).cast::<()>(), state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compared_by_identity() {
+        let double: SharedFn<dyn Fn(u8) -> u8> = SharedFn(Arc::new(|n| n * 2));
+        let other: SharedFn<dyn Fn(u8) -> u8> = SharedFn(Arc::new(|n| n * 2));
+        assert_eq!(double.clone(), double);
+        assert_ne!(double, other);
+        assert_eq!((double.0)(2), 4);
+    }
+}
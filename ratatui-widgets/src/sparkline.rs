@@ -39,12 +39,17 @@ use crate::block::{Block, BlockExt};
 /// Absent values and will be rendered with the style set by [`Sparkline::absent_value_style`] and
 /// the symbol set by [`Sparkline::absent_value_symbol`].
 ///
+/// Signed data can be shown around a midline with [`Sparkline::midline`], using bars created with
+/// [`SparklineBar::signed`]: positive values extend up from the midline and negative values extend
+/// down from it.
+///
 /// # Setter methods
 ///
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
 /// - [`Sparkline::max`] sets the maximum value of bars
 /// - [`Sparkline::direction`] sets the render direction
+/// - [`Sparkline::midline`] renders negative values below a midline
 ///
 /// # Examples
 ///
@@ -62,7 +67,7 @@ use crate::block::{Block, BlockExt};
 ///     .absent_value_style(Style::default().fg(Color::Red))
 ///     .absent_value_symbol(symbols::shade::FULL);
 /// ```
-#[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Sparkline<'a> {
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
@@ -79,8 +84,29 @@ pub struct Sparkline<'a> {
     max: Option<u64>,
     /// A set of bar symbols used to represent the give data
     bar_set: symbols::bar::Set<'a>,
+    /// A set of bar symbols used to represent the negative values below the midline
+    negative_bar_set: symbols::bar::Set<'a>,
     /// The direction to render the sparkline, either from left to right, or from right to left
     direction: RenderDirection,
+    /// Whether to split the area with a midline, with negative values rendered below it
+    midline: bool,
+}
+
+impl Default for Sparkline<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::default(),
+            absent_value_style: Style::default(),
+            absent_value_symbol: AbsentValueSymbol::default(),
+            data: Vec::new(),
+            max: None,
+            bar_set: symbols::bar::NINE_LEVELS,
+            negative_bar_set: symbols::bar::INVERTED_THREE_LEVELS,
+            direction: RenderDirection::default(),
+            midline: false,
+        }
+    }
 }
 
 /// Defines the direction in which sparkline will be rendered.
@@ -243,6 +269,41 @@ impl<'a> Sparkline<'a> {
         self.direction = direction;
         self
     }
+
+    /// Sets whether to render the sparkline around a midline, for data that can be negative.
+    ///
+    /// The area is split in two halves: positive values extend up from the midline and negative
+    /// values (see [`SparklineBar::signed`]) extend down from it, both scaled to the same maximum.
+    /// With a height of 2, this shows e.g. the upload and download rates of a network monitor in
+    /// a compact pair of rows. Without a midline, negative values are rendered as empty bars.
+    ///
+    /// The negative bars are drawn with [`Sparkline::negative_bar_set`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Sparkline, SparklineBar};
+    ///
+    /// let rates = [3, -2, 5, -4, 1];
+    /// let sparkline = Sparkline::default()
+    ///     .data(rates.into_iter().map(SparklineBar::signed))
+    ///     .midline(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn midline(mut self, midline: bool) -> Self {
+        self.midline = midline;
+        self
+    }
+
+    /// Sets the characters used to display the negative bars below the midline.
+    ///
+    /// The symbols must fill the cells from the top. Defaults to
+    /// [`symbols::bar::INVERTED_THREE_LEVELS`], which uses half blocks.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn negative_bar_set(mut self, bar_set: symbols::bar::Set<'a>) -> Self {
+        self.negative_bar_set = bar_set;
+        self
+    }
 }
 
 /// An bar in a `Sparkline`.
@@ -255,6 +316,8 @@ pub struct SparklineBar {
     ///
     /// If `None`, the bar is absent.
     value: Option<u64>,
+    /// Whether the value is negative, in which case `value` holds its magnitude.
+    negative: bool,
     /// The style of the bar.
     ///
     /// If `None`, the bar will use the style of the sparkline.
@@ -262,6 +325,17 @@ pub struct SparklineBar {
 }
 
 impl SparklineBar {
+    /// Creates a bar with a value that can be negative.
+    ///
+    /// Negative values are rendered below the midline, see [`Sparkline::midline`].
+    pub const fn signed(value: i64) -> Self {
+        Self {
+            value: Some(value.unsigned_abs()),
+            negative: value < 0,
+            style: None,
+        }
+    }
+
     /// Sets the style of the bar.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -283,7 +357,11 @@ impl SparklineBar {
 
 impl From<Option<u64>> for SparklineBar {
     fn from(value: Option<u64>) -> Self {
-        Self { value, style: None }
+        Self {
+            value,
+            negative: false,
+            style: None,
+        }
     }
 }
 
@@ -291,6 +369,7 @@ impl From<u64> for SparklineBar {
     fn from(value: u64) -> Self {
         Self {
             value: Some(value),
+            negative: false,
             style: None,
         }
     }
@@ -300,6 +379,7 @@ impl From<&u64> for SparklineBar {
     fn from(value: &u64) -> Self {
         Self {
             value: Some(*value),
+            negative: false,
             style: None,
         }
     }
@@ -309,6 +389,7 @@ impl From<&Option<u64>> for SparklineBar {
     fn from(value: &Option<u64>) -> Self {
         Self {
             value: *value,
+            negative: false,
             style: None,
         }
     }
@@ -355,10 +436,23 @@ impl Sparkline<'_> {
         if spark_area.is_empty() {
             return;
         }
-        // determine the maximum height across all bars
-        let max_height = self
-            .max
-            .unwrap_or_else(|| self.data.iter().filter_map(|s| s.value).max().unwrap_or(1));
+        // determine the maximum height across all bars, negative values are only rendered around
+        // a midline
+        let max_height = self.max.unwrap_or_else(|| {
+            self.data
+                .iter()
+                .filter(|s| self.midline || !s.negative)
+                .filter_map(|s| s.value)
+                .max()
+                .unwrap_or(1)
+        });
+
+        // split the area around the midline, positive values are rendered above it
+        let (upper_height, lower_height) = if self.midline {
+            (spark_area.height.div_ceil(2), spark_area.height / 2)
+        } else {
+            (spark_area.height, 0)
+        };
 
         // determine the maximum index to render
         let max_index = min(spark_area.width as usize, self.data.len());
@@ -381,16 +475,28 @@ impl Sparkline<'_> {
             // - the height is the total height of the spark area
             // - the symbol is the absent value symbol
             // - the style is the absent value style
-            let (mut height, symbol, style) = match item {
+            //
+            // the heights above and below the midline are tracked separately
+            let (mut height, mut negative_height, symbol, style) = match item {
                 SparklineBar {
                     value: Some(value),
+                    negative: false,
                     style,
                 } => {
-                    let height = Self::scale_height(*value, max_height, spark_area.height);
-                    (height, None, *style)
+                    let height = Self::scale_height(*value, max_height, upper_height);
+                    (height, 0, None, *style)
+                }
+                SparklineBar {
+                    value: Some(value),
+                    negative: true,
+                    style,
+                } => {
+                    let height = Self::scale_height(*value, max_height, lower_height);
+                    (0, height, None, *style)
                 }
                 _ => (
-                    u64::from(spark_area.height) * 8,
+                    u64::from(upper_height) * 8,
+                    u64::from(lower_height) * 8,
                     Some(self.absent_value_symbol.0.as_str()),
                     Some(self.absent_value_style),
                 ),
@@ -403,13 +509,20 @@ impl Sparkline<'_> {
             //
             // if the style is set it will be used for the entire height of the bar, otherwise the
             // sparkline style will be used.
-            for j in (0..spark_area.height).rev() {
-                let symbol = symbol.unwrap_or_else(|| self.symbol_for_height(height));
-                if height > 8 {
-                    height -= 8;
-                } else {
-                    height = 0;
-                }
+            for j in (0..upper_height).rev() {
+                let symbol =
+                    symbol.unwrap_or_else(|| Self::symbol_for_height(&self.bar_set, height));
+                height = height.saturating_sub(8);
+                buf[(x, spark_area.top() + j)]
+                    .set_symbol(symbol)
+                    .set_style(self.style.patch(style.unwrap_or_default()));
+            }
+            // negative values are rendered from the midline to the bottom
+            for j in upper_height..spark_area.height {
+                let symbol = symbol.unwrap_or_else(|| {
+                    Self::symbol_for_height(&self.negative_bar_set, negative_height)
+                });
+                negative_height = negative_height.saturating_sub(8);
                 buf[(x, spark_area.top() + j)]
                     .set_symbol(symbol)
                     .set_style(self.style.patch(style.unwrap_or_default()));
@@ -417,17 +530,17 @@ impl Sparkline<'_> {
         }
     }
 
-    const fn symbol_for_height(&self, height: u64) -> &str {
+    const fn symbol_for_height<'b>(bar_set: &symbols::bar::Set<'b>, height: u64) -> &'b str {
         match height {
-            0 => self.bar_set.empty,
-            1 => self.bar_set.one_eighth,
-            2 => self.bar_set.one_quarter,
-            3 => self.bar_set.three_eighths,
-            4 => self.bar_set.half,
-            5 => self.bar_set.five_eighths,
-            6 => self.bar_set.three_quarters,
-            7 => self.bar_set.seven_eighths,
-            _ => self.bar_set.full,
+            0 => bar_set.empty,
+            1 => bar_set.one_eighth,
+            2 => bar_set.one_quarter,
+            3 => bar_set.three_eighths,
+            4 => bar_set.half,
+            5 => bar_set.five_eighths,
+            6 => bar_set.three_quarters,
+            7 => bar_set.seven_eighths,
+            _ => bar_set.full,
         }
     }
 
@@ -608,6 +721,30 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["▇"]));
     }
 
+    #[test]
+    fn render_midline() {
+        let widget = Sparkline::default()
+            .data([8, -8, 4, -4, 0].map(SparklineBar::signed))
+            .midline(true);
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buffer = Buffer::empty(area);
+
+        widget.render(area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["█ ▄  ", " █ ▀ "]));
+    }
+
+    #[test]
+    fn render_negative_values_without_midline() {
+        let widget = Sparkline::default().data([8, -16, 4].map(SparklineBar::signed));
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buffer = Buffer::empty(area);
+
+        widget.render(area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["█ ▄"]));
+    }
+
     #[test]
     fn it_renders_left_to_right() {
         let widget = Sparkline::default()
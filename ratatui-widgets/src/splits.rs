@@ -0,0 +1,477 @@
+//! The [`Splits`] widget divides an area into panes that can be resized with the mouse or the
+//! keyboard.
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Direction, Position, Rect};
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::symbols::line;
+use ratatui_core::widgets::StatefulWidget;
+
+/// A widget that divides an area into resizable panes separated by one-cell dividers.
+///
+/// `Splits` draws the dividers and stores the area of each pane in the [`SplitsState`], where the
+/// panes are read from with [`SplitsState::areas`] to render their content. The size of each pane
+/// is proportional to its weight, so the panes keep their proportions when the terminal is resized.
+///
+/// Panes are resized by dragging a divider with the mouse ([`SplitsState::start_drag`],
+/// [`SplitsState::drag_to`] and [`SplitsState::end_drag`]) or by moving the
+/// [selected](SplitsState::select_divider) divider with [`SplitsState::resize`], e.g. on a key
+/// press. `Splits` doesn't handle input events itself, so it can be used with any backend. The
+/// weights can be saved (with the `serde` feature, or with [`SplitsState::weights`]) to restore
+/// the layout later.
+///
+/// Panes can be nested by rendering another `Splits` in the area of a pane.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::layout::{Direction, Rect};
+/// use ratatui::widgets::{Paragraph, Splits, SplitsState, StatefulWidget, Widget};
+/// # let mut buf = ratatui::buffer::Buffer::empty(Rect::new(0, 0, 41, 10));
+///
+/// // a sidebar and a main pane, twice as wide
+/// let mut state = SplitsState::new([1, 2]);
+/// Splits::new(Direction::Horizontal).render(buf.area, &mut buf, &mut state);
+/// assert_eq!(
+///     state.areas(),
+///     [Rect::new(0, 0, 13, 10), Rect::new(14, 0, 27, 10)]
+/// );
+/// Paragraph::new("sidebar").render(state.areas()[0], &mut buf);
+/// Paragraph::new("main").render(state.areas()[1], &mut buf);
+///
+/// // on mouse down, drag and up
+/// state.start_drag((13, 5));
+/// state.drag_to((20, 5));
+/// state.end_drag();
+/// assert_eq!(state.weights(), [20, 20]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Splits {
+    direction: Direction,
+    style: Style,
+    divider_style: Style,
+    active_divider_style: Style,
+}
+
+impl Splits {
+    /// Creates a new widget that places the panes in the given direction.
+    ///
+    /// With [`Direction::Horizontal`], the panes are side by side and separated by vertical
+    /// dividers. With [`Direction::Vertical`], they are stacked and separated by horizontal
+    /// dividers.
+    pub const fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            style: Style::new(),
+            divider_style: Style::new(),
+            active_divider_style: Style::new(),
+        }
+    }
+
+    /// Sets the style of the whole area.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the dividers.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn divider_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.divider_style = style.into();
+        self
+    }
+
+    /// Sets the style of the divider that is dragged or selected, which is patched over the
+    /// divider style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn active_divider_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.active_divider_style = style.into();
+        self
+    }
+}
+
+impl Styled for Splits {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// State of a [`Splits`] widget: the weights of the panes, and the areas computed by the last
+/// render.
+///
+/// Only the weights and the minimum size of the panes are serialized.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplitsState {
+    weights: Vec<u16>,
+    min_size: u16,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    selected: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dragging: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    direction: Direction,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    areas: Vec<Rect>,
+}
+
+impl SplitsState {
+    /// Creates a new state with a pane for each weight.
+    ///
+    /// The size of each pane is proportional to its weight. Panes are at least one cell wide by
+    /// default when they are resized, see [`SplitsState::min_size`].
+    pub fn new<I: IntoIterator<Item = u16>>(weights: I) -> Self {
+        Self {
+            weights: weights.into_iter().collect(),
+            min_size: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new state with the given number of panes of the same size.
+    pub fn equal(panes: usize) -> Self {
+        Self::new(core::iter::repeat_n(1, panes))
+    }
+
+    /// Sets the minimum size of the panes when they are resized.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_size(mut self, min_size: u16) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Returns the weights of the panes.
+    ///
+    /// After a pane is resized, the weights are the sizes of the panes in cells.
+    pub fn weights(&self) -> &[u16] {
+        &self.weights
+    }
+
+    /// Sets the weights of the panes, which also sets the number of panes.
+    pub fn set_weights<I: IntoIterator<Item = u16>>(&mut self, weights: I) {
+        self.weights = weights.into_iter().collect();
+        self.selected = None;
+        self.dragging = None;
+    }
+
+    /// Returns the areas of the panes computed by the last render of [`Splits`].
+    pub fn areas(&self) -> &[Rect] {
+        &self.areas
+    }
+
+    /// Returns the index of the divider that is selected for resizing with the keyboard.
+    ///
+    /// The divider `i` is between the panes `i` and `i + 1`.
+    pub const fn selected_divider(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the divider that is moved by [`SplitsState::resize`].
+    ///
+    /// The divider `i` is between the panes `i` and `i + 1`.
+    pub const fn select_divider(&mut self, divider: Option<usize>) {
+        self.selected = divider;
+    }
+
+    /// Moves the selected divider by the given number of cells, towards the end of the area when
+    /// positive.
+    ///
+    /// Both panes next to the divider keep at least the minimum size. Does nothing if no divider is
+    /// selected or if the widget hasn't been rendered yet.
+    pub fn resize(&mut self, delta: i32) {
+        if let Some(divider) = self.selected
+            && let Some(pane) = self.areas.get(divider)
+        {
+            let (start, length) = self.span(*pane);
+            let end = i32::from(start) + i32::from(length) + delta;
+            self.move_divider(divider, end.clamp(0, i32::from(u16::MAX)) as u16);
+        }
+    }
+
+    /// Starts dragging the divider at the given position, e.g. when a mouse button is pressed.
+    ///
+    /// Returns true if there is a divider at the position.
+    pub fn start_drag<P: Into<Position>>(&mut self, position: P) -> bool {
+        let position = position.into();
+        let dragging = self
+            .dividers()
+            .position(|divider| divider.contains(position));
+        self.dragging = dragging;
+        dragging.is_some()
+    }
+
+    /// Moves the dragged divider to the given position, e.g. when the mouse is dragged.
+    ///
+    /// Does nothing if no divider is dragged.
+    pub fn drag_to<P: Into<Position>>(&mut self, position: P) {
+        let position = position.into();
+        if let Some(divider) = self.dragging {
+            let position = match self.direction {
+                Direction::Horizontal => position.x,
+                Direction::Vertical => position.y,
+            };
+            self.move_divider(divider, position);
+        }
+    }
+
+    /// Stops dragging the divider, e.g. when the mouse button is released.
+    pub const fn end_drag(&mut self) {
+        self.dragging = None;
+    }
+
+    /// Returns true if a divider is being dragged.
+    pub const fn is_dragging(&self) -> bool {
+        self.dragging.is_some()
+    }
+
+    /// Returns the start and length of an area along the direction of the splits.
+    const fn span(&self, area: Rect) -> (u16, u16) {
+        match self.direction {
+            Direction::Horizontal => (area.x, area.width),
+            Direction::Vertical => (area.y, area.height),
+        }
+    }
+
+    /// Returns the areas of the dividers computed by the last render.
+    fn dividers(&self) -> impl Iterator<Item = Rect> + '_ {
+        self.areas.windows(2).map(|panes| match self.direction {
+            Direction::Horizontal => Rect {
+                x: panes[0].right(),
+                width: 1,
+                ..panes[0]
+            },
+            Direction::Vertical => Rect {
+                y: panes[0].bottom(),
+                height: 1,
+                ..panes[0]
+            },
+        })
+    }
+
+    /// Moves a divider to the given position, keeping the minimum size of the panes next to it,
+    /// and sets the weights of the panes to their sizes.
+    fn move_divider(&mut self, divider: usize, position: u16) {
+        if divider + 1 >= self.areas.len() {
+            return;
+        }
+        let (start, length) = self.span(self.areas[divider]);
+        let (_, next_length) = self.span(self.areas[divider + 1]);
+        let combined = length + next_length;
+        let min_size = self.min_size.min(combined / 2);
+        let length = position
+            .saturating_sub(start)
+            .clamp(min_size, combined - min_size);
+
+        self.weights = self.areas.iter().map(|area| self.span(*area).1).collect();
+        self.weights[divider] = length;
+        self.weights[divider + 1] = combined - length;
+        let mut offset = self.span(self.areas[0]).0;
+        for (area, weight) in self.areas.iter_mut().zip(&self.weights) {
+            match self.direction {
+                Direction::Horizontal => {
+                    area.x = offset;
+                    area.width = *weight;
+                }
+                Direction::Vertical => {
+                    area.y = offset;
+                    area.height = *weight;
+                }
+            }
+            offset += weight + 1;
+        }
+    }
+}
+
+/// Divides a length between panes in proportion to their weights.
+fn sizes(length: u16, weights: &[u16]) -> Vec<u16> {
+    let total: u32 = weights.iter().map(|weight| u32::from(*weight)).sum();
+    let weight = |weight: u16| if total == 0 { 1 } else { u32::from(weight) };
+    let total = if total == 0 {
+        weights.len() as u32
+    } else {
+        total
+    };
+    let mut cumulative = 0;
+    let mut previous = 0;
+    weights
+        .iter()
+        .map(|w| {
+            cumulative += weight(*w);
+            let end = ((u32::from(length) * cumulative + total / 2) / total) as u16;
+            let size = end - previous;
+            previous = end;
+            size
+        })
+        .collect()
+}
+
+impl StatefulWidget for Splits {
+    type State = SplitsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Splits {
+    type State = SplitsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        state.direction = self.direction;
+        state.areas.clear();
+        if state.weights.is_empty() {
+            return;
+        }
+
+        let (start, length) = state.span(area);
+        let dividers = state.weights.len() as u16 - 1;
+        let mut offset = start;
+        for size in sizes(length.saturating_sub(dividers), &state.weights) {
+            state.areas.push(match self.direction {
+                Direction::Horizontal => Rect {
+                    x: offset,
+                    width: size,
+                    ..area
+                },
+                Direction::Vertical => Rect {
+                    y: offset,
+                    height: size,
+                    ..area
+                },
+            });
+            offset = offset.saturating_add(size + 1);
+        }
+
+        let symbol = match self.direction {
+            Direction::Horizontal => line::VERTICAL,
+            Direction::Vertical => line::HORIZONTAL,
+        };
+        let active = state.dragging.or(state.selected);
+        let dividers: Vec<Rect> = state.dividers().collect();
+        for (index, divider) in dividers.into_iter().enumerate() {
+            let divider = divider.intersection(area);
+            let style = if active == Some(index) {
+                self.divider_style.patch(self.active_divider_style)
+            } else {
+                self.divider_style
+            };
+            for position in divider.positions() {
+                buf[position].set_symbol(symbol).set_style(style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_are_proportional() {
+        assert_eq!(sizes(10, &[1, 1]), [5, 5]);
+        assert_eq!(sizes(10, &[1, 1, 1]), [3, 4, 3]);
+        assert_eq!(sizes(10, &[1, 4]), [2, 8]);
+        assert_eq!(sizes(10, &[0, 0]), [5, 5]);
+        assert_eq!(sizes(0, &[1, 2]), [0, 0]);
+    }
+
+    #[test]
+    fn render_horizontal() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        let mut state = SplitsState::equal(3);
+        Splits::new(Direction::Horizontal).render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["  │  │  ", "  │  │  "]));
+        assert_eq!(
+            state.areas(),
+            [
+                Rect::new(0, 0, 2, 2),
+                Rect::new(3, 0, 2, 2),
+                Rect::new(6, 0, 2, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn render_vertical_with_active_divider() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 5));
+        let mut state = SplitsState::equal(2);
+        state.select_divider(Some(0));
+        Splits::new(Direction::Vertical)
+            .active_divider_style(Style::new().yellow())
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["   ", "   ", "───", "   ", "   "]);
+        expected.set_style(Rect::new(0, 2, 3, 1), Style::new().yellow());
+        assert_eq!(buf, expected);
+        assert_eq!(
+            state.areas(),
+            [Rect::new(0, 0, 3, 2), Rect::new(0, 3, 3, 2)]
+        );
+    }
+
+    #[test]
+    fn drag() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        let mut state = SplitsState::equal(2).min_size(2);
+        let splits = Splits::new(Direction::Horizontal);
+        (&splits).render(buf.area, &mut buf, &mut state);
+        assert!(!state.start_drag((4, 0)));
+        assert!(state.start_drag((5, 0)));
+
+        state.drag_to((7, 0));
+        assert_eq!(state.weights(), [7, 3]);
+        assert_eq!(
+            state.areas(),
+            [Rect::new(0, 0, 7, 1), Rect::new(8, 0, 3, 1)]
+        );
+
+        // the panes keep their minimum size
+        state.drag_to((10, 0));
+        assert_eq!(state.weights(), [8, 2]);
+        state.end_drag();
+        assert!(!state.is_dragging());
+
+        let mut buf = Buffer::empty(buf.area);
+        (&splits).render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["        │  "]));
+    }
+
+    #[test]
+    fn resize_with_keyboard() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 11));
+        let mut state = SplitsState::equal(3);
+        Splits::new(Direction::Vertical).render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.weights(), [1, 1, 1]);
+
+        state.resize(1);
+        assert_eq!(state.weights(), [1, 1, 1]);
+
+        state.select_divider(Some(1));
+        state.resize(-2);
+        assert_eq!(state.weights(), [3, 1, 5]);
+        state.resize(-5);
+        assert_eq!(state.weights(), [3, 1, 5]);
+    }
+}
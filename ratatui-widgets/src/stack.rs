@@ -0,0 +1,246 @@
+//! The [`Stack`] widget lays out a collection of widgets of different types in a row or a column.
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Constraint, Direction, Flex, Layout, Rect, Spacing};
+use ratatui_core::widgets::{Widget, WidgetRef};
+
+use crate::block::{Block, BlockExt};
+
+/// A container widget that lays out its children in a row or a column.
+///
+/// Each child is a boxed [`WidgetRef`] with a [`Constraint`] that sizes it along the direction of
+/// the stack, so widgets of different types can be stored in one collection and rendered together.
+/// The children are laid out with a [`Layout`], using the [`Flex`] and spacing of the stack.
+///
+/// Any type that implements `Widget` for a reference to itself (which includes all the built-in
+/// widgets) implements [`WidgetRef`] for that reference, so built-in widgets are added to a stack
+/// by reference. Custom widgets that implement [`WidgetRef`] directly can be owned by the stack.
+/// A stack also implements [`WidgetRef`], so stacks can be nested.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::layout::{Constraint, Rect};
+/// use ratatui::widgets::{Block, Paragraph, Stack, Widget};
+/// # let mut buf = ratatui::buffer::Buffer::empty(Rect::new(0, 0, 20, 5));
+///
+/// let header = Paragraph::new("Header").centered();
+/// let sidebar = Block::bordered().title("Sidebar");
+/// let content = Paragraph::new("Content");
+///
+/// let body = Stack::horizontal()
+///     .child(&sidebar, Constraint::Length(10))
+///     .child(&content, Constraint::Fill(1))
+///     .spacing(1);
+/// let stack = Stack::vertical()
+///     .child(&header, Constraint::Length(1))
+///     .child(body, Constraint::Fill(1));
+/// stack.render(buf.area, &mut buf);
+/// ```
+#[derive(Default)]
+pub struct Stack<'a> {
+    direction: Direction,
+    children: Vec<(Box<dyn WidgetRef + 'a>, Constraint)>,
+    flex: Flex,
+    spacing: Spacing,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> Stack<'a> {
+    /// Creates an empty stack that lays out its children in the given direction.
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            ..Self::default()
+        }
+    }
+
+    /// Creates an empty stack that lays out its children from top to bottom.
+    pub fn vertical() -> Self {
+        Self::new(Direction::Vertical)
+    }
+
+    /// Creates an empty stack that lays out its children from left to right.
+    pub fn horizontal() -> Self {
+        Self::new(Direction::Horizontal)
+    }
+
+    /// Adds a child widget sized by `constraint` after the existing children.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn child<W, C>(mut self, widget: W, constraint: C) -> Self
+    where
+        W: WidgetRef + 'a,
+        C: Into<Constraint>,
+    {
+        self.push(widget, constraint);
+        self
+    }
+
+    /// Adds a child widget sized by `constraint` after the existing children.
+    pub fn push<W, C>(&mut self, widget: W, constraint: C)
+    where
+        W: WidgetRef + 'a,
+        C: Into<Constraint>,
+    {
+        self.children.push((Box::new(widget), constraint.into()));
+    }
+
+    /// Sets the direction in which the children are laid out.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets how the space that is not used by the children is distributed.
+    ///
+    /// See [`Layout::flex`] for more details. Defaults to [`Flex::Legacy`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn flex(mut self, flex: Flex) -> Self {
+        self.flex = flex;
+        self
+    }
+
+    /// Sets the spacing between the children.
+    ///
+    /// See [`Layout::spacing`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn spacing<T: Into<Spacing>>(mut self, spacing: T) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    /// Wraps the stack with a [`Block`], and lays out the children in its inner area.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Returns the constraints of the children, in order.
+    pub fn constraints(&self) -> impl Iterator<Item = Constraint> + '_ {
+        self.children.iter().map(|(_, constraint)| *constraint)
+    }
+
+    /// Returns the number of children in the stack.
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Returns whether the stack has no children.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl fmt::Debug for Stack<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the children are trait objects, so only their constraints are shown
+        f.debug_struct("Stack")
+            .field("direction", &self.direction)
+            .field("children", &self.constraints().collect::<Vec<_>>())
+            .field("flex", &self.flex)
+            .field("spacing", &self.spacing)
+            .field("block", &self.block)
+            .finish()
+    }
+}
+
+impl Widget for Stack<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl Widget for &Stack<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for Stack<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        let layout = Layout::new(self.direction, self.constraints())
+            .flex(self.flex)
+            .spacing(self.spacing.clone());
+        for ((widget, _), area) in self.children.iter().zip(layout.split(area).iter()) {
+            widget.render_ref(*area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::text::Line;
+
+    use super::*;
+
+    struct Greeting;
+
+    impl WidgetRef for Greeting {
+        fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+            Line::from("Hello").render(area, buf);
+        }
+    }
+
+    #[test]
+    fn render_vertical() {
+        let line = Line::from("Goodbye").right_aligned();
+        let stack = Stack::vertical()
+            .child(Greeting, Constraint::Length(1))
+            .child(&line, Constraint::Length(2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+        stack.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["Hello     ", "   Goodbye", "          ", "          "])
+        );
+    }
+
+    #[test]
+    fn render_horizontal_with_spacing_and_flex() {
+        let stack = Stack::horizontal()
+            .child(Greeting, Constraint::Length(5))
+            .child(Greeting, Constraint::Length(5))
+            .spacing(1)
+            .flex(Flex::End);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        (&stack).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([" Hello Hello"]));
+    }
+
+    #[test]
+    fn render_nested_with_block() {
+        let inner = Stack::horizontal().child(Greeting, Constraint::Fill(1));
+        let mut stack = Stack::vertical().block(Block::bordered());
+        stack.push(inner, Constraint::Length(1));
+        assert_eq!(stack.len(), 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        stack.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["┌─────┐", "│Hello│", "└─────┘"]));
+    }
+
+    #[test]
+    fn render_empty() {
+        let stack = Stack::default();
+        assert!(stack.is_empty());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        stack.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 3, 1)));
+    }
+}
@@ -7,17 +7,21 @@ use alloc::vec::Vec;
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Constraint, Flex, Layout, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::text::Text;
-use ratatui_core::widgets::{StatefulWidget, Widget};
+use ratatui_core::widgets::{IntrinsicSize, StatefulWidget, Widget};
 
 pub use self::cell::Cell;
+pub use self::grid::GridStyle;
 pub use self::highlight_spacing::HighlightSpacing;
 pub use self::row::Row;
 pub use self::state::TableState;
 use crate::block::{Block, BlockExt};
+use crate::clear::Clear;
+use crate::input::TextInput;
 
 mod cell;
+mod grid;
 mod highlight_spacing;
 mod row;
 mod state;
@@ -62,6 +66,8 @@ mod state;
 /// - [`Table::rows`] sets the rows of the [`Table`].
 /// - [`Table::header`] sets the header row of the [`Table`].
 /// - [`Table::footer`] sets the footer row of the [`Table`].
+/// - [`Table::sticky_header`] and [`Table::sticky_footer`] set whether the header and footer stay
+///   pinned while the rows scroll.
 /// - [`Table::widths`] sets the width constraints of each column.
 /// - [`Table::column_spacing`] sets the spacing between each column.
 /// - [`Table::block`] wraps the table in a [`Block`] widget.
@@ -69,8 +75,10 @@ mod state;
 /// - [`Table::row_highlight_style`] sets the style of the selected row.
 /// - [`Table::column_highlight_style`] sets the style of the selected column.
 /// - [`Table::cell_highlight_style`] sets the style of the selected cell.
+/// - [`Table::editing_style`] sets the style of the input of the cell being edited.
 /// - [`Table::highlight_symbol`] sets the symbol to be displayed in front of the selected row.
 /// - [`Table::highlight_spacing`] sets when to show the highlight spacing.
+/// - [`Table::grid`] draws lines between the columns and rows.
 ///
 /// # Example
 ///
@@ -240,6 +248,12 @@ pub struct Table<'a> {
     /// Optional footer
     footer: Option<Row<'a>>,
 
+    /// Whether the header stays at the top while the rows scroll
+    sticky_header: bool,
+
+    /// Whether the footer stays at the bottom while the rows scroll
+    sticky_footer: bool,
+
     /// Width constraints for each column
     widths: Vec<Constraint>,
 
@@ -261,6 +275,9 @@ pub struct Table<'a> {
     /// Style used to render the selected cell
     cell_highlight_style: Style,
 
+    /// Style used to render the input of the cell being edited
+    editing_style: Style,
+
     /// Symbol in front of the selected row
     highlight_symbol: Text<'a>,
 
@@ -269,6 +286,12 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// Optional lines between the columns and rows
+    grid: Option<GridStyle>,
+
+    /// Style added to the occurrences of the filter of the state in the cells
+    match_style: Option<Style>,
 }
 
 impl Default for Table<'_> {
@@ -277,6 +300,8 @@ impl Default for Table<'_> {
             rows: Vec::new(),
             header: None,
             footer: None,
+            sticky_header: true,
+            sticky_footer: true,
             widths: Vec::new(),
             column_spacing: 1,
             block: None,
@@ -284,9 +309,12 @@ impl Default for Table<'_> {
             row_highlight_style: Style::new(),
             column_highlight_style: Style::new(),
             cell_highlight_style: Style::new(),
+            editing_style: Style::new(),
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            grid: None,
+            match_style: None,
         }
     }
 }
@@ -392,6 +420,9 @@ impl<'a> Table<'a> {
     ///
     /// The `footer` parameter is a [`Row`] which will be displayed at the bottom of the [`Table`]
     ///
+    /// The footer is styled by its own [`Row::style`], independently of the rows and of the
+    /// selection, which makes it suitable for a row of totals.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
@@ -411,6 +442,52 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Sets whether the header stays pinned at the top of the table while the rows scroll.
+    ///
+    /// The header is sticky by default. When it is not sticky, the header scrolls with the rows:
+    /// it is only shown when the first row is visible, and its space is given to the rows
+    /// otherwise.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let table = Table::default()
+    ///     .header(Row::new(["Name", "Price"]))
+    ///     .sticky_header(false);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sticky_header(mut self, sticky: bool) -> Self {
+        self.sticky_header = sticky;
+        self
+    }
+
+    /// Sets whether the footer stays pinned at the bottom of the table while the rows scroll.
+    ///
+    /// The footer is sticky by default. When it is not sticky, the footer scrolls with the rows:
+    /// it is only shown once the last row is fully visible, directly below that row, and its space
+    /// is given to the rows otherwise.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let table = Table::default()
+    ///     .footer(Row::new(["Total", "42.00"]))
+    ///     .sticky_footer(false);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sticky_footer(mut self, sticky: bool) -> Self {
+        self.sticky_footer = sticky;
+        self
+    }
+
     /// Set the widths of the columns.
     ///
     /// The `widths` parameter accepts any type that implements `IntoIterator<Item =
@@ -534,6 +611,26 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Applies the styles of a [`Theme`] to the widget and its block.
+    ///
+    /// The [`Theme::TEXT`] style is used as the [`style`](Table::style) and [`Theme::HIGHLIGHT`] as
+    /// the [`row_highlight_style`](Table::row_highlight_style). Styles that are not defined by
+    /// the theme are left unchanged. The block is themed with [`Block::theme`], so it must be
+    /// set before calling this method.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        if let Some(style) = theme.get(Theme::TEXT) {
+            self.style = style;
+        }
+        if let Some(style) = theme.get(Theme::HIGHLIGHT) {
+            self.row_highlight_style = style;
+        }
+        self.block = self.block.map(|block| block.theme(theme));
+        self
+    }
+
     /// Set the style of the selected row
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -638,6 +735,90 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the style of the input of the cell being edited
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// While a cell is being edited (see [`TableState::start_editing`]), its content is replaced
+    /// by a text input, which is rendered with this style on top of the highlight styles.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, style::{Style, Stylize}, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).editing_style(Style::new().black().on_yellow());
+    /// ```
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn editing_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.editing_style = style.into();
+        self
+    }
+
+    /// Set the style added to the occurrences of the filter in the cells
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// When the table is rendered with a [`TableState`] that has a
+    /// [filter](TableState::filter), the occurrences of the filter in the cells of the rows are
+    /// highlighted with this style, ignoring ASCII case. The header and footer are not
+    /// highlighted. See [`highlight_matches`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, style::{Style, Stylize}, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).match_style(Style::new().bold().yellow());
+    /// ```
+    /// [`Color`]: ratatui_core::style::Color
+    /// [`highlight_matches`]: crate::highlight::highlight_matches
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn match_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.match_style = Some(style.into());
+        self
+    }
+
+    /// Keep the rows at the given indices, in that order
+    ///
+    /// This is meant to be used with [`TableState::filtered_indices`], so that the table only
+    /// shows the rows that match the filter of the state. Indices that are out of bounds are
+    /// ignored. Does nothing if `indices` is `None`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// let data = [["Apples", "3"], ["Pears", "5"]];
+    /// let mut state = TableState::new().with_filter("pear");
+    /// state.apply_filter(data, |[name, _]| name.to_lowercase().contains("pear"));
+    ///
+    /// let table = Table::new(data.map(Row::new), [10, 10]).on_filter(state.filtered_indices());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn on_filter(mut self, indices: Option<&[usize]>) -> Self {
+        if let Some(indices) = indices {
+            let mut rows: Vec<Option<Row<'a>>> = self.rows.into_iter().map(Some).collect();
+            self.rows = indices
+                .iter()
+                .filter_map(|&index| rows.get_mut(index)?.take())
+                .collect();
+        }
+        self
+    }
+
     /// Set the symbol to be displayed in front of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -720,6 +901,96 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Draws lines between the columns and rows of the table, like a spreadsheet.
+    ///
+    /// Column separators are drawn in the spacing between the columns, so they need a
+    /// [`column_spacing`] of at least 1. Row dividers are drawn between the rows and between the
+    /// header or footer and the rows, each taking one line of height. See [`GridStyle`] for the
+    /// symbols and style of the lines.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`column_spacing`]: Self::column_spacing
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{GridStyle, Row, Table};
+    ///
+    /// let rows = [Row::new(["Alice", "32"]), Row::new(["Bob", "27"])];
+    /// let table = Table::new(rows, [8, 3])
+    ///     .header(Row::new(["Name", "Age"]))
+    ///     .grid(GridStyle::new());
+    /// // Name    │Age
+    /// // ────────┼───
+    /// // Alice   │32
+    /// // ────────┼───
+    /// // Bob     │27
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn grid(mut self, grid: GridStyle) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+}
+
+impl IntrinsicSize for Table<'_> {
+    /// Returns the total width of the columns when each column is as wide as its widest cell, plus
+    /// the column spacing, the width of the highlight symbol and the space taken by the [`Block`].
+    ///
+    /// Cells that span multiple columns are not taken into account. The highlight symbol is
+    /// included unless the [`HighlightSpacing`] is `Never`, so that selecting a row doesn't
+    /// truncate it.
+    fn width_hint(&self) -> Option<u16> {
+        let mut column_widths = vec![0; self.column_count()];
+        for row in self.rows.iter().chain(&self.header).chain(&self.footer) {
+            let mut column = 0;
+            for cell in &row.cells {
+                if let Some(width) = column_widths.get_mut(column)
+                    && cell.column_span == 1
+                {
+                    *width = (*width).max(cell.content_width());
+                }
+                column += usize::from(cell.column_span.max(1));
+            }
+        }
+        let spacing = usize::from(self.column_spacing) * column_widths.len().saturating_sub(1);
+        let selection_width = if self.highlight_spacing.should_add(true) {
+            self.highlight_symbol.width()
+        } else {
+            0
+        };
+        let width = column_widths.iter().sum::<usize>() + spacing + selection_width;
+        let width = u16::try_from(width).unwrap_or(u16::MAX);
+        Some(
+            self.block
+                .as_ref()
+                .map_or(width, |block| block.outer_width(width)),
+        )
+    }
+
+    /// Returns the total height of the header, rows and footer including their margins and the
+    /// row dividers of the grid, plus the space taken by the [`Block`].
+    fn height_hint(&self, _width: u16) -> Option<u16> {
+        let height = self
+            .header
+            .iter()
+            .chain(&self.rows)
+            .chain(&self.footer)
+            .map(Row::height_with_margin)
+            .fold(0, u16::saturating_add);
+        let dividers = self.rows.len().saturating_sub(1)
+            + usize::from(self.header.is_some())
+            + usize::from(self.footer.is_some());
+        let dividers = u16::try_from(dividers).unwrap_or(u16::MAX);
+        let height = height.saturating_add(dividers.saturating_mul(self.divider_height()));
+        Some(
+            self.block
+                .as_ref()
+                .map_or(height, |block| block.outer_height(height)),
+        )
+    }
 }
 
 impl Widget for Table<'_> {
@@ -772,46 +1043,122 @@ impl StatefulWidget for &Table<'_> {
 
         let selection_width = self.selection_width(state);
         let column_widths = self.get_column_widths(table_area.width, selection_width, column_count);
-        let (header_area, rows_area, footer_area) = self.layout(table_area);
-
-        self.render_header(header_area, buf, &column_widths);
-
-        self.render_rows(rows_area, buf, selection_width, state, &column_widths);
-
-        self.render_footer(footer_area, buf, &column_widths);
+        let (header, footer, areas) = self.scroll_layout(table_area, state);
+        let [
+            header_area,
+            header_divider,
+            rows_area,
+            footer_divider,
+            footer_area,
+        ] = areas;
+
+        Table::render_header(header, header_area, buf, &column_widths);
+
+        let mut bands = self.render_rows(rows_area, buf, selection_width, state, &column_widths);
+
+        Table::render_footer(footer, footer_area, buf, &column_widths);
+
+        if let Some(ref grid) = self.grid {
+            let mut dividers: Vec<u16> = bands.iter().skip(1).map(|(band, _)| band.y - 1).collect();
+            if let Some(header) = header {
+                let band = Rect {
+                    height: header_divider.y - table_area.y,
+                    ..table_area
+                };
+                bands.insert(0, (band, header));
+                dividers.push(header_divider.y);
+            }
+            if let Some(footer) = footer {
+                let bottom = (footer_area.bottom() + footer.bottom_margin).min(table_area.bottom());
+                let band = Rect {
+                    y: footer_divider.bottom(),
+                    height: bottom.saturating_sub(footer_divider.bottom()),
+                    ..table_area
+                };
+                bands.push((band, footer));
+                dividers.push(footer_divider.y);
+            }
+            if grid.rows {
+                self.render_grid_dividers(grid, buf, table_area, &column_widths, &bands, &dividers);
+            }
+            self.render_grid_separators(grid, buf, table_area, &column_widths, &bands);
+        }
     }
 }
 
 // private methods for rendering
 impl Table<'_> {
-    /// Splits the table area into a header, rows area and a footer
-    fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
-        let header_top_margin = self.header.as_ref().map_or(0, |h| h.top_margin);
-        let header_height = self.header.as_ref().map_or(0, |h| h.height);
-        let header_bottom_margin = self.header.as_ref().map_or(0, |h| h.bottom_margin);
-        let footer_top_margin = self.footer.as_ref().map_or(0, |h| h.top_margin);
-        let footer_height = self.footer.as_ref().map_or(0, |f| f.height);
-        let footer_bottom_margin = self.footer.as_ref().map_or(0, |h| h.bottom_margin);
+    /// Splits the table area into the header, rows and footer that are shown.
+    ///
+    /// A header or footer that is not sticky is hidden when it scrolls out of view, and a footer
+    /// that is not sticky is moved up to the last row.
+    fn scroll_layout(
+        &self,
+        area: Rect,
+        state: &TableState,
+    ) -> (Option<&Row<'_>>, Option<&Row<'_>>, [Rect; 5]) {
+        let mut header = self.header.as_ref();
+        let mut footer = self.footer.as_ref();
+        let mut areas = self.layout(area, header, footer);
+        if !self.sticky_header && header.is_some() && self.visible_rows(state, areas[2]).0 > 0 {
+            header = None;
+            areas = self.layout(area, header, footer);
+        }
+        if !self.sticky_footer && footer.is_some() {
+            let (start, end) = self.visible_rows(state, areas[2]);
+            let rows_height = self.rows[start..end]
+                .iter()
+                .map(|row| self.row_height(row))
+                .fold(0, u16::saturating_add)
+                .saturating_sub(self.divider_height());
+            if end < self.rows.len() || rows_height > areas[2].height {
+                footer = None;
+                areas = self.layout(area, header, footer);
+            } else {
+                // move the footer and its divider up, directly below the last row
+                let unused = areas[2].height - rows_height;
+                areas[2].height = rows_height;
+                areas[3].y -= unused;
+                areas[4].y -= unused;
+            }
+        }
+        (header, footer, areas)
+    }
+
+    /// Splits the table area into a header, the divider below the header, rows area, the divider
+    /// above the footer and a footer
+    ///
+    /// The dividers are empty unless the grid has row dividers.
+    fn layout(&self, area: Rect, header: Option<&Row>, footer: Option<&Row>) -> [Rect; 5] {
+        let header_top_margin = header.map_or(0, |h| h.top_margin);
+        let header_height = header.map_or(0, |h| h.height);
+        let header_bottom_margin = header.map_or(0, |h| h.bottom_margin);
+        let footer_top_margin = footer.map_or(0, |f| f.top_margin);
+        let footer_height = footer.map_or(0, |f| f.height);
+        let footer_bottom_margin = footer.map_or(0, |f| f.bottom_margin);
+        let header_divider = header.map_or(0, |_| self.divider_height());
+        let footer_divider = footer.map_or(0, |_| self.divider_height());
         let layout = Layout::vertical([
             Constraint::Length(header_top_margin),
             Constraint::Length(header_height),
             Constraint::Length(header_bottom_margin),
+            Constraint::Length(header_divider),
             Constraint::Min(0),
+            Constraint::Length(footer_divider),
             Constraint::Length(footer_top_margin),
             Constraint::Length(footer_height),
             Constraint::Length(footer_bottom_margin),
         ])
         .split(area);
-        let (header_area, rows_area, footer_area) = (layout[1], layout[3], layout[5]);
-        (header_area, rows_area, footer_area)
+        [layout[1], layout[3], layout[4], layout[5], layout[7]]
     }
 
     /// Render the header cells, if they are not `None`
     ///
     /// The `x` and `width` fields of each `Rect` in `column_widths` denote the starting
     /// x-coordinate and width of each column in the table.
-    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[Rect]) {
-        if let Some(ref header) = self.header {
+    fn render_header(header: Option<&Row>, area: Rect, buf: &mut Buffer, column_widths: &[Rect]) {
+        if let Some(header) = header {
             buf.set_style(area, header.style);
             for (cell_area, cell) in column_widths.iter().zip(header.cells.iter()) {
                 let new_x = area.x + cell_area.x;
@@ -825,8 +1172,8 @@ impl Table<'_> {
     ///
     /// The `x` and `width` fields of each `Rect` in `column_widths` denote the starting
     /// x-coordinate and width of each column in the table.
-    fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: &[Rect]) {
-        if let Some(ref footer) = self.footer {
+    fn render_footer(footer: Option<&Row>, area: Rect, buf: &mut Buffer, column_widths: &[Rect]) {
+        if let Some(footer) = footer {
             buf.set_style(area, footer.style);
             for (cell_area, cell) in column_widths.iter().zip(footer.cells.iter()) {
                 let new_x = area.x + cell_area.x;
@@ -840,6 +1187,9 @@ impl Table<'_> {
     ///
     /// The `x` and `width` fields of each `Rect` in `column_widths` denote the starting
     /// x-coordinate and width of each column in the table.
+    ///
+    /// Returns the rendered rows and their areas including their margins, which the grid is drawn
+    /// around.
     fn render_rows(
         &self,
         area: Rect,
@@ -847,9 +1197,10 @@ impl Table<'_> {
         selection_width: u16,
         state: &mut TableState,
         columns_widths: &[Rect],
-    ) {
+    ) -> Vec<(Rect, &Row<'_>)> {
+        let mut bands = Vec::new();
         if self.rows.is_empty() {
-            return;
+            return bands;
         }
 
         let (start_index, end_index) = self.visible_rows(state, area);
@@ -858,6 +1209,7 @@ impl Table<'_> {
         let mut y_offset = 0;
 
         let mut selected_row_area = None;
+        let mut editing_row_area = None;
         for (i, row) in self
             .rows
             .iter()
@@ -874,11 +1226,32 @@ impl Table<'_> {
             if selection_width > 0 && is_selected {
                 self.set_selection_style(buf, selection_width, row_area, row);
             }
-            self.render_row_cells(buf, columns_widths.iter().collect(), &row.cells, row_area);
+            self.render_row_cells(
+                buf,
+                columns_widths.iter().collect(),
+                &row.cells,
+                row_area,
+                state.filter(),
+            );
             if is_selected {
                 selected_row_area = Some(row_area);
             }
-            y_offset += row.height_with_margin();
+            if state.editing.is_some_and(|(index, _)| index == i) {
+                editing_row_area = Some(row_area);
+            }
+            let band_y = area.y + y_offset;
+            let band_height = (band_y + row.height_with_margin())
+                .min(area.bottom())
+                .saturating_sub(band_y);
+            bands.push((
+                Rect {
+                    y: band_y,
+                    height: band_height,
+                    ..area
+                },
+                row,
+            ));
+            y_offset += self.row_height(row);
         }
 
         let selected_column_area = state.selected_column.and_then(|s| {
@@ -906,6 +1279,28 @@ impl Table<'_> {
             }
             (None, None) => (),
         }
+
+        state.edit_input.cursor_position = None;
+        let editing_cell_area = state
+            .editing
+            .zip(editing_row_area)
+            .and_then(|((_, s), row)| {
+                columns_widths.get(s).map(|cell_area| Rect {
+                    x: cell_area.x + row.x,
+                    width: cell_area.width,
+                    ..row
+                })
+            });
+        if let Some(cell_area) = editing_cell_area {
+            Clear.render(cell_area, buf);
+            buf.set_style(cell_area, self.style.patch(self.editing_style));
+            TextInput::new().style(self.editing_style).render(
+                cell_area,
+                buf,
+                &mut state.edit_input,
+            );
+        }
+        bands
     }
 
     /// Render cells into the columns of a row
@@ -913,13 +1308,16 @@ impl Table<'_> {
     /// Render `Cell`s from `cells` into columns specified by `column_widths`, stopping
     /// if either of these iterators are finished.  Each `Cell` gets rendered across
     /// [`Cell::get_column_span`] columns plus the gaps between them, if this value is > 1.
+    /// The occurrences of `filter` are highlighted with the match style, if set.
     fn render_row_cells(
         &self,
         buf: &mut Buffer,
         column_widths: Vec<&Rect>,
         cells: &Vec<Cell>,
         row_area: Rect,
+        filter: &str,
     ) {
+        let match_style = self.match_style.filter(|_| !filter.is_empty());
         let mut column_widths_iterator = column_widths.into_iter();
         for current_cell in cells {
             if let Some(cell_area) = Self::get_cell_area(
@@ -929,7 +1327,12 @@ impl Table<'_> {
             ) {
                 let new_x = row_area.x + cell_area.x;
                 let area_to_render = Rect::new(new_x, row_area.y, cell_area.width, row_area.height);
-                current_cell.render(area_to_render, buf);
+                match match_style {
+                    Some(match_style) => {
+                        current_cell.render_matches(area_to_render, buf, filter, match_style);
+                    }
+                    None => current_cell.render(area_to_render, buf),
+                }
             }
         }
     }
@@ -1008,7 +1411,7 @@ impl Table<'_> {
             if height + item.height > area.height {
                 break;
             }
-            height += item.height_with_margin();
+            height += self.row_height(item);
             end += 1;
         }
 
@@ -1017,10 +1420,10 @@ impl Table<'_> {
 
             // scroll down until the selected row is visible
             while selected >= end {
-                height = height.saturating_add(self.rows[end].height_with_margin());
+                height = height.saturating_add(self.row_height(&self.rows[end]));
                 end += 1;
                 while height > area.height {
-                    height = height.saturating_sub(self.rows[start].height_with_margin());
+                    height = height.saturating_sub(self.row_height(&self.rows[start]));
                     start += 1;
                 }
             }
@@ -1064,6 +1467,122 @@ impl Table<'_> {
             .collect()
     }
 
+    /// Returns the height of the grid lines between rows, which is 1 when the grid has row
+    /// dividers, otherwise 0.
+    fn divider_height(&self) -> u16 {
+        u16::from(self.grid.is_some_and(|grid| grid.rows))
+    }
+
+    /// Returns the height of a row including its margins and the divider below it.
+    fn row_height(&self, row: &Row) -> u16 {
+        row.height_with_margin()
+            .saturating_add(self.divider_height())
+    }
+
+    /// Returns the x-coordinates of the column separators of the grid, one for each gap between
+    /// the columns, or none when the grid has no column separators.
+    fn grid_separators(&self, grid: &GridStyle, area: Rect, column_widths: &[Rect]) -> Vec<u16> {
+        if !grid.columns || self.column_spacing == 0 {
+            return Vec::new();
+        }
+        let offset = (self.column_spacing - 1) / 2;
+        column_widths
+            .iter()
+            .tuple_windows()
+            .map(|(column, _)| area.x + column.right() + offset)
+            .collect()
+    }
+
+    /// Returns whether each gap between the columns of a row has a separator, which is the case
+    /// unless a cell spans across the gap.
+    fn grid_gaps(row: &Row, column_count: usize) -> Vec<bool> {
+        let mut gaps = vec![true; column_count.saturating_sub(1)];
+        let mut column = 0;
+        for cell in &row.cells {
+            let span = usize::from(cell.column_span);
+            for gap in gaps.iter_mut().skip(column).take(span.saturating_sub(1)) {
+                *gap = false;
+            }
+            column += span;
+        }
+        gaps
+    }
+
+    /// Draws the column separators of the grid across the header, rows and footer.
+    fn render_grid_separators(
+        &self,
+        grid: &GridStyle,
+        buf: &mut Buffer,
+        area: Rect,
+        column_widths: &[Rect],
+        bands: &[(Rect, &Row)],
+    ) {
+        let separators = self.grid_separators(grid, area, column_widths);
+        for (band, row) in bands {
+            let gaps = Self::grid_gaps(row, column_widths.len());
+            for (&x, _) in separators.iter().zip(gaps).filter(|(_, gap)| *gap) {
+                if x >= band.right() {
+                    break;
+                }
+                for y in band.top()..band.bottom() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_symbol(grid.symbols.vertical).set_style(grid.style);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draws the row dividers of the grid, joining them to the column separators above and below.
+    fn render_grid_dividers(
+        &self,
+        grid: &GridStyle,
+        buf: &mut Buffer,
+        area: Rect,
+        column_widths: &[Rect],
+        bands: &[(Rect, &Row)],
+        dividers: &[u16],
+    ) {
+        let (Some(first), Some(last)) = (column_widths.first(), column_widths.last()) else {
+            return;
+        };
+        let left = area.x + first.x;
+        let right = (area.x + last.right()).min(area.right());
+        let separators = self.grid_separators(grid, area, column_widths);
+        let gaps_at = |y: u16, is_above: bool| {
+            bands
+                .iter()
+                .find(|(band, _)| {
+                    if is_above {
+                        band.bottom() == y
+                    } else {
+                        band.top() == y + 1
+                    }
+                })
+                .map(|(_, row)| Self::grid_gaps(row, column_widths.len()))
+                .unwrap_or_default()
+        };
+        for &y in dividers.iter().filter(|&&y| y < area.bottom()) {
+            for x in left..right {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_symbol(grid.symbols.horizontal)
+                        .set_style(grid.style);
+                }
+            }
+            let above = gaps_at(y, true);
+            let below = gaps_at(y, false);
+            for (gap, &x) in separators.iter().enumerate() {
+                let up = above.get(gap).copied().unwrap_or_default();
+                let down = below.get(gap).copied().unwrap_or_default();
+                if x < right
+                    && let Some(cell) = buf.cell_mut((x, y))
+                {
+                    cell.set_symbol(grid.junction(up, down));
+                }
+            }
+        }
+    }
+
     fn column_count(&self) -> usize {
         self.rows
             .iter()
@@ -1152,6 +1671,7 @@ mod tests {
         assert_eq!(table.highlight_symbol, Text::default());
         assert_eq!(table.highlight_spacing, HighlightSpacing::WhenSelected);
         assert_eq!(table.flex, Flex::Start);
+        assert_eq!(table.match_style, None);
     }
 
     #[test]
@@ -1219,6 +1739,16 @@ mod tests {
         assert_eq!(table.rows, rows);
     }
 
+    #[test]
+    fn sticky_header_and_footer() {
+        let table = Table::default();
+        assert!(table.sticky_header);
+        assert!(table.sticky_footer);
+        let table = table.sticky_header(false).sticky_footer(false);
+        assert!(!table.sticky_header);
+        assert!(!table.sticky_footer);
+    }
+
     #[test]
     fn column_spacing() {
         let table = Table::default().column_spacing(2);
@@ -1398,7 +1928,7 @@ mod tests {
 
     #[cfg(test)]
     mod render {
-        use ratatui_core::layout::Alignment;
+        use ratatui_core::layout::{Alignment, Position};
 
         use super::*;
 
@@ -1625,6 +2155,115 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_grid() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 7));
+            let header = Row::new(["A", "B", "C"]);
+            let footer = Row::new(["F", "G", "H"]);
+            let rows = [Row::new(["a1", "b1", "c1"]), Row::new(["a2", "b2", "c2"])];
+            let table = Table::new(rows, [3; 3])
+                .header(header)
+                .footer(footer)
+                .grid(GridStyle::new());
+            Widget::render(table, Rect::new(0, 0, 11, 7), &mut buf);
+            let expected = Buffer::with_lines([
+                "A  │B  │C  ",
+                "───┼───┼───",
+                "a1 │b1 │c1 ",
+                "───┼───┼───",
+                "a2 │b2 │c2 ",
+                "───┼───┼───",
+                "F  │G  │H  ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_grid_and_column_span() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 5));
+            let rows = [
+                Row::new([Cell::new("wide").column_span(2), Cell::new("c")]),
+                Row::new(["a", "b", "c"]),
+                Row::new([Cell::new("a"), Cell::new("wide").column_span(2)]),
+            ];
+            let table = Table::new(rows, [3; 3]).grid(GridStyle::new());
+            Widget::render(table, Rect::new(0, 0, 11, 5), &mut buf);
+            let expected = Buffer::with_lines([
+                "wide   │c  ",
+                "───┬───┼───",
+                "a  │b  │c  ",
+                "───┼───┴───",
+                "a  │wide   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_grid_columns_only() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 3));
+            let header = Row::new(["A", "B"]).bottom_margin(1);
+            let rows = [Row::new(["a", "b"])];
+            let grid = GridStyle::new().rows(false).style(Color::Red);
+            let table = Table::new(rows, [5; 2])
+                .header(header)
+                .column_spacing(3)
+                .grid(grid);
+            assert_eq!(table.height_hint(13), Some(3));
+            Widget::render(table, Rect::new(0, 0, 13, 3), &mut buf);
+            let mut expected =
+                Buffer::with_lines(["A     │ B    ", "      │      ", "a     │ b    "]);
+            expected.set_style(Rect::new(6, 0, 1, 3), Color::Red);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn grid_height_hint() {
+            let table = Table::new([Row::new(["a"]), Row::new(["b"])], [1])
+                .header(Row::new(["h"]))
+                .grid(GridStyle::new());
+            assert_eq!(table.height_hint(1), Some(5));
+        }
+
+        #[test]
+        fn render_with_scrolling_header() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            let rows = ["a", "b", "c", "d"].map(|cell| Row::new([cell]));
+            let table = Table::new(rows, [5])
+                .header(Row::new(["Head"]))
+                .sticky_header(false);
+            let mut state = TableState::new().with_offset(2);
+            StatefulWidget::render(&table, Rect::new(0, 0, 5, 3), &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["c    ", "d    ", "     "]));
+
+            let mut state = TableState::new();
+            StatefulWidget::render(&table, Rect::new(0, 0, 5, 3), &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["Head ", "a    ", "b    "]));
+        }
+
+        #[test]
+        fn render_with_scrolling_footer() {
+            let rows = ["a", "b", "c"].map(|cell| Row::new([cell]));
+            let table = Table::new(rows, [5])
+                .footer(Row::new(["Total"]))
+                .sticky_footer(false);
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut TableState::new());
+            assert_eq!(buf, Buffer::with_lines(["a    ", "b    ", "c    "]));
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            let mut state = TableState::new().with_offset(1);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["b    ", "c    ", "Total"]));
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 6));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut TableState::new());
+            assert_eq!(
+                buf,
+                Buffer::with_lines(["a    ", "b    ", "c    ", "Total", "     ", "     "])
+            );
+        }
+
         #[test]
         fn render_with_row_margin() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1771,6 +2410,58 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_filter() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let data = [["Apple", "red"], ["Pear", "green"], ["Grape", "green"]];
+            let mut state = TableState::new().with_selected(Some(2)).with_filter("p");
+            state.apply_filter(data, |[name, _]| name.to_lowercase().contains('p'));
+            let table = Table::new(data.map(Row::new), [Constraint::Length(6); 2])
+                .highlight_symbol(">>")
+                .match_style(Style::new().bold())
+                .on_filter(state.filtered_indices());
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines::<[Line; 3]>([
+                Line::from(vec!["  A".into(), "pp".bold(), "le  red   ".into()]),
+                Line::from(vec!["  ".into(), "P".bold(), "ear   green ".into()]),
+                Line::from(vec![">>Gra".into(), "p".bold(), "e  green ".into()]),
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.selected_unfiltered(), Some(2));
+        }
+
+        #[test]
+        fn render_with_editing_cell() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2", "Cell3"]),
+                Row::new(vec!["Cell4", "Cell5", "Cell6"]),
+                Row::new(vec!["Cell7", "Cell8", "Cell9"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 3])
+                .highlight_symbol(">>")
+                .editing_style(Style::new().yellow());
+            let mut state = TableState::new().with_selected_cell((1, 1));
+            state.start_editing("42");
+            StatefulWidget::render(table, Rect::new(0, 0, 20, 3), &mut buf, &mut state);
+            let expected = Buffer::with_lines::<[Line; 3]>([
+                Line::from(vec!["  Cell1 ".into(), "Cell2 ".into(), "Cell3 ".into()]),
+                Line::from(vec![">>Cell4 ".into(), "42   ".yellow(), " Cell6 ".into()]),
+                Line::from(vec!["  Cell7 ".into(), "Cell8 ".into(), "Cell9 ".into()]),
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.cursor_position(), Some(Position::new(10, 1)));
+
+            // the cursor is hidden when the edited row is scrolled out of view
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+            let table = Table::new([Row::new(["a"]), Row::new(["b"])], [Constraint::Length(5)]);
+            let mut state = TableState::new().with_selected_cell((1, 0));
+            state.start_editing("b");
+            state.select(Some(0));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.cursor_position(), None);
+        }
+
         #[test]
         fn render_with_selected_row_and_column() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
@@ -2714,4 +3405,20 @@ mod tests {
         StatefulWidget::render(table, area, &mut buf, &mut state);
         assert_eq!(buf, Buffer::with_lines(expected));
     }
+
+    #[test]
+    fn intrinsic_size() {
+        let table = Table::default()
+            .header(Row::new(["Name", "Age"]).bottom_margin(1))
+            .rows([Row::new(["Alice", "30"]), Row::new(["Bob", "100"])])
+            .column_spacing(2);
+        assert_eq!(table.width_hint(), Some(10));
+        assert_eq!(table.height_hint(10), Some(4));
+
+        let table = table
+            .highlight_symbol(">> ")
+            .block(Block::bordered().title("People"));
+        assert_eq!(table.width_hint(), Some(15));
+        assert_eq!(table.height_hint(10), Some(6));
+    }
 }
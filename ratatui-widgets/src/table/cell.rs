@@ -4,6 +4,8 @@ use ratatui_core::style::{Style, Styled};
 use ratatui_core::text::Text;
 use ratatui_core::widgets::Widget;
 
+use crate::highlight::highlight_matches;
+
 /// A [`Cell`] contains the [`Text`] to be displayed in a [`Row`] of a [`Table`].
 ///
 /// You can apply a [`Style`] to the [`Cell`] using [`Cell::style`]. This will set the style for the
@@ -176,10 +178,21 @@ impl<'a> Cell<'a> {
 }
 
 impl Cell<'_> {
+    /// Returns the width of the widest line of the content.
+    pub(crate) fn content_width(&self) -> usize {
+        self.content.width()
+    }
+
     pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
         Widget::render(&self.content, area, buf);
     }
+
+    /// Renders the cell with `style` added to the occurrences of `pattern`
+    pub(crate) fn render_matches(&self, area: Rect, buf: &mut Buffer, pattern: &str, style: Style) {
+        buf.set_style(area, self.style);
+        Widget::render(highlight_matches(&self.content, pattern, style), area, buf);
+    }
 }
 
 impl<'a, T> From<T> for Cell<'a>
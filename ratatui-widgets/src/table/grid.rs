@@ -0,0 +1,115 @@
+use ratatui_core::style::Style;
+use ratatui_core::symbols::line;
+
+/// The lines that a [`Table`] draws between its columns and rows.
+///
+/// Column separators are vertical lines drawn in the spacing between the columns (see
+/// [`Table::column_spacing`]), so they need a column spacing of at least 1. Row dividers are
+/// horizontal lines drawn between the rows, and between the header or footer and the rows. Each
+/// divider takes one line of height. Where the lines meet, they are joined with the matching
+/// junction symbols (`┼`, `┬` or `┴`). Cells that span several columns are not crossed by column
+/// separators.
+///
+/// By default, both kinds of lines are drawn with the [`line::NORMAL`] symbols.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::symbols::line;
+/// use ratatui::widgets::{GridStyle, Row, Table};
+///
+/// let rows = [Row::new(["a", "b"]), Row::new(["c", "d"])];
+/// let table = Table::new(rows, [5, 5]).grid(
+///     GridStyle::new()
+///         .symbols(line::ROUNDED)
+///         .style(Style::new().dark_gray()),
+/// );
+///
+/// // only the separators between the columns
+/// let table = table.grid(GridStyle::new().rows(false));
+/// ```
+///
+/// [`Table`]: crate::table::Table
+/// [`Table::column_spacing`]: crate::table::Table::column_spacing
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct GridStyle {
+    /// Whether separators are drawn between the columns
+    pub columns: bool,
+    /// Whether dividers are drawn between the rows
+    pub rows: bool,
+    /// The symbols of the lines and their junctions
+    pub symbols: line::Set<'static>,
+    /// The style of the lines
+    pub style: Style,
+}
+
+impl Default for GridStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GridStyle {
+    /// Creates a grid with column separators and row dividers drawn with the [`line::NORMAL`]
+    /// symbols.
+    pub const fn new() -> Self {
+        Self {
+            columns: true,
+            rows: true,
+            symbols: line::NORMAL,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets whether separators are drawn between the columns.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn columns(mut self, columns: bool) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets whether dividers are drawn between the rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn rows(mut self, rows: bool) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the symbols of the lines, e.g. [`line::DOUBLE`] or [`line::THICK`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbols(mut self, symbols: line::Set<'static>) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets the style of the lines.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the symbol where a row divider meets the column separators above and below it.
+    pub(crate) const fn junction(&self, up: bool, down: bool) -> &'static str {
+        match (up, down) {
+            (true, true) => self.symbols.cross,
+            (true, false) => self.symbols.horizontal_up,
+            (false, true) => self.symbols.horizontal_down,
+            (false, false) => self.symbols.horizontal,
+        }
+    }
+}
@@ -1,3 +1,10 @@
+use alloc::string::String;
+
+use ratatui_core::layout::Position;
+
+use crate::filter::Filter;
+use crate::input::TextInputState;
+
 /// State of a [`Table`] widget
 ///
 /// This state can be used to scroll through the rows and select one of them. When the table is
@@ -5,15 +12,19 @@
 /// table will be shifted to ensure that the selected row is visible. This will modify the
 /// [`TableState`] object passed to the `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of the following fields:
 /// - [`offset`]: the index of the first row to be displayed
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
 /// - [`selected_column`]: the index of the selected column, which can be `None` if no column is
 ///   selected
+/// - [`editing_cell`]: the indexes of the cell being edited, see [Editing](#editing)
+/// - [`filter`]: the filter entered by the user, see [Filtering](#filtering)
 ///
 /// [`offset`]: TableState::offset()
 /// [`selected`]: TableState::selected()
 /// [`selected_column`]: TableState::selected_column()
+/// [`editing_cell`]: TableState::editing_cell()
+/// [`filter`]: TableState::filter()
 ///
 /// See the `table` example and the `recipe` and `traceroute` tabs in the demo2 example in the
 /// [Examples] directory for a more in depth example of the various configuration options and for
@@ -48,14 +59,93 @@
 /// Note that if [`Table::widths`] is not called before rendering, the rendered columns will have
 /// equal width.
 ///
+/// # Editing
+///
+/// The selected cell can be edited in place, e.g. to build a spreadsheet-like editor.
+/// [`TableState::start_editing`] starts editing the selected cell with its current value, after
+/// which the cell is rendered as a text input styled with [`Table::editing_style`]. Key presses
+/// are passed to the [`TextInputState`] returned by [`TableState::edit_input_mut`], and
+/// [`TableState::cursor_position`] returns where the terminal cursor should be placed after
+/// rendering. [`TableState::finish_editing`] returns the edited value to store in the
+/// application data, while [`TableState::cancel_editing`] discards it.
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::{Constraint, Rect};
+/// use ratatui::widgets::{Row, Table, TableState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let mut data = vec![["Apples", "3"], ["Pears", "5"]];
+/// let mut state = TableState::new().with_selected_cell((1, 1));
+///
+/// state.start_editing(data[1][1]);
+/// if let Some(input) = state.edit_input_mut() {
+///     input.insert_char('0');
+/// }
+///
+/// let table = Table::new(data.iter().map(|row| Row::new(*row)), [10, 10]);
+/// frame.render_stateful_widget(table, area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor_position(position);
+/// }
+///
+/// if let Some(((row, column), value)) = state.finish_editing() {
+///     assert_eq!((row, column, value.as_str()), (1, 1, "50"));
+/// }
+/// # }
+/// ```
+///
+/// # Filtering
+///
+/// The state can hold a filter, e.g. the text typed in a search box above the table. Set it with
+/// [`TableState::set_filter`], then call [`TableState::apply_filter`] with the rows and a function
+/// that returns whether a row matches. The state remembers the indices of the matching rows,
+/// which are passed to [`Table::on_filter`] to only render those rows, and keeps the same row
+/// selected when the filter changes. While a filter is applied, the selected row refers to the
+/// filtered rows, and [`TableState::selected_unfiltered`] returns the index of the selected row
+/// in the application data. [`Table::match_style`] highlights the filter in the rendered cells.
+///
+/// ```rust
+/// use ratatui::widgets::{Row, Table, TableState};
+///
+/// let data = [["Apples", "3"], ["Pears", "5"], ["Plums", "2"]];
+/// let mut state = TableState::new().with_selected(Some(2));
+///
+/// state.set_filter("p");
+/// let filter = state.filter().to_lowercase();
+/// state.apply_filter(data, |[name, _]| name.to_lowercase().starts_with(&filter));
+/// assert_eq!(state.selected(), Some(1));
+/// assert_eq!(state.selected_unfiltered(), Some(2));
+///
+/// let table = Table::new(data.map(Row::new), [10, 10]).on_filter(state.filtered_indices());
+/// ```
+///
 /// [`Table`]: super::Table
 /// [`Table::widths`]: crate::table::Table::widths
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+/// [`Table::editing_style`]: crate::table::Table::editing_style
+/// [`Table::on_filter`]: crate::table::Table::on_filter
+/// [`Table::match_style`]: crate::table::Table::match_style
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
     pub(crate) selected_column: Option<usize>,
+    /// The row and column indexes of the cell being edited
+    ///
+    /// Editing is transient, so it is not saved with the rest of the state.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) editing: Option<(usize, usize)>,
+    /// The value and cursor of the cell being edited
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) edit_input: TextInputState,
+    /// The filter and the indices of the matching items
+    ///
+    /// The filter is applied to the items of the application, so it is not saved with the rest of
+    /// the state.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) filter: Filter,
 }
 
 impl TableState {
@@ -73,6 +163,9 @@ impl TableState {
             offset: 0,
             selected: None,
             selected_column: None,
+            editing: None,
+            edit_input: TextInputState::new(),
+            filter: Filter::new(),
         }
     }
 
@@ -531,6 +624,292 @@ impl TableState {
         let selected = self.selected_column.unwrap_or_default();
         self.select_column(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Starts editing the selected cell, with `value` as the initial value of the input
+    ///
+    /// The cursor is placed at the end of the value. Does nothing if no cell is selected. If a
+    /// cell is already being edited, its edit is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected_cell((0, 1));
+    /// state.start_editing("42");
+    /// assert_eq!(state.editing_cell(), Some((0, 1)));
+    /// ```
+    pub fn start_editing<T: Into<String>>(&mut self, value: T) {
+        if let Some(cell) = self.selected_cell() {
+            self.editing = Some(cell);
+            self.edit_input = TextInputState::new().with_value(value);
+        }
+    }
+
+    /// Returns true if a cell is being edited
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert!(!state.is_editing());
+    /// ```
+    pub const fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    /// Returns the row and column indexes of the cell being edited, or `None` if no cell is being
+    /// edited
+    ///
+    /// This is the cell that was selected when [`TableState::start_editing`] was called, even if
+    /// the selection has changed since then.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.editing_cell(), None);
+    /// ```
+    pub const fn editing_cell(&self) -> Option<(usize, usize)> {
+        self.editing
+    }
+
+    /// Returns the input of the cell being edited, or `None` if no cell is being edited
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected_cell((0, 0));
+    /// state.start_editing("abc");
+    /// assert_eq!(state.edit_input().map(|input| input.value()), Some("abc"));
+    /// ```
+    pub const fn edit_input(&self) -> Option<&TextInputState> {
+        if self.editing.is_some() {
+            Some(&self.edit_input)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the input of the cell being edited, or `None` if no cell is
+    /// being edited
+    ///
+    /// Use this to pass key presses to the input, e.g. with [`TextInputState::insert_char`] and
+    /// [`TextInputState::delete_backward`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected_cell((0, 0));
+    /// state.start_editing("abc");
+    /// if let Some(input) = state.edit_input_mut() {
+    ///     input.delete_backward();
+    /// }
+    /// ```
+    pub const fn edit_input_mut(&mut self) -> Option<&mut TextInputState> {
+        if self.editing.is_some() {
+            Some(&mut self.edit_input)
+        } else {
+            None
+        }
+    }
+
+    /// Returns where the terminal cursor should be placed to edit the cell, or `None` if no cell
+    /// is being edited or the cell was not visible when the table was last rendered
+    ///
+    /// Pass this to `Frame::set_cursor_position` after rendering the table.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        if self.editing.is_some() {
+            self.edit_input.cursor_position()
+        } else {
+            None
+        }
+    }
+
+    /// Stops editing and returns the indexes of the edited cell and its new value, or `None` if no
+    /// cell is being edited
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected_cell((2, 1));
+    /// state.start_editing("abc");
+    /// assert_eq!(state.finish_editing(), Some(((2, 1), "abc".to_string())));
+    /// assert!(!state.is_editing());
+    /// ```
+    pub fn finish_editing(&mut self) -> Option<((usize, usize), String)> {
+        let cell = self.editing.take()?;
+        let input = core::mem::take(&mut self.edit_input);
+        Some((cell, input.value))
+    }
+
+    /// Stops editing and discards the edited value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected_cell((0, 0));
+    /// state.start_editing("abc");
+    /// state.cancel_editing();
+    /// assert_eq!(state.edit_input(), None);
+    /// ```
+    pub fn cancel_editing(&mut self) {
+        self.editing = None;
+        self.edit_input = TextInputState::new();
+    }
+
+    /// Sets the filter
+    ///
+    /// The filter is only applied to the rows by [`TableState::apply_filter`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_filter("foo");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_filter<T: Into<String>>(mut self, filter: T) -> Self {
+        self.filter.text = filter.into();
+        self
+    }
+
+    /// The filter, which is empty if no filter is set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.filter(), "");
+    /// ```
+    pub fn filter(&self) -> &str {
+        &self.filter.text
+    }
+
+    /// Sets the filter
+    ///
+    /// The filter is only applied to the rows by [`TableState::apply_filter`], which should be
+    /// called after the filter changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_filter("foo");
+    /// assert_eq!(state.filter(), "foo");
+    /// ```
+    pub fn set_filter<T: Into<String>>(&mut self, filter: T) {
+        self.filter.text = filter.into();
+    }
+
+    /// Keeps the rows for which `matches` returns true and returns their indices
+    ///
+    /// `matches` is usually a function of the [filter](TableState::filter), but can also filter on
+    /// other criteria. The indices are remembered and returned by
+    /// [`TableState::filtered_indices`], to pass to [`Table::on_filter`].
+    ///
+    /// The selected row stays selected if it matches, otherwise the next matching row is selected.
+    /// The offset is reset and any edit is cancelled, see [`TableState::cancel_editing`].
+    ///
+    /// [`Table::on_filter`]: super::Table::on_filter
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_filter("b");
+    /// let filter = state.filter().to_string();
+    /// let indices = state.apply_filter(["a", "b", "ab"], |row| row.contains(&filter));
+    /// assert_eq!(indices, [1, 2]);
+    /// ```
+    pub fn apply_filter<I, F>(&mut self, rows: I, matches: F) -> &[usize]
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> bool,
+    {
+        self.selected = self.filter.apply(self.selected, rows, matches);
+        self.offset = 0;
+        self.cancel_editing();
+        self.filter.indices.as_deref().unwrap_or_default()
+    }
+
+    /// Removes the filter and shows all rows again
+    ///
+    /// The selected row stays selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_filter("b");
+    /// state.apply_filter(["a", "b"], |row| row == "b");
+    /// state.clear_filter();
+    /// assert_eq!(state.filter(), "");
+    /// assert_eq!(state.filtered_indices(), None);
+    /// ```
+    pub fn clear_filter(&mut self) {
+        self.selected = self.filter.clear(self.selected);
+        self.offset = 0;
+        self.cancel_editing();
+    }
+
+    /// The indices of the rows that matched when the filter was last applied, or `None` if no
+    /// filter is applied
+    ///
+    /// Pass this to [`Table::on_filter`] to only render the matching rows.
+    ///
+    /// [`Table::on_filter`]: super::Table::on_filter
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.filtered_indices(), None);
+    /// ```
+    pub fn filtered_indices(&self) -> Option<&[usize]> {
+        self.filter.indices.as_deref()
+    }
+
+    /// Index of the selected row in the unfiltered rows
+    ///
+    /// This is the same as [`TableState::selected`] if no filter is applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected(Some(0));
+    /// state.apply_filter(["a", "b"], |row| row == "b");
+    /// assert_eq!(state.selected(), Some(0));
+    /// assert_eq!(state.selected_unfiltered(), Some(1));
+    /// ```
+    pub fn selected_unfiltered(&self) -> Option<usize> {
+        self.filter.unfiltered_index(self.selected?)
+    }
 }
 
 #[cfg(test)]
@@ -543,6 +922,7 @@ mod tests {
         assert_eq!(state.offset, 0);
         assert_eq!(state.selected, None);
         assert_eq!(state.selected_column, None);
+        assert_eq!(state.editing, None);
     }
 
     #[test]
@@ -742,4 +1122,33 @@ mod tests {
         state.scroll_left_by(20);
         assert_eq!(state.selected_column, Some(80));
     }
+
+    #[test]
+    fn editing() {
+        let mut state = TableState::new();
+        state.start_editing("ignored"); // nothing is selected
+        assert!(!state.is_editing());
+        assert_eq!(state.edit_input_mut(), None);
+
+        state.select_cell(Some((1, 2)));
+        state.start_editing("ab");
+        state.select_next(); // the edited cell doesn't follow the selection
+        let input = state.edit_input_mut().unwrap();
+        input.insert_char('c');
+        assert_eq!(input.value(), "abc");
+        assert_eq!(state.editing_cell(), Some((1, 2)));
+
+        assert_eq!(state.finish_editing(), Some(((1, 2), "abc".into())));
+        assert_eq!(state.finish_editing(), None);
+        assert_eq!(state.cursor_position(), None);
+    }
+
+    #[test]
+    fn cancel_editing() {
+        let mut state = TableState::new().with_selected_cell((0, 0));
+        state.start_editing("abc");
+        state.cancel_editing();
+        assert!(!state.is_editing());
+        assert_eq!(state.finish_editing(), None);
+    }
 }
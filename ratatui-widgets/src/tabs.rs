@@ -1,16 +1,18 @@
 //! The [`Tabs`] widget displays a horizontal set of tabs with a single tab selected.
+use alloc::string::ToString;
 use alloc::vec::Vec;
 
 use itertools::Itertools;
+use ratatui_core::accessibility::{Accessible, Role, SemanticNode, Semantics};
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::symbols;
 use ratatui_core::text::{Line, Span};
-use ratatui_core::widgets::Widget;
+use ratatui_core::widgets::{IntrinsicSize, Widget};
 use unicode_width::UnicodeWidthStr;
 
-use crate::block::{Block, BlockExt};
+use crate::block::{Block, BlockExt, describe_block};
 
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().reversed();
 
@@ -240,6 +242,26 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    /// Applies the styles of a [`Theme`] to the widget and its block.
+    ///
+    /// The [`Theme::TEXT`] style is used as the [`style`](Tabs::style) and [`Theme::HIGHLIGHT`] as
+    /// the [`highlight_style`](Tabs::highlight_style). Styles that are not defined by the theme
+    /// are left unchanged. The block is themed with [`Block::theme`], so it must be set before
+    /// calling this method.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        if let Some(style) = theme.get(Theme::TEXT) {
+            self.style = style;
+        }
+        if let Some(style) = theme.get(Theme::HIGHLIGHT) {
+            self.highlight_style = style;
+        }
+        self.block = self.block.map(|block| block.theme(theme));
+        self
+    }
+
     /// Sets the style for the highlighted tab.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -372,6 +394,22 @@ impl Widget for Tabs<'_> {
     }
 }
 
+/// Describes the tabs as a tab list with a tab for each title, of which the selected tab is marked
+/// as selected.
+impl Accessible for Tabs<'_> {
+    fn describe(&self, area: Rect, semantics: &mut Semantics) {
+        let level = describe_block(self.block.as_ref(), area, semantics);
+        let inner = self.block.inner_if_some(area);
+        semantics.push(SemanticNode::new(Role::TabList, inner).level(level));
+        semantics.extend(self.titles.iter().enumerate().map(|(i, title)| {
+            SemanticNode::new(Role::Tab, inner)
+                .label(title.to_string())
+                .selected(self.selected == Some(i))
+                .level(level + 1)
+        }));
+    }
+}
+
 impl Widget for &Tabs<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
@@ -447,6 +485,24 @@ where
     }
 }
 
+impl IntrinsicSize for Tabs<'_> {
+    /// Returns the width of the titles, dividers and padding, plus the space taken by the
+    /// [`Block`].
+    fn width_hint(&self) -> Option<u16> {
+        let width = u16::try_from(self.width()).unwrap_or(u16::MAX);
+        Some(
+            self.block
+                .as_ref()
+                .map_or(width, |block| block.outer_width(width)),
+        )
+    }
+
+    /// Returns the height of a single row of tabs, plus the space taken by the [`Block`].
+    fn height_hint(&self, _width: u16) -> Option<u16> {
+        Some(self.block.as_ref().map_or(1, |block| block.outer_height(1)))
+    }
+}
+
 impl UnicodeWidthStr for Tabs<'_> {
     /// Returns the width of the rendered tabs.
     ///
@@ -513,6 +569,17 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn intrinsic_size() {
+        let tabs = Tabs::new(["Tab1", "Tab2"]);
+        assert_eq!(tabs.width_hint(), Some(13));
+        assert_eq!(tabs.height_hint(13), Some(1));
+
+        let tabs = tabs.block(Block::bordered());
+        assert_eq!(tabs.width_hint(), Some(15));
+        assert_eq!(tabs.height_hint(15), Some(3));
+    }
+
     #[test]
     fn new() {
         let titles = vec!["Tab1", "Tab2", "Tab3", "Tab4"];
@@ -802,4 +869,15 @@ mod tests {
         let rendered = "左你右分左好右分左世界右";
         assert_eq!(tabs.width_cjk(), UnicodeWidthStr::width_cjk(rendered));
     }
+
+    #[test]
+    fn describe() {
+        let tabs = Tabs::new(["One", "Two"]).select(1);
+        let mut semantics = Semantics::new();
+        tabs.describe(Rect::new(0, 0, 10, 1), &mut semantics);
+        assert_eq!(
+            semantics.to_string(),
+            "tab list\n  tab: One\n  tab: Two, selected\n"
+        );
+    }
 }
@@ -0,0 +1,517 @@
+//! The [`TerminalPane`] widget shows the screen of a terminal emulator, such as a shell running in
+//! a PTY.
+//!
+//! [`TerminalPaneState`] holds a [`vt100`] parser that interprets the output of a program (text and
+//! escape sequences) into a screen of styled cells, which [`TerminalPane`] renders into its area.
+//! On Unix, [`TerminalPaneState::spawn`] starts a child process in a new PTY and feeds its output
+//! to the parser, which makes it possible to embed shells and other terminal programs in an
+//! application, like a terminal multiplexer does.
+//!
+//! This module is only available when the `terminal-pane` feature is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # #[cfg(unix)] {
+//! use std::process::Command;
+//!
+//! use ratatui::Frame;
+//! use ratatui::widgets::Block;
+//! use ratatui_widgets::terminal_pane::{TerminalPane, TerminalPaneState};
+//!
+//! let mut state = TerminalPaneState::spawn(Command::new("bash"), 24, 80)?;
+//!
+//! # fn draw(frame: &mut Frame, state: &mut TerminalPaneState) {
+//! // in the event loop, read the output of the process before drawing
+//! state.update();
+//! let pane = TerminalPane::new().block(Block::bordered().title("bash"));
+//! frame.render_stateful_widget(pane, frame.area(), state);
+//! if let Some(position) = state.cursor_position() {
+//!     frame.set_cursor_position(position);
+//! }
+//! # }
+//!
+//! // and forward the keys that are typed to the process
+//! state.write_input(b"ls\r")?;
+//! # }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+use core::fmt;
+use std::io;
+#[cfg(unix)]
+use std::process::{Command, ExitStatus};
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::{Color, Modifier, Style, Styled};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+
+use crate::block::{Block, BlockExt};
+
+#[cfg(unix)]
+mod pty;
+
+/// The number of rows kept in the scrollback of a [`TerminalPaneState`] by default.
+const DEFAULT_SCROLLBACK_LEN: usize = 1000;
+
+/// A widget that shows the screen of a [`TerminalPaneState`].
+///
+/// The screen is resized to the area of the pane (inside the [`Block`], if any) when it is
+/// rendered, which also resizes the PTY of the child process, so the program can redraw itself at
+/// the new size. Cells with the default colors of the terminal are drawn with the
+/// [`style`](Self::style) of the pane.
+///
+/// The cursor isn't drawn by the widget. After rendering, set the cursor of the [`Frame`] to
+/// [`TerminalPaneState::cursor_position`] to show it.
+///
+/// See the [module documentation](self) for an example.
+///
+/// [`Frame`]: https://docs.rs/ratatui/latest/ratatui/struct.Frame.html
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TerminalPane<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+}
+
+/// The state of a [`TerminalPane`]: the screen of a terminal emulator, and optionally the child
+/// process whose output it shows.
+///
+/// Output can be written to the screen directly with [`process`](Self::process), e.g. to show a
+/// recording or the output of a remote program. A state created with [`spawn`](Self::spawn) owns
+/// a child process in a PTY, whose output is added to the screen by [`update`](Self::update). The
+/// process is killed when the state is dropped.
+pub struct TerminalPaneState {
+    parser: vt100::Parser,
+    #[cfg(unix)]
+    process: Option<pty::PtyProcess>,
+    cursor_position: Option<Position>,
+}
+
+impl<'a> TerminalPane<'a> {
+    /// Creates a new pane.
+    pub const fn new() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+        }
+    }
+
+    /// Surrounds the pane with a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the pane.
+    ///
+    /// The default foreground and background colors of the terminal are drawn with this style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl TerminalPaneState {
+    /// Creates the state of an empty screen with the given number of rows and columns.
+    ///
+    /// The screen is resized to the area of the pane when it is rendered.
+    pub fn new(rows: u16, cols: u16) -> Self {
+        Self {
+            parser: vt100::Parser::new(rows, cols, DEFAULT_SCROLLBACK_LEN),
+            #[cfg(unix)]
+            process: None,
+            cursor_position: None,
+        }
+    }
+
+    /// Starts the command as a child process in a new PTY with the given number of rows and
+    /// columns, and returns a state that shows its output.
+    ///
+    /// The standard input, output and error of the command are connected to the PTY, and `TERM`
+    /// is set to `xterm-256color` unless the command sets it. The output of the process is read by
+    /// a background thread and added to the screen by [`update`](Self::update).
+    ///
+    /// The process doesn't become the session leader of the PTY, as that requires code that runs
+    /// between `fork` and `exec`, which this crate doesn't use as it forbids unsafe code. Programs
+    /// that need the PTY to be their controlling terminal, such as shells with job control, can
+    /// be started through `setsid --ctty` on Linux.
+    ///
+    /// This is only available on Unix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PTY can't be opened or the command can't be started.
+    #[cfg(unix)]
+    pub fn spawn(command: Command, rows: u16, cols: u16) -> io::Result<Self> {
+        let process = pty::PtyProcess::spawn(command, rows, cols)?;
+        let mut state = Self::new(rows, cols);
+        state.process = Some(process);
+        Ok(state)
+    }
+
+    /// Sets the number of rows that are kept in the scrollback.
+    ///
+    /// This clears the screen. Defaults to 1000 rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_scrollback_len(mut self, len: usize) -> Self {
+        let (rows, cols) = self.parser.screen().size();
+        self.parser = vt100::Parser::new(rows, cols, len);
+        self
+    }
+
+    /// Adds the output of a program, i.e. text and escape sequences, to the screen.
+    pub fn process(&mut self, output: &[u8]) {
+        self.parser.process(output);
+    }
+
+    /// Adds the output that the child process wrote since the last call to the screen, without
+    /// blocking.
+    ///
+    /// Returns `true` if there was new output, in which case the pane should be redrawn. Does
+    /// nothing if the state has no child process.
+    pub fn update(&mut self) -> bool {
+        #[cfg(unix)]
+        if let Some(process) = &mut self.process {
+            let chunks = process.read_output();
+            for chunk in &chunks {
+                self.parser.process(chunk);
+            }
+            return !chunks.is_empty();
+        }
+        false
+    }
+
+    /// Writes input, such as the bytes of typed keys, to the child process.
+    ///
+    /// Does nothing if the state has no child process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the PTY fails.
+    pub fn write_input(&mut self, input: &[u8]) -> io::Result<()> {
+        #[cfg(unix)]
+        if let Some(process) = &mut self.process {
+            return process.write_input(input);
+        }
+        let _ = input;
+        Ok(())
+    }
+
+    /// Returns whether the output of the child process has ended, which happens when the process
+    /// and any children that inherited the PTY have exited.
+    ///
+    /// Returns `true` if the state has no child process.
+    pub const fn is_finished(&self) -> bool {
+        #[cfg(unix)]
+        if let Some(process) = &self.process {
+            return process.is_closed();
+        }
+        true
+    }
+
+    /// Returns the exit status of the child process if it has exited, without blocking.
+    ///
+    /// Returns `None` if the process is still running or the state has no child process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the status of the process can't be queried.
+    #[cfg(unix)]
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        match &mut self.process {
+            Some(process) => process.try_wait(),
+            None => Ok(None),
+        }
+    }
+
+    /// Kills the child process.
+    ///
+    /// Does nothing if the state has no child process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process can't be killed.
+    #[cfg(unix)]
+    pub fn kill(&mut self) -> io::Result<()> {
+        match &mut self.process {
+            Some(process) => process.kill(),
+            None => Ok(()),
+        }
+    }
+
+    /// Resizes the screen, and the PTY of the child process if there is one.
+    ///
+    /// This is done automatically when the pane is rendered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PTY can't be resized.
+    pub fn resize(&mut self, rows: u16, cols: u16) -> io::Result<()> {
+        if self.parser.screen().size() == (rows, cols) {
+            return Ok(());
+        }
+        self.parser.screen_mut().set_size(rows, cols);
+        #[cfg(unix)]
+        if let Some(process) = &self.process {
+            process.resize(rows, cols)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of rows and columns of the screen.
+    pub fn size(&self) -> (u16, u16) {
+        self.parser.screen().size()
+    }
+
+    /// Returns the screen of the terminal emulator.
+    ///
+    /// The screen gives access to the contents of the terminal and to the modes set by the
+    /// program, e.g. [`vt100::Screen::application_cursor`] to encode the arrow keys accordingly.
+    pub fn screen(&self) -> &vt100::Screen {
+        self.parser.screen()
+    }
+
+    /// Scrolls the view up into the scrollback by the given number of rows.
+    pub fn scroll_up(&mut self, rows: usize) {
+        let offset = self.parser.screen().scrollback();
+        self.parser
+            .screen_mut()
+            .set_scrollback(offset.saturating_add(rows));
+    }
+
+    /// Scrolls the view down towards the current screen by the given number of rows.
+    pub fn scroll_down(&mut self, rows: usize) {
+        let offset = self.parser.screen().scrollback();
+        self.parser
+            .screen_mut()
+            .set_scrollback(offset.saturating_sub(rows));
+    }
+
+    /// Returns the number of rows that the view is scrolled up into the scrollback.
+    ///
+    /// This is `0` when the current screen is shown.
+    pub fn scroll_offset(&self) -> usize {
+        self.parser.screen().scrollback()
+    }
+
+    /// Returns the position of the cursor in the buffer when the pane was last rendered, or `None`
+    /// if the program hides the cursor or the view is scrolled into the scrollback.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+}
+
+impl fmt::Debug for TerminalPaneState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("TerminalPaneState");
+        debug.field("size", &self.size());
+        #[cfg(unix)]
+        debug.field("process", &self.process);
+        debug
+            .field("cursor_position", &self.cursor_position)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Styled for TerminalPane<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl StatefulWidget for TerminalPane<'_> {
+    type State = TerminalPaneState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &TerminalPane<'_> {
+    type State = TerminalPaneState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        state.cursor_position = None;
+        if inner.is_empty() {
+            return;
+        }
+        // a failure to resize the PTY only affects the layout of the program's output
+        let _ = state.resize(inner.height, inner.width);
+
+        let screen = state.parser.screen();
+        for row in 0..inner.height {
+            for col in 0..inner.width {
+                let Some(cell) = screen.cell(row, col) else {
+                    continue;
+                };
+                let position = Position::new(inner.x + col, inner.y + row);
+                let buffer_cell = &mut buf[position];
+                if cell.is_wide_continuation() {
+                    // covered by the wide character in the previous column
+                    buffer_cell.reset();
+                } else if cell.has_contents() {
+                    buffer_cell.set_symbol(cell.contents());
+                } else {
+                    buffer_cell.set_symbol(" ");
+                }
+                buffer_cell.set_style(self.style.patch(cell_style(cell)));
+            }
+        }
+
+        let (row, col) = screen.cursor_position();
+        if !screen.hide_cursor() && screen.scrollback() == 0 && row < inner.height {
+            state.cursor_position = Some(Position::new(
+                inner.x + col.min(inner.width - 1),
+                inner.y + row,
+            ));
+        }
+    }
+}
+
+/// Returns the style of a cell of the screen.
+fn cell_style(cell: &vt100::Cell) -> Style {
+    let mut style = Style::new();
+    if let Some(fg) = color(cell.fgcolor()) {
+        style = style.fg(fg);
+    }
+    if let Some(bg) = color(cell.bgcolor()) {
+        style = style.bg(bg);
+    }
+    for (enabled, modifier) in [
+        (cell.bold(), Modifier::BOLD),
+        (cell.dim(), Modifier::DIM),
+        (cell.italic(), Modifier::ITALIC),
+        (cell.underline(), Modifier::UNDERLINED),
+        (cell.inverse(), Modifier::REVERSED),
+    ] {
+        if enabled {
+            style = style.add_modifier(modifier);
+        }
+    }
+    style
+}
+
+/// Converts a color of the screen, returning `None` for the default color.
+const fn color(color: vt100::Color) -> Option<Color> {
+    match color {
+        vt100::Color::Default => None,
+        vt100::Color::Idx(index) => Some(Color::Indexed(index)),
+        vt100::Color::Rgb(r, g, b) => Some(Color::Rgb(r, g, b)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use ratatui_core::style::Stylize;
+    use ratatui_core::text::Line;
+
+    use super::*;
+
+    #[test]
+    fn renders_screen() {
+        let mut state = TerminalPaneState::new(2, 9);
+        state.process(b"\x1b[1;31mred\x1b[0m plain\r\n\x1b[44mblue");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 2));
+        TerminalPane::new().render(buf.area, &mut buf, &mut state);
+
+        let expected = Buffer::with_lines([
+            Line::from(vec!["red".fg(Color::Indexed(1)).bold(), " plain".into()]),
+            Line::from(vec!["blue".bg(Color::Indexed(4)), "     ".into()]),
+        ]);
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(4, 1)));
+    }
+
+    #[test]
+    fn resizes_to_inner_area() {
+        let mut state = TerminalPaneState::new(24, 80);
+        state.process(b"hello");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+        TerminalPane::new()
+            .block(Block::bordered())
+            .style(Style::new().on_black())
+            .render(buf.area, &mut buf, &mut state);
+
+        assert_eq!(state.size(), (1, 7));
+        let mut expected = Buffer::with_lines(["┌───────┐", "│hello  │", "└───────┘"]);
+        expected.set_style(expected.area, Style::new().on_black());
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(6, 1)));
+    }
+
+    #[test]
+    fn hidden_cursor() {
+        let mut state = TerminalPaneState::new(1, 4);
+        state.process(b"\x1b[?25l");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        TerminalPane::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.cursor_position(), None);
+    }
+
+    #[test]
+    fn scrollback() {
+        let mut state = TerminalPaneState::new(2, 3);
+        state.process(b"one\r\ntwo\r\nsix");
+        state.scroll_up(5);
+        assert_eq!(state.scroll_offset(), 1);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        TerminalPane::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["one", "two"]));
+        assert_eq!(state.cursor_position(), None);
+
+        state.scroll_down(1);
+        TerminalPane::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["two", "six"]));
+    }
+
+    #[test]
+    fn wide_characters() {
+        let mut state = TerminalPaneState::new(1, 4);
+        state.process("コン".as_bytes());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        TerminalPane::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["コン"]));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn spawns_process() {
+        use core::time::Duration;
+        use std::time::Instant;
+
+        let mut command = Command::new("sh");
+        command.args(["-c", "printf \"hi $TERM\""]);
+        let mut state = TerminalPaneState::spawn(command, 2, 30).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !state.is_finished() && Instant::now() < deadline {
+            state.update();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(state.is_finished());
+        assert_eq!(state.screen().contents(), "hi xterm-256color");
+    }
+}
@@ -0,0 +1,130 @@
+use alloc::vec::Vec;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+use rustix_openpty::rustix::termios::{self, Winsize};
+
+/// The size of the chunks that are read from the PTY.
+const READ_BUFFER_SIZE: usize = 4096;
+
+/// A child process that runs in a PTY.
+///
+/// The output of the process is read by a background thread, so that reading never blocks the
+/// thread that draws the user interface.
+#[derive(Debug)]
+pub(super) struct PtyProcess {
+    child: Child,
+    controller: File,
+    output: Receiver<Vec<u8>>,
+    /// Whether the PTY was closed, which happens when the process and its children exit.
+    closed: bool,
+}
+
+impl PtyProcess {
+    /// Starts the command in a new PTY of the given size.
+    pub(super) fn spawn(mut command: Command, rows: u16, cols: u16) -> io::Result<Self> {
+        let pty = rustix_openpty::openpty(None, Some(&winsize(rows, cols)))?;
+        let user = File::from(pty.user);
+        command
+            .stdin(Stdio::from(user.try_clone()?))
+            .stdout(Stdio::from(user.try_clone()?))
+            .stderr(Stdio::from(user));
+        if !command.get_envs().any(|(key, _)| key == "TERM") {
+            command.env("TERM", "xterm-256color");
+        }
+        let child = command.spawn()?;
+        // the command was dropped, so the process holds the only handles to the user side of the
+        // PTY and reading from the controller fails once they are closed
+        drop(command);
+
+        let controller = File::from(pty.controller);
+        let mut reader = controller.try_clone()?;
+        let (sender, output) = mpsc::channel();
+        thread::Builder::new()
+            .name("terminal-pane-reader".into())
+            .spawn(move || {
+                let mut buffer = [0; READ_BUFFER_SIZE];
+                loop {
+                    match reader.read(&mut buffer) {
+                        Ok(0) | Err(_) => break,
+                        Ok(len) => {
+                            if sender.send(buffer[..len].to_vec()).is_err() {
+                                break;
+                            }
+                        }
+                    }
+                }
+            })?;
+        Ok(Self {
+            child,
+            controller,
+            output,
+            closed: false,
+        })
+    }
+
+    /// Returns the output that was read since the last call, without blocking.
+    pub(super) fn read_output(&mut self) -> Vec<Vec<u8>> {
+        let mut chunks = Vec::new();
+        loop {
+            match self.output.try_recv() {
+                Ok(chunk) => chunks.push(chunk),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.closed = true;
+                    break;
+                }
+            }
+        }
+        chunks
+    }
+
+    /// Returns whether the PTY was closed.
+    pub(super) const fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Writes input to the process.
+    pub(super) fn write_input(&mut self, input: &[u8]) -> io::Result<()> {
+        self.controller.write_all(input)?;
+        self.controller.flush()
+    }
+
+    /// Sets the size of the PTY, which sends `SIGWINCH` to the process.
+    pub(super) fn resize(&self, rows: u16, cols: u16) -> io::Result<()> {
+        termios::tcsetwinsize(&self.controller, winsize(rows, cols))?;
+        Ok(())
+    }
+
+    /// Returns the exit status of the process if it has exited, without blocking.
+    pub(super) fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        self.child.try_wait()
+    }
+
+    /// Kills the process.
+    pub(super) fn kill(&mut self) -> io::Result<()> {
+        self.child.kill()
+    }
+}
+
+impl Drop for PtyProcess {
+    /// Kills the process, so that it doesn't outlive the pane.
+    fn drop(&mut self) {
+        if matches!(self.child.try_wait(), Ok(None)) {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+}
+
+const fn winsize(rows: u16, cols: u16) -> Winsize {
+    Winsize {
+        ws_row: rows,
+        ws_col: cols,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    }
+}
@@ -0,0 +1,414 @@
+//! The [`Toasts`] widget shows transient notifications stacked in a corner over the UI.
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{HorizontalAlignment, Rect, VerticalAlignment};
+use ratatui_core::style::{Color, Style};
+use ratatui_core::text::Text;
+use ratatui_core::widgets::Widget;
+use strum::{Display, EnumString};
+
+use crate::block::Block;
+use crate::clear::Clear;
+use crate::paragraph::Paragraph;
+
+/// How important a [`Toast`] is, which sets the color of its border.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// A neutral message, with a blue border.
+    #[default]
+    Info,
+    /// A message about something that succeeded, with a green border.
+    Success,
+    /// A message about something that may need attention, with a yellow border.
+    Warning,
+    /// A message about something that failed, with a red border.
+    Error,
+}
+
+impl Severity {
+    /// Returns the color of the border of toasts with this severity.
+    pub const fn color(self) -> Color {
+        match self {
+            Self::Info => Color::Blue,
+            Self::Success => Color::Green,
+            Self::Warning => Color::Yellow,
+            Self::Error => Color::Red,
+        }
+    }
+}
+
+/// A notification shown by [`Toasts`] until its time to live has elapsed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Toast {
+    text: Text<'static>,
+    severity: Severity,
+    remaining: Option<Duration>,
+}
+
+impl Toast {
+    /// Creates a new toast with the given text and severity.
+    ///
+    /// The toast uses the time to live of the [`Toasts`] it is pushed to, unless it is set with
+    /// [`Toast::ttl`].
+    pub fn new<T: Into<Text<'static>>>(text: T, severity: Severity) -> Self {
+        Self {
+            text: text.into(),
+            severity,
+            remaining: None,
+        }
+    }
+
+    /// Sets how long the toast is shown.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn ttl(mut self, ttl: Duration) -> Self {
+        self.remaining = Some(ttl);
+        self
+    }
+
+    /// Returns the text of the toast.
+    pub const fn text(&self) -> &Text<'static> {
+        &self.text
+    }
+
+    /// Returns the severity of the toast.
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns how long the toast is still shown, or `None` if it hasn't been pushed to [`Toasts`]
+    /// and has no time to live of its own.
+    pub const fn remaining(&self) -> Option<Duration> {
+        self.remaining
+    }
+}
+
+/// A queue of transient notifications that are rendered stacked in a corner over the UI.
+///
+/// Push notifications with [`Toasts::push`] (or [`Toasts::info`], [`Toasts::success`],
+/// [`Toasts::warning`] and [`Toasts::error`]), and call [`Toasts::tick`] with the time elapsed
+/// since the last tick (e.g. once per frame) to remove the toasts whose time to live has elapsed.
+///
+/// Render the toasts last, over the rest of the frame, in the full area of the frame. Each toast is
+/// drawn in a bordered box colored by its [`Severity`], which clears the cells under it. The newest
+/// toast is closest to the corner, and only as many toasts as fit in the area (and at most
+/// [`Toasts::max_visible`]) are shown. Toasts are not wrapped, so long lines are truncated to the
+/// [`Toasts::width`].
+///
+/// Toasts are not dismissed by input, but applications can call [`Toasts::dismiss`] on a key
+/// press.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use ratatui::layout::{HorizontalAlignment, Rect, VerticalAlignment};
+/// use ratatui::widgets::{Toasts, Widget};
+/// # let mut buf = ratatui::buffer::Buffer::empty(Rect::new(0, 0, 60, 20));
+///
+/// let mut toasts = Toasts::new()
+///     .alignment(HorizontalAlignment::Right, VerticalAlignment::Bottom)
+///     .ttl(Duration::from_secs(3));
+/// toasts.success("Saved config.toml");
+/// toasts.error("Connection lost");
+///
+/// // on each frame, after rendering the rest of the UI
+/// toasts.tick(Duration::from_millis(16));
+/// toasts.render(buf.area, &mut buf);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+    ttl: Duration,
+    width: u16,
+    max_visible: usize,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self {
+            queue: VecDeque::new(),
+            ttl: Duration::from_secs(4),
+            width: 40,
+            max_visible: 5,
+            horizontal_alignment: HorizontalAlignment::Right,
+            vertical_alignment: VerticalAlignment::Top,
+        }
+    }
+}
+
+impl Toasts {
+    /// Creates an empty queue of toasts, shown in the top right corner for 4 seconds each.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long toasts are shown, unless they have their own [`Toast::ttl`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the width of the toasts, including their borders.
+    ///
+    /// The width is reduced to the width of the area when rendering. Defaults to 40.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the maximum number of toasts that are shown at once. Defaults to 5.
+    ///
+    /// Older toasts stay queued and are shown when newer ones expire.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    /// Sets the corner (or edge) of the area where the toasts are stacked. Defaults to the top
+    /// right corner.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alignment(
+        mut self,
+        horizontal: HorizontalAlignment,
+        vertical: VerticalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = horizontal;
+        self.vertical_alignment = vertical;
+        self
+    }
+
+    /// Adds a toast, which is shown until its time to live has elapsed.
+    pub fn push(&mut self, mut toast: Toast) {
+        toast.remaining.get_or_insert(self.ttl);
+        self.queue.push_back(toast);
+    }
+
+    /// Adds a toast with [`Severity::Info`].
+    pub fn info<T: Into<Text<'static>>>(&mut self, text: T) {
+        self.push(Toast::new(text, Severity::Info));
+    }
+
+    /// Adds a toast with [`Severity::Success`].
+    pub fn success<T: Into<Text<'static>>>(&mut self, text: T) {
+        self.push(Toast::new(text, Severity::Success));
+    }
+
+    /// Adds a toast with [`Severity::Warning`].
+    pub fn warning<T: Into<Text<'static>>>(&mut self, text: T) {
+        self.push(Toast::new(text, Severity::Warning));
+    }
+
+    /// Adds a toast with [`Severity::Error`].
+    pub fn error<T: Into<Text<'static>>>(&mut self, text: T) {
+        self.push(Toast::new(text, Severity::Error));
+    }
+
+    /// Advances the time of the toasts by `delta`, and removes the toasts whose time to live has
+    /// elapsed.
+    pub fn tick(&mut self, delta: Duration) {
+        self.queue.retain_mut(|toast| {
+            let remaining = toast.remaining.unwrap_or_default().saturating_sub(delta);
+            toast.remaining = Some(remaining);
+            !remaining.is_zero()
+        });
+    }
+
+    /// Removes the newest toast and returns it, or `None` if there are no toasts.
+    pub fn dismiss(&mut self) -> Option<Toast> {
+        self.queue.pop_back()
+    }
+
+    /// Removes all the toasts.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    /// Returns an iterator over the toasts, from the oldest to the newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Toast> {
+        self.queue.iter()
+    }
+
+    /// Returns the number of toasts, including those that are not visible.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns true if there are no toasts.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Returns the areas of the visible toasts in `area`, from the newest to the oldest.
+    fn layout(&self, area: Rect) -> Vec<(Rect, &Toast)> {
+        let width = self.width.min(area.width);
+        let x = match self.horizontal_alignment {
+            HorizontalAlignment::Left => area.left(),
+            HorizontalAlignment::Center => area.left() + (area.width - width) / 2,
+            HorizontalAlignment::Right => area.right() - width,
+        };
+        let mut total_height = 0;
+        let mut visible = Vec::new();
+        for toast in self.queue.iter().rev().take(self.max_visible) {
+            let height = u16::try_from(toast.text.height())
+                .unwrap_or(u16::MAX)
+                .saturating_add(2);
+            if total_height + u32::from(height) > u32::from(area.height) {
+                break;
+            }
+            total_height += u32::from(height);
+            visible.push((height, toast));
+        }
+        // the total height fits in the area, so it fits in a u16
+        let total_height = total_height as u16;
+        let mut y = match self.vertical_alignment {
+            VerticalAlignment::Top => area.top(),
+            VerticalAlignment::Center => area.top() + (area.height - total_height) / 2,
+            VerticalAlignment::Bottom => area.bottom() - total_height,
+        };
+        if self.vertical_alignment == VerticalAlignment::Bottom {
+            // the newest toast is at the bottom, closest to the corner
+            visible.reverse();
+        }
+        let mut areas: Vec<_> = visible
+            .into_iter()
+            .map(|(height, toast)| {
+                let toast_area = Rect::new(x, y, width, height);
+                y += height;
+                (toast_area, toast)
+            })
+            .collect();
+        if self.vertical_alignment == VerticalAlignment::Bottom {
+            areas.reverse();
+        }
+        areas
+    }
+}
+
+impl Widget for Toasts {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Toasts {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for (toast_area, toast) in self.layout(area) {
+            Clear.render(toast_area, buf);
+            let block = Block::bordered().border_style(Style::new().fg(toast.severity.color()));
+            Paragraph::new(toast.text.clone())
+                .block(block)
+                .render(toast_area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use ratatui_core::buffer::Cell;
+
+    use super::*;
+
+    fn render(toasts: &Toasts, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::filled(Rect::new(0, 0, width, height), Cell::new("·"));
+        toasts.render(buf.area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn render_top_right() {
+        let mut toasts = Toasts::new().width(9);
+        toasts.info("first");
+        toasts.error("second");
+        let mut expected = Buffer::with_lines([
+            "···┌───────┐",
+            "···│second │",
+            "···└───────┘",
+            "···┌───────┐",
+            "···│first  │",
+            "···└───────┘",
+            "············",
+        ]);
+        expected.set_style(Rect::new(3, 0, 9, 3), Style::new().red());
+        expected.set_style(Rect::new(4, 1, 7, 1), Style::reset());
+        expected.set_style(Rect::new(3, 3, 9, 3), Style::new().blue());
+        expected.set_style(Rect::new(4, 4, 7, 1), Style::reset());
+        assert_eq!(render(&toasts, 12, 7), expected);
+    }
+
+    #[test]
+    fn render_bottom_left_only_what_fits() {
+        let mut toasts = Toasts::new()
+            .width(8)
+            .alignment(HorizontalAlignment::Left, VerticalAlignment::Bottom);
+        toasts.info("one");
+        toasts.info("two");
+        toasts.info("three");
+        let buf = render(&toasts, 10, 7);
+        let lines: Vec<_> = buf
+            .content()
+            .chunks(10)
+            .map(|row| row.iter().map(Cell::symbol).collect::<String>())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "··········",
+                "┌──────┐··",
+                "│two   │··",
+                "└──────┘··",
+                "┌──────┐··",
+                "│three │··",
+                "└──────┘··",
+            ]
+        );
+    }
+
+    #[test]
+    fn tick_removes_expired_toasts() {
+        let mut toasts = Toasts::new().ttl(Duration::from_secs(2));
+        toasts.info("default");
+        toasts.push(Toast::new("long", Severity::Warning).ttl(Duration::from_secs(5)));
+        toasts.tick(Duration::from_secs(1));
+        assert_eq!(toasts.len(), 2);
+        toasts.tick(Duration::from_secs(1));
+        assert_eq!(toasts.len(), 1);
+        assert_eq!(
+            toasts.iter().next().unwrap().remaining(),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(toasts.dismiss().unwrap().severity(), Severity::Warning);
+        assert!(toasts.is_empty());
+    }
+
+    #[test]
+    fn max_visible() {
+        let mut toasts = Toasts::new().width(5).max_visible(1);
+        toasts.info("a");
+        toasts.info("b");
+        assert_eq!(toasts.layout(Rect::new(0, 0, 10, 10)).len(), 1);
+        assert_eq!(toasts.layout(Rect::new(0, 0, 10, 2)).len(), 0);
+    }
+}
@@ -0,0 +1,80 @@
+//! Compares formatting strings with `format!` against allocating them in the frame arena, and
+//! building text in new vectors against reusing the vectors of the frame's scratch space.
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Text};
+
+const ROWS: u16 = 50;
+
+fn frame_arena(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("frame_arena");
+    let mut terminal = Terminal::new(TestBackend::new(80, ROWS)).unwrap();
+
+    group.bench_function("format", |bencher| {
+        bencher.iter(|| {
+            terminal
+                .draw(|frame| {
+                    for row in 0..ROWS {
+                        let value = black_box(f64::from(row) * 1.5);
+                        let line = Line::from(format!("row {row}: {value:.2}"));
+                        frame.render_widget(line, Rect::new(0, row, 80, 1));
+                    }
+                })
+                .unwrap();
+        });
+    });
+
+    group.bench_function("alloc_str", |bencher| {
+        bencher.iter(|| {
+            terminal
+                .draw(|frame| {
+                    for row in 0..ROWS {
+                        let value = black_box(f64::from(row) * 1.5);
+                        let line =
+                            Line::from(frame.alloc_str(format_args!("row {row}: {value:.2}")));
+                        frame.render_widget(line, Rect::new(0, row, 80, 1));
+                    }
+                })
+                .unwrap();
+        });
+    });
+
+    group.bench_function("text", |bencher| {
+        bencher.iter(|| {
+            terminal
+                .draw(|frame| {
+                    let lines: Vec<Line> = (0..ROWS)
+                        .map(|row| Line::from(frame.alloc_str(format_args!("row {row}"))))
+                        .collect();
+                    frame.render_widget(Text::from(lines), frame.area());
+                })
+                .unwrap();
+        });
+    });
+
+    group.bench_function("scratch", |bencher| {
+        bencher.iter(|| {
+            terminal
+                .draw(|frame| {
+                    let scratch = frame.scratch();
+                    let mut lines = scratch.lines();
+                    lines.extend(
+                        (0..ROWS).map(|row| Line::from(frame.alloc_str(format_args!("row {row}")))),
+                    );
+                    let text = Text::from(lines);
+                    frame.render_widget(&text, frame.area());
+                    scratch.recycle_text(text);
+                })
+                .unwrap();
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, frame_arena);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+use criterion::{BatchSize, Criterion, Throughput, criterion_group};
+use ratatui::backend::{Backend, CrosstermBackend};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+
+criterion_group!(benches, draw);
+
+/// The area of the frames that are drawn.
+const AREA: Rect = Rect::new(0, 0, 200, 50);
+
+/// Benchmarks drawing the difference between two frames with the crossterm backend.
+///
+/// The number of bytes written per frame is reported as the throughput, so that changes to the
+/// escape sequences the backend writes can be compared.
+fn draw(c: &mut Criterion) {
+    let mut group = c.benchmark_group("backend/draw");
+    let previous = Buffer::empty(AREA);
+
+    // every fourth cell changes, with the same colors, as when updating a column of numbers
+    let mut scattered = Buffer::empty(AREA);
+    for y in AREA.rows().map(|row| row.y) {
+        for x in (0..AREA.width).step_by(4) {
+            scattered[(x, y)].set_symbol("x").set_fg(Color::Yellow);
+        }
+    }
+
+    // every cell changes, with a different background on every other row
+    let mut full = Buffer::empty(AREA);
+    for y in AREA.rows().map(|row| row.y) {
+        let bg = if y % 2 == 0 {
+            Color::Blue
+        } else {
+            Color::Reset
+        };
+        full.set_string(0, y, "=".repeat(AREA.width.into()), Style::new().bg(bg));
+    }
+
+    for (name, next) in [("scattered", &scattered), ("full", &full)] {
+        let bytes = draw_frame(&previous, next).len();
+        group.throughput(Throughput::Bytes(bytes as u64));
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || Vec::with_capacity(bytes),
+                |writer| {
+                    let mut backend = CrosstermBackend::new(writer);
+                    backend.draw(previous.diff_iter(next)).unwrap();
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+/// Returns the bytes written to draw the difference between the two frames.
+fn draw_frame(previous: &Buffer, next: &Buffer) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut backend = CrosstermBackend::new(&mut bytes);
+    backend.draw(previous.diff_iter(next)).unwrap();
+    bytes
+}
@@ -52,6 +52,40 @@ fn barchart(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark for rendering a large barchart that fills a big terminal on every frame.
+///
+/// Most of the time is spent filling the cells of the bars, so this measures the cost of the
+/// render path per cell rather than the cost of building the chart.
+fn barchart_large(c: &mut Criterion) {
+    let mut group = c.benchmark_group("barchart/large");
+    let mut rng = rand::rng();
+    let area = Rect::new(0, 0, 400, 120);
+    let data: Vec<Bar> = (0..100)
+        .map(|_| Bar::default().value(rng.random_range(0..100)))
+        .collect();
+
+    for (name, direction) in [
+        ("vertical", Direction::Vertical),
+        ("horizontal", Direction::Horizontal),
+    ] {
+        let barchart = BarChart::default()
+            .direction(direction)
+            .bar_width(3)
+            .bar_gap(1)
+            .data(BarGroup::default().bars(&data));
+        group.bench_with_input(
+            BenchmarkId::new("render", name),
+            &barchart,
+            |b, barchart| {
+                let mut buffer = Buffer::empty(area);
+                b.iter(|| barchart.render(buffer.area, &mut buffer));
+            },
+        );
+    }
+
+    group.finish();
+}
+
 /// Render the widget in a classical size buffer.
 fn render(bencher: &mut Bencher, barchart: &BarChart) {
     let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
@@ -66,4 +100,4 @@ fn render(bencher: &mut Bencher, barchart: &BarChart) {
     );
 }
 
-criterion_group!(benches, barchart);
+criterion_group!(benches, barchart, barchart_large);
@@ -3,14 +3,36 @@ use std::hint::black_box;
 use criterion::{BenchmarkId, Criterion};
 use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout::Rect;
+use ratatui::style::Style;
 use ratatui::text::Line;
 
-criterion::criterion_group!(benches, empty, filled, with_lines);
+criterion::criterion_group!(benches, empty, filled, with_lines, fill_rows, clone, diff);
 
 const fn rect(size: u16) -> Rect {
     Rect::new(0, 0, size, size)
 }
 
+/// The area of a large terminal.
+const LARGE: Rect = Rect::new(0, 0, 300, 100);
+
+/// Returns a buffer of the given area filled with text, borders and wide characters.
+fn frame(area: Rect) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    let line = "│ fn main() { println!(\"こんにちは 👋\"); } ─┼─ ";
+    for y in area.top()..area.bottom() {
+        let mut x = area.left();
+        while x < area.right() {
+            let (next, _) =
+                buffer.set_stringn(x, y, line, usize::from(area.right() - x), Style::new());
+            if next == x {
+                break;
+            }
+            x = next;
+        }
+    }
+    buffer
+}
+
 fn empty(c: &mut Criterion) {
     let mut group = c.benchmark_group("buffer/empty");
     for size in [16, 64, 255] {
@@ -58,3 +80,56 @@ fn with_lines(c: &mut Criterion) {
     }
     group.finish();
 }
+
+/// Compares changing every cell of a buffer by indexing the buffer for each cell with changing
+/// the cells of each row through a row slice, which only checks the bounds once per row.
+fn fill_rows(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer/fill_rows");
+    for size in [16, 64, 255] {
+        let area = rect(size);
+        group.bench_with_input(BenchmarkId::new("index", size), &area, |b, &area| {
+            let mut buffer = Buffer::empty(area);
+            b.iter(|| {
+                for y in area.top()..area.bottom() {
+                    for x in area.left()..area.right() {
+                        buffer[(x, y)].set_symbol("█");
+                    }
+                }
+                black_box(&buffer);
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("row_mut", size), &area, |b, &area| {
+            let mut buffer = Buffer::empty(area);
+            b.iter(|| {
+                for y in area.top()..area.bottom() {
+                    for cell in buffer.row_mut(y).unwrap_or_default() {
+                        cell.set_symbol("█");
+                    }
+                }
+                black_box(&buffer);
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Measures cloning a large buffer, which happens when keeping the previous frame around.
+fn clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer/clone");
+    let buffer = frame(LARGE);
+    group.bench_function("300x100", |b| b.iter(|| black_box(&buffer).clone()));
+    group.finish();
+}
+
+/// Measures diffing two large buffers that are equal except for one line, which is the usual
+/// case when drawing a frame.
+fn diff(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer/diff");
+    let prev = frame(LARGE);
+    let mut next = prev.clone();
+    next.set_string(0, 50, "a changed line", Style::new());
+    group.bench_function("300x100", |b| {
+        b.iter(|| black_box(&prev).diff(black_box(&next)));
+    });
+    group.finish();
+}
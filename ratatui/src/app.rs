@@ -0,0 +1,381 @@
+//! A minimal runtime for applications that follow The Elm Architecture (TEA).
+//!
+//! In The Elm Architecture, the whole state of an application is a single *model*. The model is
+//! only changed by an *update* function, which handles one *message* at a time, and the UI is a
+//! pure *view* of the model. Messages come from *subscriptions* (e.g. a timer, or the keys that the
+//! user presses), and the update function can return *commands* that produce more messages or quit
+//! the application. This keeps the state changes of the application in one place, which makes it
+//! easy to follow and to test: the update function can be tested without a terminal.
+//!
+//! A [`Program`] owns the model and runs the loop that connects these parts: it draws the view,
+//! waits for the next message from its subscriptions, passes the message to the update function,
+//! runs the returned [`Command`], and draws the view again.
+//!
+//! This module is enabled by the `app` feature. It uses the crossterm backend and initializes the
+//! terminal with [`run`](crate::run), so it is a good starting point for simple applications.
+//! Applications that outgrow it can move the same model, update, and view functions into their own
+//! event loop.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use crossterm::event::KeyCode;
+//! use ratatui::Event;
+//! use ratatui::app::{Command, Program};
+//!
+//! struct Model {
+//!     count: u64,
+//! }
+//!
+//! enum Message {
+//!     Tick,
+//!     Reset,
+//!     Quit,
+//! }
+//!
+//! fn update(model: &mut Model, message: Message) -> Command<Message> {
+//!     match message {
+//!         Message::Tick => model.count += 1,
+//!         Message::Reset => model.count = 0,
+//!         Message::Quit => return Command::Quit,
+//!     }
+//!     Command::None
+//! }
+//!
+//! fn view(model: &Model, frame: &mut ratatui::Frame) {
+//!     let text = format!("{} ticks (r to reset, q to quit)", model.count);
+//!     frame.render_widget(text, frame.area());
+//! }
+//!
+//! fn main() -> std::io::Result<()> {
+//!     let model = Program::new(Model { count: 0 }, update, view)
+//!         .tick(Duration::from_millis(250), || Message::Tick)
+//!         .on_event(|event| match event {
+//!             Event::Key(key) if key.is_press() => match key.code {
+//!                 KeyCode::Char('r') => Some(Message::Reset),
+//!                 KeyCode::Char('q') => Some(Message::Quit),
+//!                 _ => None,
+//!             },
+//!             _ => None,
+//!         })
+//!         .run()?;
+//!     println!("counted {} ticks", model.count);
+//!     Ok(())
+//! }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::ControlFlow;
+use core::time::Duration;
+use std::io::{self, Write};
+
+use ratatui_core::terminal::{Frame, Terminal};
+use ratatui_crossterm::CrosstermBackend;
+use ratatui_crossterm::crossterm::event as crossterm_event;
+
+use crate::init::{Event, event_loop, read_event};
+
+/// A side effect requested by the update function of a [`Program`].
+///
+/// Commands are run by the program after the update function returns, before the view is drawn
+/// again.
+#[must_use = "commands have no effect unless they are returned to the program"]
+#[derive(Default)]
+pub enum Command<Msg> {
+    /// Does nothing.
+    #[default]
+    None,
+    /// Stops the program after the pending messages have been handled, and returns the model.
+    Quit,
+    /// Sends a message to the update function.
+    Message(Msg),
+    /// Calls a function and sends the message that it returns to the update function.
+    ///
+    /// The function is called on the thread of the program, so the UI is not redrawn until it
+    /// returns.
+    Perform(Box<dyn FnOnce() -> Msg>),
+    /// Runs several commands in order.
+    Batch(Vec<Self>),
+}
+
+impl<Msg> Command<Msg> {
+    /// Creates a command that calls a function and sends the message that it returns to the
+    /// update function.
+    pub fn perform<F>(f: F) -> Self
+    where
+        F: FnOnce() -> Msg + 'static,
+    {
+        Self::Perform(Box::new(f))
+    }
+
+    /// Creates a command that runs several commands in order.
+    pub fn batch<I>(commands: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        Self::Batch(commands.into_iter().collect())
+    }
+}
+
+impl<Msg: fmt::Debug> fmt::Debug for Command<Msg> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => f.write_str("None"),
+            Self::Quit => f.write_str("Quit"),
+            Self::Message(message) => f.debug_tuple("Message").field(message).finish(),
+            Self::Perform(_) => f.debug_tuple("Perform").finish_non_exhaustive(),
+            Self::Batch(commands) => f.debug_tuple("Batch").field(commands).finish(),
+        }
+    }
+}
+
+type UpdateFn<M, Msg> = Box<dyn FnMut(&mut M, Msg) -> Command<Msg>>;
+
+type ViewFn<M> = Box<dyn Fn(&M, &mut Frame)>;
+
+/// An application that follows The Elm Architecture, with a model of type `M` that is updated by
+/// messages of type `Msg`.
+///
+/// Create a program with the initial model, the update function, and the view function, add the
+/// subscriptions that produce messages with [`Program::tick`] and [`Program::on_event`], and run it
+/// with [`Program::run`].
+///
+/// Terminal resize events always redraw the view, even when they don't produce a message.
+///
+/// See the [module documentation](crate::app) for an example.
+pub struct Program<M, Msg> {
+    model: M,
+    update: UpdateFn<M, Msg>,
+    view: ViewFn<M>,
+    tick: Option<(Duration, Box<dyn FnMut() -> Msg>)>,
+    on_event: Option<Box<dyn FnMut(Event) -> Option<Msg>>>,
+}
+
+impl<M, Msg> Program<M, Msg> {
+    /// Creates a new program with the initial model, and the functions that update the model and
+    /// draw it.
+    ///
+    /// The program has no subscriptions, so nothing happens until some are added with
+    /// [`Program::tick`] or [`Program::on_event`].
+    pub fn new<U, V>(model: M, update: U, view: V) -> Self
+    where
+        U: FnMut(&mut M, Msg) -> Command<Msg> + 'static,
+        V: Fn(&M, &mut Frame) + 'static,
+    {
+        Self {
+            model,
+            update: Box::new(update),
+            view: Box::new(view),
+            tick: None,
+            on_event: None,
+        }
+    }
+
+    /// Sends the message returned by `message` to the update function every `tick_rate`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tick<F>(mut self, tick_rate: Duration, message: F) -> Self
+    where
+        F: FnMut() -> Msg + 'static,
+    {
+        self.tick = Some((tick_rate, Box::new(message)));
+        self
+    }
+
+    /// Sends the message returned by `message` for each terminal event to the update function.
+    ///
+    /// Return `None` to ignore an event. The function is called for terminal events only, ticks
+    /// are configured with [`Program::tick`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn on_event<F>(mut self, message: F) -> Self
+    where
+        F: FnMut(Event) -> Option<Msg> + 'static,
+    {
+        self.on_event = Some(Box::new(message));
+        self
+    }
+
+    /// Returns the current model.
+    pub const fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Sends a message to the update function and runs the commands that it returns, without
+    /// drawing the view.
+    ///
+    /// Returns `true` if a command asked the program to quit. This is useful to test the update
+    /// function, or to prepare the model before running the program.
+    pub fn dispatch(&mut self, message: Msg) -> bool {
+        let mut messages = VecDeque::from([message]);
+        let mut quit = false;
+        while let Some(message) = messages.pop_front() {
+            let command = (self.update)(&mut self.model, message);
+            quit |= run_command(command, &mut messages);
+        }
+        quit
+    }
+
+    /// Runs the program in a terminal initialized with [`run`](crate::run), and returns the final
+    /// model once a [`Command::Quit`] is run.
+    ///
+    /// The program uses the same event loop as [`run_with_events`](crate::run_with_events), so the
+    /// tick message is also sent once before the first frame is drawn.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading an event or drawing a frame fails.
+    pub fn run(self) -> io::Result<M> {
+        crate::run(|terminal| self.run_in(terminal, read_event))
+    }
+
+    /// Runs the event loop of the program.
+    ///
+    /// `read` waits for a terminal event like [`read_event`].
+    fn run_in<W: Write>(
+        mut self,
+        terminal: &mut Terminal<CrosstermBackend<W>>,
+        read: impl FnMut(Option<Duration>) -> io::Result<Option<crossterm_event::Event>>,
+    ) -> io::Result<M> {
+        let tick_rate = self.tick.as_ref().map(|(tick_rate, _)| *tick_rate);
+        event_loop(
+            terminal,
+            tick_rate,
+            |frame, event| {
+                let message = match event {
+                    Event::Tick => self.tick.as_mut().map(|(_, message)| message()),
+                    event => self.on_event.as_mut().and_then(|on_event| on_event(event)),
+                };
+                let quit = message.is_some_and(|message| self.dispatch(message));
+                (self.view)(&self.model, frame);
+                if quit {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            },
+            read,
+        )?;
+        Ok(self.model)
+    }
+}
+
+/// Runs a command, adding the messages that it produces to `messages`, and returns `true` if it
+/// asked the program to quit.
+fn run_command<Msg>(command: Command<Msg>, messages: &mut VecDeque<Msg>) -> bool {
+    match command {
+        Command::None => false,
+        Command::Quit => true,
+        Command::Message(message) => {
+            messages.push_back(message);
+            false
+        }
+        Command::Perform(f) => {
+            messages.push_back(f());
+            false
+        }
+        Command::Batch(commands) => commands
+            .into_iter()
+            .fold(false, |quit, command| run_command(command, messages) | quit),
+    }
+}
+
+impl<M: fmt::Debug, Msg> fmt::Debug for Program<M, Msg> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Program")
+            .field("model", &self.model)
+            .field(
+                "tick_rate",
+                &self.tick.as_ref().map(|(tick_rate, _)| tick_rate),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec;
+
+    use ratatui_core::layout::Rect;
+    use ratatui_core::terminal::{TerminalOptions, Viewport};
+    use ratatui_crossterm::crossterm::event::KeyCode;
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Model {
+        text: String,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Push(char),
+        Twice(char),
+        Quit,
+    }
+
+    fn update(model: &mut Model, message: Message) -> Command<Message> {
+        match message {
+            Message::Push(c) => model.text.push(c),
+            Message::Twice(c) => {
+                return Command::batch([
+                    Command::Message(Message::Push(c)),
+                    Command::perform(move || Message::Push(c)),
+                ]);
+            }
+            Message::Quit => return Command::Quit,
+        }
+        Command::None
+    }
+
+    fn view(model: &Model, frame: &mut Frame) {
+        frame.render_widget(model.text.as_str(), frame.area());
+    }
+
+    #[test]
+    fn dispatch_runs_commands() {
+        let mut program = Program::new(Model::default(), update, view);
+        assert!(!program.dispatch(Message::Twice('a')));
+        assert_eq!(program.model().text, "aa");
+        assert!(program.dispatch(Message::Quit));
+    }
+
+    #[test]
+    fn run_handles_events_until_quit() {
+        let backend = CrosstermBackend::new(Vec::new());
+        let options = TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(0, 0, 10, 1)));
+        let mut terminal = Terminal::with_options(backend, options).unwrap();
+        let mut events = VecDeque::from([
+            None,
+            Some(crossterm_event::Event::Key(KeyCode::Char('x').into())),
+            Some(crossterm_event::Event::Resize(20, 5)),
+            Some(crossterm_event::Event::Key(KeyCode::Char('q').into())),
+        ]);
+        let mut timeouts = vec![];
+
+        let program = Program::new(Model::default(), update, view)
+            .tick(Duration::ZERO, || Message::Push('.'))
+            .on_event(|event| match event {
+                Event::Key(key) if key.code == KeyCode::Char('q') => Some(Message::Quit),
+                Event::Key(key) => key.code.as_char().map(Message::Push),
+                _ => None,
+            });
+        let model = program
+            .run_in(&mut terminal, |timeout| {
+                timeouts.push(timeout);
+                Ok(events.pop_front().flatten())
+            })
+            .unwrap();
+
+        assert_eq!(model.text, "..x");
+        assert_eq!(timeouts, [Some(Duration::ZERO); 4]);
+    }
+}
@@ -0,0 +1,178 @@
+//! Compatibility with [tui-rs], the crate Ratatui was forked from.
+//!
+//! This module mirrors the module layout of `tui` and brings back the types and methods that were
+//! renamed or removed since the fork, as deprecated items that point to their replacements. It is
+//! meant to get a large `tui` codebase compiling against Ratatui in one step, and then to migrate
+//! it gradually by following the deprecation warnings.
+//!
+//! Import the module in place of the `tui` crate, and the extension traits for the removed
+//! methods:
+//!
+//! ```rust
+//! # #![allow(deprecated)]
+//! use ratatui::compat_tui as tui;
+//! use tui::layout::Corner;
+//! use tui::text::{Span, Spans};
+//! use tui::widgets::{List, ListItem};
+//! use tui::{TuiBufferExt, TuiListExt};
+//!
+//! let spans = Spans::from(vec![Span::raw("hello "), Span::raw("world")]);
+//! let list = List::new([ListItem::new(spans)]).start_corner(Corner::BottomLeft);
+//! ```
+//!
+//! The following items are provided:
+//!
+//! | `tui`                         | Ratatui                                   |
+//! | ----------------------------- | ----------------------------------------- |
+//! | `tui::Terminal` etc.          | [`Terminal`] etc.                         |
+//! | `tui::terminal::Frame` etc.   | [`Frame`] etc. at the root                |
+//! | `tui::text::Spans`            | [`Line`]                                  |
+//! | `tui::layout::Corner`         | [`ListDirection`]                         |
+//! | `List::start_corner`          | [`List::direction`]                       |
+//! | `Buffer::set_spans`           | [`Buffer::set_line`]                      |
+//! | `Buffer::set_background`      | [`Buffer::set_style`]                     |
+//!
+//! `Spans` is an alias of [`Line`], so code that accesses the spans through the `.0` field must
+//! use [`Line::spans`] instead.
+//!
+//! Methods whose signature changed but whose name didn't can't be provided by an extension trait,
+//! as the inherent method always takes precedence. These have to be migrated by hand:
+//!
+//! - `Table::new(rows)` takes the column widths too, use `Table::default().rows(rows)` instead.
+//! - `Rect::inner(&margin)` takes the [`Margin`](crate::layout::Margin) by value.
+//! - `Cell::symbol` is a method rather than a field, set it with `Cell::set_symbol`.
+//!
+//! The methods that were deprecated rather than removed, such as `Frame::size` and `Buffer::get`,
+//! are still available on the Ratatui types with their own deprecation notes. See
+//! [BREAKING-CHANGES.md] for the other changes since the fork.
+//!
+//! [tui-rs]: https://crates.io/crates/tui
+//! [BREAKING-CHANGES.md]: https://github.com/ratatui/ratatui/blob/main/BREAKING-CHANGES.md
+
+use crate::buffer::Buffer;
+use crate::layout::Rect;
+use crate::style::{Color, Style};
+use crate::text::Line;
+use crate::widgets::{List, ListDirection};
+pub use crate::{
+    CompletedFrame, Frame, Terminal, TerminalOptions, Viewport, backend, buffer, style, symbols,
+    widgets,
+};
+
+/// The `tui::terminal` module, whose types are now at the root of the crate.
+pub mod terminal {
+    pub use crate::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport};
+}
+
+/// The `tui::layout` module, with the removed [`Corner`](layout::Corner) type.
+pub mod layout {
+    pub use crate::layout::*;
+
+    /// The corner of a [`List`](crate::widgets::List) from which the items are rendered.
+    #[deprecated = "use `ListDirection` with `List::direction()` instead"]
+    #[allow(deprecated)]
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub enum Corner {
+        /// Render the items from the top, which is the default.
+        #[default]
+        TopLeft,
+        /// Render the items from the top.
+        TopRight,
+        /// Render the items from the bottom.
+        BottomLeft,
+        /// Render the items from the top, as `tui` did.
+        BottomRight,
+    }
+}
+
+/// The `tui::text` module, with the removed [`Spans`](text::Spans) type.
+pub mod text {
+    pub use crate::text::*;
+
+    /// A line of styled text, which was renamed to [`Line`].
+    #[deprecated = "use `Line` instead"]
+    pub type Spans<'a> = Line<'a>;
+}
+
+/// The methods of [`List`] that were removed since `tui`.
+pub trait TuiListExt {
+    /// Sets the corner from which the items are rendered.
+    #[deprecated = "use `List::direction()` instead"]
+    #[allow(deprecated)]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    fn start_corner(self, corner: layout::Corner) -> Self;
+}
+
+#[allow(deprecated)]
+impl TuiListExt for List<'_> {
+    fn start_corner(self, corner: layout::Corner) -> Self {
+        let direction = match corner {
+            layout::Corner::BottomLeft => ListDirection::BottomToTop,
+            _ => ListDirection::TopToBottom,
+        };
+        self.direction(direction)
+    }
+}
+
+/// The methods of [`Buffer`] that were removed since `tui`.
+pub trait TuiBufferExt {
+    /// Prints a line, starting at the position (x, y).
+    #[deprecated = "use `Buffer::set_line()` instead"]
+    fn set_spans(&mut self, x: u16, y: u16, spans: &Line<'_>, max_width: u16) -> (u16, u16);
+
+    /// Sets the background color of all cells in the given area.
+    #[deprecated = "use `Buffer::set_style()` instead"]
+    fn set_background(&mut self, area: Rect, color: Color);
+}
+
+impl TuiBufferExt for Buffer {
+    fn set_spans(&mut self, x: u16, y: u16, spans: &Line<'_>, max_width: u16) -> (u16, u16) {
+        self.set_line(x, y, spans, max_width)
+    }
+
+    fn set_background(&mut self, area: Rect, color: Color) {
+        self.set_style(area, Style::new().bg(color));
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::layout::Corner;
+    use super::text::Spans;
+    use super::*;
+    use crate::backend::TestBackend;
+    use crate::widgets::Widget;
+
+    #[test]
+    fn terminal() {
+        let options = TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(0, 0, 3, 1)));
+        let mut terminal = Terminal::with_options(TestBackend::new(3, 1), options).unwrap();
+        let completed: CompletedFrame<'_> = terminal
+            .draw(|frame: &mut Frame<'_>| frame.render_widget("abc", frame.area()))
+            .unwrap();
+        assert_eq!(*completed.buffer, Buffer::with_lines(["abc"]));
+    }
+
+    #[test]
+    fn start_corner() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 3));
+        List::new(["a", "b"])
+            .start_corner(Corner::BottomLeft)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([" ", "b", "a"]));
+
+        let list = List::new(["a"]).start_corner(Corner::BottomRight);
+        assert_eq!(list, List::new(["a"]).direction(ListDirection::TopToBottom));
+    }
+
+    #[test]
+    fn buffer() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buf.set_spans(0, 0, &Spans::from("abc"), 2);
+        buf.set_background(Rect::new(0, 0, 1, 1), Color::Red);
+        let mut expected = Buffer::with_lines(["ab  "]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().bg(Color::Red));
+        assert_eq!(buf, expected);
+    }
+}
@@ -49,6 +49,8 @@
 //! - [`run`] - Initializes a terminal, runs a closure, and automatically restores the terminal
 //!   state. This is the simplest way to run a Ratatui application and handles all setup and cleanup
 //!   automatically.
+//! - [`run_with_events`] - Same as [`run`], but also owns the event loop: it reads terminal events,
+//!   sends ticks at a fixed rate, and redraws the UI for each [`Event`].
 //! - [`init`] - Creates a terminal with reasonable defaults including alternate screen and raw
 //!   mode. Panics on failure.
 //! - [`try_init`] - Same as [`init`] but returns a `Result` instead of panicking.
@@ -128,9 +130,7 @@
 //! use ratatui::widgets::Widget;
 //! use ratatui::{TerminalOptions, Viewport};
 //!
-//! let options = TerminalOptions {
-//!     viewport: Viewport::Inline(10),
-//! };
+//! let options = TerminalOptions::new().viewport(Viewport::Inline(10));
 //!
 //! let mut terminal = ratatui::init_with_options(options);
 //!
@@ -155,9 +155,7 @@
 //! ratatui::restore();
 //!
 //! // Using try_init_with_options() - returns Result for custom error handling
-//! let options = TerminalOptions {
-//!     viewport: Viewport::Inline(10),
-//! };
+//! let options = TerminalOptions::new().viewport(Viewport::Inline(10));
 //! let mut terminal = ratatui::try_init_with_options(options)?;
 //! terminal.draw(|frame| {
 //!     frame.render_widget("Inline UI", frame.area());
@@ -182,6 +180,7 @@
 //! | Function | Alternate Screen | Raw Mode | Error Handling | Use Case |
 //! |----------|------------------|----------|----------------|----------|
 //! | [`run`] | ✓ | ✓ | Auto-cleanup | Simple apps |
+//! | [`run_with_events`] | ✓ | ✓ | Auto-cleanup | Simple apps with ticks |
 //! | [`init`] | ✓ | ✓ | Panic | Standard full-screen apps |
 //! | [`try_init`] | ✓ | ✓ | Result | Standard apps with error handling |
 //! | [`init_with_options`] | ✗ | ✓ | Panic | Custom viewport apps |
@@ -196,14 +195,21 @@
 //! **Important**: Call the initialization functions *after* installing any other panic hooks to
 //! ensure the terminal is restored before other hooks run.
 
-use std::io::{self, Stdout, stdout};
+use alloc::string::String;
+use core::ops::ControlFlow;
+use core::time::Duration;
+use std::io::{self, Stdout, Write, stdout};
+use std::time::Instant;
 
-use ratatui_core::terminal::{Terminal, TerminalOptions};
-use ratatui_crossterm::CrosstermBackend;
+use ratatui_core::event::MouseEvent;
+use ratatui_core::layout::Size;
+use ratatui_core::terminal::{Frame, Terminal, TerminalOptions};
+use ratatui_crossterm::crossterm::event::{self as crossterm_event, KeyEvent};
 use ratatui_crossterm::crossterm::execute;
 use ratatui_crossterm::crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
 };
+use ratatui_crossterm::{CrosstermBackend, FromCrossterm};
 
 /// A type alias for the default terminal type.
 ///
@@ -325,6 +331,142 @@ where
     result
 }
 
+/// An event delivered to the closure of [`run_with_events`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Event {
+    /// The tick rate elapsed since the previous tick.
+    ///
+    /// A tick is also sent before the first frame, so that the UI is drawn immediately.
+    Tick,
+    /// A key was pressed, repeated, or released.
+    Key(KeyEvent),
+    /// A mouse event, which is only sent when mouse capture is enabled.
+    ///
+    /// The crossterm mouse event is converted to the backend independent [`MouseEvent`], which
+    /// can be passed to widgets and compared with the areas they were rendered to.
+    Mouse(MouseEvent),
+    /// The terminal was resized to the given size.
+    Resize(Size),
+    /// Text was pasted, which is only sent when bracketed paste is enabled.
+    Paste(String),
+    /// The terminal gained focus, which is only sent when focus change reporting is enabled.
+    FocusGained,
+    /// The terminal lost focus, which is only sent when focus change reporting is enabled.
+    FocusLost,
+}
+
+impl From<crossterm_event::Event> for Event {
+    fn from(event: crossterm_event::Event) -> Self {
+        match event {
+            crossterm_event::Event::Key(key) => Self::Key(key),
+            crossterm_event::Event::Mouse(mouse) => Self::Mouse(MouseEvent::from_crossterm(mouse)),
+            crossterm_event::Event::Resize(width, height) => Self::Resize(Size::new(width, height)),
+            crossterm_event::Event::Paste(text) => Self::Paste(text),
+            crossterm_event::Event::FocusGained => Self::FocusGained,
+            crossterm_event::Event::FocusLost => Self::FocusLost,
+        }
+    }
+}
+
+/// Run a closure that draws a frame for each event, with a terminal initialized with reasonable
+/// defaults for most applications.
+///
+/// This is like [`run`], but also owns the event loop that most applications write themselves:
+/// it reads terminal events, sends an [`Event::Tick`] every `tick_rate` (and once before the first
+/// frame), and draws a frame for each event. The closure is called with the frame and the event,
+/// updates the state of the app and renders it. Return [`ControlFlow::Continue`] to wait for the
+/// next event, or [`ControlFlow::Break`] to restore the terminal and return the given value.
+///
+/// Events are read with [`crossterm::event::poll`] and [`crossterm::event::read`], so the app
+/// should not read events itself. Key events are sent for every kind of key event, so check
+/// [`KeyEvent::is_press`] when an action should only happen once per key press (e.g. on Windows,
+/// where key releases are reported too).
+///
+/// # Errors
+///
+/// Returns an error if reading an event or drawing a frame fails.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::ops::ControlFlow;
+/// use std::time::Duration;
+///
+/// use crossterm::event::KeyCode;
+/// use ratatui::Event;
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut ticks = 0;
+///     ratatui::run_with_events(Duration::from_millis(250), |frame, event| {
+///         match event {
+///             Event::Tick => ticks += 1,
+///             Event::Key(key) if key.is_press() && key.code == KeyCode::Char('q') => {
+///                 return ControlFlow::Break(());
+///             }
+///             _ => {}
+///         }
+///         frame.render_widget(format!("{ticks} ticks, press q to quit"), frame.area());
+///         ControlFlow::Continue(())
+///     })
+/// }
+/// ```
+///
+/// [`crossterm::event::poll`]: ratatui_crossterm::crossterm::event::poll
+/// [`crossterm::event::read`]: ratatui_crossterm::crossterm::event::read
+pub fn run_with_events<F, R>(tick_rate: Duration, f: F) -> io::Result<R>
+where
+    F: FnMut(&mut Frame, Event) -> ControlFlow<R>,
+{
+    run(|terminal| event_loop(terminal, Some(tick_rate), f, read_event))
+}
+
+/// Waits for a terminal event for at most `timeout` (or indefinitely when it is `None`), and
+/// returns `None` if there was none.
+pub(crate) fn read_event(timeout: Option<Duration>) -> io::Result<Option<crossterm_event::Event>> {
+    match timeout {
+        Some(timeout) if !crossterm_event::poll(timeout)? => Ok(None),
+        _ => crossterm_event::read().map(Some),
+    }
+}
+
+/// Draws a frame for each event until the closure breaks.
+///
+/// Ticks are only sent every `tick_rate` when it is set, but there is always one before the first
+/// frame. `read` waits for a terminal event like [`read_event`].
+pub(crate) fn event_loop<W, F, R>(
+    terminal: &mut Terminal<CrosstermBackend<W>>,
+    tick_rate: Option<Duration>,
+    mut f: F,
+    mut read: impl FnMut(Option<Duration>) -> io::Result<Option<crossterm_event::Event>>,
+) -> io::Result<R>
+where
+    W: Write,
+    F: FnMut(&mut Frame, Event) -> ControlFlow<R>,
+{
+    let mut last_tick = Instant::now();
+    let mut event = Event::Tick;
+    loop {
+        let mut flow = ControlFlow::Continue(());
+        terminal.draw(|frame| flow = f(frame, event))?;
+        if let ControlFlow::Break(value) = flow {
+            return Ok(value);
+        }
+        event = loop {
+            let timeout = tick_rate.map(|tick_rate| tick_rate.saturating_sub(last_tick.elapsed()));
+            if let Some(event) = read(timeout)? {
+                break event.into();
+            }
+            if let Some(tick_rate) = tick_rate
+                && last_tick.elapsed() >= tick_rate
+            {
+                last_tick = Instant::now();
+                break Event::Tick;
+            }
+        };
+    }
+}
+
 /// Initialize a terminal with reasonable defaults for most applications.
 ///
 /// This will create a new [`DefaultTerminal`] and initialize it with the following defaults:
@@ -441,9 +583,7 @@ pub fn try_init() -> io::Result<DefaultTerminal> {
 /// ```rust,no_run
 /// use ratatui::{TerminalOptions, Viewport};
 ///
-/// let options = TerminalOptions {
-///     viewport: Viewport::Inline(5),
-/// };
+/// let options = TerminalOptions::new().viewport(Viewport::Inline(5));
 /// let terminal = ratatui::init_with_options(options);
 /// ```
 pub fn init_with_options(options: TerminalOptions) -> DefaultTerminal {
@@ -483,9 +623,7 @@ pub fn init_with_options(options: TerminalOptions) -> DefaultTerminal {
 /// ```no_run
 /// use ratatui::{TerminalOptions, Viewport};
 ///
-/// let options = TerminalOptions {
-///     viewport: Viewport::Inline(5),
-/// };
+/// let options = TerminalOptions::new().viewport(Viewport::Inline(5));
 /// let terminal = ratatui::try_init_with_options(options)?;
 /// # Ok::<(), std::io::Error>(())
 /// ```
@@ -570,3 +708,80 @@ fn set_panic_hook() {
         hook(info);
     }));
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::VecDeque;
+    use alloc::vec::Vec;
+
+    use ratatui_core::layout::Rect;
+    use ratatui_core::terminal::Viewport;
+    use ratatui_crossterm::crossterm::event::KeyCode;
+
+    use super::*;
+
+    #[test]
+    fn event_loop_draws_each_event() {
+        let backend = CrosstermBackend::new(Vec::new());
+        let options = TerminalOptions::new().viewport(Viewport::Fixed(Rect::new(0, 0, 10, 1)));
+        let mut terminal = Terminal::with_options(backend, options).unwrap();
+        let mut events = VecDeque::from([
+            None,
+            Some(crossterm_event::Event::Resize(20, 5)),
+            Some(crossterm_event::Event::Key(KeyCode::Char('q').into())),
+        ]);
+
+        let mut received = Vec::new();
+        let value = event_loop(
+            &mut terminal,
+            Some(Duration::ZERO),
+            |frame, event| {
+                frame.render_widget("frame", frame.area());
+                let is_quit = matches!(&event, Event::Key(key) if key.code == KeyCode::Char('q'));
+                received.push(event);
+                if is_quit {
+                    ControlFlow::Break(received.len())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            },
+            |_| Ok(events.pop_front().flatten()),
+        )
+        .unwrap();
+
+        assert_eq!(value, 4);
+        assert_eq!(
+            received,
+            [
+                Event::Tick,
+                Event::Tick,
+                Event::Resize(Size::new(20, 5)),
+                Event::Key(KeyCode::Char('q').into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn mouse_event_is_converted() {
+        use ratatui_core::event::{KeyModifiers, MouseButton, MouseEventKind};
+        use ratatui_core::layout::Position;
+        use ratatui_crossterm::crossterm::event::{
+            KeyModifiers as CrosstermModifiers, MouseButton as CrosstermButton,
+            MouseEvent as CrosstermEvent, MouseEventKind as CrosstermKind,
+        };
+
+        let event = Event::from(crossterm_event::Event::Mouse(CrosstermEvent {
+            kind: CrosstermKind::Down(CrosstermButton::Left),
+            column: 3,
+            row: 1,
+            modifiers: CrosstermModifiers::ALT,
+        }));
+        assert_eq!(
+            event,
+            Event::Mouse(
+                MouseEvent::new(MouseEventKind::Down(MouseButton::Left), Position::new(3, 1))
+                    .modifiers(KeyModifiers::ALT)
+            )
+        );
+    }
+}
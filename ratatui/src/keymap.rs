@@ -0,0 +1,878 @@
+//! Mapping key sequences to application actions.
+//!
+//! A [`Keymap`] maps sequences of [`Key`]s, such as `q`, `ctrl-s` or the chord `g g`, to actions
+//! of any type, usually an enum of the things the application can do. Feed each key press to
+//! [`Keymap::handle`], which returns the action once a complete sequence has been typed.
+//!
+//! Keys and sequences can be parsed from and formatted to strings, so that keybindings can be read
+//! from a configuration file. With the `serde` feature, a keymap is (de)serialized as a map of key
+//! sequences to actions:
+//!
+//! ```toml
+//! "q" = "Quit"
+//! "ctrl-s" = "Save"
+//! "g g" = "Top"
+//! ```
+//!
+//! With the `crossterm` feature, crossterm key events convert to [`Key`]s.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::keymap::{Key, KeyMatch, Keymap};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+//! enum Action {
+//!     Quit,
+//!     Save,
+//!     Top,
+//! }
+//!
+//! let mut keymap = Keymap::new()
+//!     .bind("q".parse()?, Action::Quit)
+//!     .bind("ctrl-s".parse()?, Action::Save)
+//!     .bind("g g".parse()?, Action::Top);
+//!
+//! assert_eq!(keymap.handle(Key::from('g')), KeyMatch::Pending);
+//! assert_eq!(
+//!     keymap.handle(Key::from('g')),
+//!     KeyMatch::Action(&Action::Top)
+//! );
+//! assert_eq!(
+//!     keymap.handle("ctrl-s".parse::<Key>()?),
+//!     KeyMatch::Action(&Action::Save)
+//! );
+//! assert_eq!(keymap.handle(Key::from('x')), KeyMatch::None);
+//!
+//! // generate help text from the `Display` implementation of the actions
+//! assert_eq!(keymap.help()[1], ("ctrl-s".to_string(), "Save".to_string()));
+//! # Ok::<(), ratatui::keymap::ParseKeyError>(())
+//! ```
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+pub use ratatui_core::event::KeyModifiers;
+
+use crate::widgets::{InputAction, KeyHints};
+
+/// A key, without modifiers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum KeyCode {
+    /// A character, including space.
+    Char(char),
+    /// A function key, e.g. `F(1)` for F1.
+    F(u8),
+    /// The Enter key.
+    Enter,
+    /// The Escape key.
+    Esc,
+    /// The Tab key.
+    Tab,
+    /// Shift and Tab.
+    BackTab,
+    /// The Backspace key.
+    Backspace,
+    /// The Delete key.
+    Delete,
+    /// The Insert key.
+    Insert,
+    /// The Home key.
+    Home,
+    /// The End key.
+    End,
+    /// The Page Up key.
+    PageUp,
+    /// The Page Down key.
+    PageDown,
+    /// The up arrow key.
+    Up,
+    /// The down arrow key.
+    Down,
+    /// The left arrow key.
+    Left,
+    /// The right arrow key.
+    Right,
+}
+
+/// The names of the keys that are not characters, as parsed and formatted.
+const KEY_NAMES: [(&str, KeyCode); 16] = [
+    ("space", KeyCode::Char(' ')),
+    ("enter", KeyCode::Enter),
+    ("esc", KeyCode::Esc),
+    ("tab", KeyCode::Tab),
+    ("backtab", KeyCode::BackTab),
+    ("backspace", KeyCode::Backspace),
+    ("delete", KeyCode::Delete),
+    ("insert", KeyCode::Insert),
+    ("home", KeyCode::Home),
+    ("end", KeyCode::End),
+    ("pageup", KeyCode::PageUp),
+    ("pagedown", KeyCode::PageDown),
+    ("up", KeyCode::Up),
+    ("down", KeyCode::Down),
+    ("left", KeyCode::Left),
+    ("right", KeyCode::Right),
+];
+
+/// The names of the modifiers, as parsed and formatted.
+const MODIFIER_NAMES: [(&str, KeyModifiers); 4] = [
+    ("ctrl", KeyModifiers::CTRL),
+    ("alt", KeyModifiers::ALT),
+    ("shift", KeyModifiers::SHIFT),
+    ("super", KeyModifiers::SUPER),
+];
+
+/// A key press: a [`KeyCode`] and the [`KeyModifiers`] held with it.
+///
+/// Keys are normalized so that the same key press always compares equal, whatever the backend
+/// reports: Shift with a character is the uppercase character (`shift-g` is `G`), and Shift with
+/// Tab is [`KeyCode::BackTab`].
+///
+/// Keys are formatted as the modifiers followed by the key, separated by `-`, such as `q`, `G`,
+/// `ctrl-s`, `alt-enter`, `f5` or `space`. When parsing, `+` is also accepted as a separator and
+/// the names of modifiers and keys are case-insensitive.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::keymap::{Key, KeyCode, KeyModifiers};
+///
+/// let key: Key = "Ctrl+s".parse()?;
+/// assert_eq!(key, Key::new(KeyCode::Char('s'), KeyModifiers::CTRL));
+/// assert_eq!(key.to_string(), "ctrl-s");
+/// assert_eq!("shift-g".parse::<Key>()?, Key::from('G'));
+/// # Ok::<(), ratatui::keymap::ParseKeyError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Key {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl Key {
+    /// Creates a new normalized key.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let shift = modifiers.contains(KeyModifiers::SHIFT);
+        let code = match code {
+            KeyCode::Char(c) if shift => KeyCode::Char(c.to_ascii_uppercase()),
+            KeyCode::Tab if shift => KeyCode::BackTab,
+            code => code,
+        };
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+
+    /// Returns the key, without modifiers.
+    pub const fn code(self) -> KeyCode {
+        self.code
+    }
+
+    /// Returns the modifiers held with the key.
+    pub const fn modifiers(self) -> KeyModifiers {
+        self.modifiers
+    }
+}
+
+impl From<char> for Key {
+    fn from(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::empty())
+    }
+}
+
+impl From<KeyCode> for Key {
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::empty())
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl TryFrom<crate::crossterm::event::KeyEvent> for Key {
+    type Error = UnsupportedKeyError;
+
+    /// Converts a crossterm key event, ignoring whether the key was pressed, repeated or released.
+    ///
+    /// Keys that have no [`KeyCode`], such as media keys, return an [`UnsupportedKeyError`].
+    fn try_from(event: crate::crossterm::event::KeyEvent) -> Result<Self, Self::Error> {
+        use crate::backend::FromCrossterm;
+        use crate::crossterm::event::KeyCode as Code;
+
+        let code = match event.code {
+            Code::Char(c) => KeyCode::Char(c),
+            Code::F(n) => KeyCode::F(n),
+            Code::Enter => KeyCode::Enter,
+            Code::Esc => KeyCode::Esc,
+            Code::Tab => KeyCode::Tab,
+            Code::BackTab => KeyCode::BackTab,
+            Code::Backspace => KeyCode::Backspace,
+            Code::Delete => KeyCode::Delete,
+            Code::Insert => KeyCode::Insert,
+            Code::Home => KeyCode::Home,
+            Code::End => KeyCode::End,
+            Code::PageUp => KeyCode::PageUp,
+            Code::PageDown => KeyCode::PageDown,
+            Code::Up => KeyCode::Up,
+            Code::Down => KeyCode::Down,
+            Code::Left => KeyCode::Left,
+            Code::Right => KeyCode::Right,
+            _ => return Err(UnsupportedKeyError),
+        };
+        Ok(Self::new(
+            code,
+            KeyModifiers::from_crossterm(event.modifiers),
+        ))
+    }
+}
+
+#[cfg(all(not(windows), feature = "termion"))]
+impl TryFrom<crate::termion::event::Key> for Key {
+    type Error = UnsupportedKeyError;
+
+    /// Converts a termion key.
+    ///
+    /// Termion reports Enter and Tab as the `'\n'` and `'\t'` characters, which convert to
+    /// [`KeyCode::Enter`] and [`KeyCode::Tab`]. Keys that have no [`KeyCode`], such as the null
+    /// byte, return an [`UnsupportedKeyError`].
+    fn try_from(key: crate::termion::event::Key) -> Result<Self, Self::Error> {
+        use crate::termion::event::Key as TermionKey;
+
+        let char_code = |c| match c {
+            '\n' => KeyCode::Enter,
+            '\t' => KeyCode::Tab,
+            c => KeyCode::Char(c),
+        };
+        let (code, modifiers) = match key {
+            TermionKey::Char(c) => (char_code(c), KeyModifiers::empty()),
+            TermionKey::Alt(c) => (char_code(c), KeyModifiers::ALT),
+            TermionKey::Ctrl(c) => (char_code(c), KeyModifiers::CTRL),
+            TermionKey::F(n) => (KeyCode::F(n), KeyModifiers::empty()),
+            TermionKey::Esc => (KeyCode::Esc, KeyModifiers::empty()),
+            TermionKey::BackTab => (KeyCode::BackTab, KeyModifiers::empty()),
+            TermionKey::Backspace => (KeyCode::Backspace, KeyModifiers::empty()),
+            TermionKey::Delete => (KeyCode::Delete, KeyModifiers::empty()),
+            TermionKey::Insert => (KeyCode::Insert, KeyModifiers::empty()),
+            TermionKey::Home => (KeyCode::Home, KeyModifiers::empty()),
+            TermionKey::CtrlHome => (KeyCode::Home, KeyModifiers::CTRL),
+            TermionKey::End => (KeyCode::End, KeyModifiers::empty()),
+            TermionKey::CtrlEnd => (KeyCode::End, KeyModifiers::CTRL),
+            TermionKey::PageUp => (KeyCode::PageUp, KeyModifiers::empty()),
+            TermionKey::PageDown => (KeyCode::PageDown, KeyModifiers::empty()),
+            TermionKey::Up => (KeyCode::Up, KeyModifiers::empty()),
+            TermionKey::ShiftUp => (KeyCode::Up, KeyModifiers::SHIFT),
+            TermionKey::AltUp => (KeyCode::Up, KeyModifiers::ALT),
+            TermionKey::CtrlUp => (KeyCode::Up, KeyModifiers::CTRL),
+            TermionKey::Down => (KeyCode::Down, KeyModifiers::empty()),
+            TermionKey::ShiftDown => (KeyCode::Down, KeyModifiers::SHIFT),
+            TermionKey::AltDown => (KeyCode::Down, KeyModifiers::ALT),
+            TermionKey::CtrlDown => (KeyCode::Down, KeyModifiers::CTRL),
+            TermionKey::Left => (KeyCode::Left, KeyModifiers::empty()),
+            TermionKey::ShiftLeft => (KeyCode::Left, KeyModifiers::SHIFT),
+            TermionKey::AltLeft => (KeyCode::Left, KeyModifiers::ALT),
+            TermionKey::CtrlLeft => (KeyCode::Left, KeyModifiers::CTRL),
+            TermionKey::Right => (KeyCode::Right, KeyModifiers::empty()),
+            TermionKey::ShiftRight => (KeyCode::Right, KeyModifiers::SHIFT),
+            TermionKey::AltRight => (KeyCode::Right, KeyModifiers::ALT),
+            TermionKey::CtrlRight => (KeyCode::Right, KeyModifiers::CTRL),
+            _ => return Err(UnsupportedKeyError),
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl TryFrom<Key> for InputAction {
+    type Error = UnsupportedKeyError;
+
+    /// Converts a key to the [`TextInputState`](crate::widgets::TextInputState) editing action it
+    /// is bound to by default.
+    ///
+    /// Characters typed without Ctrl, Alt or Super insert themselves, the arrow keys, Home and End
+    /// move the cursor, Backspace and Delete delete, `ctrl-z` undoes and `ctrl-y` or `ctrl-Z`
+    /// (Ctrl+Shift+Z) redoes. Other keys return an [`UnsupportedKeyError`].
+    fn try_from(key: Key) -> Result<Self, Self::Error> {
+        let ctrl = key.modifiers == KeyModifiers::CTRL;
+        let action = match key.code {
+            KeyCode::Char(c) if key.modifiers.is_empty() => Self::Insert(c),
+            KeyCode::Char('z') if ctrl => Self::Undo,
+            KeyCode::Char('y' | 'Z') if ctrl => Self::Redo,
+            KeyCode::Backspace => Self::DeleteBackward,
+            KeyCode::Delete => Self::DeleteForward,
+            KeyCode::Left => Self::MoveLeft,
+            KeyCode::Right => Self::MoveRight,
+            KeyCode::Home => Self::MoveToStart,
+            KeyCode::End => Self::MoveToEnd,
+            _ => return Err(UnsupportedKeyError),
+        };
+        Ok(action)
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, modifier) in MODIFIER_NAMES {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}-")?;
+            }
+        }
+        if let Some((name, _)) = KEY_NAMES.iter().find(|(_, code)| *code == self.code) {
+            return f.write_str(name);
+        }
+        match self.code {
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "f{n}"),
+            // every other key has a name
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Error type indicating a failure to parse a [`Key`] or [`KeySequence`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ParseKeyError;
+
+impl fmt::Display for ParseKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse key")
+    }
+}
+
+impl core::error::Error for ParseKeyError {}
+
+/// Error type indicating that a key event of a backend has no equivalent [`Key`], such as a media
+/// key.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct UnsupportedKeyError;
+
+impl fmt::Display for UnsupportedKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unsupported key")
+    }
+}
+
+impl core::error::Error for UnsupportedKeyError {}
+
+impl FromStr for Key {
+    type Err = ParseKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rest = s;
+        let mut modifiers = KeyModifiers::empty();
+        'modifiers: loop {
+            for (name, modifier) in MODIFIER_NAMES {
+                if let Some((prefix, remainder)) = rest.split_at_checked(name.len())
+                    && prefix.eq_ignore_ascii_case(name)
+                    && let Some(remainder) = remainder.strip_prefix(['-', '+'])
+                {
+                    modifiers |= modifier;
+                    rest = remainder;
+                    continue 'modifiers;
+                }
+            }
+            break;
+        }
+
+        let mut chars = rest.chars();
+        let name = rest.to_ascii_lowercase();
+        let code = if let (Some(c), None) = (chars.next(), chars.next()) {
+            KeyCode::Char(c)
+        } else if let Some((_, code)) = KEY_NAMES.iter().find(|(key, _)| *key == name) {
+            *code
+        } else if let Some(n) = name.strip_prefix('f')
+            && let Ok(n) = n.parse()
+        {
+            KeyCode::F(n)
+        } else {
+            return Err(ParseKeyError);
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+/// A sequence of [`Key`]s that are pressed one after another, such as `g g`.
+///
+/// Sequences are formatted as their keys separated by spaces.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct KeySequence(Vec<Key>);
+
+impl KeySequence {
+    /// Creates a new sequence of keys.
+    pub fn new<I: IntoIterator<Item = Key>>(keys: I) -> Self {
+        Self(keys.into_iter().collect())
+    }
+
+    /// Returns the keys of the sequence.
+    pub fn keys(&self) -> &[Key] {
+        &self.0
+    }
+}
+
+impl From<Key> for KeySequence {
+    fn from(key: Key) -> Self {
+        Self(Vec::from([key]))
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, key) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{key}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for KeySequence {
+    type Err = ParseKeyError;
+
+    /// Parses keys separated by whitespace. A sequence needs at least one key.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let keys = s
+            .split_whitespace()
+            .map(Key::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.is_empty() {
+            return Err(ParseKeyError);
+        }
+        Ok(Self(keys))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Key {
+    /// This utilises the [`fmt::Display`] implementation for serialization.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Key {
+    /// This utilises the [`FromStr`] implementation for deserialization.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <alloc::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeySequence {
+    /// This utilises the [`fmt::Display`] implementation for serialization.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KeySequence {
+    /// This utilises the [`FromStr`] implementation for deserialization.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <alloc::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The result of [`Keymap::handle`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum KeyMatch<'a, A> {
+    /// The keys pressed so far complete the sequence bound to the action.
+    Action(&'a A),
+    /// The keys pressed so far start one or more sequences, and more keys are needed.
+    Pending,
+    /// The key isn't bound.
+    None,
+}
+
+/// A map of [`KeySequence`]s to actions.
+///
+/// Bind sequences with [`Keymap::bind`], then feed each key press to [`Keymap::handle`]. The
+/// keymap remembers the keys of a sequence that has been started but not finished, which are
+/// returned by [`Keymap::pending`], e.g. to show them in a status bar.
+///
+/// A sequence that is also the start of a longer sequence is matched as soon as it is typed, so
+/// the longer sequence can't be typed. When a key doesn't continue the pending sequence, the
+/// pending keys are dropped and the key is handled as the start of a new sequence.
+///
+/// Several sequences can be bound to the same action, and binding a sequence again replaces its
+/// action. [`Keymap::help`] lists the sequences of each action, for a help screen or a footer.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Keymap<A> {
+    bindings: Vec<(KeySequence, A)>,
+    pending: Vec<Key>,
+}
+
+impl<A> Default for Keymap<A> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl<A> Keymap<A> {
+    /// Creates a new empty keymap.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds a sequence of keys to an action, replacing the action it was bound to if any.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bind(mut self, keys: KeySequence, action: A) -> Self {
+        self.insert(keys, action);
+        self
+    }
+
+    /// Binds a sequence of keys to an action, returning the action it was bound to if any.
+    pub fn insert(&mut self, keys: KeySequence, action: A) -> Option<A> {
+        if let Some((_, bound)) = self.bindings.iter_mut().find(|(bound, _)| *bound == keys) {
+            return Some(core::mem::replace(bound, action));
+        }
+        self.bindings.push((keys, action));
+        None
+    }
+
+    /// Removes the binding of a sequence of keys, returning its action if any.
+    pub fn remove(&mut self, keys: &KeySequence) -> Option<A> {
+        let index = self.bindings.iter().position(|(bound, _)| bound == keys)?;
+        Some(self.bindings.remove(index).1)
+    }
+
+    /// Returns the action bound to a sequence of keys.
+    pub fn get(&self, keys: &KeySequence) -> Option<&A> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| bound == keys)
+            .map(|(_, action)| action)
+    }
+
+    /// Returns an iterator over the bindings, in the order they were bound.
+    pub fn iter(&self) -> impl Iterator<Item = (&KeySequence, &A)> {
+        self.bindings.iter().map(|(keys, action)| (keys, action))
+    }
+
+    /// Returns the number of bindings.
+    pub const fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Returns true if there are no bindings.
+    pub const fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Returns the keys of the sequence that has been started but not finished.
+    pub fn pending(&self) -> &[Key] {
+        &self.pending
+    }
+
+    /// Drops the keys of the sequence that has been started but not finished, e.g. when Escape is
+    /// pressed or after a timeout.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Handles a key press, returning the action whose sequence it completes.
+    pub fn handle<K: Into<Key>>(&mut self, key: K) -> KeyMatch<'_, A> {
+        self.pending.push(key.into());
+        let mut is_prefix = false;
+        let mut matched = None;
+        for (index, (keys, _)) in self.bindings.iter().enumerate() {
+            if keys.keys() == self.pending.as_slice() {
+                matched = Some(index);
+                break;
+            }
+            is_prefix |= keys.keys().starts_with(&self.pending);
+        }
+        if let Some(index) = matched {
+            self.pending.clear();
+            return KeyMatch::Action(&self.bindings[index].1);
+        }
+        if is_prefix {
+            return KeyMatch::Pending;
+        }
+        if self.pending.len() > 1 {
+            let key = self.pending[self.pending.len() - 1];
+            self.pending.clear();
+            return self.handle(key);
+        }
+        self.pending.clear();
+        KeyMatch::None
+    }
+}
+
+impl<A: fmt::Display + PartialEq> Keymap<A> {
+    /// Returns the sequences and description of each action, in the order they were first bound.
+    ///
+    /// The description is the [`Display`](fmt::Display) of the action, and the sequences bound to
+    /// the same action are joined with `, `, e.g. `("j, down", "Move down")`.
+    pub fn help(&self) -> Vec<(String, String)> {
+        let mut help: Vec<(String, &A)> = Vec::new();
+        for (keys, action) in &self.bindings {
+            if let Some((help_keys, _)) = help.iter_mut().find(|(_, other)| *other == action) {
+                help_keys.push_str(", ");
+                help_keys.push_str(&keys.to_string());
+            } else {
+                help.push((keys.to_string(), action));
+            }
+        }
+        help.into_iter()
+            .map(|(keys, action)| (keys, action.to_string()))
+            .collect()
+    }
+
+    /// Returns a [`KeyHints`] widget that shows the [help](Keymap::help) of the keymap, e.g. as a
+    /// footer.
+    pub fn key_hints(&self) -> KeyHints<'static> {
+        KeyHints::new(self.help())
+    }
+}
+
+impl<A> FromIterator<(KeySequence, A)> for Keymap<A> {
+    fn from_iter<T: IntoIterator<Item = (KeySequence, A)>>(iter: T) -> Self {
+        let mut keymap = Self::new();
+        for (keys, action) in iter {
+            keymap.insert(keys, action);
+        }
+        keymap
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A: serde::Serialize> serde::Serialize for Keymap<A> {
+    /// Serializes the keymap as a map of key sequences to actions.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A: serde::Deserialize<'de>> serde::Deserialize<'de> for Keymap<A> {
+    /// Deserializes the keymap from a map of key sequences to actions, keeping the order of the
+    /// map.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<A>(core::marker::PhantomData<A>);
+
+        impl<'de, A: serde::Deserialize<'de>> serde::de::Visitor<'de> for Visitor<A> {
+            type Value = Keymap<A>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map of key sequences to actions")
+            }
+
+            fn visit_map<M: serde::de::MapAccess<'de>>(
+                self,
+                mut map: M,
+            ) -> Result<Self::Value, M::Error> {
+                let mut keymap = Keymap::new();
+                while let Some((keys, action)) = map.next_entry::<KeySequence, A>()? {
+                    keymap.insert(keys, action);
+                }
+                Ok(keymap)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(core::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum Action {
+        Quit,
+        Down,
+        Top,
+        Bottom,
+    }
+
+    #[rstest]
+    #[case("q", Key::from('q'))]
+    #[case("Q", Key::from('Q'))]
+    #[case("shift-q", Key::from('Q'))]
+    #[case("-", Key::from('-'))]
+    #[case("ctrl--", Key::new(KeyCode::Char('-'), KeyModifiers::CTRL))]
+    #[case("Ctrl+Alt+Enter", Key::new(KeyCode::Enter, KeyModifiers::CTRL | KeyModifiers::ALT))]
+    #[case("shift-tab", Key::from(KeyCode::BackTab))]
+    #[case("F12", Key::from(KeyCode::F(12)))]
+    #[case("space", Key::from(' '))]
+    fn parse_key(#[case] s: &str, #[case] key: Key) {
+        assert_eq!(s.parse(), Ok(key));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("ctrl-")]
+    #[case("hyper-a")]
+    #[case("fx")]
+    fn parse_key_error(#[case] s: &str) {
+        assert_eq!(s.parse::<Key>(), Err(ParseKeyError));
+    }
+
+    #[rstest]
+    #[case(Key::new(KeyCode::Char('s'), KeyModifiers::CTRL | KeyModifiers::ALT), "ctrl-alt-s")]
+    #[case(Key::new(KeyCode::Up, KeyModifiers::SHIFT), "shift-up")]
+    #[case(Key::from(' '), "space")]
+    #[case(Key::from(KeyCode::F(5)), "f5")]
+    fn display_key(#[case] key: Key, #[case] s: &str) {
+        assert_eq!(key.to_string(), s);
+        assert_eq!(s.parse(), Ok(key));
+    }
+
+    #[test]
+    fn sequence() {
+        let sequence: KeySequence = " g  ctrl-g ".parse().unwrap();
+        assert_eq!(
+            sequence.keys(),
+            [
+                Key::from('g'),
+                Key::new(KeyCode::Char('g'), KeyModifiers::CTRL)
+            ]
+        );
+        assert_eq!(sequence.to_string(), "g ctrl-g");
+        assert_eq!(" ".parse::<KeySequence>(), Err(ParseKeyError));
+    }
+
+    fn keymap() -> Keymap<Action> {
+        Keymap::from_iter([
+            ("q".parse().unwrap(), Action::Quit),
+            ("j".parse().unwrap(), Action::Down),
+            ("down".parse().unwrap(), Action::Down),
+            ("g g".parse().unwrap(), Action::Top),
+            ("G".parse().unwrap(), Action::Bottom),
+        ])
+    }
+
+    #[test]
+    fn handle() {
+        let mut keymap = keymap();
+        assert_eq!(keymap.handle('q'), KeyMatch::Action(&Action::Quit));
+        assert_eq!(
+            keymap.handle(KeyCode::Down),
+            KeyMatch::Action(&Action::Down)
+        );
+        assert_eq!(keymap.handle('x'), KeyMatch::None);
+
+        assert_eq!(keymap.handle('g'), KeyMatch::Pending);
+        assert_eq!(keymap.pending(), [Key::from('g')]);
+        assert_eq!(keymap.handle('g'), KeyMatch::Action(&Action::Top));
+        assert!(keymap.pending().is_empty());
+
+        // a key that doesn't continue the sequence starts a new one
+        assert_eq!(keymap.handle('g'), KeyMatch::Pending);
+        assert_eq!(keymap.handle('j'), KeyMatch::Action(&Action::Down));
+        assert_eq!(keymap.handle('g'), KeyMatch::Pending);
+        keymap.reset();
+        assert_eq!(keymap.handle('G'), KeyMatch::Action(&Action::Bottom));
+    }
+
+    #[test]
+    fn insert_replaces() {
+        let mut keymap = keymap();
+        let len = keymap.len();
+        assert_eq!(
+            keymap.insert(Key::from('q').into(), Action::Top),
+            Some(Action::Quit)
+        );
+        assert_eq!(keymap.len(), len);
+        assert_eq!(keymap.get(&Key::from('q').into()), Some(&Action::Top));
+        assert_eq!(keymap.remove(&Key::from('q').into()), Some(Action::Top));
+        assert_eq!(keymap.get(&Key::from('q').into()), None);
+    }
+
+    #[test]
+    fn help() {
+        assert_eq!(
+            keymap().help(),
+            vec![
+                ("q".to_string(), "Quit".to_string()),
+                ("j, down".to_string(), "Down".to_string()),
+                ("g g".to_string(), "Top".to_string()),
+                ("G".to_string(), "Bottom".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn key_hints() {
+        assert_eq!(
+            keymap().key_hints(),
+            KeyHints::new([
+                ("q", "Quit"),
+                ("j, down", "Down"),
+                ("g g", "Top"),
+                ("G", "Bottom")
+            ])
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn from_crossterm() {
+        use crate::crossterm::event::{KeyCode as Code, KeyEvent, KeyModifiers as Modifiers};
+
+        let event = KeyEvent::new(Code::Char('G'), Modifiers::SHIFT);
+        assert_eq!(Key::try_from(event), Ok(Key::from('G')));
+        let event = KeyEvent::new(Code::BackTab, Modifiers::SHIFT);
+        assert_eq!(Key::try_from(event), Ok(Key::from(KeyCode::BackTab)));
+        let event = KeyEvent::new(Code::Char('c'), Modifiers::CONTROL);
+        assert_eq!(Key::try_from(event), Ok("ctrl-c".parse().unwrap()));
+        let event = KeyEvent::new(Code::CapsLock, Modifiers::NONE);
+        assert_eq!(Key::try_from(event), Err(UnsupportedKeyError));
+    }
+
+    #[cfg(all(not(windows), feature = "termion"))]
+    #[test]
+    fn from_termion() {
+        use crate::termion::event::Key as TermionKey;
+
+        let key = |key| Key::try_from(key).unwrap();
+        assert_eq!(key(TermionKey::Char('G')), Key::from('G'));
+        assert_eq!(key(TermionKey::Char('\n')), Key::from(KeyCode::Enter));
+        assert_eq!(key(TermionKey::BackTab), Key::from(KeyCode::BackTab));
+        assert_eq!(key(TermionKey::Ctrl('c')), "ctrl-c".parse().unwrap());
+        assert_eq!(key(TermionKey::Alt('\n')), "alt-enter".parse().unwrap());
+        assert_eq!(key(TermionKey::ShiftUp), "shift-up".parse().unwrap());
+        assert_eq!(Key::try_from(TermionKey::Null), Err(UnsupportedKeyError));
+    }
+
+    #[test]
+    fn input_action() {
+        let action = |key: &str| InputAction::try_from(key.parse::<Key>().unwrap());
+        assert_eq!(action("a"), Ok(InputAction::Insert('a')));
+        assert_eq!(action("shift-a"), Ok(InputAction::Insert('A')));
+        assert_eq!(action("space"), Ok(InputAction::Insert(' ')));
+        assert_eq!(action("backspace"), Ok(InputAction::DeleteBackward));
+        assert_eq!(action("ctrl-z"), Ok(InputAction::Undo));
+        assert_eq!(action("ctrl-y"), Ok(InputAction::Redo));
+        assert_eq!(action("ctrl-shift-z"), Ok(InputAction::Redo));
+        assert_eq!(action("ctrl-a"), Err(UnsupportedKeyError));
+        assert_eq!(action("enter"), Err(UnsupportedKeyError));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let json = r#"{"q":"Quit","g g":"Top","ctrl-d":"Down"}"#;
+        let keymap: Keymap<Action> = serde_json::from_str(json).unwrap();
+        assert_eq!(keymap.get(&"g g".parse().unwrap()), Some(&Action::Top));
+        assert_eq!(serde_json::to_string(&keymap).unwrap(), json);
+        assert!(serde_json::from_str::<Keymap<Action>>(r#"{"hyper-x":"Quit"}"#).is_err());
+    }
+}
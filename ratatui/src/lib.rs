@@ -476,11 +476,17 @@ extern crate std;
 /// re-export the `palette` crate so that users don't have to add it as a dependency
 #[cfg(feature = "palette")]
 pub use palette;
-pub use ratatui_core::terminal::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport};
-pub use ratatui_core::{buffer, layout};
+#[cfg(feature = "clipboard")]
+pub use ratatui_core::clipboard;
+pub use ratatui_core::terminal::{
+    CompletedFrame, Frame, FrameStats, Terminal, TerminalOptions, Viewport, ViewportId,
+};
+pub use ratatui_core::{accessibility, buffer, event, layout};
 /// re-export the `crossterm` crate so that users don't have to add it as a dependency
 #[cfg(feature = "crossterm")]
 pub use ratatui_crossterm::crossterm;
+#[cfg(feature = "dsl")]
+pub use ratatui_dsl as dsl;
 #[cfg(feature = "macros")]
 pub use ratatui_macros as macros;
 /// re-export the `termina` crate so that users don't have to add it as a dependency
@@ -496,13 +502,20 @@ pub use ratatui_termwiz::termwiz;
 #[cfg(feature = "crossterm")]
 #[doc(inline)]
 pub use crate::init::{
-    DefaultTerminal, init, init_with_options, restore, run, try_init, try_init_with_options,
-    try_restore,
+    DefaultTerminal, Event, init, init_with_options, restore, run, run_with_events, try_init,
+    try_init_with_options, try_restore,
 };
 
 /// Re-exports for the backend implementations.
 pub mod backend {
-    pub use ratatui_core::backend::{Backend, ClearType, TestBackend, WindowSize};
+    #[cfg(feature = "async")]
+    pub use ratatui_core::backend::AsyncWriteBackend;
+    pub use ratatui_core::backend::{
+        AnsiBackend, AnsiTarget, Backend, ClearType, FramebufferBackend, FramebufferCell,
+        TestBackend, WindowSize,
+    };
+    #[cfg(feature = "asciicast")]
+    pub use ratatui_core::backend::{AsciicastBackend, AsciicastError};
     #[cfg(feature = "crossterm")]
     pub use ratatui_crossterm::{CrosstermBackend, FromCrossterm, IntoCrossterm};
     #[cfg(feature = "termina")]
@@ -513,6 +526,11 @@ pub mod backend {
     pub use ratatui_termwiz::{FromTermwiz, IntoTermwiz, TermwizBackend};
 }
 
+#[cfg(feature = "app")]
+pub mod app;
+#[cfg(feature = "compat-tui")]
+pub mod compat_tui;
+pub mod keymap;
 pub mod prelude;
 pub use ratatui_core::{style, symbols, text};
 pub mod widgets;
@@ -14,13 +14,12 @@
 //!
 //! Starting with Ratatui 0.30.0, the project was split into multiple crates for better modularity:
 //!
-//! - **[`ratatui-core`]**: Contains the core widget traits ([`Widget`], [`StatefulWidget`]) and
-//!   text-related types ([`String`], [`&str`], [`Span`], [`Line`], [`Text`])
+//! - **[`ratatui-core`]**: Contains the core widget traits ([`Widget`], [`StatefulWidget`],
+//!   [`WidgetRef`], [`StatefulWidgetRef`]) and text-related types ([`String`], [`&str`], [`Span`],
+//!   [`Line`], [`Text`])
 //! - **[`ratatui-widgets`]**: Contains all the built-in widget implementations ([`Block`],
 //!   [`Paragraph`], [`List`], etc.)
-//! - **[`ratatui`](crate)**: The main crate that re-exports everything for convenience. The
-//!   unstable [`WidgetRef`] and [`StatefulWidgetRef`] traits are defined in the main `ratatui`
-//!   crate as they are experimental.
+//! - **[`ratatui`](crate)**: The main crate that re-exports everything for convenience.
 //!
 //! This split serves different user needs:
 //!
@@ -45,19 +44,31 @@
 //!
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles and styles.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
+//! - [`BigText`]: renders text in large letters using block characters.
 //! - [`calendar::Monthly`]: displays a single month.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
+//! - [`Backdrop`]: dims the content behind a modal.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Fill`]: paints every cell in its area with a single repeated symbol and style.
+//! - [`FpsWidget`]: displays the frame rate and timings reported by the terminal.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`Heatmap`]: displays a 2D array of values as colors.
+//! - [`Knob`]: displays a value within a range as a circular dial.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`TextInput`]: edits a single line of text, with numeric and masked variants.
+//! - [`markdown::Markdown`]: renders Markdown as styled text.
+//! - [`NodeMap`]: displays a graph of nodes connected by edges.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
 //! - [`Scrollbar`]: displays a scrollbar.
+//! - [`Separator`]: draws a rule between the segments of a layout.
 //! - [`Sparkline`]: display a single data set as a sparkline.
+//! - [`Stack`]: lays out widgets of different types in a row or a column.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`terminal_pane::TerminalPane`]: shows the screen of a terminal emulator, such as a shell
+//!   running in a PTY.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //!
@@ -103,8 +114,8 @@
 //!
 //! - [`Widget`]: Basic trait for stateless widgets that are consumed when rendered
 //! - [`StatefulWidget`]: Trait for widgets that maintain state between renders
-//! - [`WidgetRef`]: Trait for rendering widgets by reference (unstable)
-//! - [`StatefulWidgetRef`]: Trait for rendering stateful widgets by reference (unstable)
+//! - [`WidgetRef`]: Trait for rendering widgets by reference
+//! - [`StatefulWidgetRef`]: Trait for rendering stateful widgets by reference
 //!
 //! ## `Widget`
 //!
@@ -152,12 +163,10 @@
 //!
 //! ```rust
 //! # use ratatui_core::{buffer::Buffer, layout::Rect};
-//! # #[cfg(feature = "unstable-widget-ref")]
 //! pub trait WidgetRef {
 //!     fn render_ref(&self, area: Rect, buf: &mut Buffer);
 //! }
 //!
-//! # #[cfg(feature = "unstable-widget-ref")]
 //! pub trait StatefulWidgetRef {
 //!     type State;
 //!     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State);
@@ -171,17 +180,9 @@
 //! - **Borrowing**: Render widgets when you only have a reference, not ownership
 //! - **Efficiency**: Avoid unnecessary cloning or reconstruction for repeated renders
 //!
-//! These traits are currently **experimental** and gated behind the `unstable-widget-ref` feature
-//! flag. This means:
-//!
-//! - The API may change in future releases
-//! - Method names, signatures, or behavior might be adjusted based on community feedback
-//! - You must explicitly enable the feature flag to use them: `features = ["unstable-widget-ref"]`
-//! - They are not covered by semantic versioning guarantees until stabilized
-//!
-//! The traits are being evaluated for potential breaking changes and improvements. See the
-//! [tracking issue](https://github.com/ratatui/ratatui/issues/1287) for ongoing discussions and
-//! design considerations.
+//! These traits are stable and no longer require the `unstable-widget-ref` feature flag. The
+//! [`Stack`] widget uses them to lay out a collection of widgets of different
+//! types.
 //!
 //! # Rendering Widgets
 //!
@@ -496,7 +497,7 @@
 //! any additional code. Ratatui provides blanket implementations that automatically implement these
 //! traits for any type that implements [`Widget`] or [`StatefulWidget`] on a reference. This means
 //! that implementing `Widget for &MyWidget` gives you both the standard widget functionality and
-//! the unstable [`WidgetRef`] capabilities for free.
+//! the [`WidgetRef`] capabilities for free.
 //!
 //! ## Manual `WidgetRef` Implementation (Advanced)
 //!
@@ -514,7 +515,6 @@
 //! than the reference-based [`Widget`] implementation:
 //!
 //! ```rust
-//! # #[cfg(feature = "unstable-widget-ref")] {
 //! # use ratatui_core::{buffer::Buffer, layout::Rect, style::Modifier, text::{Line, Span}};
 //! # use ratatui::widgets::{Widget, WidgetRef};
 //! struct GreetingWidget {
@@ -537,13 +537,11 @@
 //!         self.render_ref(area, buf);
 //!     }
 //! }
-//! # }
 //! ```
 //!
 //! This pattern allows the widget to be stored and rendered multiple times:
 //!
 //! ```rust
-//! # #[cfg(feature = "unstable-widget-ref")] {
 //! # use ratatui_core::{buffer::Buffer, layout::Rect};
 //! # use ratatui::widgets::WidgetRef;
 //! # struct GreetingWidget { name: String }
@@ -558,7 +556,6 @@
 //! fn render_app(app: &App, area: Rect, buf: &mut Buffer) {
 //!     app.greeting.render_ref(area, buf);
 //! }
-//! # }
 //! ```
 //!
 //! ### Using Trait Objects for Dynamic Collections
@@ -568,7 +565,6 @@
 //! types that are not known at compile time:
 //!
 //! ```rust
-//! # #[cfg(feature = "unstable-widget-ref")] {
 //! # use ratatui_core::{buffer::Buffer, layout::Rect};
 //! # use ratatui::widgets::WidgetRef;
 //! # struct Greeting;
@@ -582,7 +578,6 @@
 //! for widget in &widgets {
 //!     widget.render_ref(area, buf);
 //! }
-//! # }
 //! ```
 //!
 //! However, if you implement `Widget for &MyWidget`, you can achieve similar functionality by
@@ -664,40 +659,61 @@
 //! [`ratatui-core`]: https://crates.io/crates/ratatui-core
 //! [no-std concept guide]: https://ratatui.rs/concepts/no-std/
 
-pub use ratatui_core::widgets::{StatefulWidget, Widget};
-pub use ratatui_widgets::barchart::{Bar, BarChart, BarGroup};
+use ratatui_core::layout::Rect;
+pub use ratatui_core::widgets::{
+    IntrinsicSize, StatefulWidget, StatefulWidgetRef, Widget, WidgetRef,
+};
+#[cfg(feature = "animation")]
+pub use ratatui_widgets::animation;
+pub use ratatui_widgets::backdrop::Backdrop;
+pub use ratatui_widgets::barchart::{Bar, BarChart, BarGroup, ValueFormatter};
+#[cfg(feature = "widget-big-text")]
+pub use ratatui_widgets::big_text::{BigText, PixelSize};
 pub use ratatui_widgets::block::{
     Block, BlockExt, CellEffect, Dimmed, Padding, Shadow, TitlePosition, dimmed,
 };
 pub use ratatui_widgets::borders::{BorderType, Borders};
 #[cfg(feature = "widget-calendar")]
 pub use ratatui_widgets::calendar;
-pub use ratatui_widgets::canvas;
-pub use ratatui_widgets::chart::{Axis, Chart, Dataset, GraphType, LegendPosition};
-pub use ratatui_widgets::clear::Clear;
+pub use ratatui_widgets::chart::{
+    Axis, Chart, Dataset, GraphType, LegendPosition, StreamingDataset,
+};
+pub use ratatui_widgets::clear::{Clear, StyledClear};
 pub use ratatui_widgets::fill::Fill;
+pub use ratatui_widgets::focus::FocusManager;
+pub use ratatui_widgets::fps::FpsWidget;
 pub use ratatui_widgets::gauge::{Gauge, LineGauge};
-pub use ratatui_widgets::list::{List, ListDirection, ListItem, ListState};
+pub use ratatui_widgets::heatmap::{ColorScale, Heatmap};
+pub use ratatui_widgets::history::History;
+pub use ratatui_widgets::input::{InputAction, NumericInput, TextInput, TextInputState};
+pub use ratatui_widgets::key_hints::KeyHints;
+pub use ratatui_widgets::knob::Knob;
+pub use ratatui_widgets::list::{List, ListDirection, ListItem, ListState, Reorder};
+pub use ratatui_widgets::log_view::{self, LogBuffer, LogLevel, LogRecord, LogView, LogViewState};
 pub use ratatui_widgets::logo::{RatatuiLogo, Size as RatatuiLogoSize};
-pub use ratatui_widgets::mascot::{MascotEyeColor, RatatuiMascot};
-pub use ratatui_widgets::paragraph::{Paragraph, Wrap};
+#[cfg(feature = "widget-markdown")]
+pub use ratatui_widgets::markdown;
+pub use ratatui_widgets::mascot::{MascotEyeColor, MascotSize, RatatuiMascot};
+pub use ratatui_widgets::node_map::{Edge, Node, NodeLayout, NodeMap};
+pub use ratatui_widgets::pager::{Pager, PagerState};
+pub use ratatui_widgets::paragraph::{BufferExt, LineDecorator, Paragraph, Wrap};
+pub use ratatui_widgets::scroll_memory::ScrollMemory;
 pub use ratatui_widgets::scrollbar::{
     ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState,
 };
+pub use ratatui_widgets::separator::Separator;
 pub use ratatui_widgets::sparkline::{RenderDirection, Sparkline, SparklineBar};
-pub use ratatui_widgets::table::{Cell, HighlightSpacing, Row, Table, TableState};
+pub use ratatui_widgets::splits::{Splits, SplitsState};
+pub use ratatui_widgets::stack::Stack;
+pub use ratatui_widgets::table::{Cell, GridStyle, HighlightSpacing, Row, Table, TableState};
 pub use ratatui_widgets::tabs::Tabs;
-#[instability::unstable(feature = "widget-ref")]
-pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};
-
-mod stateful_widget_ref;
-mod widget_ref;
-
-use ratatui_core::layout::Rect;
+#[cfg(feature = "widget-terminal-pane")]
+pub use ratatui_widgets::terminal_pane::{self, TerminalPane, TerminalPaneState};
+pub use ratatui_widgets::toast::{Severity, Toast, Toasts};
+pub use ratatui_widgets::{canvas, highlight};
 
 /// Extension trait for [`Frame`] that provides methods to render [`WidgetRef`] and
 /// [`StatefulWidgetRef`] to the current buffer.
-#[instability::unstable(feature = "widget-ref")]
 pub trait FrameExt {
     /// Render a [`WidgetRef`] to the current buffer using [`WidgetRef::render_ref`].
     ///
@@ -707,7 +723,6 @@ pub trait FrameExt {
     /// # Example
     ///
     /// ```rust
-    /// # #[cfg(feature = "unstable-widget-ref")] {
     /// # use ratatui::{backend::TestBackend, Terminal};
     /// # let backend = TestBackend::new(5, 5);
     /// # let mut terminal = Terminal::new(backend).unwrap();
@@ -718,7 +733,6 @@ pub trait FrameExt {
     /// let block = Block::new();
     /// let area = Rect::new(0, 0, 5, 5);
     /// frame.render_widget_ref(&block, area);
-    /// # }
     /// ```
     ///
     /// [`Layout`]: crate::layout::Layout
@@ -736,7 +750,6 @@ pub trait FrameExt {
     /// # Example
     ///
     /// ```rust
-    /// # #[cfg(feature = "unstable-widget-ref")] {
     /// # use ratatui::{backend::TestBackend, Terminal};
     /// # let backend = TestBackend::new(5, 5);
     /// # let mut terminal = Terminal::new(backend).unwrap();
@@ -748,7 +761,6 @@ pub trait FrameExt {
     /// let list = List::new(vec![ListItem::new("Item 1"), ListItem::new("Item 2")]);
     /// let area = Rect::new(0, 0, 5, 5);
     /// frame.render_stateful_widget_ref(&list, area, &mut state);
-    /// # }
     /// ```
     /// [`Layout`]: crate::layout::Layout
     fn render_stateful_widget_ref<W>(&mut self, widget: W, area: Rect, state: &mut W::State)
@@ -756,7 +768,6 @@ pub trait FrameExt {
         W: StatefulWidgetRef;
 }
 
-#[cfg(feature = "unstable-widget-ref")]
 impl FrameExt for ratatui_core::terminal::Frame<'_> {
     fn render_widget_ref<W: WidgetRef>(&mut self, widget: W, area: Rect) {
         widget.render_ref(area, self.buffer_mut());
@@ -15,9 +15,7 @@ fn backend_termion_should_only_write_diffs() -> Result<(), Box<dyn std::error::E
         let area = Rect::new(0, 0, 3, 1);
         let mut terminal = Terminal::with_options(
             backend,
-            TerminalOptions {
-                viewport: Viewport::Fixed(area),
-            },
+            TerminalOptions::new().viewport(Viewport::Fixed(area)),
         )?;
         terminal.draw(|f| {
             f.render_widget(Paragraph::new("a"), area);
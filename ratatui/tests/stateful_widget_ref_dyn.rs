@@ -1,5 +1,3 @@
-#![cfg(feature = "unstable-widget-ref")]
-
 use std::any::{Any, type_name};
 use std::cell::RefCell;
 
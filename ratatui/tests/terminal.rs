@@ -71,9 +71,7 @@ fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
     let backend = TestBackend::new(20, 5);
     let mut terminal = Terminal::with_options(
         backend,
-        TerminalOptions {
-            viewport: Viewport::Inline(1),
-        },
+        TerminalOptions::new().viewport(Viewport::Inline(1)),
     )?;
 
     // insert_before cannot guarantee the contents of the viewport remain unharmed
@@ -114,9 +112,7 @@ fn terminal_insert_before_moves_viewport_does_not_clobber() -> Result<(), Box<dy
     let backend = TestBackend::new(20, 5);
     let mut terminal = Terminal::with_options(
         backend,
-        TerminalOptions {
-            viewport: Viewport::Inline(1),
-        },
+        TerminalOptions::new().viewport(Viewport::Inline(1)),
     )?;
 
     terminal.draw(|f| {
@@ -154,9 +150,7 @@ fn terminal_insert_before_scrolls_on_large_input() -> Result<(), Box<dyn Error>>
     let backend = TestBackend::new(20, 5);
     let mut terminal = Terminal::with_options(
         backend,
-        TerminalOptions {
-            viewport: Viewport::Inline(1),
-        },
+        TerminalOptions::new().viewport(Viewport::Inline(1)),
     )?;
 
     terminal.insert_before(5, |buf| {
@@ -199,9 +193,7 @@ fn terminal_insert_before_scrolls_on_large_input_does_not_clobber() -> Result<()
     let backend = TestBackend::new(20, 5);
     let mut terminal = Terminal::with_options(
         backend,
-        TerminalOptions {
-            viewport: Viewport::Inline(1),
-        },
+        TerminalOptions::new().viewport(Viewport::Inline(1)),
     )?;
 
     terminal.draw(|f| {
@@ -245,9 +237,7 @@ fn terminal_insert_before_scrolls_on_many_inserts() -> Result<(), Box<dyn Error>
     let backend = TestBackend::new(20, 5);
     let mut terminal = Terminal::with_options(
         backend,
-        TerminalOptions {
-            viewport: Viewport::Inline(1),
-        },
+        TerminalOptions::new().viewport(Viewport::Inline(1)),
     )?;
 
     terminal.insert_before(1, |buf| {
@@ -299,9 +289,7 @@ fn terminal_insert_before_scrolls_on_many_inserts_does_not_clobber() -> Result<(
     let backend = TestBackend::new(20, 5);
     let mut terminal = Terminal::with_options(
         backend,
-        TerminalOptions {
-            viewport: Viewport::Inline(1),
-        },
+        TerminalOptions::new().viewport(Viewport::Inline(1)),
     )?;
 
     terminal.draw(|f| {
@@ -351,9 +339,7 @@ fn terminal_insert_before_large_viewport() -> Result<(), Box<dyn Error>> {
     let backend = TestBackend::new(20, 3);
     let mut terminal = Terminal::with_options(
         backend,
-        TerminalOptions {
-            viewport: Viewport::Inline(3),
-        },
+        TerminalOptions::new().viewport(Viewport::Inline(3)),
     )?;
 
     terminal.insert_before(1, |buf| {
@@ -421,9 +407,7 @@ fn terminal_insert_before_large_viewport_does_not_clobber() -> Result<(), Box<dy
     let backend = TestBackend::new(20, 3);
     let mut terminal = Terminal::with_options(
         backend,
-        TerminalOptions {
-            viewport: Viewport::Inline(3),
-        },
+        TerminalOptions::new().viewport(Viewport::Inline(3)),
     )?;
 
     terminal.draw(|f| {